@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use flux_core::{CalendarEvent, CalendarGateway, CalendarGatewayError};
+
+use super::ics::parse_events;
+
+/// Reads meetings from an ICS feed: a CalDAV export URL, a webcal:// link or a local file
+#[derive(Clone)]
+pub struct IcsCalendarGateway {
+    source: String,
+}
+
+impl IcsCalendarGateway {
+    pub fn new(source: String) -> Self {
+        Self { source }
+    }
+
+    fn fetch_document(&self) -> Result<String, CalendarGatewayError> {
+        let url = match self.source.strip_prefix("webcal://") {
+            Some(rest) => format!("https://{}", rest),
+            None => self.source.clone(),
+        };
+
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return std::fs::read_to_string(&url).map_err(|error| CalendarGatewayError::Network {
+                message: format!("{}: {}", url, error),
+            });
+        }
+
+        ureq::get(&url)
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(code, response) => CalendarGatewayError::Network {
+                    message: format!("HTTP {}: {}", code, response.status_text()),
+                },
+                ureq::Error::Transport(transport) => CalendarGatewayError::Network {
+                    message: transport.to_string(),
+                },
+            })?
+            .into_string()
+            .map_err(|error| CalendarGatewayError::Network {
+                message: error.to_string(),
+            })
+    }
+}
+
+#[async_trait]
+impl CalendarGateway for IcsCalendarGateway {
+    async fn events_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>, CalendarGatewayError> {
+        let gateway = self.clone();
+
+        tokio::task::spawn_blocking(move || parse_events(&gateway.fetch_document()?, from, to))
+            .await
+            .map_err(|error| CalendarGatewayError::Network {
+                message: format!("task join error: {}", error),
+            })?
+    }
+
+    fn source_name(&self) -> &'static str {
+        "ics"
+    }
+}
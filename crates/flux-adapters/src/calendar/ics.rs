@@ -0,0 +1,448 @@
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+    Weekday,
+};
+use flux_core::{CalendarEvent, CalendarGatewayError};
+
+/// Upper bound on generated occurrences per recurring event
+const MAX_OCCURRENCES: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Daily,
+    Weekly,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<usize>,
+    until: Option<DateTime<Utc>>,
+    weekdays: Vec<Weekday>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct IcsEvent {
+    event: CalendarEvent,
+    rule: Option<RecurrenceRule>,
+    excluded: Vec<DateTime<Utc>>,
+}
+
+#[derive(Default)]
+struct EventBuilder {
+    title: String,
+    starts_at: Option<DateTime<Utc>>,
+    ends_at: Option<DateTime<Utc>>,
+    duration: Option<Duration>,
+    all_day: bool,
+    cancelled: bool,
+    rule: Option<RecurrenceRule>,
+    unsupported_rule: bool,
+    excluded: Vec<DateTime<Utc>>,
+}
+
+impl EventBuilder {
+    fn build(self) -> Option<IcsEvent> {
+        if self.all_day || self.cancelled {
+            return None;
+        }
+        let starts_at = self.starts_at?;
+        let ends_at = self
+            .ends_at
+            .or_else(|| self.duration.map(|duration| starts_at + duration))?;
+
+        Some(IcsEvent {
+            event: CalendarEvent::new(self.title, starts_at, ends_at),
+            rule: if self.unsupported_rule {
+                None
+            } else {
+                self.rule
+            },
+            excluded: self.excluded,
+        })
+    }
+}
+
+/// Timed meetings of an iCalendar document overlapping the window, sorted by start.
+/// All-day and cancelled events are skipped; daily and weekly recurrences are expanded,
+/// other recurrences only keep their first occurrence. Times with a TZID are read as local time.
+pub fn parse_events(
+    document: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<CalendarEvent>, CalendarGatewayError> {
+    let lines = unfold_lines(document);
+    if !lines
+        .iter()
+        .any(|line| line.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err(CalendarGatewayError::Parse {
+            message: "BEGIN:VCALENDAR absent".to_string(),
+        });
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<EventBuilder> = None;
+
+    for line in &lines {
+        if line.eq_ignore_ascii_case("BEGIN:VEVENT") {
+            current = Some(EventBuilder::default());
+            continue;
+        }
+        if line.eq_ignore_ascii_case("END:VEVENT") {
+            if let Some(ics_event) = current.take().and_then(EventBuilder::build) {
+                events.extend(occurrences(&ics_event, from, to));
+            }
+            continue;
+        }
+        let Some(builder) = current.as_mut() else {
+            continue;
+        };
+        let Some((name, parameters, value)) = split_property(line) else {
+            continue;
+        };
+
+        match name.as_str() {
+            "SUMMARY" => builder.title = unescape_text(value),
+            "DTSTART" => match parse_date_time(parameters, value) {
+                Some(DateValue::Timed(starts_at)) => builder.starts_at = Some(starts_at),
+                Some(DateValue::AllDay) => builder.all_day = true,
+                None => {}
+            },
+            "DTEND" => {
+                if let Some(DateValue::Timed(ends_at)) = parse_date_time(parameters, value) {
+                    builder.ends_at = Some(ends_at);
+                }
+            }
+            "DURATION" => builder.duration = parse_duration(value),
+            "STATUS" => builder.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            "RRULE" => match parse_rule(value) {
+                Some(rule) => builder.rule = Some(rule),
+                None => builder.unsupported_rule = true,
+            },
+            "EXDATE" => builder.excluded.extend(value.split(',').filter_map(|date| {
+                match parse_date_time(parameters, date) {
+                    Some(DateValue::Timed(excluded)) => Some(excluded),
+                    _ => None,
+                }
+            })),
+            _ => {}
+        }
+    }
+
+    events.sort_by_key(|event| event.starts_at);
+    Ok(events)
+}
+
+/// Joins continuation lines, which start with a space or a tab
+fn unfold_lines(document: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in document.lines() {
+        let raw = raw.trim_end_matches('\r');
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(previous)) => previous.push_str(continuation),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+fn split_property(line: &str) -> Option<(String, &str, &str)> {
+    let (head, value) = line.split_once(':')?;
+    let (name, parameters) = head.split_once(';').unwrap_or((head, ""));
+    Some((name.to_ascii_uppercase(), parameters, value.trim()))
+}
+
+fn unescape_text(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+enum DateValue {
+    Timed(DateTime<Utc>),
+    AllDay,
+}
+
+fn parse_date_time(parameters: &str, value: &str) -> Option<DateValue> {
+    let value = value.trim();
+    if parameters.to_ascii_uppercase().contains("VALUE=DATE") && !value.contains('T') {
+        return Some(DateValue::AllDay);
+    }
+    if value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()
+            .map(|_| DateValue::AllDay);
+    }
+
+    if let Some(utc) = value.strip_suffix(['Z', 'z']) {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(DateValue::Timed(naive.and_utc()));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    local_to_utc(naive).map(DateValue::Timed)
+}
+
+fn local_to_utc(naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|local| local.with_timezone(&Utc))
+}
+
+/// Durations such as `PT30M`, `PT1H30M` or `P1DT2H`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let body = value.trim().strip_prefix('P')?;
+    let (date_part, time_part) = body.split_once('T').unwrap_or((body, ""));
+
+    let mut total = Duration::zero();
+    for (part, units) in [(date_part, "WD"), (time_part, "HMS")] {
+        let mut number = String::new();
+        for character in part.chars() {
+            if character.is_ascii_digit() {
+                number.push(character);
+                continue;
+            }
+            if !units.contains(character) {
+                return None;
+            }
+            let amount: i64 = number.parse().ok()?;
+            number.clear();
+            total += match character {
+                'W' => Duration::weeks(amount),
+                'D' => Duration::days(amount),
+                'H' => Duration::hours(amount),
+                'M' => Duration::minutes(amount),
+                _ => Duration::seconds(amount),
+            };
+        }
+    }
+    Some(total)
+}
+
+fn parse_rule(value: &str) -> Option<RecurrenceRule> {
+    let mut frequency = None;
+    let mut rule = RecurrenceRule {
+        frequency: Frequency::Daily,
+        interval: 1,
+        count: None,
+        until: None,
+        weekdays: Vec::new(),
+    };
+
+    for part in value.split(';') {
+        let (key, part_value) = part.split_once('=')?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                frequency = match part_value.to_ascii_uppercase().as_str() {
+                    "DAILY" => Some(Frequency::Daily),
+                    "WEEKLY" => Some(Frequency::Weekly),
+                    _ => return None,
+                }
+            }
+            "INTERVAL" => rule.interval = part_value.parse().ok().filter(|&value| value > 0)?,
+            "COUNT" => rule.count = Some(part_value.parse().ok()?),
+            "UNTIL" => {
+                rule.until = Some(match parse_date_time("", part_value)? {
+                    DateValue::Timed(until) => until,
+                    DateValue::AllDay => {
+                        let date = NaiveDate::parse_from_str(part_value, "%Y%m%d").ok()?;
+                        local_to_utc(date.and_time(NaiveTime::from_hms_opt(23, 59, 59)?))?
+                    }
+                })
+            }
+            "BYDAY" => {
+                rule.weekdays = part_value
+                    .split(',')
+                    .map(|day| {
+                        parse_weekday(day.trim_start_matches(|character: char| {
+                            character.is_ascii_digit() || character == '+' || character == '-'
+                        }))
+                    })
+                    .collect::<Option<Vec<_>>>()?
+            }
+            "WKST" => {}
+            _ => return None,
+        }
+    }
+
+    rule.frequency = frequency?;
+    Some(rule)
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    match value.to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Occurrences overlapping the window; recurrences keep their local wall-clock time
+fn occurrences(ics_event: &IcsEvent, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<CalendarEvent> {
+    let event = &ics_event.event;
+    let overlaps = |candidate: &CalendarEvent| candidate.ends_at > from && candidate.starts_at < to;
+
+    let Some(rule) = &ics_event.rule else {
+        return [event.clone()].into_iter().filter(overlaps).collect();
+    };
+
+    let length = event.ends_at.signed_duration_since(event.starts_at);
+    let first_local = event.starts_at.with_timezone(&Local).naive_local();
+    let mut weekdays = rule.weekdays.clone();
+    if weekdays.is_empty() || rule.frequency == Frequency::Daily {
+        weekdays = vec![first_local.weekday()];
+    }
+    weekdays.sort_by_key(Weekday::num_days_from_monday);
+
+    let first_week =
+        first_local.date() - Duration::days(first_local.weekday().num_days_from_monday() as i64);
+    let mut found = Vec::new();
+    let mut generated = 0;
+
+    for period in 0.. {
+        let candidates: Vec<NaiveDateTime> = match rule.frequency {
+            Frequency::Daily => {
+                vec![first_local + Duration::days(period * rule.interval as i64)]
+            }
+            Frequency::Weekly => {
+                let week = first_week + Duration::weeks(period * rule.interval as i64);
+                weekdays
+                    .iter()
+                    .map(|weekday| {
+                        (week + Duration::days(weekday.num_days_from_monday() as i64))
+                            .and_time(first_local.time())
+                    })
+                    .filter(|candidate| *candidate >= first_local)
+                    .collect()
+            }
+        };
+
+        for candidate in candidates {
+            let Some(starts_at) = local_to_utc(candidate) else {
+                continue;
+            };
+            let past_until = rule.until.is_some_and(|until| starts_at > until);
+            let past_count = rule.count.is_some_and(|count| generated >= count);
+            if past_until || past_count || starts_at >= to || generated >= MAX_OCCURRENCES {
+                return found;
+            }
+            generated += 1;
+
+            let occurrence = CalendarEvent::new(event.title.clone(), starts_at, starts_at + length);
+            if overlaps(&occurrence) && !ics_event.excluded.contains(&starts_at) {
+                found.push(occurrence);
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window() -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            Utc.with_ymd_and_hms(2026, 10, 12, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap(),
+        )
+    }
+
+    fn calendar(events: &str) -> String {
+        format!("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n{events}END:VCALENDAR\r\n")
+    }
+
+    #[test]
+    fn timed_events_are_read_and_all_day_or_cancelled_ones_skipped() {
+        let document = calendar(
+            "BEGIN:VEVENT\r\nSUMMARY:Sprint review\\, team\r\nDTSTART:20261014T140000Z\r\n\
+             DTEND:20261014T150000Z\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nSUMMARY:Holiday\r\nDTSTART;VALUE=DATE:20261015\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nSUMMARY:Cancelled sync\r\nSTATUS:CANCELLED\r\n\
+             DTSTART:20261016T090000Z\r\nDURATION:PT30M\r\nEND:VEVENT\r\n",
+        );
+        let (from, to) = window();
+
+        let events = parse_events(&document, from, to).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].title, "Sprint review, team");
+        assert_eq!(
+            events[0].ends_at,
+            Utc.with_ymd_and_hms(2026, 10, 14, 15, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn folded_lines_and_durations_are_supported() {
+        let document = calendar(
+            "BEGIN:VEVENT\r\nSUMMARY:One-on-one with\r\n  Alex\r\nDTSTART:20261013T100000Z\r\n\
+             DURATION:PT1H30M\r\nEND:VEVENT\r\n",
+        );
+        let (from, to) = window();
+
+        let events = parse_events(&document, from, to).unwrap();
+
+        assert_eq!(events[0].title, "One-on-one with Alex");
+        assert_eq!(
+            events[0].ends_at,
+            Utc.with_ymd_and_hms(2026, 10, 13, 11, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn weekly_recurrences_are_expanded_within_the_window() {
+        let document = calendar(
+            "BEGIN:VEVENT\r\nSUMMARY:Standup\r\nDTSTART:20260105T090000Z\r\n\
+             DTEND:20260105T091500Z\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR\r\n\
+             EXDATE:20261016T090000Z\r\nEND:VEVENT\r\n",
+        );
+        let (from, to) = window();
+
+        let events = parse_events(&document, from, to).unwrap();
+
+        let days: Vec<u32> = events.iter().map(|event| event.starts_at.day()).collect();
+        assert_eq!(days, vec![12, 14]);
+    }
+
+    #[test]
+    fn recurrence_stops_after_its_count() {
+        let document = calendar(
+            "BEGIN:VEVENT\r\nSUMMARY:Onboarding\r\nDTSTART:20261010T080000Z\r\n\
+             DTEND:20261010T083000Z\r\nRRULE:FREQ=DAILY;COUNT=4\r\nEND:VEVENT\r\n",
+        );
+        let (from, to) = window();
+
+        let events = parse_events(&document, from, to).unwrap();
+
+        assert_eq!(events.len(), 2);
+    }
+
+    #[test]
+    fn documents_without_a_calendar_are_rejected() {
+        let (from, to) = window();
+
+        assert!(parse_events("<html>login</html>", from, to).is_err());
+    }
+
+    #[test]
+    fn durations_combine_days_and_times() {
+        assert_eq!(parse_duration("PT45M"), Some(Duration::minutes(45)));
+        assert_eq!(
+            parse_duration("P1DT2H"),
+            Some(Duration::days(1) + Duration::hours(2))
+        );
+        assert_eq!(parse_duration("PT5X"), None);
+    }
+}
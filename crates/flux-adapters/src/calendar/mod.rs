@@ -0,0 +1,4 @@
+mod gateway;
+mod ics;
+
+pub use gateway::IcsCalendarGateway;
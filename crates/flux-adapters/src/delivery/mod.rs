@@ -0,0 +1,5 @@
+mod smtp;
+mod webhook;
+
+pub use smtp::SmtpDigestGateway;
+pub use webhook::WebhookDigestGateway;
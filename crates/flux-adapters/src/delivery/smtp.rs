@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use flux_core::{DigestDeliveryError, DigestDeliveryGateway, DigestReport, SmtpDeliveryConfig};
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Message, SmtpTransport, Transport};
+
+const IMPLICIT_TLS_PORT: u16 = 465;
+
+#[derive(Clone)]
+pub struct SmtpDigestGateway {
+    config: SmtpDeliveryConfig,
+    password: Option<String>,
+}
+
+impl SmtpDigestGateway {
+    pub fn new(config: SmtpDeliveryConfig, password: Option<String>) -> Self {
+        Self { config, password }
+    }
+
+    fn build_message(&self, report: &DigestReport) -> Result<Message, DigestDeliveryError> {
+        let mut builder = Message::builder()
+            .from(parse_mailbox(&self.config.from)?)
+            .subject(&report.subject)
+            .header(ContentType::TEXT_PLAIN);
+
+        for recipient in &self.config.to {
+            builder = builder.to(parse_mailbox(recipient)?);
+        }
+
+        builder.body(report.markdown.clone()).map_err(|error| {
+            DigestDeliveryError::InvalidConfiguration {
+                message: error.to_string(),
+            }
+        })
+    }
+
+    fn send(&self, report: &DigestReport) -> Result<(), DigestDeliveryError> {
+        let message = self.build_message(report)?;
+
+        let builder = if self.config.port == IMPLICIT_TLS_PORT {
+            SmtpTransport::relay(&self.config.host)
+        } else {
+            SmtpTransport::starttls_relay(&self.config.host)
+        }
+        .map_err(|error| DigestDeliveryError::InvalidConfiguration {
+            message: error.to_string(),
+        })?
+        .port(self.config.port);
+
+        let builder = match (&self.config.username, &self.password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username.clone(), password.clone()))
+            }
+            _ => builder,
+        };
+
+        builder.build().send(&message).map_err(|error| {
+            if error.is_permanent() {
+                DigestDeliveryError::Rejected {
+                    message: error.to_string(),
+                }
+            } else {
+                DigestDeliveryError::Network {
+                    message: error.to_string(),
+                }
+            }
+        })?;
+
+        Ok(())
+    }
+}
+
+fn parse_mailbox(address: &str) -> Result<Mailbox, DigestDeliveryError> {
+    address
+        .parse()
+        .map_err(|_| DigestDeliveryError::InvalidConfiguration {
+            message: format!("adresse email invalide: {}", address),
+        })
+}
+
+#[async_trait]
+impl DigestDeliveryGateway for SmtpDigestGateway {
+    async fn deliver(&self, report: &DigestReport) -> Result<(), DigestDeliveryError> {
+        let gateway = self.clone();
+        let report = report.clone();
+
+        tokio::task::spawn_blocking(move || gateway.send(&report))
+            .await
+            .map_err(|error| DigestDeliveryError::Network {
+                message: format!("task join error: {}", error),
+            })?
+    }
+
+    fn channel_name(&self) -> &'static str {
+        "SMTP"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_config(to: Vec<&str>) -> SmtpDeliveryConfig {
+        SmtpDeliveryConfig {
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            username: None,
+            from: "Flux <flux@example.com>".to_string(),
+            to: to.into_iter().map(String::from).collect(),
+        }
+    }
+
+    fn create_report() -> DigestReport {
+        DigestReport {
+            subject: "Weekly Summary".to_string(),
+            summary: "2h 00min this week".to_string(),
+            markdown: "# Weekly Summary\n\n- **Total time**: 2h 00min\n".to_string(),
+        }
+    }
+
+    #[test]
+    fn builds_message_for_every_recipient() {
+        let gateway = SmtpDigestGateway::new(
+            create_config(vec!["damien@example.com", "team@example.com"]),
+            None,
+        );
+
+        let message = gateway.build_message(&create_report()).unwrap();
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+
+        assert!(formatted.contains("Subject: Weekly Summary"));
+        assert!(formatted.contains("damien@example.com"));
+        assert!(formatted.contains("team@example.com"));
+        assert!(formatted.contains("Total time"));
+    }
+
+    #[test]
+    fn rejects_invalid_recipient() {
+        let gateway = SmtpDigestGateway::new(create_config(vec!["not an address"]), None);
+
+        let result = gateway.build_message(&create_report());
+
+        assert!(matches!(
+            result,
+            Err(DigestDeliveryError::InvalidConfiguration { .. })
+        ));
+    }
+}
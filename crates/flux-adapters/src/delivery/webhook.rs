@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use flux_core::{DigestDeliveryError, DigestDeliveryGateway, DigestReport};
+use serde_json::{json, Value};
+
+const DISCORD_CONTENT_LIMIT: usize = 2000;
+
+#[derive(Clone)]
+pub struct WebhookDigestGateway {
+    url: String,
+}
+
+impl WebhookDigestGateway {
+    pub fn new(url: String) -> Self {
+        Self { url }
+    }
+
+    fn send(&self, report: &DigestReport) -> Result<(), DigestDeliveryError> {
+        ureq::post(&self.url)
+            .send_json(build_payload(report))
+            .map_err(|error| match error {
+                ureq::Error::Status(code, response) => DigestDeliveryError::Rejected {
+                    message: format!("HTTP {}: {}", code, response.status_text()),
+                },
+                ureq::Error::Transport(transport) => DigestDeliveryError::Network {
+                    message: transport.to_string(),
+                },
+            })?;
+
+        Ok(())
+    }
+}
+
+fn build_payload(report: &DigestReport) -> Value {
+    json!({
+        "username": "Flux",
+        "text": report.markdown,
+        "content": truncate(&report.markdown, DISCORD_CONTENT_LIMIT),
+    })
+}
+
+fn truncate(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_string();
+    }
+
+    let mut truncated: String = text.chars().take(limit - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[async_trait]
+impl DigestDeliveryGateway for WebhookDigestGateway {
+    async fn deliver(&self, report: &DigestReport) -> Result<(), DigestDeliveryError> {
+        let gateway = self.clone();
+        let report = report.clone();
+
+        tokio::task::spawn_blocking(move || gateway.send(&report))
+            .await
+            .map_err(|error| DigestDeliveryError::Network {
+                message: format!("task join error: {}", error),
+            })?
+    }
+
+    fn channel_name(&self) -> &'static str {
+        "webhook"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_report(markdown: &str) -> DigestReport {
+        DigestReport {
+            subject: "Weekly Summary".to_string(),
+            summary: "2h 00min this week".to_string(),
+            markdown: markdown.to_string(),
+        }
+    }
+
+    #[test]
+    fn payload_is_compatible_with_slack_and_discord() {
+        let payload = build_payload(&create_report("# Weekly Summary"));
+
+        assert_eq!(payload["text"], "# Weekly Summary");
+        assert_eq!(payload["content"], "# Weekly Summary");
+    }
+
+    #[test]
+    fn discord_content_is_truncated() {
+        let long_report = "é".repeat(DISCORD_CONTENT_LIMIT + 10);
+
+        let payload = build_payload(&create_report(&long_report));
+        let content = payload["content"].as_str().unwrap();
+
+        assert_eq!(content.chars().count(), DISCORD_CONTENT_LIMIT);
+        assert!(content.ends_with('…'));
+        assert_eq!(payload["text"].as_str().unwrap(), long_report);
+    }
+}
@@ -3,12 +3,20 @@
 //! This crate contains concrete implementations of the ports defined in flux-core.
 //! It bridges the domain logic with external services like GitLab, GitHub, etc.
 
+pub mod calendar;
+pub mod delivery;
 pub mod gitlab;
 pub mod sqlite;
+pub mod sync;
 pub mod testing;
 
+pub use calendar::IcsCalendarGateway;
+pub use delivery::{SmtpDigestGateway, WebhookDigestGateway};
 pub use gitlab::GitLabReviewGateway;
 pub use sqlite::{
-    SqliteAppTrackingRepository, SqliteSessionMetricsRepository, SqliteSessionRepository,
+    Database, SqliteAchievementRepository, SqliteAppTrackingRepository, SqliteArchive,
+    SqliteSessionMetricsRepository, SqliteSessionNoteRepository, SqliteSessionRepository,
+    SqliteSyncRepository,
 };
+pub use sync::FolderSyncGateway;
 pub use testing::{FailingReviewGateway, StubReviewGateway};
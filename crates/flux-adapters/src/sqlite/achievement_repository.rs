@@ -0,0 +1,138 @@
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+
+use flux_core::{
+    Achievement, AchievementRepository, AchievementRepositoryError, UnlockedAchievement,
+};
+
+use super::Database;
+
+pub struct SqliteAchievementRepository {
+    database: Database,
+}
+
+impl SqliteAchievementRepository {
+    pub fn new(path: &Path) -> Result<Self, AchievementRepositoryError> {
+        let database = Database::open(path)
+            .map_err(|error| AchievementRepositoryError::Persistence(error.to_string()))?;
+        Ok(Self::with_database(database))
+    }
+
+    pub fn in_memory() -> Result<Self, AchievementRepositoryError> {
+        let database = Database::in_memory()
+            .map_err(|error| AchievementRepositoryError::Persistence(error.to_string()))?;
+        Ok(Self::with_database(database))
+    }
+
+    pub fn with_database(database: Database) -> Self {
+        Self { database }
+    }
+}
+
+impl AchievementRepository for SqliteAchievementRepository {
+    fn unlock(&self, unlocked: &UnlockedAchievement) -> Result<bool, AchievementRepositoryError> {
+        let connection = self.database.connection();
+
+        let inserted = connection
+            .execute(
+                "INSERT OR IGNORE INTO achievements (achievement, session_id, unlocked_at)
+                 VALUES (?1, ?2, ?3)",
+                params![
+                    unlocked.achievement.id(),
+                    unlocked.session_id,
+                    unlocked.unlocked_at.to_rfc3339()
+                ],
+            )
+            .map_err(|error| AchievementRepositoryError::Persistence(error.to_string()))?;
+
+        Ok(inserted > 0)
+    }
+
+    fn find_all(&self) -> Result<Vec<UnlockedAchievement>, AchievementRepositoryError> {
+        let connection = self.database.connection();
+
+        let mut statement = connection
+            .prepare(
+                "SELECT achievement, session_id, unlocked_at
+                 FROM achievements
+                 ORDER BY unlocked_at ASC",
+            )
+            .map_err(|error| AchievementRepositoryError::Persistence(error.to_string()))?;
+
+        let rows = statement
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<i64>>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })
+            .map_err(|error| AchievementRepositoryError::Persistence(error.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| AchievementRepositoryError::Persistence(error.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id, session_id, unlocked_at)| {
+                let achievement = Achievement::from_id(&id)?;
+                let unlocked_at = DateTime::parse_from_rfc3339(&unlocked_at)
+                    .ok()?
+                    .with_timezone(&Utc);
+                Some(UnlockedAchievement {
+                    achievement,
+                    session_id,
+                    unlocked_at,
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlock_is_recorded_only_once() {
+        let repository = SqliteAchievementRepository::in_memory().unwrap();
+
+        let first = UnlockedAchievement::now(Achievement::DeepFocus, Some(4));
+        assert!(repository.unlock(&first).unwrap());
+        assert!(!repository
+            .unlock(&UnlockedAchievement::now(Achievement::DeepFocus, Some(9)))
+            .unwrap());
+
+        let unlocked = repository.find_all().unwrap();
+        assert_eq!(unlocked.len(), 1);
+        assert_eq!(unlocked[0].achievement, Achievement::DeepFocus);
+        assert_eq!(unlocked[0].session_id, Some(4));
+    }
+
+    #[test]
+    fn find_all_returns_unlocks_in_order() {
+        let repository = SqliteAchievementRepository::in_memory().unwrap();
+
+        let mut earlier = UnlockedAchievement::now(Achievement::ProductiveWeek, None);
+        earlier.unlocked_at -= chrono::Duration::days(1);
+        repository
+            .unlock(&UnlockedAchievement::now(
+                Achievement::DistractionFree,
+                Some(2),
+            ))
+            .unwrap();
+        repository.unlock(&earlier).unwrap();
+
+        let achievements: Vec<_> = repository
+            .find_all()
+            .unwrap()
+            .into_iter()
+            .map(|unlocked| unlocked.achievement)
+            .collect();
+        assert_eq!(
+            achievements,
+            vec![Achievement::ProductiveWeek, Achievement::DistractionFree]
+        );
+    }
+}
@@ -1,128 +1,60 @@
 use std::path::Path;
-use std::sync::Mutex;
 
+use chrono::{DateTime, NaiveDate, Utc};
 use rusqlite::{params, Connection};
 
-use flux_core::{AppTrackingRepository, AppTrackingRepositoryError, AppUsage, SessionId};
+use flux_core::{
+    AppTrackingRepository, AppTrackingRepositoryError, AppUsage, AppUsageSegment, CheckInOutcome,
+    DailyAppUsage, SegmentKind, SessionId, UsageRollupRepository, UsageRollupRepositoryError,
+};
+
+use super::session_metrics_repository::convert_projects;
+use super::title_cipher::SEALED_PATTERN;
+use super::{Database, TitleCipher};
 
 pub struct SqliteAppTrackingRepository {
-    connection: Mutex<Connection>,
+    database: Database,
 }
 
 impl SqliteAppTrackingRepository {
     pub fn new(path: &Path) -> Result<Self, AppTrackingRepositoryError> {
-        let connection =
-            Connection::open(path).map_err(|error| AppTrackingRepositoryError::Storage {
+        let database =
+            Database::open(path).map_err(|error| AppTrackingRepositoryError::Storage {
                 message: error.to_string(),
             })?;
-
-        let repository = Self {
-            connection: Mutex::new(connection),
-        };
-        repository.initialize_schema()?;
-
-        Ok(repository)
+        Ok(Self::with_database(database))
     }
 
     pub fn in_memory() -> Result<Self, AppTrackingRepositoryError> {
-        let connection =
-            Connection::open_in_memory().map_err(|error| AppTrackingRepositoryError::Storage {
+        let database =
+            Database::in_memory().map_err(|error| AppTrackingRepositoryError::Storage {
                 message: error.to_string(),
             })?;
-
-        let repository = Self {
-            connection: Mutex::new(connection),
-        };
-        repository.initialize_schema()?;
-
-        Ok(repository)
+        Ok(Self::with_database(database))
     }
 
-    fn initialize_schema(&self) -> Result<(), AppTrackingRepositoryError> {
-        let connection = self.connection.lock().unwrap();
-
-        let table_exists: bool = connection
-            .query_row(
-                "SELECT 1 FROM sqlite_master WHERE type='table' AND name='app_tracking'",
-                [],
-                |_| Ok(true),
-            )
-            .unwrap_or(false);
-
-        if table_exists {
-            self.migrate_schema(&connection)?;
-        } else {
-            connection
-                .execute_batch(
-                    "CREATE TABLE app_tracking (
-                        session_id INTEGER NOT NULL,
-                        application_name TEXT NOT NULL,
-                        window_title TEXT NOT NULL DEFAULT '',
-                        duration_seconds INTEGER NOT NULL DEFAULT 0,
-                        PRIMARY KEY (session_id, application_name, window_title)
-                    );",
-                )
-                .map_err(|error| AppTrackingRepositoryError::Storage {
-                    message: error.to_string(),
-                })?;
-        }
-
-        Ok(())
-    }
-
-    fn migrate_schema(&self, connection: &Connection) -> Result<(), AppTrackingRepositoryError> {
-        let has_window_title: bool = connection
-            .query_row(
-                "SELECT 1 FROM pragma_table_info('app_tracking') WHERE name='window_title'",
-                [],
-                |_| Ok(true),
-            )
-            .unwrap_or(false);
-
-        if has_window_title {
-            return Ok(());
-        }
-
-        connection
-            .execute_batch(
-                "
-                ALTER TABLE app_tracking RENAME TO app_tracking_old;
-
-                CREATE TABLE app_tracking (
-                    session_id INTEGER NOT NULL,
-                    application_name TEXT NOT NULL,
-                    window_title TEXT NOT NULL DEFAULT '',
-                    duration_seconds INTEGER NOT NULL DEFAULT 0,
-                    PRIMARY KEY (session_id, application_name, window_title)
-                );
-
-                INSERT INTO app_tracking (session_id, application_name, window_title, duration_seconds)
-                SELECT session_id, application_name, '', duration_seconds
-                FROM app_tracking_old;
-
-                DROP TABLE app_tracking_old;
-                ",
-            )
-            .map_err(|error| AppTrackingRepositoryError::Storage {
-                message: format!("migration failed: {}", error),
-            })
+    pub fn with_database(database: Database) -> Self {
+        Self { database }
     }
 }
 
+const UPSERT_APP_USAGE: &str =
+    "INSERT INTO app_tracking (session_id, application_name, window_title, duration_seconds)
+     VALUES (?1, ?2, ?3, ?4)
+     ON CONFLICT (session_id, application_name, window_title)
+     DO UPDATE SET duration_seconds = duration_seconds + excluded.duration_seconds";
+
 impl AppTrackingRepository for SqliteAppTrackingRepository {
     fn save_or_update(&self, usage: &AppUsage) -> Result<(), AppTrackingRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         connection
             .execute(
-                "INSERT INTO app_tracking (session_id, application_name, window_title, duration_seconds)
-                 VALUES (?1, ?2, ?3, ?4)
-                 ON CONFLICT (session_id, application_name, window_title)
-                 DO UPDATE SET duration_seconds = duration_seconds + excluded.duration_seconds",
+                UPSERT_APP_USAGE,
                 params![
                     usage.session_id,
                     &usage.application_name,
-                    &usage.window_title,
+                    self.database.seal_title(&usage.window_title),
                     usage.duration_seconds
                 ],
             )
@@ -133,25 +65,70 @@ impl AppTrackingRepository for SqliteAppTrackingRepository {
         Ok(())
     }
 
+    fn save_or_update_batch(&self, usages: &[AppUsage]) -> Result<(), AppTrackingRepositoryError> {
+        if usages.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = self.database.connection();
+
+        let transaction =
+            connection
+                .transaction()
+                .map_err(|error| AppTrackingRepositoryError::Storage {
+                    message: error.to_string(),
+                })?;
+
+        {
+            let mut statement = transaction
+                .prepare_cached(UPSERT_APP_USAGE)
+                .map_err(|error| AppTrackingRepositoryError::Storage {
+                    message: error.to_string(),
+                })?;
+
+            for usage in usages {
+                statement
+                    .execute(params![
+                        usage.session_id,
+                        &usage.application_name,
+                        self.database.seal_title(&usage.window_title),
+                        usage.duration_seconds
+                    ])
+                    .map_err(|error| AppTrackingRepositoryError::Storage {
+                        message: error.to_string(),
+                    })?;
+            }
+        }
+
+        transaction
+            .commit()
+            .map_err(|error| AppTrackingRepositoryError::Storage {
+                message: error.to_string(),
+            })
+    }
+
     fn find_by_session(
         &self,
         session_id: SessionId,
     ) -> Result<Vec<AppUsage>, AppTrackingRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let mut statement = connection
             .prepare(
-                "SELECT session_id, application_name, window_title, duration_seconds
-                 FROM app_tracking
+                "SELECT session_id, application_name, window_title, SUM(duration_seconds) as total_seconds
+                 FROM app_usage_totals
                  WHERE session_id = ?1
-                 ORDER BY duration_seconds DESC",
+                 GROUP BY application_name, window_title
+                 ORDER BY total_seconds DESC",
             )
             .map_err(|error| AppTrackingRepositoryError::Storage {
                 message: error.to_string(),
             })?;
 
         let usages = statement
-            .query_map(params![session_id], |row| Ok(row_to_app_usage(row)))
+            .query_map(params![session_id], |row| {
+                Ok(row_to_app_usage(row, &self.database))
+            })
             .map_err(|error| AppTrackingRepositoryError::Storage {
                 message: error.to_string(),
             })?
@@ -171,7 +148,7 @@ impl AppTrackingRepository for SqliteAppTrackingRepository {
             return Ok(Vec::new());
         }
 
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let placeholders: String = session_ids
             .iter()
@@ -180,7 +157,7 @@ impl AppTrackingRepository for SqliteAppTrackingRepository {
             .join(",");
         let query = format!(
             "SELECT 0 as session_id, application_name, window_title, SUM(duration_seconds) as total_seconds
-             FROM app_tracking
+             FROM app_usage_totals
              WHERE session_id IN ({})
              GROUP BY application_name, window_title
              ORDER BY total_seconds DESC",
@@ -196,7 +173,7 @@ impl AppTrackingRepository for SqliteAppTrackingRepository {
 
         let usages = statement
             .query_map(rusqlite::params_from_iter(session_ids.iter()), |row| {
-                Ok(row_to_app_usage(row))
+                Ok(row_to_app_usage(row, &self.database))
             })
             .map_err(|error| AppTrackingRepositoryError::Storage {
                 message: error.to_string(),
@@ -209,23 +186,290 @@ impl AppTrackingRepository for SqliteAppTrackingRepository {
         Ok(usages)
     }
 
-    fn delete_by_session(&self, session_id: SessionId) -> Result<(), AppTrackingRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+    fn save_segments(
+        &self,
+        segments: &[AppUsageSegment],
+    ) -> Result<(), AppTrackingRepositoryError> {
+        if segments.is_empty() {
+            return Ok(());
+        }
 
-        connection
-            .execute(
-                "DELETE FROM app_tracking WHERE session_id = ?1",
-                params![session_id],
+        let mut connection = self.database.connection();
+
+        let transaction =
+            connection
+                .transaction()
+                .map_err(|error| AppTrackingRepositoryError::Storage {
+                    message: error.to_string(),
+                })?;
+
+        {
+            let mut statement = transaction
+                .prepare_cached(
+                    "INSERT INTO app_usage_segments (session_id, kind, application_name, window_title, started_at, ended_at, check_in_outcome)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                )
+                .map_err(|error| AppTrackingRepositoryError::Storage {
+                    message: error.to_string(),
+                })?;
+
+            for segment in segments {
+                statement
+                    .execute(params![
+                        segment.session_id,
+                        segment.kind.as_str(),
+                        &segment.application_name,
+                        self.database.seal_title(&segment.window_title),
+                        segment.started_at.to_rfc3339(),
+                        segment.ended_at.to_rfc3339(),
+                        segment.check_in_outcome.map(|outcome| outcome.as_str())
+                    ])
+                    .map_err(|error| AppTrackingRepositoryError::Storage {
+                        message: error.to_string(),
+                    })?;
+            }
+        }
+
+        transaction
+            .commit()
+            .map_err(|error| AppTrackingRepositoryError::Storage {
+                message: error.to_string(),
+            })
+    }
+
+    fn find_segments_by_session(
+        &self,
+        session_id: SessionId,
+    ) -> Result<Vec<AppUsageSegment>, AppTrackingRepositoryError> {
+        let connection = self.database.connection();
+
+        let mut statement = connection
+            .prepare(
+                "SELECT session_id, kind, application_name, window_title, started_at, ended_at, check_in_outcome
+                 FROM app_usage_segments
+                 WHERE session_id = ?1
+                 ORDER BY started_at",
             )
             .map_err(|error| AppTrackingRepositoryError::Storage {
                 message: error.to_string(),
             })?;
 
+        let segments = statement
+            .query_map(params![session_id], |row| {
+                Ok(row_to_segment(row, &self.database))
+            })
+            .map_err(|error| AppTrackingRepositoryError::Storage {
+                message: error.to_string(),
+            })?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| AppTrackingRepositoryError::Storage {
+                message: error.to_string(),
+            })?;
+
+        Ok(segments.into_iter().flatten().collect())
+    }
+
+    fn delete_by_session(&self, session_id: SessionId) -> Result<(), AppTrackingRepositoryError> {
+        let connection = self.database.connection();
+
+        for query in [
+            "DELETE FROM app_tracking WHERE session_id = ?1",
+            "DELETE FROM app_usage_segments WHERE session_id = ?1",
+        ] {
+            connection
+                .execute(query, params![session_id])
+                .map_err(|error| AppTrackingRepositoryError::Storage {
+                    message: error.to_string(),
+                })?;
+        }
+
         Ok(())
     }
 }
 
-fn row_to_app_usage(row: &rusqlite::Row) -> AppUsage {
+fn row_to_segment(row: &rusqlite::Row, database: &Database) -> Option<AppUsageSegment> {
+    let session_id: i64 = row.get(0).ok()?;
+    let kind: String = row.get(1).ok()?;
+    let application_name: String = row.get(2).ok()?;
+    let window_title: String = row.get(3).ok()?;
+    let started_at: String = row.get(4).ok()?;
+    let ended_at: String = row.get(5).ok()?;
+    let check_in_outcome: Option<String> = row.get(6).ok()?;
+
+    Some(
+        AppUsageSegment::new(
+            session_id,
+            SegmentKind::from_stored(&kind)?,
+            application_name,
+            parse_timestamp(&started_at)?,
+            parse_timestamp(&ended_at)?,
+        )
+        .with_window_title(database.open_title(window_title))
+        .with_check_in_outcome(
+            check_in_outcome
+                .as_deref()
+                .and_then(CheckInOutcome::from_stored),
+        ),
+    )
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+const SEGMENT_DAILY_TOTALS: &str = "SELECT date(started_at, 'localtime') AS date, application_name,
+        kind = 'distraction' AS is_distraction,
+        CAST(ROUND((julianday(ended_at) - julianday(started_at)) * 86400) AS INTEGER) AS seconds
+     FROM app_usage_segments
+     WHERE kind IN ('focus', 'distraction')";
+
+/// Usage recorded before segments existed, dated by the start of its session
+const LEGACY_DAILY_TOTALS: &str = "SELECT date(sessions.started_at, 'localtime') AS date,
+        app_tracking.application_name, 0 AS is_distraction, app_tracking.duration_seconds AS seconds
+     FROM app_tracking
+     JOIN sessions ON sessions.id = app_tracking.session_id";
+
+impl UsageRollupRepository for SqliteAppTrackingRepository {
+    fn refresh_rollups(
+        &self,
+        since: Option<NaiveDate>,
+    ) -> Result<usize, UsageRollupRepositoryError> {
+        let mut connection = self.database.connection();
+        let since = since
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+
+        let has_sessions: bool = connection
+            .query_row(
+                "SELECT 1 FROM sqlite_master WHERE type='table' AND name='sessions'",
+                [],
+                |_| Ok(true),
+            )
+            .unwrap_or(false);
+        let sources = if has_sessions {
+            format!("{} UNION ALL {}", SEGMENT_DAILY_TOTALS, LEGACY_DAILY_TOTALS)
+        } else {
+            SEGMENT_DAILY_TOTALS.to_string()
+        };
+
+        let transaction = connection
+            .transaction()
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?;
+
+        transaction
+            .execute(
+                "DELETE FROM app_usage_daily WHERE date >= ?1",
+                params![since],
+            )
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?;
+
+        let written = transaction
+            .execute(
+                &format!(
+                    "INSERT INTO app_usage_daily (date, application_name, is_distraction, seconds)
+                     SELECT date, application_name, is_distraction, SUM(seconds)
+                     FROM ({})
+                     WHERE date >= ?1
+                     GROUP BY date, application_name, is_distraction",
+                    sources
+                ),
+                params![since],
+            )
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?;
+
+        transaction
+            .execute(
+                "INSERT OR REPLACE INTO app_usage_daily_refreshes (id, refreshed_at) VALUES (1, ?1)",
+                params![Utc::now().to_rfc3339()],
+            )
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?;
+
+        transaction
+            .commit()
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?;
+
+        Ok(written)
+    }
+
+    fn find_rollups_since(
+        &self,
+        since: NaiveDate,
+    ) -> Result<Vec<DailyAppUsage>, UsageRollupRepositoryError> {
+        let connection = self.database.connection();
+
+        let mut statement = connection
+            .prepare(
+                "SELECT date, application_name, seconds, is_distraction
+                 FROM app_usage_daily
+                 WHERE date >= ?1
+                 ORDER BY date ASC, seconds DESC",
+            )
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?;
+
+        let rows = statement
+            .query_map(params![since.format("%Y-%m-%d").to_string()], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, i64>(2)?,
+                    row.get::<_, bool>(3)?,
+                ))
+            })
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(date, application_name, seconds, is_distraction)| {
+                Some(DailyAppUsage {
+                    date: NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok()?,
+                    application_name,
+                    seconds,
+                    is_distraction,
+                })
+            })
+            .collect())
+    }
+
+    fn last_rollup_day(&self) -> Result<Option<NaiveDate>, UsageRollupRepositoryError> {
+        let connection = self.database.connection();
+
+        let last_day: Option<String> = connection
+            .query_row("SELECT MAX(date) FROM app_usage_daily", [], |row| {
+                row.get(0)
+            })
+            .map_err(|error| UsageRollupRepositoryError::Persistence(error.to_string()))?;
+
+        Ok(last_day.and_then(|date| NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok()))
+    }
+
+    fn last_refreshed_at(&self) -> Result<Option<DateTime<Utc>>, UsageRollupRepositoryError> {
+        let connection = self.database.connection();
+
+        let refreshed_at: Option<String> = connection
+            .query_row(
+                "SELECT refreshed_at FROM app_usage_daily_refreshes WHERE id = 1",
+                [],
+                |row| row.get(0),
+            )
+            .map(Some)
+            .or_else(|error| match error {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                error => Err(UsageRollupRepositoryError::Persistence(error.to_string())),
+            })?;
+
+        Ok(refreshed_at.and_then(|refreshed_at| {
+            DateTime::parse_from_rfc3339(&refreshed_at)
+                .ok()
+                .map(|refreshed_at| refreshed_at.with_timezone(&Utc))
+        }))
+    }
+}
+
+fn row_to_app_usage(row: &rusqlite::Row, database: &Database) -> AppUsage {
     let session_id: i64 = row.get(0).unwrap();
     let application_name: String = row.get(1).unwrap();
     let window_title: String = row.get(2).unwrap();
@@ -234,15 +478,202 @@ fn row_to_app_usage(row: &rusqlite::Row) -> AppUsage {
     AppUsage {
         session_id,
         application_name,
-        window_title,
+        window_title: database.open_title(window_title),
         duration_seconds,
     }
 }
 
+/// Rewrites the stored window titles and the project names read from them
+/// encrypted, or decrypted when `encrypt` is off. Titles the cipher cannot
+/// decrypt are left as they are.
+pub(super) fn convert_titles(
+    connection: &mut Connection,
+    cipher: &TitleCipher,
+    encrypt: bool,
+) -> rusqlite::Result<()> {
+    let filter = if encrypt {
+        "window_title != '' AND window_title NOT LIKE ?1"
+    } else {
+        "window_title LIKE ?1"
+    };
+    let convert = |stored: &str| {
+        if encrypt {
+            Some(cipher.seal(stored))
+        } else {
+            cipher.open(stored)
+        }
+    };
+
+    let transaction = connection.transaction()?;
+
+    let segments = transaction
+        .prepare(&format!(
+            "SELECT rowid, window_title FROM app_usage_segments WHERE {filter}"
+        ))?
+        .query_map([SEALED_PATTERN], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    for (rowid, stored) in segments {
+        if let Some(title) = convert(&stored) {
+            transaction.execute(
+                "UPDATE app_usage_segments SET window_title = ?1 WHERE rowid = ?2",
+                params![title, rowid],
+            )?;
+        }
+    }
+
+    // The converted title may already have a row for the session, which
+    // then receives the duration
+    let usages = transaction
+        .prepare(&format!(
+            "SELECT rowid, session_id, application_name, window_title, duration_seconds
+             FROM app_tracking WHERE {filter}"
+        ))?
+        .query_map([SEALED_PATTERN], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, i64>(4)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    for (rowid, session_id, application_name, stored, duration_seconds) in usages {
+        if let Some(title) = convert(&stored) {
+            transaction.execute("DELETE FROM app_tracking WHERE rowid = ?1", params![rowid])?;
+            transaction.execute(
+                UPSERT_APP_USAGE,
+                params![session_id, application_name, title, duration_seconds],
+            )?;
+        }
+    }
+    convert_projects(&transaction, cipher, encrypt)?;
+
+    transaction.commit()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn segments_are_returned_in_chronological_order() {
+        let repository = SqliteAppTrackingRepository::in_memory().unwrap();
+        let start = Utc::now();
+
+        repository
+            .save_segments(&[
+                AppUsageSegment::new(
+                    1,
+                    SegmentKind::Distraction,
+                    "discord".to_string(),
+                    start + chrono::Duration::seconds(60),
+                    start + chrono::Duration::seconds(90),
+                ),
+                AppUsageSegment::new(
+                    1,
+                    SegmentKind::Focus,
+                    "cursor".to_string(),
+                    start,
+                    start + chrono::Duration::seconds(60),
+                ),
+                AppUsageSegment::check_in(2, start),
+            ])
+            .unwrap();
+
+        let segments = repository.find_segments_by_session(1).unwrap();
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].application_name, "cursor");
+        assert_eq!(segments[1].kind, SegmentKind::Distraction);
+        assert_eq!(segments[1].duration_seconds(), 30);
+
+        repository.delete_by_session(1).unwrap();
+        assert!(repository.find_segments_by_session(1).unwrap().is_empty());
+        assert_eq!(repository.find_segments_by_session(2).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn check_in_outcomes_are_persisted() {
+        let repository = SqliteAppTrackingRepository::in_memory().unwrap();
+        let start = Utc::now();
+
+        repository
+            .save_segments(&[
+                AppUsageSegment::check_in(1, start)
+                    .with_check_in_outcome(Some(CheckInOutcome::NotFocused)),
+                AppUsageSegment::check_in(1, start + chrono::Duration::minutes(10)),
+            ])
+            .unwrap();
+
+        let outcomes: Vec<Option<CheckInOutcome>> = repository
+            .find_segments_by_session(1)
+            .unwrap()
+            .iter()
+            .map(|segment| segment.check_in_outcome)
+            .collect();
+        assert_eq!(outcomes, vec![Some(CheckInOutcome::NotFocused), None]);
+    }
+
+    #[test]
+    fn usage_totals_include_focus_and_distraction_segments() {
+        let repository = SqliteAppTrackingRepository::in_memory().unwrap();
+        let start = Utc::now();
+
+        repository
+            .save_or_update(&AppUsage::with_duration(1, "cursor".to_string(), 40))
+            .unwrap();
+        repository
+            .save_segments(&[
+                AppUsageSegment::new(
+                    1,
+                    SegmentKind::Focus,
+                    "cursor".to_string(),
+                    start,
+                    start + chrono::Duration::seconds(60),
+                ),
+                AppUsageSegment::new(
+                    1,
+                    SegmentKind::Distraction,
+                    "firefox".to_string(),
+                    start + chrono::Duration::seconds(60),
+                    start + chrono::Duration::seconds(90),
+                )
+                .with_window_title("YouTube".to_string()),
+                AppUsageSegment::pause(
+                    1,
+                    start + chrono::Duration::seconds(90),
+                    start + chrono::Duration::seconds(600),
+                ),
+                AppUsageSegment::new(
+                    2,
+                    SegmentKind::Focus,
+                    "firefox".to_string(),
+                    start,
+                    start + chrono::Duration::seconds(20),
+                )
+                .with_window_title("YouTube".to_string()),
+            ])
+            .unwrap();
+
+        let usages = repository.find_by_session(1).unwrap();
+
+        assert_eq!(usages.len(), 2);
+        assert_eq!(usages[0].session_id, 1);
+        assert_eq!(usages[0].application_name, "cursor");
+        assert_eq!(usages[0].duration_seconds, 100);
+        assert_eq!(usages[1].window_title, "YouTube");
+        assert_eq!(usages[1].duration_seconds, 30);
+
+        let totals = repository.find_by_sessions(&[1, 2]).unwrap();
+
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[1].application_name, "firefox");
+        assert_eq!(totals[1].duration_seconds, 50);
+    }
+
     #[test]
     fn save_and_retrieve_app_usage() {
         let repository = SqliteAppTrackingRepository::in_memory().unwrap();
@@ -273,6 +704,40 @@ mod tests {
         assert_eq!(usages[0].duration_seconds, 55);
     }
 
+    #[test]
+    fn save_or_update_batch_persists_all_usages() {
+        let repository = SqliteAppTrackingRepository::in_memory().unwrap();
+
+        repository
+            .save_or_update(&AppUsage::with_duration(1, "cursor".to_string(), 40))
+            .unwrap();
+
+        repository
+            .save_or_update_batch(&[
+                AppUsage::with_duration(1, "cursor".to_string(), 60),
+                AppUsage::with_duration(1, "firefox".to_string(), 30),
+                AppUsage::with_title(1, "firefox".to_string(), "GitHub".to_string(), 20),
+            ])
+            .unwrap();
+
+        let usages = repository.find_by_session(1).unwrap();
+
+        assert_eq!(usages.len(), 3);
+        assert_eq!(usages[0].application_name, "cursor");
+        assert_eq!(usages[0].duration_seconds, 100);
+        assert_eq!(usages[1].duration_seconds, 30);
+        assert_eq!(usages[2].window_title, "GitHub");
+    }
+
+    #[test]
+    fn save_or_update_batch_accepts_empty_input() {
+        let repository = SqliteAppTrackingRepository::in_memory().unwrap();
+
+        repository.save_or_update_batch(&[]).unwrap();
+
+        assert!(repository.find_by_session(1).unwrap().is_empty());
+    }
+
     #[test]
     fn multiple_apps_in_same_session() {
         let repository = SqliteAppTrackingRepository::in_memory().unwrap();
@@ -462,4 +927,165 @@ mod tests {
         assert_eq!(usages[1].window_title, "GitHub");
         assert_eq!(usages[1].duration_seconds, 30);
     }
+
+    fn local_time(day: u32, hour: u32) -> DateTime<Utc> {
+        use chrono::TimeZone;
+        chrono::Local
+            .with_ymd_and_hms(2026, 10, day, hour, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn segment(
+        session_id: SessionId,
+        kind: SegmentKind,
+        application_name: &str,
+        start: DateTime<Utc>,
+        seconds: i64,
+    ) -> AppUsageSegment {
+        AppUsageSegment::new(
+            session_id,
+            kind,
+            application_name.to_string(),
+            start,
+            start + chrono::Duration::seconds(seconds),
+        )
+    }
+
+    #[test]
+    fn rollups_sum_each_day_per_application_and_kind() {
+        let repository = SqliteAppTrackingRepository::in_memory().unwrap();
+        repository
+            .save_segments(&[
+                segment(1, SegmentKind::Focus, "cursor", local_time(14, 9), 600),
+                segment(
+                    1,
+                    SegmentKind::Distraction,
+                    "discord",
+                    local_time(14, 10),
+                    60,
+                ),
+                AppUsageSegment::pause(1, local_time(14, 11), local_time(14, 12)),
+                segment(2, SegmentKind::Focus, "cursor", local_time(14, 15), 300),
+                segment(3, SegmentKind::Focus, "cursor", local_time(15, 9), 120),
+            ])
+            .unwrap();
+
+        assert_eq!(repository.refresh_rollups(None).unwrap(), 3);
+
+        let day = |day: u32| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+        let rollups = repository.find_rollups_since(day(1)).unwrap();
+        assert_eq!(
+            rollups,
+            vec![
+                DailyAppUsage {
+                    date: day(14),
+                    application_name: "cursor".to_string(),
+                    seconds: 900,
+                    is_distraction: false,
+                },
+                DailyAppUsage {
+                    date: day(14),
+                    application_name: "discord".to_string(),
+                    seconds: 60,
+                    is_distraction: true,
+                },
+                DailyAppUsage {
+                    date: day(15),
+                    application_name: "cursor".to_string(),
+                    seconds: 120,
+                    is_distraction: false,
+                },
+            ]
+        );
+        assert_eq!(repository.last_rollup_day().unwrap(), Some(day(15)));
+    }
+
+    #[test]
+    fn partial_refresh_keeps_earlier_days() {
+        let repository = SqliteAppTrackingRepository::in_memory().unwrap();
+        let day = |day: u32| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+        assert_eq!(repository.last_rollup_day().unwrap(), None);
+        assert_eq!(repository.last_refreshed_at().unwrap(), None);
+
+        repository
+            .save_segments(&[segment(
+                1,
+                SegmentKind::Focus,
+                "cursor",
+                local_time(14, 9),
+                600,
+            )])
+            .unwrap();
+        repository.refresh_rollups(None).unwrap();
+        repository
+            .save_segments(&[
+                segment(1, SegmentKind::Focus, "cursor", local_time(14, 10), 600),
+                segment(2, SegmentKind::Focus, "zed", local_time(15, 9), 60),
+            ])
+            .unwrap();
+
+        repository.refresh_rollups(Some(day(15))).unwrap();
+        assert!(repository.last_refreshed_at().unwrap().is_some());
+
+        let rollups = repository.find_rollups_since(day(14)).unwrap();
+        assert_eq!(rollups.len(), 2);
+        assert_eq!(rollups[0].seconds, 600);
+        assert_eq!(rollups[1].application_name, "zed");
+    }
+
+    fn stored_titles(database: &Database) -> Vec<String> {
+        let connection = database.connection();
+        let mut statement = connection
+            .prepare("SELECT window_title FROM app_tracking")
+            .unwrap();
+        statement
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn titles_are_stored_encrypted_and_read_back_in_clear() {
+        let key = TitleCipher::generate_key().unwrap();
+        let jira = |seconds| {
+            AppUsage::with_title(
+                1,
+                "firefox".to_string(),
+                "ACME-1234 - Jira".to_string(),
+                seconds,
+            )
+        };
+        let plain = Database::in_memory().unwrap();
+        SqliteAppTrackingRepository::with_database(plain.clone())
+            .save_or_update(&jira(30))
+            .unwrap();
+
+        let encrypted = plain
+            .clone()
+            .with_title_cipher(TitleCipher::new(&key).unwrap(), true);
+        assert_eq!(stored_titles(&plain), vec!["ACME-1234 - Jira"]);
+        encrypted.convert_stored_titles().unwrap();
+        let repository = SqliteAppTrackingRepository::with_database(encrypted);
+        repository.save_or_update(&jira(20)).unwrap();
+
+        let stored = stored_titles(&plain);
+        assert_eq!(stored.len(), 1);
+        assert!(!stored[0].contains("ACME"));
+        let usages = repository.find_by_session(1).unwrap();
+        assert_eq!(usages[0].window_title, "ACME-1234 - Jira");
+        assert_eq!(usages[0].duration_seconds, 50);
+        assert_eq!(
+            SqliteAppTrackingRepository::with_database(plain.clone())
+                .find_by_session(1)
+                .unwrap()[0]
+                .window_title,
+            flux_core::REDACTED_TITLE
+        );
+
+        let decrypted = plain.with_title_cipher(TitleCipher::new(&key).unwrap(), false);
+        decrypted.convert_stored_titles().unwrap();
+        assert_eq!(stored_titles(&decrypted), vec!["ACME-1234 - Jira"]);
+    }
 }
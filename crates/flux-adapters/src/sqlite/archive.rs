@@ -0,0 +1,263 @@
+use std::fs;
+use std::path::Path;
+
+use rusqlite::types::ValueRef;
+use rusqlite::Connection;
+
+use flux_core::{
+    ArchiveCompatibility, ArchiveError, ArchiveManifest, TableChecksum, ARCHIVE_DATABASE_FILE,
+    ARCHIVE_MANIFEST_FILE,
+};
+
+use super::{migrate, SCHEMA_VERSION};
+
+const COLUMN_SEPARATOR: char = '\u{1f}';
+
+pub struct SqliteArchive;
+
+impl SqliteArchive {
+    pub fn create(
+        database_path: &Path,
+        destination: &Path,
+    ) -> Result<ArchiveManifest, ArchiveError> {
+        fs::create_dir_all(destination).map_err(storage_error)?;
+
+        let archived_database = destination.join(ARCHIVE_DATABASE_FILE);
+        if archived_database.exists() {
+            fs::remove_file(&archived_database).map_err(storage_error)?;
+        }
+
+        let connection = Connection::open(database_path).map_err(storage_error)?;
+        connection
+            .execute(
+                "VACUUM INTO ?1",
+                [archived_database.to_string_lossy().as_ref()],
+            )
+            .map_err(storage_error)?;
+
+        let archived_connection = Connection::open(&archived_database).map_err(storage_error)?;
+        let manifest = ArchiveManifest::new(
+            SCHEMA_VERSION,
+            env!("CARGO_PKG_VERSION"),
+            table_checksums(&archived_connection)?,
+        );
+
+        fs::write(destination.join(ARCHIVE_MANIFEST_FILE), manifest.to_toml()?)
+            .map_err(storage_error)?;
+
+        Ok(manifest)
+    }
+
+    pub fn restore(
+        archive: &Path,
+        database_path: &Path,
+    ) -> Result<ArchiveCompatibility, ArchiveError> {
+        let content =
+            fs::read_to_string(archive.join(ARCHIVE_MANIFEST_FILE)).map_err(storage_error)?;
+        let manifest = ArchiveManifest::from_toml(&content)?;
+        let compatibility = manifest.compatibility(SCHEMA_VERSION)?;
+
+        let archived_database = archive.join(ARCHIVE_DATABASE_FILE);
+        {
+            let connection = Connection::open(&archived_database).map_err(storage_error)?;
+            manifest.verify(&table_checksums(&connection)?)?;
+        }
+
+        let staging_path = database_path.with_extension("restore");
+        fs::copy(&archived_database, &staging_path).map_err(storage_error)?;
+
+        if let ArchiveCompatibility::RequiresMigration { .. } = compatibility {
+            if let Err(error) = migrate_database(&staging_path) {
+                let _ = fs::remove_file(&staging_path);
+                return Err(error);
+            }
+        }
+
+        fs::rename(&staging_path, database_path).map_err(storage_error)?;
+
+        Ok(compatibility)
+    }
+}
+
+fn table_checksums(connection: &Connection) -> Result<Vec<TableChecksum>, ArchiveError> {
+    let mut statement = connection
+        .prepare(
+            "SELECT name FROM sqlite_master
+             WHERE type = 'table' AND name NOT LIKE 'sqlite_%'
+             ORDER BY name",
+        )
+        .map_err(storage_error)?;
+    let tables = statement
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(storage_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(storage_error)?;
+
+    tables
+        .iter()
+        .map(|table| {
+            let rows = table_rows(connection, table)?;
+            Ok(TableChecksum::compute(
+                table,
+                rows.iter().map(String::as_str),
+            ))
+        })
+        .collect()
+}
+
+fn table_rows(connection: &Connection, table: &str) -> Result<Vec<String>, ArchiveError> {
+    let query = format!(
+        "SELECT * FROM \"{}\" ORDER BY rowid",
+        table.replace('"', "\"\"")
+    );
+    let mut statement = connection.prepare(&query).map_err(storage_error)?;
+    let column_count = statement.column_count();
+
+    let rows = statement
+        .query_map([], |row| {
+            let mut columns = Vec::with_capacity(column_count);
+            for index in 0..column_count {
+                columns.push(format_value(row.get_ref(index)?));
+            }
+            Ok(columns.join(&COLUMN_SEPARATOR.to_string()))
+        })
+        .map_err(storage_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(storage_error)?;
+
+    Ok(rows)
+}
+
+fn format_value(value: ValueRef) -> String {
+    match value {
+        ValueRef::Null => "NULL".to_string(),
+        ValueRef::Integer(integer) => integer.to_string(),
+        ValueRef::Real(real) => real.to_string(),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned(),
+        ValueRef::Blob(blob) => blob.iter().map(|byte| format!("{:02x}", byte)).collect(),
+    }
+}
+
+fn migrate_database(database_path: &Path) -> Result<(), ArchiveError> {
+    let mut connection = Connection::open(database_path).map_err(storage_error)?;
+    migrate(&mut connection).map_err(storage_error)?;
+    Ok(())
+}
+
+fn storage_error(error: impl std::fmt::Display) -> ArchiveError {
+    ArchiveError::Storage(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::SqliteSessionRepository;
+    use flux_core::{FocusMode, Session, SessionRepository};
+
+    fn temporary_directory(name: &str) -> std::path::PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("flux-archive-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn create_database(path: &Path) {
+        let repository = SqliteSessionRepository::new(path).unwrap();
+        let mut session = Session::start(FocusMode::Review).with_tags(vec!["client".to_string()]);
+        repository.save(&mut session).unwrap();
+        session.end();
+        repository.update(&session).unwrap();
+    }
+
+    #[test]
+    fn backup_and_restore_roundtrip() {
+        let directory = temporary_directory("roundtrip");
+        let database_path = directory.join("sessions.db");
+        create_database(&database_path);
+
+        let manifest = SqliteArchive::create(&database_path, &directory.join("backup")).unwrap();
+        assert_eq!(manifest.schema_version, SCHEMA_VERSION);
+        assert!(manifest
+            .tables
+            .iter()
+            .any(|table| table.name == "sessions" && table.row_count == 1));
+
+        let restored_path = directory.join("restored.db");
+        let compatibility =
+            SqliteArchive::restore(&directory.join("backup"), &restored_path).unwrap();
+        assert_eq!(compatibility, ArchiveCompatibility::Current);
+
+        let restored = SqliteSessionRepository::new(&restored_path).unwrap();
+        assert_eq!(restored.count_completed_sessions().unwrap(), 1);
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn restore_rejects_tampered_archive() {
+        let directory = temporary_directory("tampered");
+        let database_path = directory.join("sessions.db");
+        create_database(&database_path);
+        let backup = directory.join("backup");
+        SqliteArchive::create(&database_path, &backup).unwrap();
+
+        Connection::open(backup.join(ARCHIVE_DATABASE_FILE))
+            .unwrap()
+            .execute("UPDATE sessions SET mode = 'veille'", [])
+            .unwrap();
+
+        let result = SqliteArchive::restore(&backup, &directory.join("restored.db"));
+        assert!(matches!(result, Err(ArchiveError::ChecksumMismatch { .. })));
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn restoring_an_older_schema_runs_migrations() {
+        let directory = temporary_directory("migration");
+        let backup = directory.join("backup");
+        fs::create_dir_all(&backup).unwrap();
+
+        let archived = Connection::open(backup.join(ARCHIVE_DATABASE_FILE)).unwrap();
+        archived
+            .execute_batch(
+                "CREATE TABLE session_metrics (
+                    session_id INTEGER PRIMARY KEY,
+                    context_switch_count INTEGER NOT NULL DEFAULT 0,
+                    total_short_bursts INTEGER NOT NULL DEFAULT 0,
+                    short_bursts_by_app TEXT NOT NULL DEFAULT '{}'
+                );",
+            )
+            .unwrap();
+        let manifest = ArchiveManifest::new(0, "0.2.0", table_checksums(&archived).unwrap());
+        fs::write(
+            backup.join(ARCHIVE_MANIFEST_FILE),
+            manifest.to_toml().unwrap(),
+        )
+        .unwrap();
+        drop(archived);
+
+        let restored_path = directory.join("restored.db");
+        let compatibility = SqliteArchive::restore(&backup, &restored_path).unwrap();
+        assert_eq!(
+            compatibility,
+            ArchiveCompatibility::RequiresMigration {
+                from: 0,
+                to: SCHEMA_VERSION
+            }
+        );
+
+        let restored = Connection::open(&restored_path).unwrap();
+        let has_project_seconds: bool = restored
+            .query_row(
+                "SELECT 1 FROM pragma_table_info('session_metrics') WHERE name='project_seconds'",
+                [],
+                |_| Ok(true),
+            )
+            .unwrap_or(false);
+        assert!(has_project_seconds);
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,174 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use flux_core::{PrivacyConfig, REDACTED_TITLE};
+use rusqlite::Connection;
+use thiserror::Error;
+
+use super::app_tracking_repository::convert_titles;
+use super::title_cipher::is_sealed;
+use super::{migrate, MigrationError, TitleCipher};
+
+/// How long a statement waits for another process (daemon, CLI or GUI) to
+/// release its write lock before failing with SQLITE_BUSY
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Error)]
+pub enum DatabaseError {
+    #[error("cannot open database: {0}")]
+    Open(String),
+
+    #[error(transparent)]
+    Migration(#[from] MigrationError),
+
+    #[error("cannot set up window title encryption: {0}")]
+    TitleEncryption(String),
+}
+
+/// Shared handle on `sessions.db`: every repository of a process goes through
+/// the same connection instead of opening its own. Cloning is cheap.
+///
+/// The database runs in WAL mode so that readers in other processes, such as
+/// `flux stats` or the dashboard, never block the daemon while it writes.
+#[derive(Debug, Clone)]
+pub struct Database {
+    connection: Arc<Mutex<Connection>>,
+    /// Reads encrypted window titles, and encrypts new ones when `encrypt_titles` is set
+    title_cipher: Option<Arc<TitleCipher>>,
+    encrypt_titles: bool,
+}
+
+impl Database {
+    pub fn open(path: &Path) -> Result<Self, DatabaseError> {
+        let connection = Connection::open(path).map_err(open_error)?;
+        connection
+            .pragma_update(None, "journal_mode", "WAL")
+            .map_err(open_error)?;
+        Self::from_connection(connection)
+    }
+
+    pub fn in_memory() -> Result<Self, DatabaseError> {
+        Self::from_connection(Connection::open_in_memory().map_err(open_error)?)
+    }
+
+    pub(crate) fn from_connection(mut connection: Connection) -> Result<Self, DatabaseError> {
+        connection.busy_timeout(BUSY_TIMEOUT).map_err(open_error)?;
+        migrate(&mut connection)?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+            title_cipher: None,
+            encrypt_titles: false,
+        })
+    }
+
+    /// Reads encrypted window titles, and writes new ones encrypted when
+    /// `encrypt_window_titles` is set. The titles already stored are left as
+    /// they are until `convert_stored_titles` runs.
+    pub fn with_privacy(self, privacy: &PrivacyConfig) -> Result<Self, DatabaseError> {
+        match TitleCipher::from_privacy(privacy) {
+            Ok(Some(cipher)) => Ok(self.with_title_cipher(cipher, privacy.encrypt_window_titles)),
+            Ok(None) => Ok(self),
+            Err(error) => Err(DatabaseError::TitleEncryption(error.to_string())),
+        }
+    }
+
+    pub(crate) fn with_title_cipher(mut self, cipher: TitleCipher, encrypt: bool) -> Self {
+        self.title_cipher = Some(Arc::new(cipher));
+        self.encrypt_titles = encrypt;
+        self
+    }
+
+    /// Encrypts the titles already stored, or decrypts them once the option
+    /// is turned off. It rewrites the whole usage history, so only the daemon
+    /// runs it, when it starts.
+    pub fn convert_stored_titles(&self) -> Result<(), DatabaseError> {
+        let Some(cipher) = &self.title_cipher else {
+            return Ok(());
+        };
+        convert_titles(&mut self.connection(), cipher, self.encrypt_titles)
+            .map_err(|error| DatabaseError::TitleEncryption(error.to_string()))
+    }
+
+    pub(crate) fn connection(&self) -> MutexGuard<'_, Connection> {
+        self.connection.lock().unwrap()
+    }
+
+    /// Window title as it is written to the database; one already sealed,
+    /// as received from another device, is kept as it came
+    pub(crate) fn seal_title(&self, title: &str) -> String {
+        match &self.title_cipher {
+            Some(cipher) if self.encrypt_titles && !is_sealed(title) => cipher.seal(title),
+            _ => title.to_string(),
+        }
+    }
+
+    /// Window title as sent to the other devices: still sealed while titles
+    /// are encrypted, so that only a device holding the same key reads it. A
+    /// title this device cannot decrypt is passed on as it came.
+    pub(crate) fn export_title(&self, stored: String) -> String {
+        if self.encrypt_titles {
+            return self.seal_title(&stored);
+        }
+        self.title_cipher
+            .as_ref()
+            .and_then(|cipher| cipher.open(&stored))
+            .unwrap_or(stored)
+    }
+
+    /// Window title as read from the database; one that cannot be decrypted
+    /// reads as redacted
+    pub(crate) fn open_title(&self, stored: String) -> String {
+        if !is_sealed(&stored) {
+            return stored;
+        }
+        self.title_cipher
+            .as_ref()
+            .and_then(|cipher| cipher.open(&stored))
+            .unwrap_or_else(|| REDACTED_TITLE.to_string())
+    }
+}
+
+fn open_error(error: rusqlite::Error) -> DatabaseError {
+    DatabaseError::Open(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::{SqliteSessionNoteRepository, SqliteSessionRepository};
+    use flux_core::{FocusMode, Session, SessionNote, SessionNoteRepository, SessionRepository};
+
+    #[test]
+    fn repositories_built_on_one_database_see_each_other_writes() {
+        let database = Database::in_memory().unwrap();
+        let sessions = SqliteSessionRepository::with_database(database.clone());
+        let notes = SqliteSessionNoteRepository::with_database(database);
+
+        let mut session = Session::start(FocusMode::Review);
+        let session_id = sessions.save(&mut session).unwrap();
+        notes
+            .save(&SessionNote::new(session_id, "shipped the parser").unwrap())
+            .unwrap();
+
+        assert_eq!(notes.find_by_sessions(&[session_id]).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn file_database_uses_write_ahead_logging() {
+        let path = std::env::temp_dir().join(format!("flux-database-{}.db", std::process::id()));
+
+        let database = Database::open(&path).unwrap();
+        let journal_mode: String = database
+            .connection()
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .unwrap();
+        drop(database);
+
+        assert_eq!(journal_mode, "wal");
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+        }
+    }
+}
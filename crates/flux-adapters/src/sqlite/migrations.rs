@@ -0,0 +1,500 @@
+use std::path::Path;
+
+use chrono::Utc;
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension, TransactionBehavior};
+use thiserror::Error;
+
+/// Version of the newest migration, also recorded in backup manifests
+pub const SCHEMA_VERSION: u32 = 4;
+
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    #[error("database schema v{found} is newer than this version of flux supports (v{supported}), update flux")]
+    NewerSchema { found: u32, supported: u32 },
+
+    #[error("migration to schema v{version} failed: {message}")]
+    Failed { version: u32, message: String },
+
+    #[error("storage error: {0}")]
+    Storage(String),
+}
+
+struct Migration {
+    version: u32,
+    description: &'static str,
+    apply: fn(&Connection) -> rusqlite::Result<()>,
+}
+
+/// Ordered from oldest to newest. Never edit a released migration, add a new one.
+///
+/// Databases created before the `schema_version` table existed start at v0 and
+/// go through the baseline, which therefore only creates what is missing. It is
+/// numbered 2 because backups were already tagged with that version.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 2,
+        description: "baseline sessions, app tracking, metrics, notes and achievements",
+        apply: baseline,
+    },
+    Migration {
+        version: 3,
+        description: "session interruption reasons",
+        apply: session_interruptions,
+    },
+    Migration {
+        version: 4,
+        description: "session identifiers and change tracking for sync",
+        apply: session_sync,
+    },
+];
+
+/// Random version 4 UUID, computed by SQLite so that every insert gets one
+pub(crate) const NEW_UUID: &str = "lower(hex(randomblob(4)) || '-' || hex(randomblob(2)) || '-4'
+    || substr(hex(randomblob(2)), 2) || '-' || substr('89ab', 1 + (abs(random()) % 4), 1)
+    || substr(hex(randomblob(2)), 2) || '-' || hex(randomblob(6)))";
+
+/// Same format as `DateTime::to_rfc3339`, with milliseconds
+const NOW: &str = "strftime('%Y-%m-%dT%H:%M:%fZ', 'now')";
+
+/// Brings the database up to [`SCHEMA_VERSION`] and refuses databases written
+/// by a newer flux, so that binaries of different versions never reshape the
+/// schema behind each other's back. The write lock is taken up front: when the
+/// daemon, the CLI and the GUI start together after an upgrade, the first one
+/// migrates and the others wait for it.
+pub(crate) fn migrate(connection: &mut Connection) -> Result<u32, MigrationError> {
+    let transaction = connection
+        .transaction_with_behavior(TransactionBehavior::Immediate)
+        .map_err(storage_error)?;
+    transaction
+        .execute_batch(
+            "CREATE TABLE IF NOT EXISTS schema_version (
+                version INTEGER PRIMARY KEY,
+                description TEXT NOT NULL,
+                applied_at TEXT NOT NULL
+            );",
+        )
+        .map_err(storage_error)?;
+
+    let current = current_version(&transaction)?;
+    if current > SCHEMA_VERSION {
+        return Err(MigrationError::NewerSchema {
+            found: current,
+            supported: SCHEMA_VERSION,
+        });
+    }
+
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.version > current)
+    {
+        (migration.apply)(&transaction).map_err(|error| MigrationError::Failed {
+            version: migration.version,
+            message: error.to_string(),
+        })?;
+        transaction
+            .execute(
+                "INSERT INTO schema_version (version, description, applied_at)
+                 VALUES (?1, ?2, ?3)",
+                params![
+                    migration.version,
+                    migration.description,
+                    Utc::now().to_rfc3339()
+                ],
+            )
+            .map_err(storage_error)?;
+    }
+
+    transaction.commit().map_err(storage_error)?;
+    Ok(SCHEMA_VERSION)
+}
+
+/// Schema version of an existing database, read without migrating it.
+/// Databases that predate versioning report v0.
+pub fn schema_version(database_path: &Path) -> Result<u32, MigrationError> {
+    let connection = Connection::open_with_flags(database_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(storage_error)?;
+    stored_version(&connection)
+}
+
+fn stored_version(connection: &Connection) -> Result<u32, MigrationError> {
+    let versioned: bool = connection
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type='table' AND name='schema_version'",
+            [],
+            |_| Ok(true),
+        )
+        .optional()
+        .map_err(storage_error)?
+        .unwrap_or(false);
+
+    if versioned {
+        current_version(connection)
+    } else {
+        Ok(0)
+    }
+}
+
+fn current_version(connection: &Connection) -> Result<u32, MigrationError> {
+    connection
+        .query_row(
+            "SELECT COALESCE(MAX(version), 0) FROM schema_version",
+            [],
+            |row| row.get(0),
+        )
+        .map_err(storage_error)
+}
+
+fn baseline(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "CREATE TABLE IF NOT EXISTS sessions (
+            id INTEGER PRIMARY KEY,
+            mode TEXT NOT NULL,
+            started_at TEXT NOT NULL,
+            ended_at TEXT,
+            duration_seconds INTEGER,
+            check_in_count INTEGER DEFAULT 0,
+            end_reason TEXT,
+            planned_minutes INTEGER
+        );
+        CREATE TABLE IF NOT EXISTS session_tags (
+            session_id INTEGER NOT NULL,
+            tag TEXT NOT NULL,
+            PRIMARY KEY (session_id, tag)
+        );
+        CREATE INDEX IF NOT EXISTS idx_session_tags_tag ON session_tags(tag);",
+    )?;
+    add_column_if_missing(connection, "sessions", "end_reason", "TEXT")?;
+    add_column_if_missing(connection, "sessions", "planned_minutes", "INTEGER")?;
+
+    if table_exists(connection, "app_tracking")? {
+        add_window_title_to_app_tracking(connection)?;
+    } else {
+        connection.execute_batch(
+            "CREATE TABLE app_tracking (
+                session_id INTEGER NOT NULL,
+                application_name TEXT NOT NULL,
+                window_title TEXT NOT NULL DEFAULT '',
+                duration_seconds INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (session_id, application_name, window_title)
+            );",
+        )?;
+    }
+
+    connection.execute_batch(
+        "CREATE TABLE IF NOT EXISTS app_usage_segments (
+            session_id INTEGER NOT NULL,
+            kind TEXT NOT NULL,
+            application_name TEXT NOT NULL DEFAULT '',
+            window_title TEXT NOT NULL DEFAULT '',
+            started_at TEXT NOT NULL,
+            ended_at TEXT NOT NULL,
+            check_in_outcome TEXT
+        );
+        CREATE INDEX IF NOT EXISTS idx_app_usage_segments_session
+            ON app_usage_segments(session_id);",
+    )?;
+    add_column_if_missing(
+        connection,
+        "app_usage_segments",
+        "window_title",
+        "TEXT NOT NULL DEFAULT ''",
+    )?;
+    add_column_if_missing(connection, "app_usage_segments", "check_in_outcome", "TEXT")?;
+
+    connection.execute_batch(
+        "CREATE VIEW IF NOT EXISTS app_usage_totals AS
+            SELECT session_id, application_name, window_title, duration_seconds
+            FROM app_tracking
+            UNION ALL
+            SELECT session_id, application_name, window_title,
+                CAST(ROUND((julianday(ended_at) - julianday(started_at)) * 86400) AS INTEGER)
+            FROM app_usage_segments
+            WHERE kind IN ('focus', 'distraction');
+
+        CREATE TABLE IF NOT EXISTS app_usage_daily (
+            date TEXT NOT NULL,
+            application_name TEXT NOT NULL,
+            is_distraction INTEGER NOT NULL,
+            seconds INTEGER NOT NULL,
+            PRIMARY KEY (date, application_name, is_distraction)
+        );
+
+        CREATE TABLE IF NOT EXISTS app_usage_daily_refreshes (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            refreshed_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS session_metrics (
+            session_id INTEGER PRIMARY KEY,
+            context_switch_count INTEGER NOT NULL DEFAULT 0,
+            total_short_bursts INTEGER NOT NULL DEFAULT 0,
+            short_bursts_by_app TEXT NOT NULL DEFAULT '{}',
+            project_seconds TEXT NOT NULL DEFAULT '{}',
+            active_input_ratio REAL,
+            tracked_seconds INTEGER NOT NULL DEFAULT 0,
+            distraction_seconds INTEGER NOT NULL DEFAULT 0,
+            pause_count INTEGER NOT NULL DEFAULT 0
+        );
+
+        CREATE TABLE IF NOT EXISTS session_notes (
+            session_id INTEGER PRIMARY KEY,
+            text TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS achievements (
+            achievement TEXT PRIMARY KEY,
+            session_id INTEGER,
+            unlocked_at TEXT NOT NULL
+        );",
+    )?;
+    for (column, definition) in [
+        ("project_seconds", "TEXT NOT NULL DEFAULT '{}'"),
+        ("active_input_ratio", "REAL"),
+        ("tracked_seconds", "INTEGER NOT NULL DEFAULT 0"),
+        ("distraction_seconds", "INTEGER NOT NULL DEFAULT 0"),
+        ("pause_count", "INTEGER NOT NULL DEFAULT 0"),
+    ] {
+        add_column_if_missing(connection, "session_metrics", column, definition)?;
+    }
+
+    Ok(())
+}
+
+fn session_interruptions(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "CREATE TABLE IF NOT EXISTS session_interruptions (
+            id INTEGER PRIMARY KEY,
+            session_id INTEGER NOT NULL,
+            kind TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            occurred_at TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_session_interruptions_session
+            ON session_interruptions(session_id);",
+    )
+}
+
+/// Sessions get a UUID shared across devices and an `updated_at` stamped by
+/// triggers on every change to them, their tags or their note, so that the
+/// repositories need not know about sync. Deleted sessions leave a tombstone.
+fn session_sync(connection: &Connection) -> rusqlite::Result<()> {
+    add_column_if_missing(connection, "sessions", "uuid", "TEXT")?;
+    add_column_if_missing(connection, "sessions", "updated_at", "TEXT")?;
+
+    connection.execute_batch(&format!(
+        "UPDATE sessions SET uuid = {NEW_UUID}, updated_at = COALESCE(ended_at, started_at)
+            WHERE uuid IS NULL;
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_sessions_uuid ON sessions(uuid);
+
+        CREATE TABLE IF NOT EXISTS session_tombstones (
+            uuid TEXT PRIMARY KEY,
+            deleted_at TEXT NOT NULL
+        );
+
+        CREATE TRIGGER IF NOT EXISTS sessions_sync_insert AFTER INSERT ON sessions
+            WHEN NEW.uuid IS NULL
+        BEGIN
+            UPDATE sessions SET uuid = {NEW_UUID}, updated_at = {NOW} WHERE id = NEW.id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS sessions_sync_update AFTER UPDATE ON sessions
+            WHEN NEW.updated_at IS OLD.updated_at
+        BEGIN
+            UPDATE sessions SET updated_at = {NOW} WHERE id = NEW.id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS sessions_sync_delete AFTER DELETE ON sessions
+            WHEN OLD.uuid IS NOT NULL
+        BEGIN
+            INSERT OR REPLACE INTO session_tombstones (uuid, deleted_at) VALUES (OLD.uuid, {NOW});
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS session_tags_sync_insert AFTER INSERT ON session_tags
+        BEGIN
+            UPDATE sessions SET updated_at = {NOW} WHERE id = NEW.session_id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS session_tags_sync_delete AFTER DELETE ON session_tags
+        BEGIN
+            UPDATE sessions SET updated_at = {NOW} WHERE id = OLD.session_id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS session_notes_sync_insert AFTER INSERT ON session_notes
+        BEGIN
+            UPDATE sessions SET updated_at = {NOW} WHERE id = NEW.session_id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS session_notes_sync_update AFTER UPDATE ON session_notes
+        BEGIN
+            UPDATE sessions SET updated_at = {NOW} WHERE id = NEW.session_id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS session_notes_sync_delete AFTER DELETE ON session_notes
+        BEGIN
+            UPDATE sessions SET updated_at = {NOW} WHERE id = OLD.session_id;
+        END;"
+    ))
+}
+
+/// The first releases keyed app tracking by application only
+fn add_window_title_to_app_tracking(connection: &Connection) -> rusqlite::Result<()> {
+    if column_exists(connection, "app_tracking", "window_title")? {
+        return Ok(());
+    }
+
+    connection.execute_batch(
+        "ALTER TABLE app_tracking RENAME TO app_tracking_old;
+
+        CREATE TABLE app_tracking (
+            session_id INTEGER NOT NULL,
+            application_name TEXT NOT NULL,
+            window_title TEXT NOT NULL DEFAULT '',
+            duration_seconds INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (session_id, application_name, window_title)
+        );
+
+        INSERT INTO app_tracking (session_id, application_name, window_title, duration_seconds)
+        SELECT session_id, application_name, '', duration_seconds
+        FROM app_tracking_old;
+
+        DROP TABLE app_tracking_old;",
+    )
+}
+
+fn add_column_if_missing(
+    connection: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> rusqlite::Result<()> {
+    if column_exists(connection, table, column)? {
+        return Ok(());
+    }
+
+    connection.execute_batch(&format!(
+        "ALTER TABLE {} ADD COLUMN {} {};",
+        table, column, definition
+    ))
+}
+
+fn table_exists(connection: &Connection, table: &str) -> rusqlite::Result<bool> {
+    connection
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type='table' AND name=?1",
+            params![table],
+            |_| Ok(true),
+        )
+        .optional()
+        .map(|found| found.unwrap_or(false))
+}
+
+fn column_exists(connection: &Connection, table: &str, column: &str) -> rusqlite::Result<bool> {
+    connection
+        .query_row(
+            "SELECT 1 FROM pragma_table_info(?1) WHERE name=?2",
+            params![table, column],
+            |_| Ok(true),
+        )
+        .optional()
+        .map(|found| found.unwrap_or(false))
+}
+
+fn storage_error(error: impl std::fmt::Display) -> MigrationError {
+    MigrationError::Storage(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_database_reaches_the_latest_version() {
+        let mut connection = Connection::open_in_memory().unwrap();
+
+        assert_eq!(stored_version(&connection).unwrap(), 0);
+        assert_eq!(migrate(&mut connection).unwrap(), SCHEMA_VERSION);
+        assert_eq!(stored_version(&connection).unwrap(), SCHEMA_VERSION);
+        assert!(table_exists(&connection, "session_interruptions").unwrap());
+        assert!(table_exists(&connection, "session_tombstones").unwrap());
+    }
+
+    #[test]
+    fn migrating_twice_applies_each_migration_once() {
+        let mut connection = Connection::open_in_memory().unwrap();
+
+        migrate(&mut connection).unwrap();
+        migrate(&mut connection).unwrap();
+
+        let applied: u32 = connection
+            .query_row("SELECT COUNT(*) FROM schema_version", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(applied as usize, MIGRATIONS.len());
+    }
+
+    #[test]
+    fn unversioned_database_keeps_its_rows_and_gains_missing_columns() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE sessions (
+                    id INTEGER PRIMARY KEY,
+                    mode TEXT NOT NULL,
+                    started_at TEXT NOT NULL,
+                    ended_at TEXT,
+                    duration_seconds INTEGER,
+                    check_in_count INTEGER DEFAULT 0
+                );
+                INSERT INTO sessions (mode, started_at) VALUES ('review', '2025-01-06T09:00:00Z');
+                CREATE TABLE app_tracking (
+                    session_id INTEGER NOT NULL,
+                    application_name TEXT NOT NULL,
+                    duration_seconds INTEGER NOT NULL DEFAULT 0,
+                    PRIMARY KEY (session_id, application_name)
+                );
+                INSERT INTO app_tracking VALUES (1, 'firefox', 120);",
+            )
+            .unwrap();
+
+        migrate(&mut connection).unwrap();
+
+        assert!(column_exists(&connection, "sessions", "planned_minutes").unwrap());
+        let window_title: String = connection
+            .query_row(
+                "SELECT window_title FROM app_tracking WHERE application_name = 'firefox'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(window_title, "");
+        let (sessions, uuid): (u32, String) = connection
+            .query_row("SELECT COUNT(*), MAX(uuid) FROM sessions", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(sessions, 1);
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4");
+    }
+
+    #[test]
+    fn newer_schema_is_refused() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        migrate(&mut connection).unwrap();
+        connection
+            .execute(
+                "INSERT INTO schema_version (version, description, applied_at)
+                 VALUES (?1, 'from the future', '2030-01-01T00:00:00Z')",
+                params![SCHEMA_VERSION + 1],
+            )
+            .unwrap();
+
+        let result = migrate(&mut connection);
+
+        assert!(matches!(
+            result,
+            Err(MigrationError::NewerSchema { found, supported })
+                if found == SCHEMA_VERSION + 1 && supported == SCHEMA_VERSION
+        ));
+    }
+}
@@ -1,7 +1,23 @@
+mod achievement_repository;
 mod app_tracking_repository;
+mod archive;
+mod database;
+mod migrations;
 mod session_metrics_repository;
+mod session_note_repository;
 mod session_repository;
+mod sync_repository;
+mod title_cipher;
 
+pub use achievement_repository::SqliteAchievementRepository;
 pub use app_tracking_repository::SqliteAppTrackingRepository;
+pub use archive::SqliteArchive;
+pub use database::{Database, DatabaseError};
+pub use migrations::{schema_version, MigrationError, SCHEMA_VERSION};
 pub use session_metrics_repository::SqliteSessionMetricsRepository;
+pub use session_note_repository::SqliteSessionNoteRepository;
 pub use session_repository::SqliteSessionRepository;
+pub use sync_repository::SqliteSyncRepository;
+pub use title_cipher::{TitleCipher, TitleCipherError};
+
+use migrations::migrate;
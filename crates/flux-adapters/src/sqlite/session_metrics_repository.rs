@@ -1,74 +1,68 @@
 use std::collections::HashMap;
 use std::path::Path;
-use std::sync::Mutex;
 
-use rusqlite::{params, Connection, OptionalExtension};
+use rusqlite::{params, OptionalExtension, Transaction};
 
 use flux_core::{
     SessionId, SessionMetrics, SessionMetricsRepository, SessionMetricsRepositoryError,
 };
 
+use super::title_cipher::is_sealed;
+use super::{Database, TitleCipher};
+
 pub struct SqliteSessionMetricsRepository {
-    connection: Mutex<Connection>,
+    database: Database,
 }
 
 impl SqliteSessionMetricsRepository {
     pub fn new(path: &Path) -> Result<Self, SessionMetricsRepositoryError> {
-        let connection = Connection::open(path)
+        let database = Database::open(path)
             .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))?;
-
-        let repository = Self {
-            connection: Mutex::new(connection),
-        };
-        repository.initialize_schema()?;
-
-        Ok(repository)
+        Ok(Self::with_database(database))
     }
 
     pub fn in_memory() -> Result<Self, SessionMetricsRepositoryError> {
-        let connection = Connection::open_in_memory()
+        let database = Database::in_memory()
             .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))?;
-
-        let repository = Self {
-            connection: Mutex::new(connection),
-        };
-        repository.initialize_schema()?;
-
-        Ok(repository)
+        Ok(Self::with_database(database))
     }
 
-    fn initialize_schema(&self) -> Result<(), SessionMetricsRepositoryError> {
-        let connection = self.connection.lock().unwrap();
-        connection
-            .execute_batch(
-                "CREATE TABLE IF NOT EXISTS session_metrics (
-                    session_id INTEGER PRIMARY KEY,
-                    context_switch_count INTEGER NOT NULL DEFAULT 0,
-                    total_short_bursts INTEGER NOT NULL DEFAULT 0,
-                    short_bursts_by_app TEXT NOT NULL DEFAULT '{}'
-                );",
-            )
-            .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))
+    pub fn with_database(database: Database) -> Self {
+        Self { database }
     }
 }
 
 impl SessionMetricsRepository for SqliteSessionMetricsRepository {
     fn save(&self, metrics: &SessionMetrics) -> Result<(), SessionMetricsRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let short_bursts_json = serde_json::to_string(&metrics.short_bursts_by_app)
             .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))?;
+        // Project names are read from window titles, so they are stored the
+        // same way
+        let project_seconds: HashMap<String, i64> = metrics
+            .project_seconds
+            .iter()
+            .map(|(project, seconds)| (self.database.seal_title(project), *seconds))
+            .collect();
+        let project_seconds_json = serde_json::to_string(&project_seconds)
+            .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))?;
 
         connection
             .execute(
                 "INSERT OR REPLACE INTO session_metrics
-                 (session_id, context_switch_count, total_short_bursts, short_bursts_by_app)
-                 VALUES (?1, ?2, ?3, ?4)",
+                 (session_id, context_switch_count, total_short_bursts, short_bursts_by_app, project_seconds, active_input_ratio, tracked_seconds, distraction_seconds, pause_count)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
                 params![
                     metrics.session_id,
                     metrics.context_switch_count,
                     metrics.total_short_bursts,
-                    short_bursts_json
+                    short_bursts_json,
+                    project_seconds_json,
+                    metrics.active_input_ratio,
+                    metrics.tracked_seconds,
+                    metrics.distraction_seconds,
+                    metrics.pause_count
                 ],
             )
             .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))?;
@@ -80,18 +74,20 @@ impl SessionMetricsRepository for SqliteSessionMetricsRepository {
         &self,
         session_id: SessionId,
     ) -> Result<Option<SessionMetrics>, SessionMetricsRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let mut statement = connection
             .prepare(
-                "SELECT session_id, context_switch_count, total_short_bursts, short_bursts_by_app
+                "SELECT session_id, context_switch_count, total_short_bursts, short_bursts_by_app, project_seconds, active_input_ratio, tracked_seconds, distraction_seconds, pause_count
                  FROM session_metrics
                  WHERE session_id = ?1",
             )
             .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))?;
 
         let result = statement
-            .query_row(params![session_id], |row| Ok(row_to_session_metrics(row)))
+            .query_row(params![session_id], |row| {
+                Ok(row_to_session_metrics(row, &self.database))
+            })
             .optional()
             .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))?;
 
@@ -106,7 +102,7 @@ impl SessionMetricsRepository for SqliteSessionMetricsRepository {
             return Ok(Vec::new());
         }
 
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let placeholders: String = session_ids
             .iter()
@@ -114,7 +110,7 @@ impl SessionMetricsRepository for SqliteSessionMetricsRepository {
             .collect::<Vec<_>>()
             .join(",");
         let query = format!(
-            "SELECT session_id, context_switch_count, total_short_bursts, short_bursts_by_app
+            "SELECT session_id, context_switch_count, total_short_bursts, short_bursts_by_app, project_seconds, active_input_ratio, tracked_seconds, distraction_seconds, pause_count
              FROM session_metrics
              WHERE session_id IN ({})",
             placeholders
@@ -126,7 +122,7 @@ impl SessionMetricsRepository for SqliteSessionMetricsRepository {
 
         let metrics = statement
             .query_map(rusqlite::params_from_iter(session_ids.iter()), |row| {
-                Ok(row_to_session_metrics(row))
+                Ok(row_to_session_metrics(row, &self.database))
             })
             .map_err(|error| SessionMetricsRepositoryError::Persistence(error.to_string()))?
             .collect::<Result<Vec<_>, _>>()
@@ -139,7 +135,7 @@ impl SessionMetricsRepository for SqliteSessionMetricsRepository {
         &self,
         session_id: SessionId,
     ) -> Result<(), SessionMetricsRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         connection
             .execute(
@@ -152,21 +148,84 @@ impl SessionMetricsRepository for SqliteSessionMetricsRepository {
     }
 }
 
-fn row_to_session_metrics(row: &rusqlite::Row) -> SessionMetrics {
+fn row_to_session_metrics(row: &rusqlite::Row, database: &Database) -> SessionMetrics {
     let session_id: i64 = row.get(0).unwrap();
     let context_switch_count: u32 = row.get(1).unwrap();
     let _total_short_bursts: u32 = row.get(2).unwrap();
     let short_bursts_json: String = row.get(3).unwrap();
 
+    let project_seconds_json: String = row.get(4).unwrap();
+    let active_input_ratio: Option<f64> = row.get(5).unwrap();
+    let tracked_seconds: i64 = row.get(6).unwrap();
+    let distraction_seconds: i64 = row.get(7).unwrap();
+    let pause_count: u32 = row.get(8).unwrap();
+
     let short_bursts_by_app: HashMap<String, u32> =
         serde_json::from_str(&short_bursts_json).unwrap_or_default();
+    let stored_projects: HashMap<String, i64> =
+        serde_json::from_str(&project_seconds_json).unwrap_or_default();
+    // Projects that cannot be decrypted all read as redacted
+    let mut project_seconds: HashMap<String, i64> = HashMap::new();
+    for (stored, seconds) in stored_projects {
+        *project_seconds
+            .entry(database.open_title(stored))
+            .or_insert(0) += seconds;
+    }
 
     SessionMetrics::new(session_id, context_switch_count, short_bursts_by_app)
+        .with_projects(project_seconds)
+        .with_active_input_ratio(active_input_ratio)
+        .with_distraction_time(tracked_seconds, distraction_seconds)
+        .with_pause_count(pause_count)
+}
+
+/// Rewrites the stored project names encrypted, or decrypted when `encrypt`
+/// is off, along with the window titles they come from
+pub(super) fn convert_projects(
+    transaction: &Transaction,
+    cipher: &TitleCipher,
+    encrypt: bool,
+) -> rusqlite::Result<()> {
+    let rows = transaction
+        .prepare(
+            "SELECT session_id, project_seconds FROM session_metrics WHERE project_seconds != '{}'",
+        )?
+        .query_map([], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (session_id, json) in rows {
+        let Ok(stored) = serde_json::from_str::<HashMap<String, i64>>(&json) else {
+            continue;
+        };
+        let mut converted: HashMap<String, i64> = HashMap::new();
+        for (project, seconds) in &stored {
+            let project = match (encrypt, is_sealed(project)) {
+                (true, false) => cipher.seal(project),
+                (false, true) => cipher.open(project).unwrap_or_else(|| project.clone()),
+                _ => project.clone(),
+            };
+            *converted.entry(project).or_insert(0) += seconds;
+        }
+        if converted != stored {
+            transaction.execute(
+                "UPDATE session_metrics SET project_seconds = ?1 WHERE session_id = ?2",
+                params![
+                    serde_json::to_string(&converted).unwrap_or_else(|_| json.clone()),
+                    session_id
+                ],
+            )?;
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rusqlite::Connection;
 
     #[test]
     fn save_and_retrieve_metrics() {
@@ -185,6 +244,111 @@ mod tests {
         assert_eq!(loaded.short_bursts_by_app.get("discord"), Some(&5));
     }
 
+    #[test]
+    fn project_seconds_roundtrip() {
+        let repository = SqliteSessionMetricsRepository::in_memory().unwrap();
+
+        let metrics = SessionMetrics::new(3, 0, HashMap::new())
+            .with_projects(HashMap::from([("flux".to_string(), 1200)]));
+        repository.save(&metrics).unwrap();
+
+        let loaded = repository.find_by_session(3).unwrap().unwrap();
+        assert_eq!(loaded.project_seconds.get("flux"), Some(&1200));
+    }
+
+    #[test]
+    fn project_names_are_stored_like_window_titles() {
+        let key = TitleCipher::generate_key().unwrap();
+        let plain = Database::in_memory().unwrap();
+        let stored = |database: &Database| -> String {
+            database
+                .connection()
+                .query_row("SELECT project_seconds FROM session_metrics", [], |row| {
+                    row.get(0)
+                })
+                .unwrap()
+        };
+        SqliteSessionMetricsRepository::with_database(plain.clone())
+            .save(
+                &SessionMetrics::new(3, 0, HashMap::new())
+                    .with_projects(HashMap::from([("acme-payroll".to_string(), 1200)])),
+            )
+            .unwrap();
+
+        let encrypted = plain
+            .clone()
+            .with_title_cipher(TitleCipher::new(&key).unwrap(), true);
+        encrypted.convert_stored_titles().unwrap();
+        let repository = SqliteSessionMetricsRepository::with_database(encrypted);
+
+        assert!(!stored(&plain).contains("acme"));
+        let loaded = repository.find_by_session(3).unwrap().unwrap();
+        assert_eq!(loaded.project_seconds.get("acme-payroll"), Some(&1200));
+        assert_eq!(
+            SqliteSessionMetricsRepository::with_database(plain.clone())
+                .find_by_session(3)
+                .unwrap()
+                .unwrap()
+                .project_seconds
+                .get(flux_core::REDACTED_TITLE),
+            Some(&1200)
+        );
+
+        let decrypted = plain.with_title_cipher(TitleCipher::new(&key).unwrap(), false);
+        decrypted.convert_stored_titles().unwrap();
+        assert!(stored(&decrypted).contains("acme-payroll"));
+    }
+
+    #[test]
+    fn active_input_ratio_roundtrip() {
+        let repository = SqliteSessionMetricsRepository::in_memory().unwrap();
+
+        let metrics = SessionMetrics::new(4, 0, HashMap::new()).with_active_input_ratio(Some(0.75));
+        repository.save(&metrics).unwrap();
+
+        let loaded = repository.find_by_session(4).unwrap().unwrap();
+        assert_eq!(loaded.active_input_ratio, Some(0.75));
+    }
+
+    #[test]
+    fn distraction_time_and_pauses_roundtrip() {
+        let repository = SqliteSessionMetricsRepository::in_memory().unwrap();
+
+        let metrics = SessionMetrics::new(5, 3, HashMap::new())
+            .with_distraction_time(1500, 300)
+            .with_pause_count(2);
+        repository.save(&metrics).unwrap();
+
+        let loaded = repository.find_by_session(5).unwrap().unwrap();
+        assert_eq!(loaded.tracked_seconds, 1500);
+        assert_eq!(loaded.distraction_seconds, 300);
+        assert_eq!(loaded.pause_count, 2);
+    }
+
+    #[test]
+    fn opening_adds_project_column_to_existing_table() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE session_metrics (
+                    session_id INTEGER PRIMARY KEY,
+                    context_switch_count INTEGER NOT NULL DEFAULT 0,
+                    total_short_bursts INTEGER NOT NULL DEFAULT 0,
+                    short_bursts_by_app TEXT NOT NULL DEFAULT '{}'
+                );
+                INSERT INTO session_metrics VALUES (1, 4, 0, '{}');",
+            )
+            .unwrap();
+        let repository = SqliteSessionMetricsRepository::with_database(
+            Database::from_connection(connection).unwrap(),
+        );
+
+        let loaded = repository.find_by_session(1).unwrap().unwrap();
+        assert_eq!(loaded.context_switch_count, 4);
+        assert!(loaded.project_seconds.is_empty());
+        assert!(loaded.active_input_ratio.is_none());
+    }
+
     #[test]
     fn find_by_session_returns_none_when_not_found() {
         let repository = SqliteSessionMetricsRepository::in_memory().unwrap();
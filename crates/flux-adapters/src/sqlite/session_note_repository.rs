@@ -0,0 +1,168 @@
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+
+use flux_core::{SessionId, SessionNote, SessionNoteRepository, SessionNoteRepositoryError};
+
+use super::Database;
+
+pub struct SqliteSessionNoteRepository {
+    database: Database,
+}
+
+impl SqliteSessionNoteRepository {
+    pub fn new(path: &Path) -> Result<Self, SessionNoteRepositoryError> {
+        let database = Database::open(path)
+            .map_err(|error| SessionNoteRepositoryError::Persistence(error.to_string()))?;
+        Ok(Self::with_database(database))
+    }
+
+    pub fn in_memory() -> Result<Self, SessionNoteRepositoryError> {
+        let database = Database::in_memory()
+            .map_err(|error| SessionNoteRepositoryError::Persistence(error.to_string()))?;
+        Ok(Self::with_database(database))
+    }
+
+    pub fn with_database(database: Database) -> Self {
+        Self { database }
+    }
+}
+
+impl SessionNoteRepository for SqliteSessionNoteRepository {
+    fn save(&self, note: &SessionNote) -> Result<(), SessionNoteRepositoryError> {
+        let connection = self.database.connection();
+
+        connection
+            .execute(
+                "INSERT OR REPLACE INTO session_notes (session_id, text, created_at)
+                 VALUES (?1, ?2, ?3)",
+                params![note.session_id, note.text, note.created_at.to_rfc3339()],
+            )
+            .map_err(|error| SessionNoteRepositoryError::Persistence(error.to_string()))?;
+
+        Ok(())
+    }
+
+    fn delete(&self, session_id: SessionId) -> Result<(), SessionNoteRepositoryError> {
+        let connection = self.database.connection();
+
+        connection
+            .execute(
+                "DELETE FROM session_notes WHERE session_id = ?1",
+                params![session_id],
+            )
+            .map_err(|error| SessionNoteRepositoryError::Persistence(error.to_string()))?;
+
+        Ok(())
+    }
+
+    fn find_by_sessions(
+        &self,
+        session_ids: &[SessionId],
+    ) -> Result<Vec<SessionNote>, SessionNoteRepositoryError> {
+        if session_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let connection = self.database.connection();
+
+        let placeholders: String = session_ids
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = format!(
+            "SELECT session_id, text, created_at
+             FROM session_notes
+             WHERE session_id IN ({})",
+            placeholders
+        );
+
+        let mut statement = connection
+            .prepare(&query)
+            .map_err(|error| SessionNoteRepositoryError::Persistence(error.to_string()))?;
+
+        let rows = statement
+            .query_map(rusqlite::params_from_iter(session_ids.iter()), |row| {
+                Ok((
+                    row.get::<_, SessionId>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })
+            .map_err(|error| SessionNoteRepositoryError::Persistence(error.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| SessionNoteRepositoryError::Persistence(error.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(session_id, text, created_at)| {
+                let created_at = DateTime::parse_from_rfc3339(&created_at)
+                    .ok()?
+                    .with_timezone(&Utc);
+                Some(SessionNote {
+                    session_id,
+                    text,
+                    created_at,
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_new_note_replaces_the_previous_one() {
+        let repository = SqliteSessionNoteRepository::in_memory().unwrap();
+
+        repository
+            .save(&SessionNote::new(4, "drafted the plan").unwrap())
+            .unwrap();
+        repository
+            .save(&SessionNote::new(4, "shipped the plan").unwrap())
+            .unwrap();
+
+        let notes = repository.find_by_sessions(&[4]).unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].text, "shipped the plan");
+    }
+
+    #[test]
+    fn deleting_a_note_leaves_the_other_sessions_alone() {
+        let repository = SqliteSessionNoteRepository::in_memory().unwrap();
+        repository
+            .save(&SessionNote::new(1, "first").unwrap())
+            .unwrap();
+        repository
+            .save(&SessionNote::new(2, "second").unwrap())
+            .unwrap();
+
+        repository.delete(1).unwrap();
+        repository.delete(7).unwrap();
+
+        let notes = repository.find_by_sessions(&[1, 2]).unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].session_id, 2);
+    }
+
+    #[test]
+    fn find_by_sessions_only_returns_requested_sessions() {
+        let repository = SqliteSessionNoteRepository::in_memory().unwrap();
+        repository
+            .save(&SessionNote::new(1, "first").unwrap())
+            .unwrap();
+        repository
+            .save(&SessionNote::new(2, "second").unwrap())
+            .unwrap();
+
+        let notes = repository.find_by_sessions(&[2, 3]).unwrap();
+
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].session_id, 2);
+        assert!(repository.find_by_sessions(&[]).unwrap().is_empty());
+    }
+}
@@ -1,86 +1,77 @@
+use std::collections::HashMap;
 use std::path::Path;
-use std::sync::Mutex;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rusqlite::{params, Connection};
 
-use flux_core::{FocusMode, Session, SessionId, SessionRepository, SessionRepositoryError};
+use flux_core::{
+    FocusMode, InterruptionReasonCount, Session, SessionEndReason, SessionId, SessionInterruption,
+    SessionRepository, SessionRepositoryError, TagUsage, WeekStart, WeeklyModeTotal,
+};
+
+use super::Database;
 
 pub struct SqliteSessionRepository {
-    connection: Mutex<Connection>,
+    database: Database,
 }
 
 impl SqliteSessionRepository {
     pub fn new(path: &Path) -> Result<Self, SessionRepositoryError> {
-        let connection =
-            Connection::open(path).map_err(|error| SessionRepositoryError::Storage {
-                message: error.to_string(),
-            })?;
-
-        let repository = Self {
-            connection: Mutex::new(connection),
-        };
-        repository.initialize_schema()?;
-
-        Ok(repository)
+        let database = Database::open(path).map_err(|error| SessionRepositoryError::Storage {
+            message: error.to_string(),
+        })?;
+        Ok(Self::with_database(database))
     }
 
     pub fn in_memory() -> Result<Self, SessionRepositoryError> {
-        let connection =
-            Connection::open_in_memory().map_err(|error| SessionRepositoryError::Storage {
-                message: error.to_string(),
-            })?;
-
-        let repository = Self {
-            connection: Mutex::new(connection),
-        };
-        repository.initialize_schema()?;
-
-        Ok(repository)
+        let database = Database::in_memory().map_err(|error| SessionRepositoryError::Storage {
+            message: error.to_string(),
+        })?;
+        Ok(Self::with_database(database))
     }
 
-    fn initialize_schema(&self) -> Result<(), SessionRepositoryError> {
-        let connection = self.connection.lock().unwrap();
-        connection
-            .execute_batch(
-                "CREATE TABLE IF NOT EXISTS sessions (
-                    id INTEGER PRIMARY KEY,
-                    mode TEXT NOT NULL,
-                    started_at TEXT NOT NULL,
-                    ended_at TEXT,
-                    duration_seconds INTEGER,
-                    check_in_count INTEGER DEFAULT 0
-                );",
-            )
-            .map_err(|error| SessionRepositoryError::Storage {
-                message: error.to_string(),
-            })
+    pub fn with_database(database: Database) -> Self {
+        Self { database }
     }
 }
 
 impl SessionRepository for SqliteSessionRepository {
     fn save(&self, session: &mut Session) -> Result<SessionId, SessionRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let mut connection = self.database.connection();
+        let transaction =
+            connection
+                .transaction()
+                .map_err(|error| SessionRepositoryError::Storage {
+                    message: error.to_string(),
+                })?;
 
-        connection
+        transaction
             .execute(
-                "INSERT INTO sessions (mode, started_at, ended_at, duration_seconds, check_in_count)
-                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                "INSERT INTO sessions (mode, started_at, ended_at, duration_seconds, check_in_count, end_reason, planned_minutes)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
                 params![
                     session.mode.as_str(),
                     session.started_at.to_rfc3339(),
                     session.ended_at.map(|dt| dt.to_rfc3339()),
                     session.duration_seconds,
                     session.check_in_count,
+                    session.end_reason.map(|reason| reason.as_str()),
+                    session.planned_minutes.map(|minutes| minutes as i64),
                 ],
             )
             .map_err(|error| SessionRepositoryError::Storage {
                 message: error.to_string(),
             })?;
 
-        let id = connection.last_insert_rowid();
-        session.id = Some(id);
+        let id = transaction.last_insert_rowid();
+        replace_tags(&transaction, id, &session.tags)?;
+        transaction
+            .commit()
+            .map_err(|error| SessionRepositoryError::Storage {
+                message: error.to_string(),
+            })?;
 
+        session.id = Some(id);
         Ok(id)
     }
 
@@ -89,16 +80,23 @@ impl SessionRepository for SqliteSessionRepository {
             message: "cannot update session without id".to_string(),
         })?;
 
-        let connection = self.connection.lock().unwrap();
+        let mut connection = self.database.connection();
+        let transaction =
+            connection
+                .transaction()
+                .map_err(|error| SessionRepositoryError::Storage {
+                    message: error.to_string(),
+                })?;
 
-        let rows_affected = connection
+        let rows_affected = transaction
             .execute(
-                "UPDATE sessions SET ended_at = ?1, duration_seconds = ?2, check_in_count = ?3
-                 WHERE id = ?4",
+                "UPDATE sessions SET ended_at = ?1, duration_seconds = ?2, check_in_count = ?3, end_reason = ?4
+                 WHERE id = ?5",
                 params![
                     session.ended_at.map(|dt| dt.to_rfc3339()),
                     session.duration_seconds,
                     session.check_in_count,
+                    session.end_reason.map(|reason| reason.as_str()),
                     id,
                 ],
             )
@@ -110,15 +108,20 @@ impl SessionRepository for SqliteSessionRepository {
             return Err(SessionRepositoryError::NotFound { id });
         }
 
-        Ok(())
+        replace_tags(&transaction, id, &session.tags)?;
+        transaction
+            .commit()
+            .map_err(|error| SessionRepositoryError::Storage {
+                message: error.to_string(),
+            })
     }
 
     fn find_by_id(&self, id: SessionId) -> Result<Session, SessionRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
-        connection
+        let mut session = connection
             .query_row(
-                "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count
+                "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count, end_reason, planned_minutes
                  FROM sessions WHERE id = ?1",
                 params![id],
                 |row| Ok(row_to_session(row)),
@@ -128,21 +131,49 @@ impl SessionRepository for SqliteSessionRepository {
                 _ => SessionRepositoryError::Storage {
                     message: error.to_string(),
                 },
-            })
+            })?;
+
+        attach_tags(&connection, std::slice::from_mut(&mut session))?;
+        Ok(session)
     }
 
     fn find_active(&self) -> Result<Option<Session>, SessionRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let result = connection.query_row(
-            "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count
+            "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count, end_reason, planned_minutes
              FROM sessions WHERE ended_at IS NULL ORDER BY started_at DESC LIMIT 1",
             [],
             |row| Ok(row_to_session(row)),
         );
 
         match result {
-            Ok(session) => Ok(Some(session)),
+            Ok(mut session) => {
+                attach_tags(&connection, std::slice::from_mut(&mut session))?;
+                Ok(Some(session))
+            }
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(error) => Err(SessionRepositoryError::Storage {
+                message: error.to_string(),
+            }),
+        }
+    }
+
+    fn find_latest(&self) -> Result<Option<Session>, SessionRepositoryError> {
+        let connection = self.database.connection();
+
+        let result = connection.query_row(
+            "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count, end_reason, planned_minutes
+             FROM sessions ORDER BY started_at DESC LIMIT 1",
+            [],
+            |row| Ok(row_to_session(row)),
+        );
+
+        match result {
+            Ok(mut session) => {
+                attach_tags(&connection, std::slice::from_mut(&mut session))?;
+                Ok(Some(session))
+            }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(error) => Err(SessionRepositoryError::Storage {
                 message: error.to_string(),
@@ -154,11 +185,11 @@ impl SessionRepository for SqliteSessionRepository {
         &self,
         since: DateTime<Utc>,
     ) -> Result<Vec<Session>, SessionRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let mut statement = connection
             .prepare(
-                "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count
+                "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count, end_reason, planned_minutes
                  FROM sessions
                  WHERE ended_at IS NOT NULL AND started_at >= ?1
                  ORDER BY started_at DESC",
@@ -167,7 +198,7 @@ impl SessionRepository for SqliteSessionRepository {
                 message: error.to_string(),
             })?;
 
-        let sessions = statement
+        let mut sessions = statement
             .query_map(params![since.to_rfc3339()], |row| Ok(row_to_session(row)))
             .map_err(|error| SessionRepositoryError::Storage {
                 message: error.to_string(),
@@ -177,6 +208,7 @@ impl SessionRepository for SqliteSessionRepository {
                 message: error.to_string(),
             })?;
 
+        attach_tags(&connection, &mut sessions)?;
         Ok(sessions)
     }
 
@@ -185,11 +217,11 @@ impl SessionRepository for SqliteSessionRepository {
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<Vec<Session>, SessionRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let mut statement = connection
             .prepare(
-                "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count
+                "SELECT id, mode, started_at, ended_at, duration_seconds, check_in_count, end_reason, planned_minutes
                  FROM sessions
                  WHERE ended_at IS NOT NULL AND started_at >= ?1 AND started_at < ?2
                  ORDER BY started_at DESC",
@@ -198,7 +230,7 @@ impl SessionRepository for SqliteSessionRepository {
                 message: error.to_string(),
             })?;
 
-        let sessions = statement
+        let mut sessions = statement
             .query_map(params![start.to_rfc3339(), end.to_rfc3339()], |row| {
                 Ok(row_to_session(row))
             })
@@ -210,11 +242,164 @@ impl SessionRepository for SqliteSessionRepository {
                 message: error.to_string(),
             })?;
 
+        attach_tags(&connection, &mut sessions)?;
         Ok(sessions)
     }
 
+    fn weekly_mode_totals(
+        &self,
+        since: DateTime<Utc>,
+        week_start: WeekStart,
+    ) -> Result<Vec<WeeklyModeTotal>, SessionRepositoryError> {
+        let connection = self.database.connection();
+
+        let mut statement = connection
+            .prepare(
+                "SELECT date(started_at, 'localtime', '+1 day', 'weekday ' || ?2, '-7 days') AS week_start,
+                        mode, SUM(COALESCE(duration_seconds, 0))
+                 FROM sessions
+                 WHERE ended_at IS NOT NULL AND started_at >= ?1
+                 GROUP BY week_start, mode
+                 ORDER BY week_start, mode",
+            )
+            .map_err(|error| SessionRepositoryError::Storage {
+                message: error.to_string(),
+            })?;
+
+        let rows = statement
+            .query_map(
+                params![
+                    since.to_rfc3339(),
+                    week_start.weekday().num_days_from_sunday()
+                ],
+                |row| {
+                    let week_start: String = row.get(0)?;
+                    let mode: String = row.get(1)?;
+                    let seconds: i64 = row.get(2)?;
+                    Ok((week_start, mode, seconds))
+                },
+            )
+            .map_err(|error| SessionRepositoryError::Storage {
+                message: error.to_string(),
+            })?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| SessionRepositoryError::Storage {
+                message: error.to_string(),
+            })?;
+
+        rows.into_iter()
+            .map(|(week_start, mode, seconds)| {
+                let week_start =
+                    NaiveDate::parse_from_str(&week_start, "%Y-%m-%d").map_err(|error| {
+                        SessionRepositoryError::Storage {
+                            message: error.to_string(),
+                        }
+                    })?;
+                Ok(WeeklyModeTotal {
+                    week_start,
+                    mode: FocusMode::from_stored(&mode),
+                    seconds,
+                })
+            })
+            .collect()
+    }
+
+    fn tag_usages(&self) -> Result<Vec<TagUsage>, SessionRepositoryError> {
+        let connection = self.database.connection();
+        let storage_error = |error: rusqlite::Error| SessionRepositoryError::Storage {
+            message: error.to_string(),
+        };
+
+        let mut statement = connection
+            .prepare(
+                "SELECT t.tag, COUNT(*), COALESCE(SUM(s.duration_seconds), 0) AS total
+                 FROM session_tags t
+                 JOIN sessions s ON s.id = t.session_id
+                 WHERE s.ended_at IS NOT NULL
+                 GROUP BY t.tag
+                 ORDER BY total DESC, t.tag",
+            )
+            .map_err(storage_error)?;
+
+        let usages = statement
+            .query_map([], |row| {
+                Ok(TagUsage {
+                    tag: row.get(0)?,
+                    session_count: row.get(1)?,
+                    total_seconds: row.get(2)?,
+                })
+            })
+            .map_err(storage_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+
+        Ok(usages)
+    }
+
+    fn record_interruption(
+        &self,
+        interruption: &SessionInterruption,
+    ) -> Result<(), SessionRepositoryError> {
+        let connection = self.database.connection();
+
+        connection
+            .execute(
+                "INSERT INTO session_interruptions (session_id, kind, reason, occurred_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    interruption.session_id,
+                    interruption.kind.as_str(),
+                    interruption.reason,
+                    interruption.occurred_at.to_rfc3339()
+                ],
+            )
+            .map_err(|error| SessionRepositoryError::Storage {
+                message: error.to_string(),
+            })?;
+
+        Ok(())
+    }
+
+    fn interruption_reasons(
+        &self,
+        session_ids: &[SessionId],
+    ) -> Result<Vec<InterruptionReasonCount>, SessionRepositoryError> {
+        if session_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let connection = self.database.connection();
+        let storage_error = |error: rusqlite::Error| SessionRepositoryError::Storage {
+            message: error.to_string(),
+        };
+
+        let placeholders = vec!["?"; session_ids.len()].join(",");
+        let query = format!(
+            "SELECT reason, COUNT(*) AS occurrences
+             FROM session_interruptions
+             WHERE session_id IN ({})
+             GROUP BY reason
+             ORDER BY occurrences DESC, reason",
+            placeholders
+        );
+
+        let mut statement = connection.prepare(&query).map_err(storage_error)?;
+        let reasons = statement
+            .query_map(rusqlite::params_from_iter(session_ids.iter()), |row| {
+                Ok(InterruptionReasonCount {
+                    reason: row.get(0)?,
+                    count: row.get(1)?,
+                })
+            })
+            .map_err(storage_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+
+        Ok(reasons)
+    }
+
     fn count_completed_sessions(&self) -> Result<u32, SessionRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
 
         let count: i64 = connection
             .query_row(
@@ -230,7 +415,18 @@ impl SessionRepository for SqliteSessionRepository {
     }
 
     fn clear_completed_sessions(&self) -> Result<u32, SessionRepositoryError> {
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
+
+        connection
+            .execute_batch(
+                "DELETE FROM session_tags
+                 WHERE session_id IN (SELECT id FROM sessions WHERE ended_at IS NOT NULL);
+                 DELETE FROM session_interruptions
+                 WHERE session_id IN (SELECT id FROM sessions WHERE ended_at IS NOT NULL);",
+            )
+            .map_err(|error| SessionRepositoryError::Storage {
+                message: error.to_string(),
+            })?;
 
         let deleted = connection
             .execute("DELETE FROM sessions WHERE ended_at IS NOT NULL", [])
@@ -252,7 +448,18 @@ impl SessionRepository for SqliteSessionRepository {
             return Err(SessionRepositoryError::ActiveSession { id });
         }
 
-        let connection = self.connection.lock().unwrap();
+        let connection = self.database.connection();
+
+        for table in ["session_tags", "session_interruptions"] {
+            connection
+                .execute(
+                    &format!("DELETE FROM {} WHERE session_id = ?1", table),
+                    params![id],
+                )
+                .map_err(|error| SessionRepositoryError::Storage {
+                    message: error.to_string(),
+                })?;
+        }
 
         connection
             .execute("DELETE FROM sessions WHERE id = ?1", params![id])
@@ -264,6 +471,80 @@ impl SessionRepository for SqliteSessionRepository {
     }
 }
 
+fn replace_tags(
+    connection: &Connection,
+    session_id: SessionId,
+    tags: &[String],
+) -> Result<(), SessionRepositoryError> {
+    let storage_error = |error: rusqlite::Error| SessionRepositoryError::Storage {
+        message: error.to_string(),
+    };
+
+    connection
+        .execute(
+            "DELETE FROM session_tags WHERE session_id = ?1",
+            params![session_id],
+        )
+        .map_err(storage_error)?;
+
+    for tag in tags {
+        connection
+            .execute(
+                "INSERT OR IGNORE INTO session_tags (session_id, tag) VALUES (?1, ?2)",
+                params![session_id, tag],
+            )
+            .map_err(storage_error)?;
+    }
+
+    Ok(())
+}
+
+fn attach_tags(
+    connection: &Connection,
+    sessions: &mut [Session],
+) -> Result<(), SessionRepositoryError> {
+    let session_ids: Vec<SessionId> = sessions.iter().filter_map(|session| session.id).collect();
+    if session_ids.is_empty() {
+        return Ok(());
+    }
+
+    let storage_error = |error: rusqlite::Error| SessionRepositoryError::Storage {
+        message: error.to_string(),
+    };
+
+    let placeholders = session_ids
+        .iter()
+        .map(|_| "?")
+        .collect::<Vec<_>>()
+        .join(",");
+    let query = format!(
+        "SELECT session_id, tag FROM session_tags WHERE session_id IN ({}) ORDER BY tag",
+        placeholders
+    );
+
+    let mut statement = connection.prepare(&query).map_err(storage_error)?;
+    let rows = statement
+        .query_map(rusqlite::params_from_iter(session_ids.iter()), |row| {
+            Ok((row.get::<_, SessionId>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(storage_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(storage_error)?;
+
+    let mut tags_by_session: HashMap<SessionId, Vec<String>> = HashMap::new();
+    for (session_id, tag) in rows {
+        tags_by_session.entry(session_id).or_default().push(tag);
+    }
+
+    for session in sessions {
+        if let Some(tags) = session.id.and_then(|id| tags_by_session.remove(&id)) {
+            session.tags = tags;
+        }
+    }
+
+    Ok(())
+}
+
 fn row_to_session(row: &rusqlite::Row) -> Session {
     let id: i64 = row.get(0).unwrap();
     let mode_str: String = row.get(1).unwrap();
@@ -271,6 +552,8 @@ fn row_to_session(row: &rusqlite::Row) -> Session {
     let ended_at_str: Option<String> = row.get(3).unwrap();
     let duration_seconds: Option<i64> = row.get(4).unwrap();
     let check_in_count: i32 = row.get(5).unwrap();
+    let end_reason: Option<String> = row.get(6).unwrap();
+    let planned_minutes: Option<i64> = row.get(7).unwrap();
 
     Session {
         id: Some(id),
@@ -285,12 +568,18 @@ fn row_to_session(row: &rusqlite::Row) -> Session {
         }),
         duration_seconds,
         check_in_count,
+        tags: Vec::new(),
+        end_reason: end_reason
+            .as_deref()
+            .and_then(SessionEndReason::from_stored),
+        planned_minutes: planned_minutes.map(|minutes| minutes as u64),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use flux_core::InterruptionKind;
 
     #[test]
     fn save_and_retrieve_session() {
@@ -323,6 +612,38 @@ mod tests {
         assert!(retrieved.duration_seconds.is_some());
     }
 
+    #[test]
+    fn end_reason_is_migrated_and_persisted() {
+        let path =
+            std::env::temp_dir().join(format!("flux-session-end-reason-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE sessions (
+                    id INTEGER PRIMARY KEY,
+                    mode TEXT NOT NULL,
+                    started_at TEXT NOT NULL,
+                    ended_at TEXT,
+                    duration_seconds INTEGER,
+                    check_in_count INTEGER DEFAULT 0
+                );",
+            )
+            .unwrap();
+
+        let repository = SqliteSessionRepository::new(&path).unwrap();
+        let mut session = Session::start(FocusMode::Review);
+        repository.save(&mut session).unwrap();
+        session.end_with_reason(Utc::now(), SessionEndReason::Logout);
+        repository.update(&session).unwrap();
+
+        let retrieved = repository.find_by_id(session.id.unwrap()).unwrap();
+        assert_eq!(retrieved.end_reason, Some(SessionEndReason::Logout));
+
+        drop(repository);
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn find_active_returns_active_session() {
         let repository = SqliteSessionRepository::in_memory().unwrap();
@@ -412,6 +733,89 @@ mod tests {
         assert_eq!(sessions[0].mode, FocusMode::AiAssisted);
     }
 
+    #[test]
+    fn weekly_mode_totals_bucket_sessions_by_week_and_mode() {
+        use chrono::{Duration, Local, TimeZone};
+
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+        let wednesday = Local.with_ymd_and_hms(2026, 10, 14, 12, 0, 0).unwrap();
+        let sessions = [
+            (FocusMode::Review, wednesday, 1800),
+            (FocusMode::Review, wednesday + Duration::days(1), 600),
+            (FocusMode::Architecture, wednesday, 3600),
+            (FocusMode::Review, wednesday - Duration::days(7), 900),
+        ];
+        for (mode, started_at, seconds) in sessions {
+            let mut session = Session::start(mode);
+            session.started_at = started_at.with_timezone(&Utc);
+            session.ended_at = Some(session.started_at + Duration::seconds(seconds));
+            session.duration_seconds = Some(seconds);
+            repository.save(&mut session).unwrap();
+        }
+
+        let totals = repository
+            .weekly_mode_totals(
+                (wednesday - Duration::days(30)).with_timezone(&Utc),
+                WeekStart::Monday,
+            )
+            .unwrap();
+
+        let previous_week = NaiveDate::from_ymd_opt(2026, 10, 5).unwrap();
+        let current_week = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        assert_eq!(
+            totals,
+            vec![
+                WeeklyModeTotal {
+                    week_start: previous_week,
+                    mode: FocusMode::Review,
+                    seconds: 900,
+                },
+                WeeklyModeTotal {
+                    week_start: current_week,
+                    mode: FocusMode::Architecture,
+                    seconds: 3600,
+                },
+                WeeklyModeTotal {
+                    week_start: current_week,
+                    mode: FocusMode::Review,
+                    seconds: 2400,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn weekly_mode_totals_bucket_from_sunday_when_configured() {
+        use chrono::{Duration, Local, TimeZone};
+
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+        let sunday = Local.with_ymd_and_hms(2026, 10, 11, 12, 0, 0).unwrap();
+        let saturday = Local.with_ymd_and_hms(2026, 10, 10, 12, 0, 0).unwrap();
+        for started_at in [sunday, saturday] {
+            let mut session = Session::start(FocusMode::Review);
+            session.started_at = started_at.with_timezone(&Utc);
+            session.ended_at = Some(session.started_at + Duration::seconds(600));
+            session.duration_seconds = Some(600);
+            repository.save(&mut session).unwrap();
+        }
+
+        let totals = repository
+            .weekly_mode_totals(
+                (sunday - Duration::days(30)).with_timezone(&Utc),
+                WeekStart::Sunday,
+            )
+            .unwrap();
+
+        let weeks: Vec<NaiveDate> = totals.iter().map(|total| total.week_start).collect();
+        assert_eq!(
+            weeks,
+            vec![
+                NaiveDate::from_ymd_opt(2026, 10, 4).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 10, 11).unwrap(),
+            ]
+        );
+    }
+
     #[test]
     fn count_completed_sessions_returns_correct_count() {
         let repository = SqliteSessionRepository::in_memory().unwrap();
@@ -482,6 +886,37 @@ mod tests {
         assert!(!repository.has_active_session().unwrap());
     }
 
+    #[test]
+    fn find_latest_returns_the_last_started_session() {
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+        assert!(repository.find_latest().unwrap().is_none());
+
+        let mut earlier = Session::start(FocusMode::Review);
+        earlier.started_at -= chrono::Duration::hours(2);
+        earlier.end();
+        repository.save(&mut earlier).unwrap();
+        let mut later = Session::start(FocusMode::Architecture);
+        later.started_at -= chrono::Duration::hours(1);
+        later.end();
+        repository.save(&mut later).unwrap();
+
+        assert_eq!(repository.find_latest().unwrap().unwrap().id, later.id);
+    }
+
+    #[test]
+    fn planned_minutes_are_persisted() {
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+
+        let mut planned = Session::start(FocusMode::Review).with_planned_minutes(45);
+        repository.save(&mut planned).unwrap();
+        let mut legacy = Session::start(FocusMode::Review);
+        repository.save(&mut legacy).unwrap();
+
+        let find = |session: &Session| repository.find_by_id(session.id.unwrap()).unwrap();
+        assert_eq!(find(&planned).planned_minutes, Some(45));
+        assert_eq!(find(&legacy).planned_minutes, None);
+    }
+
     #[test]
     fn delete_session_removes_completed_session() {
         let repository = SqliteSessionRepository::in_memory().unwrap();
@@ -569,4 +1004,150 @@ mod tests {
 
         assert!(sessions.is_empty());
     }
+
+    #[test]
+    fn tags_roundtrip_and_are_removed_with_session() {
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+
+        let mut session = Session::start(FocusMode::AiAssisted)
+            .with_tags(vec!["clientX".to_string(), "backend".to_string()]);
+        session.end();
+        repository.save(&mut session).unwrap();
+
+        let mut untagged = Session::start(FocusMode::Review);
+        untagged.end();
+        repository.save(&mut untagged).unwrap();
+
+        let since = Utc::now() - chrono::Duration::hours(1);
+        let sessions = repository.find_completed_since(since).unwrap();
+        let tagged = sessions.iter().find(|s| s.id == session.id).unwrap();
+        assert_eq!(tagged.tags, vec!["backend", "clientx"]);
+        assert!(sessions
+            .iter()
+            .find(|s| s.id == untagged.id)
+            .unwrap()
+            .tags
+            .is_empty());
+
+        repository.delete_session(session.id.unwrap()).unwrap();
+        let connection = repository.database.connection();
+        let remaining: i64 = connection
+            .query_row("SELECT COUNT(*) FROM session_tags", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(remaining, 0);
+    }
+
+    #[test]
+    fn a_failed_tag_write_leaves_the_session_unchanged() {
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+        let mut session = Session::start(FocusMode::Review).with_tags(vec!["clientx".to_string()]);
+        repository.save(&mut session).unwrap();
+        repository
+            .database
+            .connection()
+            .execute_batch(
+                "CREATE TRIGGER reject_tag BEFORE INSERT ON session_tags WHEN NEW.tag = 'rejected'
+                 BEGIN SELECT RAISE(ABORT, 'rejected tag'); END;",
+            )
+            .unwrap();
+
+        session.end();
+        session.add_tag("backend");
+        session.add_tag("rejected");
+        assert!(repository.update(&session).is_err());
+
+        let stored = repository.find_by_id(session.id.unwrap()).unwrap();
+        assert!(stored.is_active());
+        assert_eq!(stored.tags, vec!["clientx"]);
+
+        let mut rejected =
+            Session::start(FocusMode::Review).with_tags(vec!["rejected".to_string()]);
+        assert!(repository.save(&mut rejected).is_err());
+        assert_eq!(rejected.id, None);
+        assert_eq!(repository.find_active().unwrap().unwrap().id, session.id);
+    }
+
+    #[test]
+    fn tag_usages_sum_ended_sessions_per_tag() {
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+        let save = |tags: &[&str], seconds: Option<i64>| {
+            let mut session = Session::start(FocusMode::Review)
+                .with_tags(tags.iter().map(|tag| tag.to_string()).collect());
+            if let Some(seconds) = seconds {
+                session.end();
+                session.duration_seconds = Some(seconds);
+            }
+            repository.save(&mut session).unwrap();
+        };
+        save(&["backend", "clientx"], Some(1500));
+        save(&["clientx"], Some(3000));
+        save(&["backend"], None);
+
+        let usages = repository.tag_usages().unwrap();
+
+        assert_eq!(
+            usages,
+            vec![
+                TagUsage {
+                    tag: "clientx".to_string(),
+                    session_count: 2,
+                    total_seconds: 4500,
+                },
+                TagUsage {
+                    tag: "backend".to_string(),
+                    session_count: 1,
+                    total_seconds: 1500,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn interruption_reasons_are_counted_for_the_requested_sessions() {
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+        let record = |session_id, kind, reason| {
+            repository
+                .record_interruption(&SessionInterruption::new(session_id, kind, reason).unwrap())
+                .unwrap();
+        };
+        record(1, InterruptionKind::Pause, "Standup meeting");
+        record(1, InterruptionKind::Stop, "phone call");
+        record(2, InterruptionKind::Pause, "standup meeting");
+        record(3, InterruptionKind::Pause, "lunch");
+
+        let reasons = repository.interruption_reasons(&[1, 2]).unwrap();
+
+        assert_eq!(
+            reasons,
+            vec![
+                InterruptionReasonCount {
+                    reason: "standup meeting".to_string(),
+                    count: 2,
+                },
+                InterruptionReasonCount {
+                    reason: "phone call".to_string(),
+                    count: 1,
+                },
+            ]
+        );
+        assert!(repository.interruption_reasons(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn deleting_a_session_removes_its_interruptions() {
+        let repository = SqliteSessionRepository::in_memory().unwrap();
+        let mut session = Session::start(FocusMode::Review);
+        let id = repository.save(&mut session).unwrap();
+        session.end();
+        repository.update(&session).unwrap();
+        repository
+            .record_interruption(
+                &SessionInterruption::new(id, InterruptionKind::Stop, "phone call").unwrap(),
+            )
+            .unwrap();
+
+        repository.delete_session(id).unwrap();
+
+        assert!(repository.interruption_reasons(&[id]).unwrap().is_empty());
+    }
 }
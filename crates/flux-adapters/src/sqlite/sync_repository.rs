@@ -0,0 +1,610 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use flux_core::{
+    SessionId, SyncRecord, SyncRepository, SyncRepositoryError, SyncedAppUsage, SyncedSegment,
+    SyncedSession,
+};
+
+use super::Database;
+
+/// Tables holding the rows of a session that travel with it, replaced by a
+/// newer version
+const SYNCED_CHILD_TABLES: [&str; 4] = [
+    "session_tags",
+    "session_notes",
+    "app_tracking",
+    "app_usage_segments",
+];
+
+/// Tables holding what only the device that recorded a session knows about
+/// it, kept until the session is deleted
+const LOCAL_CHILD_TABLES: [&str; 2] = ["session_interruptions", "session_metrics"];
+
+pub struct SqliteSyncRepository {
+    database: Database,
+}
+
+impl SqliteSyncRepository {
+    pub fn new(path: &Path) -> Result<Self, SyncRepositoryError> {
+        let database = Database::open(path).map_err(persistence_error)?;
+        Ok(Self::with_database(database))
+    }
+
+    pub fn in_memory() -> Result<Self, SyncRepositoryError> {
+        let database = Database::in_memory().map_err(persistence_error)?;
+        Ok(Self::with_database(database))
+    }
+
+    pub fn with_database(database: Database) -> Self {
+        Self { database }
+    }
+}
+
+impl SyncRepository for SqliteSyncRepository {
+    fn export(&self) -> Result<Vec<SyncRecord>, SyncRepositoryError> {
+        let connection = self.database.connection();
+
+        let mut sessions = Vec::new();
+        {
+            let mut statement = connection
+                .prepare(
+                    "SELECT id, uuid, updated_at, mode, started_at, ended_at, duration_seconds,
+                        check_in_count, end_reason, planned_minutes
+                     FROM sessions
+                     WHERE ended_at IS NOT NULL AND uuid IS NOT NULL
+                     ORDER BY started_at",
+                )
+                .map_err(persistence_error)?;
+            let rows = statement
+                .query_map([], |row| {
+                    Ok((
+                        row.get::<_, SessionId>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        SyncedSession {
+                            mode: row.get(3)?,
+                            started_at: parse_timestamp(&row.get::<_, String>(4)?),
+                            ended_at: parse_timestamp(&row.get::<_, String>(5)?),
+                            duration_seconds: row.get(6)?,
+                            check_in_count: row.get(7)?,
+                            tags: Vec::new(),
+                            end_reason: row.get(8)?,
+                            planned_minutes: row
+                                .get::<_, Option<i64>>(9)?
+                                .map(|minutes| minutes as u64),
+                            note: None,
+                            app_usage: Vec::new(),
+                            segments: Vec::new(),
+                        },
+                    ))
+                })
+                .map_err(persistence_error)?;
+            for row in rows {
+                sessions.push(row.map_err(persistence_error)?);
+            }
+        }
+
+        let mut tags = grouped(
+            &connection,
+            "SELECT session_id, tag FROM session_tags ORDER BY tag",
+            |row| row.get::<_, String>(1),
+        )?;
+        let mut notes = grouped(
+            &connection,
+            "SELECT session_id, text FROM session_notes",
+            |row| row.get::<_, String>(1),
+        )?;
+        let mut app_usage = grouped(
+            &connection,
+            "SELECT session_id, application_name, window_title, duration_seconds
+             FROM app_tracking ORDER BY application_name, window_title",
+            |row| {
+                Ok(SyncedAppUsage {
+                    application_name: row.get(1)?,
+                    window_title: self.database.export_title(row.get(2)?),
+                    duration_seconds: row.get(3)?,
+                })
+            },
+        )?;
+        let mut segments = grouped(
+            &connection,
+            "SELECT session_id, kind, application_name, window_title, started_at, ended_at,
+                check_in_outcome
+             FROM app_usage_segments ORDER BY started_at",
+            |row| {
+                Ok(SyncedSegment {
+                    kind: row.get(1)?,
+                    application_name: row.get(2)?,
+                    window_title: self.database.export_title(row.get(3)?),
+                    started_at: parse_timestamp(&row.get::<_, String>(4)?),
+                    ended_at: parse_timestamp(&row.get::<_, String>(5)?),
+                    check_in_outcome: row.get(6)?,
+                })
+            },
+        )?;
+
+        let mut records: Vec<SyncRecord> = sessions
+            .into_iter()
+            .map(|(id, uuid, updated_at, mut session)| {
+                session.tags = tags.remove(&id).unwrap_or_default();
+                session.note = notes.remove(&id).and_then(|mut notes| notes.pop());
+                session.app_usage = app_usage.remove(&id).unwrap_or_default();
+                session.segments = segments.remove(&id).unwrap_or_default();
+                SyncRecord {
+                    uuid,
+                    updated_at: parse_timestamp(&updated_at),
+                    session: Some(session),
+                }
+            })
+            .collect();
+
+        let mut statement = connection
+            .prepare("SELECT uuid, deleted_at FROM session_tombstones ORDER BY uuid")
+            .map_err(persistence_error)?;
+        let tombstones = statement
+            .query_map([], |row| {
+                Ok(SyncRecord {
+                    uuid: row.get(0)?,
+                    updated_at: parse_timestamp(&row.get::<_, String>(1)?),
+                    session: None,
+                })
+            })
+            .map_err(persistence_error)?;
+        for tombstone in tombstones {
+            records.push(tombstone.map_err(persistence_error)?);
+        }
+
+        Ok(records)
+    }
+
+    fn import(&self, records: &[SyncRecord]) -> Result<(), SyncRepositoryError> {
+        let mut connection = self.database.connection();
+        let transaction = connection.transaction().map_err(persistence_error)?;
+
+        for record in records {
+            let existing: Option<SessionId> = transaction
+                .query_row(
+                    "SELECT id FROM sessions WHERE uuid = ?1",
+                    params![record.uuid],
+                    |row| row.get(0),
+                )
+                .optional()
+                .map_err(persistence_error)?;
+
+            match &record.session {
+                Some(session) => {
+                    store_session(&transaction, &self.database, record, session, existing)?
+                }
+                None => delete_session(&transaction, record, existing)?,
+            }
+        }
+
+        transaction.commit().map_err(persistence_error)
+    }
+}
+
+/// The triggers stamp the session on every write to it or to its tags and
+/// note, so the timestamp it was received with is restored last
+///
+/// Titles are stored the way this database writes its own, except those
+/// sealed by a device encrypting them, which stay sealed
+fn store_session(
+    connection: &Connection,
+    database: &Database,
+    record: &SyncRecord,
+    session: &SyncedSession,
+    existing: Option<SessionId>,
+) -> Result<(), SyncRepositoryError> {
+    let fields = params![
+        session.mode,
+        session.started_at.to_rfc3339(),
+        session.ended_at.to_rfc3339(),
+        session.duration_seconds,
+        session.check_in_count,
+        session.end_reason,
+        session.planned_minutes.map(|minutes| minutes as i64),
+        record.uuid,
+    ];
+    let id = match existing {
+        Some(id) => {
+            connection
+                .execute(
+                    "UPDATE sessions SET mode = ?1, started_at = ?2, ended_at = ?3,
+                        duration_seconds = ?4, check_in_count = ?5, end_reason = ?6,
+                        planned_minutes = ?7
+                     WHERE uuid = ?8",
+                    fields,
+                )
+                .map_err(persistence_error)?;
+            delete_children(connection, id, &SYNCED_CHILD_TABLES)?;
+            id
+        }
+        None => {
+            connection
+                .execute(
+                    "INSERT INTO sessions (mode, started_at, ended_at, duration_seconds,
+                        check_in_count, end_reason, planned_minutes, uuid)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    fields,
+                )
+                .map_err(persistence_error)?;
+            connection.last_insert_rowid()
+        }
+    };
+
+    for tag in &session.tags {
+        connection
+            .execute(
+                "INSERT OR IGNORE INTO session_tags (session_id, tag) VALUES (?1, ?2)",
+                params![id, tag],
+            )
+            .map_err(persistence_error)?;
+    }
+    if let Some(note) = &session.note {
+        connection
+            .execute(
+                "INSERT INTO session_notes (session_id, text, created_at) VALUES (?1, ?2, ?3)",
+                params![id, note, session.ended_at.to_rfc3339()],
+            )
+            .map_err(persistence_error)?;
+    }
+    for usage in &session.app_usage {
+        connection
+            .execute(
+                "INSERT OR REPLACE INTO app_tracking
+                    (session_id, application_name, window_title, duration_seconds)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    id,
+                    usage.application_name,
+                    database.seal_title(&usage.window_title),
+                    usage.duration_seconds
+                ],
+            )
+            .map_err(persistence_error)?;
+    }
+    for segment in &session.segments {
+        connection
+            .execute(
+                "INSERT INTO app_usage_segments (session_id, kind, application_name,
+                    window_title, started_at, ended_at, check_in_outcome)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    id,
+                    segment.kind,
+                    segment.application_name,
+                    database.seal_title(&segment.window_title),
+                    segment.started_at.to_rfc3339(),
+                    segment.ended_at.to_rfc3339(),
+                    segment.check_in_outcome,
+                ],
+            )
+            .map_err(persistence_error)?;
+    }
+
+    // Cleared first: setting the stamp the triggers just wrote would leave
+    // it unchanged, which the update trigger takes for an edit to stamp
+    connection
+        .execute(
+            "UPDATE sessions SET updated_at = NULL WHERE id = ?1",
+            params![id],
+        )
+        .map_err(persistence_error)?;
+    connection
+        .execute(
+            "UPDATE sessions SET updated_at = ?1 WHERE id = ?2",
+            params![timestamp(record.updated_at), id],
+        )
+        .map_err(persistence_error)?;
+    connection
+        .execute(
+            "DELETE FROM session_tombstones WHERE uuid = ?1",
+            params![record.uuid],
+        )
+        .map_err(persistence_error)?;
+
+    Ok(())
+}
+
+fn delete_session(
+    connection: &Connection,
+    record: &SyncRecord,
+    existing: Option<SessionId>,
+) -> Result<(), SyncRepositoryError> {
+    if let Some(id) = existing {
+        delete_children(connection, id, &SYNCED_CHILD_TABLES)?;
+        delete_children(connection, id, &LOCAL_CHILD_TABLES)?;
+        connection
+            .execute("DELETE FROM sessions WHERE id = ?1", params![id])
+            .map_err(persistence_error)?;
+    }
+
+    // Replaces the tombstone the trigger stamped with the time of the deletion
+    connection
+        .execute(
+            "INSERT OR REPLACE INTO session_tombstones (uuid, deleted_at) VALUES (?1, ?2)",
+            params![record.uuid, timestamp(record.updated_at)],
+        )
+        .map_err(persistence_error)?;
+
+    Ok(())
+}
+
+fn delete_children(
+    connection: &Connection,
+    id: SessionId,
+    tables: &[&str],
+) -> Result<(), SyncRepositoryError> {
+    for table in tables {
+        connection
+            .execute(
+                &format!("DELETE FROM {} WHERE session_id = ?1", table),
+                params![id],
+            )
+            .map_err(persistence_error)?;
+    }
+    Ok(())
+}
+
+/// Rows of a `SELECT session_id, ...` query, grouped by session
+fn grouped<T>(
+    connection: &Connection,
+    query: &str,
+    map: impl Fn(&rusqlite::Row) -> rusqlite::Result<T>,
+) -> Result<HashMap<SessionId, Vec<T>>, SyncRepositoryError> {
+    let mut statement = connection.prepare(query).map_err(persistence_error)?;
+    let rows = statement
+        .query_map([], |row| Ok((row.get::<_, SessionId>(0)?, map(row)?)))
+        .map_err(persistence_error)?;
+
+    let mut grouped: HashMap<SessionId, Vec<T>> = HashMap::new();
+    for row in rows {
+        let (session_id, value) = row.map_err(persistence_error)?;
+        grouped.entry(session_id).or_default().push(value);
+    }
+    Ok(grouped)
+}
+
+/// Milliseconds, like the timestamps written by the sync triggers
+fn timestamp(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn parse_timestamp(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|value| value.with_timezone(&Utc))
+        .unwrap_or_default()
+}
+
+fn persistence_error(error: impl std::fmt::Display) -> SyncRepositoryError {
+    SyncRepositoryError::Persistence(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::TitleCipher;
+    use crate::sqlite::{
+        SqliteAppTrackingRepository, SqliteSessionMetricsRepository, SqliteSessionNoteRepository,
+        SqliteSessionRepository,
+    };
+    use flux_core::{
+        AppTrackingRepository, AppUsage, AppUsageSegment, FocusMode, InterruptionKind, SegmentKind,
+        Session, SessionInterruption, SessionMetrics, SessionMetricsRepository, SessionNote,
+        SessionNoteRepository, SessionRepository, SyncMerge,
+    };
+
+    struct Device {
+        sessions: SqliteSessionRepository,
+        notes: SqliteSessionNoteRepository,
+        tracking: SqliteAppTrackingRepository,
+        metrics: SqliteSessionMetricsRepository,
+        sync: SqliteSyncRepository,
+    }
+
+    impl Device {
+        fn new() -> Self {
+            Self::with_database(Database::in_memory().unwrap())
+        }
+
+        fn with_title_cipher(key: &str, encrypt: bool) -> Self {
+            Self::with_database(
+                Database::in_memory()
+                    .unwrap()
+                    .with_title_cipher(TitleCipher::new(key).unwrap(), encrypt),
+            )
+        }
+
+        fn with_database(database: Database) -> Self {
+            Self {
+                sessions: SqliteSessionRepository::with_database(database.clone()),
+                notes: SqliteSessionNoteRepository::with_database(database.clone()),
+                tracking: SqliteAppTrackingRepository::with_database(database.clone()),
+                metrics: SqliteSessionMetricsRepository::with_database(database.clone()),
+                sync: SqliteSyncRepository::with_database(database),
+            }
+        }
+
+        fn ended_session(&self) -> SessionId {
+            let mut session = Session::start(FocusMode::Review)
+                .with_tags(vec!["clientx".to_string()])
+                .with_planned_minutes(25);
+            let id = self.sessions.save(&mut session).unwrap();
+            session.end();
+            self.sessions.update(&session).unwrap();
+            id
+        }
+
+        fn records(&self) -> Vec<SyncRecord> {
+            self.sync.export().unwrap()
+        }
+    }
+
+    #[test]
+    fn ended_sessions_are_exported_with_their_tags_note_and_usage() {
+        let device = Device::new();
+        let id = device.ended_session();
+        let mut running = Session::start(FocusMode::Veille);
+        device.sessions.save(&mut running).unwrap();
+        device
+            .notes
+            .save(&SessionNote::new(id, "shipped the parser").unwrap())
+            .unwrap();
+        let started_at = device.sessions.find_by_id(id).unwrap().started_at;
+        device
+            .tracking
+            .save_segments(&[AppUsageSegment::new(
+                id,
+                SegmentKind::Focus,
+                "code".to_string(),
+                started_at,
+                started_at + chrono::Duration::minutes(5),
+            )])
+            .unwrap();
+
+        let records = device.records();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].uuid.len(), 36);
+        let session = records[0].session.as_ref().unwrap();
+        assert_eq!(session.mode, "review");
+        assert_eq!(session.tags, vec!["clientx"]);
+        assert_eq!(session.note.as_deref(), Some("shipped the parser"));
+        assert_eq!(session.segments[0].application_name, "code");
+    }
+
+    #[test]
+    fn imported_sessions_keep_their_version_and_are_not_sent_back() {
+        let laptop = Device::new();
+        let desktop = Device::new();
+        laptop.ended_session();
+
+        let merge = SyncMerge::new(&desktop.records(), laptop.records());
+        desktop.sync.import(&merge.incoming).unwrap();
+
+        assert_eq!(desktop.records(), laptop.records());
+        assert_eq!(desktop.sessions.count_completed_sessions().unwrap(), 1);
+        assert_eq!(
+            SyncMerge::new(&desktop.records(), laptop.records()),
+            SyncMerge::default()
+        );
+    }
+
+    #[test]
+    fn encrypted_titles_only_travel_sealed() {
+        let key = TitleCipher::generate_key().unwrap();
+        let laptop = Device::with_title_cipher(&key, true);
+        let id = laptop.ended_session();
+        laptop
+            .tracking
+            .save_or_update(&AppUsage::with_title(
+                id,
+                "firefox".to_string(),
+                "ACME-1234 - Jira".to_string(),
+                30,
+            ))
+            .unwrap();
+        let records = laptop.records();
+        let sent = &records[0].session.as_ref().unwrap().app_usage[0].window_title;
+        assert!(!sent.contains("ACME"));
+
+        let desktop = Device::with_title_cipher(&key, false);
+        let phone = Device::new();
+        desktop.sync.import(&records).unwrap();
+        phone.sync.import(&records).unwrap();
+
+        let title = |device: &Device| {
+            let session = device.records()[0].session.clone().unwrap();
+            session.app_usage[0].window_title.clone()
+        };
+        assert_eq!(title(&desktop), "ACME-1234 - Jira");
+        assert_eq!(&title(&phone), sent);
+        let phone_id = phone
+            .sessions
+            .find_completed_since(Utc::now() - chrono::Duration::hours(1))
+            .unwrap()[0]
+            .id
+            .unwrap();
+        assert_eq!(
+            phone.tracking.find_by_session(phone_id).unwrap()[0].window_title,
+            flux_core::REDACTED_TITLE
+        );
+    }
+
+    #[test]
+    fn editing_a_tag_stamps_the_session() {
+        let device = Device::new();
+        let id = device.ended_session();
+        let before = device.records()[0].updated_at;
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        let mut session = device.sessions.find_by_id(id).unwrap();
+        session.add_tag("spike");
+        device.sessions.update(&session).unwrap();
+
+        assert!(device.records()[0].updated_at > before);
+    }
+
+    #[test]
+    fn a_newer_version_keeps_the_details_only_this_device_recorded() {
+        let laptop = Device::new();
+        let desktop = Device::new();
+        let id = laptop.ended_session();
+        laptop
+            .metrics
+            .save(&SessionMetrics::new(id, 4, HashMap::new()))
+            .unwrap();
+        let interruption =
+            SessionInterruption::new(id, InterruptionKind::Pause, "meeting").unwrap();
+        laptop.sessions.record_interruption(&interruption).unwrap();
+        desktop.sync.import(&laptop.records()).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        let desktop_id = desktop
+            .sessions
+            .find_completed_since(Utc::now() - chrono::Duration::hours(1))
+            .unwrap()[0]
+            .id
+            .unwrap();
+        let mut session = desktop.sessions.find_by_id(desktop_id).unwrap();
+        session.add_tag("spike");
+        desktop.sessions.update(&session).unwrap();
+        let merge = SyncMerge::new(&laptop.records(), desktop.records());
+        laptop.sync.import(&merge.incoming).unwrap();
+
+        assert!(laptop.sessions.find_by_id(id).unwrap().has_tag("spike"));
+        assert_eq!(
+            laptop
+                .metrics
+                .find_by_session(id)
+                .unwrap()
+                .map(|metrics| metrics.context_switch_count),
+            Some(4)
+        );
+        assert_eq!(
+            laptop.sessions.interruption_reasons(&[id]).unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn deletions_travel_as_tombstones() {
+        let laptop = Device::new();
+        let desktop = Device::new();
+        let id = laptop.ended_session();
+        desktop.sync.import(&laptop.records()).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        laptop.sessions.delete_session(id).unwrap();
+        let merge = SyncMerge::new(&desktop.records(), laptop.records());
+        desktop.sync.import(&merge.incoming).unwrap();
+
+        assert_eq!(desktop.sessions.count_completed_sessions().unwrap(), 0);
+        assert!(desktop.records()[0].is_deleted());
+        assert_eq!(desktop.records(), laptop.records());
+    }
+}
@@ -0,0 +1,197 @@
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use thiserror::Error;
+
+use flux_core::{resolve_database_key, store_database_key, PrivacyConfig, SecretsError};
+
+const KEY_LEN: usize = 32;
+
+/// Marks an encrypted title, followed by the hexadecimal nonce and ciphertext
+const SEALED_PREFIX: &str = "enc1:";
+
+#[derive(Debug, Error)]
+pub enum TitleCipherError {
+    #[error("the database key must be {} hexadecimal digits", KEY_LEN * 2)]
+    InvalidKey,
+
+    #[error("cannot generate a database key")]
+    Random,
+
+    #[error(transparent)]
+    Secrets(#[from] SecretsError),
+}
+
+/// Encrypts window titles with AES-256-GCM. The nonce is derived from the
+/// title, so a title always encrypts to the same text: usage keeps being
+/// grouped and merged by title in SQL, at the cost of revealing which rows
+/// share a title.
+pub struct TitleCipher {
+    key: LessSafeKey,
+    nonce_key: hmac::Key,
+}
+
+impl std::fmt::Debug for TitleCipher {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("TitleCipher")
+    }
+}
+
+impl TitleCipher {
+    pub fn new(hex_key: &str) -> Result<Self, TitleCipherError> {
+        let master = decode_hex(hex_key.trim())
+            .filter(|bytes| bytes.len() == KEY_LEN)
+            .ok_or(TitleCipherError::InvalidKey)?;
+        let master = hmac::Key::new(hmac::HMAC_SHA256, &master);
+        let derive = |purpose: &str| hmac::sign(&master, purpose.as_bytes());
+
+        let key = UnboundKey::new(&AES_256_GCM, derive("flux window title key").as_ref())
+            .map_err(|_| TitleCipherError::InvalidKey)?;
+        Ok(Self {
+            key: LessSafeKey::new(key),
+            nonce_key: hmac::Key::new(
+                hmac::HMAC_SHA256,
+                derive("flux window title nonce").as_ref(),
+            ),
+        })
+    }
+
+    pub fn generate_key() -> Result<String, TitleCipherError> {
+        let mut key = [0u8; KEY_LEN];
+        SystemRandom::new()
+            .fill(&mut key)
+            .map_err(|_| TitleCipherError::Random)?;
+        Ok(encode_hex(&key))
+    }
+
+    /// The key comes from the secrets; when titles are to be encrypted and
+    /// none is stored yet, one is generated and stored. Without a key, titles
+    /// are neither encrypted nor readable once encrypted.
+    pub fn from_privacy(privacy: &PrivacyConfig) -> Result<Option<Self>, TitleCipherError> {
+        match resolve_database_key() {
+            Ok(key) => Self::new(&key).map(Some),
+            Err(SecretsError::NotFound { .. }) if privacy.encrypt_window_titles => {
+                // Another process may have stored its own key meanwhile
+                let key = store_database_key(&Self::generate_key()?)?;
+                Self::new(&key).map(Some)
+            }
+            Err(SecretsError::NotFound { .. }) => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Empty titles stay empty: they only mean the title was not read
+    pub fn seal(&self, title: &str) -> String {
+        if title.is_empty() {
+            return String::new();
+        }
+
+        let tag = hmac::sign(&self.nonce_key, title.as_bytes());
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce.copy_from_slice(&tag.as_ref()[..NONCE_LEN]);
+
+        let mut sealed = title.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut sealed,
+            )
+            .expect("window titles are far below the AES-GCM size limit");
+
+        format!(
+            "{SEALED_PREFIX}{}{}",
+            encode_hex(&nonce),
+            encode_hex(&sealed)
+        )
+    }
+
+    /// None when the title was encrypted with another key or altered
+    pub fn open(&self, stored: &str) -> Option<String> {
+        let Some(hex) = stored.strip_prefix(SEALED_PREFIX) else {
+            return Some(stored.to_string());
+        };
+
+        let bytes = decode_hex(hex)?;
+        if bytes.len() < NONCE_LEN {
+            return None;
+        }
+        let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+        let mut sealed = sealed.to_vec();
+        let title = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut sealed)
+            .ok()?;
+        String::from_utf8(title.to_vec()).ok()
+    }
+}
+
+pub(crate) fn is_sealed(stored: &str) -> bool {
+    stored.starts_with(SEALED_PREFIX)
+}
+
+/// SQL pattern matching the encrypted titles
+pub(crate) const SEALED_PATTERN: &str = "enc1:%";
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cipher() -> TitleCipher {
+        TitleCipher::new(&"2a".repeat(KEY_LEN)).unwrap()
+    }
+
+    #[test]
+    fn a_title_always_encrypts_to_the_same_text() {
+        let cipher = cipher();
+
+        let sealed = cipher.seal("ACME-1234 - Jira");
+
+        assert!(is_sealed(&sealed));
+        assert!(!sealed.contains("ACME"));
+        assert_eq!(sealed, cipher.seal("ACME-1234 - Jira"));
+        assert_ne!(sealed, cipher.seal("ACME-1235 - Jira"));
+        assert_eq!(cipher.open(&sealed).as_deref(), Some("ACME-1234 - Jira"));
+        assert_eq!(cipher.seal(""), "");
+    }
+
+    #[test]
+    fn titles_from_another_key_or_altered_cannot_be_read() {
+        let sealed = cipher().seal("Client Dupont - Outlook");
+        let other = TitleCipher::new(&TitleCipher::generate_key().unwrap()).unwrap();
+        let mut altered = sealed.clone();
+        altered.replace_range(sealed.len() - 2.., "00");
+
+        assert_eq!(other.open(&sealed), None);
+        assert_eq!(cipher().open(&altered), None);
+        assert_eq!(cipher().open("plain title").as_deref(), Some("plain title"));
+    }
+
+    #[test]
+    fn keys_must_be_thirty_two_hexadecimal_bytes() {
+        assert!(matches!(
+            TitleCipher::new("2a2a"),
+            Err(TitleCipherError::InvalidKey)
+        ));
+        assert!(matches!(
+            TitleCipher::new(&"zz".repeat(KEY_LEN)),
+            Err(TitleCipherError::InvalidKey)
+        ));
+        assert_eq!(TitleCipher::generate_key().unwrap().len(), KEY_LEN * 2);
+    }
+}
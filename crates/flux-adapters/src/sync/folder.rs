@@ -0,0 +1,225 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use flux_core::{latest_versions, SyncGateway, SyncGatewayError, SyncRecord};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+const FILE_SUFFIX: &str = ".flux-sync.json";
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct DeviceHistory {
+    format: u32,
+    device: String,
+    records: Vec<SyncRecord>,
+}
+
+/// Exchanges session history through a folder that a file sync tool
+/// (Syncthing, Dropbox...) replicates between devices. Each device only ever
+/// writes its own file, so the tool never has to resolve a conflict.
+#[derive(Clone)]
+pub struct FolderSyncGateway {
+    folder: PathBuf,
+    device: String,
+}
+
+impl FolderSyncGateway {
+    pub fn new(folder: PathBuf, device: &str) -> Self {
+        let device = device
+            .chars()
+            .map(|character| {
+                if character.is_ascii_alphanumeric() || character == '-' || character == '_' {
+                    character
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        Self { folder, device }
+    }
+
+    fn own_file(&self) -> PathBuf {
+        self.folder.join(format!("{}{}", self.device, FILE_SUFFIX))
+    }
+
+    fn read_others(&self) -> Result<Vec<SyncRecord>, SyncGatewayError> {
+        let entries = match std::fs::read_dir(&self.folder) {
+            Ok(entries) => entries,
+            // Nothing was published yet, or the folder is not replicated here yet
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(unavailable(&self.folder, error)),
+        };
+
+        let own_file = self.own_file();
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| *path != own_file)
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.ends_with(FILE_SUFFIX) && !name.starts_with('.'))
+            })
+            .collect();
+        files.sort();
+
+        let mut records = Vec::new();
+        for file in files {
+            // A file still being replicated must not hold back the other devices
+            match read_history(&file) {
+                Ok(history) => records.extend(history.records),
+                Err(error) => warn!(%error, "skipping sync file"),
+            }
+        }
+        Ok(latest_versions(records))
+    }
+
+    fn write_own(&self, records: &[SyncRecord]) -> Result<(), SyncGatewayError> {
+        std::fs::create_dir_all(&self.folder).map_err(|error| unavailable(&self.folder, error))?;
+
+        let history = DeviceHistory {
+            format: FORMAT_VERSION,
+            device: self.device.clone(),
+            records: records.to_vec(),
+        };
+        let content =
+            serde_json::to_string_pretty(&history).map_err(|error| SyncGatewayError::Parse {
+                message: error.to_string(),
+            })?;
+
+        // Written aside then renamed, so that the sync tool never picks up half a file
+        let staging = self
+            .folder
+            .join(format!(".{}{}.tmp", self.device, FILE_SUFFIX));
+        std::fs::write(&staging, content).map_err(|error| unavailable(&staging, error))?;
+        std::fs::rename(&staging, self.own_file())
+            .map_err(|error| unavailable(&self.own_file(), error))
+    }
+}
+
+fn read_history(path: &Path) -> Result<DeviceHistory, SyncGatewayError> {
+    let content = std::fs::read_to_string(path).map_err(|error| unavailable(path, error))?;
+    let history: DeviceHistory =
+        serde_json::from_str(&content).map_err(|error| SyncGatewayError::Parse {
+            message: format!("{}: {}", path.display(), error),
+        })?;
+
+    if history.format > FORMAT_VERSION {
+        return Err(SyncGatewayError::Parse {
+            message: format!(
+                "{}: format v{} written by a newer flux",
+                path.display(),
+                history.format
+            ),
+        });
+    }
+    Ok(history)
+}
+
+fn unavailable(path: &Path, error: std::io::Error) -> SyncGatewayError {
+    SyncGatewayError::Unavailable {
+        message: format!("{}: {}", path.display(), error),
+    }
+}
+
+#[async_trait]
+impl SyncGateway for FolderSyncGateway {
+    async fn pull(&self) -> Result<Vec<SyncRecord>, SyncGatewayError> {
+        let gateway = self.clone();
+
+        tokio::task::spawn_blocking(move || gateway.read_others())
+            .await
+            .map_err(|error| SyncGatewayError::Unavailable {
+                message: format!("task join error: {}", error),
+            })?
+    }
+
+    async fn push(&self, records: &[SyncRecord]) -> Result<(), SyncGatewayError> {
+        let gateway = self.clone();
+        let records = records.to_vec();
+
+        tokio::task::spawn_blocking(move || gateway.write_own(&records))
+            .await
+            .map_err(|error| SyncGatewayError::Unavailable {
+                message: format!("task join error: {}", error),
+            })?
+    }
+
+    fn target_name(&self) -> String {
+        self.folder.display().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn folder(name: &str) -> PathBuf {
+        let folder =
+            std::env::temp_dir().join(format!("flux-sync-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        folder
+    }
+
+    fn deletion(uuid: &str, hour: u32) -> SyncRecord {
+        SyncRecord {
+            uuid: uuid.to_string(),
+            updated_at: Utc.with_ymd_and_hms(2026, 10, 12, hour, 0, 0).unwrap(),
+            session: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn each_device_reads_what_the_others_published() {
+        let folder = folder("devices");
+        let laptop = FolderSyncGateway::new(folder.clone(), "laptop");
+        let desktop = FolderSyncGateway::new(folder.clone(), "desktop");
+        let tablet = FolderSyncGateway::new(folder.clone(), "tablet");
+
+        assert!(laptop.pull().await.unwrap().is_empty());
+        laptop.push(&[deletion("a", 10)]).await.unwrap();
+        desktop
+            .push(&[deletion("a", 11), deletion("b", 9)])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            laptop.pull().await.unwrap(),
+            vec![deletion("a", 11), deletion("b", 9)]
+        );
+        assert_eq!(desktop.pull().await.unwrap(), vec![deletion("a", 10)]);
+        assert_eq!(
+            tablet.pull().await.unwrap(),
+            vec![deletion("a", 11), deletion("b", 9)]
+        );
+        assert!(folder.join("laptop.flux-sync.json").exists());
+
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+
+    #[tokio::test]
+    async fn unreadable_files_are_skipped() {
+        let folder = folder("unreadable");
+        let laptop = FolderSyncGateway::new(folder.clone(), "laptop");
+        FolderSyncGateway::new(folder.clone(), "desktop")
+            .push(&[deletion("a", 10)])
+            .await
+            .unwrap();
+        std::fs::write(folder.join("phone.flux-sync.json"), "{\"format\": 1, \"dev").unwrap();
+
+        assert_eq!(laptop.pull().await.unwrap(), vec![deletion("a", 10)]);
+
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+
+    #[test]
+    fn device_names_are_safe_file_names() {
+        let gateway = FolderSyncGateway::new(PathBuf::from("/sync"), "Jane's Mac/Book");
+
+        assert_eq!(
+            gateway.own_file(),
+            PathBuf::from("/sync/Jane-s-Mac-Book.flux-sync.json")
+        );
+    }
+}
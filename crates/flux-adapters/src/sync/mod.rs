@@ -0,0 +1,3 @@
+mod folder;
+
+pub use folder::FolderSyncGateway;
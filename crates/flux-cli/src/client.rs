@@ -1,8 +1,5 @@
-use flux_protocol::{Request, Response};
-use interprocess::local_socket::{
-    tokio::{prelude::*, Stream},
-    GenericFilePath,
-};
+use flux_protocol::{decode, encode, socket, Request, Response, PROTOCOL_VERSION};
+use interprocess::local_socket::tokio::{prelude::*, Stream};
 use std::path::PathBuf;
 use std::time::Duration;
 use thiserror::Error;
@@ -23,6 +20,11 @@ pub enum ClientError {
     Serialization(String),
     #[error("erreur d'entrée/sortie: {0}")]
     InputOutput(String),
+    #[error("le daemon (version {daemon_version}) n'est pas compatible avec flux {client_version}. Mettez à jour Flux, puis lancez `flux daemon restart`")]
+    VersionMismatch {
+        daemon_version: String,
+        client_version: String,
+    },
 }
 
 pub struct DaemonClient {
@@ -32,25 +34,13 @@ pub struct DaemonClient {
 
 impl DaemonClient {
     pub fn new() -> Self {
-        let socket_path = Self::default_socket_path();
+        let socket_path = socket::socket_path();
         Self {
             socket_path,
             timeout: DEFAULT_TIMEOUT,
         }
     }
 
-    #[cfg(unix)]
-    fn default_socket_path() -> PathBuf {
-        let uid = unsafe { libc::getuid() };
-        PathBuf::from(format!("/run/user/{}/flux.sock", uid))
-    }
-
-    #[cfg(windows)]
-    fn default_socket_path() -> PathBuf {
-        let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_else(|_| ".".to_string());
-        PathBuf::from(format!(r"{}\flux\flux.sock", local_app_data))
-    }
-
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
@@ -58,14 +48,47 @@ impl DaemonClient {
 
     pub async fn send(&self, request: Request) -> Result<Response, ClientError> {
         let stream = self.connect().await?;
-        self.send_request(stream, request).await
+        match self.send_request(stream, request).await {
+            Err(error @ (ClientError::Serialization(_) | ClientError::InputOutput(_))) => {
+                Err(self.check_version().await.err().unwrap_or(error))
+            }
+            result => result,
+        }
+    }
+
+    pub async fn check_version(&self) -> Result<(), ClientError> {
+        let stream = self.connect().await?;
+        let handshake = Request::Handshake {
+            protocol_version: PROTOCOL_VERSION,
+            client_version: env!("CARGO_PKG_VERSION").to_string(),
+        };
+
+        match self.send_request(stream, handshake).await {
+            Ok(Response::Handshake {
+                protocol_version,
+                accepted,
+                ..
+            }) if accepted && protocol_version == PROTOCOL_VERSION => Ok(()),
+            Ok(Response::Handshake { daemon_version, .. }) => Err(self.mismatch(daemon_version)),
+            Ok(_) | Err(ClientError::Serialization(_) | ClientError::InputOutput(_)) => {
+                Err(self.mismatch("inconnue".to_string()))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    fn mismatch(&self, daemon_version: String) -> ClientError {
+        ClientError::VersionMismatch {
+            daemon_version,
+            client_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
     }
 
     async fn connect(&self) -> Result<Stream, ClientError> {
+        socket::check_socket_directory(&self.socket_path)
+            .map_err(|error| ClientError::Connection(error.to_string()))?;
         let connect_future = Stream::connect(
-            self.socket_path
-                .as_os_str()
-                .to_fs_name::<GenericFilePath>()
+            socket::socket_name(&self.socket_path)
                 .map_err(|error| ClientError::Connection(error.to_string()))?,
         );
 
@@ -81,8 +104,8 @@ impl DaemonClient {
         mut stream: Stream,
         request: Request,
     ) -> Result<Response, ClientError> {
-        let request_bytes = bincode::serialize(&request)
-            .map_err(|error| ClientError::Serialization(error.to_string()))?;
+        let request_bytes =
+            encode(&request).map_err(|error| ClientError::Serialization(error.to_string()))?;
 
         let request_length = (request_bytes.len() as u32).to_le_bytes();
 
@@ -113,8 +136,7 @@ impl DaemonClient {
             .map_err(|_| ClientError::Timeout)?
             .map_err(|error| ClientError::InputOutput(error.to_string()))?;
 
-        bincode::deserialize(&response_bytes)
-            .map_err(|error| ClientError::Serialization(error.to_string()))
+        decode(&response_bytes).map_err(|error| ClientError::Serialization(error.to_string()))
     }
 }
 
@@ -198,11 +220,11 @@ mod unix_tests {
             let mut payload = vec![0u8; length];
             stream.read_exact(&mut payload).await.unwrap();
 
-            let request: Request = bincode::deserialize(&payload).unwrap();
+            let request: Request = decode(&payload).unwrap();
             assert!(matches!(request, Request::Ping));
 
             let response = Response::Pong;
-            let response_bytes = bincode::serialize(&response).unwrap();
+            let response_bytes = encode(&response).unwrap();
             let response_length = (response_bytes.len() as u32).to_le_bytes();
 
             stream.write_all(&response_length).await.unwrap();
@@ -250,7 +272,7 @@ mod unix_tests {
             let mut payload = vec![0u8; length];
             stream.read_exact(&mut payload).await.unwrap();
 
-            let request: Request = bincode::deserialize(&payload).unwrap();
+            let request: Request = decode(&payload).unwrap();
             assert!(matches!(request, Request::GetStatus));
 
             let response = Response::SessionStatus {
@@ -258,8 +280,10 @@ mod unix_tests {
                 remaining_seconds: 1500,
                 mode: Some(flux_protocol::FocusMode::AiAssisted),
                 paused: false,
+                preparing_seconds: None,
+                strict: false,
             };
-            let response_bytes = bincode::serialize(&response).unwrap();
+            let response_bytes = encode(&response).unwrap();
             let response_length = (response_bytes.len() as u32).to_le_bytes();
 
             stream.write_all(&response_length).await.unwrap();
@@ -291,4 +315,54 @@ mod unix_tests {
         server_handle.await.unwrap();
         cleanup_socket(&unique_path);
     }
+
+    #[tokio::test]
+    async fn unreadable_exchange_is_reported_as_version_mismatch() {
+        let socket_path = test_socket_path();
+        let unique_path = PathBuf::from(format!("{}-mismatch", socket_path.display()));
+        cleanup_socket(&unique_path);
+
+        let server_path = unique_path.clone();
+        let server_handle = tokio::spawn(async move {
+            let listener = ListenerOptions::new()
+                .name(
+                    server_path
+                        .as_os_str()
+                        .to_fs_name::<GenericFilePath>()
+                        .unwrap(),
+                )
+                .create_tokio()
+                .unwrap();
+
+            for _ in 0..2 {
+                let mut stream = listener.accept().await.unwrap();
+
+                let mut length_buffer = [0u8; 4];
+                stream.read_exact(&mut length_buffer).await.unwrap();
+                let length = u32::from_le_bytes(length_buffer) as usize;
+
+                let mut payload = vec![0u8; length];
+                stream.read_exact(&mut payload).await.unwrap();
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let mut client = DaemonClient::new();
+        client.socket_path = unique_path.clone();
+
+        let result = client.send(Request::GetStatus).await;
+
+        assert!(
+            matches!(
+                &result,
+                Err(ClientError::VersionMismatch { daemon_version, .. }) if daemon_version == "inconnue"
+            ),
+            "expected VersionMismatch, got {:?}",
+            result
+        );
+
+        server_handle.await.unwrap();
+        cleanup_socket(&unique_path);
+    }
 }
@@ -0,0 +1,38 @@
+use anyhow::{bail, Context, Result};
+use flux_adapters::SqliteSessionRepository;
+use flux_core::{Config, SessionRepository, Translator};
+
+use super::start;
+
+/// Starts a session with the mode, length and tags of the last one
+pub async fn execute() -> Result<()> {
+    let translator = Config::load()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default();
+
+    let database_path = dirs::data_dir()
+        .context("cannot find data directory")?
+        .join("flux")
+        .join("sessions.db");
+
+    let last_session = if database_path.exists() {
+        SqliteSessionRepository::new(&database_path)
+            .map_err(|error| anyhow::anyhow!("database access error: {}", error))?
+            .find_latest()
+            .map_err(|error| anyhow::anyhow!("read error: {}", error))?
+    } else {
+        None
+    };
+
+    let Some(session) = last_session else {
+        bail!("{}", translator.get("command.again_no_session"));
+    };
+
+    start::execute(
+        session.planned_minutes,
+        Some(session.mode.as_str().to_string()),
+        session.tags,
+        false,
+    )
+    .await
+}
@@ -57,7 +57,7 @@ pub async fn execute(skip_confirmation: bool) -> Result<()> {
 
 fn get_translator() -> Translator {
     Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
 
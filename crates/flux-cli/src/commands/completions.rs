@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use clap::builder::PossibleValuesParser;
+use clap::{Arg, Command};
+use clap_complete::Shell;
+use flux_core::{Config, ConfigKey};
+
+pub fn execute(command: Command, shell: Shell) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let mut command = with_dynamic_values(command, &config);
+    let name = command.get_name().to_string();
+
+    clap_complete::generate(shell, &mut command, name, &mut std::io::stdout());
+    Ok(())
+}
+
+/// Shells cannot ask flux for candidates while completing, so the profile
+/// names, configuration keys and configured apps are captured when the script
+/// is generated. Regenerate it after adding a profile or an app.
+fn with_dynamic_values(command: Command, config: &Config) -> Command {
+    let profiles: Vec<String> = config
+        .profile_names()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let keys: Vec<String> = ConfigKey::all()
+        .iter()
+        .map(|key| key.name.to_string())
+        .collect();
+    let distractions = config.distractions();
+
+    command
+        .mut_subcommand("profile", |profile| {
+            profile
+                .mut_subcommand("show", |show| show.mut_arg("name", values(&profiles)))
+                .mut_subcommand("use", |using| using.mut_arg("name", values(&profiles)))
+        })
+        .mut_subcommand("config", |config| {
+            config
+                .mut_subcommand("get", |get| get.mut_arg("key", values(&keys)))
+                .mut_subcommand("set", |set| set.mut_arg("key", values(&keys)))
+        })
+        .mut_subcommand("distractions", |command| {
+            command
+                .mut_arg("profile", values(&profiles))
+                .mut_subcommand("remove", |remove| {
+                    remove.mut_arg("app", values(&sorted(&distractions.apps)))
+                })
+                .mut_subcommand("remove-pattern", |remove| {
+                    remove.mut_arg("pattern", values(&sorted(&distractions.title_patterns)))
+                })
+                .mut_subcommand("whitelist", |whitelist| {
+                    whitelist.mut_subcommand("remove", |remove| {
+                        remove.mut_arg("app", values(&sorted(&distractions.whitelist_apps)))
+                    })
+                })
+                .mut_subcommand("friction", |friction| {
+                    friction.mut_subcommand("remove", |remove| {
+                        remove.mut_arg("app", values(&sorted(&distractions.friction_apps)))
+                    })
+                })
+        })
+}
+
+/// Without known values the argument keeps the shell's default completion
+fn values(candidates: &[String]) -> impl FnOnce(Arg) -> Arg + '_ {
+    move |arg| {
+        if candidates.is_empty() {
+            arg
+        } else {
+            arg.value_parser(PossibleValuesParser::new(candidates.iter().cloned()))
+        }
+    }
+}
+
+fn sorted(values: &HashSet<String>) -> Vec<String> {
+    let mut sorted: Vec<String> = values.iter().cloned().collect();
+    sorted.sort();
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn config_with_profiles() -> Config {
+        toml::from_str(
+            r#"
+[profile.default.distractions]
+apps = ["slack", "discord"]
+
+[profile.deep.distractions]
+apps = []
+"#,
+        )
+        .unwrap()
+    }
+
+    fn possible_values(command: &Command, path: &[&str], arg: &str) -> Vec<String> {
+        let mut current = command;
+        for name in path {
+            current = current.find_subcommand(name).unwrap();
+        }
+        current
+            .get_arguments()
+            .find(|candidate| candidate.get_id() == arg)
+            .unwrap()
+            .get_possible_values()
+            .iter()
+            .map(|value| value.get_name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn profile_names_and_distraction_apps_become_candidates() {
+        let command = with_dynamic_values(crate::Cli::command(), &config_with_profiles());
+
+        let mut profiles = possible_values(&command, &["profile", "use"], "name");
+        profiles.sort();
+        assert_eq!(profiles, vec!["deep", "default"]);
+        assert_eq!(
+            possible_values(&command, &["distractions", "remove"], "app"),
+            vec!["discord", "slack"]
+        );
+        assert!(possible_values(&command, &["config", "set"], "key")
+            .contains(&"focus.default_duration_minutes".to_string()));
+    }
+
+    #[test]
+    fn empty_lists_keep_free_form_arguments() {
+        let config: Config = toml::from_str("[profile.default.distractions]\napps = []\n").unwrap();
+
+        let command = with_dynamic_values(crate::Cli::command(), &config);
+
+        assert!(possible_values(&command, &["distractions", "remove"], "app").is_empty());
+    }
+
+    #[test]
+    fn every_shell_script_lists_profile_names() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let mut command = with_dynamic_values(crate::Cli::command(), &config_with_profiles());
+            let mut script = Vec::new();
+
+            clap_complete::generate(shell, &mut command, "flux", &mut script);
+
+            let script = String::from_utf8(script).unwrap();
+            assert!(
+                script.contains("deep"),
+                "{shell} script lacks profile names"
+            );
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use anyhow::Result;
+use flux_core::{Config, Translator};
+
+pub fn get(key: &str) -> Result<()> {
+    let config = Config::load()?;
+    println!("{}", config.get_value(key)?);
+    Ok(())
+}
+
+pub fn set(key: &str, value: &str) -> Result<()> {
+    Config::set_value(key, value)?;
+
+    let config = Config::load()?;
+    let translator = Translator::new(config.language());
+    let stored_value = config.get_value(key)?;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.config_updated",
+            &[("key", key), ("value", &stored_value)]
+        )
+    );
+    Ok(())
+}
+
+pub fn list() -> Result<()> {
+    let config = Config::load()?;
+    let translator = Translator::new(config.language());
+
+    println!(
+        "\n{}: {}\n",
+        translator.get("command.config_list_header"),
+        config.active_profile_name()
+    );
+
+    for (key, value) in config.values() {
+        println!("  {} = {}", key, value);
+    }
+
+    println!();
+    Ok(())
+}
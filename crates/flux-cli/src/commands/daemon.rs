@@ -0,0 +1,155 @@
+use crate::client::{ClientError, DaemonClient};
+use crate::daemon_launcher;
+use anyhow::Result;
+use flux_core::{Config, Translator};
+use flux_protocol::{Request, Response};
+
+pub async fn status() -> Result<()> {
+    let translator = get_translator();
+    let client = DaemonClient::new();
+
+    match client.send(Request::GetDaemonInfo).await {
+        Ok(Response::DaemonInfo {
+            version,
+            pid,
+            uptime_seconds,
+            socket_path,
+            database_path,
+            tray_active,
+            tracker_active,
+        }) => {
+            println!("\n{}\n", translator.get("command.daemon_status_header"));
+            println!(
+                "  {}",
+                translator.format("command.daemon_status_version", &[("version", &version)])
+            );
+            println!(
+                "  {}",
+                translator.format("command.daemon_status_pid", &[("pid", &pid.to_string())])
+            );
+            println!(
+                "  {}",
+                translator.format(
+                    "command.daemon_status_uptime",
+                    &[("uptime", &format_uptime(uptime_seconds))]
+                )
+            );
+            println!(
+                "  {}",
+                translator.format("command.daemon_status_socket", &[("path", &socket_path)])
+            );
+            match database_path {
+                Some(path) => println!(
+                    "  {}",
+                    translator.format("command.daemon_status_database", &[("path", &path)])
+                ),
+                None => println!(
+                    "  {}",
+                    translator.get("command.daemon_status_database_unavailable")
+                ),
+            }
+            println!(
+                "  {}",
+                translator.format(
+                    "command.daemon_status_tray",
+                    &[("state", &yes_no(tray_active, &translator))]
+                )
+            );
+            println!(
+                "  {}",
+                translator.format(
+                    "command.daemon_status_tracker",
+                    &[("state", &yes_no(tracker_active, &translator))]
+                )
+            );
+            println!();
+            Ok(())
+        }
+        Ok(Response::Error { message }) => Err(anyhow::anyhow!(message)),
+        Ok(_) => Err(anyhow::anyhow!(translator.get("error.unexpected_response"))),
+        Err(ClientError::DaemonNotRunning) => {
+            println!("{}", translator.get("error.daemon_not_running"));
+            println!("{}", translator.get("error.daemon_not_running_hint"));
+            Ok(())
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+pub async fn stop() -> Result<()> {
+    let translator = get_translator();
+
+    if shutdown(&DaemonClient::new(), &translator).await? {
+        println!("{}", translator.get("command.daemon_stopped"));
+    } else {
+        println!("{}", translator.get("error.daemon_not_running"));
+    }
+
+    Ok(())
+}
+
+pub async fn restart() -> Result<()> {
+    let translator = get_translator();
+
+    shutdown(&DaemonClient::new(), &translator).await?;
+    daemon_launcher::ensure_daemon_running().await?;
+    println!("{}", translator.get("command.daemon_restarted"));
+
+    Ok(())
+}
+
+pub(crate) async fn shutdown(client: &DaemonClient, translator: &Translator) -> Result<bool> {
+    match client.send(Request::Shutdown).await {
+        Ok(Response::Ok) => {
+            daemon_launcher::wait_for_shutdown().await?;
+            Ok(true)
+        }
+        Ok(Response::Error { message }) => Err(anyhow::anyhow!(message)),
+        Ok(_) => Err(anyhow::anyhow!(translator.get("error.unexpected_response"))),
+        Err(ClientError::DaemonNotRunning) => Ok(false),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn yes_no(value: bool, translator: &Translator) -> String {
+    if value {
+        translator.get("command.daemon_status_yes")
+    } else {
+        translator.get("command.daemon_status_no")
+    }
+}
+
+fn format_uptime(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let hours = (seconds % 86_400) / 3600;
+    let minutes = (seconds % 3600) / 60;
+
+    if days > 0 {
+        format!("{}d {}h {:02}min", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {:02}min", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}min", minutes)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+fn get_translator() -> Translator {
+    Config::load()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uptime_uses_largest_relevant_units() {
+        assert_eq!(format_uptime(42), "42s");
+        assert_eq!(format_uptime(125), "2min");
+        assert_eq!(format_uptime(3 * 3600 + 5 * 60), "3h 05min");
+        assert_eq!(format_uptime(2 * 86_400 + 3600 + 60), "2d 1h 01min");
+    }
+}
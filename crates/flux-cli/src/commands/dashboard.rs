@@ -18,7 +18,7 @@ pub fn execute() -> Result<()> {
 
 fn get_translator() -> Translator {
     Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
 
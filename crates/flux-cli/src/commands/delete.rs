@@ -32,7 +32,7 @@ pub async fn execute(session_id: i64) -> Result<()> {
 
 fn get_translator() -> Translator {
     Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
 
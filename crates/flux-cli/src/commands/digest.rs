@@ -1,31 +1,88 @@
-use std::collections::HashMap;
-
 use anyhow::{Context, Result};
-use chrono::{Duration, Utc};
-use flux_adapters::{SqliteAppTrackingRepository, SqliteSessionRepository};
+use chrono::{DateTime, Duration, Local, Utc};
+use flux_adapters::{Database, SqliteAppTrackingRepository, SqliteSessionRepository};
 use flux_core::{
-    AppTrackingRepository, AppUsage, Config, DigestStats, DistractionConfig, Session,
-    SessionRepository, Translator, WeekStats,
+    AppTrackingRepository, AppUsage, Config, DigestInsight, DigestStats, DistractionConfig,
+    FocusMode, PrivacyConfig, Session, SessionRepository, Streak, Translator, WeekStats,
 };
+use serde::Serialize;
+
+use super::render::{format_duration, percent, OutputFormat, Report, Row, Section, Value};
+
+const TOP_APPLICATIONS_LIMIT: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestFormat {
+    Json,
+    Rendered(OutputFormat),
+}
+
+impl DigestFormat {
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "json" => Some(DigestFormat::Json),
+            other => OutputFormat::from_str(other).map(DigestFormat::Rendered),
+        }
+    }
+}
 
-pub async fn execute() -> Result<()> {
+#[derive(Serialize)]
+struct DigestOutput<'a> {
+    generated_at: DateTime<Utc>,
+    current_week: &'a WeekStats,
+    previous_week: Option<&'a WeekStats>,
+    deltas: DigestDeltas,
+    top_focus_applications: Vec<ApplicationOutput>,
+    top_distractions: Vec<ApplicationOutput>,
+    insights: Vec<DigestInsight>,
+    streak: Streak,
+}
+
+#[derive(Serialize)]
+struct DigestDeltas {
+    total_seconds: Option<i64>,
+    distraction_seconds: Option<i64>,
+    session_count: Option<i64>,
+    focus_score: Option<i8>,
+}
+
+#[derive(Serialize)]
+struct ApplicationOutput {
+    name: String,
+    seconds: i64,
+}
+
+pub async fn execute(format: DigestFormat) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
-    let repository = open_repository()?;
+    let translator = Translator::new(config.language());
+    let database = open_database(&config.privacy)?;
 
-    let digest_stats = compute_digest_stats(&repository, config.distractions())?;
+    let digest_stats = compute_digest_stats(&database, config.distractions())?;
+
+    let format = match format {
+        DigestFormat::Json => {
+            println!("{}", digest_to_json(&digest_stats, Utc::now())?);
+            return Ok(());
+        }
+        DigestFormat::Rendered(format) => format,
+    };
 
-    if digest_stats.current_week.session_count == 0 {
+    if digest_stats.current_week.session_count == 0 && !format.is_machine_readable() {
         println!("{}", translator.get("command.digest_no_data"));
         return Ok(());
     }
 
-    display_digest(&digest_stats, &translator);
+    let report = if digest_stats.current_week.session_count == 0 {
+        Report::default()
+    } else {
+        digest_report(&digest_stats, &translator)
+    };
+    print!("{}", format.renderer().render(&report));
 
     Ok(())
 }
 
-fn open_repository() -> Result<SqliteSessionRepository> {
+fn open_database(privacy: &PrivacyConfig) -> Result<Database> {
     let data_dir = dirs::data_dir()
         .context("cannot find data directory")?
         .join("flux");
@@ -36,14 +93,16 @@ fn open_repository() -> Result<SqliteSessionRepository> {
         anyhow::bail!("no session data. Start a session first with 'flux start'.");
     }
 
-    SqliteSessionRepository::new(&database_path)
+    Database::open(&database_path)
+        .and_then(|database| database.with_privacy(privacy))
         .map_err(|error| anyhow::anyhow!("database access error: {}", error))
 }
 
 fn compute_digest_stats(
-    repository: &SqliteSessionRepository,
+    database: &Database,
     distraction_config: &DistractionConfig,
 ) -> Result<DigestStats> {
+    let repository = SqliteSessionRepository::with_database(database.clone());
     let now = Utc::now();
 
     let current_start = now - Duration::days(7);
@@ -59,8 +118,8 @@ fn compute_digest_stats(
     let current_session_ids: Vec<i64> = current_sessions.iter().filter_map(|s| s.id).collect();
     let previous_session_ids: Vec<i64> = previous_sessions.iter().filter_map(|s| s.id).collect();
 
-    let current_app_usages = fetch_app_tracking(&current_session_ids);
-    let previous_app_usages = fetch_app_tracking(&previous_session_ids);
+    let current_app_usages = fetch_app_tracking(database, &current_session_ids);
+    let previous_app_usages = fetch_app_tracking(database, &previous_session_ids);
 
     let current_week =
         compute_week_stats(&current_sessions, &current_app_usages, distraction_config);
@@ -74,27 +133,18 @@ fn compute_digest_stats(
         ))
     };
 
-    Ok(DigestStats::new(current_week, previous_week))
-}
-
-fn fetch_app_tracking(session_ids: &[i64]) -> Vec<AppUsage> {
-    let data_dir = match dirs::data_dir() {
-        Some(dir) => dir.join("flux"),
-        None => return Vec::new(),
-    };
-
-    let database_path = data_dir.join("sessions.db");
-
-    if !database_path.exists() {
-        return Vec::new();
-    }
+    let all_sessions = repository
+        .find_completed_since(DateTime::UNIX_EPOCH)
+        .map_err(|error| anyhow::anyhow!("read error: {}", error))?;
+    let streak = Streak::from_sessions(&all_sessions, Local::now().date_naive());
 
-    let repository = match SqliteAppTrackingRepository::new(&database_path) {
-        Ok(repo) => repo,
-        Err(_) => return Vec::new(),
-    };
+    Ok(DigestStats::new(current_week, previous_week).with_streak(streak))
+}
 
-    repository.find_by_sessions(session_ids).unwrap_or_default()
+fn fetch_app_tracking(database: &Database, session_ids: &[i64]) -> Vec<AppUsage> {
+    SqliteAppTrackingRepository::with_database(database.clone())
+        .find_by_sessions(session_ids)
+        .unwrap_or_default()
 }
 
 fn compute_week_stats(
@@ -102,181 +152,146 @@ fn compute_week_stats(
     app_usages: &[AppUsage],
     distraction_config: &DistractionConfig,
 ) -> WeekStats {
-    let mut total_seconds = 0i64;
-    let mut by_mode: HashMap<String, i64> = HashMap::new();
-
-    for session in sessions {
-        let duration = session.duration_seconds.unwrap_or(0);
-        total_seconds += duration;
-
-        let mode_key = session.mode.to_string();
-        *by_mode.entry(mode_key).or_insert(0) += duration;
-    }
-
-    let mut focus_applications: HashMap<String, i64> = HashMap::new();
-    let mut distraction_applications: HashMap<String, i64> = HashMap::new();
-    let mut total_distraction_seconds = 0i64;
-
-    for usage in app_usages {
-        if distraction_config.is_distraction(&usage.application_name) {
-            *distraction_applications
-                .entry(usage.application_name.clone())
-                .or_insert(0) += usage.duration_seconds;
-            total_distraction_seconds += usage.duration_seconds;
-        } else {
-            *focus_applications
-                .entry(usage.application_name.clone())
-                .or_insert(0) += usage.duration_seconds;
-        }
-    }
-
-    WeekStats {
-        total_seconds,
-        session_count: sessions.len(),
-        by_mode,
-        focus_applications,
-        distraction_applications,
-        total_distraction_seconds,
-        average_focus_score: 0,
-    }
+    WeekStats::compute(sessions, app_usages, |application| {
+        distraction_config.is_distraction(application)
+    })
 }
 
-fn display_digest(stats: &DigestStats, translator: &Translator) {
-    println!();
-    println!("{}", translator.get("command.digest_header"));
-    println!();
-
-    let time_delta = format_delta(stats.time_delta(), translator);
-    println!(
-        "{:16}: {} {}",
-        translator.get("command.digest_total_time"),
-        format_duration(stats.current_week.total_seconds),
-        time_delta
-    );
-
-    let avg_duration = if stats.current_week.session_count > 0 {
-        stats.current_week.total_seconds / stats.current_week.session_count as i64
+fn digest_report(stats: &DigestStats, translator: &Translator) -> Report {
+    let week = &stats.current_week;
+    let mut report = Report {
+        heading: vec![translator.get("command.digest_header")],
+        ..Report::default()
+    };
+
+    let average_duration = if week.session_count > 0 {
+        week.total_seconds / week.session_count as i64
     } else {
         0
     };
-
-    println!(
-        "{:16}: {} ({}: {})",
-        translator.get("command.digest_sessions"),
-        stats.current_week.session_count,
-        translator.get("command.digest_average"),
-        format_duration(avg_duration)
-    );
-
-    println!();
-
-    if !stats.current_week.by_mode.is_empty() {
-        println!("{}:", translator.get("command.digest_by_mode"));
-
-        let mut modes: Vec<_> = stats.current_week.by_mode.iter().collect();
-        modes.sort_by(|a, b| b.1.cmp(a.1));
-
-        let total = stats.current_week.total_seconds.max(1) as f64;
-
-        for (index, (mode, seconds)) in modes.iter().enumerate() {
-            let percentage = (**seconds as f64 / total * 100.0) as u32;
-            let prefix = if index == modes.len() - 1 {
-                "└──"
-            } else {
-                "├──"
-            };
-            println!(
-                "{} {:14} {:>8} ({}%)",
-                prefix,
-                format!("{}:", mode),
-                format_duration(**seconds),
-                percentage
-            );
-        }
-        println!();
-    }
-
-    display_top_apps(
-        &stats.current_week.focus_applications,
-        &translator.get("command.digest_top_focus"),
-        5,
-    );
-
-    display_distractions(stats, translator);
-
-    println!();
-}
-
-fn display_top_apps(applications: &HashMap<String, i64>, header: &str, limit: usize) {
-    if applications.is_empty() {
-        return;
+    let mut summary = vec![
+        Row::new(
+            "total_time",
+            translator.get("command.digest_total_time"),
+            Value::Duration(week.total_seconds),
+        )
+        .with_detail(format_delta(stats.time_delta(), translator)),
+        Row::new(
+            "sessions",
+            translator.get("command.digest_sessions"),
+            Value::Count(week.session_count as i64),
+        )
+        .with_detail(format!(
+            "({}: {})",
+            translator.get("command.digest_average"),
+            format_duration(average_duration)
+        )),
+    ];
+    if let Some(rate) = week.completion_rate {
+        summary.push(Row::new(
+            "completion_rate",
+            translator.get("command.digest_completion_rate"),
+            Value::Percent(rate),
+        ));
     }
-
-    println!("{}:", header);
-
-    let mut apps: Vec<_> = applications.iter().collect();
-    apps.sort_by(|a, b| b.1.cmp(a.1));
-
-    let top_apps: Vec<_> = apps.into_iter().take(limit).collect();
-
-    for (index, (app, seconds)) in top_apps.iter().enumerate() {
-        let prefix = if index == top_apps.len() - 1 {
-            "└──"
-        } else {
-            "├──"
-        };
-        println!(
-            "{} {:14} {:>8}",
-            prefix,
-            format!("{}:", app),
-            format_duration(**seconds)
-        );
+    if stats.streak.current_days > 0 {
+        summary.push(Row::new(
+            "streak",
+            translator.get("command.digest_streak"),
+            Value::Text(translator.format(
+                "command.streak_days",
+                &[
+                    ("current", &stats.streak.current_days.to_string()),
+                    ("longest", &stats.streak.longest_days.to_string()),
+                ],
+            )),
+        ));
     }
-    println!();
+    report.push(Section::summary("summary", summary));
+
+    let mut modes: Vec<_> = week.by_mode.iter().collect();
+    modes.sort_by(|a, b| b.1.cmp(a.1));
+    let modes = modes
+        .into_iter()
+        .map(|(mode, seconds)| {
+            Row::new(
+                mode.as_str(),
+                translator.mode_name(&FocusMode::from_stored(mode)),
+                Value::Duration(*seconds),
+            )
+            .with_percent(percent(*seconds, week.total_seconds))
+        })
+        .collect();
+    report.push(Section::breakdown(
+        "by_mode",
+        translator.get("command.digest_by_mode"),
+        modes,
+    ));
+
+    report.push(top_applications_section(
+        "focus_applications",
+        translator.get("command.digest_top_focus"),
+        week.top_focus_applications(TOP_APPLICATIONS_LIMIT),
+    ));
+    report.push(top_applications_section(
+        "distractions",
+        format!(
+            "{} ({}{})",
+            translator.get("command.digest_distractions"),
+            format_duration(week.total_distraction_seconds),
+            format_delta(stats.distraction_delta(), translator)
+        ),
+        week.top_distraction_applications(TOP_APPLICATIONS_LIMIT),
+    ));
+
+    report
 }
 
-fn display_distractions(stats: &DigestStats, translator: &Translator) {
-    if stats.current_week.distraction_applications.is_empty() {
-        return;
-    }
-
-    let distraction_delta = format_delta(stats.distraction_delta(), translator);
-    println!(
-        "{} ({}{}):",
-        translator.get("command.digest_distractions"),
-        format_duration(stats.current_week.total_distraction_seconds),
-        distraction_delta
-    );
-
-    let mut apps: Vec<_> = stats.current_week.distraction_applications.iter().collect();
-    apps.sort_by(|a, b| b.1.cmp(a.1));
-
-    let top_apps: Vec<_> = apps.into_iter().take(5).collect();
-
-    for (index, (app, seconds)) in top_apps.iter().enumerate() {
-        let prefix = if index == top_apps.len() - 1 {
-            "└──"
-        } else {
-            "├──"
-        };
-        println!(
-            "{} {:14} {:>8}",
-            prefix,
-            format!("{}:", app),
-            format_duration(**seconds)
-        );
-    }
+fn top_applications_section(
+    id: &'static str,
+    title: String,
+    applications: Vec<(String, i64)>,
+) -> Section {
+    let rows = applications
+        .into_iter()
+        .map(|(name, seconds)| Row::new(name.as_str(), name.as_str(), Value::Duration(seconds)))
+        .collect();
+    Section::breakdown(id, title, rows)
 }
 
-fn format_duration(seconds: i64) -> String {
-    let hours = seconds / 3600;
-    let minutes = (seconds % 3600) / 60;
+fn digest_to_json(stats: &DigestStats, generated_at: DateTime<Utc>) -> Result<String> {
+    let to_output = |applications: Vec<(String, i64)>| {
+        applications
+            .into_iter()
+            .map(|(name, seconds)| ApplicationOutput { name, seconds })
+            .collect()
+    };
 
-    if hours > 0 {
-        format!("{}h {:02}min", hours, minutes)
-    } else {
-        format!("{}min", minutes)
-    }
+    let output = DigestOutput {
+        generated_at,
+        current_week: &stats.current_week,
+        previous_week: stats.previous_week.as_ref(),
+        deltas: DigestDeltas {
+            total_seconds: stats.time_delta(),
+            distraction_seconds: stats.distraction_delta(),
+            session_count: stats.session_delta(),
+            focus_score: stats.score_delta(),
+        },
+        top_focus_applications: to_output(
+            stats
+                .current_week
+                .top_focus_applications(TOP_APPLICATIONS_LIMIT),
+        ),
+        top_distractions: to_output(
+            stats
+                .current_week
+                .top_distraction_applications(TOP_APPLICATIONS_LIMIT),
+        ),
+        insights: stats.insights(),
+        streak: stats.streak,
+    };
+
+    Ok(serde_json::to_string_pretty(&output)?)
 }
 
 fn format_delta(delta: Option<i64>, translator: &Translator) -> String {
@@ -296,24 +311,10 @@ fn format_delta(delta: Option<i64>, translator: &Translator) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn format_duration_shows_hours_and_minutes() {
-        assert_eq!(format_duration(3661), "1h 01min");
-        assert_eq!(format_duration(7200), "2h 00min");
-    }
-
-    #[test]
-    fn format_duration_shows_only_minutes_when_under_hour() {
-        assert_eq!(format_duration(1500), "25min");
-        assert_eq!(format_duration(60), "1min");
-        assert_eq!(format_duration(0), "0min");
-    }
+    use std::collections::HashMap;
 
     #[test]
     fn compute_week_stats_aggregates_correctly() {
-        use flux_core::FocusMode;
-
         let sessions = vec![
             create_test_session(FocusMode::AiAssisted, 1800),
             create_test_session(FocusMode::AiAssisted, 1200),
@@ -329,6 +330,65 @@ mod tests {
         assert_eq!(stats.by_mode.get("review"), Some(&900));
     }
 
+    #[test]
+    fn digest_json_contains_weeks_deltas_and_insights() {
+        let current = WeekStats {
+            total_seconds: 3600,
+            session_count: 2,
+            by_mode: HashMap::from([("review".to_string(), 3600)]),
+            focus_applications: HashMap::from([("code".to_string(), 3000)]),
+            ..Default::default()
+        };
+        let previous = WeekStats {
+            total_seconds: 1800,
+            session_count: 1,
+            ..Default::default()
+        };
+        let stats = DigestStats::new(current, Some(previous)).with_streak(Streak {
+            current_days: 5,
+            longest_days: 9,
+            active_today: true,
+        });
+
+        let json = digest_to_json(&stats, Utc::now()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["current_week"]["total_seconds"], 3600);
+        assert_eq!(value["previous_week"]["session_count"], 1);
+        assert_eq!(value["deltas"]["total_seconds"], 1800);
+        assert_eq!(value["deltas"]["session_count"], 1);
+        assert_eq!(value["top_focus_applications"][0]["name"], "code");
+        assert_eq!(value["insights"][0]["kind"], "focus_time_increased");
+        assert_eq!(value["streak"]["current_days"], 5);
+        assert_eq!(value["streak"]["longest_days"], 9);
+    }
+
+    #[test]
+    fn digest_json_uses_null_without_previous_week() {
+        let stats = DigestStats::new(WeekStats::default(), None);
+
+        let json = digest_to_json(&stats, Utc::now()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert!(value["previous_week"].is_null());
+        assert!(value["deltas"]["total_seconds"].is_null());
+        assert_eq!(value["insights"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn digest_format_parses_known_values() {
+        assert_eq!(DigestFormat::from_str("JSON"), Some(DigestFormat::Json));
+        assert_eq!(
+            DigestFormat::from_str("text"),
+            Some(DigestFormat::Rendered(OutputFormat::Pretty))
+        );
+        assert_eq!(
+            DigestFormat::from_str("tsv"),
+            Some(DigestFormat::Rendered(OutputFormat::Tsv))
+        );
+        assert_eq!(DigestFormat::from_str("yaml"), None);
+    }
+
     fn create_test_session(mode: flux_core::FocusMode, duration: i64) -> Session {
         let mut session = Session::start(mode);
         session.duration_seconds = Some(duration);
@@ -336,15 +396,18 @@ mod tests {
     }
 
     fn create_test_distraction_config() -> DistractionConfig {
-        use std::collections::HashSet;
+        use std::collections::{HashMap, HashSet};
         DistractionConfig {
             apps: HashSet::from(["discord".to_string(), "slack".to_string()]),
             title_patterns: HashSet::new(),
             alert_enabled: false,
             alert_after_seconds: 30,
+            alert_mode: flux_core::DistractionAlertMode::Immediate,
             friction_apps: HashSet::new(),
             friction_delay_seconds: 10,
+            friction_overrides: HashMap::new(),
             whitelist_apps: HashSet::new(),
+            overrides: HashMap::new(),
         }
     }
 }
@@ -1,13 +1,12 @@
-use anyhow::{Context, Result};
-use flux_core::{Config, DistractionConfig, Translator};
-use std::collections::HashSet;
-use std::fs;
-use std::path::PathBuf;
+use anyhow::{anyhow, Context, Result};
+use flux_core::{
+    Config, DistractionConfig, DistractionMatch, DistractionRule, DistractionVerdict, Translator,
+};
 
-pub fn list() -> Result<()> {
+pub fn list(profile: Option<&str>) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
-    let distractions = config.distractions();
+    let translator = Translator::new(config.language());
+    let (_, distractions) = load_profile_distractions(&config, profile, &translator)?;
 
     println!(
         "\n{}:\n",
@@ -47,13 +46,15 @@ pub fn list() -> Result<()> {
     Ok(())
 }
 
-pub fn add(app: &str) -> Result<()> {
+pub fn add(app: &str, profile: Option<&str>) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
 
     let app_lower = app.to_lowercase();
 
-    if config.distractions().apps.contains(&app_lower) {
+    if distractions.apps.contains(&app_lower) {
         println!(
             "{}",
             translator.format(
@@ -64,10 +65,10 @@ pub fn add(app: &str) -> Result<()> {
         return Ok(());
     }
 
-    let mut new_apps = config.distractions().apps.clone();
-    new_apps.insert(app_lower.clone());
-
-    update_distractions_config(&new_apps)?;
+    distractions.add_app(&app_lower);
+    distractions
+        .save_to_profile(&profile_name)
+        .context("Cannot save distractions config")?;
 
     println!(
         "{}",
@@ -76,13 +77,15 @@ pub fn add(app: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn remove(app: &str) -> Result<()> {
+pub fn remove(app: &str, profile: Option<&str>) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
 
     let app_lower = app.to_lowercase();
 
-    if !config.distractions().apps.contains(&app_lower) {
+    if !distractions.apps.contains(&app_lower) {
         println!(
             "{}",
             translator.format("command.distractions_not_found", &[("app", &app_lower)])
@@ -90,10 +93,10 @@ pub fn remove(app: &str) -> Result<()> {
         return Ok(());
     }
 
-    let mut new_apps = config.distractions().apps.clone();
-    new_apps.remove(&app_lower);
-
-    update_distractions_config(&new_apps)?;
+    distractions.remove_app(&app_lower);
+    distractions
+        .save_to_profile(&profile_name)
+        .context("Cannot save distractions config")?;
 
     println!(
         "{}",
@@ -102,17 +105,15 @@ pub fn remove(app: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn add_pattern(pattern: &str) -> Result<()> {
+pub fn add_pattern(pattern: &str, profile: Option<&str>) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
 
     let pattern_lower = pattern.to_lowercase();
 
-    if config
-        .distractions()
-        .title_patterns
-        .contains(&pattern_lower)
-    {
+    if distractions.title_patterns.contains(&pattern_lower) {
         println!(
             "{}",
             translator.format(
@@ -123,10 +124,9 @@ pub fn add_pattern(pattern: &str) -> Result<()> {
         return Ok(());
     }
 
-    let mut distractions = config.distractions().clone();
     distractions.add_title_pattern(&pattern_lower);
     distractions
-        .save()
+        .save_to_profile(&profile_name)
         .context("Cannot save distractions config")?;
 
     println!(
@@ -139,17 +139,15 @@ pub fn add_pattern(pattern: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn remove_pattern(pattern: &str) -> Result<()> {
+pub fn remove_pattern(pattern: &str, profile: Option<&str>) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
 
     let pattern_lower = pattern.to_lowercase();
 
-    if !config
-        .distractions()
-        .title_patterns
-        .contains(&pattern_lower)
-    {
+    if !distractions.title_patterns.contains(&pattern_lower) {
         println!(
             "{}",
             translator.format(
@@ -160,10 +158,9 @@ pub fn remove_pattern(pattern: &str) -> Result<()> {
         return Ok(());
     }
 
-    let mut distractions = config.distractions().clone();
     distractions.remove_title_pattern(&pattern_lower);
     distractions
-        .save()
+        .save_to_profile(&profile_name)
         .context("Cannot save distractions config")?;
 
     println!(
@@ -176,164 +173,412 @@ pub fn remove_pattern(pattern: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn reset() -> Result<()> {
+pub fn reset(profile: Option<&str>) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
+    let (profile_name, _) = load_profile_distractions(&config, profile, &translator)?;
 
     let default_distractions = DistractionConfig::default();
     default_distractions
-        .save()
+        .save_to_profile(&profile_name)
         .context("Cannot save distractions config")?;
 
     println!("{}", translator.get("command.distractions_reset"));
     Ok(())
 }
 
-fn update_distractions_config(apps: &HashSet<String>) -> Result<()> {
-    let config_path = get_config_path()?;
+pub fn whitelist_list(profile: Option<&str>) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let (_, distractions) = load_profile_distractions(&config, profile, &translator)?;
 
-    let content = if config_path.exists() {
-        fs::read_to_string(&config_path).context("Cannot read config file")?
-    } else {
-        String::new()
-    };
+    println!(
+        "\n{}:\n",
+        translator.get("command.distractions_whitelist_header")
+    );
 
-    let updated_content = update_distractions_in_toml(&content, apps);
+    let mut apps: Vec<_> = distractions.whitelist_apps.iter().collect();
+    apps.sort();
 
-    if let Some(parent) = config_path.parent() {
-        fs::create_dir_all(parent).context("Cannot create config directory")?;
+    if apps.is_empty() {
+        println!("{}", translator.get("command.distractions_whitelist_empty"));
     }
 
-    fs::write(&config_path, updated_content).context("Cannot write config file")?;
+    for (index, app) in apps.iter().enumerate() {
+        let prefix = if index == apps.len() - 1 {
+            "└──"
+        } else {
+            "├──"
+        };
+        println!("{} {}", prefix, app);
+    }
 
+    println!();
     Ok(())
 }
 
-fn update_distractions_in_toml(content: &str, apps: &HashSet<String>) -> String {
-    let mut lines: Vec<String> = content.lines().map(String::from).collect();
-    let mut in_distractions_section = false;
-    let mut apps_updated = false;
-    let mut distractions_section_exists = false;
-
-    let mut sorted_apps: Vec<_> = apps.iter().collect();
-    sorted_apps.sort();
-    let apps_line = format!(
-        "apps = [{}]",
-        sorted_apps
-            .iter()
-            .map(|a| format!("\"{}\"", a))
-            .collect::<Vec<_>>()
-            .join(", ")
+pub fn whitelist_add(app: &str, profile: Option<&str>) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
+
+    let app_lower = app.to_lowercase();
+    let was_distraction = distractions.apps.contains(&app_lower);
+
+    if !distractions.add_to_whitelist(&app_lower) {
+        println!(
+            "{}",
+            translator.format(
+                "command.distractions_whitelist_already_exists",
+                &[("app", &app_lower)]
+            )
+        );
+        return Ok(());
+    }
+
+    distractions
+        .save_to_profile(&profile_name)
+        .context("Cannot save distractions config")?;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.distractions_whitelist_added",
+            &[("app", &app_lower)]
+        )
     );
+    if was_distraction {
+        println!(
+            "{}",
+            translator.format(
+                "command.distractions_whitelist_moved",
+                &[("app", &app_lower)]
+            )
+        );
+    }
+    Ok(())
+}
 
-    for line in &mut lines {
-        let trimmed = line.trim();
+pub fn whitelist_remove(app: &str, profile: Option<&str>) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
 
-        if trimmed.starts_with('[') {
-            in_distractions_section = trimmed == "[distractions]";
-            if in_distractions_section {
-                distractions_section_exists = true;
-            }
-        }
+    let app_lower = app.to_lowercase();
 
-        if in_distractions_section && trimmed.starts_with("apps") {
-            *line = apps_line.clone();
-            apps_updated = true;
-        }
+    if !distractions.remove_from_whitelist(&app_lower) {
+        println!(
+            "{}",
+            translator.format(
+                "command.distractions_whitelist_not_found",
+                &[("app", &app_lower)]
+            )
+        );
+        return Ok(());
     }
 
-    if !distractions_section_exists {
-        if !lines.is_empty() && !lines.last().unwrap().is_empty() {
-            lines.push(String::new());
-        }
-        lines.push("[distractions]".to_string());
-        lines.push(apps_line);
-        return lines.join("\n");
+    distractions
+        .save_to_profile(&profile_name)
+        .context("Cannot save distractions config")?;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.distractions_whitelist_removed",
+            &[("app", &app_lower)]
+        )
+    );
+    Ok(())
+}
+
+pub fn friction_list(profile: Option<&str>) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let (_, distractions) = load_profile_distractions(&config, profile, &translator)?;
+
+    for line in describe_friction(&distractions, &translator) {
+        println!("{}", line);
     }
+    Ok(())
+}
 
-    if !apps_updated {
-        for (index, line) in lines.iter().enumerate() {
-            if line.trim() == "[distractions]" {
-                lines.insert(index + 1, apps_line);
-                break;
-            }
+pub fn friction_add(app: &str, profile: Option<&str>) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
+
+    let app_lower = app.to_lowercase();
+
+    if !distractions.add_friction_app(&app_lower) {
+        println!(
+            "{}",
+            translator.format(
+                "command.distractions_friction_already_exists",
+                &[("app", &app_lower)]
+            )
+        );
+        return Ok(());
+    }
+
+    distractions
+        .save_to_profile(&profile_name)
+        .context("Cannot save distractions config")?;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.distractions_friction_added",
+            &[("app", &app_lower)]
+        )
+    );
+    Ok(())
+}
+
+pub fn friction_remove(app: &str, profile: Option<&str>) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
+
+    let app_lower = app.to_lowercase();
+
+    if !distractions.remove_friction_app(&app_lower) {
+        println!(
+            "{}",
+            translator.format(
+                "command.distractions_friction_not_found",
+                &[("app", &app_lower)]
+            )
+        );
+        return Ok(());
+    }
+
+    distractions
+        .save_to_profile(&profile_name)
+        .context("Cannot save distractions config")?;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.distractions_friction_removed",
+            &[("app", &app_lower)]
+        )
+    );
+    Ok(())
+}
+
+pub fn friction_set_delay(seconds: u64, profile: Option<&str>) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let (profile_name, mut distractions) =
+        load_profile_distractions(&config, profile, &translator)?;
+
+    distractions.friction_delay_seconds = seconds;
+    distractions
+        .save_to_profile(&profile_name)
+        .context("Cannot save distractions config")?;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.distractions_friction_delay_set",
+            &[("seconds", &seconds.to_string())]
+        )
+    );
+    Ok(())
+}
+
+fn describe_friction(distractions: &DistractionConfig, translator: &Translator) -> Vec<String> {
+    let mut lines = vec![
+        String::new(),
+        format!(
+            "{}:",
+            translator.format(
+                "command.distractions_friction_header",
+                &[("seconds", &distractions.friction_delay_seconds.to_string())]
+            )
+        ),
+        String::new(),
+    ];
+
+    let mut apps: Vec<_> = distractions.friction_apps.iter().collect();
+    apps.sort();
+
+    if apps.is_empty() {
+        lines.push(translator.get("command.distractions_friction_empty"));
+    }
+
+    for (index, app) in apps.iter().enumerate() {
+        let prefix = if index == apps.len() - 1 {
+            "└──"
+        } else {
+            "├──"
+        };
+        let delay = distractions.friction_delay_for(app);
+        if delay == distractions.friction_delay_seconds {
+            lines.push(format!("{} {}", prefix, app));
+        } else {
+            lines.push(format!("{} {} ({}s)", prefix, app, delay));
         }
     }
 
-    lines.join("\n")
+    lines.push(String::new());
+    lines
 }
 
-fn get_config_path() -> Result<PathBuf> {
-    let config_dir = dirs::config_dir()
-        .context("Cannot determine config directory")?
-        .join("flux");
+pub fn test(name: &str, title: Option<&str>, profile: Option<&str>) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let (_, distractions) = load_profile_distractions(&config, profile, &translator)?;
+
+    let result = distractions.explain(name, title.unwrap_or_default());
+    for line in describe_match(&result, name, &distractions, &translator) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn describe_match(
+    result: &DistractionMatch,
+    name: &str,
+    distractions: &DistractionConfig,
+    translator: &Translator,
+) -> Vec<String> {
+    let verdict_key = match result.verdict {
+        DistractionVerdict::Distraction => "command.distractions_test_distraction",
+        DistractionVerdict::Friction => "command.distractions_test_friction",
+        DistractionVerdict::Whitelisted => "command.distractions_test_whitelisted",
+        DistractionVerdict::Neutral => "command.distractions_test_neutral",
+    };
+    let mut lines = vec![translator.format(verdict_key, &[("name", name)])];
+
+    if !result.rules.is_empty() {
+        lines.push(translator.get("command.distractions_test_matched"));
+    }
+
+    for (index, rule) in result.rules.iter().enumerate() {
+        let (key, pattern) = match rule {
+            DistractionRule::Application(pattern) => {
+                ("command.distractions_test_rule_application", pattern)
+            }
+            DistractionRule::TitlePattern(pattern) => {
+                ("command.distractions_test_rule_title_pattern", pattern)
+            }
+            DistractionRule::Friction(pattern) => {
+                ("command.distractions_test_rule_friction", pattern)
+            }
+            DistractionRule::Whitelist(pattern) => {
+                ("command.distractions_test_rule_whitelist", pattern)
+            }
+        };
+        let prefix = if index == result.rules.len() - 1 {
+            "└──"
+        } else {
+            "├──"
+        };
+        lines.push(format!(
+            "   {} {}",
+            prefix,
+            translator.format(key, &[("pattern", pattern)])
+        ));
+    }
 
-    Ok(config_dir.join("config.toml"))
+    if result.verdict == DistractionVerdict::Distraction && !distractions.alert_enabled {
+        lines.push(translator.get("command.distractions_test_alerts_disabled"));
+    }
+
+    lines
+}
+
+fn load_profile_distractions(
+    config: &Config,
+    profile: Option<&str>,
+    translator: &Translator,
+) -> Result<(String, DistractionConfig)> {
+    match profile {
+        Some(name) => config
+            .profile
+            .get(name)
+            .map(|found| (name.to_string(), found.distractions.clone()))
+            .ok_or_else(|| {
+                anyhow!(translator.format("command.profile_not_found", &[("name", name)]))
+            }),
+        None => Ok((config.active_profile_name(), config.distractions().clone())),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use flux_core::Profile;
+
+    fn config_with_profile(name: &str, app: &str) -> Config {
+        let mut config = Config::default();
+        let mut profile = Profile::default();
+        profile.distractions.apps = [app.to_string()].into();
+        config.profile.insert(name.to_string(), profile);
+        config
+    }
 
     #[test]
-    fn update_distractions_replaces_existing_apps() {
-        let content = r#"[distractions]
-apps = ["discord", "slack"]
-alert_enabled = false
-"#;
-
-        let new_apps: HashSet<String> =
-            HashSet::from(["discord".to_string(), "twitter".to_string()]);
-        let result = update_distractions_in_toml(content, &new_apps);
-
-        assert!(result.contains("\"discord\""));
-        assert!(result.contains("\"twitter\""));
-        assert!(!result.contains("\"slack\""));
-        assert!(result.contains("alert_enabled = false"));
+    fn load_profile_distractions_uses_requested_profile() {
+        let config = config_with_profile("deep_focus", "reddit");
+        let translator = Translator::default();
+
+        let (name, distractions) =
+            load_profile_distractions(&config, Some("deep_focus"), &translator).unwrap();
+
+        assert_eq!(name, "deep_focus");
+        assert!(distractions.apps.contains("reddit"));
     }
 
     #[test]
-    fn update_distractions_creates_section_if_missing() {
-        let content = r#"[focus]
-duration = 25
-"#;
+    fn describe_match_lists_verdict_and_matched_entries() {
+        let distractions = DistractionConfig {
+            apps: ["discord".to_string()].into(),
+            whitelist_apps: ["discord-canary".to_string()].into(),
+            alert_enabled: false,
+            ..Default::default()
+        };
+        let translator = Translator::default();
 
-        let new_apps: HashSet<String> = HashSet::from(["discord".to_string()]);
-        let result = update_distractions_in_toml(content, &new_apps);
+        let result = distractions.explain("discord-canary", "");
+        let lines = describe_match(&result, "discord-canary", &distractions, &translator);
 
-        assert!(result.contains("[distractions]"));
-        assert!(result.contains("apps = [\"discord\"]"));
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].contains("distraction"));
+        assert!(lines[2].contains("├──") && lines[2].contains("discord"));
+        assert!(lines[3].contains("└──") && lines[3].contains("discord-canary"));
+        assert!(lines[4].contains("alert_enabled"));
     }
 
     #[test]
-    fn update_distractions_adds_apps_to_empty_section() {
-        let content = r#"[distractions]
-alert_enabled = true
-"#;
+    fn describe_friction_shows_overridden_delays() {
+        let distractions = DistractionConfig {
+            friction_apps: ["slack".to_string(), "youtube".to_string()].into(),
+            friction_delay_seconds: 30,
+            friction_overrides: [("youtube".to_string(), 5)].into(),
+            ..Default::default()
+        };
+        let translator = Translator::default();
 
-        let new_apps: HashSet<String> = HashSet::from(["slack".to_string()]);
-        let result = update_distractions_in_toml(content, &new_apps);
+        let lines = describe_friction(&distractions, &translator);
 
-        assert!(result.contains("apps = [\"slack\"]"));
-        assert!(result.contains("alert_enabled = true"));
+        assert!(lines[1].contains("30"));
+        assert_eq!(lines[3], "├── slack");
+        assert_eq!(lines[4], "└── youtube (5s)");
     }
 
     #[test]
-    fn update_distractions_sorts_apps_alphabetically() {
-        let content = "";
-
-        let new_apps: HashSet<String> = HashSet::from([
-            "youtube".to_string(),
-            "discord".to_string(),
-            "slack".to_string(),
-        ]);
-        let result = update_distractions_in_toml(content, &new_apps);
-
-        let apps_pos_discord = result.find("\"discord\"").unwrap();
-        let apps_pos_slack = result.find("\"slack\"").unwrap();
-        let apps_pos_youtube = result.find("\"youtube\"").unwrap();
-
-        assert!(apps_pos_discord < apps_pos_slack);
-        assert!(apps_pos_slack < apps_pos_youtube);
+    fn load_profile_distractions_rejects_unknown_profile() {
+        let config = config_with_profile("deep_focus", "reddit");
+        let translator = Translator::default();
+
+        let result = load_profile_distractions(&config, Some("missing"), &translator);
+
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,459 @@
+use crate::client::{ClientError, DaemonClient};
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use flux_adapters::sqlite::{schema_version, MigrationError, SCHEMA_VERSION};
+use flux_core::{Config, Heartbeat, Translator};
+use flux_protocol::{Request, Response, SubsystemHealth, SubsystemStatus};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    Passed,
+    Warning,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Check {
+    status: CheckStatus,
+    message: String,
+}
+
+impl Check {
+    fn new(status: CheckStatus, message: String) -> Self {
+        Self { status, message }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DaemonHealth {
+    pub subsystems: Vec<SubsystemHealth>,
+    pub config_warnings: Vec<String>,
+}
+
+pub async fn execute() -> Result<()> {
+    let translator = get_translator();
+
+    println!("\n{}\n", translator.get("command.doctor_header"));
+    print!("{}", report(&translator).await);
+    println!();
+
+    Ok(())
+}
+
+/// Every check as printed by `flux doctor`, one per line
+pub async fn report(translator: &Translator) -> String {
+    run_checks(translator)
+        .await
+        .iter()
+        .map(|check| format!("{}\n", render_check(check)))
+        .collect()
+}
+
+async fn run_checks(translator: &Translator) -> Vec<Check> {
+    let client = DaemonClient::new();
+
+    let mut checks = vec![check_config(translator)];
+    checks.extend(check_database(translator));
+    checks.push(check_daemon(translator).await);
+    if let Some(health) = fetch_health(&client).await {
+        checks.extend(health_checks(&health, translator));
+    }
+    checks.extend(check_heartbeat(
+        Heartbeat::load().as_ref(),
+        Utc::now(),
+        translator,
+    ));
+    checks
+}
+
+pub async fn fetch_health(client: &DaemonClient) -> Option<DaemonHealth> {
+    match client.send(Request::GetHealth).await {
+        Ok(Response::Health {
+            subsystems,
+            config_warnings,
+        }) => Some(DaemonHealth {
+            subsystems,
+            config_warnings,
+        }),
+        _ => None,
+    }
+}
+
+pub fn print_health(health: &DaemonHealth, translator: &Translator) {
+    for check in health_checks(health, translator) {
+        print_check(&check);
+    }
+}
+
+fn print_check(check: &Check) {
+    println!("{}", render_check(check));
+}
+
+fn render_check(check: &Check) -> String {
+    let icon = match check.status {
+        CheckStatus::Passed => "✓",
+        CheckStatus::Warning => "⚠",
+        CheckStatus::Failed => "✗",
+    };
+    format!("  {} {}", icon, check.message)
+}
+
+fn get_translator() -> Translator {
+    Config::load()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default()
+}
+
+fn check_config(translator: &Translator) -> Check {
+    let path = Config::config_path();
+    let path_display = path.display().to_string();
+
+    if !path.exists() {
+        return Check::new(
+            CheckStatus::Warning,
+            translator.format("command.doctor_config_missing", &[("path", &path_display)]),
+        );
+    }
+
+    match Config::load() {
+        Ok(_) => Check::new(
+            CheckStatus::Passed,
+            translator.format("command.doctor_config_ok", &[("path", &path_display)]),
+        ),
+        Err(error) => Check::new(
+            CheckStatus::Failed,
+            translator.format(
+                "command.doctor_config_invalid",
+                &[("error", &error.to_string())],
+            ),
+        ),
+    }
+}
+
+fn check_database(translator: &Translator) -> Option<Check> {
+    let database_path = dirs::data_dir()?.join("flux").join("sessions.db");
+    if !database_path.exists() {
+        return None;
+    }
+    Some(database_check(schema_version(&database_path), translator))
+}
+
+fn database_check(version: Result<u32, MigrationError>, translator: &Translator) -> Check {
+    let latest = SCHEMA_VERSION.to_string();
+    match version {
+        Ok(version) if version == SCHEMA_VERSION => Check::new(
+            CheckStatus::Passed,
+            translator.format("command.doctor_database_ok", &[("version", &latest)]),
+        ),
+        Ok(version) if version < SCHEMA_VERSION => Check::new(
+            CheckStatus::Warning,
+            translator.format(
+                "command.doctor_database_outdated",
+                &[("version", &version.to_string()), ("latest", &latest)],
+            ),
+        ),
+        Ok(version) => Check::new(
+            CheckStatus::Failed,
+            translator.format(
+                "command.doctor_database_newer",
+                &[("version", &version.to_string()), ("latest", &latest)],
+            ),
+        ),
+        Err(error) => Check::new(
+            CheckStatus::Failed,
+            translator.format(
+                "command.doctor_database_unreadable",
+                &[("error", &error.to_string())],
+            ),
+        ),
+    }
+}
+
+async fn check_daemon(translator: &Translator) -> Check {
+    match DaemonClient::new().send(Request::Ping).await {
+        Ok(Response::Pong) => Check::new(
+            CheckStatus::Passed,
+            translator.get("command.doctor_daemon_running"),
+        ),
+        Ok(_) => Check::new(
+            CheckStatus::Failed,
+            translator.get("error.unexpected_response"),
+        ),
+        Err(ClientError::DaemonNotRunning) => Check::new(
+            CheckStatus::Warning,
+            translator.get("command.doctor_daemon_not_running"),
+        ),
+        Err(error) => Check::new(
+            CheckStatus::Failed,
+            translator.format(
+                "command.doctor_daemon_unreachable",
+                &[("error", &error.to_string())],
+            ),
+        ),
+    }
+}
+
+fn health_checks(health: &DaemonHealth, translator: &Translator) -> Vec<Check> {
+    let mut checks: Vec<Check> = health
+        .subsystems
+        .iter()
+        .map(|subsystem| subsystem_check(subsystem, translator))
+        .collect();
+
+    checks.extend(
+        health
+            .config_warnings
+            .iter()
+            .map(|warning| Check::new(CheckStatus::Warning, warning.clone())),
+    );
+
+    checks
+}
+
+fn subsystem_check(health: &SubsystemHealth, translator: &Translator) -> Check {
+    let subsystem = health.subsystem.id();
+    let name = translator.get(&format!("health.{}_name", subsystem));
+
+    let (status, status_text, detail) = match &health.status {
+        SubsystemStatus::Running => (
+            CheckStatus::Passed,
+            translator.get("health.status_running"),
+            health.detail.clone(),
+        ),
+        SubsystemStatus::Disabled => (
+            CheckStatus::Passed,
+            translator.get("health.status_disabled"),
+            None,
+        ),
+        SubsystemStatus::Failed { reason } => (
+            CheckStatus::Failed,
+            translator.get("health.status_failed"),
+            reason.clone(),
+        ),
+        SubsystemStatus::Degraded { restarts, reason } => (
+            CheckStatus::Warning,
+            translator.format(
+                "health.status_degraded",
+                &[("restarts", &restarts.to_string())],
+            ),
+            reason.clone(),
+        ),
+    };
+
+    let mut message = match detail {
+        Some(detail) => translator.format(
+            "command.doctor_subsystem_detail",
+            &[
+                ("name", &name),
+                ("status", &status_text),
+                ("detail", &detail),
+            ],
+        ),
+        None => translator.format(
+            "command.doctor_subsystem",
+            &[("name", &name), ("status", &status_text)],
+        ),
+    };
+
+    if status == CheckStatus::Failed {
+        message.push_str("\n    ");
+        message.push_str(&translator.get(&format!("health.{}_hint", subsystem)));
+    }
+
+    Check::new(status, message)
+}
+
+fn check_heartbeat(
+    heartbeat: Option<&Heartbeat>,
+    now: DateTime<Utc>,
+    translator: &Translator,
+) -> Vec<Check> {
+    let Some(heartbeat) = heartbeat else {
+        return vec![Check::new(
+            CheckStatus::Warning,
+            translator.get("command.doctor_heartbeat_missing"),
+        )];
+    };
+
+    let format_time = |time: DateTime<Utc>| {
+        let local: DateTime<Local> = time.into();
+        local.format("%Y-%m-%d %H:%M").to_string()
+    };
+
+    if heartbeat.is_stale(now) {
+        return vec![Check::new(
+            CheckStatus::Warning,
+            translator.format(
+                "command.doctor_heartbeat_stale",
+                &[("time", &format_time(heartbeat.updated_at))],
+            ),
+        )];
+    }
+
+    let mut checks = vec![Check::new(
+        CheckStatus::Passed,
+        translator.format(
+            "command.doctor_heartbeat_ok",
+            &[
+                ("version", &heartbeat.version),
+                ("pid", &heartbeat.pid.to_string()),
+                ("time", &format_time(heartbeat.started_at)),
+            ],
+        ),
+    )];
+
+    let notifications = &heartbeat.notifications;
+    match &notifications.server_name {
+        Some(name) => checks.push(Check::new(
+            CheckStatus::Passed,
+            translator.format("command.doctor_notification_server", &[("name", name)]),
+        )),
+        None => checks.push(Check::new(
+            CheckStatus::Failed,
+            translator.get("command.doctor_notification_server_missing"),
+        )),
+    }
+
+    let capabilities = [
+        (notifications.actions, "actions"),
+        (notifications.sound, "sound"),
+        (notifications.urgency, "urgency"),
+    ];
+    for (supported, capability) in capabilities {
+        let (status, suffix) = if supported {
+            (CheckStatus::Passed, "supported")
+        } else {
+            (CheckStatus::Warning, "unsupported")
+        };
+        checks.push(Check::new(
+            status,
+            translator.get(&format!("command.doctor_{}_{}", capability, suffix)),
+        ));
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use flux_core::NotificationCapabilities;
+    use flux_protocol::Subsystem;
+
+    fn create_heartbeat(updated_at: DateTime<Utc>, actions: bool) -> Heartbeat {
+        Heartbeat {
+            pid: 1234,
+            version: "0.2.13".to_string(),
+            started_at: updated_at - Duration::hours(2),
+            updated_at,
+            notifications: NotificationCapabilities {
+                server_name: Some("dunst".to_string()),
+                actions,
+                sound: true,
+                urgency: true,
+            },
+        }
+    }
+
+    #[test]
+    fn database_schema_is_compared_with_the_supported_version() {
+        let translator = Translator::new(flux_core::Language::En);
+
+        assert_eq!(
+            database_check(Ok(SCHEMA_VERSION), &translator).status,
+            CheckStatus::Passed
+        );
+        assert_eq!(
+            database_check(Ok(0), &translator).status,
+            CheckStatus::Warning
+        );
+        assert_eq!(
+            database_check(Ok(SCHEMA_VERSION + 1), &translator).status,
+            CheckStatus::Failed
+        );
+    }
+
+    #[test]
+    fn missing_heartbeat_is_a_warning() {
+        let checks = check_heartbeat(None, Utc::now(), &Translator::default());
+
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].status, CheckStatus::Warning);
+    }
+
+    #[test]
+    fn stale_heartbeat_skips_capability_checks() {
+        let now = Utc::now();
+        let heartbeat = create_heartbeat(now - Duration::minutes(10), true);
+
+        let checks = check_heartbeat(Some(&heartbeat), now, &Translator::default());
+
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].status, CheckStatus::Warning);
+    }
+
+    #[test]
+    fn missing_actions_support_is_reported() {
+        let now = Utc::now();
+        let heartbeat = create_heartbeat(now, false);
+
+        let checks = check_heartbeat(Some(&heartbeat), now, &Translator::default());
+
+        assert_eq!(checks.len(), 5);
+        assert_eq!(checks[0].status, CheckStatus::Passed);
+        assert!(checks[1].message.contains("dunst"));
+        assert_eq!(checks[2].status, CheckStatus::Warning);
+        assert_eq!(checks[3].status, CheckStatus::Passed);
+        assert_eq!(checks[4].status, CheckStatus::Passed);
+    }
+
+    #[test]
+    fn health_reports_failures_with_hint_and_config_warnings() {
+        let health = DaemonHealth {
+            subsystems: vec![
+                SubsystemHealth::new(Subsystem::Notifications, SubsystemStatus::Running)
+                    .with_detail("dunst"),
+                SubsystemHealth::new(Subsystem::Tray, SubsystemStatus::Disabled),
+                SubsystemHealth::new(
+                    Subsystem::WindowDetector,
+                    SubsystemStatus::Failed { reason: None },
+                ),
+            ],
+            config_warnings: vec!["Active profile \"work\" not found".to_string()],
+        };
+
+        let checks = health_checks(&health, &Translator::default());
+
+        assert_eq!(checks.len(), 4);
+        assert_eq!(checks[0].status, CheckStatus::Passed);
+        assert_eq!(checks[0].message, "Notifications: running (dunst)");
+        assert_eq!(checks[1].message, "System tray: disabled");
+        assert_eq!(checks[2].status, CheckStatus::Failed);
+        assert!(checks[2].message.contains("X11"));
+        assert_eq!(checks[3].status, CheckStatus::Warning);
+    }
+
+    #[test]
+    fn restarted_subsystem_is_a_warning_with_its_crash_reason() {
+        let health = DaemonHealth {
+            subsystems: vec![SubsystemHealth::new(
+                Subsystem::AppTracking,
+                SubsystemStatus::Degraded {
+                    restarts: 2,
+                    reason: Some("poll panicked".to_string()),
+                },
+            )],
+            config_warnings: Vec::new(),
+        };
+
+        let checks = health_checks(&health, &Translator::default());
+
+        assert_eq!(checks[0].status, CheckStatus::Warning);
+        assert_eq!(
+            checks[0].message,
+            "Application tracking: restarted 2 time(s) after a crash (poll panicked)"
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, Utc};
+use flux_adapters::{Database, SqliteSessionNoteRepository, SqliteSessionRepository};
+use flux_core::{Config, Session, SessionNoteRepository, SessionRepository, Translator};
+use serde::Serialize;
+
+use super::render::format_duration;
+
+#[derive(Serialize)]
+struct SessionOutput<'a> {
+    id: Option<i64>,
+    mode: String,
+    started_at: DateTime<Utc>,
+    ended_at: Option<DateTime<Utc>>,
+    duration_seconds: Option<i64>,
+    planned_minutes: Option<u64>,
+    end_reason: Option<&'static str>,
+    tags: &'a [String],
+    note: Option<&'a str>,
+}
+
+pub fn execute(limit: usize, json: bool) -> Result<()> {
+    let translator = get_translator();
+    let database_path = dirs::data_dir()
+        .context("cannot find data directory")?
+        .join("flux")
+        .join("sessions.db");
+
+    if !database_path.exists() {
+        bail!("no session data. Start a session first with 'flux start'.");
+    }
+
+    let database = Database::open(&database_path)
+        .map_err(|error| anyhow::anyhow!("database access error: {}", error))?;
+    let repository = SqliteSessionRepository::with_database(database.clone());
+    let mut sessions = repository
+        .find_completed_since(DateTime::UNIX_EPOCH)
+        .map_err(|error| anyhow::anyhow!("read error: {}", error))?;
+    sessions.truncate(limit);
+
+    let session_ids: Vec<i64> = sessions.iter().filter_map(|session| session.id).collect();
+    let notes: HashMap<i64, String> = SqliteSessionNoteRepository::with_database(database)
+        .find_by_sessions(&session_ids)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|note| (note.session_id, note.text))
+        .collect();
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&history_output(&sessions, &notes))?
+        );
+        return Ok(());
+    }
+
+    if sessions.is_empty() {
+        println!("\n{}\n", translator.get("command.history_empty"));
+        return Ok(());
+    }
+
+    println!("\n{}:\n", translator.get("command.history_header"));
+    for line in describe_sessions(&sessions, &notes, &translator) {
+        println!("{}", line);
+    }
+    println!();
+
+    Ok(())
+}
+
+fn history_output<'a>(
+    sessions: &'a [Session],
+    notes: &'a HashMap<i64, String>,
+) -> Vec<SessionOutput<'a>> {
+    sessions
+        .iter()
+        .map(|session| SessionOutput {
+            id: session.id,
+            mode: session.mode.as_str().to_string(),
+            started_at: session.started_at,
+            ended_at: session.ended_at,
+            duration_seconds: session.duration_seconds,
+            planned_minutes: session.planned_minutes,
+            end_reason: session.end_reason.map(|reason| reason.as_str()),
+            tags: &session.tags,
+            note: session.id.and_then(|id| notes.get(&id)).map(String::as_str),
+        })
+        .collect()
+}
+
+fn describe_sessions(
+    sessions: &[Session],
+    notes: &HashMap<i64, String>,
+    translator: &Translator,
+) -> Vec<String> {
+    sessions
+        .iter()
+        .enumerate()
+        .map(|(index, session)| {
+            let prefix = if index == sessions.len() - 1 {
+                "└──"
+            } else {
+                "├──"
+            };
+            let started_at: DateTime<Local> = session.started_at.into();
+            let mut line = format!(
+                "{} #{:<4} {}  {:16} {:>8}",
+                prefix,
+                session.id.unwrap_or_default(),
+                started_at.format("%Y-%m-%d %H:%M"),
+                translator.mode_name(&session.mode),
+                format_duration(session.duration_seconds.unwrap_or(0)),
+            );
+            if !session.tags.is_empty() {
+                line.push_str(&format!("  [{}]", session.tags.join(", ")));
+            }
+            if let Some(note) = session.id.and_then(|id| notes.get(&id)) {
+                line.push_str(&format!("  📝 {}", note));
+            }
+            line
+        })
+        .collect()
+}
+
+fn get_translator() -> Translator {
+    Config::load()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::{FocusMode, SessionEndReason};
+
+    fn ended_session() -> Session {
+        let mut session = Session::start(FocusMode::Review).with_tags(vec!["clientx".to_string()]);
+        session.id = Some(42);
+        session.end();
+        session.duration_seconds = Some(1500);
+        session.end_reason = Some(SessionEndReason::StoppedEarly);
+        session
+    }
+
+    #[test]
+    fn json_output_uses_stable_identifiers() {
+        let sessions = vec![ended_session()];
+        let notes = HashMap::from([(42, "shipped the parser".to_string())]);
+
+        let json = serde_json::to_value(history_output(&sessions, &notes)).unwrap();
+
+        assert_eq!(json[0]["id"], 42);
+        assert_eq!(json[0]["mode"], "review");
+        assert_eq!(json[0]["duration_seconds"], 1500);
+        assert_eq!(json[0]["end_reason"], "stopped_early");
+        assert_eq!(json[0]["tags"], serde_json::json!(["clientx"]));
+        assert_eq!(json[0]["note"], "shipped the parser");
+    }
+
+    #[test]
+    fn text_lines_show_tags_and_note() {
+        let translator = Translator::new(flux_core::Language::En);
+        let notes = HashMap::from([(42, "shipped the parser".to_string())]);
+
+        let lines = describe_sessions(&[ended_session()], &notes, &translator);
+
+        assert!(lines[0].starts_with("└── #42"));
+        assert!(lines[0].contains("Code review"));
+        assert!(lines[0].ends_with("25min  [clientx]  📝 shipped the parser"));
+    }
+}
@@ -61,7 +61,7 @@ fn get_config_path() -> Result<PathBuf> {
 
 fn load_existing_translator() -> Translator {
     flux_core::Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
 
@@ -1,11 +1,9 @@
 use anyhow::{Context, Result};
-use flux_core::{Config, Language, Translator};
-use std::fs;
-use std::path::PathBuf;
+use flux_core::{Config, ConfigWriter, Language, Translator};
 
 pub fn execute(language: Option<String>) -> Result<()> {
     let config = Config::load().context("error.config_not_found")?;
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
 
     match language {
         Some(lang_code) => set_language(&lang_code, &translator),
@@ -14,7 +12,7 @@ pub fn execute(language: Option<String>) -> Result<()> {
 }
 
 fn display_current_language(config: &Config, translator: &Translator) -> Result<()> {
-    let language = config.general.language;
+    let language = config.language();
     println!(
         "{}",
         translator.format(
@@ -45,109 +43,9 @@ fn set_language(lang_code: &str, translator: &Translator) -> Result<()> {
 }
 
 fn update_config_language(language: Language) -> Result<()> {
-    let config_path = get_config_path()?;
-    let content = fs::read_to_string(&config_path).context("Cannot read config file")?;
-
-    let updated_content = update_language_in_toml(&content, language);
-
-    fs::write(&config_path, updated_content).context("Cannot write config file")?;
+    let mut writer = ConfigWriter::open().context("Cannot read config file")?;
+    writer.set_language(language)?;
+    writer.save().context("Cannot write config file")?;
 
     Ok(())
 }
-
-fn update_language_in_toml(content: &str, language: Language) -> String {
-    let mut lines: Vec<String> = content.lines().map(String::from).collect();
-    let mut in_general_section = false;
-    let mut language_updated = false;
-    let mut general_section_exists = false;
-
-    for line in &mut lines {
-        let trimmed = line.trim();
-
-        if trimmed.starts_with('[') {
-            in_general_section = trimmed == "[general]";
-            if in_general_section {
-                general_section_exists = true;
-            }
-        }
-
-        if in_general_section && trimmed.starts_with("language") {
-            *line = format!("language = \"{}\"", language.code());
-            language_updated = true;
-        }
-    }
-
-    if !general_section_exists {
-        let mut new_lines = vec![
-            "[general]".to_string(),
-            format!("language = \"{}\"", language.code()),
-            String::new(),
-        ];
-        new_lines.extend(lines);
-        return new_lines.join("\n");
-    }
-
-    if !language_updated {
-        for (index, line) in lines.iter().enumerate() {
-            if line.trim() == "[general]" {
-                lines.insert(index + 1, format!("language = \"{}\"", language.code()));
-                break;
-            }
-        }
-    }
-
-    lines.join("\n")
-}
-
-fn get_config_path() -> Result<PathBuf> {
-    let config_dir = dirs::config_dir()
-        .context("Cannot determine config directory")?
-        .join("flux");
-
-    Ok(config_dir.join("config.toml"))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn update_language_replaces_existing() {
-        let content = r#"[general]
-language = "en"
-
-[focus]
-duration = 25
-"#;
-
-        let result = update_language_in_toml(content, Language::Fr);
-
-        assert!(result.contains("language = \"fr\""));
-        assert!(!result.contains("language = \"en\""));
-    }
-
-    #[test]
-    fn update_language_adds_to_existing_general_section() {
-        let content = r#"[general]
-
-[focus]
-duration = 25
-"#;
-
-        let result = update_language_in_toml(content, Language::Fr);
-
-        assert!(result.contains("language = \"fr\""));
-    }
-
-    #[test]
-    fn update_language_creates_general_section() {
-        let content = r#"[focus]
-duration = 25
-"#;
-
-        let result = update_language_in_toml(content, Language::Fr);
-
-        assert!(result.contains("[general]"));
-        assert!(result.contains("language = \"fr\""));
-    }
-}
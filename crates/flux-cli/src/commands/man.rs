@@ -0,0 +1,351 @@
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use clap::builder::StyledStr;
+use clap::{Arg, Command};
+use flux_core::{Config, Language, Translator};
+
+/// One roff page, named after the command path (`flux-distractions-add`)
+#[derive(Debug)]
+struct ManPage {
+    name: String,
+    content: String,
+}
+
+impl ManPage {
+    fn file_name(&self) -> String {
+        format!("{}.1", self.name)
+    }
+}
+
+pub fn execute(command: Command, output: Option<&Path>, language: Option<&str>) -> Result<()> {
+    let translator = Translator::new(Config::load().unwrap_or_default().language());
+    let translator = match language {
+        Some(code) => Translator::new(code.parse::<Language>().map_err(|_| {
+            anyhow::anyhow!(
+                "{}",
+                translator.format("lang.unsupported", &[("lang", code)])
+            )
+        })?),
+        None => translator,
+    };
+    let pages = pages(command, &translator);
+
+    let Some(directory) = output else {
+        print!("{}", pages[0].content);
+        return Ok(());
+    };
+
+    std::fs::create_dir_all(directory)
+        .with_context(|| format!("cannot create {}", directory.display()))?;
+    for page in &pages {
+        let path = directory.join(page.file_name());
+        std::fs::write(&path, &page.content)
+            .with_context(|| format!("cannot write {}", path.display()))?;
+    }
+    println!(
+        "{}",
+        translator.format(
+            "man.written",
+            &[
+                ("count", &pages.len().to_string()),
+                ("path", &directory.display().to_string()),
+            ]
+        )
+    );
+    Ok(())
+}
+
+/// The root page first, then one page per visible subcommand, depth first
+fn pages(command: Command, translator: &Translator) -> Vec<ManPage> {
+    let mut command = command.disable_help_subcommand(true);
+    command.build();
+    let version = command.get_version().unwrap_or_default().to_string();
+
+    let mut pages = Vec::new();
+    collect(&command, &[], &version, translator, &mut pages);
+    pages
+}
+
+fn collect<'a>(
+    command: &'a Command,
+    parents: &[&'a str],
+    version: &str,
+    translator: &Translator,
+    pages: &mut Vec<ManPage>,
+) {
+    let mut path = parents.to_vec();
+    path.push(command.get_name());
+
+    pages.push(render(command, &path, version, translator));
+    for subcommand in visible_subcommands(command) {
+        collect(subcommand, &path, version, translator, pages);
+    }
+}
+
+fn render(command: &Command, path: &[&str], version: &str, translator: &Translator) -> ManPage {
+    let name = path.join("-");
+    let about = command_description(command, path, translator);
+    let (positionals, options): (Vec<&Arg>, Vec<&Arg>) = command
+        .get_arguments()
+        .filter(|arg| !arg.is_hide_set())
+        .partition(|arg| arg.is_positional());
+    let subcommands: Vec<&Command> = visible_subcommands(command).collect();
+
+    let mut page = String::new();
+    let _ = writeln!(
+        page,
+        ".TH {} 1 \"\" \"{}\" \"{}\"",
+        escape(&name.to_uppercase()),
+        escape(&format!("flux {version}")),
+        escape(&translator.get("man.manual"))
+    );
+
+    heading(&mut page, translator, "man.name");
+    let _ = writeln!(page, "{} \\- {}", escape(&name), escape(&about));
+
+    heading(&mut page, translator, "man.synopsis");
+    let mut synopsis = format!("\\fB{}\\fR", escape(&path.join(" ")));
+    if !options.is_empty() {
+        let placeholder = translator.get("man.options_placeholder");
+        let _ = write!(synopsis, " [\\fI{}\\fR]", escape(&placeholder));
+    }
+    for arg in &positionals {
+        let value = format!("\\fI{}\\fR", escape(&value_name(arg)));
+        let value = if arg
+            .get_num_args()
+            .is_some_and(|range| range.max_values() > 1)
+        {
+            format!("{value}...")
+        } else {
+            value
+        };
+        if arg.is_required_set() {
+            let _ = write!(synopsis, " {value}");
+        } else {
+            let _ = write!(synopsis, " [{value}]");
+        }
+    }
+    if !subcommands.is_empty() {
+        let placeholder = format!("<{}>", translator.get("man.command_placeholder"));
+        let _ = write!(synopsis, " \\fI{}\\fR", escape(&placeholder));
+    }
+    let _ = writeln!(page, "{synopsis}");
+
+    heading(&mut page, translator, "man.description");
+    let _ = writeln!(page, "{}", escape(&about));
+
+    if !positionals.is_empty() {
+        heading(&mut page, translator, "man.arguments");
+        for arg in &positionals {
+            let _ = writeln!(page, ".TP\n\\fI{}\\fR", escape(&value_name(arg)));
+            let _ = writeln!(page, "{}", escape(&arg_description(arg, path, translator)));
+        }
+    }
+
+    if !options.is_empty() {
+        heading(&mut page, translator, "man.options");
+        for arg in &options {
+            let _ = writeln!(page, ".TP\n{}", option_flags(arg));
+            let _ = writeln!(page, "{}", escape(&arg_description(arg, path, translator)));
+        }
+    }
+
+    if !subcommands.is_empty() {
+        heading(&mut page, translator, "man.commands");
+        for subcommand in &subcommands {
+            let mut subpath = path.to_vec();
+            subpath.push(subcommand.get_name());
+            let _ = writeln!(page, ".TP\n\\fB{}\\fR(1)", escape(&subpath.join("-")));
+            let _ = writeln!(
+                page,
+                "{}",
+                escape(&command_description(subcommand, &subpath, translator))
+            );
+        }
+    }
+
+    if let [parents @ .., _] = path {
+        if !parents.is_empty() {
+            heading(&mut page, translator, "man.see_also");
+            let _ = writeln!(page, "\\fB{}\\fR(1)", escape(&parents.join("-")));
+        }
+    }
+
+    ManPage {
+        name,
+        content: page,
+    }
+}
+
+fn heading(page: &mut String, translator: &Translator, key: &str) {
+    let _ = writeln!(page, ".SH \"{}\"", escape(&translator.get(key)));
+}
+
+fn visible_subcommands(command: &Command) -> impl Iterator<Item = &Command> {
+    command
+        .get_subcommands()
+        .filter(|subcommand| !subcommand.is_hide_set())
+}
+
+/// Descriptions are looked up in the `man_page` translations, keyed by the
+/// command line they document (`flux start`, `flux start --duration`,
+/// `flux delete <id>`), and fall back to the help written on the command.
+/// Options shared by several commands (`--json`, `--help`) may be keyed by
+/// their flag alone. The help on the commands is written in French, so the
+/// French catalogue only lists what it lacks.
+fn command_description(command: &Command, path: &[&str], translator: &Translator) -> String {
+    translator
+        .try_get(&format!("man_page.{}", path.join(" ")))
+        .unwrap_or_else(|| help_text(command.get_long_about().or(command.get_about())))
+}
+
+fn arg_description(arg: &Arg, path: &[&str], translator: &Translator) -> String {
+    arg_keys(arg, path)
+        .iter()
+        .find_map(|key| translator.try_get(&format!("man_page.{key}")))
+        .unwrap_or_else(|| help_text(arg.get_long_help().or(arg.get_help())))
+}
+
+fn arg_keys(arg: &Arg, path: &[&str]) -> Vec<String> {
+    let command = path.join(" ");
+    match arg.get_long() {
+        Some(long) => vec![format!("{command} --{long}"), format!("--{long}")],
+        None if arg.is_positional() => vec![format!("{command} <{}>", arg.get_id())],
+        None => Vec::new(),
+    }
+}
+
+fn help_text(help: Option<&StyledStr>) -> String {
+    help.map(ToString::to_string).unwrap_or_default()
+}
+
+fn value_name(arg: &Arg) -> String {
+    let name = arg
+        .get_value_names()
+        .and_then(|names| names.first())
+        .map(ToString::to_string)
+        .unwrap_or_else(|| arg.get_id().to_string().to_uppercase());
+    format!("<{name}>")
+}
+
+fn option_flags(arg: &Arg) -> String {
+    let mut flags = Vec::new();
+    if let Some(short) = arg.get_short() {
+        flags.push(format!("\\fB{}\\fR", escape(&format!("-{short}"))));
+    }
+    if let Some(long) = arg.get_long() {
+        flags.push(format!("\\fB{}\\fR", escape(&format!("--{long}"))));
+    }
+    let mut line = flags.join(", ");
+    if arg.get_action().takes_values() {
+        let _ = write!(line, " \\fI{}\\fR", escape(&value_name(arg)));
+    }
+    line
+}
+
+/// Hyphens become minus signs so that flags can be copied and searched, and
+/// a line starting with a dot or a quote is not read as a request
+fn escape(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let line = line.replace('\\', "\\e").replace('-', "\\-");
+            if line.starts_with(['.', '\'']) {
+                format!("\\&{line}")
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn page<'a>(pages: &'a [ManPage], name: &str) -> &'a str {
+        &pages
+            .iter()
+            .find(|page| page.name == name)
+            .unwrap_or_else(|| panic!("no page for {name}"))
+            .content
+    }
+
+    #[test]
+    fn every_subcommand_gets_its_own_page() {
+        let pages = pages(crate::Cli::command(), &Translator::new(Language::En));
+
+        assert_eq!(pages[0].name, "flux");
+        assert_eq!(pages[0].file_name(), "flux.1");
+        assert!(page(&pages, "flux").contains(".TH FLUX 1"));
+        assert!(page(&pages, "flux").contains("\\fBflux\\-start\\fR(1)"));
+        assert!(page(&pages, "flux-distractions-whitelist-add")
+            .contains("\\fBflux\\-distractions\\-whitelist\\fR(1)"));
+        assert!(!pages.iter().any(|page| page.name.ends_with("-help")));
+    }
+
+    #[test]
+    fn descriptions_follow_the_requested_language() {
+        let english = pages(crate::Cli::command(), &Translator::new(Language::En));
+        let french = pages(crate::Cli::command(), &Translator::new(Language::Fr));
+
+        let start = page(&english, "flux-start");
+        assert!(start.contains("flux\\-start \\- Start a focus session"));
+        assert!(start.contains(".SH \"OPTIONS\""));
+        assert!(start.contains(
+            "\\fB\\-d\\fR, \\fB\\-\\-duration\\fR \\fI<DURATION>\\fR\nDuration in minutes"
+        ));
+
+        let start = page(&french, "flux-start");
+        assert!(start.contains("flux\\-start \\- Démarrer une session focus"));
+        assert!(start.contains(".SH \"NOM\""));
+        assert!(page(&french, "flux-lang").contains("Changer ou afficher la langue courante"));
+    }
+
+    /// Keys of the commands and arguments below `command` that have no
+    /// description in the catalogue
+    fn undescribed(command: &Command, parents: &[&str], translator: &Translator) -> Vec<String> {
+        let mut path = parents.to_vec();
+        path.push(command.get_name());
+
+        let mut keys = vec![path.join(" ")];
+        for arg in command.get_arguments().filter(|arg| !arg.is_hide_set()) {
+            keys.extend(arg_keys(arg, &path).into_iter().take(1));
+        }
+        let mut missing: Vec<String> = keys
+            .into_iter()
+            .filter(|key| {
+                let shared = key.find(" --").map(|flag| &key[flag + 1..]);
+                [Some(key.as_str()), shared]
+                    .into_iter()
+                    .flatten()
+                    .all(|key| translator.try_get(&format!("man_page.{key}")).is_none())
+            })
+            .collect();
+        for subcommand in visible_subcommands(command) {
+            missing.extend(undescribed(subcommand, &path, translator));
+        }
+        missing
+    }
+
+    #[test]
+    fn every_command_and_argument_has_an_english_description() {
+        let mut command = crate::Cli::command().disable_help_subcommand(true);
+        command.build();
+
+        assert_eq!(
+            undescribed(&command, &[], &Translator::new(Language::En)),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn roff_requests_and_escapes_are_neutralized() {
+        assert_eq!(escape("--auto-start"), "\\-\\-auto\\-start");
+        assert_eq!(escape(".hidden\n'quoted"), "\\&.hidden\n\\&'quoted");
+        assert_eq!(escape("C:\\path"), "C:\\epath");
+    }
+}
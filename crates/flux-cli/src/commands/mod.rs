@@ -1,30 +1,58 @@
+mod again;
 mod clear;
+mod completions;
+pub mod config;
+pub mod daemon;
 mod dashboard;
 mod delete;
 mod digest;
 pub mod distractions;
+mod doctor;
+mod history;
 mod init;
 mod lang;
+mod man;
+mod note;
 mod pause;
 pub mod profile;
+mod prompt;
+mod render;
+mod report_bug;
 mod resume;
 mod start;
 mod stats;
 mod status;
 mod stop;
+mod strict;
 pub mod suggestions;
+mod sync;
+pub mod tags;
+mod today;
+mod uninstall;
 mod update;
 
+pub use again::execute as again;
 pub use clear::execute as clear;
+pub use completions::execute as completions;
 pub use dashboard::execute as dashboard;
 pub use delete::execute as delete;
-pub use digest::execute as digest;
+pub use digest::{execute as digest, DigestFormat};
+pub use doctor::execute as doctor;
+pub use history::execute as history;
 pub use init::{config_exists, execute as init};
 pub use lang::execute as lang;
+pub use man::execute as man;
+pub use note::execute as note;
 pub use pause::execute as pause;
+pub use prompt::execute as prompt;
+pub use render::OutputFormat;
+pub use report_bug::execute as report_bug;
 pub use resume::execute as resume;
 pub use start::execute as start;
-pub use stats::{execute as stats, Period};
+pub use stats::{execute as stats, trend as stats_trend, Period, Trend};
 pub use status::execute as status;
 pub use stop::execute as stop;
+pub use sync::execute as sync;
+pub use today::execute as today;
+pub use uninstall::execute as uninstall;
 pub use update::execute as update;
@@ -0,0 +1,105 @@
+use crate::client::{ClientError, DaemonClient};
+use anyhow::{bail, Context, Result};
+use flux_adapters::{Database, SqliteSessionNoteRepository, SqliteSessionRepository};
+use flux_core::{
+    Config, SessionId, SessionNote, SessionNoteRepository, SessionRepository, Translator,
+};
+use flux_protocol::{Request, Response};
+
+pub async fn execute(words: Vec<String>, session_id: Option<SessionId>) -> Result<()> {
+    let translator = get_translator();
+    let text = words.join(" ");
+    let client = DaemonClient::new();
+
+    let request = Request::AddNote {
+        session_id,
+        text: text.clone(),
+    };
+
+    let saved_session_id = match client.send(request).await {
+        Ok(Response::NoteAdded { session_id }) => session_id,
+        Ok(Response::Error { message }) => {
+            bail!("{}", message);
+        }
+        Ok(Response::Unsupported { feature }) => {
+            bail!(
+                "{}",
+                translator.format(
+                    "error.feature_unsupported",
+                    &[(
+                        "feature",
+                        &translator.get(&format!("feature.{}", feature.id()))
+                    )],
+                )
+            );
+        }
+        Ok(_) => {
+            bail!("{}", translator.get("error.unexpected_response"));
+        }
+        Err(ClientError::DaemonNotRunning) => save_offline(session_id, &text, &translator)?,
+        Err(ClientError::Timeout) => {
+            bail!("{}", translator.get("error.connection_timeout"));
+        }
+        Err(error) => {
+            bail!("{}", error);
+        }
+    };
+
+    println!(
+        "{}",
+        translator.format(
+            "command.note_saved",
+            &[("id", &saved_session_id.to_string())]
+        )
+    );
+
+    Ok(())
+}
+
+/// Writes the note straight to the database, as `flux stop` also shuts the daemon down
+fn save_offline(
+    session_id: Option<SessionId>,
+    text: &str,
+    translator: &Translator,
+) -> Result<SessionId> {
+    let database_path = dirs::data_dir()
+        .context("cannot find data directory")?
+        .join("flux")
+        .join("sessions.db");
+
+    if !database_path.exists() {
+        bail!("{}", translator.get("error.note_session_not_found"));
+    }
+
+    let database = Database::open(&database_path)
+        .map_err(|error| anyhow::anyhow!("database access error: {}", error))?;
+    let sessions = SqliteSessionRepository::with_database(database.clone());
+    let notes = SqliteSessionNoteRepository::with_database(database);
+
+    let session = match session_id {
+        Some(id) => sessions.find_by_id(id).ok(),
+        None => sessions.find_latest().ok().flatten(),
+    };
+    let Some(session_id) = session.and_then(|session| session.id) else {
+        bail!("{}", translator.get("error.note_session_not_found"));
+    };
+
+    let Some(note) = SessionNote::new(session_id, text) else {
+        bail!("{}", translator.get("error.note_empty"));
+    };
+
+    notes.save(&note).map_err(|error| {
+        anyhow::anyhow!(
+            "{}",
+            translator.format("error.note_save_failed", &[("error", &error.to_string())])
+        )
+    })?;
+
+    Ok(session_id)
+}
+
+fn get_translator() -> Translator {
+    Config::load()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default()
+}
@@ -1,16 +1,50 @@
+use super::strict;
 use crate::client::{ClientError, DaemonClient};
+use crate::daemon_launcher::send_starting_daemon;
 use anyhow::{bail, Result};
 use flux_core::{Config, Translator};
 use flux_protocol::{Request, Response};
 
-pub async fn execute() -> Result<()> {
+pub async fn execute(auto_start: bool, reason: Option<String>) -> Result<()> {
     let translator = get_translator();
     let client = DaemonClient::new();
 
-    match client.send(Request::PauseSession).await {
+    let mut response = send_starting_daemon(
+        &client,
+        Request::PauseSession {
+            confirmation: None,
+            reason: reason.clone(),
+        },
+        auto_start,
+        &translator,
+    )
+    .await;
+    if let Ok(Response::StrictSession {
+        remaining_seconds,
+        confirmation_phrase,
+    }) = &response
+    {
+        let Some(confirmation) =
+            strict::ask_confirmation(*remaining_seconds, confirmation_phrase, &translator)?
+        else {
+            println!("{}", translator.get("command.strict_cancelled"));
+            return Ok(());
+        };
+        response = client
+            .send(Request::PauseSession {
+                confirmation: Some(confirmation),
+                reason,
+            })
+            .await;
+    }
+
+    match response {
         Ok(Response::Ok) => {
             println!("{}", translator.get("command.pause_success"));
         }
+        Ok(Response::StrictSession { .. }) => {
+            println!("{}", translator.get("command.strict_cancelled"));
+        }
         Ok(Response::Error { message }) => {
             if message.contains("aucune session") || message.contains("no session") {
                 println!("{}", translator.get("status.no_session"));
@@ -41,6 +75,6 @@ pub async fn execute() -> Result<()> {
 
 fn get_translator() -> Translator {
     Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
@@ -1,16 +1,35 @@
 use anyhow::{bail, Result};
 use flux_core::{AppState, Config, Translator};
+use serde::Serialize;
 
-pub fn list() -> Result<()> {
+#[derive(Serialize)]
+struct ProfileOutput<'a> {
+    name: &'a str,
+    active: bool,
+}
+
+pub fn list(json: bool) -> Result<()> {
     let config = Config::load().unwrap_or_default();
     let state = AppState::load();
-    let translator = Translator::new(config.general.language);
-
-    println!("\n{}:\n", translator.get("command.profile_list_header"));
+    let translator = Translator::new(config.language());
 
     let mut names: Vec<_> = config.profile_names();
     names.sort();
 
+    if json {
+        let output: Vec<ProfileOutput> = names
+            .iter()
+            .map(|name| ProfileOutput {
+                name,
+                active: *name == state.active_profile,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("\n{}:\n", translator.get("command.profile_list_header"));
+
     for (index, name) in names.iter().enumerate() {
         let marker = if *name == state.active_profile {
             "●"
@@ -32,7 +51,7 @@ pub fn list() -> Result<()> {
 pub fn show(name: Option<String>) -> Result<()> {
     let config = Config::load().unwrap_or_default();
     let state = AppState::load();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
 
     let profile_name = name.as_deref().unwrap_or(&state.active_profile);
     let profile = config.profile.get(profile_name).ok_or_else(|| {
@@ -81,6 +100,10 @@ pub fn show(name: Option<String>) -> Result<()> {
         "  alert_after_seconds = {}",
         profile.distractions.alert_after_seconds
     );
+    println!(
+        "  alert_mode = \"{}\"",
+        profile.distractions.alert_mode.as_str()
+    );
     if !profile.distractions.friction_apps.is_empty() {
         let mut friction: Vec<_> = profile.distractions.friction_apps.iter().collect();
         friction.sort();
@@ -96,6 +119,21 @@ pub fn show(name: Option<String>) -> Result<()> {
             "  friction_delay_seconds = {}",
             profile.distractions.friction_delay_seconds
         );
+        let mut overrides: Vec<_> = profile.distractions.friction_overrides.iter().collect();
+        overrides.sort();
+        for (app, delay) in overrides {
+            println!("  friction_overrides.{} = {}", app, delay);
+        }
+    }
+    let mut alert_overrides: Vec<_> = profile
+        .distractions
+        .overrides
+        .iter()
+        .filter_map(|(app, settings)| settings.alert_after_seconds.map(|seconds| (app, seconds)))
+        .collect();
+    alert_overrides.sort();
+    for (app, seconds) in alert_overrides {
+        println!("  overrides.{}.alert_after_seconds = {}", app, seconds);
     }
 
     println!("\n[notifications]");
@@ -113,7 +151,7 @@ pub fn show(name: Option<String>) -> Result<()> {
 
 pub fn use_profile(name: &str) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
 
     if !config.profile.contains_key(name) {
         bail!(translator.format("command.profile_not_found", &[("name", name)]));
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use crate::client::DaemonClient;
+use anyhow::Result;
+use flux_protocol::{FocusMode, Request, Response};
+
+/// The prompt is redrawn on every command, so a slow or missing daemon must
+/// never be noticeable: past this budget the segment is simply left empty.
+const PROMPT_TIMEOUT: Duration = Duration::from_millis(100);
+
+pub async fn execute() -> Result<()> {
+    let client = DaemonClient::new().with_timeout(PROMPT_TIMEOUT);
+    let response = tokio::time::timeout(PROMPT_TIMEOUT, client.send(Request::GetStatus)).await;
+
+    if let Ok(Ok(Response::SessionStatus {
+        active: true,
+        remaining_seconds,
+        mode,
+        paused,
+        preparing_seconds,
+        ..
+    })) = response
+    {
+        println!(
+            "{}",
+            format_segment(remaining_seconds, mode.as_ref(), paused, preparing_seconds)
+        );
+    }
+
+    Ok(())
+}
+
+fn format_segment(
+    remaining_seconds: u64,
+    mode: Option<&FocusMode>,
+    paused: bool,
+    preparing_seconds: Option<u64>,
+) -> String {
+    let (icon, seconds) = match preparing_seconds {
+        Some(seconds) => ("⏳", seconds),
+        None if paused => ("⏸", remaining_seconds),
+        None => ("🔥", remaining_seconds),
+    };
+
+    let mut segment = format!("{} {:02}:{:02}", icon, seconds / 60, seconds % 60);
+    if let Some(mode) = mode {
+        segment.push(' ');
+        segment.push_str(mode.as_str());
+    }
+    segment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_session_shows_countdown_and_mode() {
+        let segment = format_segment(754, Some(&FocusMode::AiAssisted), false, None);
+        assert_eq!(segment, "🔥 12:34 ai-assisted");
+    }
+
+    #[test]
+    fn paused_and_preparing_sessions_use_their_own_icon() {
+        assert_eq!(
+            format_segment(754, Some(&FocusMode::Review), true, None),
+            "⏸ 12:34 review"
+        );
+        assert_eq!(format_segment(1500, None, false, Some(20)), "⏳ 00:20");
+    }
+}
@@ -0,0 +1,482 @@
+use std::fmt::Write;
+
+use serde::Serialize;
+
+const MINIMUM_LABEL_WIDTH: usize = 14;
+const VALUE_WIDTH: usize = 8;
+const DELIMITED_HEADER: [&str; 4] = ["section", "key", "value", "percent"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Pretty,
+    Table,
+    Csv,
+    Tsv,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "pretty" | "text" => Some(OutputFormat::Pretty),
+            "table" => Some(OutputFormat::Table),
+            "csv" => Some(OutputFormat::Csv),
+            "tsv" => Some(OutputFormat::Tsv),
+            "json" => Some(OutputFormat::Json),
+            _ => None,
+        }
+    }
+
+    /// Machine-readable formats carry raw values only, for spreadsheets and scripts
+    pub fn is_machine_readable(self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Csv | OutputFormat::Tsv | OutputFormat::Json
+        )
+    }
+
+    pub fn renderer(self) -> Box<dyn Renderer> {
+        match self {
+            OutputFormat::Pretty => Box::new(PrettyRenderer),
+            OutputFormat::Table => Box::new(TableRenderer),
+            OutputFormat::Csv => Box::new(DelimitedRenderer { separator: ',' }),
+            OutputFormat::Tsv => Box::new(DelimitedRenderer { separator: '\t' }),
+            OutputFormat::Json => Box::new(JsonRenderer),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Duration(i64),
+    Count(i64),
+    Percent(u32),
+    Text(String),
+}
+
+impl Value {
+    fn display(&self) -> String {
+        match self {
+            Value::Duration(seconds) => format_duration(*seconds),
+            Value::Count(count) => count.to_string(),
+            Value::Percent(percent) => format!("{}%", percent),
+            Value::Text(text) => text.clone(),
+        }
+    }
+
+    fn raw(&self) -> String {
+        match self {
+            Value::Duration(seconds) | Value::Count(seconds) => seconds.to_string(),
+            Value::Percent(percent) => percent.to_string(),
+            Value::Text(text) => text.clone(),
+        }
+    }
+
+    fn json(&self) -> serde_json::Value {
+        match self {
+            Value::Duration(number) | Value::Count(number) => (*number).into(),
+            Value::Percent(percent) => (*percent).into(),
+            Value::Text(text) => text.as_str().into(),
+        }
+    }
+}
+
+/// One line of a report: `key` stays stable for scripts, `label` is translated for people
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub key: String,
+    pub label: String,
+    pub value: Value,
+    pub percent: Option<u32>,
+    pub detail: Option<String>,
+}
+
+impl Row {
+    pub fn new(key: impl Into<String>, label: impl Into<String>, value: Value) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            value,
+            percent: None,
+            detail: None,
+        }
+    }
+
+    pub fn with_percent(mut self, percent: u32) -> Self {
+        self.percent = Some(percent);
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        let detail = detail.into();
+        if !detail.is_empty() {
+            self.detail = Some(detail);
+        }
+        self
+    }
+}
+
+/// Untitled sections are key figures, titled ones are breakdowns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub id: &'static str,
+    pub title: Option<String>,
+    pub rows: Vec<Row>,
+}
+
+impl Section {
+    pub fn summary(id: &'static str, rows: Vec<Row>) -> Self {
+        Self {
+            id,
+            title: None,
+            rows,
+        }
+    }
+
+    pub fn breakdown(id: &'static str, title: impl Into<String>, rows: Vec<Row>) -> Self {
+        Self {
+            id,
+            title: Some(title.into()),
+            rows,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub heading: Vec<String>,
+    pub sections: Vec<Section>,
+    pub notes: Vec<String>,
+}
+
+impl Report {
+    pub fn push(&mut self, section: Section) {
+        if !section.rows.is_empty() {
+            self.sections.push(section);
+        }
+    }
+}
+
+pub trait Renderer {
+    fn render(&self, report: &Report) -> String;
+}
+
+/// Tree layout meant for the terminal
+pub struct PrettyRenderer;
+
+impl Renderer for PrettyRenderer {
+    fn render(&self, report: &Report) -> String {
+        let mut output = String::new();
+
+        if !report.heading.is_empty() {
+            output.push('\n');
+            for line in &report.heading {
+                let _ = writeln!(output, "{}", line);
+            }
+        }
+        output.push('\n');
+
+        for section in &report.sections {
+            match &section.title {
+                None => {
+                    for row in &section.rows {
+                        let _ = writeln!(
+                            output,
+                            "{}: {}{}",
+                            row.label,
+                            row.value.display(),
+                            suffix(row)
+                        );
+                    }
+                }
+                Some(title) => {
+                    let _ = writeln!(output, "{}:", title);
+                    let width = section
+                        .rows
+                        .iter()
+                        .map(|row| row.label.chars().count() + 1)
+                        .max()
+                        .unwrap_or(0)
+                        .max(MINIMUM_LABEL_WIDTH);
+                    for (index, row) in section.rows.iter().enumerate() {
+                        let prefix = if index == section.rows.len() - 1 {
+                            "└──"
+                        } else {
+                            "├──"
+                        };
+                        let _ = writeln!(
+                            output,
+                            "{} {:width$} {:>VALUE_WIDTH$}{}",
+                            prefix,
+                            format!("{}:", row.label),
+                            row.value.display(),
+                            suffix(row),
+                        );
+                    }
+                }
+            }
+            output.push('\n');
+        }
+
+        for note in &report.notes {
+            let _ = writeln!(output, "{}", note);
+        }
+        output
+    }
+}
+
+/// Aligned columns without decoration, ready for `column` or `grep`
+pub struct TableRenderer;
+
+impl Renderer for TableRenderer {
+    fn render(&self, report: &Report) -> String {
+        let label_width = report
+            .sections
+            .iter()
+            .flat_map(|section| &section.rows)
+            .map(|row| row.label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut output = String::new();
+
+        for section in &report.sections {
+            if let Some(title) = &section.title {
+                let _ = writeln!(output, "{}", title);
+            }
+            for row in &section.rows {
+                let line = format!(
+                    "{:label_width$}  {:>VALUE_WIDTH$}  {:>4}  {}",
+                    row.label,
+                    row.value.display(),
+                    row.percent
+                        .map(|percent| format!("{}%", percent))
+                        .unwrap_or_default(),
+                    row.detail.as_deref().unwrap_or_default(),
+                );
+                let _ = writeln!(output, "{}", line.trim_end());
+            }
+            output.push('\n');
+        }
+
+        for note in &report.notes {
+            let _ = writeln!(output, "{}", note);
+        }
+        output
+    }
+}
+
+/// One record per row with raw values: seconds for durations, an empty field for no percentage
+pub struct DelimitedRenderer {
+    separator: char,
+}
+
+impl DelimitedRenderer {
+    fn field(&self, value: &str) -> String {
+        if self.separator == '\t' {
+            return value.replace(['\t', '\n', '\r'], " ");
+        }
+        if value.contains([self.separator, '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn record(&self, fields: &[&str]) -> String {
+        let fields: Vec<String> = fields.iter().map(|field| self.field(field)).collect();
+        format!("{}\n", fields.join(&self.separator.to_string()))
+    }
+}
+
+impl Renderer for DelimitedRenderer {
+    fn render(&self, report: &Report) -> String {
+        let mut output = self.record(&DELIMITED_HEADER);
+
+        for section in &report.sections {
+            for row in &section.rows {
+                let percent = row
+                    .percent
+                    .map(|percent| percent.to_string())
+                    .unwrap_or_default();
+                output.push_str(&self.record(&[section.id, &row.key, &row.value.raw(), &percent]));
+            }
+        }
+        output
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    sections: Vec<JsonSection<'a>>,
+    notes: &'a [String],
+}
+
+#[derive(Serialize)]
+struct JsonSection<'a> {
+    id: &'a str,
+    rows: Vec<JsonRow<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonRow<'a> {
+    key: &'a str,
+    value: serde_json::Value,
+    percent: Option<u32>,
+}
+
+/// Sections keyed by their stable ids, with the same raw values as the delimited formats
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, report: &Report) -> String {
+        let output = JsonReport {
+            sections: report
+                .sections
+                .iter()
+                .map(|section| JsonSection {
+                    id: section.id,
+                    rows: section
+                        .rows
+                        .iter()
+                        .map(|row| JsonRow {
+                            key: &row.key,
+                            value: row.value.json(),
+                            percent: row.percent,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            notes: &report.notes,
+        };
+        let mut json = serde_json::to_string_pretty(&output).unwrap_or_default();
+        json.push('\n');
+        json
+    }
+}
+
+/// Share of `total`, rounded down
+pub fn percent(part: i64, total: i64) -> u32 {
+    (part as f64 / total.max(1) as f64 * 100.0) as u32
+}
+
+pub fn format_duration(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+
+    if hours > 0 {
+        format!("{}h {:02}min", hours, minutes)
+    } else {
+        format!("{}min", minutes)
+    }
+}
+
+fn suffix(row: &Row) -> String {
+    let mut suffix = String::new();
+    if let Some(percent) = row.percent {
+        let _ = write!(suffix, " ({}%)", percent);
+    }
+    if let Some(detail) = &row.detail {
+        let _ = write!(suffix, " {}", detail);
+    }
+    suffix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        let mut report = Report {
+            heading: vec!["Statistics (week)".to_string()],
+            ..Report::default()
+        };
+        report.push(Section::summary(
+            "summary",
+            vec![
+                Row::new("total_time", "Total time", Value::Duration(5400)),
+                Row::new("sessions", "Sessions", Value::Count(3)),
+            ],
+        ));
+        report.push(Section::breakdown(
+            "focus_applications",
+            "Applications",
+            vec![
+                Row::new("cursor", "cursor", Value::Duration(3600)).with_percent(66),
+                Row::new("Slack, general", "Slack, general", Value::Duration(1800))
+                    .with_percent(33),
+            ],
+        ));
+        report.push(Section::breakdown("by_tag", "Tags", Vec::new()));
+        report
+    }
+
+    #[test]
+    fn pretty_layout_draws_breakdowns_as_trees() {
+        let output = PrettyRenderer.render(&report());
+
+        assert!(output.contains("Total time: 1h 30min\n"));
+        assert!(output.contains("Applications:\n├── cursor:"));
+        assert!(output.contains("└── Slack, general:    30min (33%)\n"));
+        assert!(!output.contains("Tags"));
+    }
+
+    #[test]
+    fn table_layout_aligns_columns_without_decoration() {
+        let output = TableRenderer.render(&report());
+
+        assert!(output.contains("Total time      1h 30min\n"));
+        assert!(output.contains("cursor          1h 00min   66%\n"));
+        assert!(!output.contains("──"));
+    }
+
+    #[test]
+    fn delimited_layouts_carry_raw_values() {
+        let csv = OutputFormat::Csv.renderer().render(&report());
+        let tsv = OutputFormat::Tsv.renderer().render(&report());
+
+        assert_eq!(
+            csv,
+            "section,key,value,percent\n\
+             summary,total_time,5400,\n\
+             summary,sessions,3,\n\
+             focus_applications,cursor,3600,66\n\
+             focus_applications,\"Slack, general\",1800,33\n"
+        );
+        assert!(tsv.starts_with("section\tkey\tvalue\tpercent\n"));
+        assert!(tsv.contains("focus_applications\tSlack, general\t1800\t33\n"));
+    }
+
+    #[test]
+    fn json_layout_keeps_section_ids_and_raw_values() {
+        let output = OutputFormat::Json.renderer().render(&report());
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(json["sections"][0]["id"], "summary");
+        assert_eq!(json["sections"][0]["rows"][0]["key"], "total_time");
+        assert_eq!(json["sections"][0]["rows"][0]["value"], 5400);
+        assert!(json["sections"][0]["rows"][0]["percent"].is_null());
+        assert_eq!(json["sections"][1]["rows"][1]["percent"], 33);
+        assert_eq!(json["sections"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn format_duration_shows_hours_and_minutes() {
+        assert_eq!(format_duration(3661), "1h 01min");
+        assert_eq!(format_duration(7200), "2h 00min");
+    }
+
+    #[test]
+    fn format_duration_shows_only_minutes_when_under_hour() {
+        assert_eq!(format_duration(1500), "25min");
+        assert_eq!(format_duration(60), "1min");
+        assert_eq!(format_duration(0), "0min");
+    }
+
+    #[test]
+    fn formats_are_parsed_case_insensitively() {
+        assert_eq!(OutputFormat::from_str("CSV"), Some(OutputFormat::Csv));
+        assert_eq!(OutputFormat::from_str("text"), Some(OutputFormat::Pretty));
+        assert_eq!(OutputFormat::from_str("JSON"), Some(OutputFormat::Json));
+        assert_eq!(OutputFormat::from_str("xml"), None);
+    }
+}
@@ -0,0 +1,341 @@
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use dialoguer::Confirm;
+use flux_core::{Config, Translator};
+use flux_protocol::{Feature, Request, Response, PROTOCOL_VERSION};
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+use crate::client::DaemonClient;
+use crate::commands::doctor;
+use crate::daemon_launcher;
+
+const LOG_LINES: usize = 1000;
+const REDACTED: &str = "[retiré]";
+/// Keys whose values can hold credentials, private addresses, calendar links
+/// or words of window titles
+const SENSITIVE_KEY_PARTS: [&str; 5] = ["token", "password", "secret", "url", "title_patterns"];
+const SENSITIVE_KEYS: [&str; 4] = ["username", "host", "from", "to"];
+/// Log fields holding a window or meeting title
+const TITLE_FIELDS: [&str; 2] = ["window_title=", "title="];
+
+pub async fn execute(output: Option<PathBuf>, skip_confirmation: bool) -> Result<()> {
+    let config = Config::load().ok();
+    let translator = config
+        .as_ref()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default();
+
+    println!("{}", translator.get("command.report_bug_intro"));
+    println!("{}", translator.get("command.report_bug_always_included"));
+    println!();
+
+    let include_config = skip_confirmation
+        || Confirm::new()
+            .with_prompt(translator.get("command.report_bug_include_config"))
+            .default(true)
+            .interact()?;
+    let include_logs = skip_confirmation
+        || Confirm::new()
+            .with_prompt(translator.format(
+                "command.report_bug_include_logs",
+                &[("lines", &LOG_LINES.to_string())],
+            ))
+            .default(true)
+            .interact()?;
+
+    let mut files = vec![
+        ("versions.txt", versions().await),
+        ("doctor.txt", doctor::report(&translator).await),
+    ];
+    if include_config {
+        files.push(("config.toml", sanitized_config()?));
+    }
+    if include_logs {
+        let patterns = config
+            .map(|config| config.privacy.redact_title_patterns)
+            .unwrap_or_default();
+        match recent_logs(&daemon_launcher::log_path(), &patterns) {
+            Some(logs) => files.push(("daemon.log", logs)),
+            None => println!("{}", translator.get("command.report_bug_logs_missing")),
+        }
+    }
+
+    let path = output.unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "flux-bug-report-{}.zip",
+            Local::now().format("%Y%m%d-%H%M%S")
+        ))
+    });
+    write_archive(&path, &files)?;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.report_bug_written",
+            &[("path", &path.display().to_string())]
+        )
+    );
+    println!("{}", translator.get("command.report_bug_review_hint"));
+
+    Ok(())
+}
+
+async fn versions() -> String {
+    let daemon_version = match DaemonClient::new()
+        .send(Request::Handshake {
+            protocol_version: PROTOCOL_VERSION,
+            client_version: env!("CARGO_PKG_VERSION").to_string(),
+        })
+        .await
+    {
+        Ok(Response::Handshake {
+            protocol_version,
+            daemon_version,
+            features,
+            ..
+        }) => format!(
+            "{} (protocol {}, features: {})",
+            daemon_version,
+            protocol_version,
+            features
+                .iter()
+                .map(Feature::id)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Ok(_) => "unexpected response".to_string(),
+        Err(error) => format!("unavailable: {}", error),
+    };
+
+    format!(
+        "flux: {}\nprotocol: {}\ndaemon: {}\nos: {} ({})\n",
+        env!("CARGO_PKG_VERSION"),
+        PROTOCOL_VERSION,
+        daemon_version,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+    )
+}
+
+fn sanitized_config() -> Result<String> {
+    let path = Config::config_path();
+    if !path.exists() {
+        return Ok(format!("# {} not found\n", path.display()));
+    }
+
+    let document = fs::read_to_string(&path)
+        .with_context(|| format!("impossible de lire {}", path.display()))?;
+    Ok(redact_config(&document)
+        .unwrap_or_else(|error| format!("# configuration invalide, non incluse: {}\n", error)))
+}
+
+fn redact_config(document: &str) -> Result<String, toml::de::Error> {
+    let mut value: toml::Value = toml::from_str(document)?;
+    redact(&mut value);
+    Ok(toml::to_string_pretty(&value).unwrap_or_default())
+}
+
+fn redact(value: &mut toml::Value) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table.iter_mut() {
+                if is_sensitive(key) && !value.is_table() {
+                    *value = toml::Value::String(REDACTED.to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        toml::Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+fn is_sensitive(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SENSITIVE_KEYS.contains(&key.as_str())
+        || SENSITIVE_KEY_PARTS.iter().any(|part| key.contains(part))
+}
+
+fn recent_logs(path: &Path, patterns: &HashSet<String>) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let lines: Vec<&str> = content.lines().collect();
+    let recent = &lines[lines.len().saturating_sub(LOG_LINES)..];
+    Some(
+        recent
+            .iter()
+            .map(|line| format!("{}\n", scrub_log_line(line, patterns)))
+            .collect(),
+    )
+}
+
+/// A title field is cut with the rest of the line, since a title may hold
+/// spaces and `=`; a line mentioning one of the privacy patterns is dropped
+fn scrub_log_line(line: &str, patterns: &HashSet<String>) -> String {
+    let lowercase = line.to_lowercase();
+    if patterns
+        .iter()
+        .any(|pattern| lowercase.contains(&pattern.to_lowercase()))
+    {
+        return REDACTED.to_string();
+    }
+
+    let title_start = TITLE_FIELDS
+        .iter()
+        .filter_map(|field| {
+            line.match_indices(field)
+                .find(|(index, _)| *index == 0 || line[..*index].ends_with(' '))
+                .map(|(index, _)| index + field.len())
+        })
+        .min();
+    match title_start {
+        Some(start) => format!("{}{}", &line[..start], REDACTED),
+        None => line.to_string(),
+    }
+}
+
+fn write_archive(path: &Path, files: &[(&str, String)]) -> Result<()> {
+    let file =
+        File::create(path).with_context(|| format!("impossible de créer {}", path.display()))?;
+    let mut archive = ZipWriter::new(file);
+
+    for (name, content) in files {
+        archive.start_file(*name, SimpleFileOptions::default())?;
+        archive.write_all(content.as_bytes())?;
+    }
+    archive.finish()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn credentials_and_addresses_are_removed_from_the_config() {
+        let document = r#"
+[general]
+language = "fr"
+
+[gitlab]
+base_url = "https://gitlab.internal.example"
+token = "glpat-secret"
+
+[calendar]
+url = "https://calendar.example/private-abc.ics"
+auto_pause = true
+
+[profile.default.digest.delivery.smtp]
+host = "smtp.example.com"
+username = "damien"
+from = "flux@example.com"
+to = ["damien@example.com"]
+"#;
+
+        let redacted = redact_config(document).unwrap();
+
+        assert!(redacted.contains("language = \"fr\""));
+        assert!(redacted.contains("auto_pause = true"));
+        for secret in [
+            "gitlab.internal",
+            "glpat-secret",
+            "private-abc",
+            "smtp.example.com",
+            "damien",
+            "@example.com",
+        ] {
+            assert!(!redacted.contains(secret), "{} leaked", secret);
+        }
+    }
+
+    #[test]
+    fn title_patterns_are_removed_from_the_config() {
+        let document = r#"
+[privacy]
+redact_title_patterns = ["acme-bank"]
+
+[profile.default.distractions]
+title_patterns = ["secret-project"]
+"#;
+
+        let redacted = redact_config(document).unwrap();
+
+        assert!(!redacted.contains("acme-bank"));
+        assert!(!redacted.contains("secret-project"));
+    }
+
+    #[test]
+    fn titles_are_removed_from_the_logs() {
+        let patterns = HashSet::from(["Banking".to_string()]);
+        let detected = "2026-10-16T09:12:03Z DEBUG flux_daemon::window::x11_detector: \
+            detected active window window_class=firefox \
+            window_title=Some(\"ACME-1234 - Jira\")";
+        let meeting = "2026-10-16T09:30:00Z  INFO flux_daemon::calendar: \
+            meeting started, pausing session title=Salary review with Bob";
+
+        let scrubbed = scrub_log_line(detected, &patterns);
+        assert!(scrubbed.contains("window_class=firefox"));
+        assert!(!scrubbed.contains("ACME"));
+        assert!(!scrub_log_line(meeting, &patterns).contains("Salary review"));
+        assert_eq!(
+            scrub_log_line("opened my banking app", &patterns),
+            REDACTED.to_string()
+        );
+        assert_eq!(
+            scrub_log_line("session started mode=deep", &patterns),
+            "session started mode=deep"
+        );
+    }
+
+    #[test]
+    fn only_the_most_recent_log_lines_are_kept() {
+        let directory =
+            std::env::temp_dir().join(format!("flux-report-logs-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("daemon.log");
+        let lines: Vec<String> = (0..LOG_LINES + 5)
+            .map(|index| format!("line {}", index))
+            .collect();
+        fs::write(&path, lines.join("\n")).unwrap();
+
+        let logs = recent_logs(&path, &HashSet::new()).unwrap();
+
+        assert_eq!(logs.lines().count(), LOG_LINES);
+        assert!(logs.starts_with("line 5\n"));
+        assert!(recent_logs(&directory.join("missing.log"), &HashSet::new()).is_none());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn archive_contains_every_collected_file() {
+        let path = std::env::temp_dir().join(format!("flux-report-{}.zip", std::process::id()));
+
+        write_archive(
+            &path,
+            &[
+                ("versions.txt", "flux: 0.2.13\n".to_string()),
+                ("doctor.txt", "  ✓ ok\n".to_string()),
+            ],
+        )
+        .unwrap();
+
+        let mut archive = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        let mut versions = String::new();
+        archive
+            .by_name("versions.txt")
+            .unwrap()
+            .read_to_string(&mut versions)
+            .unwrap();
+        assert_eq!(archive.len(), 2);
+        assert_eq!(versions, "flux: 0.2.13\n");
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -1,13 +1,14 @@
 use crate::client::{ClientError, DaemonClient};
+use crate::daemon_launcher::send_starting_daemon;
 use anyhow::{bail, Result};
 use flux_core::{Config, Translator};
 use flux_protocol::{Request, Response};
 
-pub async fn execute() -> Result<()> {
+pub async fn execute(auto_start: bool) -> Result<()> {
     let translator = get_translator();
     let client = DaemonClient::new();
 
-    match client.send(Request::ResumeSession).await {
+    match send_starting_daemon(&client, Request::ResumeSession, auto_start, &translator).await {
         Ok(Response::Ok) => {
             println!("{}", translator.get("command.resume_success"));
         }
@@ -41,6 +42,6 @@ pub async fn execute() -> Result<()> {
 
 fn get_translator() -> Translator {
     Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
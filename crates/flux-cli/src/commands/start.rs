@@ -1,11 +1,37 @@
-use crate::client::{ClientError, DaemonClient};
-use crate::daemon_launcher::ensure_daemon_running;
+use crate::client::DaemonClient;
+use crate::daemon_launcher::send_starting_daemon;
 use anyhow::{bail, Result};
-use flux_core::{Config, Translator};
-use flux_protocol::{FocusMode, Request, Response};
+use flux_core::{Config, StartOptions, Translator, WorkspaceConfig};
+use flux_protocol::{new_request_id, FocusMode, Request, Response};
 
-pub async fn execute(duration: Option<u64>, mode: Option<String>) -> Result<()> {
-    let translator = get_translator();
+pub async fn execute(
+    duration: Option<u64>,
+    mode: Option<String>,
+    tags: Vec<String>,
+    strict: bool,
+) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+
+    let options = StartOptions {
+        mode,
+        duration_minutes: duration,
+        tags,
+        distraction_apps: Vec::new(),
+    };
+    let workspace = match std::env::current_dir() {
+        Ok(directory) => WorkspaceConfig::discover(&directory)?,
+        Err(_) => None,
+    };
+    let StartOptions {
+        mode,
+        duration_minutes: duration,
+        tags,
+        distraction_apps,
+    } = match &workspace {
+        Some((_, workspace)) => workspace.apply(options),
+        None => options,
+    };
 
     let focus_mode = match mode.as_deref() {
         Some("ai-assisted") => Some(FocusMode::AiAssisted),
@@ -17,35 +43,40 @@ pub async fn execute(duration: Option<u64>, mode: Option<String>) -> Result<()>
     };
 
     let client = DaemonClient::new();
+    let request_id = new_request_id();
 
-    let response = match client
-        .send(Request::StartSession {
+    let response = send_starting_daemon(
+        &client,
+        Request::StartSession {
             duration,
             mode: focus_mode.clone(),
-        })
-        .await
-    {
-        Ok(response) => response,
-        Err(ClientError::DaemonNotRunning) => {
-            ensure_daemon_running().await?;
-            client
-                .send(Request::StartSession {
-                    duration,
-                    mode: focus_mode.clone(),
-                })
-                .await?
-        }
-        Err(error) => return Err(error.into()),
-    };
+            tags: tags.clone(),
+            request_id: Some(request_id),
+            strict,
+            distraction_apps: distraction_apps.clone(),
+        },
+        true,
+        &translator,
+    )
+    .await?;
 
     match response {
         Response::Ok => {
-            let duration_display = duration.unwrap_or(25);
-            let mode_display = focus_mode
-                .map(format_mode)
-                .unwrap_or_else(|| "ai-assisted".to_string());
+            let selected_mode = focus_mode.unwrap_or(FocusMode::AiAssisted);
+            let duration_display =
+                duration.unwrap_or(config.focus().settings_for(&selected_mode).duration_minutes);
+            let mode_display = translator.mode_name(&selected_mode);
 
             println!("{}", translator.get("command.start_success"));
+            if let Some((path, _)) = &workspace {
+                println!(
+                    "{}",
+                    translator.format(
+                        "command.start_workspace",
+                        &[("path", &path.display().to_string())]
+                    )
+                );
+            }
             println!(
                 "{}",
                 translator.format(
@@ -57,6 +88,39 @@ pub async fn execute(duration: Option<u64>, mode: Option<String>) -> Result<()>
                 "{}",
                 translator.format("command.start_mode", &[("mode", &mode_display)])
             );
+            if !tags.is_empty() {
+                println!(
+                    "{}",
+                    translator.format("command.start_tags", &[("tags", &tags.join(", "))])
+                );
+            }
+            if !distraction_apps.is_empty() {
+                println!(
+                    "{}",
+                    translator.format(
+                        "command.start_distractions",
+                        &[("apps", &distraction_apps.join(", "))]
+                    )
+                );
+            }
+            if strict {
+                println!("{}", translator.get("command.start_strict"));
+            }
+        }
+        Response::AlreadyActive {
+            mode,
+            remaining_seconds,
+        } => {
+            bail!(
+                "{}",
+                translator.format(
+                    "error.session_already_active",
+                    &[
+                        ("mode", &translator.mode_name(&mode)),
+                        ("minutes", &remaining_seconds.div_ceil(60).to_string()),
+                    ]
+                )
+            );
         }
         Response::Error { message } => {
             bail!("{}", message);
@@ -68,19 +132,3 @@ pub async fn execute(duration: Option<u64>, mode: Option<String>) -> Result<()>
 
     Ok(())
 }
-
-fn get_translator() -> Translator {
-    Config::load()
-        .map(|config| Translator::new(config.general.language))
-        .unwrap_or_default()
-}
-
-fn format_mode(mode: FocusMode) -> String {
-    match mode {
-        FocusMode::AiAssisted => "ai-assisted".to_string(),
-        FocusMode::Review => "review".to_string(),
-        FocusMode::Architecture => "architecture".to_string(),
-        FocusMode::Veille => "veille".to_string(),
-        FocusMode::Custom(name) => name,
-    }
-}
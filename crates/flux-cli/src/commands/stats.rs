@@ -1,13 +1,23 @@
 use std::collections::HashMap;
 
 use anyhow::{Context, Result};
-use chrono::{Duration, Local, Utc};
-use flux_adapters::{SqliteAppTrackingRepository, SqliteSessionRepository};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use flux_adapters::{
+    Database, IcsCalendarGateway, SqliteAppTrackingRepository, SqliteSessionMetricsRepository,
+    SqliteSessionRepository,
+};
 use flux_core::{
-    AppTrackingRepository, AppUsage, Config, DistractionConfig, Session, SessionRepository,
-    Translator,
+    completion_rate, AppTrackingRepository, AppUsage, CalendarConfig, CalendarEvent,
+    CalendarGateway, Config, DistractionConfig, FocusMode, InterruptionReasonCount, PrivacyConfig,
+    ScoreBreakdown, ScoreWeights, Session, SessionMetrics, SessionMetricsRepository,
+    SessionRepository, Translator, UsageRollupRepository, WeekStart, WeeklyModeTotal,
 };
 
+use super::render::{percent, OutputFormat, Report, Row, Section, Value};
+
+const TREND_WEEKS: i64 = 8;
+const ALL_TIME_DAYS: i64 = 365 * 10;
+
 #[derive(Debug, Clone, Copy)]
 pub enum Period {
     Today,
@@ -37,27 +47,292 @@ impl Period {
     }
 }
 
-pub async fn execute(period: Period) -> Result<()> {
+#[derive(Debug, Clone, Copy)]
+pub enum Trend {
+    Mode,
+}
+
+impl Trend {
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "mode" => Some(Trend::Mode),
+            _ => None,
+        }
+    }
+}
+
+pub async fn execute(
+    period: Period,
+    tag: Option<String>,
+    by_project: bool,
+    explain_score: bool,
+    exact: bool,
+    format: OutputFormat,
+) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
-    let repository = open_repository()?;
-    let sessions = fetch_sessions(&repository, period)?;
+    let translator = Translator::new(config.language());
+    let database = open_database(&config.privacy)?;
+    let repository = SqliteSessionRepository::with_database(database.clone());
+    let sessions = filter_by_tag(fetch_sessions(&repository, period)?, tag.as_deref());
 
+    let renderer = format.renderer();
     if sessions.is_empty() {
-        println!("{}", translator.get("command.stats_no_sessions"));
+        if format.is_machine_readable() {
+            print!("{}", renderer.render(&Report::default()));
+        } else {
+            println!("{}", translator.get("command.stats_no_sessions"));
+        }
         return Ok(());
     }
 
     let session_ids: Vec<i64> = sessions.iter().filter_map(|s| s.id).collect();
-    let app_usages = fetch_app_tracking(&session_ids);
+    let rollups = if uses_rollups(period, tag.as_deref(), exact) {
+        fetch_rollup_usages(&database)
+    } else {
+        None
+    };
+    let (app_usages, rollups_refreshed_at) = match rollups {
+        Some((usages, refreshed_at)) => (usages, Some(refreshed_at)),
+        None => (fetch_app_tracking(&database, &session_ids), None),
+    };
 
     let stats = compute_stats(&sessions, &app_usages, config.distractions());
-    display_stats(&stats, period, &translator);
+    let mut report = stats_report(&stats, period, tag.as_deref(), &translator);
+    report.push(interruption_reasons_section(
+        &repository
+            .interruption_reasons(&session_ids)
+            .unwrap_or_default(),
+        &translator,
+    ));
+
+    if let Some(calendar) = &config.calendar {
+        match meeting_time_section(calendar, &sessions, &translator).await {
+            Ok(section) => report.push(section),
+            Err(note) => report.notes.push(note),
+        }
+    }
+
+    if let Some(refreshed_at) = rollups_refreshed_at {
+        let refreshed_at: DateTime<Local> = refreshed_at.into();
+        report.notes.push(translator.format(
+            "command.stats_rollups_freshness",
+            &[(
+                "refreshed_at",
+                &refreshed_at.format("%Y-%m-%d %H:%M").to_string(),
+            )],
+        ));
+    }
+
+    if by_project {
+        let projects = aggregate_projects(&fetch_session_metrics(&database, &session_ids));
+        if projects.is_empty() {
+            report
+                .notes
+                .push(translator.get("command.stats_no_projects"));
+        } else {
+            report.push(applications_section(
+                "by_project",
+                &projects,
+                translator.get("command.stats_by_project"),
+            ));
+        }
+    }
+
+    print!("{}", renderer.render(&report));
+
+    if explain_score && !format.is_machine_readable() {
+        display_score_explanation(
+            &fetch_session_metrics(&database, &session_ids),
+            &config.metrics.score_weights(),
+            &translator,
+        );
+    }
+
+    Ok(())
+}
+
+fn display_score_explanation(
+    session_metrics: &[SessionMetrics],
+    weights: &ScoreWeights,
+    translator: &Translator,
+) {
+    println!();
+    if session_metrics.is_empty() {
+        println!("{}", translator.get("command.stats_score_no_metrics"));
+        return;
+    }
+
+    let breakdowns: Vec<ScoreBreakdown> = session_metrics
+        .iter()
+        .map(|metrics| metrics.score_breakdown_with(weights))
+        .collect();
+    let average_score = breakdowns
+        .iter()
+        .map(|breakdown| breakdown.score as u32)
+        .sum::<u32>()
+        / breakdowns.len() as u32;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.stats_score_header",
+            &[
+                ("score", &average_score.to_string()),
+                ("sessions", &breakdowns.len().to_string()),
+            ],
+        )
+    );
+
+    let contributions = ScoreBreakdown::average_contributions(&breakdowns);
+    if contributions.is_empty() {
+        println!("└── {}", translator.get("command.stats_score_no_penalty"));
+    }
+    for (index, contribution) in contributions.iter().enumerate() {
+        let prefix = if index == contributions.len() - 1 {
+            "└──"
+        } else {
+            "├──"
+        };
+        let label = translator.format(
+            &format!("command.score_factor_{}", contribution.factor.as_str()),
+            &[("count", &contribution.count.to_string())],
+        );
+        println!("{} {:36} {:>4}", prefix, label, contribution.points);
+    }
+
+    println!(
+        "{}",
+        translator.format(
+            "command.stats_score_weights",
+            &[
+                ("context_switch", &weights.context_switch.to_string()),
+                ("short_burst", &weights.short_burst.to_string()),
+                ("distraction_ratio", &weights.distraction_ratio.to_string()),
+                ("pause", &weights.pause.to_string()),
+            ],
+        )
+    );
+}
+
+pub async fn trend(trend: Trend) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let repository = SqliteSessionRepository::with_database(open_database(&config.privacy)?);
+
+    match trend {
+        Trend::Mode => display_mode_trend(&repository, &translator, config.general.week_starts_on),
+    }
+}
+
+fn display_mode_trend(
+    repository: &SqliteSessionRepository,
+    translator: &Translator,
+    week_start: WeekStart,
+) -> Result<()> {
+    let weeks = trend_weeks(Local::now().date_naive(), week_start);
+    let since = weeks[0]
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_local_timezone(Local)
+        .unwrap()
+        .with_timezone(&Utc);
+    let totals = repository
+        .weekly_mode_totals(since, week_start)
+        .map_err(|error| anyhow::anyhow!("read error: {}", error))?;
+
+    if totals.is_empty() {
+        println!("{}", translator.get("command.stats_no_sessions"));
+        return Ok(());
+    }
+
+    let (modes, rows) = mode_trend_table(&weeks, &totals);
+    let width = modes.iter().map(String::len).max().unwrap_or(0).max(6) + 2;
+
+    println!();
+    println!(
+        "{}",
+        translator.format(
+            "command.stats_trend_mode_header",
+            &[("weeks", &TREND_WEEKS.to_string())]
+        )
+    );
+    println!();
+    print!("{:12}", translator.get("command.stats_trend_week"));
+    for mode in &modes {
+        print!("{:>width$}", mode, width = width);
+    }
+    println!();
+    for (week_start, seconds) in rows {
+        print!("{:12}", week_start.format("%Y-%m-%d").to_string());
+        for seconds in seconds {
+            print!("{:>width$}", format_hours(seconds), width = width);
+        }
+        println!();
+    }
+    println!();
 
     Ok(())
 }
 
-fn open_repository() -> Result<SqliteSessionRepository> {
+fn trend_weeks(today: NaiveDate, week_start: WeekStart) -> Vec<NaiveDate> {
+    let current_week = week_start.week_of(today);
+    (0..TREND_WEEKS)
+        .rev()
+        .map(|offset| current_week - Duration::weeks(offset))
+        .collect()
+}
+
+fn mode_trend_table(
+    weeks: &[NaiveDate],
+    totals: &[WeeklyModeTotal],
+) -> (Vec<String>, Vec<(NaiveDate, Vec<i64>)>) {
+    let mut seconds_by_mode: HashMap<String, i64> = HashMap::new();
+    for total in totals {
+        *seconds_by_mode.entry(total.mode.to_string()).or_insert(0) += total.seconds;
+    }
+
+    let mut modes: Vec<(String, i64)> = seconds_by_mode.into_iter().collect();
+    modes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    let modes: Vec<String> = modes.into_iter().map(|(mode, _)| mode).collect();
+
+    let rows = weeks
+        .iter()
+        .map(|week_start| {
+            let seconds = modes
+                .iter()
+                .map(|mode| {
+                    totals
+                        .iter()
+                        .filter(|total| {
+                            total.week_start == *week_start && total.mode.to_string() == *mode
+                        })
+                        .map(|total| total.seconds)
+                        .sum()
+                })
+                .collect();
+            (*week_start, seconds)
+        })
+        .collect();
+
+    (modes, rows)
+}
+
+fn format_hours(seconds: i64) -> String {
+    format!("{:.1}h", seconds as f64 / 3600.0)
+}
+
+fn filter_by_tag(sessions: Vec<Session>, tag: Option<&str>) -> Vec<Session> {
+    match tag {
+        Some(tag) => sessions
+            .into_iter()
+            .filter(|session| session.has_tag(tag))
+            .collect(),
+        None => sessions,
+    }
+}
+
+/// Opened once per command and shared by every repository it reads from
+fn open_database(privacy: &PrivacyConfig) -> Result<Database> {
     let data_dir = dirs::data_dir()
         .context("cannot find data directory")?
         .join("flux");
@@ -68,7 +343,8 @@ fn open_repository() -> Result<SqliteSessionRepository> {
         anyhow::bail!("no session data. Start a session first with 'flux start'.");
     }
 
-    SqliteSessionRepository::new(&database_path)
+    Database::open(&database_path)
+        .and_then(|database| database.with_privacy(privacy))
         .map_err(|error| anyhow::anyhow!("database access error: {}", error))
 }
 
@@ -83,7 +359,7 @@ fn fetch_sessions(repository: &SqliteSessionRepository, period: Period) -> Resul
             .with_timezone(&Utc),
         Period::Week => Utc::now() - Duration::days(7),
         Period::Month => Utc::now() - Duration::days(30),
-        Period::All => Utc::now() - Duration::days(365 * 10),
+        Period::All => Utc::now() - Duration::days(ALL_TIME_DAYS),
     };
 
     repository
@@ -91,34 +367,58 @@ fn fetch_sessions(repository: &SqliteSessionRepository, period: Period) -> Resul
         .map_err(|error| anyhow::anyhow!("read error: {}", error))
 }
 
-fn fetch_app_tracking(session_ids: &[i64]) -> Vec<AppUsage> {
-    let data_dir = match dirs::data_dir() {
-        Some(dir) => dir.join("flux"),
-        None => return Vec::new(),
-    };
+fn fetch_app_tracking(database: &Database, session_ids: &[i64]) -> Vec<AppUsage> {
+    SqliteAppTrackingRepository::with_database(database.clone())
+        .find_by_sessions(session_ids)
+        .unwrap_or_default()
+}
 
-    let database_path = data_dir.join("sessions.db");
+/// Long periods read the daily rollups unless a tag filter or `--exact` needs per-session rows
+fn uses_rollups(period: Period, tag: Option<&str>, exact: bool) -> bool {
+    matches!(period, Period::All) && tag.is_none() && !exact
+}
 
-    if !database_path.exists() {
-        return Vec::new();
-    }
+/// Application totals from the daily rollups, None when they were never computed
+fn fetch_rollup_usages(database: &Database) -> Option<(Vec<AppUsage>, DateTime<Utc>)> {
+    let repository = SqliteAppTrackingRepository::with_database(database.clone());
+    let refreshed_at = repository.last_refreshed_at().ok()??;
+    let since = Local::now().date_naive() - Duration::days(ALL_TIME_DAYS);
+    let usages = repository
+        .find_rollups_since(since)
+        .ok()?
+        .into_iter()
+        .map(|rollup| AppUsage::with_duration(0, rollup.application_name, rollup.seconds))
+        .collect();
+
+    Some((usages, refreshed_at))
+}
 
-    let repository = match SqliteAppTrackingRepository::new(&database_path) {
-        Ok(repo) => repo,
-        Err(_) => return Vec::new(),
-    };
+fn fetch_session_metrics(database: &Database, session_ids: &[i64]) -> Vec<SessionMetrics> {
+    SqliteSessionMetricsRepository::with_database(database.clone())
+        .find_by_sessions(session_ids)
+        .unwrap_or_default()
+}
 
-    repository.find_by_sessions(session_ids).unwrap_or_default()
+fn aggregate_projects(session_metrics: &[SessionMetrics]) -> HashMap<String, i64> {
+    let mut projects: HashMap<String, i64> = HashMap::new();
+    for metrics in session_metrics {
+        for (project, seconds) in &metrics.project_seconds {
+            *projects.entry(project.clone()).or_insert(0) += seconds;
+        }
+    }
+    projects
 }
 
 struct Stats {
     total_seconds: i64,
     session_count: usize,
     by_mode: HashMap<String, i64>,
+    by_tag: HashMap<String, i64>,
     focus_applications: HashMap<String, i64>,
     distraction_applications: HashMap<String, i64>,
     total_distraction_seconds: i64,
     total_check_ins: i32,
+    completion_rate: Option<u32>,
 }
 
 fn compute_stats(
@@ -128,6 +428,7 @@ fn compute_stats(
 ) -> Stats {
     let mut total_seconds = 0i64;
     let mut by_mode: HashMap<String, i64> = HashMap::new();
+    let mut by_tag: HashMap<String, i64> = HashMap::new();
     let mut total_check_ins = 0i32;
 
     for session in sessions {
@@ -137,6 +438,10 @@ fn compute_stats(
 
         let mode_key = session.mode.to_string();
         *by_mode.entry(mode_key).or_insert(0) += duration;
+
+        for tag in &session.tags {
+            *by_tag.entry(tag.clone()).or_insert(0) += duration;
+        }
     }
 
     let mut focus_applications: HashMap<String, i64> = HashMap::new();
@@ -160,174 +465,217 @@ fn compute_stats(
         total_seconds,
         session_count: sessions.len(),
         by_mode,
+        by_tag,
         focus_applications,
         distraction_applications,
         total_distraction_seconds,
         total_check_ins,
+        completion_rate: completion_rate(sessions),
     }
 }
 
-fn display_stats(stats: &Stats, period: Period, translator: &Translator) {
-    println!();
-    println!(
+fn stats_report(
+    stats: &Stats,
+    period: Period,
+    tag: Option<&str>,
+    translator: &Translator,
+) -> Report {
+    let mut heading = vec![format!(
         "{} ({})",
         translator.get("command.stats_header"),
         period.label(translator)
-    );
-    println!();
-    println!(
-        "{}: {}",
-        translator.get("command.stats_total_time"),
-        format_duration(stats.total_seconds)
-    );
-    println!(
-        "{}: {}",
-        translator.get("command.stats_total_sessions"),
-        stats.session_count
-    );
-    println!();
-
-    if !stats.by_mode.is_empty() {
-        println!("{}:", translator.get("command.status_mode"));
-
-        let mut modes: Vec<_> = stats.by_mode.iter().collect();
-        modes.sort_by(|a, b| b.1.cmp(a.1));
-
-        let total = stats.total_seconds.max(1) as f64;
-
-        for (index, (mode, seconds)) in modes.iter().enumerate() {
-            let percentage = (**seconds as f64 / total * 100.0) as u32;
-            let prefix = if index == modes.len() - 1 {
-                "└──"
-            } else {
-                "├──"
-            };
-            println!(
-                "{} {:14} {:>8} ({}%)",
-                prefix,
-                format!("{}:", mode),
-                format_duration(**seconds),
-                percentage
-            );
-        }
-        println!();
+    )];
+    if let Some(tag) = tag {
+        heading.push(translator.format("command.stats_tag_filter", &[("tag", tag)]));
     }
+    let mut report = Report {
+        heading,
+        ..Report::default()
+    };
 
-    display_applications(
+    report.push(Section::summary(
+        "summary",
+        vec![
+            Row::new(
+                "total_time",
+                translator.get("command.stats_total_time"),
+                Value::Duration(stats.total_seconds),
+            ),
+            Row::new(
+                "sessions",
+                translator.get("command.stats_total_sessions"),
+                Value::Count(stats.session_count as i64),
+            ),
+        ],
+    ));
+
+    let modes = sorted_by_time(&stats.by_mode, usize::MAX)
+        .into_iter()
+        .map(|(mode, seconds)| {
+            Row::new(
+                mode,
+                translator.mode_name(&FocusMode::from_stored(mode)),
+                Value::Duration(seconds),
+            )
+            .with_percent(percent(seconds, stats.total_seconds))
+        })
+        .collect();
+    report.push(Section::breakdown(
+        "by_mode",
+        translator.get("command.status_mode"),
+        modes,
+    ));
+
+    if tag.is_none() {
+        report.push(applications_section(
+            "by_tag",
+            &stats.by_tag,
+            translator.get("command.stats_by_tag"),
+        ));
+    }
+    report.push(applications_section(
+        "focus_applications",
         &stats.focus_applications,
-        &translator.get("command.stats_focus_apps"),
-    );
-
-    display_distractions(stats, translator);
+        translator.get("command.stats_focus_apps"),
+    ));
+    report.push(distractions_section(stats, translator));
 
+    let mut averages = Vec::new();
     if stats.session_count > 0 {
-        let avg_seconds = stats.total_seconds / stats.session_count as i64;
-        println!(
-            "{}: {}",
+        averages.push(Row::new(
+            "average_duration",
             translator.get("command.stats_average_duration"),
-            format_duration(avg_seconds)
-        );
+            Value::Duration(stats.total_seconds / stats.session_count as i64),
+        ));
+    }
+    if let Some(rate) = stats.completion_rate {
+        averages.push(Row::new(
+            "completion_rate",
+            translator.get("command.stats_completion_rate"),
+            Value::Percent(rate),
+        ));
     }
-
     if stats.total_check_ins > 0 {
-        println!(
-            "{}: {}",
+        averages.push(Row::new(
+            "check_ins",
             translator.get("command.stats_check_ins"),
-            stats.total_check_ins
-        );
+            Value::Count(stats.total_check_ins as i64),
+        ));
     }
+    report.push(Section::summary("averages", averages));
 
-    println!();
+    report
 }
 
-fn display_applications(applications: &HashMap<String, i64>, header: &str) {
-    if applications.is_empty() {
-        return;
+async fn meeting_time_section(
+    calendar: &CalendarConfig,
+    sessions: &[Session],
+    translator: &Translator,
+) -> Result<Section, String> {
+    let Some(from) = sessions.iter().map(|session| session.started_at).min() else {
+        return Ok(Section::summary("meetings", Vec::new()));
+    };
+    let now = Utc::now();
+    let gateway = IcsCalendarGateway::new(calendar.url.clone());
+
+    match gateway.events_between(from, now).await {
+        Ok(events) => Ok(Section::summary(
+            "meetings",
+            vec![Row::new(
+                "meeting_time",
+                translator.get("command.stats_meeting_time"),
+                Value::Duration(meeting_seconds(&events, sessions, now)),
+            )],
+        )),
+        Err(error) => Err(translator.format(
+            "command.stats_calendar_unavailable",
+            &[("error", &error.to_string())],
+        )),
     }
+}
 
-    println!("{}:", header);
-
-    let mut apps: Vec<_> = applications.iter().collect();
-    apps.sort_by(|a, b| b.1.cmp(a.1));
-
-    let total_app_time: i64 = apps.iter().map(|(_, seconds)| **seconds).sum();
-    let total = total_app_time.max(1) as f64;
+/// Time meetings took out of the sessions
+fn meeting_seconds(events: &[CalendarEvent], sessions: &[Session], now: DateTime<Utc>) -> i64 {
+    sessions
+        .iter()
+        .map(|session| {
+            CalendarEvent::meeting_seconds(
+                events,
+                session.started_at,
+                session.ended_at.unwrap_or(now),
+            )
+        })
+        .sum()
+}
 
-    for (index, (app, seconds)) in apps.iter().enumerate() {
-        let percentage = (**seconds as f64 / total * 100.0) as u32;
-        let prefix = if index == apps.len() - 1 {
-            "└──"
-        } else {
-            "├──"
-        };
-        println!(
-            "{} {:14} {:>8} ({}%)",
-            prefix,
-            format!("{}:", app),
-            format_duration(**seconds),
-            percentage
-        );
-    }
-    println!();
+fn sorted_by_time(entries: &HashMap<String, i64>, limit: usize) -> Vec<(&String, i64)> {
+    let mut entries: Vec<(&String, i64)> = entries
+        .iter()
+        .map(|(name, seconds)| (name, *seconds))
+        .collect();
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    entries.truncate(limit);
+    entries
 }
 
-fn display_distractions(stats: &Stats, translator: &Translator) {
-    if stats.distraction_applications.is_empty() {
-        return;
-    }
+fn applications_section(
+    id: &'static str,
+    applications: &HashMap<String, i64>,
+    title: String,
+) -> Section {
+    let total: i64 = applications.values().sum();
+    let rows = sorted_by_time(applications, usize::MAX)
+        .into_iter()
+        .map(|(application, seconds)| {
+            Row::new(application, application, Value::Duration(seconds))
+                .with_percent(percent(seconds, total))
+        })
+        .collect();
+    Section::breakdown(id, title, rows)
+}
 
+fn distractions_section(stats: &Stats, translator: &Translator) -> Section {
     let total_tracked_time: i64 = stats
         .focus_applications
         .values()
         .chain(stats.distraction_applications.values())
         .sum();
-
-    let distraction_percentage = if total_tracked_time > 0 {
-        (stats.total_distraction_seconds as f64 / total_tracked_time as f64 * 100.0) as u32
-    } else {
-        0
-    };
-
-    println!(
-        "{} ({}% {}):",
+    let title = format!(
+        "{} ({}% {})",
         translator.get("command.stats_distractions"),
-        distraction_percentage,
+        percent(stats.total_distraction_seconds, total_tracked_time),
         translator.get("command.stats_time_lost")
     );
 
-    let mut apps: Vec<_> = stats.distraction_applications.iter().collect();
-    apps.sort_by(|a, b| b.1.cmp(a.1));
-
-    let top_apps: Vec<_> = apps.into_iter().take(5).collect();
-    let total = stats.total_distraction_seconds.max(1) as f64;
-
-    for (index, (app, seconds)) in top_apps.iter().enumerate() {
-        let percentage = (**seconds as f64 / total * 100.0) as u32;
-        let prefix = if index == top_apps.len() - 1 {
-            "└──"
-        } else {
-            "├──"
-        };
-        println!(
-            "{} {:14} {:>8} ({}%)",
-            prefix,
-            format!("{}:", app),
-            format_duration(**seconds),
-            percentage
-        );
-    }
-    println!();
+    let rows = sorted_by_time(&stats.distraction_applications, 5)
+        .into_iter()
+        .map(|(application, seconds)| {
+            Row::new(application, application, Value::Duration(seconds))
+                .with_percent(percent(seconds, stats.total_distraction_seconds))
+        })
+        .collect();
+    Section::breakdown("distractions", title, rows)
 }
 
-fn format_duration(seconds: i64) -> String {
-    let hours = seconds / 3600;
-    let minutes = (seconds % 3600) / 60;
-
-    if hours > 0 {
-        format!("{}h {:02}min", hours, minutes)
-    } else {
-        format!("{}min", minutes)
-    }
+fn interruption_reasons_section(
+    reasons: &[InterruptionReasonCount],
+    translator: &Translator,
+) -> Section {
+    let total: i64 = reasons.iter().map(|reason| i64::from(reason.count)).sum();
+    let rows = reasons
+        .iter()
+        .take(5)
+        .map(|reason| {
+            let count = i64::from(reason.count);
+            Row::new(&reason.reason, &reason.reason, Value::Count(count))
+                .with_percent(percent(count, total))
+        })
+        .collect();
+    Section::breakdown(
+        "interruption_reasons",
+        translator.get("command.stats_interruption_reasons"),
+        rows,
+    )
 }
 
 #[cfg(test)]
@@ -335,16 +683,55 @@ mod tests {
     use super::*;
 
     #[test]
-    fn format_duration_shows_hours_and_minutes() {
-        assert_eq!(format_duration(3661), "1h 01min");
-        assert_eq!(format_duration(7200), "2h 00min");
+    fn trend_covers_the_last_weeks_starting_on_monday() {
+        let weeks = trend_weeks(
+            NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+            WeekStart::Monday,
+        );
+
+        assert_eq!(weeks.len(), TREND_WEEKS as usize);
+        assert_eq!(weeks[0], NaiveDate::from_ymd_opt(2026, 8, 24).unwrap());
+        assert_eq!(weeks[7], NaiveDate::from_ymd_opt(2026, 10, 12).unwrap());
+    }
+
+    #[test]
+    fn trend_weeks_start_on_sunday_when_configured() {
+        let weeks = trend_weeks(
+            NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+            WeekStart::Sunday,
+        );
+
+        assert_eq!(weeks[0], NaiveDate::from_ymd_opt(2026, 8, 23).unwrap());
+        assert_eq!(weeks[7], NaiveDate::from_ymd_opt(2026, 10, 11).unwrap());
     }
 
     #[test]
-    fn format_duration_shows_only_minutes_when_under_hour() {
-        assert_eq!(format_duration(1500), "25min");
-        assert_eq!(format_duration(60), "1min");
-        assert_eq!(format_duration(0), "0min");
+    fn mode_trend_table_fills_missing_weeks_and_orders_modes_by_time() {
+        let weeks = trend_weeks(
+            NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+            WeekStart::Monday,
+        );
+        let totals = vec![
+            WeeklyModeTotal {
+                week_start: weeks[6],
+                mode: flux_core::FocusMode::Architecture,
+                seconds: 1800,
+            },
+            WeeklyModeTotal {
+                week_start: weeks[7],
+                mode: flux_core::FocusMode::Review,
+                seconds: 7200,
+            },
+        ];
+
+        let (modes, rows) = mode_trend_table(&weeks, &totals);
+
+        assert_eq!(modes, vec!["review", "architecture"]);
+        assert_eq!(rows.len(), 8);
+        assert_eq!(rows[0].1, vec![0, 0]);
+        assert_eq!(rows[6].1, vec![0, 1800]);
+        assert_eq!(rows[7].1, vec![7200, 0]);
+        assert_eq!(format_hours(5400), "1.5h");
     }
 
     #[test]
@@ -355,12 +742,40 @@ mod tests {
         assert!(matches!(Period::from_str("all"), Some(Period::All)));
     }
 
+    #[test]
+    fn only_untagged_all_time_stats_use_rollups() {
+        assert!(uses_rollups(Period::All, None, false));
+        assert!(!uses_rollups(Period::All, None, true));
+        assert!(!uses_rollups(Period::All, Some("clientx"), false));
+        assert!(!uses_rollups(Period::Month, None, false));
+    }
+
     #[test]
     fn period_from_str_returns_none_for_invalid() {
         assert!(Period::from_str("invalid").is_none());
         assert!(Period::from_str("").is_none());
     }
 
+    #[test]
+    fn interruption_reasons_keep_the_five_most_common() {
+        let translator = Translator::new(flux_core::Language::En);
+        let reasons: Vec<InterruptionReasonCount> = [4, 2, 1, 1, 1, 1]
+            .into_iter()
+            .enumerate()
+            .map(|(index, count)| InterruptionReasonCount {
+                reason: format!("reason {}", index),
+                count,
+            })
+            .collect();
+
+        let section = interruption_reasons_section(&reasons, &translator);
+
+        assert_eq!(section.rows.len(), 5);
+        assert_eq!(section.rows[0].key, "reason 0");
+        assert_eq!(section.rows[0].value, Value::Count(4));
+        assert_eq!(section.rows[0].percent, Some(40));
+    }
+
     #[test]
     fn compute_stats_aggregates_correctly() {
         use flux_core::FocusMode;
@@ -379,10 +794,29 @@ mod tests {
         assert_eq!(stats.by_mode.get("ai-assisted"), Some(&3000));
         assert_eq!(stats.by_mode.get("review"), Some(&900));
         assert_eq!(stats.total_check_ins, 3);
+        assert_eq!(stats.completion_rate, None);
         assert!(stats.focus_applications.is_empty());
         assert!(stats.distraction_applications.is_empty());
     }
 
+    #[test]
+    fn csv_report_uses_mode_ids_and_seconds() {
+        use flux_core::{FocusMode, Language};
+
+        let sessions = vec![
+            create_test_session(FocusMode::AiAssisted, 1800, 2),
+            create_test_session(FocusMode::Review, 600, 0),
+        ];
+        let stats = compute_stats(&sessions, &[], &create_test_distraction_config());
+        let report = stats_report(&stats, Period::Week, None, &Translator::new(Language::Fr));
+
+        let csv = OutputFormat::Csv.renderer().render(&report);
+
+        assert!(csv.contains("summary,total_time,2400,\n"));
+        assert!(csv.contains("by_mode,ai-assisted,1800,75\n"));
+        assert!(csv.contains("averages,check_ins,2,\n"));
+    }
+
     #[test]
     fn compute_stats_separates_focus_and_distraction_apps() {
         use flux_core::FocusMode;
@@ -428,6 +862,43 @@ mod tests {
         assert_eq!(stats.total_distraction_seconds, 250);
     }
 
+    #[test]
+    fn filter_by_tag_keeps_matching_sessions_and_aggregates_tags() {
+        use flux_core::FocusMode;
+
+        let sessions = vec![
+            create_test_session(FocusMode::AiAssisted, 1800, 0)
+                .with_tags(vec!["clientX".to_string(), "backend".to_string()]),
+            create_test_session(FocusMode::Review, 600, 0).with_tags(vec!["clientY".to_string()]),
+            create_test_session(FocusMode::Review, 300, 0),
+        ];
+
+        let all = compute_stats(&sessions, &[], &create_test_distraction_config());
+        assert_eq!(all.by_tag.get("clientx"), Some(&1800));
+        assert_eq!(all.by_tag.get("clienty"), Some(&600));
+
+        let filtered = filter_by_tag(sessions, Some("ClientX"));
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].duration_seconds, Some(1800));
+    }
+
+    #[test]
+    fn aggregate_projects_sums_across_sessions() {
+        let metrics = vec![
+            SessionMetrics::new(1, 0, HashMap::new()).with_projects(HashMap::from([
+                ("flux".to_string(), 1200),
+                ("website".to_string(), 300),
+            ])),
+            SessionMetrics::new(2, 0, HashMap::new())
+                .with_projects(HashMap::from([("flux".to_string(), 600)])),
+        ];
+
+        let projects = aggregate_projects(&metrics);
+
+        assert_eq!(projects.get("flux"), Some(&1800));
+        assert_eq!(projects.get("website"), Some(&300));
+    }
+
     fn create_test_session(mode: flux_core::FocusMode, duration: i64, check_ins: i32) -> Session {
         let mut session = Session::start(mode);
         session.duration_seconds = Some(duration);
@@ -436,15 +907,18 @@ mod tests {
     }
 
     fn create_test_distraction_config() -> DistractionConfig {
-        use std::collections::HashSet;
+        use std::collections::{HashMap, HashSet};
         DistractionConfig {
             apps: HashSet::from(["discord".to_string(), "slack".to_string()]),
             title_patterns: HashSet::new(),
             alert_enabled: false,
             alert_after_seconds: 30,
+            alert_mode: flux_core::DistractionAlertMode::Immediate,
             friction_apps: HashSet::new(),
             friction_delay_seconds: 10,
+            friction_overrides: HashMap::new(),
             whitelist_apps: HashSet::new(),
+            overrides: HashMap::new(),
         }
     }
 }
@@ -1,6 +1,9 @@
+use super::doctor::{self, DaemonHealth};
 use crate::client::{ClientError, DaemonClient};
 use anyhow::Result;
-use flux_core::{Config, Translator};
+use chrono::{DateTime, Local};
+use flux_adapters::SqliteSessionRepository;
+use flux_core::{Config, SessionRepository, Streak, Translator};
 use flux_protocol::{FocusMode, Request, Response};
 use serde::Serialize;
 
@@ -11,11 +14,18 @@ struct StatusOutput {
     remaining_seconds: u64,
     remaining_formatted: String,
     paused: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preparing_seconds: Option<u64>,
+    strict: bool,
+    streak: Option<Streak>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    health: Option<DaemonHealth>,
 }
 
-pub async fn execute(json: bool) -> Result<()> {
+pub async fn execute(json: bool, verbose: bool) -> Result<()> {
     let translator = get_translator();
     let client = DaemonClient::new();
+    let streak = load_streak();
 
     match client.send(Request::GetStatus).await {
         Ok(Response::SessionStatus {
@@ -23,11 +33,36 @@ pub async fn execute(json: bool) -> Result<()> {
             remaining_seconds,
             mode,
             paused,
+            preparing_seconds,
+            strict,
         }) => {
+            let health = if verbose {
+                doctor::fetch_health(&client).await
+            } else {
+                None
+            };
+            let output = StatusOutput {
+                active,
+                mode: mode.clone().map(format_mode),
+                remaining_seconds,
+                remaining_formatted: format_duration(remaining_seconds),
+                paused,
+                preparing_seconds,
+                strict,
+                streak,
+                health,
+            };
+
             if json {
-                print_json(active, remaining_seconds, mode, paused)?;
+                println!("{}", serde_json::to_string_pretty(&output)?);
             } else {
-                print_formatted(active, remaining_seconds, mode, paused, &translator);
+                print_formatted(&output, mode.as_ref(), &translator);
+                print_streak(output.streak, &translator);
+                if let Some(health) = output.health {
+                    println!();
+                    println!("{}", translator.get("command.status_health_header"));
+                    doctor::print_health(&health, &translator);
+                }
             }
         }
         Ok(Response::Error { message }) => {
@@ -51,6 +86,7 @@ pub async fn execute(json: bool) -> Result<()> {
                 println!(r#"{{"error": "daemon not running", "active": false}}"#);
             } else {
                 println!("{}", translator.get("error.daemon_not_running"));
+                print_streak(streak, &translator);
             }
         }
         Err(ClientError::Timeout) => {
@@ -76,57 +112,75 @@ pub async fn execute(json: bool) -> Result<()> {
 
 fn get_translator() -> Translator {
     Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
 
-fn print_json(
-    active: bool,
-    remaining_seconds: u64,
-    mode: Option<FocusMode>,
-    paused: bool,
-) -> Result<()> {
-    let output = StatusOutput {
-        active,
-        mode: mode.map(format_mode),
-        remaining_seconds,
-        remaining_formatted: format_duration(remaining_seconds),
-        paused,
-    };
-    println!("{}", serde_json::to_string_pretty(&output)?);
-    Ok(())
-}
-
-fn print_formatted(
-    active: bool,
-    remaining_seconds: u64,
-    mode: Option<FocusMode>,
-    paused: bool,
-    translator: &Translator,
-) {
-    if !active {
+fn print_formatted(output: &StatusOutput, mode: Option<&FocusMode>, translator: &Translator) {
+    if !output.active {
         println!("{}", translator.get("status.no_session"));
         return;
     }
 
-    if paused {
+    if let Some(seconds) = output.preparing_seconds {
+        println!(
+            "{}",
+            translator.format(
+                "command.status_state_preparing",
+                &[("seconds", &seconds.to_string())],
+            )
+        );
+    } else if output.paused {
         println!("{}", translator.get("command.status_state_paused"));
     } else {
         println!("{}", translator.get("command.status_state_active"));
     }
 
-    if let Some(focus_mode) = mode {
+    if let Some(mode) = mode {
         println!(
             "   {}: {}",
             translator.get("command.status_mode"),
-            format_mode(focus_mode)
+            translator.mode_name(mode)
         );
     }
 
     println!(
         "   {}: {}",
         translator.get("command.status_remaining"),
-        format_duration(remaining_seconds)
+        output.remaining_formatted
+    );
+
+    if output.strict {
+        println!("   {}", translator.get("command.status_strict"));
+    }
+}
+
+fn load_streak() -> Option<Streak> {
+    let database_path = dirs::data_dir()?.join("flux").join("sessions.db");
+    if !database_path.exists() {
+        return None;
+    }
+
+    let repository = SqliteSessionRepository::new(&database_path).ok()?;
+    let sessions = repository.find_completed_since(DateTime::UNIX_EPOCH).ok()?;
+    Some(Streak::from_sessions(&sessions, Local::now().date_naive()))
+}
+
+fn print_streak(streak: Option<Streak>, translator: &Translator) {
+    let Some(streak) = streak.filter(|streak| streak.current_days > 0) else {
+        return;
+    };
+
+    println!(
+        "   {}: {}",
+        translator.get("command.status_streak"),
+        translator.format(
+            "command.streak_days",
+            &[
+                ("current", &streak.current_days.to_string()),
+                ("longest", &streak.longest_days.to_string()),
+            ],
+        )
     );
 }
 
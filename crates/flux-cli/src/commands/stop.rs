@@ -1,16 +1,46 @@
+use super::strict;
 use crate::client::{ClientError, DaemonClient};
 use anyhow::{bail, Result};
 use flux_core::{Config, Translator};
 use flux_protocol::{Request, Response};
 
-pub async fn execute() -> Result<()> {
+pub async fn execute(reason: Option<String>) -> Result<()> {
     let translator = get_translator();
     let client = DaemonClient::new();
 
-    match client.send(Request::StopSession).await {
+    let mut response = client
+        .send(Request::StopSession {
+            confirmation: None,
+            reason: reason.clone(),
+        })
+        .await;
+    if let Ok(Response::StrictSession {
+        remaining_seconds,
+        confirmation_phrase,
+    }) = &response
+    {
+        let Some(confirmation) =
+            strict::ask_confirmation(*remaining_seconds, confirmation_phrase, &translator)?
+        else {
+            println!("{}", translator.get("command.strict_cancelled"));
+            return Ok(());
+        };
+        response = client
+            .send(Request::StopSession {
+                confirmation: Some(confirmation),
+                reason,
+            })
+            .await;
+    }
+
+    match response {
         Ok(Response::Ok) => {
             println!("{}", translator.get("command.stop_success"));
         }
+        Ok(Response::StrictSession { .. }) => {
+            println!("{}", translator.get("command.strict_cancelled"));
+            return Ok(());
+        }
         Ok(Response::Error { message }) => {
             if message.contains("aucune session") || message.contains("no session") {
                 println!("{}", translator.get("status.no_session"));
@@ -40,7 +70,7 @@ pub async fn execute() -> Result<()> {
 
 fn get_translator() -> Translator {
     Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
 
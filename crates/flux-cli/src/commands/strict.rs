@@ -0,0 +1,41 @@
+use anyhow::Result;
+use dialoguer::Input;
+use flux_core::Translator;
+
+/// Asks for the phrase the daemon expects; `None` when the user typed something else
+pub fn ask_confirmation(
+    remaining_seconds: u64,
+    confirmation_phrase: &str,
+    translator: &Translator,
+) -> Result<Option<String>> {
+    println!(
+        "{}",
+        translator.format(
+            "command.strict_refused",
+            &[("minutes", &remaining_seconds.div_ceil(60).to_string())],
+        )
+    );
+
+    let typed: String = Input::new()
+        .with_prompt(translator.format("command.strict_prompt", &[("phrase", confirmation_phrase)]))
+        .allow_empty(true)
+        .interact_text()?;
+
+    Ok(matches_phrase(&typed, confirmation_phrase).then_some(typed))
+}
+
+fn matches_phrase(typed: &str, confirmation_phrase: &str) -> bool {
+    typed.trim().eq_ignore_ascii_case(confirmation_phrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phrase_must_be_typed_in_full() {
+        assert!(matches_phrase(" Stop My Focus ", "stop my focus"));
+        assert!(!matches_phrase("stop", "stop my focus"));
+        assert!(!matches_phrase("", "stop my focus"));
+    }
+}
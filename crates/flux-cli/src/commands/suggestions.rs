@@ -1,12 +1,39 @@
-use anyhow::Result;
-use flux_core::{Config, SuggestionReason, SuggestionReport, Translator};
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use flux_core::{AppState, Config, DistractionSuggestion, SuggestionReport, Translator};
+use serde::Serialize;
 
-pub fn list() -> Result<()> {
+#[derive(Serialize)]
+struct SuggestionsOutput<'a> {
+    generated_at: Option<DateTime<Utc>>,
+    session_id: Option<i64>,
+    context_switch_count: u32,
+    suggestions: Vec<SuggestionOutput<'a>>,
+}
+
+#[derive(Serialize)]
+struct SuggestionOutput<'a> {
+    number: usize,
+    application: &'a str,
+    reason: &'static str,
+    confidence: u8,
+    short_burst_count: u32,
+}
+
+pub fn list(json: bool) -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
 
     let report = SuggestionReport::load().unwrap_or_default();
 
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&suggestions_output(&report))?
+        );
+        return Ok(());
+    }
+
     if report.suggestions.is_empty() {
         println!("\n{}\n", translator.get("command.suggestions_empty"));
         return Ok(());
@@ -21,16 +48,14 @@ pub fn list() -> Result<()> {
             "├──"
         };
 
-        let reason_text = match suggestion.reason {
-            SuggestionReason::FrequentShortBursts => translator.format(
-                "command.suggestions_reason_short_bursts",
-                &[("count", &suggestion.short_burst_count.to_string())],
-            ),
-        };
+        let reason_text = translator.suggestion_reason(suggestion);
 
         println!(
-            "{} {} ({})",
-            prefix, suggestion.application_name, reason_text
+            "{} {}. {} ({})",
+            prefix,
+            index + 1,
+            suggestion.application_name,
+            reason_text
         );
     }
 
@@ -50,12 +75,123 @@ pub fn list() -> Result<()> {
     Ok(())
 }
 
+fn suggestions_output(report: &SuggestionReport) -> SuggestionsOutput<'_> {
+    SuggestionsOutput {
+        generated_at: report.generated_at,
+        session_id: report.session_id,
+        context_switch_count: report.context_switch_count,
+        suggestions: report
+            .suggestions
+            .iter()
+            .enumerate()
+            .map(|(index, suggestion)| SuggestionOutput {
+                number: index + 1,
+                application: &suggestion.application_name,
+                reason: suggestion.reason.as_str(),
+                confidence: suggestion.confidence,
+                short_burst_count: suggestion.short_burst_count,
+            })
+            .collect(),
+    }
+}
+
+pub fn accept(number: usize) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let mut report = SuggestionReport::load().unwrap_or_default();
+    let suggestion = take_suggestion(&mut report, number, &translator)?;
+
+    let mut distractions = config.distractions().clone();
+    distractions.accept_suggestion(&suggestion);
+    distractions
+        .save_to_profile(&config.active_profile_name())
+        .context("Cannot save distractions config")?;
+    report.store().context("Cannot save suggestions")?;
+
+    let name = suggestion.application_name.to_lowercase();
+    let message = if suggestion.is_title_pattern() {
+        translator.format("command.distractions_pattern_added", &[("pattern", &name)])
+    } else {
+        translator.format("command.distractions_added", &[("app", &name)])
+    };
+    println!("{}", message);
+    Ok(())
+}
+
+pub fn dismiss(number: usize) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let mut report = SuggestionReport::load().unwrap_or_default();
+    let suggestion = take_suggestion(&mut report, number, &translator)?;
+
+    let mut state = AppState::load();
+    state.dismiss_suggestion(&suggestion.application_name);
+    state.save().context("Cannot save dismissed suggestion")?;
+    report.store().context("Cannot save suggestions")?;
+
+    println!(
+        "{}",
+        translator.format(
+            "command.suggestions_dismissed",
+            &[("app", &suggestion.application_name)]
+        )
+    );
+    Ok(())
+}
+
+fn take_suggestion(
+    report: &mut SuggestionReport,
+    number: usize,
+    translator: &Translator,
+) -> Result<DistractionSuggestion> {
+    report.take(number).ok_or_else(|| {
+        anyhow!(translator.format(
+            "command.suggestions_not_found",
+            &[("number", &number.to_string())]
+        ))
+    })
+}
+
 pub fn clear() -> Result<()> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
 
     SuggestionReport::clear()?;
 
     println!("{}", translator.get("command.suggestions_cleared"));
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::SuggestionReason;
+
+    #[test]
+    fn json_output_numbers_suggestions_like_the_list() {
+        let report = SuggestionReport {
+            context_switch_count: 12,
+            suggestions: vec![DistractionSuggestion {
+                application_name: "Slack".to_string(),
+                short_burst_count: 5,
+                reason: SuggestionReason::FrequentShortBursts,
+                confidence: 83,
+            }],
+            ..SuggestionReport::default()
+        };
+
+        let json = serde_json::to_value(suggestions_output(&report)).unwrap();
+
+        assert_eq!(json["context_switch_count"], 12);
+        assert_eq!(
+            json["suggestions"][0],
+            serde_json::json!({
+                "number": 1,
+                "application": "Slack",
+                "reason": "frequent_short_bursts",
+                "confidence": 83,
+                "short_burst_count": 5,
+            })
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use anyhow::{Context, Result};
+use flux_adapters::{
+    Database, FolderSyncGateway, SqliteAppTrackingRepository, SqliteSyncRepository,
+};
+use flux_core::{
+    Config, PrivacyConfig, SyncGateway, SyncMerge, SyncRepository, Translator,
+    UsageRollupRepository,
+};
+
+pub async fn execute() -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let Some(sync) = config.sync else {
+        anyhow::bail!("{}", translator.get("sync.not_configured"));
+    };
+    let device = sync
+        .device_name()
+        .with_context(|| translator.get("sync.device_unknown"))?;
+
+    let database = open_database(&config.privacy)?;
+    let repository = SqliteSyncRepository::with_database(database.clone());
+    let gateway = FolderSyncGateway::new(sync.folder, &device);
+
+    let local = repository
+        .export()
+        .map_err(|error| anyhow::anyhow!("{}", error))?;
+    let merge = SyncMerge::new(&local, gateway.pull().await?);
+    repository
+        .import(&merge.incoming)
+        .map_err(|error| anyhow::anyhow!("{}", error))?;
+    if merge.outgoing > 0 {
+        let records = repository
+            .export()
+            .map_err(|error| anyhow::anyhow!("{}", error))?;
+        gateway.push(&records).await?;
+    }
+
+    // Received sessions may predate the last rollup refresh
+    if !merge.incoming.is_empty() {
+        SqliteAppTrackingRepository::with_database(database)
+            .refresh_rollups(None)
+            .map_err(|error| anyhow::anyhow!("{}", error))?;
+    }
+
+    println!(
+        "{}",
+        translator.format(
+            "sync.done",
+            &[
+                ("target", &gateway.target_name()),
+                ("received", &merge.incoming.len().to_string()),
+                ("sent", &merge.outgoing.to_string()),
+            ]
+        )
+    );
+    Ok(())
+}
+
+/// A device that never ran a session still receives the others' history
+fn open_database(privacy: &PrivacyConfig) -> Result<Database> {
+    let data_dir = dirs::data_dir()
+        .context("cannot find data directory")?
+        .join("flux");
+    std::fs::create_dir_all(&data_dir)?;
+
+    Database::open(&data_dir.join("sessions.db"))
+        .and_then(|database| database.with_privacy(privacy))
+        .map_err(|error| anyhow::anyhow!("database access error: {}", error))
+}
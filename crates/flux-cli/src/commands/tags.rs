@@ -0,0 +1,150 @@
+use anyhow::{bail, Context, Result};
+use flux_adapters::SqliteSessionRepository;
+use flux_core::{
+    Config, SessionId, SessionRepository, SessionRepositoryError, TagUsage, Translator,
+};
+
+use super::render::format_duration;
+
+pub fn list() -> Result<()> {
+    let translator = get_translator();
+    let repository = open_repository()?;
+    let usages = repository
+        .tag_usages()
+        .map_err(|error| anyhow::anyhow!("{}", error))?;
+
+    if usages.is_empty() {
+        println!("\n{}\n", translator.get("command.tags_empty"));
+        return Ok(());
+    }
+
+    println!("\n{}:\n", translator.get("command.tags_header"));
+    for line in describe_tags(&usages, &translator) {
+        println!("{}", line);
+    }
+    println!("\n{}\n", translator.get("command.tags_hint"));
+
+    Ok(())
+}
+
+pub fn tag(session_id: SessionId, tag: &str) -> Result<()> {
+    let translator = get_translator();
+    let id = session_id.to_string();
+    let tag = tag.trim().to_lowercase();
+    if tag.is_empty() {
+        bail!("{}", translator.get("command.tag_empty"));
+    }
+
+    let repository = open_repository()?;
+    let mut session = match repository.find_by_id(session_id) {
+        Ok(session) => session,
+        Err(SessionRepositoryError::NotFound { .. }) => {
+            bail!(
+                "{}",
+                translator.format("command.delete_not_found", &[("id", &id)])
+            );
+        }
+        Err(error) => bail!("{}", error),
+    };
+
+    if session.is_active() {
+        bail!("{}", translator.get("command.tag_active_session"));
+    }
+
+    if !session.add_tag(&tag) {
+        println!(
+            "{}",
+            translator.format("command.tag_already_present", &[("id", &id), ("tag", &tag)])
+        );
+        return Ok(());
+    }
+
+    repository
+        .update(&session)
+        .map_err(|error| anyhow::anyhow!("{}", error))?;
+
+    println!(
+        "{}",
+        translator.format("command.tag_added", &[("id", &id), ("tag", &tag)])
+    );
+    Ok(())
+}
+
+fn describe_tags(usages: &[TagUsage], translator: &Translator) -> Vec<String> {
+    let width = usages
+        .iter()
+        .map(|usage| usage.tag.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    usages
+        .iter()
+        .enumerate()
+        .map(|(index, usage)| {
+            let prefix = if index == usages.len() - 1 {
+                "└──"
+            } else {
+                "├──"
+            };
+            format!(
+                "{} {:width$}  {:>8}  {}",
+                prefix,
+                usage.tag,
+                format_duration(usage.total_seconds),
+                translator.format(
+                    "command.tags_sessions",
+                    &[("count", &usage.session_count.to_string())]
+                ),
+            )
+        })
+        .collect()
+}
+
+fn get_translator() -> Translator {
+    Config::load()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default()
+}
+
+fn open_repository() -> Result<SqliteSessionRepository> {
+    let data_dir = dirs::data_dir()
+        .context("cannot find data directory")?
+        .join("flux");
+
+    let database_path = data_dir.join("sessions.db");
+
+    if !database_path.exists() {
+        bail!("no session data. Start a session first with 'flux start'.");
+    }
+
+    SqliteSessionRepository::new(&database_path)
+        .map_err(|error| anyhow::anyhow!("database access error: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::Language;
+
+    #[test]
+    fn tags_are_listed_with_their_time_and_session_count() {
+        let translator = Translator::new(Language::En);
+        let usages = vec![
+            TagUsage {
+                tag: "clientx".to_string(),
+                session_count: 3,
+                total_seconds: 5400,
+            },
+            TagUsage {
+                tag: "ops".to_string(),
+                session_count: 1,
+                total_seconds: 900,
+            },
+        ];
+
+        let lines = describe_tags(&usages, &translator);
+
+        assert_eq!(lines[0], "├── clientx  1h 30min  3 session(s)");
+        assert_eq!(lines[1], "└── ops         15min  1 session(s)");
+    }
+}
@@ -0,0 +1,121 @@
+use anyhow::Result;
+use chrono::{Local, NaiveDate, Utc};
+use flux_adapters::SqliteSessionRepository;
+use flux_core::{Config, DailyTarget, Session, SessionRepository, Translator};
+
+pub fn execute() -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let translator = Translator::new(config.language());
+    let today = Local::now().date_naive();
+    let week_start = config.general.week_starts_on;
+    let sessions = load_week_sessions(week_start.week_of(today));
+
+    println!();
+    println!("{}", translator.get("command.today_header"));
+    println!();
+
+    match config.goals.daily_target(week_start, &sessions, today) {
+        Some(target) => print_target(&target, &translator),
+        None => {
+            println!(
+                "   {}",
+                translator.format(
+                    "command.today_focused",
+                    &[("focused", &format_duration(focused_on(&sessions, today)))]
+                )
+            );
+            println!();
+            println!("{}", translator.get("command.today_no_goal"));
+        }
+    }
+    println!();
+
+    Ok(())
+}
+
+fn print_target(target: &DailyTarget, translator: &Translator) {
+    println!(
+        "   {}",
+        translator.format(
+            "command.today_focused",
+            &[("focused", &format_duration(target.focused_seconds))]
+        )
+    );
+
+    if target.rest_day {
+        println!("   {}", translator.get("command.today_rest_day"));
+    } else if target.remaining_seconds() == 0 {
+        println!("   {}", translator.get("command.today_target_reached"));
+    } else {
+        println!(
+            "   {}",
+            translator.format(
+                "command.today_target",
+                &[
+                    ("target", &format_duration(target.target_seconds)),
+                    ("percent", &target.progress_percent().to_string()),
+                    ("remaining", &format_duration(target.remaining_seconds())),
+                ],
+            )
+        );
+    }
+}
+
+fn load_week_sessions(week_start: NaiveDate) -> Vec<Session> {
+    let Some(database_path) = dirs::data_dir().map(|path| path.join("flux").join("sessions.db"))
+    else {
+        return Vec::new();
+    };
+    if !database_path.exists() {
+        return Vec::new();
+    }
+
+    let since = week_start
+        .and_hms_opt(0, 0, 0)
+        .and_then(|start| start.and_local_timezone(Local).earliest())
+        .map(|start| start.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now);
+
+    SqliteSessionRepository::new(&database_path)
+        .and_then(|repository| repository.find_completed_since(since))
+        .unwrap_or_default()
+}
+
+fn focused_on(sessions: &[Session], day: NaiveDate) -> i64 {
+    sessions
+        .iter()
+        .filter(|session| session.started_at.with_timezone(&Local).date_naive() == day)
+        .filter_map(|session| session.duration_seconds)
+        .sum()
+}
+
+fn format_duration(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+
+    if hours > 0 {
+        format!("{}h {:02}min", hours, minutes)
+    } else {
+        format!("{}min", minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use flux_core::FocusMode;
+
+    #[test]
+    fn focused_time_only_counts_the_given_day() {
+        let today = Local::now().date_naive();
+        let mut yesterday_session = Session::start(FocusMode::Review);
+        yesterday_session.started_at -= Duration::days(1);
+        yesterday_session.duration_seconds = Some(600);
+        let mut today_session = Session::start(FocusMode::Review);
+        today_session.duration_seconds = Some(1500);
+
+        assert_eq!(focused_on(&[yesterday_session, today_session], today), 1500);
+        assert_eq!(format_duration(5400), "1h 30min");
+    }
+}
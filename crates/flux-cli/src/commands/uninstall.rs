@@ -0,0 +1,275 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use dialoguer::{Confirm, Select};
+use flux_core::{Config, Translator};
+
+use crate::client::DaemonClient;
+use crate::commands::daemon;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DataChoice {
+    Keep,
+    Export,
+    Delete,
+}
+
+pub async fn execute() -> Result<()> {
+    let translator = get_translator();
+
+    let confirmed = Confirm::new()
+        .with_prompt(translator.get("command.uninstall_confirm"))
+        .default(false)
+        .interact()?;
+    if !confirmed {
+        println!("{}", translator.get("command.uninstall_cancelled"));
+        return Ok(());
+    }
+
+    let data_choice = prompt_data_choice(&translator)?;
+
+    if daemon::shutdown(&DaemonClient::new(), &translator).await? {
+        println!("{}", translator.get("command.uninstall_daemon_stopped"));
+    }
+
+    for unit in remove_service_units()? {
+        println!(
+            "{}",
+            translator.format(
+                "command.uninstall_service_removed",
+                &[("path", &unit.display().to_string())]
+            )
+        );
+    }
+
+    let directories = flux_directories();
+    match data_choice {
+        DataChoice::Keep => {
+            for (_, directory) in &directories {
+                println!(
+                    "{}",
+                    translator.format(
+                        "command.uninstall_data_kept",
+                        &[("path", &directory.display().to_string())]
+                    )
+                );
+            }
+        }
+        DataChoice::Export => {
+            let export_directory = export_data(&directories)?;
+            delete_data(&directories)?;
+            println!(
+                "{}",
+                translator.format(
+                    "command.uninstall_data_exported",
+                    &[("path", &export_directory.display().to_string())]
+                )
+            );
+        }
+        DataChoice::Delete => {
+            delete_data(&directories)?;
+            for (_, directory) in &directories {
+                println!(
+                    "{}",
+                    translator.format(
+                        "command.uninstall_data_deleted",
+                        &[("path", &directory.display().to_string())]
+                    )
+                );
+            }
+        }
+    }
+
+    println!();
+    println!("{}", translator.get("command.uninstall_done"));
+    let binaries = installed_binaries();
+    if !binaries.is_empty() {
+        println!(
+            "{}",
+            translator.format(
+                "command.uninstall_binaries_hint",
+                &[("paths", &binaries.join(" "))]
+            )
+        );
+    }
+
+    Ok(())
+}
+
+fn prompt_data_choice(translator: &Translator) -> Result<DataChoice> {
+    let choices = [DataChoice::Keep, DataChoice::Export, DataChoice::Delete];
+    let items: Vec<String> = [
+        "command.uninstall_data_keep",
+        "command.uninstall_data_export",
+        "command.uninstall_data_delete",
+    ]
+    .iter()
+    .map(|key| translator.get(key))
+    .collect();
+
+    let selection = Select::new()
+        .with_prompt(translator.get("command.uninstall_data_prompt"))
+        .items(&items)
+        .default(0)
+        .interact()?;
+
+    Ok(choices[selection])
+}
+
+fn flux_directories() -> Vec<(&'static str, PathBuf)> {
+    let mut directories: Vec<(&'static str, PathBuf)> =
+        [("data", dirs::data_dir()), ("config", dirs::config_dir())]
+            .into_iter()
+            .filter_map(|(name, directory)| directory.map(|path| (name, path.join("flux"))))
+            .filter(|(_, directory)| directory.exists())
+            .collect();
+    directories.dedup_by(|first, second| first.1 == second.1);
+    directories
+}
+
+fn export_data(directories: &[(&str, PathBuf)]) -> Result<PathBuf> {
+    let home = dirs::home_dir().context("cannot find home directory")?;
+    let export_directory = home.join(format!(
+        "flux-export-{}",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    for (name, directory) in directories {
+        copy_directory(directory, &export_directory.join(name))?;
+    }
+
+    Ok(export_directory)
+}
+
+fn copy_directory(source: &Path, destination: &Path) -> Result<()> {
+    fs::create_dir_all(destination)
+        .with_context(|| format!("impossible de créer {}", destination.display()))?;
+
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let target = destination.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_directory(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)
+                .with_context(|| format!("impossible de copier {}", entry.path().display()))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn delete_data(directories: &[(&str, PathBuf)]) -> Result<()> {
+    for (_, directory) in directories {
+        fs::remove_dir_all(directory)
+            .with_context(|| format!("impossible de supprimer {}", directory.display()))?;
+    }
+    Ok(())
+}
+
+/// Only the unit named after the daemon: other `flux*` units, such as
+/// `fluxbox.service`, belong to other programs
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const FLUX_UNITS: [&str; 1] = ["flux-daemon.service"];
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn is_flux_unit(file_name: &str) -> bool {
+    FLUX_UNITS.contains(&file_name)
+}
+
+#[cfg(target_os = "linux")]
+fn remove_service_units() -> Result<Vec<PathBuf>> {
+    use std::process::Command;
+
+    let Some(unit_directory) = dirs::config_dir().map(|path| path.join("systemd").join("user"))
+    else {
+        return Ok(Vec::new());
+    };
+    let Ok(entries) = fs::read_dir(&unit_directory) else {
+        return Ok(Vec::new());
+    };
+
+    let units: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(is_flux_unit)
+        })
+        .collect();
+
+    for unit in &units {
+        if let Some(name) = unit.file_name() {
+            let _ = Command::new("systemctl")
+                .args(["--user", "disable", "--now"])
+                .arg(name)
+                .status();
+        }
+        fs::remove_file(unit)
+            .with_context(|| format!("impossible de supprimer {}", unit.display()))?;
+    }
+
+    if !units.is_empty() {
+        let _ = Command::new("systemctl")
+            .args(["--user", "daemon-reload"])
+            .status();
+    }
+
+    Ok(units)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn remove_service_units() -> Result<Vec<PathBuf>> {
+    Ok(Vec::new())
+}
+
+fn installed_binaries() -> Vec<String> {
+    ["flux", "flux-daemon", "flux-gui"]
+        .iter()
+        .filter_map(|binary| which::which(binary).ok())
+        .map(|path| path.display().to_string())
+        .collect()
+}
+
+fn get_translator() -> Translator {
+    Config::load()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_flux_services_are_removed() {
+        assert!(is_flux_unit("flux-daemon.service"));
+        assert!(!is_flux_unit("fluxbox.service"));
+        assert!(!is_flux_unit("flux-daemon.timer"));
+        assert!(!is_flux_unit("pipewire.service"));
+    }
+
+    #[test]
+    fn copy_directory_copies_nested_files() {
+        let root = std::env::temp_dir().join(format!("flux-uninstall-{}", std::process::id()));
+        let source = root.join("source");
+        fs::create_dir_all(source.join("digests")).unwrap();
+        fs::write(source.join("sessions.db"), "sessions").unwrap();
+        fs::write(source.join("digests").join("week.md"), "digest").unwrap();
+
+        copy_directory(&source, &root.join("export")).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join("export").join("sessions.db")).unwrap(),
+            "sessions"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("export").join("digests").join("week.md")).unwrap(),
+            "digest"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,10 +1,17 @@
+use crate::client::{ClientError, DaemonClient};
 use anyhow::{bail, Context, Result};
+use dialoguer::Confirm;
+use flux_core::Translator;
+use flux_protocol::{socket, Request, Response};
+use std::fs::{self, File};
+use std::io::IsTerminal;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::time::Duration;
 use tokio::time::sleep;
 
 const DAEMON_STARTUP_TIMEOUT: Duration = Duration::from_secs(5);
+const DAEMON_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
 const DAEMON_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 pub async fn ensure_daemon_running() -> Result<()> {
@@ -16,19 +23,85 @@ pub async fn ensure_daemon_running() -> Result<()> {
     Ok(())
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StartDecision {
+    Start,
+    Ask,
+    Skip,
+}
+
+fn start_decision(auto_start: bool, interactive: bool) -> StartDecision {
+    match (auto_start, interactive) {
+        (true, _) => StartDecision::Start,
+        (false, true) => StartDecision::Ask,
+        (false, false) => StartDecision::Skip,
+    }
+}
+
+/// Sends a request, starting the daemon first when it is absent and the user agrees
+pub async fn send_starting_daemon(
+    client: &DaemonClient,
+    request: Request,
+    auto_start: bool,
+    translator: &Translator,
+) -> Result<Response, ClientError> {
+    match client.send(request.clone()).await {
+        Err(ClientError::DaemonNotRunning) => {
+            let confirmed = match start_decision(auto_start, std::io::stdin().is_terminal()) {
+                StartDecision::Start => true,
+                StartDecision::Ask => Confirm::new()
+                    .with_prompt(translator.get("error.daemon_start_prompt"))
+                    .default(true)
+                    .interact()
+                    .unwrap_or(false),
+                StartDecision::Skip => false,
+            };
+            if !confirmed {
+                return Err(ClientError::DaemonNotRunning);
+            }
+
+            ensure_daemon_running()
+                .await
+                .map_err(|error| ClientError::Connection(error.to_string()))?;
+            client.send(request).await
+        }
+        result => result,
+    }
+}
+
 fn spawn_daemon() -> Result<()> {
     let daemon_path = find_daemon_binary()?;
 
+    let log = open_log_file()
+        .map(Stdio::from)
+        .unwrap_or_else(|_| Stdio::null());
+
     Command::new(&daemon_path)
         .stdin(Stdio::null())
         .stdout(Stdio::null())
-        .stderr(Stdio::null())
+        .stderr(log)
         .spawn()
         .with_context(|| format!("impossible de lancer {}", daemon_path.display()))?;
 
     Ok(())
 }
 
+/// Where the daemon launched by the CLI writes its logs, rewritten at each launch
+pub fn log_path() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("flux")
+        .join("daemon.log")
+}
+
+fn open_log_file() -> std::io::Result<File> {
+    let path = log_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    File::create(path)
+}
+
 fn find_daemon_binary() -> Result<PathBuf> {
     if let Ok(path) = std::env::var("FLUX_DAEMON_PATH") {
         return Ok(PathBuf::from(path));
@@ -60,11 +133,11 @@ fn find_daemon_binary() -> Result<PathBuf> {
 }
 
 async fn wait_for_socket() -> Result<()> {
-    let socket_path = default_socket_path();
+    let socket_path = socket::socket_path();
     let start = std::time::Instant::now();
 
     while start.elapsed() < DAEMON_STARTUP_TIMEOUT {
-        if socket_path.exists() {
+        if socket::is_listening(&socket_path) {
             return Ok(());
         }
         sleep(DAEMON_POLL_INTERVAL).await;
@@ -76,14 +149,36 @@ async fn wait_for_socket() -> Result<()> {
     );
 }
 
-#[cfg(unix)]
-fn default_socket_path() -> PathBuf {
-    let uid = unsafe { libc::getuid() };
-    PathBuf::from(format!("/run/user/{}/flux.sock", uid))
+pub async fn wait_for_shutdown() -> Result<()> {
+    let socket_path = socket::socket_path();
+    let start = std::time::Instant::now();
+
+    while start.elapsed() < DAEMON_SHUTDOWN_TIMEOUT {
+        if !socket::is_listening(&socket_path) {
+            return Ok(());
+        }
+        sleep(DAEMON_POLL_INTERVAL).await;
+    }
+
+    bail!(
+        "timeout: le daemon ne s'est pas arrêté après {} secondes",
+        DAEMON_SHUTDOWN_TIMEOUT.as_secs()
+    );
 }
 
-#[cfg(windows)]
-fn default_socket_path() -> PathBuf {
-    let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_else(|_| ".".to_string());
-    PathBuf::from(format!(r"{}\flux\flux.sock", local_app_data))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_start_skips_the_prompt() {
+        assert_eq!(start_decision(true, true), StartDecision::Start);
+        assert_eq!(start_decision(true, false), StartDecision::Start);
+    }
+
+    #[test]
+    fn prompt_only_in_a_terminal() {
+        assert_eq!(start_decision(false, true), StartDecision::Ask);
+        assert_eq!(start_decision(false, false), StartDecision::Skip);
+    }
 }
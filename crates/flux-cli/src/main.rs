@@ -2,13 +2,16 @@ pub mod client;
 mod commands;
 pub mod daemon_launcher;
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(name = "flux")]
 #[command(version)]
 #[command(about = "Flux CLI - Gestionnaire de sessions focus", long_about = None)]
 struct Cli {
+    /// Lancer le daemon sans confirmation s'il n'est pas démarré
+    #[arg(long, global = true)]
+    auto_start: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -29,11 +32,27 @@ enum Commands {
         /// Mode focus: ai-assisted, review, architecture, veille, ou custom
         #[arg(short, long)]
         mode: Option<String>,
+        /// Tag libre associé à la session (répétable)
+        #[arg(short, long = "tag")]
+        tags: Vec<String>,
+        /// Refuser pause et arrêt jusqu'à la fin, sauf en tapant la phrase de confirmation
+        #[arg(long)]
+        strict: bool,
     },
+    /// Relancer une session avec le mode, la durée et les tags de la précédente
+    Again,
     /// Arrêter la session en cours
-    Stop,
+    Stop {
+        /// Raison de l'arrêt, reprise dans les statistiques (ex: "réunion imprévue")
+        #[arg(short, long)]
+        reason: Option<String>,
+    },
     /// Mettre la session en pause
-    Pause,
+    Pause {
+        /// Raison de la pause, reprise dans les statistiques (ex: "standup")
+        #[arg(short, long)]
+        reason: Option<String>,
+    },
     /// Reprendre une session en pause
     Resume,
     /// Afficher le statut de la session
@@ -41,21 +60,72 @@ enum Commands {
         /// Afficher en format JSON
         #[arg(long)]
         json: bool,
+        /// Afficher l'état de chaque sous-système du daemon
+        #[arg(short, long)]
+        verbose: bool,
     },
+    /// Afficher un segment compact pour le prompt du shell (starship, PS1)
+    Prompt,
+    /// Afficher le temps de concentration du jour et l'objectif calculé
+    Today,
     /// Afficher les statistiques d'utilisation
     Stats {
         /// Période: today, week, month, all (défaut: week)
         #[arg(short, long, default_value = "week")]
         period: String,
+        /// Filtrer les sessions par tag
+        #[arg(long)]
+        tag: Option<String>,
+        /// Afficher la répartition par projet détecté
+        #[arg(long)]
+        by_project: bool,
+        /// Détailler le calcul du score de focus et ses poids
+        #[arg(long)]
+        explain_score: bool,
+        /// Calculer les temps par application depuis les données brutes plutôt que les agrégats quotidiens
+        #[arg(long)]
+        exact: bool,
+        /// Afficher l'évolution sur 8 semaines: mode (heures par mode)
+        #[arg(long, value_name = "TYPE")]
+        trend: Option<String>,
+        /// Format de sortie: pretty, table, csv, tsv, json (défaut: pretty)
+        #[arg(short, long, default_value = "pretty")]
+        format: String,
     },
     /// Afficher le résumé hebdomadaire
-    Digest,
+    Digest {
+        /// Format de sortie: text, table, csv, tsv, json (défaut: text)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+    /// Afficher les dernières sessions terminées
+    History {
+        /// Nombre de sessions à afficher (défaut: 10)
+        #[arg(short = 'n', long, default_value_t = 10)]
+        limit: usize,
+        /// Afficher en format JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Diagnostiquer la configuration, le daemon et les notifications
+    Doctor,
+    /// Rassembler un diagnostic anonymisé dans une archive zip à joindre à un ticket
+    ReportBug {
+        /// Chemin de l'archive (défaut: flux-bug-report-<date>.zip dans le dossier courant)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+        /// Inclure la configuration et les journaux sans confirmation
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
     /// Mettre à jour Flux vers la dernière version
     Update {
         /// Ignorer la confirmation si le daemon est en cours
         #[arg(short, long)]
         yes: bool,
     },
+    /// Désinstaller Flux: arrêter le daemon, retirer son service et choisir le sort des données
+    Uninstall,
     /// Change or display the current language
     Lang {
         /// Language code to set (en, fr). Without argument: displays current language.
@@ -74,8 +144,20 @@ enum Commands {
         /// Identifiant de la session à supprimer
         id: i64,
     },
+    /// Ajouter une note de rétrospective à une session
+    Note {
+        /// Ce que vous avez accompli pendant la session
+        #[arg(required = true)]
+        text: Vec<String>,
+        /// Session à annoter (défaut: la plus récente)
+        #[arg(long)]
+        session: Option<i64>,
+    },
     /// Gérer la liste des applications de distraction
     Distractions {
+        /// Profil à modifier (défaut: profil actif)
+        #[arg(short, long, global = true)]
+        profile: Option<String>,
         #[command(subcommand)]
         action: DistractionsAction,
     },
@@ -84,17 +166,88 @@ enum Commands {
         #[command(subcommand)]
         action: SuggestionsAction,
     },
+    /// Lister les tags utilisés et le temps passé sur chacun
+    Tags {
+        #[command(subcommand)]
+        action: TagsAction,
+    },
+    /// Ajouter un tag à une session terminée
+    Tag {
+        /// Identifiant de la session à taguer
+        session_id: i64,
+        /// Tag à ajouter
+        tag: String,
+    },
     /// Gérer les profils de configuration
     Profile {
         #[command(subcommand)]
         action: ProfileAction,
     },
+    /// Lire ou modifier la configuration
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Gérer le daemon Flux
+    Daemon {
+        #[command(subcommand)]
+        action: DaemonAction,
+    },
+    /// Fusionner l'historique des sessions avec les autres appareils via le dossier partagé
+    Sync,
+    /// Générer le script d'autocomplétion pour un shell (bash, zsh, fish...)
+    Completions {
+        /// Shell cible
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+    /// Générer les pages de manuel (roff) de flux et de ses sous-commandes
+    Man {
+        /// Dossier où écrire une page par commande (défaut: page principale sur la sortie standard)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+        /// Langue des descriptions: en, fr (défaut: langue configurée)
+        #[arg(long)]
+        lang: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum DaemonAction {
+    /// Afficher l'état du daemon (version, uptime, socket, base de données)
+    Status,
+    /// Arrêter proprement le daemon
+    Stop,
+    /// Redémarrer le daemon
+    Restart,
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Afficher la valeur d'une clé (ex: focus.default_duration_minutes)
+    Get {
+        /// Clé de configuration
+        key: String,
+    },
+    /// Modifier la valeur d'une clé
+    Set {
+        /// Clé de configuration
+        key: String,
+        /// Nouvelle valeur (listes séparées par des virgules)
+        value: String,
+    },
+    /// Afficher toutes les clés et leurs valeurs
+    List,
 }
 
 #[derive(Subcommand)]
 enum ProfileAction {
     /// Afficher la liste des profils disponibles
-    List,
+    List {
+        /// Afficher en format JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Afficher les détails d'un profil
     Show {
         /// Nom du profil à afficher (défaut: profil actif)
@@ -133,64 +286,233 @@ enum DistractionsAction {
     },
     /// Réinitialiser la liste aux valeurs par défaut
     Reset,
+    /// Gérer la liste blanche: jamais comptées comme distraction ni comme changement de contexte
+    Whitelist {
+        #[command(subcommand)]
+        action: WhitelistAction,
+    },
+    /// Gérer les applications à friction: un rappel s'affiche après un délai d'utilisation continue
+    Friction {
+        #[command(subcommand)]
+        action: FrictionAction,
+    },
+    /// Tester comment une application ou un titre serait classé
+    Test {
+        /// Nom de l'application à tester
+        name: String,
+        /// Titre de fenêtre à tester avec l'application
+        #[arg(long)]
+        title: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum WhitelistAction {
+    /// Afficher les applications en liste blanche
+    List,
+    /// Ajouter une application à la liste blanche (la retire des distractions)
+    Add {
+        /// Nom de l'application à ajouter
+        app: String,
+    },
+    /// Retirer une application de la liste blanche
+    Remove {
+        /// Nom de l'application à retirer
+        app: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum FrictionAction {
+    /// Afficher les applications à friction et le délai avant rappel
+    List,
+    /// Ajouter une application à friction
+    Add {
+        /// Nom de l'application à ajouter
+        app: String,
+    },
+    /// Retirer une application à friction
+    Remove {
+        /// Nom de l'application à retirer
+        app: String,
+    },
+    /// Définir le délai avant rappel, en secondes (1 à 300)
+    SetDelay {
+        /// Délai en secondes
+        #[arg(value_parser = clap::value_parser!(u64).range(1..=300))]
+        seconds: u64,
+    },
 }
 
 #[derive(Subcommand)]
 enum SuggestionsAction {
     /// Afficher les suggestions détectées
-    List,
+    List {
+        /// Afficher en format JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Ajouter l'application suggérée aux distractions du profil actif
+    Accept {
+        /// Numéro de la suggestion, tel qu'affiché par `flux suggestions list`
+        number: usize,
+    },
+    /// Écarter la suggestion: l'application ne sera plus jamais suggérée
+    Dismiss {
+        /// Numéro de la suggestion, tel qu'affiché par `flux suggestions list`
+        number: usize,
+    },
     /// Effacer les suggestions
     Clear,
 }
 
+#[derive(Subcommand)]
+enum TagsAction {
+    /// Afficher les tags avec leur temps de focus et leur nombre de sessions
+    List,
+}
+
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
 
     let result = match cli.command {
         Commands::Init { force } => commands::init(force),
-        Commands::Start { duration, mode } => {
+        Commands::Start {
+            duration,
+            mode,
+            tags,
+            strict,
+        } => {
             if !commands::config_exists() {
                 eprintln!("Erreur: Aucune configuration trouvée. Lancez `flux init` pour configurer Flux.");
                 std::process::exit(1);
             }
-            commands::start(duration, mode).await
+            commands::start(duration, mode, tags, strict).await
         }
-        Commands::Stop => commands::stop().await,
-        Commands::Pause => commands::pause().await,
-        Commands::Resume => commands::resume().await,
-        Commands::Status { json } => commands::status(json).await,
-        Commands::Stats { period } => {
-            let period = commands::Period::from_str(&period).unwrap_or(commands::Period::Week);
-            commands::stats(period).await
+        Commands::Again => {
+            if !commands::config_exists() {
+                eprintln!("Erreur: Aucune configuration trouvée. Lancez `flux init` pour configurer Flux.");
+                std::process::exit(1);
+            }
+            commands::again().await
         }
-        Commands::Digest => commands::digest().await,
+        Commands::Stop { reason } => commands::stop(reason).await,
+        Commands::Pause { reason } => commands::pause(cli.auto_start, reason).await,
+        Commands::Resume => commands::resume(cli.auto_start).await,
+        Commands::Status { json, verbose } => commands::status(json, verbose).await,
+        Commands::Prompt => commands::prompt().await,
+        Commands::Today => commands::today(),
+        Commands::Stats {
+            trend: Some(trend), ..
+        } => match commands::Trend::from_str(&trend) {
+            Some(trend) => commands::stats_trend(trend).await,
+            None => Err(anyhow::anyhow!("tendance inconnue: {} (mode)", trend)),
+        },
+        Commands::Stats {
+            period,
+            tag,
+            by_project,
+            explain_score,
+            exact,
+            trend: None,
+            format,
+        } => match commands::OutputFormat::from_str(&format) {
+            Some(format) => {
+                let period = commands::Period::from_str(&period).unwrap_or(commands::Period::Week);
+                commands::stats(period, tag, by_project, explain_score, exact, format).await
+            }
+            None => Err(anyhow::anyhow!(
+                "format inconnu: {} (pretty, table, csv, tsv, json)",
+                format
+            )),
+        },
+        Commands::Digest { format } => match commands::DigestFormat::from_str(&format) {
+            Some(format) => commands::digest(format).await,
+            None => Err(anyhow::anyhow!(
+                "format inconnu: {} (text, table, csv, tsv, json)",
+                format
+            )),
+        },
+        Commands::History { limit, json } => commands::history(limit, json),
+        Commands::Doctor => commands::doctor().await,
+        Commands::ReportBug { output, yes } => commands::report_bug(output, yes).await,
         Commands::Update { yes } => commands::update(yes).await,
+        Commands::Uninstall => commands::uninstall().await,
         Commands::Lang { language } => commands::lang(language),
         Commands::Dashboard => commands::dashboard(),
         Commands::Clear { yes } => commands::clear(yes).await,
         Commands::Delete { id } => commands::delete(id).await,
-        Commands::Distractions { action } => match action {
-            DistractionsAction::List => commands::distractions::list(),
-            DistractionsAction::Add { app } => commands::distractions::add(&app),
-            DistractionsAction::Remove { app } => commands::distractions::remove(&app),
-            DistractionsAction::AddPattern { pattern } => {
-                commands::distractions::add_pattern(&pattern)
-            }
-            DistractionsAction::RemovePattern { pattern } => {
-                commands::distractions::remove_pattern(&pattern)
+        Commands::Note { text, session } => commands::note(text, session).await,
+        Commands::Distractions { profile, action } => {
+            let profile = profile.as_deref();
+            match action {
+                DistractionsAction::List => commands::distractions::list(profile),
+                DistractionsAction::Add { app } => commands::distractions::add(&app, profile),
+                DistractionsAction::Remove { app } => commands::distractions::remove(&app, profile),
+                DistractionsAction::AddPattern { pattern } => {
+                    commands::distractions::add_pattern(&pattern, profile)
+                }
+                DistractionsAction::RemovePattern { pattern } => {
+                    commands::distractions::remove_pattern(&pattern, profile)
+                }
+                DistractionsAction::Reset => commands::distractions::reset(profile),
+                DistractionsAction::Whitelist { action } => match action {
+                    WhitelistAction::List => commands::distractions::whitelist_list(profile),
+                    WhitelistAction::Add { app } => {
+                        commands::distractions::whitelist_add(&app, profile)
+                    }
+                    WhitelistAction::Remove { app } => {
+                        commands::distractions::whitelist_remove(&app, profile)
+                    }
+                },
+                DistractionsAction::Friction { action } => match action {
+                    FrictionAction::List => commands::distractions::friction_list(profile),
+                    FrictionAction::Add { app } => {
+                        commands::distractions::friction_add(&app, profile)
+                    }
+                    FrictionAction::Remove { app } => {
+                        commands::distractions::friction_remove(&app, profile)
+                    }
+                    FrictionAction::SetDelay { seconds } => {
+                        commands::distractions::friction_set_delay(seconds, profile)
+                    }
+                },
+                DistractionsAction::Test { name, title } => {
+                    commands::distractions::test(&name, title.as_deref(), profile)
+                }
             }
-            DistractionsAction::Reset => commands::distractions::reset(),
-        },
+        }
         Commands::Suggestions { action } => match action {
-            SuggestionsAction::List => commands::suggestions::list(),
+            SuggestionsAction::List { json } => commands::suggestions::list(json),
+            SuggestionsAction::Accept { number } => commands::suggestions::accept(number),
+            SuggestionsAction::Dismiss { number } => commands::suggestions::dismiss(number),
             SuggestionsAction::Clear => commands::suggestions::clear(),
         },
+        Commands::Tags { action } => match action {
+            TagsAction::List => commands::tags::list(),
+        },
+        Commands::Tag { session_id, tag } => commands::tags::tag(session_id, &tag),
         Commands::Profile { action } => match action {
-            ProfileAction::List => commands::profile::list(),
+            ProfileAction::List { json } => commands::profile::list(json),
             ProfileAction::Show { name } => commands::profile::show(name),
             ProfileAction::Use { name } => commands::profile::use_profile(&name),
         },
+        Commands::Config { action } => match action {
+            ConfigAction::Get { key } => commands::config::get(&key),
+            ConfigAction::Set { key, value } => commands::config::set(&key, &value),
+            ConfigAction::List => commands::config::list(),
+        },
+        Commands::Daemon { action } => match action {
+            DaemonAction::Status => commands::daemon::status().await,
+            DaemonAction::Stop => commands::daemon::stop().await,
+            DaemonAction::Restart => commands::daemon::restart().await,
+        },
+        Commands::Sync => commands::sync().await,
+        Commands::Completions { shell } => commands::completions(Cli::command(), shell),
+        Commands::Man { output, lang } => {
+            commands::man(Cli::command(), output.as_deref(), lang.as_deref())
+        }
     };
 
     if let Err(error) = result {
@@ -0,0 +1,216 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub const ARCHIVE_FORMAT_VERSION: u32 = 1;
+pub const ARCHIVE_MANIFEST_FILE: &str = "manifest.toml";
+pub const ARCHIVE_DATABASE_FILE: &str = "sessions.db";
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+#[derive(Error, Debug)]
+pub enum ArchiveError {
+    #[error("manifeste d'archive invalide: {0}")]
+    InvalidManifest(String),
+
+    #[error("format d'archive non supporté: version {found} (maximum {supported})")]
+    UnsupportedFormat { found: u32, supported: u32 },
+
+    #[error(
+        "l'archive utilise un schéma plus récent ({archive}) que cette version de Flux ({current})"
+    )]
+    NewerSchema { archive: u32, current: u32 },
+
+    #[error("somme de contrôle invalide pour la table {table}")]
+    ChecksumMismatch { table: String },
+
+    #[error("table manquante dans l'archive: {table}")]
+    MissingTable { table: String },
+
+    #[error("erreur d'archive: {0}")]
+    Storage(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableChecksum {
+    pub name: String,
+    pub row_count: u64,
+    pub checksum: String,
+}
+
+impl TableChecksum {
+    pub fn compute<'a>(name: &str, rows: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut row_count = 0;
+
+        for row in rows {
+            for byte in row.bytes().chain(std::iter::once(b'\n')) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+            row_count += 1;
+        }
+
+        Self {
+            name: name.to_string(),
+            row_count,
+            checksum: format!("{:016x}", hash),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveCompatibility {
+    Current,
+    RequiresMigration { from: u32, to: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub format_version: u32,
+    pub schema_version: u32,
+    pub app_version: String,
+    pub created_at: DateTime<Utc>,
+    pub tables: Vec<TableChecksum>,
+}
+
+impl ArchiveManifest {
+    pub fn new(schema_version: u32, app_version: &str, tables: Vec<TableChecksum>) -> Self {
+        Self {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            schema_version,
+            app_version: app_version.to_string(),
+            created_at: Utc::now(),
+            tables,
+        }
+    }
+
+    pub fn to_toml(&self) -> Result<String, ArchiveError> {
+        toml::to_string_pretty(self)
+            .map_err(|error| ArchiveError::InvalidManifest(error.to_string()))
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, ArchiveError> {
+        toml::from_str(content).map_err(|error| ArchiveError::InvalidManifest(error.to_string()))
+    }
+
+    pub fn compatibility(
+        &self,
+        current_schema_version: u32,
+    ) -> Result<ArchiveCompatibility, ArchiveError> {
+        if self.format_version > ARCHIVE_FORMAT_VERSION {
+            return Err(ArchiveError::UnsupportedFormat {
+                found: self.format_version,
+                supported: ARCHIVE_FORMAT_VERSION,
+            });
+        }
+
+        if self.schema_version > current_schema_version {
+            return Err(ArchiveError::NewerSchema {
+                archive: self.schema_version,
+                current: current_schema_version,
+            });
+        }
+
+        if self.schema_version < current_schema_version {
+            return Ok(ArchiveCompatibility::RequiresMigration {
+                from: self.schema_version,
+                to: current_schema_version,
+            });
+        }
+
+        Ok(ArchiveCompatibility::Current)
+    }
+
+    pub fn verify(&self, actual: &[TableChecksum]) -> Result<(), ArchiveError> {
+        for expected in &self.tables {
+            let found = actual
+                .iter()
+                .find(|table| table.name == expected.name)
+                .ok_or_else(|| ArchiveError::MissingTable {
+                    table: expected.name.clone(),
+                })?;
+
+            if found != expected {
+                return Err(ArchiveError::ChecksumMismatch {
+                    table: expected.name.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_manifest(schema_version: u32) -> ArchiveManifest {
+        ArchiveManifest::new(
+            schema_version,
+            "0.2.13",
+            vec![TableChecksum::compute("sessions", ["1|review", "2|veille"])],
+        )
+    }
+
+    #[test]
+    fn checksum_depends_on_content_and_order() {
+        let first = TableChecksum::compute("sessions", ["a", "b"]);
+
+        assert_eq!(first, TableChecksum::compute("sessions", ["a", "b"]));
+        assert_ne!(first, TableChecksum::compute("sessions", ["b", "a"]));
+        assert_ne!(first, TableChecksum::compute("sessions", ["ab"]));
+        assert_eq!(first.row_count, 2);
+    }
+
+    #[test]
+    fn manifest_roundtrips_through_toml() {
+        let manifest = create_manifest(2);
+
+        let decoded = ArchiveManifest::from_toml(&manifest.to_toml().unwrap()).unwrap();
+
+        assert_eq!(decoded, manifest);
+    }
+
+    #[test]
+    fn older_schema_requires_migration_and_newer_is_rejected() {
+        assert_eq!(
+            create_manifest(2).compatibility(2).unwrap(),
+            ArchiveCompatibility::Current
+        );
+        assert_eq!(
+            create_manifest(1).compatibility(3).unwrap(),
+            ArchiveCompatibility::RequiresMigration { from: 1, to: 3 }
+        );
+        assert!(matches!(
+            create_manifest(4).compatibility(3),
+            Err(ArchiveError::NewerSchema { .. })
+        ));
+
+        let mut future_format = create_manifest(1);
+        future_format.format_version = ARCHIVE_FORMAT_VERSION + 1;
+        assert!(matches!(
+            future_format.compatibility(1),
+            Err(ArchiveError::UnsupportedFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_detects_tampered_and_missing_tables() {
+        let manifest = create_manifest(1);
+
+        assert!(manifest
+            .verify(&[TableChecksum::compute("sessions", ["1|review", "2|veille"])])
+            .is_ok());
+        assert!(matches!(
+            manifest.verify(&[TableChecksum::compute("sessions", ["1|review"])]),
+            Err(ArchiveError::ChecksumMismatch { .. })
+        ));
+        assert!(matches!(
+            manifest.verify(&[]),
+            Err(ArchiveError::MissingTable { .. })
+        ));
+    }
+}
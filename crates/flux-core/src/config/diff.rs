@@ -0,0 +1,100 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Unchanged(String),
+    Removed(String),
+    Added(String),
+}
+
+impl DiffLine {
+    pub fn is_change(&self) -> bool {
+        !matches!(self, DiffLine::Unchanged(_))
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            DiffLine::Unchanged(text) | DiffLine::Removed(text) | DiffLine::Added(text) => text,
+        }
+    }
+}
+
+/// Line-by-line diff of two configuration files, based on their longest common
+/// subsequence. Configuration files stay small, so the quadratic table is fine.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    let mut common = vec![vec![0usize; new_lines.len() + 1]; old_lines.len() + 1];
+    for old_index in (0..old_lines.len()).rev() {
+        for new_index in (0..new_lines.len()).rev() {
+            common[old_index][new_index] = if old_lines[old_index] == new_lines[new_index] {
+                common[old_index + 1][new_index + 1] + 1
+            } else {
+                common[old_index + 1][new_index].max(common[old_index][new_index + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut old_index, mut new_index) = (0, 0);
+    while old_index < old_lines.len() && new_index < new_lines.len() {
+        if old_lines[old_index] == new_lines[new_index] {
+            lines.push(DiffLine::Unchanged(old_lines[old_index].to_string()));
+            old_index += 1;
+            new_index += 1;
+        } else if common[old_index + 1][new_index] >= common[old_index][new_index + 1] {
+            lines.push(DiffLine::Removed(old_lines[old_index].to_string()));
+            old_index += 1;
+        } else {
+            lines.push(DiffLine::Added(new_lines[new_index].to_string()));
+            new_index += 1;
+        }
+    }
+    lines.extend(
+        old_lines[old_index..]
+            .iter()
+            .map(|line| DiffLine::Removed(line.to_string())),
+    );
+    lines.extend(
+        new_lines[new_index..]
+            .iter()
+            .map(|line| DiffLine::Added(line.to_string())),
+    );
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_content_has_no_change() {
+        let content = "[general]\nlanguage = \"en\"\n";
+
+        assert!(!diff_lines(content, content).iter().any(DiffLine::is_change));
+    }
+
+    #[test]
+    fn replaced_line_shows_as_removed_then_added() {
+        let old = "[distractions]\napps = [\"discord\"]\nfriction_delay_seconds = 10\n";
+        let new = "[distractions]\napps = [\"discord\", \"slack\"]\nfriction_delay_seconds = 10\n";
+
+        assert_eq!(
+            diff_lines(old, new),
+            vec![
+                DiffLine::Unchanged("[distractions]".to_string()),
+                DiffLine::Removed("apps = [\"discord\"]".to_string()),
+                DiffLine::Added("apps = [\"discord\", \"slack\"]".to_string()),
+                DiffLine::Unchanged("friction_delay_seconds = 10".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn new_section_on_empty_file_is_all_added() {
+        let lines = diff_lines("", "[distractions]\napps = [\"discord\"]\n");
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| matches!(line, DiffLine::Added(_))));
+    }
+}
@@ -0,0 +1,179 @@
+use super::DistractionConfig;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DistractionRule {
+    Application(String),
+    TitlePattern(String),
+    Friction(String),
+    Whitelist(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistractionVerdict {
+    Distraction,
+    Friction,
+    Whitelisted,
+    Neutral,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistractionMatch {
+    pub verdict: DistractionVerdict,
+    pub rules: Vec<DistractionRule>,
+}
+
+impl DistractionConfig {
+    pub fn explain(&self, application_name: &str, window_title: &str) -> DistractionMatch {
+        let application = application_name.to_lowercase();
+        let title = window_title.to_lowercase();
+        let mut rules = Vec::new();
+
+        if !application.is_empty() {
+            rules.extend(
+                matching_patterns(&self.apps, &application)
+                    .into_iter()
+                    .map(DistractionRule::Application),
+            );
+        }
+        if !title.is_empty() {
+            rules.extend(
+                matching_patterns(&self.title_patterns, &title)
+                    .into_iter()
+                    .map(DistractionRule::TitlePattern),
+            );
+        }
+        if !application.is_empty() {
+            rules.extend(
+                matching_patterns(&self.friction_apps, &application)
+                    .into_iter()
+                    .map(DistractionRule::Friction),
+            );
+            rules.extend(
+                matching_patterns(&self.whitelist_apps, &application)
+                    .into_iter()
+                    .map(DistractionRule::Whitelist),
+            );
+        }
+
+        let verdict = if rules.iter().any(|rule| {
+            matches!(
+                rule,
+                DistractionRule::Application(_) | DistractionRule::TitlePattern(_)
+            )
+        }) {
+            DistractionVerdict::Distraction
+        } else if rules
+            .iter()
+            .any(|rule| matches!(rule, DistractionRule::Friction(_)))
+        {
+            DistractionVerdict::Friction
+        } else if rules.is_empty() {
+            DistractionVerdict::Neutral
+        } else {
+            DistractionVerdict::Whitelisted
+        };
+
+        DistractionMatch { verdict, rules }
+    }
+}
+
+fn matching_patterns<'a>(
+    patterns: impl IntoIterator<Item = &'a String>,
+    value: &str,
+) -> Vec<String> {
+    let mut matched: Vec<String> = patterns
+        .into_iter()
+        .filter(|pattern| value.contains(pattern.as_str()))
+        .cloned()
+        .collect();
+    matched.sort();
+    matched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn create_config() -> DistractionConfig {
+        DistractionConfig {
+            apps: HashSet::from(["discord".to_string(), "slack".to_string()]),
+            title_patterns: HashSet::from(["youtube".to_string()]),
+            whitelist_apps: HashSet::from(["code".to_string(), "firefox".to_string()]),
+            friction_apps: HashSet::from(["reddit".to_string()]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn explain_reports_application_and_title_rules() {
+        let config = create_config();
+
+        let result = config.explain("Discord", "");
+        assert_eq!(result.verdict, DistractionVerdict::Distraction);
+        assert_eq!(
+            result.rules,
+            vec![DistractionRule::Application("discord".to_string())]
+        );
+
+        let result = config.explain("Firefox", "Rust talk - YouTube");
+        assert_eq!(result.verdict, DistractionVerdict::Distraction);
+        assert_eq!(
+            result.rules,
+            vec![
+                DistractionRule::TitlePattern("youtube".to_string()),
+                DistractionRule::Whitelist("firefox".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn explain_matches_tracker_predicates() {
+        let config = create_config();
+
+        for (application, title) in [
+            ("code", "main.rs"),
+            ("Slack", ""),
+            ("alacritty", "youtube-dl"),
+            ("obsidian", "notes"),
+        ] {
+            let is_distraction =
+                config.is_distraction(application) || config.is_title_distraction(title);
+            let verdict = config.explain(application, title).verdict;
+
+            assert_eq!(
+                verdict == DistractionVerdict::Distraction,
+                is_distraction,
+                "{application} / {title}"
+            );
+        }
+    }
+
+    #[test]
+    fn explain_reports_friction_when_no_distraction_rule_matches() {
+        let config = create_config();
+
+        let result = config.explain("reddit-desktop", "");
+
+        assert_eq!(result.verdict, DistractionVerdict::Friction);
+        assert_eq!(
+            result.rules,
+            vec![DistractionRule::Friction("reddit".to_string())]
+        );
+    }
+
+    #[test]
+    fn explain_distinguishes_whitelisted_and_neutral() {
+        let config = create_config();
+
+        assert_eq!(
+            config.explain("Code", "").verdict,
+            DistractionVerdict::Whitelisted
+        );
+        assert_eq!(
+            config.explain("obsidian", "").verdict,
+            DistractionVerdict::Neutral
+        );
+        assert!(config.explain("", "").rules.is_empty());
+    }
+}
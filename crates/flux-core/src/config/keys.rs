@@ -0,0 +1,342 @@
+use std::collections::HashSet;
+
+use toml_edit::{Array, Value};
+
+use super::{Config, ConfigError, ConfigWriter, NotificationUrgency};
+
+const WEEKDAYS: &[&str] = &[
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigScope {
+    Global,
+    Profile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigValueKind {
+    Boolean,
+    Integer { minimum: i64, maximum: i64 },
+    Choice(&'static [&'static str]),
+    List,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigKey {
+    pub name: &'static str,
+    pub scope: ConfigScope,
+    pub kind: ConfigValueKind,
+}
+
+const fn global(name: &'static str, kind: ConfigValueKind) -> ConfigKey {
+    ConfigKey {
+        name,
+        scope: ConfigScope::Global,
+        kind,
+    }
+}
+
+const fn profile(name: &'static str, kind: ConfigValueKind) -> ConfigKey {
+    ConfigKey {
+        name,
+        scope: ConfigScope::Profile,
+        kind,
+    }
+}
+
+const fn integer(minimum: i64, maximum: i64) -> ConfigValueKind {
+    ConfigValueKind::Integer { minimum, maximum }
+}
+
+const CONFIG_KEYS: &[ConfigKey] = &[
+    global("general.language", ConfigValueKind::Choice(&["en", "fr"])),
+    global(
+        "general.week_starts_on",
+        ConfigValueKind::Choice(&["monday", "sunday"]),
+    ),
+    global("tray.enabled", ConfigValueKind::Boolean),
+    global("tray.show_countdown", ConfigValueKind::Boolean),
+    global("tray.hide_seconds", ConfigValueKind::Boolean),
+    global("battery.enabled", ConfigValueKind::Boolean),
+    global("battery.polling_interval_seconds", integer(5, 300)),
+    global("battery.sound_enabled", ConfigValueKind::Boolean),
+    global("battery.tray_countdown", ConfigValueKind::Boolean),
+    global("storage.required", ConfigValueKind::Boolean),
+    global("privacy.redact_title_patterns", ConfigValueKind::List),
+    global("privacy.encrypt_window_titles", ConfigValueKind::Boolean),
+    global("tracking.title_apps", ConfigValueKind::List),
+    global("goals.weekly_minutes", integer(1, 10080)),
+    global("goals.rest_days", ConfigValueKind::List),
+    global("experimental.input_activity", ConfigValueKind::Boolean),
+    global("metrics.context_switch_weight", integer(0, 100)),
+    global("metrics.short_burst_weight", integer(0, 100)),
+    global("metrics.distraction_ratio_weight", integer(0, 100)),
+    global("metrics.pause_weight", integer(0, 100)),
+    global("suggestions.min_short_bursts", integer(1, 100)),
+    global("suggestions.cumulative_minutes", integer(1, 480)),
+    global("suggestions.title_minutes", integer(1, 480)),
+    global("suggestions.friction_quota_minutes", integer(1, 480)),
+    profile("focus.default_duration_minutes", integer(1, 480)),
+    profile("focus.check_in_interval_minutes", integer(1, 480)),
+    profile("focus.check_in_timeout_seconds", integer(1, 3600)),
+    profile("focus.veille_reminder_minutes", integer(1, 1440)),
+    profile("focus.preparation_seconds", integer(0, 600)),
+    profile("focus.auto_complete_threshold_percent", integer(50, 100)),
+    profile("notifications.sound_enabled", ConfigValueKind::Boolean),
+    profile(
+        "notifications.urgency",
+        ConfigValueKind::Choice(&["low", "normal", "critical"]),
+    ),
+    profile("notifications.enable_dnd", ConfigValueKind::Boolean),
+    profile("distractions.apps", ConfigValueKind::List),
+    profile("distractions.title_patterns", ConfigValueKind::List),
+    profile("distractions.alert_enabled", ConfigValueKind::Boolean),
+    profile("distractions.alert_after_seconds", integer(1, 3600)),
+    profile(
+        "distractions.alert_mode",
+        ConfigValueKind::Choice(&["immediate", "summary", "both"]),
+    ),
+    profile("distractions.friction_apps", ConfigValueKind::List),
+    profile("distractions.friction_delay_seconds", integer(1, 300)),
+    profile("distractions.whitelist_apps", ConfigValueKind::List),
+    profile("digest.enabled", ConfigValueKind::Boolean),
+    profile("digest.day", ConfigValueKind::Choice(WEEKDAYS)),
+    profile("digest.hour", integer(0, 23)),
+    profile("digest.save_report", ConfigValueKind::Boolean),
+    profile("digest.regression_threshold_percent", integer(0, 100)),
+];
+
+impl ConfigKey {
+    pub fn all() -> &'static [ConfigKey] {
+        CONFIG_KEYS
+    }
+
+    pub fn find(name: &str) -> Result<&'static ConfigKey, ConfigError> {
+        CONFIG_KEYS
+            .iter()
+            .find(|key| key.name == name)
+            .ok_or_else(|| ConfigError::UnknownKey {
+                key: name.to_string(),
+            })
+    }
+
+    pub fn section(&self) -> &'static str {
+        self.name
+            .split_once('.')
+            .map(|(section, _)| section)
+            .unwrap_or(self.name)
+    }
+
+    pub fn field(&self) -> &'static str {
+        self.name
+            .split_once('.')
+            .map(|(_, field)| field)
+            .unwrap_or(self.name)
+    }
+
+    pub fn parse_value(&self, raw: &str) -> Result<Value, ConfigError> {
+        let raw = raw.trim();
+        let invalid = || ConfigError::InvalidValue {
+            key: self.name.to_string(),
+            value: raw.to_string(),
+            expected: self.expected(),
+        };
+
+        match self.kind {
+            ConfigValueKind::Boolean => match raw.to_lowercase().as_str() {
+                "true" | "yes" | "on" => Ok(Value::from(true)),
+                "false" | "no" | "off" => Ok(Value::from(false)),
+                _ => Err(invalid()),
+            },
+            ConfigValueKind::Integer { minimum, maximum } => {
+                let value: i64 = raw.parse().map_err(|_| invalid())?;
+                if (minimum..=maximum).contains(&value) {
+                    Ok(Value::from(value))
+                } else {
+                    Err(invalid())
+                }
+            }
+            ConfigValueKind::Choice(choices) => {
+                let value = raw.to_lowercase();
+                if choices.contains(&value.as_str()) {
+                    Ok(Value::from(value))
+                } else {
+                    Err(invalid())
+                }
+            }
+            ConfigValueKind::List => {
+                let mut items: Vec<String> = raw
+                    .split(',')
+                    .map(|item| item.trim().to_lowercase())
+                    .filter(|item| !item.is_empty())
+                    .collect();
+                items.sort();
+                items.dedup();
+                Ok(Value::Array(items.into_iter().collect::<Array>()))
+            }
+        }
+    }
+
+    fn expected(&self) -> String {
+        match self.kind {
+            ConfigValueKind::Boolean => "true ou false".to_string(),
+            ConfigValueKind::Integer { minimum, maximum } => {
+                format!("un entier entre {} et {}", minimum, maximum)
+            }
+            ConfigValueKind::Choice(choices) => format!("une valeur parmi {}", choices.join(", ")),
+            ConfigValueKind::List => "une liste séparée par des virgules".to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub fn get_value(&self, name: &str) -> Result<String, ConfigError> {
+        let key = ConfigKey::find(name)?;
+        Ok(self.read_value(key))
+    }
+
+    pub fn values(&self) -> Vec<(&'static str, String)> {
+        CONFIG_KEYS
+            .iter()
+            .map(|key| (key.name, self.read_value(key)))
+            .collect()
+    }
+
+    pub fn set_value(name: &str, raw: &str) -> Result<(), ConfigError> {
+        let key = ConfigKey::find(name)?;
+        let value = key.parse_value(raw)?;
+        let active_profile = Self::load()?.active_profile_name();
+
+        let mut writer = ConfigWriter::open()?;
+        writer.set_value(key, value, &active_profile)?;
+        writer.save()
+    }
+
+    fn read_value(&self, key: &ConfigKey) -> String {
+        let profile = self.active_profile();
+        match key.name {
+            "general.language" => self.general.language.code().to_string(),
+            "general.week_starts_on" => self.general.week_starts_on.as_str().to_string(),
+            "tray.enabled" => self.tray.enabled.to_string(),
+            "tray.show_countdown" => self.tray.show_countdown.to_string(),
+            "tray.hide_seconds" => self.tray.hide_seconds.to_string(),
+            "battery.enabled" => self.battery.enabled.to_string(),
+            "battery.polling_interval_seconds" => self.battery.polling_interval_seconds.to_string(),
+            "battery.sound_enabled" => self.battery.sound_enabled.to_string(),
+            "battery.tray_countdown" => self.battery.tray_countdown.to_string(),
+            "storage.required" => self.storage.required.to_string(),
+            "privacy.redact_title_patterns" => format_list(&self.privacy.redact_title_patterns),
+            "privacy.encrypt_window_titles" => self.privacy.encrypt_window_titles.to_string(),
+            "tracking.title_apps" => format_list(&self.tracking.title_apps),
+            "goals.weekly_minutes" => self
+                .goals
+                .weekly_minutes
+                .map(|minutes| minutes.to_string())
+                .unwrap_or_default(),
+            "goals.rest_days" => self.goals.rest_days.join(", "),
+            "experimental.input_activity" => self.experimental.input_activity.to_string(),
+            "metrics.context_switch_weight" => self.metrics.context_switch_weight.to_string(),
+            "metrics.short_burst_weight" => self.metrics.short_burst_weight.to_string(),
+            "metrics.distraction_ratio_weight" => self.metrics.distraction_ratio_weight.to_string(),
+            "metrics.pause_weight" => self.metrics.pause_weight.to_string(),
+            "suggestions.min_short_bursts" => self.suggestions.min_short_bursts.to_string(),
+            "suggestions.cumulative_minutes" => self.suggestions.cumulative_minutes.to_string(),
+            "suggestions.title_minutes" => self.suggestions.title_minutes.to_string(),
+            "suggestions.friction_quota_minutes" => {
+                self.suggestions.friction_quota_minutes.to_string()
+            }
+            "focus.default_duration_minutes" => profile.focus.default_duration_minutes.to_string(),
+            "focus.check_in_interval_minutes" => {
+                profile.focus.check_in_interval_minutes.to_string()
+            }
+            "focus.check_in_timeout_seconds" => profile.focus.check_in_timeout_seconds.to_string(),
+            "focus.veille_reminder_minutes" => profile.focus.veille_reminder_minutes.to_string(),
+            "focus.preparation_seconds" => profile.focus.preparation_seconds.to_string(),
+            "focus.auto_complete_threshold_percent" => {
+                profile.focus.auto_complete_threshold_percent.to_string()
+            }
+            "notifications.sound_enabled" => profile.notifications.sound_enabled.to_string(),
+            "notifications.urgency" => match profile.notifications.urgency {
+                NotificationUrgency::Low => "low",
+                NotificationUrgency::Normal => "normal",
+                NotificationUrgency::Critical => "critical",
+            }
+            .to_string(),
+            "notifications.enable_dnd" => profile.notifications.enable_dnd.to_string(),
+            "distractions.apps" => format_list(&profile.distractions.apps),
+            "distractions.title_patterns" => format_list(&profile.distractions.title_patterns),
+            "distractions.alert_enabled" => profile.distractions.alert_enabled.to_string(),
+            "distractions.alert_after_seconds" => {
+                profile.distractions.alert_after_seconds.to_string()
+            }
+            "distractions.alert_mode" => profile.distractions.alert_mode.as_str().to_string(),
+            "distractions.friction_apps" => format_list(&profile.distractions.friction_apps),
+            "distractions.friction_delay_seconds" => {
+                profile.distractions.friction_delay_seconds.to_string()
+            }
+            "distractions.whitelist_apps" => format_list(&profile.distractions.whitelist_apps),
+            "digest.enabled" => profile.digest.enabled.to_string(),
+            "digest.day" => profile.digest.day.clone(),
+            "digest.hour" => profile.digest.hour.to_string(),
+            "digest.save_report" => profile.digest.save_report.to_string(),
+            "digest.regression_threshold_percent" => {
+                profile.digest.regression_threshold_percent.to_string()
+            }
+            _ => String::new(),
+        }
+    }
+}
+
+fn format_list(values: &HashSet<String>) -> String {
+    let mut sorted: Vec<_> = values.iter().map(String::as_str).collect();
+    sorted.sort();
+    sorted.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_rejects_unknown_key() {
+        let error = ConfigKey::find("focus.unknown").unwrap_err();
+
+        assert!(matches!(error, ConfigError::UnknownKey { key } if key == "focus.unknown"));
+    }
+
+    #[test]
+    fn parse_value_validates_each_kind() {
+        let hour = ConfigKey::find("digest.hour").unwrap();
+        assert_eq!(hour.parse_value("9").unwrap().as_integer(), Some(9));
+        assert!(hour.parse_value("24").is_err());
+        assert!(hour.parse_value("nine").is_err());
+
+        let sound = ConfigKey::find("notifications.sound_enabled").unwrap();
+        assert_eq!(sound.parse_value("off").unwrap().as_bool(), Some(false));
+        assert!(sound.parse_value("maybe").is_err());
+
+        let urgency = ConfigKey::find("notifications.urgency").unwrap();
+        assert_eq!(
+            urgency.parse_value("Critical").unwrap().as_str(),
+            Some("critical")
+        );
+        assert!(urgency.parse_value("urgent").is_err());
+
+        let apps = ConfigKey::find("distractions.apps").unwrap();
+        let parsed = apps.parse_value("Slack, discord, ,slack").unwrap();
+        let items: Vec<_> = parsed
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|item| item.as_str())
+            .collect();
+        assert_eq!(items, vec!["discord", "slack"]);
+    }
+}
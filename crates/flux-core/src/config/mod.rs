@@ -0,0 +1,1541 @@
+use crate::domain::{
+    is_project_application, DailyTarget, DistractionSuggestion, FocusMode, ScoreWeights, Session,
+    SuggestionThresholds, WeekStart,
+};
+use crate::i18n::Language;
+use crate::state::AppState;
+use chrono::{NaiveDate, Weekday};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+mod diff;
+mod distraction_match;
+mod keys;
+mod mode_colors;
+mod workspace;
+mod writer;
+
+pub use diff::{diff_lines, DiffLine};
+pub use distraction_match::{DistractionMatch, DistractionRule, DistractionVerdict};
+pub use keys::{ConfigKey, ConfigScope, ConfigValueKind};
+pub use mode_colors::{ModeColor, ModesConfig};
+pub use workspace::{StartOptions, WorkspaceConfig, WorkspaceDistractions, WORKSPACE_CONFIG_FILE};
+pub use writer::ConfigWriter;
+
+fn default_profile() -> &'static Profile {
+    static DEFAULT_PROFILE: OnceLock<Profile> = OnceLock::new();
+    DEFAULT_PROFILE.get_or_init(Profile::default)
+}
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("fichier de configuration introuvable: {path}")]
+    NotFound { path: String },
+
+    #[error("erreur de lecture: {source}")]
+    Read {
+        #[from]
+        source: std::io::Error,
+    },
+
+    #[error("erreur de parsing TOML: {source}")]
+    Parse {
+        #[from]
+        source: toml::de::Error,
+    },
+
+    #[error("document TOML invalide: {message}")]
+    InvalidDocument { message: String },
+
+    #[error("clé de configuration inconnue: {key}")]
+    UnknownKey { key: String },
+
+    #[error("valeur invalide pour {key}: \"{value}\" (attendu: {expected})")]
+    InvalidValue {
+        key: String,
+        value: String,
+        expected: String,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct Profile {
+    pub general: ProfileGeneralConfig,
+    pub focus: FocusConfig,
+    pub notifications: NotificationConfig,
+    pub distractions: DistractionConfig,
+    pub digest: DigestConfig,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct Config {
+    pub general: GeneralConfig,
+    pub tray: TrayConfig,
+    pub battery: BatteryConfig,
+    pub storage: StorageConfig,
+    pub hooks: HooksConfig,
+    pub privacy: PrivacyConfig,
+    pub tracking: TrackingConfig,
+    pub modes: ModesConfig,
+    pub goals: GoalsConfig,
+    pub experimental: ExperimentalConfig,
+    pub metrics: MetricsConfig,
+    pub suggestions: SuggestionsConfig,
+    pub gitlab: Option<ProviderConfig>,
+    pub github: Option<ProviderConfig>,
+    pub calendar: Option<CalendarConfig>,
+    pub sync: Option<SyncConfig>,
+    #[serde(default)]
+    pub profile: HashMap<String, Profile>,
+
+    #[serde(default)]
+    focus: Option<FocusConfig>,
+    #[serde(default)]
+    notifications: Option<NotificationConfig>,
+    #[serde(default)]
+    distractions: Option<DistractionConfig>,
+    #[serde(default)]
+    digest: Option<DigestConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct GeneralConfig {
+    pub language: Language,
+    pub week_starts_on: WeekStart,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ProfileGeneralConfig {
+    pub language: Option<Language>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FocusConfig {
+    pub default_duration_minutes: u64,
+    pub check_in_interval_minutes: u64,
+    pub check_in_timeout_seconds: u64,
+    pub veille_reminder_minutes: u64,
+    pub preparation_seconds: u64,
+    /// Stopping a session past this share of its planned time records it as completed
+    pub auto_complete_threshold_percent: u8,
+    pub modes: HashMap<String, ModeOverride>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ModeOverride {
+    pub default_duration_minutes: Option<u64>,
+    pub check_ins_enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModeSettings {
+    pub duration_minutes: u64,
+    pub check_ins_enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    pub sound_enabled: bool,
+    pub urgency: NotificationUrgency,
+    pub enable_dnd: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationUrgency {
+    Low,
+    #[default]
+    Normal,
+    Critical,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DistractionAlertMode {
+    #[default]
+    Immediate,
+    Summary,
+    Both,
+}
+
+impl DistractionAlertMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistractionAlertMode::Immediate => "immediate",
+            DistractionAlertMode::Summary => "summary",
+            DistractionAlertMode::Both => "both",
+        }
+    }
+
+    /// Whether an alert is shown while the distraction is happening
+    pub fn interrupts(&self) -> bool {
+        *self != DistractionAlertMode::Summary
+    }
+
+    /// Whether incidents are collected for the end-of-session summary
+    pub fn summarizes(&self) -> bool {
+        *self != DistractionAlertMode::Immediate
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderConfig {
+    pub base_url: String,
+}
+
+/// ICS feed of the user's meetings: an https:// or webcal:// URL (CalDAV export) or a local file
+#[derive(Debug, Clone, Deserialize)]
+pub struct CalendarConfig {
+    pub url: String,
+    #[serde(default = "default_meeting_warning_minutes")]
+    pub warning_minutes: u32,
+    #[serde(default)]
+    pub auto_pause: bool,
+}
+
+fn default_meeting_warning_minutes() -> u32 {
+    5
+}
+
+/// Folder shared between devices (Syncthing, Dropbox...) through which their
+/// session histories are merged
+#[derive(Debug, Clone, Deserialize)]
+pub struct SyncConfig {
+    pub folder: PathBuf,
+    /// Name of this device in the folder, the host name when unset
+    #[serde(default)]
+    pub device: Option<String>,
+    #[serde(default = "default_sync_interval_minutes")]
+    pub interval_minutes: u64,
+}
+
+fn default_sync_interval_minutes() -> u64 {
+    15
+}
+
+impl SyncConfig {
+    /// None when no name is configured and the host name cannot be read
+    pub fn device_name(&self) -> Option<String> {
+        self.device
+            .clone()
+            .or_else(|| std::env::var("HOSTNAME").ok())
+            .or_else(|| std::env::var("COMPUTERNAME").ok())
+            .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+    }
+}
+
+impl Default for FocusConfig {
+    fn default() -> Self {
+        Self {
+            default_duration_minutes: 25,
+            check_in_interval_minutes: 25,
+            check_in_timeout_seconds: 120,
+            veille_reminder_minutes: 60,
+            preparation_seconds: 0,
+            auto_complete_threshold_percent: 95,
+            modes: HashMap::new(),
+        }
+    }
+}
+
+impl FocusConfig {
+    pub fn settings_for(&self, mode: &FocusMode) -> ModeSettings {
+        let mode_override = self.modes.get(mode.as_str()).or_else(|| match mode {
+            FocusMode::AiAssisted => self.modes.get("prompting"),
+            _ => None,
+        });
+
+        ModeSettings {
+            duration_minutes: mode_override
+                .and_then(|settings| settings.default_duration_minutes)
+                .unwrap_or(self.default_duration_minutes),
+            check_ins_enabled: mode_override
+                .and_then(|settings| settings.check_ins_enabled)
+                .unwrap_or(!mode.disables_interruptions()),
+        }
+    }
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            sound_enabled: true,
+            urgency: NotificationUrgency::Normal,
+            enable_dnd: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct TrayConfig {
+    pub enabled: bool,
+    pub show_countdown: bool,
+    /// Show whole minutes in the tooltip, so the tray only redraws once a minute
+    pub hide_seconds: bool,
+}
+
+/// Lighter tracking applied while the machine runs on battery
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct BatteryConfig {
+    pub enabled: bool,
+    pub polling_interval_seconds: u64,
+    pub sound_enabled: bool,
+    pub tray_countdown: bool,
+}
+
+impl Default for BatteryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            polling_interval_seconds: 15,
+            sound_enabled: false,
+            tray_countdown: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct StorageConfig {
+    pub required: bool,
+}
+
+/// Opt-in features whose behavior may still change
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ExperimentalConfig {
+    pub input_activity: bool,
+}
+
+/// Focus score weights: points removed per occurrence, or at 100% for `distraction_ratio_weight`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    pub context_switch_weight: u32,
+    pub short_burst_weight: u32,
+    pub distraction_ratio_weight: u32,
+    pub pause_weight: u32,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        let weights = ScoreWeights::default();
+        Self {
+            context_switch_weight: weights.context_switch,
+            short_burst_weight: weights.short_burst,
+            distraction_ratio_weight: weights.distraction_ratio,
+            pause_weight: weights.pause,
+        }
+    }
+}
+
+impl MetricsConfig {
+    pub fn score_weights(&self) -> ScoreWeights {
+        ScoreWeights {
+            context_switch: self.context_switch_weight,
+            short_burst: self.short_burst_weight,
+            distraction_ratio: self.distraction_ratio_weight,
+            pause: self.pause_weight,
+        }
+    }
+}
+
+/// Evidence the distraction suggestion strategies need before suggesting an app
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SuggestionsConfig {
+    pub min_short_bursts: u32,
+    pub cumulative_minutes: u32,
+    pub title_minutes: u32,
+    pub friction_quota_minutes: u32,
+}
+
+impl Default for SuggestionsConfig {
+    fn default() -> Self {
+        let thresholds = SuggestionThresholds::default();
+        Self {
+            min_short_bursts: thresholds.short_bursts,
+            cumulative_minutes: (thresholds.cumulative_seconds / 60) as u32,
+            title_minutes: (thresholds.title_seconds / 60) as u32,
+            friction_quota_minutes: (thresholds.friction_quota_seconds / 60) as u32,
+        }
+    }
+}
+
+impl SuggestionsConfig {
+    pub fn thresholds(&self) -> SuggestionThresholds {
+        SuggestionThresholds {
+            short_bursts: self.min_short_bursts,
+            cumulative_seconds: self.cumulative_minutes as i64 * 60,
+            title_seconds: self.title_minutes as i64 * 60,
+            friction_quota_seconds: self.friction_quota_minutes as i64 * 60,
+            ..SuggestionThresholds::default()
+        }
+    }
+}
+
+pub const REDACTED_TITLE: &str = "[redacted]";
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct PrivacyConfig {
+    pub redact_title_patterns: HashSet<String>,
+    /// Window titles, and the project names detected in them, are stored
+    /// encrypted, with the key kept in secrets.toml
+    pub encrypt_window_titles: bool,
+}
+
+impl PrivacyConfig {
+    pub fn redact_title<'a>(&self, window_title: &'a str) -> &'a str {
+        if window_title.is_empty() {
+            return window_title;
+        }
+        let lowercase = window_title.to_lowercase();
+        let redacted = self
+            .redact_title_patterns
+            .iter()
+            .any(|pattern| lowercase.contains(&pattern.to_lowercase()));
+        if redacted {
+            REDACTED_TITLE
+        } else {
+            window_title
+        }
+    }
+}
+
+const DEFAULT_TITLE_APPS: &[&str] = &[
+    "firefox",
+    "librewolf",
+    "chrome",
+    "chromium",
+    "brave",
+    "vivaldi",
+    "opera",
+    "msedge",
+    "microsoft-edge",
+    "safari",
+];
+
+/// Applications whose window titles are read; the others are tracked by name only
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TrackingConfig {
+    pub title_apps: HashSet<String>,
+}
+
+impl Default for TrackingConfig {
+    fn default() -> Self {
+        Self {
+            title_apps: DEFAULT_TITLE_APPS
+                .iter()
+                .map(|app| app.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl TrackingConfig {
+    /// Editors and terminals always keep their titles so projects are still detected
+    pub fn tracks_title(&self, application_name: &str) -> bool {
+        let lowercase = application_name.to_lowercase();
+        self.title_apps
+            .iter()
+            .any(|app| lowercase.contains(&app.to_lowercase()))
+            || is_project_application(application_name)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct GoalsConfig {
+    pub weekly_minutes: Option<u64>,
+    pub rest_days: Vec<String>,
+}
+
+impl Default for GoalsConfig {
+    fn default() -> Self {
+        Self {
+            weekly_minutes: None,
+            rest_days: vec!["saturday".to_string(), "sunday".to_string()],
+        }
+    }
+}
+
+impl GoalsConfig {
+    pub fn rest_weekdays(&self) -> Vec<Weekday> {
+        self.rest_days
+            .iter()
+            .filter_map(|day| day.trim().parse().ok())
+            .collect()
+    }
+
+    pub fn invalid_rest_days(&self) -> Vec<&str> {
+        self.rest_days
+            .iter()
+            .filter(|day| day.trim().parse::<Weekday>().is_err())
+            .map(String::as_str)
+            .collect()
+    }
+
+    pub fn daily_target(
+        &self,
+        week_start: WeekStart,
+        sessions: &[Session],
+        today: NaiveDate,
+    ) -> Option<DailyTarget> {
+        let weekly_minutes = self.weekly_minutes.filter(|minutes| *minutes > 0)?;
+        Some(DailyTarget::from_weekly_goal(
+            weekly_minutes as i64 * 60,
+            week_start,
+            &self.rest_weekdays(),
+            sessions,
+            today,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    pub on_session_start: Option<String>,
+    pub on_session_end: Option<String>,
+    pub on_pause: Option<String>,
+    pub on_resume: Option<String>,
+    pub timeout_seconds: u64,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            on_session_start: None,
+            on_session_end: None,
+            on_pause: None,
+            on_resume: None,
+            timeout_seconds: 10,
+        }
+    }
+}
+
+impl HooksConfig {
+    pub fn is_configured(&self) -> bool {
+        [
+            &self.on_session_start,
+            &self.on_session_end,
+            &self.on_pause,
+            &self.on_resume,
+        ]
+        .iter()
+        .any(|command| {
+            command
+                .as_deref()
+                .is_some_and(|command| !command.trim().is_empty())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DigestConfig {
+    pub enabled: bool,
+    pub day: String,
+    pub hour: u8,
+    pub save_report: bool,
+    /// Week-over-week focus drop that triggers a regression notification, 0 to disable
+    pub regression_threshold_percent: u8,
+    pub delivery: DigestDeliveryConfig,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            day: "monday".to_string(),
+            hour: 9,
+            save_report: false,
+            regression_threshold_percent: 30,
+            delivery: DigestDeliveryConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct DigestDeliveryConfig {
+    pub smtp: Option<SmtpDeliveryConfig>,
+    pub webhook: Option<WebhookDeliveryConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SmtpDeliveryConfig {
+    pub host: String,
+    #[serde(default = "default_smtp_port")]
+    pub port: u16,
+    #[serde(default)]
+    pub username: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookDeliveryConfig {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DistractionConfig {
+    pub apps: HashSet<String>,
+    pub title_patterns: HashSet<String>,
+    pub alert_enabled: bool,
+    pub alert_after_seconds: u64,
+    pub alert_mode: DistractionAlertMode,
+    pub friction_apps: HashSet<String>,
+    pub friction_delay_seconds: u64,
+    pub friction_overrides: HashMap<String, u64>,
+    pub whitelist_apps: HashSet<String>,
+    pub overrides: HashMap<String, DistractionOverride>,
+}
+
+/// Per-app settings under `[distractions.overrides.<app>]`
+#[derive(Debug, Clone, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct DistractionOverride {
+    pub alert_after_seconds: Option<u64>,
+}
+
+impl Default for DistractionConfig {
+    fn default() -> Self {
+        Self {
+            apps: HashSet::from([
+                "discord".to_string(),
+                "slack".to_string(),
+                "telegram".to_string(),
+                "whatsapp".to_string(),
+                "twitter".to_string(),
+                "youtube".to_string(),
+                "reddit".to_string(),
+            ]),
+            title_patterns: HashSet::from([
+                "linkedin".to_string(),
+                "facebook".to_string(),
+                "instagram".to_string(),
+                "tiktok".to_string(),
+                "x.com".to_string(),
+                "netflix".to_string(),
+                "twitch".to_string(),
+            ]),
+            alert_enabled: false,
+            alert_after_seconds: 30,
+            alert_mode: DistractionAlertMode::Immediate,
+            friction_apps: HashSet::new(),
+            friction_delay_seconds: 10,
+            friction_overrides: HashMap::new(),
+            whitelist_apps: HashSet::new(),
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl DistractionConfig {
+    pub fn is_distraction(&self, application_name: &str) -> bool {
+        let lowercase = application_name.to_lowercase();
+        self.apps.iter().any(|app| lowercase.contains(app))
+    }
+
+    pub fn is_title_distraction(&self, window_title: &str) -> bool {
+        if window_title.is_empty() {
+            return false;
+        }
+        let lowercase = window_title.to_lowercase();
+        self.title_patterns
+            .iter()
+            .any(|pattern| lowercase.contains(pattern))
+    }
+
+    pub fn is_friction(&self, application_name: &str) -> bool {
+        let lowercase = application_name.to_lowercase();
+        self.friction_apps.iter().any(|app| lowercase.contains(app))
+            || self
+                .friction_overrides
+                .keys()
+                .any(|app| lowercase.contains(&app.to_lowercase()))
+    }
+
+    /// Shortest matching per-app override, or the global delay
+    pub fn friction_delay_for(&self, application_name: &str) -> u64 {
+        let lowercase = application_name.to_lowercase();
+        self.friction_overrides
+            .iter()
+            .filter(|(app, _)| lowercase.contains(&app.to_lowercase()))
+            .map(|(_, delay)| *delay)
+            .min()
+            .unwrap_or(self.friction_delay_seconds)
+    }
+
+    /// Strictest matching per-app alert threshold, or the global one
+    pub fn alert_after_for(&self, application_name: &str) -> u64 {
+        let lowercase = application_name.to_lowercase();
+        self.overrides
+            .iter()
+            .filter(|(app, _)| lowercase.contains(&app.to_lowercase()))
+            .filter_map(|(_, settings)| settings.alert_after_seconds)
+            .min()
+            .unwrap_or(self.alert_after_seconds)
+    }
+
+    pub fn add_app(&mut self, app: &str) -> bool {
+        let app_lower = app.to_lowercase();
+        self.whitelist_apps.remove(&app_lower);
+        self.apps.insert(app_lower)
+    }
+
+    pub fn remove_app(&mut self, app: &str) -> bool {
+        self.apps.remove(&app.to_lowercase())
+    }
+
+    pub fn add_title_pattern(&mut self, pattern: &str) -> bool {
+        self.title_patterns.insert(pattern.to_lowercase())
+    }
+
+    pub fn remove_title_pattern(&mut self, pattern: &str) -> bool {
+        self.title_patterns.remove(&pattern.to_lowercase())
+    }
+
+    /// Adds the suggested app, or the site for title suggestions
+    pub fn accept_suggestion(&mut self, suggestion: &DistractionSuggestion) -> bool {
+        if suggestion.is_title_pattern() {
+            self.add_title_pattern(&suggestion.application_name)
+        } else {
+            self.add_app(&suggestion.application_name)
+        }
+    }
+
+    pub fn add_friction_app(&mut self, app: &str) -> bool {
+        self.friction_apps.insert(app.to_lowercase())
+    }
+
+    pub fn remove_friction_app(&mut self, app: &str) -> bool {
+        self.friction_apps.remove(&app.to_lowercase())
+    }
+
+    pub fn is_whitelisted(&self, application_name: &str) -> bool {
+        let lowercase = application_name.to_lowercase();
+        self.whitelist_apps
+            .iter()
+            .any(|app| lowercase.contains(app))
+    }
+
+    pub fn add_to_whitelist(&mut self, app: &str) -> bool {
+        let app_lower = app.to_lowercase();
+        self.apps.remove(&app_lower);
+        self.whitelist_apps.insert(app_lower)
+    }
+
+    pub fn remove_from_whitelist(&mut self, app: &str) -> bool {
+        self.whitelist_apps.remove(&app.to_lowercase())
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let active_profile = Config::load()?.active_profile_name();
+        self.save_to_profile(&active_profile)
+    }
+
+    pub fn save_to_profile(&self, profile: &str) -> Result<(), ConfigError> {
+        self.prepare_save(profile)?.save()
+    }
+
+    /// Applies these distractions to the configuration file without writing it,
+    /// so the change can be reviewed first
+    pub fn prepare_save(&self, profile: &str) -> Result<ConfigWriter, ConfigError> {
+        let mut writer = ConfigWriter::open()?;
+        writer.set_distractions(self, profile)?;
+        Ok(writer)
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = Self::config_path();
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)?;
+        let mut config: Config = toml::from_str(&content)?;
+        config.migrate_legacy();
+        Ok(config)
+    }
+
+    fn migrate_legacy(&mut self) {
+        if self.profile.is_empty() {
+            let profile = Profile {
+                general: ProfileGeneralConfig::default(),
+                focus: self.focus.take().unwrap_or_default(),
+                notifications: self.notifications.take().unwrap_or_default(),
+                distractions: self.distractions.take().unwrap_or_default(),
+                digest: self.digest.take().unwrap_or_default(),
+            };
+            self.profile.insert("default".to_string(), profile);
+        }
+    }
+
+    pub fn active_profile(&self) -> &Profile {
+        let state = AppState::load();
+        self.profile
+            .get(&state.active_profile)
+            .or_else(|| self.profile.get("default"))
+            .unwrap_or_else(|| default_profile())
+    }
+
+    pub fn active_profile_name(&self) -> String {
+        let state = AppState::load();
+        if self.profile.contains_key(&state.active_profile) {
+            state.active_profile
+        } else {
+            "default".to_string()
+        }
+    }
+
+    pub fn profile_names(&self) -> Vec<&str> {
+        self.profile.keys().map(|s| s.as_str()).collect()
+    }
+
+    pub fn config_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("flux")
+            .join("config.toml")
+    }
+
+    pub fn language(&self) -> Language {
+        self.language_for(&self.active_profile_name())
+    }
+
+    pub fn language_for(&self, profile_name: &str) -> Language {
+        self.profile
+            .get(profile_name)
+            .and_then(|profile| profile.general.language)
+            .unwrap_or(self.general.language)
+    }
+
+    pub fn focus(&self) -> &FocusConfig {
+        &self.active_profile().focus
+    }
+
+    pub fn notifications(&self) -> &NotificationConfig {
+        &self.active_profile().notifications
+    }
+
+    pub fn distractions(&self) -> &DistractionConfig {
+        &self.active_profile().distractions
+    }
+
+    pub fn digest(&self) -> &DigestConfig {
+        &self.active_profile().digest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_with_migration(toml_content: &str) -> Config {
+        let mut config: Config = toml::from_str(toml_content).unwrap();
+        config.migrate_legacy();
+        config
+    }
+
+    #[test]
+    fn default_profile_has_sensible_values() {
+        let profile = Profile::default();
+
+        assert_eq!(profile.focus.default_duration_minutes, 25);
+        assert_eq!(profile.focus.check_in_interval_minutes, 25);
+        assert_eq!(profile.focus.check_in_timeout_seconds, 120);
+        assert_eq!(profile.focus.veille_reminder_minutes, 60);
+        assert_eq!(profile.focus.preparation_seconds, 0);
+        assert!(profile.notifications.sound_enabled);
+        assert!(!profile.notifications.enable_dnd);
+        assert!(!profile.distractions.alert_enabled);
+        assert_eq!(profile.distractions.alert_after_seconds, 30);
+        assert_eq!(
+            profile.distractions.alert_mode,
+            DistractionAlertMode::Immediate
+        );
+        assert!(profile.distractions.apps.contains("discord"));
+    }
+
+    #[test]
+    fn default_config_has_sensible_values() {
+        let mut config = Config::default();
+        config.migrate_legacy();
+
+        assert_eq!(config.general.language, Language::En);
+        assert!(!config.tray.enabled);
+        assert!(config.gitlab.is_none());
+        assert!(config.github.is_none());
+        assert!(config.calendar.is_none());
+        assert!(config.profile.contains_key("default"));
+    }
+
+    #[test]
+    fn calendar_warns_five_minutes_before_without_pausing_by_default() {
+        let config = parse_with_migration(
+            r#"
+            [calendar]
+            url = "webcal://calendar.example.com/me.ics"
+        "#,
+        );
+
+        let calendar = config.calendar.unwrap();
+        assert_eq!(calendar.url, "webcal://calendar.example.com/me.ics");
+        assert_eq!(calendar.warning_minutes, 5);
+        assert!(!calendar.auto_pause);
+    }
+
+    #[test]
+    fn sync_runs_every_fifteen_minutes_under_the_configured_device_name() {
+        let config: Config = toml::from_str(
+            r#"
+            [sync]
+            folder = "/home/me/Sync/flux"
+            device = " desktop "
+            "#,
+        )
+        .unwrap();
+
+        let sync = config.sync.unwrap();
+        assert_eq!(sync.folder, PathBuf::from("/home/me/Sync/flux"));
+        assert_eq!(sync.interval_minutes, 15);
+        assert_eq!(sync.device_name().as_deref(), Some("desktop"));
+    }
+
+    #[test]
+    fn parse_legacy_minimal_config() {
+        let config = parse_with_migration(
+            r#"
+            [focus]
+            default_duration_minutes = 50
+        "#,
+        );
+
+        assert_eq!(config.focus().default_duration_minutes, 50);
+        assert_eq!(config.focus().check_in_interval_minutes, 25);
+    }
+
+    #[test]
+    fn parse_legacy_full_config() {
+        let config = parse_with_migration(
+            r#"
+            [focus]
+            default_duration_minutes = 45
+            check_in_interval_minutes = 15
+            check_in_timeout_seconds = 60
+
+            [notifications]
+            sound_enabled = false
+            urgency = "critical"
+            enable_dnd = true
+
+            [gitlab]
+            base_url = "https://gitlab.example.com"
+
+            [github]
+            base_url = "https://github.com"
+        "#,
+        );
+
+        assert_eq!(config.focus().default_duration_minutes, 45);
+        assert_eq!(config.focus().check_in_interval_minutes, 15);
+        assert!(!config.notifications().sound_enabled);
+        assert!(config.notifications().enable_dnd);
+        assert!(matches!(
+            config.notifications().urgency,
+            NotificationUrgency::Critical
+        ));
+        assert_eq!(
+            config.gitlab.as_ref().unwrap().base_url,
+            "https://gitlab.example.com"
+        );
+        assert_eq!(
+            config.github.as_ref().unwrap().base_url,
+            "https://github.com"
+        );
+    }
+
+    #[test]
+    fn experimental_features_are_disabled_by_default() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.experimental.input_activity);
+
+        let config: Config = toml::from_str(
+            r#"
+            [experimental]
+            input_activity = true
+        "#,
+        )
+        .unwrap();
+        assert!(config.experimental.input_activity);
+    }
+
+    #[test]
+    fn metrics_weights_default_to_the_built_in_score() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.metrics.score_weights(), ScoreWeights::default());
+
+        let config: Config = toml::from_str(
+            r#"
+            [metrics]
+            pause_weight = 0
+            distraction_ratio_weight = 60
+        "#,
+        )
+        .unwrap();
+        let weights = config.metrics.score_weights();
+        assert_eq!(weights.pause, 0);
+        assert_eq!(weights.distraction_ratio, 60);
+        assert_eq!(weights.context_switch, 2);
+    }
+
+    #[test]
+    fn suggestion_thresholds_are_read_in_minutes() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(
+            config.suggestions.thresholds(),
+            SuggestionThresholds::default()
+        );
+
+        let config: Config = toml::from_str(
+            r#"
+            [suggestions]
+            min_short_bursts = 5
+            friction_quota_minutes = 20
+        "#,
+        )
+        .unwrap();
+        let thresholds = config.suggestions.thresholds();
+        assert_eq!(thresholds.short_bursts, 5);
+        assert_eq!(thresholds.friction_quota_seconds, 1200);
+        assert_eq!(thresholds.cumulative_seconds, 300);
+    }
+
+    #[test]
+    fn parse_tray_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [tray]
+            enabled = true
+            show_countdown = true
+        "#,
+        )
+        .unwrap();
+
+        assert!(config.tray.enabled);
+        assert!(config.tray.show_countdown);
+    }
+
+    #[test]
+    fn battery_profile_is_off_until_enabled() {
+        assert!(!Config::default().battery.enabled);
+
+        let config: Config = toml::from_str(
+            r#"
+            [battery]
+            enabled = true
+            polling_interval_seconds = 30
+        "#,
+        )
+        .unwrap();
+
+        assert!(config.battery.enabled);
+        assert_eq!(config.battery.polling_interval_seconds, 30);
+        assert!(!config.battery.sound_enabled);
+        assert!(!config.battery.tray_countdown);
+    }
+
+    #[test]
+    fn parse_language_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [general]
+            language = "fr"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.general.language, Language::Fr);
+    }
+
+    #[test]
+    fn parse_week_starts_on() {
+        let config: Config = toml::from_str(
+            r#"
+            [general]
+            week_starts_on = "sunday"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.general.week_starts_on, WeekStart::Sunday);
+        assert_eq!(Config::default().general.week_starts_on, WeekStart::Monday);
+    }
+
+    #[test]
+    fn missing_language_defaults_to_english() {
+        let config: Config = toml::from_str(
+            r#"
+            [focus]
+            default_duration_minutes = 25
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.general.language, Language::En);
+    }
+
+    #[test]
+    fn parse_legacy_distractions_config() {
+        let config = parse_with_migration(
+            r#"
+            [distractions]
+            apps = ["discord", "slack", "twitter"]
+            alert_enabled = true
+            alert_after_seconds = 60
+            alert_mode = "summary"
+        "#,
+        );
+
+        assert!(config.distractions().alert_enabled);
+        assert_eq!(config.distractions().alert_after_seconds, 60);
+        assert_eq!(
+            config.distractions().alert_mode,
+            DistractionAlertMode::Summary
+        );
+        assert_eq!(config.distractions().apps.len(), 3);
+        assert!(config.distractions().apps.contains("discord"));
+        assert!(config.distractions().apps.contains("slack"));
+    }
+
+    #[test]
+    fn parse_focus_mode_overrides() {
+        let config = parse_with_migration(
+            r#"
+            [focus]
+            default_duration_minutes = 30
+
+            [focus.modes.review]
+            default_duration_minutes = 45
+            check_ins_enabled = false
+
+            [focus.modes.prompting]
+            default_duration_minutes = 25
+        "#,
+        );
+
+        let review = config.focus().settings_for(&FocusMode::Review);
+        assert_eq!(review.duration_minutes, 45);
+        assert!(!review.check_ins_enabled);
+
+        let prompting = config.focus().settings_for(&FocusMode::AiAssisted);
+        assert_eq!(prompting.duration_minutes, 25);
+        assert!(prompting.check_ins_enabled);
+
+        let architecture = config.focus().settings_for(&FocusMode::Architecture);
+        assert_eq!(architecture.duration_minutes, 30);
+        assert!(architecture.check_ins_enabled);
+    }
+
+    #[test]
+    fn mode_settings_default_to_no_check_ins_for_veille() {
+        let focus = FocusConfig::default();
+
+        let settings = focus.settings_for(&FocusMode::Veille);
+
+        assert_eq!(settings.duration_minutes, 25);
+        assert!(!settings.check_ins_enabled);
+    }
+
+    #[test]
+    fn custom_mode_override_uses_its_name() {
+        let mut focus = FocusConfig::default();
+        focus.modes.insert(
+            "deep-work".to_string(),
+            ModeOverride {
+                default_duration_minutes: Some(90),
+                check_ins_enabled: None,
+            },
+        );
+
+        let settings = focus.settings_for(&FocusMode::Custom("deep-work".to_string()));
+
+        assert_eq!(settings.duration_minutes, 90);
+        assert!(settings.check_ins_enabled);
+    }
+
+    #[test]
+    fn is_distraction_matches_case_insensitive() {
+        let config = DistractionConfig::default();
+
+        assert!(config.is_distraction("Discord"));
+        assert!(config.is_distraction("DISCORD"));
+        assert!(config.is_distraction("discord"));
+    }
+
+    #[test]
+    fn is_distraction_matches_partial_name() {
+        let config = DistractionConfig::default();
+
+        assert!(config.is_distraction("Discord-canary"));
+        assert!(config.is_distraction("org.telegram.desktop"));
+        assert!(config.is_distraction("youtube-music"));
+    }
+
+    #[test]
+    fn is_distraction_returns_false_for_non_distraction() {
+        let config = DistractionConfig::default();
+
+        assert!(!config.is_distraction("cursor"));
+        assert!(!config.is_distraction("firefox"));
+        assert!(!config.is_distraction("code"));
+    }
+
+    #[test]
+    fn is_title_distraction_matches_configured_patterns() {
+        let config = DistractionConfig {
+            title_patterns: HashSet::from(["youtube".to_string(), "linkedin".to_string()]),
+            ..Default::default()
+        };
+
+        assert!(config.is_title_distraction("YouTube - Video Name"));
+        assert!(config.is_title_distraction("linkedin.com/feed"));
+        assert!(!config.is_title_distraction("localhost:3000"));
+        assert!(!config.is_title_distraction("GitHub - Pull Request"));
+        assert!(!config.is_title_distraction(""));
+    }
+
+    #[test]
+    fn friction_override_replaces_global_delay() {
+        let config: DistractionConfig = toml::from_str(
+            r#"
+            friction_apps = ["slack"]
+            friction_delay_seconds = 30
+
+            [friction_overrides]
+            YouTube = 5
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.friction_delay_for("youtube-music"), 5);
+        assert_eq!(config.friction_delay_for("Slack"), 30);
+        assert!(config.is_friction("YouTube"));
+        assert!(!config.is_friction("firefox"));
+    }
+
+    #[test]
+    fn whitelisting_an_app_removes_it_from_distractions() {
+        let mut config = DistractionConfig::default();
+        config.add_app("Slack");
+
+        assert!(config.add_to_whitelist("SLACK"));
+        assert!(!config.add_to_whitelist("slack"));
+        assert!(!config.is_distraction("slack"));
+        assert!(config.is_whitelisted("slack-desktop"));
+        assert!(config.remove_from_whitelist("Slack"));
+        assert!(!config.is_whitelisted("slack"));
+    }
+
+    #[test]
+    fn friction_apps_are_stored_lowercase() {
+        let mut config = DistractionConfig::default();
+
+        assert!(config.add_friction_app("Reddit"));
+        assert!(!config.add_friction_app("reddit"));
+        assert!(config.is_friction("reddit-desktop"));
+        assert!(config.remove_friction_app("REDDIT"));
+        assert!(!config.is_friction("reddit"));
+    }
+
+    #[test]
+    fn alert_threshold_can_be_overridden_per_app() {
+        let config: DistractionConfig = toml::from_str(
+            r#"
+            alert_after_seconds = 60
+
+            [overrides.youtube]
+            alert_after_seconds = 10
+
+            [overrides.slack]
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.alert_after_for("YouTube Music"), 10);
+        assert_eq!(config.alert_after_for("slack"), 60);
+        assert_eq!(config.alert_after_for("discord"), 60);
+    }
+
+    #[test]
+    fn default_digest_config_is_monday_9am() {
+        let config = DigestConfig::default();
+
+        assert!(config.enabled);
+        assert_eq!(config.day, "monday");
+        assert_eq!(config.hour, 9);
+        assert!(!config.save_report);
+        assert_eq!(config.regression_threshold_percent, 30);
+    }
+
+    #[test]
+    fn parse_legacy_digest_config() {
+        let config = parse_with_migration(
+            r#"
+            [digest]
+            enabled = false
+            day = "sunday"
+            hour = 18
+            save_report = true
+        "#,
+        );
+
+        assert!(!config.digest().enabled);
+        assert_eq!(config.digest().day, "sunday");
+        assert_eq!(config.digest().hour, 18);
+        assert!(config.digest().save_report);
+    }
+
+    #[test]
+    fn storage_is_optional_by_default() {
+        let config = parse_with_migration("");
+        assert!(!config.storage.required);
+
+        let config = parse_with_migration(
+            r#"
+            [storage]
+            required = true
+        "#,
+        );
+        assert!(config.storage.required);
+    }
+
+    #[test]
+    fn redact_title_matches_patterns_case_insensitively() {
+        let config = parse_with_migration(
+            r#"
+            [privacy]
+            redact_title_patterns = ["password", "Banking"]
+        "#,
+        );
+
+        assert_eq!(
+            config.privacy.redact_title("KeePassXC - Password Database"),
+            REDACTED_TITLE
+        );
+        assert_eq!(
+            config.privacy.redact_title("My banking - Firefox"),
+            REDACTED_TITLE
+        );
+        assert_eq!(
+            config.privacy.redact_title("main.rs - flux"),
+            "main.rs - flux"
+        );
+        assert_eq!(config.privacy.redact_title(""), "");
+        assert_eq!(
+            PrivacyConfig::default().redact_title("password"),
+            "password"
+        );
+    }
+
+    #[test]
+    fn titles_are_read_for_browsers_and_project_applications_only() {
+        let default = TrackingConfig::default();
+        let custom = parse_with_migration(
+            r#"
+            [tracking]
+            title_apps = ["Obsidian"]
+        "#,
+        );
+
+        assert!(default.tracks_title("Firefox"));
+        assert!(default.tracks_title("google-chrome"));
+        assert!(default.tracks_title("kitty"));
+        assert!(!default.tracks_title("slack"));
+        assert!(custom.tracking.tracks_title("obsidian"));
+        assert!(!custom.tracking.tracks_title("firefox"));
+        assert!(custom.tracking.tracks_title("code"));
+    }
+
+    #[test]
+    fn parse_hooks_config() {
+        let config = parse_with_migration("");
+        assert!(!config.hooks.is_configured());
+        assert_eq!(config.hooks.timeout_seconds, 10);
+
+        let config = parse_with_migration(
+            r#"
+            [hooks]
+            on_session_start = "notify-send start"
+            on_pause = "   "
+            timeout_seconds = 3
+        "#,
+        );
+        assert!(config.hooks.is_configured());
+        assert_eq!(
+            config.hooks.on_session_start.as_deref(),
+            Some("notify-send start")
+        );
+        assert_eq!(config.hooks.on_session_end, None);
+        assert_eq!(config.hooks.timeout_seconds, 3);
+    }
+
+    #[test]
+    fn parse_goals_config() {
+        let config = parse_with_migration("");
+        assert_eq!(config.goals.weekly_minutes, None);
+        assert_eq!(
+            config.goals.rest_weekdays(),
+            vec![Weekday::Sat, Weekday::Sun]
+        );
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        assert!(config
+            .goals
+            .daily_target(WeekStart::Monday, &[], monday)
+            .is_none());
+
+        let config = parse_with_migration(
+            r#"
+            [goals]
+            weekly_minutes = 600
+            rest_days = ["Sunday", "funday"]
+        "#,
+        );
+        assert_eq!(config.goals.rest_weekdays(), vec![Weekday::Sun]);
+        assert_eq!(config.goals.invalid_rest_days(), vec!["funday"]);
+        assert_eq!(
+            config
+                .goals
+                .daily_target(WeekStart::Monday, &[], monday)
+                .map(|target| target.target_seconds),
+            Some(100 * 60)
+        );
+    }
+
+    #[test]
+    fn parse_digest_delivery_config() {
+        let config = parse_with_migration(
+            r#"
+            [digest.delivery.smtp]
+            host = "smtp.example.com"
+            username = "damien"
+            from = "flux@example.com"
+            to = ["damien@example.com"]
+
+            [digest.delivery.webhook]
+            url = "https://hooks.slack.com/services/T000/B000/XXX"
+        "#,
+        );
+
+        let delivery = &config.digest().delivery;
+        let smtp = delivery.smtp.as_ref().unwrap();
+        assert_eq!(smtp.host, "smtp.example.com");
+        assert_eq!(smtp.port, 587);
+        assert_eq!(smtp.username.as_deref(), Some("damien"));
+        assert_eq!(smtp.to, vec!["damien@example.com".to_string()]);
+        assert_eq!(
+            delivery.webhook.as_ref().unwrap().url,
+            "https://hooks.slack.com/services/T000/B000/XXX"
+        );
+    }
+
+    #[test]
+    fn parse_profile_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [general]
+            language = "fr"
+
+            [profile.default.focus]
+            default_duration_minutes = 25
+
+            [profile.deep_focus.focus]
+            default_duration_minutes = 50
+
+            [profile.deep_focus.distractions]
+            apps = ["discord", "slack", "twitter"]
+            alert_enabled = true
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.profile.len(), 2);
+        assert!(config.profile.contains_key("default"));
+        assert!(config.profile.contains_key("deep_focus"));
+
+        let deep_focus = config.profile.get("deep_focus").unwrap();
+        assert_eq!(deep_focus.focus.default_duration_minutes, 50);
+        assert!(deep_focus.distractions.alert_enabled);
+    }
+
+    #[test]
+    fn profile_names_returns_all_profiles() {
+        let config: Config = toml::from_str(
+            r#"
+            [profile.default.focus]
+            default_duration_minutes = 25
+
+            [profile.work.focus]
+            default_duration_minutes = 45
+
+            [profile.creative.focus]
+            default_duration_minutes = 60
+        "#,
+        )
+        .unwrap();
+
+        let names = config.profile_names();
+        assert_eq!(names.len(), 3);
+        assert!(names.contains(&"default"));
+        assert!(names.contains(&"work"));
+        assert!(names.contains(&"creative"));
+    }
+
+    #[test]
+    fn profile_language_overrides_global_language() {
+        let config: Config = toml::from_str(
+            r#"
+            [general]
+            language = "fr"
+
+            [profile.work.general]
+            language = "en"
+
+            [profile.personal.focus]
+            default_duration_minutes = 25
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.language_for("work"), Language::En);
+        assert_eq!(config.language_for("personal"), Language::Fr);
+        assert_eq!(config.language_for("missing"), Language::Fr);
+    }
+}
@@ -0,0 +1,130 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::domain::FocusMode;
+
+const CUSTOM_MODE_KEY: &str = "custom";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModeColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl ModeColor {
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let hex = value.trim().strip_prefix('#')?;
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+
+        let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&hex[range], 16).ok();
+        Some(Self::new(channel(0..2)?, channel(2..4)?, channel(4..6)?))
+    }
+
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+
+    fn default_for(mode: &FocusMode) -> Self {
+        match mode {
+            FocusMode::AiAssisted => Self::new(59, 130, 246),
+            FocusMode::Review => Self::new(168, 85, 247),
+            FocusMode::Architecture => Self::new(6, 182, 212),
+            FocusMode::Veille | FocusMode::Custom(_) => Self::new(16, 185, 129),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ModesConfig {
+    pub colors: HashMap<String, String>,
+}
+
+impl ModesConfig {
+    pub fn color_for(&self, mode: &FocusMode) -> ModeColor {
+        let configured = |key: &str| {
+            self.colors
+                .get(key)
+                .and_then(|value| ModeColor::parse(value))
+        };
+
+        let alias = match mode {
+            FocusMode::AiAssisted => Some("prompting"),
+            FocusMode::Custom(_) => Some(CUSTOM_MODE_KEY),
+            _ => None,
+        };
+
+        configured(mode.as_str())
+            .or_else(|| alias.and_then(configured))
+            .unwrap_or_else(|| ModeColor::default_for(mode))
+    }
+
+    pub fn invalid_colors(&self) -> Vec<(&str, &str)> {
+        let mut invalid: Vec<_> = self
+            .colors
+            .iter()
+            .filter(|(_, value)| ModeColor::parse(value).is_none())
+            .map(|(mode, value)| (mode.as_str(), value.as_str()))
+            .collect();
+        invalid.sort();
+        invalid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_hex_colors_only() {
+        assert_eq!(
+            ModeColor::parse("#ff8800"),
+            Some(ModeColor::new(255, 136, 0))
+        );
+        assert_eq!(
+            ModeColor::parse(" #FF8800 "),
+            Some(ModeColor::new(255, 136, 0))
+        );
+        assert_eq!(ModeColor::parse("ff8800"), None);
+        assert_eq!(ModeColor::parse("#ff88"), None);
+        assert_eq!(ModeColor::parse("#gg8800"), None);
+        assert_eq!(ModeColor::new(6, 182, 212).to_hex(), "#06b6d4");
+    }
+
+    #[test]
+    fn configured_colors_override_defaults() {
+        let config = ModesConfig {
+            colors: HashMap::from([
+                ("review".to_string(), "#ff0000".to_string()),
+                ("custom".to_string(), "#00ff00".to_string()),
+                ("deep-work".to_string(), "#0000ff".to_string()),
+                ("architecture".to_string(), "cyan".to_string()),
+            ]),
+        };
+
+        assert_eq!(
+            config.color_for(&FocusMode::Review),
+            ModeColor::new(255, 0, 0)
+        );
+        assert_eq!(
+            config.color_for(&FocusMode::Custom("deep-work".to_string())),
+            ModeColor::new(0, 0, 255)
+        );
+        assert_eq!(
+            config.color_for(&FocusMode::Custom("reading".to_string())),
+            ModeColor::new(0, 255, 0)
+        );
+        assert_eq!(
+            config.color_for(&FocusMode::Architecture),
+            ModeColor::new(6, 182, 212)
+        );
+        assert_eq!(config.invalid_colors(), vec![("architecture", "cyan")]);
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::ConfigError;
+
+pub const WORKSPACE_CONFIG_FILE: &str = ".flux.toml";
+
+/// Project-local settings read from a `.flux.toml`, applied by `flux start`
+/// on top of the user configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WorkspaceConfig {
+    pub mode: Option<String>,
+    pub duration_minutes: Option<u64>,
+    pub tags: Vec<String>,
+    pub distractions: WorkspaceDistractions,
+}
+
+/// Only additions: a project can flag more apps, never unflag the user's ones
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WorkspaceDistractions {
+    pub apps: Vec<String>,
+}
+
+/// What a session is started with once every source has been merged
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StartOptions {
+    pub mode: Option<String>,
+    pub duration_minutes: Option<u64>,
+    pub tags: Vec<String>,
+    pub distraction_apps: Vec<String>,
+}
+
+impl WorkspaceConfig {
+    /// Looks for a `.flux.toml` in `directory` then in each parent, and returns
+    /// the nearest one with its path
+    pub fn discover(directory: &Path) -> Result<Option<(PathBuf, Self)>, ConfigError> {
+        for ancestor in directory.ancestors() {
+            let path = ancestor.join(WORKSPACE_CONFIG_FILE);
+            if path.is_file() {
+                let content = std::fs::read_to_string(&path)?;
+                let config =
+                    toml::from_str(&content).map_err(|error| ConfigError::InvalidDocument {
+                        message: format!("{}: {}", path.display(), error),
+                    })?;
+                return Ok(Some((path, config)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Command-line flags win over the workspace, which wins over the user
+    /// configuration (left to the daemon when nothing is set). Tags and
+    /// distraction apps add up instead of replacing each other.
+    pub fn apply(&self, options: StartOptions) -> StartOptions {
+        let mut tags = options.tags;
+        for tag in &self.tags {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+
+        let mut distraction_apps = options.distraction_apps;
+        for app in &self.distractions.apps {
+            let app = app.to_lowercase();
+            if !distraction_apps.contains(&app) {
+                distraction_apps.push(app);
+            }
+        }
+
+        StartOptions {
+            mode: options.mode.or_else(|| self.mode.clone()),
+            duration_minutes: options.duration_minutes.or(self.duration_minutes),
+            tags,
+            distraction_apps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> WorkspaceConfig {
+        toml::from_str(
+            r#"
+mode = "review"
+duration_minutes = 50
+tags = ["clientx"]
+
+[distractions]
+apps = ["Slack"]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn workspace_fills_what_the_command_line_left_out() {
+        let options = workspace().apply(StartOptions::default());
+
+        assert_eq!(options.mode.as_deref(), Some("review"));
+        assert_eq!(options.duration_minutes, Some(50));
+        assert_eq!(options.tags, vec!["clientx"]);
+        assert_eq!(options.distraction_apps, vec!["slack"]);
+    }
+
+    #[test]
+    fn command_line_flags_win_and_tags_add_up() {
+        let options = workspace().apply(StartOptions {
+            mode: Some("architecture".to_string()),
+            duration_minutes: Some(25),
+            tags: vec!["spike".to_string(), "clientx".to_string()],
+            distraction_apps: Vec::new(),
+        });
+
+        assert_eq!(options.mode.as_deref(), Some("architecture"));
+        assert_eq!(options.duration_minutes, Some(25));
+        assert_eq!(options.tags, vec!["spike", "clientx"]);
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert!(toml::from_str::<WorkspaceConfig>("duration = 50").is_err());
+    }
+
+    #[test]
+    fn nearest_workspace_file_is_found_from_a_subdirectory() {
+        let root = std::env::temp_dir().join(format!("flux-workspace-{}", std::process::id()));
+        let nested = root.join("project").join("src");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.join(WORKSPACE_CONFIG_FILE), "mode = \"veille\"").unwrap();
+        std::fs::write(
+            root.join("project").join(WORKSPACE_CONFIG_FILE),
+            "mode = \"review\"",
+        )
+        .unwrap();
+
+        let (path, config) = WorkspaceConfig::discover(&nested).unwrap().unwrap();
+
+        assert_eq!(path, root.join("project").join(WORKSPACE_CONFIG_FILE));
+        assert_eq!(config.mode.as_deref(), Some("review"));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
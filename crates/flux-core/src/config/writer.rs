@@ -0,0 +1,382 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
+
+use super::diff::{diff_lines, DiffLine};
+use super::{Config, ConfigError, ConfigKey, ConfigScope, DistractionConfig};
+use crate::i18n::Language;
+
+pub struct ConfigWriter {
+    path: PathBuf,
+    original: String,
+    document: DocumentMut,
+}
+
+impl ConfigWriter {
+    pub fn open() -> Result<Self, ConfigError> {
+        let path = Config::config_path();
+        let content = if path.exists() {
+            std::fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+
+        Ok(Self {
+            path,
+            document: parse_document(&content)?,
+            original: content,
+        })
+    }
+
+    pub fn from_content(content: &str) -> Result<Self, ConfigError> {
+        Ok(Self {
+            path: Config::config_path(),
+            original: content.to_string(),
+            document: parse_document(content)?,
+        })
+    }
+
+    pub fn set_language(&mut self, language: Language) -> Result<(), ConfigError> {
+        self.section_mut(None, "general")?
+            .insert("language", Item::Value(Value::from(language.code())));
+        Ok(())
+    }
+
+    pub fn set_value(
+        &mut self,
+        key: &ConfigKey,
+        value: Value,
+        profile: &str,
+    ) -> Result<(), ConfigError> {
+        let profile = match key.scope {
+            ConfigScope::Global => None,
+            ConfigScope::Profile => Some(profile),
+        };
+        self.section_mut(profile, key.section())?
+            .insert(key.field(), Item::Value(value));
+        Ok(())
+    }
+
+    pub fn set_distractions(
+        &mut self,
+        distractions: &DistractionConfig,
+        profile: &str,
+    ) -> Result<(), ConfigError> {
+        let section = self.section_mut(Some(profile), "distractions")?;
+        section.insert("apps", string_list(&distractions.apps));
+        section.insert("title_patterns", string_list(&distractions.title_patterns));
+        section.insert("whitelist_apps", string_list(&distractions.whitelist_apps));
+        section.insert("friction_apps", string_list(&distractions.friction_apps));
+        section.insert(
+            "friction_delay_seconds",
+            Item::Value(Value::from(distractions.friction_delay_seconds as i64)),
+        );
+        Ok(())
+    }
+
+    /// Lines changed since the file was read, to preview before `save`
+    pub fn diff(&self) -> Vec<DiffLine> {
+        diff_lines(&self.original, &self.to_string())
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.original != self.to_string()
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let content = self.to_string();
+        toml::from_str::<Config>(&content)?;
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    fn section_mut(
+        &mut self,
+        profile: Option<&str>,
+        section: &str,
+    ) -> Result<&mut Table, ConfigError> {
+        let mut path = Vec::new();
+        if let Some(profile) = profile {
+            if self.document.contains_key("profile") {
+                path.push("profile");
+                path.push(profile);
+            }
+        }
+        path.push(section);
+
+        let last_index = path.len() - 1;
+        let mut table = self.document.as_table_mut();
+        for (index, segment) in path.into_iter().enumerate() {
+            let item = table.entry(segment).or_insert_with(|| {
+                let mut new_table = Table::new();
+                new_table.set_implicit(index < last_index);
+                Item::Table(new_table)
+            });
+            table = item
+                .as_table_mut()
+                .ok_or_else(|| ConfigError::InvalidValue {
+                    key: section.to_string(),
+                    value: segment.to_string(),
+                    expected: "une table TOML".to_string(),
+                })?;
+        }
+
+        Ok(table)
+    }
+}
+
+impl std::fmt::Display for ConfigWriter {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}", self.document)
+    }
+}
+
+fn parse_document(content: &str) -> Result<DocumentMut, ConfigError> {
+    content
+        .parse::<DocumentMut>()
+        .map_err(|error| ConfigError::InvalidDocument {
+            message: error.to_string(),
+        })
+}
+
+fn string_list(values: &HashSet<String>) -> Item {
+    let mut sorted: Vec<_> = values.iter().collect();
+    sorted.sort();
+
+    let mut array = Array::new();
+    for value in sorted {
+        array.push(value.as_str());
+    }
+    Item::Value(Value::Array(array))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distractions_with_apps(apps: &[&str]) -> DistractionConfig {
+        DistractionConfig {
+            apps: apps.iter().map(|app| app.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn set_language_replaces_existing_value() {
+        let content = "[general]\nlanguage = \"en\"\n\n[focus]\ndefault_duration_minutes = 25\n";
+        let mut writer = ConfigWriter::from_content(content).unwrap();
+
+        writer.set_language(Language::Fr).unwrap();
+        let result = writer.to_string();
+
+        assert!(result.contains("language = \"fr\""));
+        assert!(!result.contains("language = \"en\""));
+        assert!(result.contains("default_duration_minutes = 25"));
+    }
+
+    #[test]
+    fn set_language_adds_to_existing_general_section() {
+        let content = "[general]\n\n[focus]\ndefault_duration_minutes = 25\n";
+        let mut writer = ConfigWriter::from_content(content).unwrap();
+
+        writer.set_language(Language::Fr).unwrap();
+        let config: Config = toml::from_str(&writer.to_string()).unwrap();
+
+        assert_eq!(config.general.language, Language::Fr);
+    }
+
+    #[test]
+    fn set_language_creates_general_section() {
+        let mut writer =
+            ConfigWriter::from_content("[focus]\ndefault_duration_minutes = 25\n").unwrap();
+
+        writer.set_language(Language::Fr).unwrap();
+        let result = writer.to_string();
+
+        assert!(result.contains("[general]"));
+        assert!(result.contains("language = \"fr\""));
+    }
+
+    #[test]
+    fn writer_preserves_comments_and_unrelated_keys() {
+        let content = r#"# Flux configuration
+[general]
+language = "en" # interface language
+
+[distractions]
+# keep alerts quiet
+alert_enabled = false
+apps = ["discord", "slack"]
+"#;
+        let mut writer = ConfigWriter::from_content(content).unwrap();
+
+        writer
+            .set_distractions(&distractions_with_apps(&["discord", "twitter"]), "default")
+            .unwrap();
+        let result = writer.to_string();
+
+        assert!(result.starts_with("# Flux configuration"));
+        assert!(result.contains("language = \"en\" # interface language"));
+        assert!(result.contains("# keep alerts quiet"));
+        assert!(result.contains("alert_enabled = false"));
+        assert!(result.contains("apps = [\"discord\", \"twitter\"]"));
+        assert!(!result.contains("\"slack\""));
+    }
+
+    #[test]
+    fn set_distractions_creates_section_if_missing() {
+        let mut writer =
+            ConfigWriter::from_content("[focus]\ndefault_duration_minutes = 25\n").unwrap();
+
+        writer
+            .set_distractions(&distractions_with_apps(&["discord"]), "default")
+            .unwrap();
+        let result = writer.to_string();
+
+        assert!(result.contains("[distractions]"));
+        assert!(result.contains("apps = [\"discord\"]"));
+    }
+
+    #[test]
+    fn set_distractions_sorts_apps_alphabetically() {
+        let mut writer = ConfigWriter::from_content("").unwrap();
+
+        writer
+            .set_distractions(
+                &distractions_with_apps(&["youtube", "discord", "slack"]),
+                "default",
+            )
+            .unwrap();
+
+        assert!(writer
+            .to_string()
+            .contains("apps = [\"discord\", \"slack\", \"youtube\"]"));
+    }
+
+    #[test]
+    fn set_distractions_keeps_friction_settings() {
+        let mut writer = ConfigWriter::from_content("").unwrap();
+        let distractions = DistractionConfig {
+            friction_apps: HashSet::from(["reddit".to_string()]),
+            friction_delay_seconds: 45,
+            ..Default::default()
+        };
+
+        writer.set_distractions(&distractions, "default").unwrap();
+        let result = writer.to_string();
+
+        assert!(result.contains("friction_apps = [\"reddit\"]"));
+        assert!(result.contains("friction_delay_seconds = 45"));
+    }
+
+    #[test]
+    fn set_distractions_targets_profile_table_when_profiles_exist() {
+        let content = r#"
+[profile.default.distractions]
+apps = ["discord"]
+
+[profile.deep.distractions]
+apps = ["slack"]
+"#;
+        let mut writer = ConfigWriter::from_content(content).unwrap();
+
+        writer
+            .set_distractions(&distractions_with_apps(&["reddit"]), "deep")
+            .unwrap();
+        let config: Config = toml::from_str(&writer.to_string()).unwrap();
+
+        assert!(config.profile["deep"].distractions.apps.contains("reddit"));
+        assert!(!config.profile["deep"].distractions.apps.contains("slack"));
+        assert!(config.profile["default"]
+            .distractions
+            .apps
+            .contains("discord"));
+        assert!(config.distractions.is_none());
+    }
+
+    #[test]
+    fn set_value_writes_legacy_section_without_profiles() {
+        let key = ConfigKey::find("focus.default_duration_minutes").unwrap();
+        let mut writer = ConfigWriter::from_content("[general]\nlanguage = \"fr\"\n").unwrap();
+
+        writer.set_value(key, Value::from(50), "default").unwrap();
+        let config: Config = toml::from_str(&writer.to_string()).unwrap();
+
+        assert_eq!(config.general.language, Language::Fr);
+        assert_eq!(config.focus.unwrap().default_duration_minutes, 50);
+    }
+
+    #[test]
+    fn set_value_writes_active_profile_when_profiles_exist() {
+        let content = r#"
+[profile.default.focus]
+default_duration_minutes = 25
+
+[profile.deep.focus]
+default_duration_minutes = 90
+"#;
+        let key = ConfigKey::find("digest.hour").unwrap();
+        let mut writer = ConfigWriter::from_content(content).unwrap();
+
+        writer.set_value(key, Value::from(18), "deep").unwrap();
+        let result = writer.to_string();
+        let config: Config = toml::from_str(&result).unwrap();
+
+        assert!(result.contains("[profile.deep.digest]"));
+        assert!(!result.contains("[profile]\n"));
+        assert_eq!(config.profile["deep"].digest.hour, 18);
+        assert_eq!(config.profile["deep"].focus.default_duration_minutes, 90);
+        assert_eq!(config.profile["default"].digest.hour, 9);
+    }
+
+    #[test]
+    fn set_value_writes_global_keys_at_top_level() {
+        let key = ConfigKey::find("tray.enabled").unwrap();
+        let mut writer =
+            ConfigWriter::from_content("[profile.default.focus]\ndefault_duration_minutes = 25\n")
+                .unwrap();
+
+        writer.set_value(key, Value::from(true), "default").unwrap();
+        let config: Config = toml::from_str(&writer.to_string()).unwrap();
+
+        assert!(config.tray.enabled);
+    }
+
+    #[test]
+    fn diff_lists_only_the_edited_lines_as_changes() {
+        let content = "# my settings\n[distractions]\napps = [\"discord\"]\n";
+        let mut writer = ConfigWriter::from_content(content).unwrap();
+        assert!(!writer.has_changes());
+
+        writer
+            .set_distractions(&distractions_with_apps(&["discord", "slack"]), "default")
+            .unwrap();
+        let changes: Vec<DiffLine> = writer
+            .diff()
+            .into_iter()
+            .filter(DiffLine::is_change)
+            .collect();
+
+        assert!(writer.has_changes());
+        assert!(changes.contains(&DiffLine::Removed("apps = [\"discord\"]".to_string())));
+        assert!(changes.contains(&DiffLine::Added(
+            "apps = [\"discord\", \"slack\"]".to_string()
+        )));
+        assert!(!changes
+            .iter()
+            .any(|line| line.text().starts_with("# my settings")));
+    }
+
+    #[test]
+    fn from_content_rejects_invalid_toml() {
+        let result = ConfigWriter::from_content("[general\nlanguage = ");
+
+        assert!(matches!(result, Err(ConfigError::InvalidDocument { .. })));
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::SessionId;
+
+pub const DEEP_FOCUS_MINIMUM_SECONDS: i64 = 90 * 60;
+pub const PRODUCTIVE_WEEK_SESSIONS: usize = 10;
+pub const DISTRACTION_FREE_MINIMUM_SECONDS: i64 = 25 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Achievement {
+    DeepFocus,
+    ProductiveWeek,
+    DistractionFree,
+}
+
+impl Achievement {
+    pub fn all() -> &'static [Achievement] {
+        &[
+            Achievement::DeepFocus,
+            Achievement::ProductiveWeek,
+            Achievement::DistractionFree,
+        ]
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Achievement::DeepFocus => "deep_focus",
+            Achievement::ProductiveWeek => "productive_week",
+            Achievement::DistractionFree => "distraction_free",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|achievement| achievement.id() == id)
+    }
+
+    pub fn for_completed_session(duration_seconds: i64, sessions_this_week: usize) -> Vec<Self> {
+        let mut earned = Vec::new();
+        if duration_seconds >= DEEP_FOCUS_MINIMUM_SECONDS {
+            earned.push(Achievement::DeepFocus);
+        }
+        if sessions_this_week >= PRODUCTIVE_WEEK_SESSIONS {
+            earned.push(Achievement::ProductiveWeek);
+        }
+        earned
+    }
+
+    pub fn for_tracked_session(tracked_seconds: i64, distraction_seconds: i64) -> Vec<Self> {
+        if tracked_seconds >= DISTRACTION_FREE_MINIMUM_SECONDS && distraction_seconds == 0 {
+            vec![Achievement::DistractionFree]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnlockedAchievement {
+    pub achievement: Achievement,
+    pub session_id: Option<SessionId>,
+    pub unlocked_at: DateTime<Utc>,
+}
+
+impl UnlockedAchievement {
+    pub fn now(achievement: Achievement, session_id: Option<SessionId>) -> Self {
+        Self {
+            achievement,
+            session_id,
+            unlocked_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_roundtrip() {
+        for achievement in Achievement::all() {
+            assert_eq!(Achievement::from_id(achievement.id()), Some(*achievement));
+        }
+        assert_eq!(Achievement::from_id("unknown"), None);
+    }
+
+    #[test]
+    fn completed_session_rules() {
+        assert!(Achievement::for_completed_session(25 * 60, 3).is_empty());
+        assert_eq!(
+            Achievement::for_completed_session(90 * 60, 10),
+            vec![Achievement::DeepFocus, Achievement::ProductiveWeek]
+        );
+    }
+
+    #[test]
+    fn distraction_free_requires_a_full_session_without_distractions() {
+        assert_eq!(
+            Achievement::for_tracked_session(30 * 60, 0),
+            vec![Achievement::DistractionFree]
+        );
+        assert!(Achievement::for_tracked_session(30 * 60, 5).is_empty());
+        assert!(Achievement::for_tracked_session(5 * 60, 0).is_empty());
+    }
+}
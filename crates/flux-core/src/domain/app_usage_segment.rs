@@ -0,0 +1,224 @@
+use chrono::{DateTime, Duration, Utc};
+
+use super::SessionId;
+
+const JOIN_TOLERANCE_SECONDS: i64 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentKind {
+    Focus,
+    Distraction,
+    Pause,
+    CheckIn,
+}
+
+impl SegmentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SegmentKind::Focus => "focus",
+            SegmentKind::Distraction => "distraction",
+            SegmentKind::Pause => "pause",
+            SegmentKind::CheckIn => "check_in",
+        }
+    }
+
+    pub fn from_stored(value: &str) -> Option<Self> {
+        match value {
+            "focus" => Some(SegmentKind::Focus),
+            "distraction" => Some(SegmentKind::Distraction),
+            "pause" => Some(SegmentKind::Pause),
+            "check_in" => Some(SegmentKind::CheckIn),
+            _ => None,
+        }
+    }
+}
+
+/// How the user answered a check-in notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckInOutcome {
+    Focused,
+    NotFocused,
+    Timeout,
+}
+
+impl CheckInOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckInOutcome::Focused => "focused",
+            CheckInOutcome::NotFocused => "not_focused",
+            CheckInOutcome::Timeout => "timeout",
+        }
+    }
+
+    pub fn from_stored(value: &str) -> Option<Self> {
+        match value {
+            "focused" => Some(CheckInOutcome::Focused),
+            "not_focused" => Some(CheckInOutcome::NotFocused),
+            "timeout" => Some(CheckInOutcome::Timeout),
+            _ => None,
+        }
+    }
+}
+
+/// A timestamped stretch of a session: an application in focus, a pause or a check-in
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppUsageSegment {
+    pub session_id: SessionId,
+    pub kind: SegmentKind,
+    pub application_name: String,
+    pub window_title: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    /// Answer to a check-in, unknown until the user responds
+    pub check_in_outcome: Option<CheckInOutcome>,
+}
+
+impl AppUsageSegment {
+    pub fn new(
+        session_id: SessionId,
+        kind: SegmentKind,
+        application_name: String,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            session_id,
+            kind,
+            application_name,
+            window_title: String::new(),
+            started_at,
+            ended_at: ended_at.max(started_at),
+            check_in_outcome: None,
+        }
+    }
+
+    pub fn with_window_title(mut self, window_title: String) -> Self {
+        self.window_title = window_title;
+        self
+    }
+
+    pub fn with_check_in_outcome(mut self, outcome: Option<CheckInOutcome>) -> Self {
+        self.check_in_outcome = outcome;
+        self
+    }
+
+    pub fn pause(
+        session_id: SessionId,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+    ) -> Self {
+        Self::new(
+            session_id,
+            SegmentKind::Pause,
+            String::new(),
+            started_at,
+            ended_at,
+        )
+    }
+
+    pub fn check_in(session_id: SessionId, at: DateTime<Utc>) -> Self {
+        Self::new(session_id, SegmentKind::CheckIn, String::new(), at, at)
+    }
+
+    pub fn duration_seconds(&self) -> i64 {
+        self.ended_at
+            .signed_duration_since(self.started_at)
+            .num_seconds()
+    }
+
+    /// Extends this segment with `next` when it is the same activity without a gap
+    pub fn absorb(&mut self, next: &AppUsageSegment) -> bool {
+        let continues = self.session_id == next.session_id
+            && self.kind == next.kind
+            && self.kind != SegmentKind::CheckIn
+            && self.application_name == next.application_name
+            && self.window_title == next.window_title
+            && next.started_at >= self.started_at
+            && next.started_at <= self.ended_at + Duration::seconds(JOIN_TOLERANCE_SECONDS);
+
+        if continues {
+            self.ended_at = self.ended_at.max(next.ended_at);
+        }
+        continues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 14, 9, 0, 0).unwrap() + Duration::seconds(seconds)
+    }
+
+    fn focus(application_name: &str, start: i64, end: i64) -> AppUsageSegment {
+        AppUsageSegment::new(
+            1,
+            SegmentKind::Focus,
+            application_name.to_string(),
+            at(start),
+            at(end),
+        )
+    }
+
+    #[test]
+    fn consecutive_polls_of_the_same_app_merge() {
+        let mut segment = focus("cursor", 0, 5);
+
+        assert!(segment.absorb(&focus("cursor", 5, 10)));
+        assert_eq!(segment.ended_at, at(10));
+        assert_eq!(segment.duration_seconds(), 10);
+    }
+
+    #[test]
+    fn another_app_or_a_gap_starts_a_new_segment() {
+        let mut segment = focus("cursor", 0, 5);
+
+        assert!(!segment.absorb(&focus("discord", 5, 10)));
+        assert!(!segment.absorb(&focus("cursor", 20, 25)));
+        assert_eq!(segment.ended_at, at(5));
+    }
+
+    #[test]
+    fn another_window_title_starts_a_new_segment() {
+        let mut segment = focus("firefox", 0, 5).with_window_title("GitHub".to_string());
+
+        assert!(!segment.absorb(&focus("firefox", 5, 10).with_window_title("YouTube".to_string())));
+        assert!(segment.absorb(&focus("firefox", 5, 10).with_window_title("GitHub".to_string())));
+        assert_eq!(segment.ended_at, at(10));
+    }
+
+    #[test]
+    fn check_ins_never_merge() {
+        let mut check_in = AppUsageSegment::check_in(1, at(0));
+
+        assert!(!check_in.absorb(&AppUsageSegment::check_in(1, at(0))));
+        assert_eq!(check_in.duration_seconds(), 0);
+    }
+
+    #[test]
+    fn kind_round_trips_through_storage() {
+        for kind in [
+            SegmentKind::Focus,
+            SegmentKind::Distraction,
+            SegmentKind::Pause,
+            SegmentKind::CheckIn,
+        ] {
+            assert_eq!(SegmentKind::from_stored(kind.as_str()), Some(kind));
+        }
+        assert_eq!(SegmentKind::from_stored("unknown"), None);
+    }
+
+    #[test]
+    fn check_in_outcome_round_trips_through_storage() {
+        for outcome in [
+            CheckInOutcome::Focused,
+            CheckInOutcome::NotFocused,
+            CheckInOutcome::Timeout,
+        ] {
+            assert_eq!(CheckInOutcome::from_stored(outcome.as_str()), Some(outcome));
+        }
+        assert_eq!(CheckInOutcome::from_stored(""), None);
+    }
+}
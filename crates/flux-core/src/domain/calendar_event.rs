@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+
+/// A meeting read from the user's calendar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    pub title: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl CalendarEvent {
+    pub fn new(title: String, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> Self {
+        Self {
+            title,
+            starts_at,
+            ends_at: ends_at.max(starts_at),
+        }
+    }
+
+    pub fn is_ongoing(&self, now: DateTime<Utc>) -> bool {
+        self.starts_at <= now && now < self.ends_at
+    }
+
+    /// Whole minutes left before the meeting, or None once it has started
+    pub fn minutes_until_start(&self, now: DateTime<Utc>) -> Option<i64> {
+        (self.starts_at > now).then(|| {
+            let seconds = self.starts_at.signed_duration_since(now).num_seconds();
+            (seconds + 59) / 60
+        })
+    }
+
+    /// Seconds between `from` and `to` spent in meetings, overlapping meetings counted once
+    pub fn meeting_seconds(
+        events: &[CalendarEvent],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> i64 {
+        let mut spans: Vec<(DateTime<Utc>, DateTime<Utc>)> = events
+            .iter()
+            .map(|event| (event.starts_at.max(from), event.ends_at.min(to)))
+            .filter(|(start, end)| start < end)
+            .collect();
+        spans.sort();
+
+        let mut total = 0;
+        let mut covered_until: Option<DateTime<Utc>> = None;
+        for (start, end) in spans {
+            let start = covered_until.map_or(start, |covered| start.max(covered));
+            if start < end {
+                total += end.signed_duration_since(start).num_seconds();
+            }
+            covered_until = Some(covered_until.map_or(end, |covered| covered.max(end)));
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 9, 0, 0).unwrap() + Duration::minutes(minutes)
+    }
+
+    fn meeting(start: i64, end: i64) -> CalendarEvent {
+        CalendarEvent::new("Standup".to_string(), at(start), at(end))
+    }
+
+    #[test]
+    fn minutes_until_start_rounds_up_and_stops_once_started() {
+        let standup = meeting(10, 25);
+
+        assert_eq!(standup.minutes_until_start(at(5)), Some(5));
+        assert_eq!(
+            standup.minutes_until_start(at(5) + Duration::seconds(30)),
+            Some(5)
+        );
+        assert_eq!(standup.minutes_until_start(at(10)), None);
+        assert!(standup.is_ongoing(at(10)));
+        assert!(!standup.is_ongoing(at(25)));
+    }
+
+    #[test]
+    fn meeting_time_is_clamped_to_the_window_and_counted_once() {
+        let events = [meeting(-10, 10), meeting(5, 20), meeting(50, 70)];
+
+        assert_eq!(
+            CalendarEvent::meeting_seconds(&events, at(0), at(60)),
+            (20 + 10) * 60
+        );
+        assert_eq!(CalendarEvent::meeting_seconds(&events, at(20), at(50)), 0);
+    }
+}
@@ -0,0 +1,11 @@
+use chrono::NaiveDate;
+
+/// Time spent in one application on one local day, summed across sessions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailyAppUsage {
+    pub date: NaiveDate,
+    pub application_name: String,
+    pub seconds: i64,
+    /// Whether the tracker flagged this time as a distraction
+    pub is_distraction: bool,
+}
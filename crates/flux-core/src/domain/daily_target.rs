@@ -0,0 +1,160 @@
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+
+use super::{Session, WeekStart};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DailyTarget {
+    pub target_seconds: i64,
+    pub focused_seconds: i64,
+    pub rest_day: bool,
+}
+
+impl DailyTarget {
+    /// Spreads what is left of the weekly goal over the remaining working days,
+    /// so days without focus raise the target of the following ones
+    pub fn from_weekly_goal(
+        weekly_seconds: i64,
+        week_start: WeekStart,
+        rest_days: &[Weekday],
+        sessions: &[Session],
+        today: NaiveDate,
+    ) -> Self {
+        let week_start = week_start.week_of(today);
+
+        let mut focused_before_today = 0;
+        let mut focused_seconds = 0;
+        for session in sessions.iter().filter(|session| !session.is_active()) {
+            let day = session.started_at.with_timezone(&Local).date_naive();
+            let seconds = session.duration_seconds.unwrap_or(0);
+            if day == today {
+                focused_seconds += seconds;
+            } else if day >= week_start && day < today {
+                focused_before_today += seconds;
+            }
+        }
+
+        let rest_day = rest_days.contains(&today.weekday());
+        let remaining_working_days = today
+            .iter_days()
+            .take_while(|day| *day < week_start + Duration::days(7))
+            .filter(|day| !rest_days.contains(&day.weekday()))
+            .count() as i64;
+
+        let remaining_seconds = (weekly_seconds - focused_before_today).max(0);
+        let target_seconds = if rest_day || remaining_working_days == 0 {
+            0
+        } else {
+            (remaining_seconds + remaining_working_days - 1) / remaining_working_days
+        };
+
+        Self {
+            target_seconds,
+            focused_seconds,
+            rest_day,
+        }
+    }
+
+    pub fn remaining_seconds(&self) -> i64 {
+        (self.target_seconds - self.focused_seconds).max(0)
+    }
+
+    pub fn progress_percent(&self) -> u32 {
+        if self.target_seconds == 0 {
+            return 100;
+        }
+        (self.focused_seconds * 100 / self.target_seconds).clamp(0, 100) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::FocusMode;
+    use chrono::TimeZone;
+
+    const WEEKEND: [Weekday; 2] = [Weekday::Sat, Weekday::Sun];
+
+    fn session_on(day: NaiveDate, seconds: i64) -> Session {
+        let mut session = Session::start(FocusMode::AiAssisted);
+        session.started_at = Local
+            .from_local_datetime(&day.and_hms_opt(10, 0, 0).unwrap())
+            .unwrap()
+            .into();
+        session.ended_at = Some(session.started_at + Duration::seconds(seconds));
+        session.duration_seconds = Some(seconds);
+        session
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    #[test]
+    fn weekly_goal_is_spread_over_working_days() {
+        let target =
+            DailyTarget::from_weekly_goal(10 * 3600, WeekStart::Monday, &WEEKEND, &[], date(12));
+
+        assert_eq!(target.target_seconds, 2 * 3600);
+        assert!(!target.rest_day);
+    }
+
+    #[test]
+    fn missed_days_raise_the_remaining_targets() {
+        let sessions = vec![session_on(date(12), 2 * 3600), session_on(date(15), 1800)];
+
+        let target = DailyTarget::from_weekly_goal(
+            10 * 3600,
+            WeekStart::Monday,
+            &WEEKEND,
+            &sessions,
+            date(15),
+        );
+
+        assert_eq!(target.target_seconds, 4 * 3600);
+        assert_eq!(target.focused_seconds, 1800);
+        assert_eq!(target.remaining_seconds(), 4 * 3600 - 1800);
+        assert_eq!(target.progress_percent(), 12);
+    }
+
+    #[test]
+    fn sunday_week_start_counts_the_previous_sunday() {
+        let sessions = vec![session_on(date(11), 2 * 3600)];
+
+        let monday_week = DailyTarget::from_weekly_goal(
+            10 * 3600,
+            WeekStart::Monday,
+            &WEEKEND,
+            &sessions,
+            date(12),
+        );
+        let sunday_week = DailyTarget::from_weekly_goal(
+            10 * 3600,
+            WeekStart::Sunday,
+            &WEEKEND,
+            &sessions,
+            date(12),
+        );
+
+        assert_eq!(monday_week.target_seconds, 2 * 3600);
+        assert_eq!(sunday_week.target_seconds, 8 * 3600 / 5);
+    }
+
+    #[test]
+    fn rest_days_and_reached_goals_have_no_target() {
+        let rest =
+            DailyTarget::from_weekly_goal(10 * 3600, WeekStart::Monday, &WEEKEND, &[], date(17));
+        assert!(rest.rest_day);
+        assert_eq!(rest.target_seconds, 0);
+
+        let sessions = vec![session_on(date(12), 11 * 3600)];
+        let reached = DailyTarget::from_weekly_goal(
+            10 * 3600,
+            WeekStart::Monday,
+            &WEEKEND,
+            &sessions,
+            date(14),
+        );
+        assert_eq!(reached.target_seconds, 0);
+        assert_eq!(reached.progress_percent(), 100);
+    }
+}
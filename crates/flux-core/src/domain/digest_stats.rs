@@ -1,6 +1,12 @@
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Default)]
+use serde::Serialize;
+
+use super::{completion_rate, AppUsage, Session, Streak};
+
+const DOMINANT_MODE_PERCENTAGE: u32 = 50;
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct WeekStats {
     pub total_seconds: i64,
     pub session_count: usize,
@@ -9,12 +15,102 @@ pub struct WeekStats {
     pub distraction_applications: HashMap<String, i64>,
     pub total_distraction_seconds: i64,
     pub average_focus_score: u8,
+    pub completion_rate: Option<u32>,
+}
+
+impl WeekStats {
+    pub fn compute(
+        sessions: &[Session],
+        app_usages: &[AppUsage],
+        is_distraction: impl Fn(&str) -> bool,
+    ) -> Self {
+        let mut total_seconds = 0i64;
+        let mut by_mode: HashMap<String, i64> = HashMap::new();
+
+        for session in sessions {
+            let duration = session.duration_seconds.unwrap_or(0);
+            total_seconds += duration;
+            *by_mode.entry(session.mode.to_string()).or_insert(0) += duration;
+        }
+
+        let mut focus_applications: HashMap<String, i64> = HashMap::new();
+        let mut distraction_applications: HashMap<String, i64> = HashMap::new();
+        let mut total_distraction_seconds = 0i64;
+
+        for usage in app_usages {
+            let applications = if is_distraction(&usage.application_name) {
+                total_distraction_seconds += usage.duration_seconds;
+                &mut distraction_applications
+            } else {
+                &mut focus_applications
+            };
+            *applications
+                .entry(usage.application_name.clone())
+                .or_insert(0) += usage.duration_seconds;
+        }
+
+        Self {
+            total_seconds,
+            session_count: sessions.len(),
+            by_mode,
+            focus_applications,
+            distraction_applications,
+            total_distraction_seconds,
+            average_focus_score: 0,
+            completion_rate: completion_rate(sessions),
+        }
+    }
+
+    pub fn top_focus_applications(&self, limit: usize) -> Vec<(String, i64)> {
+        top_applications(&self.focus_applications, limit)
+    }
+
+    pub fn top_distraction_applications(&self, limit: usize) -> Vec<(String, i64)> {
+        top_applications(&self.distraction_applications, limit)
+    }
+}
+
+fn top_applications(applications: &HashMap<String, i64>, limit: usize) -> Vec<(String, i64)> {
+    let mut sorted: Vec<_> = applications
+        .iter()
+        .map(|(name, seconds)| (name.clone(), *seconds))
+        .collect();
+    sorted.sort_by(|first, second| second.1.cmp(&first.1).then_with(|| first.0.cmp(&second.0)));
+    sorted.truncate(limit);
+    sorted
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DigestInsight {
+    FocusTimeIncreased { seconds: i64 },
+    FocusTimeDecreased { seconds: i64 },
+    DistractionsIncreased { seconds: i64 },
+    DistractionsDecreased { seconds: i64 },
+    DominantMode { mode: String, percentage: u32 },
+    TopDistraction { application: String, seconds: i64 },
+}
+
+/// Week-over-week slump worth a notification of its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestRegression {
+    pub focus_drop_percent: Option<u32>,
+    pub distractions_doubled: bool,
+    pub top_application: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestReport {
+    pub subject: String,
+    pub summary: String,
+    pub markdown: String,
 }
 
 #[derive(Debug, Clone)]
 pub struct DigestStats {
     pub current_week: WeekStats,
     pub previous_week: Option<WeekStats>,
+    pub streak: Streak,
 }
 
 impl DigestStats {
@@ -22,9 +118,15 @@ impl DigestStats {
         Self {
             current_week,
             previous_week,
+            streak: Streak::default(),
         }
     }
 
+    pub fn with_streak(mut self, streak: Streak) -> Self {
+        self.streak = streak;
+        self
+    }
+
     pub fn time_delta(&self) -> Option<i64> {
         self.previous_week
             .as_ref()
@@ -48,6 +150,112 @@ impl DigestStats {
             .as_ref()
             .map(|prev| self.current_week.session_count as i64 - prev.session_count as i64)
     }
+
+    /// None when focus time dropped by at most `threshold_percent` and distractions did not
+    /// double; a zero threshold disables the check
+    pub fn regression(&self, threshold_percent: u32) -> Option<DigestRegression> {
+        let previous = self.previous_week.as_ref()?;
+        if threshold_percent == 0 {
+            return None;
+        }
+        let current = &self.current_week;
+
+        let focus_drop_percent = (previous.total_seconds > 0
+            && current.total_seconds < previous.total_seconds)
+            .then(|| {
+                ((previous.total_seconds - current.total_seconds) * 100 / previous.total_seconds)
+                    as u32
+            })
+            .filter(|drop| *drop > threshold_percent);
+        let distractions_doubled = previous.total_distraction_seconds > 0
+            && current.total_distraction_seconds >= previous.total_distraction_seconds * 2;
+
+        if focus_drop_percent.is_none() && !distractions_doubled {
+            return None;
+        }
+
+        Some(DigestRegression {
+            focus_drop_percent,
+            distractions_doubled,
+            top_application: self.top_regressing_application(),
+        })
+    }
+
+    /// The distraction that grew the most since last week, or this week's top one
+    fn top_regressing_application(&self) -> Option<String> {
+        let previous = self.previous_week.as_ref();
+        self.current_week
+            .distraction_applications
+            .iter()
+            .map(|(application, seconds)| {
+                let before = previous
+                    .and_then(|week| week.distraction_applications.get(application))
+                    .copied()
+                    .unwrap_or(0);
+                (application, *seconds - before)
+            })
+            .filter(|(_, growth)| *growth > 0)
+            .max_by(|first, second| first.1.cmp(&second.1).then_with(|| second.0.cmp(first.0)))
+            .map(|(application, _)| application.clone())
+            .or_else(|| {
+                self.current_week
+                    .top_distraction_applications(1)
+                    .pop()
+                    .map(|(application, _)| application)
+            })
+    }
+
+    pub fn insights(&self) -> Vec<DigestInsight> {
+        let mut insights = Vec::new();
+
+        match self.time_delta() {
+            Some(delta) if delta > 0 => {
+                insights.push(DigestInsight::FocusTimeIncreased { seconds: delta })
+            }
+            Some(delta) if delta < 0 => insights.push(DigestInsight::FocusTimeDecreased {
+                seconds: delta.abs(),
+            }),
+            _ => {}
+        }
+
+        match self.distraction_delta() {
+            Some(delta) if delta > 0 => {
+                insights.push(DigestInsight::DistractionsIncreased { seconds: delta })
+            }
+            Some(delta) if delta < 0 => insights.push(DigestInsight::DistractionsDecreased {
+                seconds: delta.abs(),
+            }),
+            _ => {}
+        }
+
+        let total_seconds = self.current_week.total_seconds;
+        if total_seconds > 0 {
+            let dominant_mode =
+                self.current_week.by_mode.iter().max_by(|first, second| {
+                    first.1.cmp(second.1).then_with(|| second.0.cmp(first.0))
+                });
+            if let Some((mode, seconds)) = dominant_mode {
+                let percentage = (*seconds * 100 / total_seconds) as u32;
+                if percentage >= DOMINANT_MODE_PERCENTAGE {
+                    insights.push(DigestInsight::DominantMode {
+                        mode: mode.clone(),
+                        percentage,
+                    });
+                }
+            }
+        }
+
+        if let Some((application, seconds)) =
+            self.current_week.top_distraction_applications(1).pop()
+        {
+            insights.push(DigestInsight::TopDistraction {
+                application,
+                seconds,
+            });
+        }
+
+        insights
+    }
 }
 
 #[cfg(test)]
@@ -113,4 +321,151 @@ mod tests {
 
         assert_eq!(digest.distraction_delta(), Some(-300));
     }
+
+    #[test]
+    fn top_applications_sorted_by_duration_then_name() {
+        let week = WeekStats {
+            focus_applications: HashMap::from([
+                ("code".to_string(), 600),
+                ("alacritty".to_string(), 600),
+                ("firefox".to_string(), 1200),
+            ]),
+            ..Default::default()
+        };
+
+        let top = week.top_focus_applications(2);
+
+        assert_eq!(
+            top,
+            vec![
+                ("firefox".to_string(), 1200),
+                ("alacritty".to_string(), 600)
+            ]
+        );
+    }
+
+    #[test]
+    fn insights_describe_week_over_week_changes() {
+        let current = WeekStats {
+            total_seconds: 3600,
+            by_mode: HashMap::from([
+                ("review".to_string(), 2700),
+                ("ai-assisted".to_string(), 900),
+            ]),
+            distraction_applications: HashMap::from([("discord".to_string(), 120)]),
+            total_distraction_seconds: 120,
+            ..Default::default()
+        };
+        let previous = WeekStats {
+            total_seconds: 1800,
+            total_distraction_seconds: 600,
+            ..Default::default()
+        };
+
+        let digest = DigestStats::new(current, Some(previous));
+
+        assert_eq!(
+            digest.insights(),
+            vec![
+                DigestInsight::FocusTimeIncreased { seconds: 1800 },
+                DigestInsight::DistractionsDecreased { seconds: 480 },
+                DigestInsight::DominantMode {
+                    mode: "review".to_string(),
+                    percentage: 75,
+                },
+                DigestInsight::TopDistraction {
+                    application: "discord".to_string(),
+                    seconds: 120,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn insights_skip_dominant_mode_when_balanced() {
+        let current = WeekStats {
+            total_seconds: 3000,
+            by_mode: HashMap::from([
+                ("review".to_string(), 1000),
+                ("ai-assisted".to_string(), 1000),
+                ("architecture".to_string(), 1000),
+            ]),
+            ..Default::default()
+        };
+
+        let digest = DigestStats::new(current, None);
+
+        assert!(digest.insights().is_empty());
+    }
+
+    #[test]
+    fn regression_flags_a_focus_drop_beyond_the_threshold() {
+        let current = WeekStats {
+            total_seconds: 6000,
+            distraction_applications: HashMap::from([
+                ("discord".to_string(), 900),
+                ("youtube".to_string(), 600),
+            ]),
+            total_distraction_seconds: 1500,
+            ..Default::default()
+        };
+        let previous = WeekStats {
+            total_seconds: 10000,
+            distraction_applications: HashMap::from([
+                ("discord".to_string(), 800),
+                ("youtube".to_string(), 100),
+            ]),
+            total_distraction_seconds: 900,
+            ..Default::default()
+        };
+
+        let digest = DigestStats::new(current, Some(previous));
+
+        assert_eq!(
+            digest.regression(30),
+            Some(DigestRegression {
+                focus_drop_percent: Some(40),
+                distractions_doubled: false,
+                top_application: Some("youtube".to_string()),
+            })
+        );
+        assert_eq!(digest.regression(40), None);
+        assert_eq!(digest.regression(0), None);
+    }
+
+    #[test]
+    fn regression_flags_doubled_distractions() {
+        let current = WeekStats {
+            total_seconds: 10000,
+            distraction_applications: HashMap::from([("slack".to_string(), 1200)]),
+            total_distraction_seconds: 1200,
+            ..Default::default()
+        };
+        let previous = WeekStats {
+            total_seconds: 9000,
+            total_distraction_seconds: 600,
+            ..Default::default()
+        };
+
+        let digest = DigestStats::new(current, Some(previous));
+
+        assert_eq!(
+            digest.regression(30),
+            Some(DigestRegression {
+                focus_drop_percent: None,
+                distractions_doubled: true,
+                top_application: Some("slack".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn regression_needs_a_previous_week() {
+        let current = WeekStats {
+            total_distraction_seconds: 1200,
+            ..Default::default()
+        };
+
+        assert_eq!(DigestStats::new(current, None).regression(30), None);
+    }
 }
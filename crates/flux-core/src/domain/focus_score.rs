@@ -0,0 +1,187 @@
+const MAX_SCORE: u32 = 100;
+
+/// Behavior that lowers the focus score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoreFactor {
+    ContextSwitches,
+    ShortBursts,
+    DistractionRatio,
+    Pauses,
+}
+
+impl ScoreFactor {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoreFactor::ContextSwitches => "context_switches",
+            ScoreFactor::ShortBursts => "short_bursts",
+            ScoreFactor::DistractionRatio => "distraction_ratio",
+            ScoreFactor::Pauses => "pauses",
+        }
+    }
+
+    /// Whether the count is a percentage rather than a number of occurrences
+    pub fn is_ratio(&self) -> bool {
+        *self == ScoreFactor::DistractionRatio
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreContribution {
+    pub factor: ScoreFactor,
+    /// Occurrences, or a percentage for ratio factors
+    pub count: u32,
+    /// Points removed from the score, always zero or negative
+    pub points: i32,
+}
+
+/// Focus score with the points each factor removed; contributions add up to `score - 100`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreBreakdown {
+    pub score: u8,
+    pub contributions: Vec<ScoreContribution>,
+}
+
+/// Points removed per occurrence, or for a ratio factor at 100%
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreWeights {
+    pub context_switch: u32,
+    pub short_burst: u32,
+    pub distraction_ratio: u32,
+    pub pause: u32,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            context_switch: 2,
+            short_burst: 5,
+            distraction_ratio: 40,
+            pause: 3,
+        }
+    }
+}
+
+impl ScoreWeights {
+    pub fn weight(&self, factor: ScoreFactor) -> u32 {
+        match factor {
+            ScoreFactor::ContextSwitches => self.context_switch,
+            ScoreFactor::ShortBursts => self.short_burst,
+            ScoreFactor::DistractionRatio => self.distraction_ratio,
+            ScoreFactor::Pauses => self.pause,
+        }
+    }
+}
+
+impl ScoreBreakdown {
+    /// Removes each factor's points in turn, never going below zero
+    pub fn compute(counts: &[(ScoreFactor, u32)], weights: &ScoreWeights) -> Self {
+        let mut remaining = MAX_SCORE;
+        let contributions = counts
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|&(factor, count)| {
+                let weighted = count.saturating_mul(weights.weight(factor));
+                let raw = if factor.is_ratio() {
+                    (weighted + 50) / 100
+                } else {
+                    weighted
+                };
+                let points = raw.min(remaining);
+                remaining -= points;
+                ScoreContribution {
+                    factor,
+                    count,
+                    points: -(points as i32),
+                }
+            })
+            .collect();
+
+        Self {
+            score: remaining as u8,
+            contributions,
+        }
+    }
+
+    /// Points each factor removed per session on average; occurrences are summed, ratios averaged
+    pub fn average_contributions(breakdowns: &[ScoreBreakdown]) -> Vec<ScoreContribution> {
+        let mut contributions: Vec<ScoreContribution> = Vec::new();
+        for contribution in breakdowns
+            .iter()
+            .flat_map(|breakdown| &breakdown.contributions)
+        {
+            match contributions
+                .iter_mut()
+                .find(|existing| existing.factor == contribution.factor)
+            {
+                Some(existing) => {
+                    existing.count += contribution.count;
+                    existing.points += contribution.points;
+                }
+                None => contributions.push(contribution.clone()),
+            }
+        }
+
+        let session_count = breakdowns.len().max(1) as f64;
+        for contribution in &mut contributions {
+            contribution.points = (contribution.points as f64 / session_count).round() as i32;
+            if contribution.factor.is_ratio() {
+                contribution.count = (contribution.count as f64 / session_count).round() as u32;
+            }
+        }
+        contributions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_factors_scale_with_their_percentage() {
+        let breakdown = ScoreBreakdown::compute(
+            &[
+                (ScoreFactor::DistractionRatio, 25),
+                (ScoreFactor::Pauses, 2),
+            ],
+            &ScoreWeights::default(),
+        );
+
+        assert_eq!(breakdown.score, 84);
+        assert_eq!(breakdown.contributions[0].points, -10);
+        assert_eq!(breakdown.contributions[1].points, -6);
+    }
+
+    #[test]
+    fn zero_weight_ignores_a_factor() {
+        let weights = ScoreWeights {
+            pause: 0,
+            ..ScoreWeights::default()
+        };
+
+        let breakdown = ScoreBreakdown::compute(&[(ScoreFactor::Pauses, 10)], &weights);
+
+        assert_eq!(breakdown.score, 100);
+        assert_eq!(breakdown.contributions[0].points, 0);
+    }
+
+    #[test]
+    fn averages_sum_occurrences_and_average_ratios() {
+        let weights = ScoreWeights::default();
+        let calm = ScoreBreakdown::compute(&[(ScoreFactor::DistractionRatio, 10)], &weights);
+        let scattered = ScoreBreakdown::compute(
+            &[
+                (ScoreFactor::ContextSwitches, 10),
+                (ScoreFactor::DistractionRatio, 50),
+            ],
+            &weights,
+        );
+
+        let average = ScoreBreakdown::average_contributions(&[calm, scattered]);
+
+        assert_eq!(average[0].factor, ScoreFactor::DistractionRatio);
+        assert_eq!(average[0].count, 30);
+        assert_eq!(average[0].points, -12);
+        assert_eq!(average[1].count, 10);
+        assert_eq!(average[1].points, -10);
+    }
+}
@@ -1,15 +1,53 @@
+mod achievement;
 mod app_usage;
+mod app_usage_segment;
+mod calendar_event;
+mod daily_app_usage;
+mod daily_target;
 mod digest_stats;
 mod focus_mode;
+mod focus_score;
+mod mode_trend;
+mod project;
 mod review_event;
 mod session;
+mod session_interruption;
 mod session_metrics;
+mod session_note;
+mod streak;
 mod suggestion;
+mod suggestion_strategy;
+mod sync_record;
+mod week_start;
 
+pub use achievement::{
+    Achievement, UnlockedAchievement, DEEP_FOCUS_MINIMUM_SECONDS, DISTRACTION_FREE_MINIMUM_SECONDS,
+    PRODUCTIVE_WEEK_SESSIONS,
+};
 pub use app_usage::AppUsage;
-pub use digest_stats::{DigestStats, WeekStats};
+pub use app_usage_segment::{AppUsageSegment, CheckInOutcome, SegmentKind};
+pub use calendar_event::CalendarEvent;
+pub use daily_app_usage::DailyAppUsage;
+pub use daily_target::DailyTarget;
+pub use digest_stats::{DigestInsight, DigestRegression, DigestReport, DigestStats, WeekStats};
 pub use focus_mode::FocusMode;
+pub use focus_score::{ScoreBreakdown, ScoreContribution, ScoreFactor, ScoreWeights};
+pub use mode_trend::WeeklyModeTotal;
+pub use project::{detect_project, is_project_application};
 pub use review_event::{Provider, ReviewAction, ReviewEvent};
-pub use session::{Session, SessionId};
+pub use session::{
+    completion_rate, CompletionBreakdown, Session, SessionEndReason, SessionId, TagUsage,
+};
+pub use session_interruption::{InterruptionKind, InterruptionReasonCount, SessionInterruption};
 pub use session_metrics::SessionMetrics;
+pub use session_note::SessionNote;
+pub use streak::{Streak, STREAK_MILESTONES};
 pub use suggestion::{DistractionSuggestion, SuggestionReason, SuggestionReport};
+pub use suggestion_strategy::{
+    CumulativeTimeStrategy, QuotaOverrunStrategy, ShortBurstStrategy, SuggestionContext,
+    SuggestionPipeline, SuggestionStrategy, SuggestionThresholds, TitlePatternStrategy,
+};
+pub use sync_record::{
+    latest_versions, SyncMerge, SyncRecord, SyncedAppUsage, SyncedSegment, SyncedSession,
+};
+pub use week_start::WeekStart;
@@ -0,0 +1,10 @@
+use chrono::NaiveDate;
+
+use super::FocusMode;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyModeTotal {
+    pub week_start: NaiveDate,
+    pub mode: FocusMode,
+    pub seconds: i64,
+}
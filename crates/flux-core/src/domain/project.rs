@@ -0,0 +1,182 @@
+const EDITOR_APPLICATIONS: &[&str] = &["code", "cursor", "codium", "zed", "sublime", "windsurf"];
+
+const JETBRAINS_APPLICATIONS: &[&str] = &[
+    "jetbrains",
+    "idea",
+    "pycharm",
+    "webstorm",
+    "rustrover",
+    "goland",
+    "clion",
+    "rider",
+    "phpstorm",
+];
+
+const TERMINAL_APPLICATIONS: &[&str] = &[
+    "terminal",
+    "alacritty",
+    "kitty",
+    "konsole",
+    "wezterm",
+    "foot",
+    "tilix",
+    "xterm",
+    "ghostty",
+];
+
+const TITLE_SEPARATORS: &[&str] = &[" — ", " – ", " - "];
+
+pub fn detect_project(application_name: &str, window_title: &str) -> Option<String> {
+    let application = application_name.to_lowercase();
+    let title = window_title.trim().trim_start_matches(['●', '*']).trim();
+
+    if title.is_empty() {
+        return None;
+    }
+
+    let project = if matches_any(&application, JETBRAINS_APPLICATIONS) {
+        jetbrains_project(title)
+    } else if matches_any(&application, EDITOR_APPLICATIONS) {
+        editor_project(title, &application)
+    } else if matches_any(&application, TERMINAL_APPLICATIONS) {
+        terminal_project(title)
+    } else {
+        None
+    }?;
+
+    let project = project.trim();
+    if project.is_empty() || project == "~" {
+        None
+    } else {
+        Some(project.to_string())
+    }
+}
+
+/// Editors, IDEs and terminals whose window title names the current project
+pub fn is_project_application(application_name: &str) -> bool {
+    let application = application_name.to_lowercase();
+    matches_any(&application, EDITOR_APPLICATIONS)
+        || matches_any(&application, JETBRAINS_APPLICATIONS)
+        || matches_any(&application, TERMINAL_APPLICATIONS)
+}
+
+fn matches_any(application: &str, candidates: &[&str]) -> bool {
+    candidates
+        .iter()
+        .any(|candidate| application.contains(candidate))
+}
+
+fn split_title(title: &str) -> Vec<&str> {
+    let separator = TITLE_SEPARATORS
+        .iter()
+        .find(|separator| title.contains(*separator));
+
+    match separator {
+        Some(separator) => title.split(separator).map(str::trim).collect(),
+        None => vec![title],
+    }
+}
+
+fn editor_project(title: &str, application: &str) -> Option<String> {
+    let mut segments = split_title(title);
+
+    if let Some(last) = segments.last() {
+        let last = last.to_lowercase();
+        if last.contains(application) || last.contains("visual studio code") || last == "zed" {
+            segments.pop();
+        }
+    }
+
+    match segments.as_slice() {
+        [] => None,
+        [single] if looks_like_file(single) => None,
+        [single] => Some(single.to_string()),
+        [.., project] => Some(project.to_string()),
+    }
+}
+
+fn jetbrains_project(title: &str) -> Option<String> {
+    let first = split_title(title).into_iter().next()?;
+    let project = first.split(" [").next().unwrap_or(first);
+    Some(project.to_string())
+}
+
+fn terminal_project(title: &str) -> Option<String> {
+    let path = title
+        .rsplit_once(':')
+        .map(|(_, path)| path.trim())
+        .filter(|path| path.starts_with('~') || path.starts_with('/'))
+        .or_else(|| {
+            title
+                .split_whitespace()
+                .find(|token| token.starts_with("~/") || token.starts_with('/'))
+        })?;
+
+    path.trim_end_matches('/')
+        .rsplit('/')
+        .find(|component| !component.is_empty())
+        .map(str::to_string)
+}
+
+fn looks_like_file(segment: &str) -> bool {
+    match segment.rsplit_once('.') {
+        Some((stem, extension)) => {
+            !stem.is_empty()
+                && !segment.contains(' ')
+                && (1..=5).contains(&extension.len())
+                && extension
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_project_from_vscode_style_titles() {
+        assert_eq!(
+            detect_project("Cursor", "file.rs — flux — Cursor"),
+            Some("flux".to_string())
+        );
+        assert_eq!(
+            detect_project("code", "● main.rs - api-server - Visual Studio Code"),
+            Some("api-server".to_string())
+        );
+        assert_eq!(
+            detect_project("cursor", "flux - Cursor"),
+            Some("flux".to_string())
+        );
+        assert_eq!(detect_project("cursor", "main.rs - Cursor"), None);
+    }
+
+    #[test]
+    fn detects_project_from_jetbrains_titles() {
+        assert_eq!(
+            detect_project("jetbrains-rustrover", "flux [~/code/flux] – main.rs"),
+            Some("flux".to_string())
+        );
+    }
+
+    #[test]
+    fn detects_project_from_terminal_working_directory() {
+        assert_eq!(
+            detect_project("Alacritty", "damien@laptop: ~/code/flux"),
+            Some("flux".to_string())
+        );
+        assert_eq!(
+            detect_project("kitty", "nvim ~/code/website/"),
+            Some("website".to_string())
+        );
+        assert_eq!(detect_project("kitty", "damien@laptop: ~"), None);
+    }
+
+    #[test]
+    fn ignores_other_applications_and_empty_titles() {
+        assert_eq!(detect_project("firefox", "flux - GitHub"), None);
+        assert_eq!(detect_project("cursor", ""), None);
+    }
+}
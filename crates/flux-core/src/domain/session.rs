@@ -4,6 +4,44 @@ use super::FocusMode;
 
 pub type SessionId = i64;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEndReason {
+    Completed,
+    StoppedEarly,
+    Logout,
+    Interrupted,
+}
+
+impl SessionEndReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionEndReason::Completed => "completed",
+            SessionEndReason::StoppedEarly => "stopped_early",
+            SessionEndReason::Logout => "logout",
+            SessionEndReason::Interrupted => "interrupted",
+        }
+    }
+
+    pub fn from_stored(value: &str) -> Option<Self> {
+        match value {
+            "completed" => Some(SessionEndReason::Completed),
+            "stopped_early" => Some(SessionEndReason::StoppedEarly),
+            "logout" => Some(SessionEndReason::Logout),
+            "interrupted" => Some(SessionEndReason::Interrupted),
+            _ => None,
+        }
+    }
+
+    /// A manual stop once `threshold_percent` of the planned focus time has elapsed still completes the session
+    pub fn for_stop(elapsed_seconds: u64, planned_seconds: u64, threshold_percent: u8) -> Self {
+        if elapsed_seconds * 100 >= planned_seconds * threshold_percent as u64 {
+            SessionEndReason::Completed
+        } else {
+            SessionEndReason::StoppedEarly
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Session {
     pub id: Option<SessionId>,
@@ -12,6 +50,10 @@ pub struct Session {
     pub ended_at: Option<DateTime<Utc>>,
     pub duration_seconds: Option<i64>,
     pub check_in_count: i32,
+    pub tags: Vec<String>,
+    pub end_reason: Option<SessionEndReason>,
+    /// Length the session was started with, None for sessions recorded before it was stored
+    pub planned_minutes: Option<u64>,
 }
 
 impl Session {
@@ -23,13 +65,50 @@ impl Session {
             ended_at: None,
             duration_seconds: None,
             check_in_count: 0,
+            tags: Vec::new(),
+            end_reason: None,
+            planned_minutes: None,
+        }
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = normalize_tags(tags);
+        self
+    }
+
+    pub fn with_planned_minutes(mut self, minutes: u64) -> Self {
+        self.planned_minutes = Some(minutes);
+        self
+    }
+
+    /// False when the tag is blank or already attached
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        if tag.trim().is_empty() || self.has_tag(tag) {
+            return false;
         }
+        let mut tags = std::mem::take(&mut self.tags);
+        tags.push(tag.to_string());
+        self.tags = normalize_tags(tags);
+        true
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+        self.tags.contains(&tag)
     }
 
     pub fn end(&mut self) {
-        let now = Utc::now();
-        let duration = now.signed_duration_since(self.started_at);
-        self.ended_at = Some(now);
+        self.end_at(Utc::now());
+    }
+
+    pub fn end_with_reason(&mut self, ended_at: DateTime<Utc>, reason: SessionEndReason) {
+        self.end_at(ended_at.max(self.started_at));
+        self.end_reason = Some(reason);
+    }
+
+    fn end_at(&mut self, ended_at: DateTime<Utc>) {
+        let duration = ended_at.signed_duration_since(self.started_at);
+        self.ended_at = Some(ended_at);
         self.duration_seconds = Some(duration.num_seconds());
     }
 
@@ -40,6 +119,98 @@ impl Session {
     pub fn is_active(&self) -> bool {
         self.ended_at.is_none()
     }
+
+    /// Focus time reached as a percentage of the planned length, capped at 100
+    fn planned_share(&self) -> Option<u32> {
+        let planned_seconds = self.planned_minutes.filter(|minutes| *minutes > 0)? * 60;
+        let focused_seconds = self.duration_seconds?.max(0) as u64;
+        Some((focused_seconds * 100 / planned_seconds).min(100) as u32)
+    }
+
+    /// None when the outcome is unknown: sessions recorded before it was stored, or cut by a crash
+    pub fn is_completed(&self) -> Option<bool> {
+        match self.end_reason? {
+            SessionEndReason::Completed => Some(true),
+            SessionEndReason::StoppedEarly | SessionEndReason::Logout => Some(false),
+            SessionEndReason::Interrupted => None,
+        }
+    }
+}
+
+/// Share of sessions with a known outcome that ran to completion, as a percentage
+pub fn completion_rate(sessions: &[Session]) -> Option<u32> {
+    let outcomes: Vec<bool> = sessions.iter().filter_map(Session::is_completed).collect();
+    if outcomes.is_empty() {
+        return None;
+    }
+    let completed = outcomes.iter().filter(|completed| **completed).count();
+    Some((completed * 100 / outcomes.len()) as u32)
+}
+
+/// Focus time spent under one tag, across ended sessions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagUsage {
+    pub tag: String,
+    pub session_count: u32,
+    pub total_seconds: i64,
+}
+
+/// How ended sessions finished, for the completion and abandonment analytics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompletionBreakdown {
+    pub completed: usize,
+    pub stopped_early: usize,
+    /// Cut by a logout or a crash
+    pub discarded: usize,
+    /// Mean share of the planned length reached by sessions stopped early
+    pub average_stop_percent: Option<u32>,
+}
+
+impl CompletionBreakdown {
+    pub fn from_sessions<'a>(sessions: impl IntoIterator<Item = &'a Session>) -> Self {
+        let mut breakdown = Self::default();
+        let mut stop_percents = Vec::new();
+
+        for session in sessions {
+            match session.end_reason {
+                Some(SessionEndReason::Completed) => breakdown.completed += 1,
+                Some(SessionEndReason::StoppedEarly) => {
+                    breakdown.stopped_early += 1;
+                    stop_percents.extend(session.planned_share());
+                }
+                Some(SessionEndReason::Logout | SessionEndReason::Interrupted) => {
+                    breakdown.discarded += 1
+                }
+                None => {}
+            }
+        }
+
+        if !stop_percents.is_empty() {
+            breakdown.average_stop_percent =
+                Some(stop_percents.iter().sum::<u32>() / stop_percents.len() as u32);
+        }
+        breakdown
+    }
+
+    pub fn total(&self) -> usize {
+        self.completed + self.stopped_early + self.discarded
+    }
+
+    /// Share of `count` among the sessions with a known outcome
+    pub fn percent_of(&self, count: usize) -> u32 {
+        (count * 100 / self.total().max(1)) as u32
+    }
+}
+
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = tags
+        .into_iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    normalized.sort();
+    normalized.dedup();
+    normalized
 }
 
 #[cfg(test)]
@@ -67,6 +238,29 @@ mod tests {
         assert!(session.duration_seconds.is_some());
     }
 
+    #[test]
+    fn ending_with_reason_never_precedes_start() {
+        let mut session = Session::start(FocusMode::Review);
+        let started_at = session.started_at;
+
+        session.end_with_reason(
+            started_at - chrono::Duration::minutes(5),
+            SessionEndReason::Interrupted,
+        );
+
+        assert_eq!(session.ended_at, Some(started_at));
+        assert_eq!(session.duration_seconds, Some(0));
+        assert_eq!(session.end_reason, Some(SessionEndReason::Interrupted));
+        assert_eq!(
+            SessionEndReason::from_stored(SessionEndReason::Logout.as_str()),
+            Some(SessionEndReason::Logout)
+        );
+        assert_eq!(
+            SessionEndReason::from_stored(SessionEndReason::StoppedEarly.as_str()),
+            Some(SessionEndReason::StoppedEarly)
+        );
+    }
+
     #[test]
     fn check_in_increments_counter() {
         let mut session = Session::start(FocusMode::Architecture);
@@ -77,4 +271,91 @@ mod tests {
         session.increment_check_in();
         assert_eq!(session.check_in_count, 2);
     }
+
+    #[test]
+    fn tags_are_normalized_and_matched_case_insensitively() {
+        let session = Session::start(FocusMode::Review).with_tags(vec![
+            "ClientX".to_string(),
+            " backend ".to_string(),
+            "clientx".to_string(),
+            "".to_string(),
+        ]);
+
+        assert_eq!(session.tags, vec!["backend", "clientx"]);
+        assert!(session.has_tag("CLIENTX"));
+        assert!(!session.has_tag("frontend"));
+    }
+
+    #[test]
+    fn tags_added_later_are_normalized_once() {
+        let mut session = Session::start(FocusMode::Review).with_tags(vec!["backend".to_string()]);
+
+        assert!(session.add_tag(" ClientX "));
+        assert!(!session.add_tag("clientx"));
+        assert!(!session.add_tag("  "));
+        assert_eq!(session.tags, vec!["backend", "clientx"]);
+    }
+
+    #[test]
+    fn stopping_past_the_threshold_counts_as_completed() {
+        assert_eq!(
+            SessionEndReason::for_stop(24 * 60, 25 * 60, 95),
+            SessionEndReason::Completed
+        );
+        assert_eq!(
+            SessionEndReason::for_stop(20 * 60, 25 * 60, 95),
+            SessionEndReason::StoppedEarly
+        );
+        assert_eq!(
+            SessionEndReason::for_stop(25 * 60, 25 * 60, 100),
+            SessionEndReason::Completed
+        );
+    }
+
+    #[test]
+    fn completion_rate_ignores_sessions_without_a_known_outcome() {
+        let ended = |reason: Option<SessionEndReason>| {
+            let mut session = Session::start(FocusMode::Review);
+            session.end();
+            session.end_reason = reason;
+            session
+        };
+        let sessions = vec![
+            ended(Some(SessionEndReason::Completed)),
+            ended(Some(SessionEndReason::Completed)),
+            ended(Some(SessionEndReason::StoppedEarly)),
+            ended(Some(SessionEndReason::Interrupted)),
+            ended(None),
+        ];
+
+        assert_eq!(completion_rate(&sessions), Some(66));
+        assert_eq!(completion_rate(&sessions[3..]), None);
+    }
+
+    #[test]
+    fn breakdown_averages_where_early_stops_happened() {
+        let ended = |reason: SessionEndReason, planned_minutes: Option<u64>, seconds: i64| {
+            let mut session = Session::start(FocusMode::Review);
+            session.end();
+            session.end_reason = Some(reason);
+            session.planned_minutes = planned_minutes;
+            session.duration_seconds = Some(seconds);
+            session
+        };
+        let sessions = [
+            ended(SessionEndReason::Completed, Some(25), 25 * 60),
+            ended(SessionEndReason::StoppedEarly, Some(50), 10 * 60),
+            ended(SessionEndReason::StoppedEarly, Some(25), 15 * 60),
+            ended(SessionEndReason::StoppedEarly, None, 5 * 60),
+            ended(SessionEndReason::Logout, Some(25), 60),
+        ];
+
+        let breakdown = CompletionBreakdown::from_sessions(&sessions);
+
+        assert_eq!(breakdown.completed, 1);
+        assert_eq!(breakdown.stopped_early, 3);
+        assert_eq!(breakdown.discarded, 1);
+        assert_eq!(breakdown.percent_of(breakdown.stopped_early), 60);
+        assert_eq!(breakdown.average_stop_percent, Some(40));
+    }
 }
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+
+use super::SessionId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptionKind {
+    Pause,
+    Stop,
+}
+
+impl InterruptionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InterruptionKind::Pause => "pause",
+            InterruptionKind::Stop => "stop",
+        }
+    }
+
+    pub fn from_stored(value: &str) -> Option<Self> {
+        match value {
+            "pause" => Some(InterruptionKind::Pause),
+            "stop" => Some(InterruptionKind::Stop),
+            _ => None,
+        }
+    }
+}
+
+/// Why a session was paused or stopped, as given with `--reason`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionInterruption {
+    pub session_id: SessionId,
+    pub kind: InterruptionKind,
+    pub reason: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl SessionInterruption {
+    /// Reasons are stored lowercase on one line, like tags, so the same reason
+    /// typed twice is counted once; None when nothing is left
+    pub fn new(session_id: SessionId, kind: InterruptionKind, reason: &str) -> Option<Self> {
+        let reason = reason
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if reason.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            session_id,
+            kind,
+            reason,
+            occurred_at: Utc::now(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterruptionReasonCount {
+    pub reason: String,
+    pub count: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reason_is_normalized_to_one_lowercase_line() {
+        let interruption =
+            SessionInterruption::new(7, InterruptionKind::Pause, "  Standup\n Meeting ").unwrap();
+
+        assert_eq!(interruption.session_id, 7);
+        assert_eq!(interruption.reason, "standup meeting");
+    }
+
+    #[test]
+    fn blank_reason_is_rejected() {
+        assert!(SessionInterruption::new(7, InterruptionKind::Stop, " \t").is_none());
+    }
+
+    #[test]
+    fn kind_round_trips_through_storage() {
+        for kind in [InterruptionKind::Pause, InterruptionKind::Stop] {
+            assert_eq!(InterruptionKind::from_stored(kind.as_str()), Some(kind));
+        }
+        assert_eq!(InterruptionKind::from_stored("resume"), None);
+    }
+}
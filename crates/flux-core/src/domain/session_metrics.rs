@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::SessionId;
+use super::{ScoreBreakdown, ScoreFactor, ScoreWeights, SessionId};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionMetrics {
@@ -9,6 +9,16 @@ pub struct SessionMetrics {
     pub context_switch_count: u32,
     pub total_short_bursts: u32,
     pub short_bursts_by_app: HashMap<String, u32>,
+    #[serde(default)]
+    pub project_seconds: HashMap<String, i64>,
+    #[serde(default)]
+    pub active_input_ratio: Option<f64>,
+    #[serde(default)]
+    pub tracked_seconds: i64,
+    #[serde(default)]
+    pub distraction_seconds: i64,
+    #[serde(default)]
+    pub pause_count: u32,
 }
 
 impl SessionMetrics {
@@ -24,25 +34,69 @@ impl SessionMetrics {
             context_switch_count,
             total_short_bursts,
             short_bursts_by_app,
+            project_seconds: HashMap::new(),
+            active_input_ratio: None,
+            tracked_seconds: 0,
+            distraction_seconds: 0,
+            pause_count: 0,
         }
     }
 
-    pub fn focus_score(&self) -> u8 {
-        if self.context_switch_count == 0 {
-            return 100;
+    pub fn with_projects(mut self, project_seconds: HashMap<String, i64>) -> Self {
+        self.project_seconds = project_seconds;
+        self
+    }
+
+    /// Share of polls, between 0 and 1, with keyboard or mouse activity
+    pub fn with_active_input_ratio(mut self, active_input_ratio: Option<f64>) -> Self {
+        self.active_input_ratio = active_input_ratio.map(|ratio| ratio.clamp(0.0, 1.0));
+        self
+    }
+
+    pub fn with_distraction_time(mut self, tracked_seconds: i64, distraction_seconds: i64) -> Self {
+        self.tracked_seconds = tracked_seconds.max(0);
+        self.distraction_seconds = distraction_seconds.clamp(0, self.tracked_seconds);
+        self
+    }
+
+    pub fn with_pause_count(mut self, pause_count: u32) -> Self {
+        self.pause_count = pause_count;
+        self
+    }
+
+    /// Share of tracked time, in percent, spent on distractions
+    pub fn distraction_percent(&self) -> u32 {
+        if self.tracked_seconds <= 0 {
+            return 0;
         }
+        (self.distraction_seconds as f64 * 100.0 / self.tracked_seconds as f64).round() as u32
+    }
+
+    pub fn focus_score(&self) -> u8 {
+        self.score_breakdown().score
+    }
 
-        let penalty = (self.context_switch_count as f64 * 2.0
-            + self.total_short_bursts as f64 * 5.0)
-            .min(100.0);
+    pub fn score_breakdown(&self) -> ScoreBreakdown {
+        self.score_breakdown_with(&ScoreWeights::default())
+    }
 
-        (100.0 - penalty).max(0.0) as u8
+    pub fn score_breakdown_with(&self, weights: &ScoreWeights) -> ScoreBreakdown {
+        ScoreBreakdown::compute(
+            &[
+                (ScoreFactor::ContextSwitches, self.context_switch_count),
+                (ScoreFactor::ShortBursts, self.total_short_bursts),
+                (ScoreFactor::DistractionRatio, self.distraction_percent()),
+                (ScoreFactor::Pauses, self.pause_count),
+            ],
+            weights,
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::ScoreContribution;
 
     #[test]
     fn new_computes_total_short_bursts() {
@@ -55,6 +109,12 @@ mod tests {
         assert_eq!(metrics.total_short_bursts, 8);
     }
 
+    #[test]
+    fn active_input_ratio_is_clamped() {
+        let metrics = SessionMetrics::new(1, 0, HashMap::new()).with_active_input_ratio(Some(1.4));
+        assert_eq!(metrics.active_input_ratio, Some(1.0));
+    }
+
     #[test]
     fn focus_score_is_100_with_no_switches() {
         let metrics = SessionMetrics::new(1, 0, HashMap::new());
@@ -67,6 +127,76 @@ mod tests {
         assert!(metrics.focus_score() < 100);
     }
 
+    #[test]
+    fn score_breakdown_lists_each_factor() {
+        let metrics = SessionMetrics::new(1, 6, HashMap::from([("slack".to_string(), 2)]));
+
+        let breakdown = metrics.score_breakdown();
+
+        assert_eq!(breakdown.score, 78);
+        assert_eq!(
+            breakdown.contributions,
+            vec![
+                ScoreContribution {
+                    factor: ScoreFactor::ContextSwitches,
+                    count: 6,
+                    points: -12,
+                },
+                ScoreContribution {
+                    factor: ScoreFactor::ShortBursts,
+                    count: 2,
+                    points: -10,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn score_breakdown_adds_up_to_the_score_when_capped() {
+        let metrics = SessionMetrics::new(1, 40, HashMap::from([("slack".to_string(), 10)]));
+
+        let breakdown = metrics.score_breakdown();
+        let total: i32 = breakdown
+            .contributions
+            .iter()
+            .map(|contribution| contribution.points)
+            .sum();
+
+        assert_eq!(breakdown.score, 0);
+        assert_eq!(total, -100);
+        assert_eq!(breakdown.contributions[1].points, -20);
+        assert_eq!(metrics.focus_score(), breakdown.score);
+    }
+
+    #[test]
+    fn score_breakdown_includes_distraction_time_and_pauses() {
+        let metrics = SessionMetrics::new(1, 2, HashMap::new())
+            .with_distraction_time(1200, 300)
+            .with_pause_count(1);
+
+        let breakdown = metrics.score_breakdown_with(&ScoreWeights {
+            context_switch: 1,
+            short_burst: 0,
+            distraction_ratio: 20,
+            pause: 4,
+        });
+
+        assert_eq!(metrics.distraction_percent(), 25);
+        assert_eq!(breakdown.score, 89);
+        assert_eq!(
+            breakdown
+                .contributions
+                .iter()
+                .map(|contribution| contribution.factor)
+                .collect::<Vec<_>>(),
+            vec![
+                ScoreFactor::ContextSwitches,
+                ScoreFactor::DistractionRatio,
+                ScoreFactor::Pauses,
+            ]
+        );
+    }
+
     #[test]
     fn focus_score_penalizes_short_bursts_more() {
         let mut short_bursts = HashMap::new();
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+
+use super::SessionId;
+
+/// One-line retrospective written when a session ends
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionNote {
+    pub session_id: SessionId,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SessionNote {
+    /// Collapses the text to a single line; None when nothing is left
+    pub fn new(session_id: SessionId, text: &str) -> Option<Self> {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            session_id,
+            text,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_is_collapsed_to_one_line() {
+        let note = SessionNote::new(3, "  shipped the parser\n  and its tests ").unwrap();
+
+        assert_eq!(note.session_id, 3);
+        assert_eq!(note.text, "shipped the parser and its tests");
+    }
+
+    #[test]
+    fn blank_text_is_rejected() {
+        assert!(SessionNote::new(3, " \n\t").is_none());
+    }
+}
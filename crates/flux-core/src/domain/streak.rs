@@ -0,0 +1,113 @@
+use std::collections::BTreeSet;
+
+use chrono::{Duration, Local, NaiveDate};
+use serde::Serialize;
+
+use super::Session;
+
+pub const STREAK_MILESTONES: [u32; 2] = [7, 30];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Streak {
+    pub current_days: u32,
+    pub longest_days: u32,
+    pub active_today: bool,
+}
+
+impl Streak {
+    pub fn from_sessions(sessions: &[Session], today: NaiveDate) -> Self {
+        Self::from_dates(
+            sessions
+                .iter()
+                .filter(|session| !session.is_active())
+                .map(|session| session.started_at.with_timezone(&Local).date_naive()),
+            today,
+        )
+    }
+
+    pub fn from_dates(dates: impl IntoIterator<Item = NaiveDate>, today: NaiveDate) -> Self {
+        let days: BTreeSet<NaiveDate> = dates.into_iter().filter(|day| *day <= today).collect();
+
+        let mut longest_days = 0;
+        let mut run_length = 0;
+        let mut previous_day: Option<NaiveDate> = None;
+
+        for day in &days {
+            run_length = match previous_day {
+                Some(previous) if *day - previous == Duration::days(1) => run_length + 1,
+                _ => 1,
+            };
+            longest_days = longest_days.max(run_length);
+            previous_day = Some(*day);
+        }
+
+        let active_today = days.contains(&today);
+        let mut cursor = if active_today {
+            today
+        } else {
+            today - Duration::days(1)
+        };
+        let mut current_days = 0;
+        while days.contains(&cursor) {
+            current_days += 1;
+            cursor -= Duration::days(1);
+        }
+
+        Self {
+            current_days,
+            longest_days,
+            active_today,
+        }
+    }
+
+    pub fn milestone(&self) -> Option<u32> {
+        (self.active_today && STREAK_MILESTONES.contains(&self.current_days))
+            .then_some(self.current_days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    #[test]
+    fn counts_consecutive_days_ending_today() {
+        let streak = Streak::from_dates([date(8), date(9), date(10), date(10)], date(10));
+
+        assert_eq!(streak.current_days, 3);
+        assert_eq!(streak.longest_days, 3);
+        assert!(streak.active_today);
+    }
+
+    #[test]
+    fn keeps_streak_alive_until_today_ends() {
+        let streak = Streak::from_dates([date(8), date(9)], date(10));
+
+        assert_eq!(streak.current_days, 2);
+        assert!(!streak.active_today);
+    }
+
+    #[test]
+    fn resets_after_a_missed_day_but_remembers_longest() {
+        let streak = Streak::from_dates([date(1), date(2), date(3), date(4), date(7)], date(9));
+
+        assert_eq!(streak.current_days, 0);
+        assert_eq!(streak.longest_days, 4);
+    }
+
+    #[test]
+    fn milestone_requires_activity_today() {
+        let week: Vec<_> = (4..=10).map(date).collect();
+
+        assert_eq!(
+            Streak::from_dates(week.clone(), date(10)).milestone(),
+            Some(7)
+        );
+        assert_eq!(Streak::from_dates(week, date(11)).milestone(), None);
+        assert_eq!(Streak::default().milestone(), None);
+    }
+}
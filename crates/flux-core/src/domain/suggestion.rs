@@ -1,19 +1,46 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs;
 use std::path::PathBuf;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use super::{SuggestionContext, SuggestionPipeline};
+
+/// An app, or for title suggestions a site seen in window titles, worth adding to the distractions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DistractionSuggestion {
     pub application_name: String,
     pub short_burst_count: u32,
     pub reason: SuggestionReason,
+    /// 0 to 100, 50 when the evidence just meets the strategy threshold
+    #[serde(default)]
+    pub confidence: u8,
+}
+
+impl DistractionSuggestion {
+    /// Title suggestions name a window title pattern rather than an application
+    pub fn is_title_pattern(&self) -> bool {
+        matches!(self.reason, SuggestionReason::DistractingTitle { .. })
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SuggestionReason {
     FrequentShortBursts,
+    CumulativeTime { seconds: i64 },
+    DistractingTitle { seconds: i64 },
+    QuotaOverrun { seconds: i64, quota_seconds: i64 },
+}
+
+impl SuggestionReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuggestionReason::FrequentShortBursts => "frequent_short_bursts",
+            SuggestionReason::CumulativeTime { .. } => "cumulative_time",
+            SuggestionReason::DistractingTitle { .. } => "distracting_title",
+            SuggestionReason::QuotaOverrun { .. } => "quota_overrun",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -24,33 +51,29 @@ pub struct SuggestionReport {
     pub suggestions: Vec<DistractionSuggestion>,
 }
 
-const MIN_SHORT_BURSTS_FOR_SUGGESTION: u32 = 3;
-
 impl SuggestionReport {
     pub fn from_session_data(
         session_id: i64,
-        short_burst_count: &HashMap<String, u32>,
         context_switch_count: u32,
-        existing_distractions: &std::collections::HashSet<String>,
-        whitelist_apps: &std::collections::HashSet<String>,
+        context: &SuggestionContext,
+        pipeline: &SuggestionPipeline,
+        existing_distractions: &HashSet<String>,
+        whitelist_apps: &HashSet<String>,
     ) -> Self {
-        let mut suggestions: Vec<DistractionSuggestion> = short_burst_count
-            .iter()
-            .filter(|(app, count)| {
-                let app_lower = app.to_lowercase();
+        let suggestions = pipeline
+            .run(context)
+            .into_iter()
+            .filter(|suggestion| {
+                if suggestion.is_title_pattern() {
+                    return true;
+                }
+                let app_lower = suggestion.application_name.to_lowercase();
                 let is_distraction = existing_distractions.iter().any(|d| app_lower.contains(d));
                 let is_whitelisted = whitelist_apps.iter().any(|w| app_lower.contains(w));
-                **count >= MIN_SHORT_BURSTS_FOR_SUGGESTION && !is_distraction && !is_whitelisted
-            })
-            .map(|(app, count)| DistractionSuggestion {
-                application_name: app.clone(),
-                short_burst_count: *count,
-                reason: SuggestionReason::FrequentShortBursts,
+                !is_distraction && !is_whitelisted
             })
             .collect();
 
-        suggestions.sort_by(|a, b| b.short_burst_count.cmp(&a.short_burst_count));
-
         Self {
             generated_at: Some(Utc::now()),
             session_id: Some(session_id),
@@ -59,6 +82,27 @@ impl SuggestionReport {
         }
     }
 
+    /// Removes the suggestion at `position`, counted from 1 as in `flux suggestions list`
+    pub fn take(&mut self, position: usize) -> Option<DistractionSuggestion> {
+        let index = position.checked_sub(1)?;
+        (index < self.suggestions.len()).then(|| self.suggestions.remove(index))
+    }
+
+    pub fn without_dismissed(mut self, is_dismissed: impl Fn(&str) -> bool) -> Self {
+        self.suggestions
+            .retain(|suggestion| !is_dismissed(&suggestion.application_name));
+        self
+    }
+
+    /// Saves the remaining suggestions, or removes the file once none are left
+    pub fn store(&self) -> Result<(), std::io::Error> {
+        if self.suggestions.is_empty() {
+            Self::clear()
+        } else {
+            self.save().map(|_| ())
+        }
+    }
+
     pub fn save(&self) -> Result<PathBuf, std::io::Error> {
         let path = Self::file_path()?;
 
@@ -109,7 +153,29 @@ impl SuggestionReport {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashSet;
+    use std::collections::HashMap;
+
+    fn report_from_bursts(
+        short_burst_count: &HashMap<String, u32>,
+        existing: &HashSet<String>,
+        whitelist: &HashSet<String>,
+    ) -> SuggestionReport {
+        let empty = HashSet::new();
+        let context = SuggestionContext {
+            short_burst_count,
+            segments: &[],
+            friction_apps: &empty,
+            title_patterns: &empty,
+        };
+        SuggestionReport::from_session_data(
+            1,
+            10,
+            &context,
+            &SuggestionPipeline::default(),
+            existing,
+            whitelist,
+        )
+    }
 
     #[test]
     fn from_session_data_filters_below_threshold() {
@@ -119,8 +185,7 @@ mod tests {
 
         let existing = HashSet::new();
         let whitelist = HashSet::new();
-        let report =
-            SuggestionReport::from_session_data(1, &short_burst_count, 10, &existing, &whitelist);
+        let report = report_from_bursts(&short_burst_count, &existing, &whitelist);
 
         assert_eq!(report.suggestions.len(), 1);
         assert_eq!(report.suggestions[0].application_name, "slack");
@@ -136,8 +201,7 @@ mod tests {
         existing.insert("discord".to_string());
         let whitelist = HashSet::new();
 
-        let report =
-            SuggestionReport::from_session_data(1, &short_burst_count, 10, &existing, &whitelist);
+        let report = report_from_bursts(&short_burst_count, &existing, &whitelist);
 
         assert_eq!(report.suggestions.len(), 1);
         assert_eq!(report.suggestions[0].application_name, "slack");
@@ -153,8 +217,7 @@ mod tests {
         let mut whitelist = HashSet::new();
         whitelist.insert("cursor".to_string());
 
-        let report =
-            SuggestionReport::from_session_data(1, &short_burst_count, 10, &existing, &whitelist);
+        let report = report_from_bursts(&short_burst_count, &existing, &whitelist);
 
         assert_eq!(report.suggestions.len(), 1);
         assert_eq!(report.suggestions[0].application_name, "slack");
@@ -169,12 +232,65 @@ mod tests {
 
         let existing = HashSet::new();
         let whitelist = HashSet::new();
-        let report =
-            SuggestionReport::from_session_data(1, &short_burst_count, 10, &existing, &whitelist);
+        let report = report_from_bursts(&short_burst_count, &existing, &whitelist);
 
         assert_eq!(report.suggestions.len(), 3);
         assert_eq!(report.suggestions[0].application_name, "youtube");
         assert_eq!(report.suggestions[1].application_name, "reddit");
         assert_eq!(report.suggestions[2].application_name, "twitter");
     }
+
+    #[test]
+    fn take_counts_positions_from_one() {
+        let mut short_burst_count = HashMap::new();
+        short_burst_count.insert("youtube".to_string(), 15);
+        short_burst_count.insert("reddit".to_string(), 8);
+        let mut report = report_from_bursts(&short_burst_count, &HashSet::new(), &HashSet::new());
+
+        assert!(report.take(0).is_none());
+        assert!(report.take(3).is_none());
+        assert_eq!(report.take(2).unwrap().application_name, "reddit");
+        assert_eq!(report.suggestions.len(), 1);
+    }
+
+    #[test]
+    fn dismissed_applications_are_filtered_out() {
+        let mut short_burst_count = HashMap::new();
+        short_burst_count.insert("youtube".to_string(), 15);
+        short_burst_count.insert("Spotify".to_string(), 8);
+
+        let report = report_from_bursts(&short_burst_count, &HashSet::new(), &HashSet::new())
+            .without_dismissed(|application| application.eq_ignore_ascii_case("spotify"));
+
+        assert_eq!(report.suggestions.len(), 1);
+        assert_eq!(report.suggestions[0].application_name, "youtube");
+    }
+
+    #[test]
+    fn title_suggestions_name_a_window_title_pattern() {
+        let suggestion = DistractionSuggestion {
+            application_name: "youtube".to_string(),
+            short_burst_count: 0,
+            reason: SuggestionReason::DistractingTitle { seconds: 300 },
+            confidence: 100,
+        };
+
+        assert!(suggestion.is_title_pattern());
+        assert_eq!(suggestion.reason.as_str(), "distracting_title");
+    }
+
+    #[test]
+    fn reports_saved_before_confidence_still_load() {
+        let report: SuggestionReport = serde_json::from_str(
+            r#"{"generated_at":null,"session_id":1,"context_switch_count":4,
+                "suggestions":[{"application_name":"slack","short_burst_count":5,"reason":"FrequentShortBursts"}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(report.suggestions[0].confidence, 0);
+        assert_eq!(
+            report.suggestions[0].reason,
+            SuggestionReason::FrequentShortBursts
+        );
+    }
 }
@@ -0,0 +1,430 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{AppUsageSegment, DistractionSuggestion, SegmentKind, SuggestionReason};
+
+/// Sites commonly opened in a browser tab, matched against window titles
+const DISTRACTING_SITES: &[&str] = &[
+    "youtube",
+    "reddit",
+    "twitter",
+    "x.com",
+    "facebook",
+    "instagram",
+    "tiktok",
+    "linkedin",
+    "netflix",
+    "twitch",
+    "hacker news",
+    "9gag",
+];
+
+/// What a finished session looked like, as seen by the suggestion strategies
+pub struct SuggestionContext<'a> {
+    pub short_burst_count: &'a HashMap<String, u32>,
+    pub segments: &'a [AppUsageSegment],
+    pub friction_apps: &'a HashSet<String>,
+    pub title_patterns: &'a HashSet<String>,
+}
+
+/// Minimum evidence each strategy needs before suggesting anything
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuggestionThresholds {
+    pub short_bursts: u32,
+    /// Visits shorter than this count toward the cumulative-time strategy
+    pub short_visit_seconds: i64,
+    pub cumulative_seconds: i64,
+    pub title_seconds: i64,
+    /// Time a friction app may take in one session before it is suggested as a distraction
+    pub friction_quota_seconds: i64,
+}
+
+impl Default for SuggestionThresholds {
+    fn default() -> Self {
+        Self {
+            short_bursts: 3,
+            short_visit_seconds: 120,
+            cumulative_seconds: 300,
+            title_seconds: 120,
+            friction_quota_seconds: 600,
+        }
+    }
+}
+
+pub trait SuggestionStrategy: Send + Sync {
+    fn suggest(&self, context: &SuggestionContext) -> Vec<DistractionSuggestion>;
+}
+
+/// Apps opened for a few seconds over and over
+pub struct ShortBurstStrategy {
+    pub minimum_bursts: u32,
+}
+
+impl SuggestionStrategy for ShortBurstStrategy {
+    fn suggest(&self, context: &SuggestionContext) -> Vec<DistractionSuggestion> {
+        context
+            .short_burst_count
+            .iter()
+            .filter(|(_, count)| **count >= self.minimum_bursts)
+            .map(|(application, count)| DistractionSuggestion {
+                application_name: application.clone(),
+                short_burst_count: *count,
+                reason: SuggestionReason::FrequentShortBursts,
+                confidence: confidence(*count as i64, self.minimum_bursts as i64),
+            })
+            .collect()
+    }
+}
+
+/// Apps that add up to a lot of time through brief visits, none long enough to be real work
+pub struct CumulativeTimeStrategy {
+    pub short_visit_seconds: i64,
+    pub minimum_seconds: i64,
+}
+
+impl SuggestionStrategy for CumulativeTimeStrategy {
+    fn suggest(&self, context: &SuggestionContext) -> Vec<DistractionSuggestion> {
+        let mut seconds_by_application: HashMap<&str, i64> = HashMap::new();
+        for (application, seconds) in visits(context.segments) {
+            if seconds < self.short_visit_seconds {
+                *seconds_by_application.entry(application).or_insert(0) += seconds;
+            }
+        }
+
+        seconds_by_application
+            .into_iter()
+            .filter(|(_, seconds)| *seconds >= self.minimum_seconds)
+            .map(|(application, seconds)| DistractionSuggestion {
+                application_name: application.to_string(),
+                short_burst_count: burst_count(context, application),
+                reason: SuggestionReason::CumulativeTime { seconds },
+                confidence: confidence(seconds, self.minimum_seconds),
+            })
+            .collect()
+    }
+}
+
+/// Known distracting sites seen in window titles the current title patterns do not catch
+pub struct TitlePatternStrategy {
+    pub minimum_seconds: i64,
+}
+
+impl SuggestionStrategy for TitlePatternStrategy {
+    fn suggest(&self, context: &SuggestionContext) -> Vec<DistractionSuggestion> {
+        let mut seconds_by_site: HashMap<&str, i64> = HashMap::new();
+        for segment in focus_segments(context.segments) {
+            let title = segment.window_title.to_lowercase();
+            if let Some(site) = DISTRACTING_SITES
+                .iter()
+                .find(|site| title.contains(*site) && !context.title_patterns.contains(**site))
+            {
+                *seconds_by_site.entry(*site).or_insert(0) += segment.duration_seconds();
+            }
+        }
+
+        seconds_by_site
+            .into_iter()
+            .filter(|(_, seconds)| *seconds >= self.minimum_seconds)
+            .map(|(site, seconds)| DistractionSuggestion {
+                application_name: site.to_string(),
+                short_burst_count: 0,
+                reason: SuggestionReason::DistractingTitle { seconds },
+                confidence: confidence(seconds, self.minimum_seconds),
+            })
+            .collect()
+    }
+}
+
+/// Friction apps that went well past the time they are allowed in a session
+pub struct QuotaOverrunStrategy {
+    pub quota_seconds: i64,
+}
+
+impl SuggestionStrategy for QuotaOverrunStrategy {
+    fn suggest(&self, context: &SuggestionContext) -> Vec<DistractionSuggestion> {
+        let mut seconds_by_application: HashMap<&str, i64> = HashMap::new();
+        for segment in focus_segments(context.segments) {
+            let application = segment.application_name.to_lowercase();
+            if context
+                .friction_apps
+                .iter()
+                .any(|friction| application.contains(friction))
+            {
+                *seconds_by_application
+                    .entry(&segment.application_name)
+                    .or_insert(0) += segment.duration_seconds();
+            }
+        }
+
+        seconds_by_application
+            .into_iter()
+            .filter(|(_, seconds)| *seconds > self.quota_seconds)
+            .map(|(application, seconds)| DistractionSuggestion {
+                application_name: application.to_string(),
+                short_burst_count: burst_count(context, application),
+                reason: SuggestionReason::QuotaOverrun {
+                    seconds,
+                    quota_seconds: self.quota_seconds,
+                },
+                confidence: confidence(seconds, self.quota_seconds),
+            })
+            .collect()
+    }
+}
+
+/// Runs every strategy and keeps the most confident suggestion per app or site
+pub struct SuggestionPipeline {
+    strategies: Vec<Box<dyn SuggestionStrategy>>,
+}
+
+impl SuggestionPipeline {
+    pub fn new(thresholds: SuggestionThresholds) -> Self {
+        Self {
+            strategies: vec![
+                Box::new(ShortBurstStrategy {
+                    minimum_bursts: thresholds.short_bursts,
+                }),
+                Box::new(CumulativeTimeStrategy {
+                    short_visit_seconds: thresholds.short_visit_seconds,
+                    minimum_seconds: thresholds.cumulative_seconds,
+                }),
+                Box::new(TitlePatternStrategy {
+                    minimum_seconds: thresholds.title_seconds,
+                }),
+                Box::new(QuotaOverrunStrategy {
+                    quota_seconds: thresholds.friction_quota_seconds,
+                }),
+            ],
+        }
+    }
+
+    pub fn with_strategy(mut self, strategy: Box<dyn SuggestionStrategy>) -> Self {
+        self.strategies.push(strategy);
+        self
+    }
+
+    pub fn run(&self, context: &SuggestionContext) -> Vec<DistractionSuggestion> {
+        let mut best: HashMap<String, DistractionSuggestion> = HashMap::new();
+        for suggestion in self
+            .strategies
+            .iter()
+            .flat_map(|strategy| strategy.suggest(context))
+        {
+            let key = suggestion.application_name.to_lowercase();
+            match best.get(&key) {
+                Some(kept) if kept.confidence >= suggestion.confidence => {}
+                _ => {
+                    best.insert(key, suggestion);
+                }
+            }
+        }
+
+        let mut suggestions: Vec<_> = best.into_values().collect();
+        suggestions.sort_by(|first, second| {
+            second
+                .confidence
+                .cmp(&first.confidence)
+                .then_with(|| second.short_burst_count.cmp(&first.short_burst_count))
+                .then_with(|| first.application_name.cmp(&second.application_name))
+        });
+        suggestions
+    }
+}
+
+impl Default for SuggestionPipeline {
+    fn default() -> Self {
+        Self::new(SuggestionThresholds::default())
+    }
+}
+
+/// 50 at the threshold, 100 from twice the threshold
+fn confidence(observed: i64, threshold: i64) -> u8 {
+    (observed * 50 / threshold.max(1)).clamp(0, 100) as u8
+}
+
+fn focus_segments(segments: &[AppUsageSegment]) -> impl Iterator<Item = &AppUsageSegment> {
+    segments
+        .iter()
+        .filter(|segment| segment.kind == SegmentKind::Focus)
+}
+
+/// Consecutive focus segments of the same app, merged into one visit
+fn visits(segments: &[AppUsageSegment]) -> Vec<(&str, i64)> {
+    let mut visits: Vec<(&str, i64)> = Vec::new();
+    for segment in focus_segments(segments) {
+        match visits.last_mut() {
+            Some((application, seconds)) if *application == segment.application_name => {
+                *seconds += segment.duration_seconds();
+            }
+            _ => visits.push((&segment.application_name, segment.duration_seconds())),
+        }
+    }
+    visits
+}
+
+fn burst_count(context: &SuggestionContext, application: &str) -> u32 {
+    context
+        .short_burst_count
+        .get(application)
+        .copied()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn segments(visits: &[(&str, &str, i64)]) -> Vec<AppUsageSegment> {
+        let mut at = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        visits
+            .iter()
+            .map(|(application, title, seconds)| {
+                let started_at = at;
+                at += Duration::seconds(*seconds);
+                AppUsageSegment::new(
+                    1,
+                    SegmentKind::Focus,
+                    application.to_string(),
+                    started_at,
+                    at,
+                )
+                .with_window_title(title.to_string())
+            })
+            .collect()
+    }
+
+    fn context<'a>(
+        short_burst_count: &'a HashMap<String, u32>,
+        segments: &'a [AppUsageSegment],
+        friction_apps: &'a HashSet<String>,
+        title_patterns: &'a HashSet<String>,
+    ) -> SuggestionContext<'a> {
+        SuggestionContext {
+            short_burst_count,
+            segments,
+            friction_apps,
+            title_patterns,
+        }
+    }
+
+    #[test]
+    fn short_burst_strategy_needs_enough_bursts() {
+        let bursts = HashMap::from([("slack".to_string(), 6), ("discord".to_string(), 2)]);
+        let empty = HashSet::new();
+
+        let suggestions = ShortBurstStrategy { minimum_bursts: 3 }.suggest(&context(
+            &bursts,
+            &[],
+            &empty,
+            &empty,
+        ));
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].application_name, "slack");
+        assert_eq!(suggestions[0].confidence, 100);
+    }
+
+    #[test]
+    fn cumulative_time_strategy_ignores_long_visits() {
+        let segments = segments(&[
+            ("code", "", 900),
+            ("spotify", "", 100),
+            ("code", "", 900),
+            ("spotify", "", 100),
+            ("code", "", 600),
+            ("spotify", "", 100),
+            ("terminal", "", 100),
+        ]);
+        let (bursts, empty) = (HashMap::new(), HashSet::new());
+
+        let suggestions = CumulativeTimeStrategy {
+            short_visit_seconds: 120,
+            minimum_seconds: 250,
+        }
+        .suggest(&context(&bursts, &segments, &empty, &empty));
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].application_name, "spotify");
+        assert_eq!(
+            suggestions[0].reason,
+            SuggestionReason::CumulativeTime { seconds: 300 }
+        );
+        assert_eq!(suggestions[0].confidence, 60);
+    }
+
+    #[test]
+    fn title_pattern_strategy_skips_sites_already_caught() {
+        let segments = segments(&[
+            ("firefox", "Lo-fi beats - YouTube — Mozilla Firefox", 200),
+            ("firefox", "Feed | LinkedIn — Mozilla Firefox", 300),
+            ("firefox", "docs.rs — Mozilla Firefox", 600),
+        ]);
+        let bursts = HashMap::new();
+        let patterns = HashSet::from(["linkedin".to_string()]);
+
+        let suggestions = TitlePatternStrategy {
+            minimum_seconds: 120,
+        }
+        .suggest(&context(&bursts, &segments, &HashSet::new(), &patterns));
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].application_name, "youtube");
+        assert_eq!(
+            suggestions[0].reason,
+            SuggestionReason::DistractingTitle { seconds: 200 }
+        );
+    }
+
+    #[test]
+    fn quota_overrun_strategy_only_watches_friction_apps() {
+        let segments = segments(&[
+            ("Telegram", "", 500),
+            ("code", "", 3000),
+            ("Telegram", "", 400),
+        ]);
+        let bursts = HashMap::new();
+        let friction = HashSet::from(["telegram".to_string()]);
+
+        let suggestions = QuotaOverrunStrategy { quota_seconds: 600 }.suggest(&context(
+            &bursts,
+            &segments,
+            &friction,
+            &HashSet::new(),
+        ));
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].application_name, "Telegram");
+        assert_eq!(
+            suggestions[0].reason,
+            SuggestionReason::QuotaOverrun {
+                seconds: 900,
+                quota_seconds: 600,
+            }
+        );
+        assert_eq!(suggestions[0].confidence, 75);
+    }
+
+    #[test]
+    fn pipeline_keeps_the_most_confident_suggestion_per_app() {
+        let segments = segments(&[("slack", "", 100), ("code", "", 600), ("slack", "", 100)]);
+        let bursts = HashMap::from([("slack".to_string(), 3), ("mail".to_string(), 4)]);
+        let empty = HashSet::new();
+        let pipeline = SuggestionPipeline::new(SuggestionThresholds {
+            cumulative_seconds: 120,
+            ..SuggestionThresholds::default()
+        });
+
+        let suggestions = pipeline.run(&context(&bursts, &segments, &empty, &empty));
+
+        let names: Vec<_> = suggestions
+            .iter()
+            .map(|suggestion| suggestion.application_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["slack", "mail"]);
+        assert_eq!(
+            suggestions[0].reason,
+            SuggestionReason::CumulativeTime { seconds: 200 }
+        );
+        assert_eq!(suggestions[0].short_burst_count, 3);
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Version of an ended session exchanged between devices. Devices number
+/// sessions on their own, so the session is identified by a UUID, and the
+/// version written last wins.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncRecord {
+    pub uuid: String,
+    pub updated_at: DateTime<Utc>,
+    /// None once the session was deleted on one of the devices
+    pub session: Option<SyncedSession>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncedSession {
+    pub mode: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub duration_seconds: Option<i64>,
+    pub check_in_count: i32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub end_reason: Option<String>,
+    pub planned_minutes: Option<u64>,
+    pub note: Option<String>,
+    /// Per-application totals of sessions recorded before segments existed
+    #[serde(default)]
+    pub app_usage: Vec<SyncedAppUsage>,
+    #[serde(default)]
+    pub segments: Vec<SyncedSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedAppUsage {
+    pub application_name: String,
+    pub window_title: String,
+    pub duration_seconds: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedSegment {
+    pub kind: String,
+    pub application_name: String,
+    pub window_title: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub check_in_outcome: Option<String>,
+}
+
+impl SyncRecord {
+    pub fn is_deleted(&self) -> bool {
+        self.session.is_none()
+    }
+
+    /// On equal timestamps the version already held is kept
+    fn supersedes(&self, other: &SyncRecord) -> bool {
+        self.updated_at > other.updated_at
+    }
+}
+
+/// Newest version of each session among records published by several devices
+pub fn latest_versions(records: impl IntoIterator<Item = SyncRecord>) -> Vec<SyncRecord> {
+    let mut latest: HashMap<String, SyncRecord> = HashMap::new();
+    for record in records {
+        match latest.get(&record.uuid) {
+            Some(held) if !record.supersedes(held) => {}
+            _ => {
+                latest.insert(record.uuid.clone(), record);
+            }
+        }
+    }
+
+    let mut records: Vec<SyncRecord> = latest.into_values().collect();
+    records.sort_by(|left, right| left.uuid.cmp(&right.uuid));
+    records
+}
+
+/// Outcome of comparing the local history with the other devices' one
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncMerge {
+    /// Versions newer than the local ones, to store on this device
+    pub incoming: Vec<SyncRecord>,
+    /// Local versions the other devices lack or hold an older version of
+    pub outgoing: usize,
+}
+
+impl SyncMerge {
+    pub fn new(local: &[SyncRecord], remote: Vec<SyncRecord>) -> Self {
+        let local_by_uuid: HashMap<&str, &SyncRecord> = local
+            .iter()
+            .map(|record| (record.uuid.as_str(), record))
+            .collect();
+        let remote = latest_versions(remote);
+        let remote_by_uuid: HashMap<&str, &SyncRecord> = remote
+            .iter()
+            .map(|record| (record.uuid.as_str(), record))
+            .collect();
+
+        let outgoing = local
+            .iter()
+            .filter(|record| {
+                remote_by_uuid
+                    .get(record.uuid.as_str())
+                    .map_or(true, |held| record.supersedes(held))
+            })
+            .count();
+        let incoming = remote
+            .iter()
+            .filter(|record| match local_by_uuid.get(record.uuid.as_str()) {
+                Some(held) => record.supersedes(held),
+                // A deletion of a session this device never had changes nothing here
+                None => !record.is_deleted(),
+            })
+            .cloned()
+            .collect();
+
+        Self { incoming, outgoing }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 12, hour, 0, 0).unwrap()
+    }
+
+    fn record(uuid: &str, updated_hour: u32, mode: &str) -> SyncRecord {
+        SyncRecord {
+            uuid: uuid.to_string(),
+            updated_at: at(updated_hour),
+            session: Some(SyncedSession {
+                mode: mode.to_string(),
+                started_at: at(8),
+                ended_at: at(9),
+                duration_seconds: Some(3600),
+                check_in_count: 0,
+                tags: Vec::new(),
+                end_reason: Some("completed".to_string()),
+                planned_minutes: Some(60),
+                note: None,
+                app_usage: Vec::new(),
+                segments: Vec::new(),
+            }),
+        }
+    }
+
+    fn deletion(uuid: &str, updated_hour: u32) -> SyncRecord {
+        SyncRecord {
+            uuid: uuid.to_string(),
+            updated_at: at(updated_hour),
+            session: None,
+        }
+    }
+
+    #[test]
+    fn the_version_written_last_wins() {
+        let local = vec![record("a", 10, "review"), record("b", 12, "review")];
+        let remote = vec![record("a", 11, "architecture"), record("b", 11, "veille")];
+
+        let merge = SyncMerge::new(&local, remote);
+
+        assert_eq!(merge.incoming, vec![record("a", 11, "architecture")]);
+        assert_eq!(merge.outgoing, 1);
+    }
+
+    #[test]
+    fn sessions_missing_on_either_side_are_exchanged() {
+        let local = vec![record("a", 10, "review")];
+        let remote = vec![record("b", 10, "review")];
+
+        let merge = SyncMerge::new(&local, remote);
+
+        assert_eq!(merge.incoming, vec![record("b", 10, "review")]);
+        assert_eq!(merge.outgoing, 1);
+        assert_eq!(
+            SyncMerge::new(&local, vec![record("a", 10, "veille")]),
+            SyncMerge::default()
+        );
+    }
+
+    #[test]
+    fn a_later_deletion_removes_the_session_and_a_later_edit_revives_it() {
+        let local = vec![record("a", 10, "review"), record("b", 12, "review")];
+        let remote = vec![deletion("a", 11), deletion("b", 11), deletion("c", 11)];
+
+        let merge = SyncMerge::new(&local, remote);
+
+        assert_eq!(merge.incoming, vec![deletion("a", 11)]);
+        assert_eq!(merge.outgoing, 1);
+    }
+
+    #[test]
+    fn devices_publishing_the_same_session_keep_its_newest_version() {
+        let latest = latest_versions(vec![
+            record("a", 10, "review"),
+            deletion("a", 12),
+            record("a", 11, "veille"),
+            record("b", 9, "review"),
+        ]);
+
+        assert_eq!(latest, vec![deletion("a", 12), record("b", 9, "review")]);
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// First day of the week used to group sessions by week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekStart {
+    #[default]
+    Monday,
+    Sunday,
+}
+
+impl WeekStart {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WeekStart::Monday => "monday",
+            WeekStart::Sunday => "sunday",
+        }
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        match self {
+            WeekStart::Monday => Weekday::Mon,
+            WeekStart::Sunday => Weekday::Sun,
+        }
+    }
+
+    /// First day of the week containing `date`
+    pub fn week_of(&self, date: NaiveDate) -> NaiveDate {
+        let days_since_start = match self {
+            WeekStart::Monday => date.weekday().num_days_from_monday(),
+            WeekStart::Sunday => date.weekday().num_days_from_sunday(),
+        };
+        date - Duration::days(days_since_start as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn week_of_returns_the_configured_first_day() {
+        let saturday = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        assert_eq!(
+            WeekStart::Monday.week_of(saturday),
+            NaiveDate::from_ymd_opt(2026, 10, 12).unwrap()
+        );
+        assert_eq!(
+            WeekStart::Monday.week_of(sunday),
+            NaiveDate::from_ymd_opt(2026, 10, 12).unwrap()
+        );
+        assert_eq!(
+            WeekStart::Sunday.week_of(saturday),
+            NaiveDate::from_ymd_opt(2026, 10, 11).unwrap()
+        );
+        assert_eq!(WeekStart::Sunday.week_of(sunday), sunday);
+    }
+}
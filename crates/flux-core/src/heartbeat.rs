@@ -0,0 +1,98 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub const HEARTBEAT_INTERVAL_SECONDS: u64 = 30;
+const STALE_AFTER_INTERVALS: i64 = 3;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationCapabilities {
+    pub server_name: Option<String>,
+    pub actions: bool,
+    pub sound: bool,
+    pub urgency: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Heartbeat {
+    pub pid: u32,
+    pub version: String,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub notifications: NotificationCapabilities,
+}
+
+impl Heartbeat {
+    pub fn load() -> Option<Self> {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content =
+            toml::to_string_pretty(self).map_err(|e| std::io::Error::other(e.to_string()))?;
+        std::fs::write(path, content)
+    }
+
+    pub fn remove() -> std::io::Result<()> {
+        match std::fs::remove_file(Self::path()) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn path() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("flux")
+            .join("heartbeat.toml")
+    }
+
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        let max_age = Duration::seconds(HEARTBEAT_INTERVAL_SECONDS as i64 * STALE_AFTER_INTERVALS);
+        now - self.updated_at > max_age
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_heartbeat(updated_at: DateTime<Utc>) -> Heartbeat {
+        Heartbeat {
+            pid: 4242,
+            version: "0.2.13".to_string(),
+            started_at: updated_at - Duration::hours(1),
+            updated_at,
+            notifications: NotificationCapabilities {
+                server_name: Some("gnome-shell".to_string()),
+                actions: true,
+                sound: false,
+                urgency: true,
+            },
+        }
+    }
+
+    #[test]
+    fn heartbeat_roundtrips_through_toml() {
+        let heartbeat = create_heartbeat(Utc::now());
+
+        let content = toml::to_string_pretty(&heartbeat).unwrap();
+        let decoded: Heartbeat = toml::from_str(&content).unwrap();
+
+        assert_eq!(decoded, heartbeat);
+    }
+
+    #[test]
+    fn heartbeat_is_stale_after_three_missed_intervals() {
+        let now = Utc::now();
+
+        assert!(!create_heartbeat(now - Duration::seconds(60)).is_stale(now));
+        assert!(create_heartbeat(now - Duration::seconds(120)).is_stale(now));
+    }
+}
@@ -1,4 +1,5 @@
 use super::Language;
+use crate::domain::{DistractionSuggestion, FocusMode, SuggestionReason};
 use std::collections::HashMap;
 
 const EN_TRANSLATIONS: &str = include_str!("locales/en.toml");
@@ -30,10 +31,53 @@ impl Translator {
     }
 
     pub fn get(&self, key: &str) -> String {
-        self.translations
-            .get(key)
-            .cloned()
-            .unwrap_or_else(|| key.to_string())
+        self.try_get(key).unwrap_or_else(|| key.to_string())
+    }
+
+    /// Like `get`, but lets the caller pick its own fallback
+    pub fn try_get(&self, key: &str) -> Option<String> {
+        self.translations.get(key).cloned()
+    }
+
+    /// Display name of a mode; custom modes keep the name the user gave them
+    pub fn mode_name(&self, mode: &FocusMode) -> String {
+        let key = match mode {
+            FocusMode::AiAssisted => "mode.ai_assisted",
+            FocusMode::Review => "mode.review",
+            FocusMode::Architecture => "mode.architecture",
+            FocusMode::Veille => "mode.veille",
+            FocusMode::Custom(name) => return name.clone(),
+        };
+        self.get(key)
+    }
+
+    /// Why a distraction was suggested, as shown next to it
+    pub fn suggestion_reason(&self, suggestion: &DistractionSuggestion) -> String {
+        let minutes = |seconds: i64| (seconds / 60).to_string();
+        match &suggestion.reason {
+            SuggestionReason::FrequentShortBursts => self.format(
+                "command.suggestions_reason_short_bursts",
+                &[("count", &suggestion.short_burst_count.to_string())],
+            ),
+            SuggestionReason::CumulativeTime { seconds } => self.format(
+                "command.suggestions_reason_cumulative_time",
+                &[("minutes", &minutes(*seconds))],
+            ),
+            SuggestionReason::DistractingTitle { seconds } => self.format(
+                "command.suggestions_reason_title",
+                &[("minutes", &minutes(*seconds))],
+            ),
+            SuggestionReason::QuotaOverrun {
+                seconds,
+                quota_seconds,
+            } => self.format(
+                "command.suggestions_reason_quota",
+                &[
+                    ("minutes", &minutes(*seconds)),
+                    ("quota", &minutes(*quota_seconds)),
+                ],
+            ),
+        }
     }
 
     pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
@@ -98,11 +142,29 @@ mod tests {
         assert_eq!(translator.get("session.started"), "Session focus démarrée");
     }
 
+    #[test]
+    fn mode_names_are_translated_but_custom_names_are_kept() {
+        let english = Translator::new(Language::En);
+        let french = Translator::new(Language::Fr);
+
+        assert_eq!(english.mode_name(&FocusMode::AiAssisted), "AI-assisted");
+        assert_eq!(french.mode_name(&FocusMode::Review), "Revue de code");
+        assert_eq!(
+            french.mode_name(&FocusMode::from_stored("prompting")),
+            "Assisté par IA"
+        );
+        assert_eq!(
+            english.mode_name(&FocusMode::Custom("deep-work".to_string())),
+            "deep-work"
+        );
+    }
+
     #[test]
     fn translator_returns_key_for_missing_translation() {
         let translator = Translator::new(Language::En);
 
         assert_eq!(translator.get("nonexistent.key"), "nonexistent.key");
+        assert_eq!(translator.try_get("nonexistent.key"), None);
     }
 
     #[test]
@@ -120,4 +182,23 @@ mod tests {
         let result = translator.format("lang.current", &[("name", "Français"), ("code", "fr")]);
         assert_eq!(result, "Langue actuelle : Français (fr)");
     }
+
+    #[test]
+    fn suggestion_reasons_describe_their_evidence() {
+        let translator = Translator::new(Language::En);
+        let suggestion = DistractionSuggestion {
+            application_name: "telegram".to_string(),
+            short_burst_count: 0,
+            reason: SuggestionReason::QuotaOverrun {
+                seconds: 900,
+                quota_seconds: 600,
+            },
+            confidence: 75,
+        };
+
+        assert_eq!(
+            translator.suggestion_reason(&suggestion),
+            "15 min used, friction budget is 10 min"
+        );
+    }
 }
@@ -3,28 +3,55 @@
 //! Contains domain types and port definitions (traits) for the Flux application.
 //! This crate has no knowledge of infrastructure concerns.
 
+pub mod archive;
 pub mod config;
 pub mod domain;
+pub mod heartbeat;
 pub mod i18n;
 pub mod ports;
 pub mod secrets;
 pub mod state;
 
+pub use archive::{
+    ArchiveCompatibility, ArchiveError, ArchiveManifest, TableChecksum, ARCHIVE_DATABASE_FILE,
+    ARCHIVE_FORMAT_VERSION, ARCHIVE_MANIFEST_FILE,
+};
 pub use config::{
-    Config, ConfigError, DigestConfig, DistractionConfig, FocusConfig, GeneralConfig,
-    NotificationConfig, NotificationUrgency, Profile, TrayConfig,
+    diff_lines, BatteryConfig, CalendarConfig, Config, ConfigError, ConfigKey, ConfigScope,
+    ConfigValueKind, ConfigWriter, DiffLine, DigestConfig, DigestDeliveryConfig,
+    DistractionAlertMode, DistractionConfig, DistractionMatch, DistractionOverride,
+    DistractionRule, DistractionVerdict, ExperimentalConfig, FocusConfig, GeneralConfig,
+    GoalsConfig, HooksConfig, MetricsConfig, ModeColor, ModeOverride, ModeSettings, ModesConfig,
+    NotificationConfig, NotificationUrgency, PrivacyConfig, Profile, ProfileGeneralConfig,
+    SmtpDeliveryConfig, StartOptions, StorageConfig, SuggestionsConfig, SyncConfig, TrackingConfig,
+    TrayConfig, WebhookDeliveryConfig, WorkspaceConfig, WorkspaceDistractions, REDACTED_TITLE,
+    WORKSPACE_CONFIG_FILE,
 };
 pub use domain::{
-    AppUsage, DigestStats, DistractionSuggestion, FocusMode, Provider, ReviewAction, ReviewEvent,
-    Session, SessionId, SessionMetrics, SuggestionReason, SuggestionReport, WeekStats,
+    completion_rate, detect_project, is_project_application, latest_versions, Achievement,
+    AppUsage, AppUsageSegment, CalendarEvent, CheckInOutcome, CompletionBreakdown, DailyAppUsage,
+    DailyTarget, DigestInsight, DigestRegression, DigestReport, DigestStats, DistractionSuggestion,
+    FocusMode, InterruptionKind, InterruptionReasonCount, Provider, ReviewAction, ReviewEvent,
+    ScoreBreakdown, ScoreContribution, ScoreFactor, ScoreWeights, SegmentKind, Session,
+    SessionEndReason, SessionId, SessionInterruption, SessionMetrics, SessionNote, Streak,
+    SuggestionContext, SuggestionPipeline, SuggestionReason, SuggestionReport, SuggestionStrategy,
+    SuggestionThresholds, SyncMerge, SyncRecord, SyncedAppUsage, SyncedSegment, SyncedSession,
+    TagUsage, UnlockedAchievement, WeekStart, WeekStats, WeeklyModeTotal,
+    DEEP_FOCUS_MINIMUM_SECONDS, DISTRACTION_FREE_MINIMUM_SECONDS, PRODUCTIVE_WEEK_SESSIONS,
+    STREAK_MILESTONES,
 };
+pub use heartbeat::{Heartbeat, NotificationCapabilities, HEARTBEAT_INTERVAL_SECONDS};
 pub use i18n::{Language, Translator, UnsupportedLanguageError};
 pub use ports::{
-    AppTrackingRepository, AppTrackingRepositoryError, ReviewActivityGateway, ReviewGatewayError,
-    SessionMetricsRepository, SessionMetricsRepositoryError, SessionRepository,
-    SessionRepositoryError,
+    AchievementRepository, AchievementRepositoryError, AppTrackingRepository,
+    AppTrackingRepositoryError, CalendarGateway, CalendarGatewayError, DigestDeliveryError,
+    DigestDeliveryGateway, ReviewActivityGateway, ReviewGatewayError, SessionMetricsRepository,
+    SessionMetricsRepositoryError, SessionNoteRepository, SessionNoteRepositoryError,
+    SessionRepository, SessionRepositoryError, SyncGateway, SyncGatewayError, SyncRepository,
+    SyncRepositoryError, UsageRollupRepository, UsageRollupRepositoryError,
 };
 pub use secrets::{
-    resolve_github_credentials, resolve_gitlab_credentials, ProviderCredentials, SecretsError,
+    resolve_database_key, resolve_github_credentials, resolve_gitlab_credentials,
+    resolve_smtp_password, store_database_key, ProviderCredentials, SecretsError,
 };
 pub use state::AppState;
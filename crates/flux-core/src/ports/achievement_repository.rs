@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+use crate::domain::UnlockedAchievement;
+
+#[derive(Error, Debug)]
+pub enum AchievementRepositoryError {
+    #[error("erreur de persistance: {0}")]
+    Persistence(String),
+}
+
+pub trait AchievementRepository: Send + Sync {
+    fn unlock(&self, unlocked: &UnlockedAchievement) -> Result<bool, AchievementRepositoryError>;
+
+    fn find_all(&self) -> Result<Vec<UnlockedAchievement>, AchievementRepositoryError>;
+}
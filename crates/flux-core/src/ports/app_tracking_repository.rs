@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::domain::{AppUsage, SessionId};
+use crate::domain::{AppUsage, AppUsageSegment, SessionId};
 
 #[derive(Error, Debug)]
 pub enum AppTrackingRepositoryError {
@@ -11,6 +11,8 @@ pub enum AppTrackingRepositoryError {
 pub trait AppTrackingRepository: Send + Sync {
     fn save_or_update(&self, usage: &AppUsage) -> Result<(), AppTrackingRepositoryError>;
 
+    fn save_or_update_batch(&self, usages: &[AppUsage]) -> Result<(), AppTrackingRepositoryError>;
+
     fn find_by_session(
         &self,
         session_id: SessionId,
@@ -21,5 +23,13 @@ pub trait AppTrackingRepository: Send + Sync {
         session_ids: &[SessionId],
     ) -> Result<Vec<AppUsage>, AppTrackingRepositoryError>;
 
+    fn save_segments(&self, segments: &[AppUsageSegment])
+        -> Result<(), AppTrackingRepositoryError>;
+
+    fn find_segments_by_session(
+        &self,
+        session_id: SessionId,
+    ) -> Result<Vec<AppUsageSegment>, AppTrackingRepositoryError>;
+
     fn delete_by_session(&self, session_id: SessionId) -> Result<(), AppTrackingRepositoryError>;
 }
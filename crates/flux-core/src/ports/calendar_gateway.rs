@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::domain::CalendarEvent;
+
+#[derive(Error, Debug, Clone)]
+pub enum CalendarGatewayError {
+    #[error("erreur réseau: {message}")]
+    Network { message: String },
+
+    #[error("calendrier illisible: {message}")]
+    Parse { message: String },
+}
+
+#[async_trait]
+pub trait CalendarGateway: Send + Sync {
+    /// Meetings overlapping the window, sorted by start
+    async fn events_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CalendarEvent>, CalendarGatewayError>;
+
+    fn source_name(&self) -> &'static str;
+}
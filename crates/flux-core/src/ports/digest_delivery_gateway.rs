@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::DigestReport;
+
+#[derive(Error, Debug, Clone)]
+pub enum DigestDeliveryError {
+    #[error("erreur réseau: {message}")]
+    Network { message: String },
+
+    #[error("livraison refusée: {message}")]
+    Rejected { message: String },
+
+    #[error("configuration de livraison invalide: {message}")]
+    InvalidConfiguration { message: String },
+}
+
+#[async_trait]
+pub trait DigestDeliveryGateway: Send + Sync {
+    async fn deliver(&self, report: &DigestReport) -> Result<(), DigestDeliveryError>;
+
+    fn channel_name(&self) -> &'static str;
+}
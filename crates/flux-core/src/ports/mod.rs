@@ -1,9 +1,23 @@
+mod achievement_repository;
 mod app_tracking_repository;
+mod calendar_gateway;
+mod digest_delivery_gateway;
 mod review_activity_gateway;
 mod session_metrics_repository;
+mod session_note_repository;
 mod session_repository;
+mod sync_gateway;
+mod sync_repository;
+mod usage_rollup_repository;
 
+pub use achievement_repository::{AchievementRepository, AchievementRepositoryError};
 pub use app_tracking_repository::{AppTrackingRepository, AppTrackingRepositoryError};
+pub use calendar_gateway::{CalendarGateway, CalendarGatewayError};
+pub use digest_delivery_gateway::{DigestDeliveryError, DigestDeliveryGateway};
 pub use review_activity_gateway::{ReviewActivityGateway, ReviewGatewayError};
 pub use session_metrics_repository::{SessionMetricsRepository, SessionMetricsRepositoryError};
+pub use session_note_repository::{SessionNoteRepository, SessionNoteRepositoryError};
 pub use session_repository::{SessionRepository, SessionRepositoryError};
+pub use sync_gateway::{SyncGateway, SyncGatewayError};
+pub use sync_repository::{SyncRepository, SyncRepositoryError};
+pub use usage_rollup_repository::{UsageRollupRepository, UsageRollupRepositoryError};
@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+use crate::domain::{SessionId, SessionNote};
+
+#[derive(Error, Debug)]
+pub enum SessionNoteRepositoryError {
+    #[error("erreur de persistance: {0}")]
+    Persistence(String),
+}
+
+pub trait SessionNoteRepository: Send + Sync {
+    /// Stores the note, replacing any previous note of the same session
+    fn save(&self, note: &SessionNote) -> Result<(), SessionNoteRepositoryError>;
+
+    /// Removes the note of the session, if it has one
+    fn delete(&self, session_id: SessionId) -> Result<(), SessionNoteRepositoryError>;
+
+    fn find_by_sessions(
+        &self,
+        session_ids: &[SessionId],
+    ) -> Result<Vec<SessionNote>, SessionNoteRepositoryError>;
+}
@@ -1,7 +1,10 @@
 use chrono::{DateTime, Utc};
 use thiserror::Error;
 
-use crate::domain::{Session, SessionId};
+use crate::domain::{
+    InterruptionReasonCount, Session, SessionId, SessionInterruption, TagUsage, WeekStart,
+    WeeklyModeTotal,
+};
 
 #[derive(Error, Debug)]
 pub enum SessionRepositoryError {
@@ -24,6 +27,9 @@ pub trait SessionRepository: Send + Sync {
 
     fn find_active(&self) -> Result<Option<Session>, SessionRepositoryError>;
 
+    /// Most recently started session, whether it is still running or not
+    fn find_latest(&self) -> Result<Option<Session>, SessionRepositoryError>;
+
     fn find_completed_since(
         &self,
         since: DateTime<Utc>,
@@ -35,6 +41,26 @@ pub trait SessionRepository: Send + Sync {
         end: DateTime<Utc>,
     ) -> Result<Vec<Session>, SessionRepositoryError>;
 
+    fn weekly_mode_totals(
+        &self,
+        since: DateTime<Utc>,
+        week_start: WeekStart,
+    ) -> Result<Vec<WeeklyModeTotal>, SessionRepositoryError>;
+
+    /// Tags of ended sessions, the most focused on first
+    fn tag_usages(&self) -> Result<Vec<TagUsage>, SessionRepositoryError>;
+
+    fn record_interruption(
+        &self,
+        interruption: &SessionInterruption,
+    ) -> Result<(), SessionRepositoryError>;
+
+    /// Reasons given when pausing or stopping these sessions, the most frequent first
+    fn interruption_reasons(
+        &self,
+        session_ids: &[SessionId],
+    ) -> Result<Vec<InterruptionReasonCount>, SessionRepositoryError>;
+
     fn count_completed_sessions(&self) -> Result<u32, SessionRepositoryError>;
 
     fn clear_completed_sessions(&self) -> Result<u32, SessionRepositoryError>;
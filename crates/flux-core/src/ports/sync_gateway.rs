@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::SyncRecord;
+
+#[derive(Error, Debug, Clone)]
+pub enum SyncGatewayError {
+    #[error("synchronisation inaccessible: {message}")]
+    Unavailable { message: String },
+
+    #[error("données de synchronisation illisibles: {message}")]
+    Parse { message: String },
+}
+
+/// Where devices publish their session history for each other
+#[async_trait]
+pub trait SyncGateway: Send + Sync {
+    /// Newest version of each session published by the other devices
+    async fn pull(&self) -> Result<Vec<SyncRecord>, SyncGatewayError>;
+
+    /// Publishes the history of this device, replacing what it published before
+    async fn push(&self, records: &[SyncRecord]) -> Result<(), SyncGatewayError>;
+
+    fn target_name(&self) -> String;
+}
@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+use crate::domain::SyncRecord;
+
+#[derive(Error, Debug)]
+pub enum SyncRepositoryError {
+    #[error("erreur de persistance: {0}")]
+    Persistence(String),
+}
+
+/// Session history as exchanged with other devices
+pub trait SyncRepository: Send + Sync {
+    /// Ended sessions and the deletions recorded on this device
+    fn export(&self) -> Result<Vec<SyncRecord>, SyncRepositoryError>;
+
+    /// Stores versions received from another device, keeping their timestamps
+    /// so that they are not sent back as local changes
+    fn import(&self, records: &[SyncRecord]) -> Result<(), SyncRepositoryError>;
+}
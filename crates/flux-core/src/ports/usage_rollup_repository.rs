@@ -0,0 +1,30 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use thiserror::Error;
+
+use crate::domain::DailyAppUsage;
+
+#[derive(Error, Debug)]
+pub enum UsageRollupRepositoryError {
+    #[error("erreur de persistance: {0}")]
+    Persistence(String),
+}
+
+/// Per-day application totals kept alongside the raw usage rows
+pub trait UsageRollupRepository: Send + Sync {
+    /// Recomputes the rollups of `since` and later days, or of every day when None
+    fn refresh_rollups(
+        &self,
+        since: Option<NaiveDate>,
+    ) -> Result<usize, UsageRollupRepositoryError>;
+
+    fn find_rollups_since(
+        &self,
+        since: NaiveDate,
+    ) -> Result<Vec<DailyAppUsage>, UsageRollupRepositoryError>;
+
+    /// Most recent day with a rollup, None before the first backfill
+    fn last_rollup_day(&self) -> Result<Option<NaiveDate>, UsageRollupRepositoryError>;
+
+    /// When the rollups were last recomputed, None if they never were
+    fn last_refreshed_at(&self) -> Result<Option<DateTime<Utc>>, UsageRollupRepositoryError>;
+}
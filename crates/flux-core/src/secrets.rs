@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -17,6 +17,15 @@ pub enum SecretsError {
         #[from]
         source: toml::de::Error,
     },
+
+    #[error("erreur de parsing secrets.toml: {source}")]
+    Edit {
+        #[from]
+        source: toml_edit::TomlError,
+    },
+
+    #[error("erreur d'écriture du fichier secrets: {source}")]
+    Write { source: std::io::Error },
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +38,18 @@ pub struct ProviderCredentials {
 struct SecretsFile {
     gitlab: Option<ProviderSecrets>,
     github: Option<ProviderSecrets>,
+    smtp: Option<SmtpSecrets>,
+    database: Option<DatabaseSecrets>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SmtpSecrets {
+    password: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct DatabaseSecrets {
+    key: String,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -45,6 +66,159 @@ pub fn resolve_github_credentials() -> Result<ProviderCredentials, SecretsError>
     resolve_credentials("github", "FLUX_GITHUB_TOKEN", "FLUX_GITHUB_USER_ID")
 }
 
+pub fn resolve_smtp_password() -> Result<String, SecretsError> {
+    if let Ok(password) = std::env::var("FLUX_SMTP_PASSWORD") {
+        return Ok(password);
+    }
+
+    let not_found = || SecretsError::NotFound {
+        provider: "smtp".to_string(),
+    };
+
+    let path = secrets_path();
+    if !path.exists() {
+        return Err(not_found());
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    let secrets: SecretsFile = toml::from_str(&content)?;
+
+    secrets
+        .smtp
+        .map(|secrets| secrets.password)
+        .ok_or_else(not_found)
+}
+
+/// Key of the window titles stored in the database, as 64 hexadecimal digits
+pub fn resolve_database_key() -> Result<String, SecretsError> {
+    resolve_database_key_from(&secrets_path())
+}
+
+/// Keeps the key in `[database]` of secrets.toml, leaving the rest of the
+/// file as it was; the file is made readable by its owner only. When another
+/// process stored a key first, that one is kept and returned instead.
+pub fn store_database_key(key: &str) -> Result<String, SecretsError> {
+    store_database_key_in(&secrets_path(), key)
+}
+
+fn resolve_database_key_from(path: &Path) -> Result<String, SecretsError> {
+    if let Ok(key) = std::env::var("FLUX_DATABASE_KEY") {
+        return Ok(key);
+    }
+
+    let not_found = || SecretsError::NotFound {
+        provider: "database".to_string(),
+    };
+
+    if !path.exists() {
+        return Err(not_found());
+    }
+
+    let content = std::fs::read_to_string(path)?;
+    let secrets: SecretsFile = toml::from_str(&content)?;
+
+    secrets
+        .database
+        .map(|secrets| secrets.key)
+        .ok_or_else(not_found)
+}
+
+fn store_database_key_in(path: &Path, key: &str) -> Result<String, SecretsError> {
+    let write_error = |source| SecretsError::Write { source };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(write_error)?;
+    }
+    // Held until the function returns, so two processes creating the key at
+    // the same time end up with the same one
+    let _lock = SecretsLock::acquire(path.with_extension("lock")).map_err(write_error)?;
+
+    let content = if path.exists() {
+        std::fs::read_to_string(path)?
+    } else {
+        String::new()
+    };
+    let mut document: toml_edit::DocumentMut = content.parse()?;
+    if let Some(stored) = document
+        .get("database")
+        .and_then(|database| database.get("key"))
+        .and_then(|stored| stored.as_str())
+    {
+        return Ok(stored.to_string());
+    }
+    let database = document.entry("database").or_insert_with(toml_edit::table);
+    if !database.is_table_like() {
+        *database = toml_edit::table();
+    }
+    database["key"] = toml_edit::value(key);
+
+    write_private(path, &document.to_string()).map_err(write_error)?;
+    Ok(key.to_string())
+}
+
+/// Lock file created exclusively, and removed when dropped
+struct SecretsLock {
+    path: PathBuf,
+}
+
+impl SecretsLock {
+    /// Writing the secrets takes milliseconds: an older lock was left by a
+    /// process that stopped while holding it
+    const STALE_AFTER: std::time::Duration = std::time::Duration::from_secs(10);
+
+    fn acquire(path: PathBuf) -> std::io::Result<Self> {
+        loop {
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(Self { path }),
+                Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let stale = std::fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .map(|modified| modified.elapsed().unwrap_or_default() > Self::STALE_AFTER)
+                        .unwrap_or(false);
+                    if stale {
+                        let _ = std::fs::remove_file(&path);
+                    } else {
+                        std::thread::sleep(std::time::Duration::from_millis(10));
+                    }
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+impl Drop for SecretsLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Writes a file that only its owner can read: the content goes to a
+/// temporary file created with those permissions, which then replaces it
+fn write_private(path: &Path, content: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let temporary = path.with_extension("toml.tmp");
+    match std::fs::remove_file(&temporary) {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => return Err(error),
+        _ => {}
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&temporary)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+    std::fs::rename(&temporary, path)
+}
+
 fn resolve_credentials(
     provider: &str,
     token_env: &str,
@@ -122,4 +296,62 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn resolve_smtp_password_from_environment() {
+        env::set_var("FLUX_SMTP_PASSWORD", "app-password");
+
+        assert_eq!(resolve_smtp_password().unwrap(), "app-password");
+
+        env::remove_var("FLUX_SMTP_PASSWORD");
+    }
+
+    #[test]
+    fn database_key_is_added_to_the_existing_secrets() {
+        env::remove_var("FLUX_DATABASE_KEY");
+        let path = env::temp_dir().join(format!("flux-secrets-{}.toml", std::process::id()));
+        std::fs::write(&path, "# tokens\n[smtp]\npassword = \"app-password\"\n").unwrap();
+        assert!(matches!(
+            resolve_database_key_from(&path),
+            Err(SecretsError::NotFound { .. })
+        ));
+
+        assert_eq!(store_database_key_in(&path, "00ff").unwrap(), "00ff");
+
+        assert_eq!(resolve_database_key_from(&path).unwrap(), "00ff");
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# tokens\n[smtp]\npassword = \"app-password\""));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn only_the_first_database_key_stored_is_kept() {
+        let path = env::temp_dir().join(format!("flux-secrets-race-{}.toml", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let stored: Vec<String> = std::thread::scope(|scope| {
+            let writers: Vec<_> = ["00aa", "00bb", "00cc", "00dd"]
+                .into_iter()
+                .map(|key| scope.spawn(|| store_database_key_in(&path, key).unwrap()))
+                .collect();
+            writers
+                .into_iter()
+                .map(|writer| writer.join().unwrap())
+                .collect()
+        });
+
+        let kept = std::fs::read_to_string(&path).unwrap();
+        assert!(!path.with_extension("lock").exists());
+        for key in &stored {
+            assert_eq!(key, &stored[0]);
+        }
+        assert!(kept.contains(&format!("key = \"{}\"", stored[0])));
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -1,15 +1,20 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppState {
     pub active_profile: String,
+    /// Applications never suggested again as distractions
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub dismissed_suggestions: BTreeSet<String>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
         Self {
             active_profile: "default".to_string(),
+            dismissed_suggestions: BTreeSet::new(),
         }
     }
 }
@@ -43,6 +48,16 @@ impl AppState {
     pub fn set_active_profile(&mut self, name: &str) {
         self.active_profile = name.to_string();
     }
+
+    pub fn dismiss_suggestion(&mut self, application_name: &str) -> bool {
+        self.dismissed_suggestions
+            .insert(application_name.to_lowercase())
+    }
+
+    pub fn is_suggestion_dismissed(&self, application_name: &str) -> bool {
+        self.dismissed_suggestions
+            .contains(&application_name.to_lowercase())
+    }
 }
 
 #[cfg(test)]
@@ -61,4 +76,17 @@ mod tests {
         state.set_active_profile("deep_focus");
         assert_eq!(state.active_profile, "deep_focus");
     }
+
+    #[test]
+    fn dismissed_suggestions_survive_a_round_trip() {
+        let legacy: AppState = toml::from_str("active_profile = \"work\"").unwrap();
+        assert!(legacy.dismissed_suggestions.is_empty());
+
+        let mut state = legacy;
+        assert!(state.dismiss_suggestion("Spotify"));
+        let reloaded: AppState = toml::from_str(&toml::to_string_pretty(&state).unwrap()).unwrap();
+
+        assert_eq!(reloaded.active_profile, "work");
+        assert!(reloaded.is_suggestion_dismissed("spotify"));
+    }
 }
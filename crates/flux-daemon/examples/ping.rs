@@ -1,23 +1,21 @@
 #![cfg(unix)]
 
-use flux_protocol::{Request, Response};
-use interprocess::local_socket::{tokio::prelude::*, GenericFilePath};
+use flux_protocol::{decode, encode, socket, Request, Response};
+use interprocess::local_socket::tokio::prelude::*;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let uid = unsafe { libc::getuid() };
-    let socket_path = format!("/run/user/{}/flux.sock", uid);
+    let socket_path = socket::socket_path();
 
-    println!("Connexion à {}...", socket_path);
+    println!("Connexion à {}...", socket_path.display());
 
-    let mut stream = interprocess::local_socket::tokio::Stream::connect(
-        socket_path.to_fs_name::<GenericFilePath>()?,
-    )
-    .await?;
+    let mut stream =
+        interprocess::local_socket::tokio::Stream::connect(socket::socket_name(&socket_path)?)
+            .await?;
 
     let request = Request::Ping;
-    let payload = bincode::serialize(&request)?;
+    let payload = encode(&request)?;
     let length = (payload.len() as u32).to_le_bytes();
 
     stream.write_all(&length).await?;
@@ -33,7 +31,7 @@ async fn main() -> anyhow::Result<()> {
     let mut response_buf = vec![0u8; response_len];
     stream.read_exact(&mut response_buf).await?;
 
-    let response: Response = bincode::deserialize(&response_buf)?;
+    let response: Response = decode(&response_buf)?;
     println!("← Reçu: {:?}", response);
 
     match response {
@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+use flux_core::{
+    Achievement, AchievementRepository, Session, SessionId, SessionRepository, Streak,
+    UnlockedAchievement,
+};
+
+use super::NotifierHandle;
+use crate::storage;
+
+pub enum AchievementsMessage {
+    SessionCompleted {
+        session_id: Option<SessionId>,
+        duration_seconds: i64,
+    },
+    SessionTracked {
+        session_id: SessionId,
+        tracked_seconds: i64,
+        distraction_seconds: i64,
+    },
+}
+
+#[derive(Clone)]
+pub struct AchievementsHandle {
+    sender: mpsc::Sender<AchievementsMessage>,
+}
+
+impl AchievementsHandle {
+    pub fn send_session_completed(&self, session_id: Option<SessionId>, duration_seconds: i64) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender
+                .send(AchievementsMessage::SessionCompleted {
+                    session_id,
+                    duration_seconds,
+                })
+                .await
+            {
+                error!(%error, "failed to send session completed to achievements");
+            }
+        });
+    }
+
+    pub fn send_session_tracked(
+        &self,
+        session_id: SessionId,
+        tracked_seconds: i64,
+        distraction_seconds: i64,
+    ) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender
+                .send(AchievementsMessage::SessionTracked {
+                    session_id,
+                    tracked_seconds,
+                    distraction_seconds,
+                })
+                .await
+            {
+                error!(%error, "failed to send session tracked to achievements");
+            }
+        });
+    }
+}
+
+pub struct AchievementsActor {
+    receiver: mpsc::Receiver<AchievementsMessage>,
+    session_repository: Arc<dyn SessionRepository>,
+    achievement_repository: Arc<dyn AchievementRepository>,
+    notifier: NotifierHandle,
+}
+
+impl AchievementsActor {
+    pub fn new(
+        session_repository: Arc<dyn SessionRepository>,
+        achievement_repository: Arc<dyn AchievementRepository>,
+        notifier: NotifierHandle,
+    ) -> (Self, AchievementsHandle) {
+        let (sender, receiver) = mpsc::channel(16);
+
+        let actor = Self {
+            receiver,
+            session_repository,
+            achievement_repository,
+            notifier,
+        };
+
+        (actor, AchievementsHandle { sender })
+    }
+
+    pub async fn run(mut self) {
+        info!("achievements actor started");
+
+        while let Some(message) = self.receiver.recv().await {
+            match message {
+                AchievementsMessage::SessionCompleted {
+                    session_id,
+                    duration_seconds,
+                } => {
+                    self.check_streak_milestone().await;
+                    self.check_completed_session(session_id, duration_seconds)
+                        .await;
+                }
+                AchievementsMessage::SessionTracked {
+                    session_id,
+                    tracked_seconds,
+                    distraction_seconds,
+                } => {
+                    let earned =
+                        Achievement::for_tracked_session(tracked_seconds, distraction_seconds);
+                    self.unlock(&earned, Some(session_id)).await;
+                }
+            }
+        }
+
+        debug!("achievements actor stopped");
+    }
+
+    async fn check_streak_milestone(&self) {
+        let sessions = match storage::blocking(&self.session_repository, |repository| {
+            repository.find_completed_since(DateTime::UNIX_EPOCH)
+        })
+        .await
+        {
+            Ok(sessions) => sessions,
+            Err(error) => {
+                warn!(%error, "failed to fetch sessions for streak");
+                return;
+            }
+        };
+
+        if let Some(days) = reached_milestone(&sessions, Local::now().date_naive()) {
+            info!(days, "streak milestone reached");
+            self.notifier.send_streak_milestone(days);
+        }
+    }
+
+    async fn check_completed_session(&self, session_id: Option<SessionId>, duration_seconds: i64) {
+        let since = Utc::now() - Duration::days(7);
+        let sessions_this_week =
+            match storage::blocking(&self.session_repository, move |repository| {
+                repository.find_completed_since(since)
+            })
+            .await
+            {
+                Ok(sessions) => sessions.len(),
+                Err(error) => {
+                    warn!(%error, "failed to fetch sessions for achievements");
+                    return;
+                }
+            };
+
+        let earned = Achievement::for_completed_session(duration_seconds, sessions_this_week);
+        self.unlock(&earned, session_id).await;
+    }
+
+    async fn unlock(&self, earned: &[Achievement], session_id: Option<SessionId>) {
+        for achievement in earned {
+            let unlocked = UnlockedAchievement::now(*achievement, session_id);
+            match storage::blocking(&self.achievement_repository, move |repository| {
+                repository.unlock(&unlocked)
+            })
+            .await
+            {
+                Ok(true) => {
+                    info!(achievement = achievement.id(), "achievement unlocked");
+                    self.notifier.send_achievement_unlocked(*achievement);
+                }
+                Ok(false) => {}
+                Err(error) => warn!(%error, "failed to save achievement"),
+            }
+        }
+    }
+}
+
+fn reached_milestone(sessions: &[Session], today: NaiveDate) -> Option<u32> {
+    let sessions_today = sessions
+        .iter()
+        .filter(|session| session.started_at.with_timezone(&Local).date_naive() == today)
+        .count();
+
+    if sessions_today != 1 {
+        return None;
+    }
+
+    Streak::from_sessions(sessions, today).milestone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use flux_core::FocusMode;
+
+    fn completed_session_on(day: NaiveDate) -> Session {
+        let mut session = Session::start(FocusMode::AiAssisted);
+        session.started_at = Local
+            .from_local_datetime(&day.and_hms_opt(10, 0, 0).unwrap())
+            .unwrap()
+            .into();
+        session.ended_at = Some(session.started_at + Duration::minutes(25));
+        session.duration_seconds = Some(1500);
+        session
+    }
+
+    #[test]
+    fn milestone_is_reported_by_first_session_of_the_day() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let mut sessions: Vec<Session> = (0..7)
+            .map(|offset| completed_session_on(today - Duration::days(offset)))
+            .collect();
+
+        assert_eq!(reached_milestone(&sessions, today), Some(7));
+
+        sessions.push(completed_session_on(today));
+        assert_eq!(reached_milestone(&sessions, today), None);
+    }
+
+    #[test]
+    fn no_milestone_outside_thresholds() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let sessions: Vec<Session> = (0..5)
+            .map(|offset| completed_session_on(today - Duration::days(offset)))
+            .collect();
+
+        assert_eq!(reached_milestone(&sessions, today), None);
+    }
+}
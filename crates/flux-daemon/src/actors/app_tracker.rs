@@ -1,32 +1,50 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 
-use tokio::sync::mpsc;
+use chrono::{DateTime, Utc};
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::{Instant, MissedTickBehavior};
 use tracing::{debug, error, info, trace, warn};
 
 use flux_core::{
-    AppTrackingRepository, AppUsage, Config, DistractionConfig, FocusMode, SessionId,
-    SessionMetrics, SessionMetricsRepository, SuggestionReport, Translator,
+    detect_project, AppState, AppTrackingRepository, AppUsageSegment, CheckInOutcome, Config,
+    DistractionConfig, FocusMode, PrivacyConfig, SegmentKind, SessionId, SessionMetrics,
+    SessionMetricsRepository, SuggestionContext, SuggestionPipeline, SuggestionReport,
+    SuggestionThresholds, TrackingConfig, Translator,
 };
 
 use super::notifier::FrictionResponse;
-use super::NotifierHandle;
+use super::{AchievementsHandle, Mailbox, NotifierHandle};
 use tokio::sync::oneshot;
 
-#[cfg(target_os = "linux")]
-use crate::window::{WindowDetector, X11WindowDetector};
+use crate::input_activity::{InputActivitySampler, InputActivityTally};
+use crate::storage;
+use crate::window::{create_window_detector, WindowDetector, WindowInfo};
 
-const POLLING_INTERVAL_SECONDS: u64 = 5;
+/// Window polling interval outside the battery profile
+pub const POLLING_INTERVAL_SECONDS: u64 = 5;
+
+/// Between two polls, the focused window is probed this often to date the
+/// changes of focus; the slower battery profile does not probe
+const FOCUS_PROBE_SECONDS: u64 = 1;
 
 pub enum AppTrackerMessage {
     Started {
         session_id: SessionId,
         mode: FocusMode,
+        distraction_apps: Vec<String>,
     },
     Ended,
     Paused,
     Resumed,
+    CheckIn,
+    CheckInAnswered {
+        outcome: CheckInOutcome,
+    },
+    PollingInterval {
+        seconds: u64,
+    },
 }
 
 #[derive(Clone)]
@@ -35,11 +53,20 @@ pub struct AppTrackerHandle {
 }
 
 impl AppTrackerHandle {
-    pub fn send_session_started(&self, session_id: SessionId, mode: FocusMode) {
+    pub fn send_session_started(
+        &self,
+        session_id: SessionId,
+        mode: FocusMode,
+        distraction_apps: Vec<String>,
+    ) {
         let sender = self.sender.clone();
         tokio::spawn(async move {
             if let Err(error) = sender
-                .send(AppTrackerMessage::Started { session_id, mode })
+                .send(AppTrackerMessage::Started {
+                    session_id,
+                    mode,
+                    distraction_apps,
+                })
                 .await
             {
                 error!(%error, "failed to send session started message to app tracker");
@@ -47,6 +74,32 @@ impl AppTrackerHandle {
         });
     }
 
+    /// Announces a running session to a restarted tracker, in one task so that
+    /// the pause follows the start
+    pub fn send_session_restored(
+        &self,
+        session_id: SessionId,
+        mode: FocusMode,
+        distraction_apps: Vec<String>,
+        paused: bool,
+    ) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            let started = AppTrackerMessage::Started {
+                session_id,
+                mode,
+                distraction_apps,
+            };
+            let sent = match sender.send(started).await {
+                Ok(()) if paused => sender.send(AppTrackerMessage::Paused).await,
+                sent => sent,
+            };
+            if let Err(error) = sent {
+                error!(%error, "failed to send restored session to app tracker");
+            }
+        });
+    }
+
     pub fn send_session_ended(&self) {
         let sender = self.sender.clone();
         tokio::spawn(async move {
@@ -73,67 +126,258 @@ impl AppTrackerHandle {
             }
         });
     }
+
+    pub fn send_polling_interval(&self, seconds: u64) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender
+                .send(AppTrackerMessage::PollingInterval { seconds })
+                .await
+            {
+                error!(%error, "failed to send polling interval to app tracker");
+            }
+        });
+    }
+
+    pub fn send_check_in(&self) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender.send(AppTrackerMessage::CheckIn).await {
+                error!(%error, "failed to send check-in message to app tracker");
+            }
+        });
+    }
+
+    pub fn send_check_in_answered(&self, outcome: CheckInOutcome) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender
+                .send(AppTrackerMessage::CheckInAnswered { outcome })
+                .await
+            {
+                error!(%error, "failed to send check-in outcome to app tracker");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+impl AppTrackerHandle {
+    pub(crate) fn detached() -> (Self, mpsc::Receiver<AppTrackerMessage>) {
+        let (sender, receiver) = mpsc::channel(8);
+        (Self { sender }, receiver)
+    }
 }
 
 const SHORT_BURST_THRESHOLD_SECONDS: u64 = 15;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct WindowKey {
-    app_name: String,
-    title: String,
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DistractionIncident {
+    application_name: String,
+    seconds: u64,
 }
 
 struct TrackerState {
     session_id: SessionId,
     mode: FocusMode,
     paused: bool,
-    accumulated: HashMap<WindowKey, i64>,
     current_distraction: Option<String>,
     distraction_consecutive_seconds: u64,
     distraction_alert_sent: bool,
+    distraction_incidents: Vec<DistractionIncident>,
     last_app: Option<String>,
     app_consecutive_seconds: u64,
     short_burst_count: HashMap<String, u32>,
     context_switch_count: u32,
+    project_seconds: HashMap<String, i64>,
+    input_activity: InputActivityTally,
+    tracked_seconds: i64,
+    distraction_seconds: i64,
     current_friction_app: Option<String>,
     friction_consecutive_seconds: u64,
     friction_reminder_count: u32,
     friction_response_pending: Option<oneshot::Receiver<FrictionResponse>>,
+    segments: Vec<AppUsageSegment>,
+    paused_at: Option<DateTime<Utc>>,
+    pause_count: u32,
+    /// Extra apps flagged for this session only, lowercase like the configured ones
+    distraction_apps: Vec<String>,
+    focus: FocusTimeline,
+}
+
+/// Part of a poll interval during which one window had the focus, in
+/// seconds from the start of the interval
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FocusSpan {
+    window: WindowInfo,
+    from: u64,
+    to: u64,
+}
+
+/// Focus changes seen since the last poll, so that the poll splits its
+/// interval between the windows instead of crediting all of it to the one
+/// focused at poll time
+#[derive(Debug, Default)]
+struct FocusTimeline {
+    /// Window the last poll credited
+    credited: Option<WindowInfo>,
+    /// Windows that took the focus since, with the moment they are estimated to have
+    changes: Vec<(Instant, WindowInfo)>,
+    last_seen_at: Option<Instant>,
+}
+
+impl FocusTimeline {
+    /// Detectors report the focused window, not when it got the focus: a
+    /// change is dated halfway between the last moment the previous window
+    /// was seen and the first moment the new one is
+    fn observe(&mut self, window: WindowInfo, at: Instant) {
+        let latest = self
+            .changes
+            .last()
+            .map(|(_, window)| window)
+            .or(self.credited.as_ref());
+        if latest != Some(&window) {
+            let changed_at = match self.last_seen_at {
+                Some(seen_at) if latest.is_some() => {
+                    seen_at + at.saturating_duration_since(seen_at) / 2
+                }
+                _ => at,
+            };
+            self.changes.push((changed_at, window));
+        }
+        self.last_seen_at = Some(at);
+    }
+
+    /// Splits the `seconds` ended at `now` between the windows focused
+    /// during them, in whole seconds. The first window ever seen is credited
+    /// the whole interval, as nothing is known of what came before it.
+    fn split(&mut self, seconds: u64, now: Instant) -> Vec<FocusSpan> {
+        let since = now.checked_sub(Duration::from_secs(seconds)).unwrap_or(now);
+        let offset = |at: Instant| {
+            let milliseconds = at.saturating_duration_since(since).as_millis() as u64;
+            ((milliseconds + 500) / 1000).min(seconds)
+        };
+
+        let mut spans = Vec::new();
+        let mut window = self.credited.take();
+        let mut from = 0;
+        for (changed_at, next) in self.changes.drain(..) {
+            if let Some(previous) = window.replace(next) {
+                let to = offset(changed_at).max(from);
+                if to > from {
+                    spans.push(FocusSpan {
+                        window: previous,
+                        from,
+                        to,
+                    });
+                }
+                from = to;
+            }
+        }
+        if let Some(window) = window {
+            if seconds > from {
+                spans.push(FocusSpan {
+                    window: window.clone(),
+                    from,
+                    to: seconds,
+                });
+            }
+            self.credited = Some(window);
+        }
+        spans
+    }
+}
+
+impl TrackerState {
+    fn record_segment(&mut self, segment: AppUsageSegment) {
+        let absorbed = self
+            .segments
+            .last_mut()
+            .is_some_and(|last| last.absorb(&segment));
+        if !absorbed {
+            self.segments.push(segment);
+        }
+    }
+
+    /// Keeps the current distraction stretch for the end-of-session summary once it crossed the alert threshold
+    fn close_distraction_stretch(&mut self, distraction_config: &DistractionConfig) {
+        if let Some(application_name) = self.current_distraction.take() {
+            if self.distraction_consecutive_seconds
+                >= distraction_config.alert_after_for(&application_name)
+            {
+                self.distraction_incidents.push(DistractionIncident {
+                    application_name,
+                    seconds: self.distraction_consecutive_seconds,
+                });
+            }
+        }
+        self.distraction_consecutive_seconds = 0;
+        self.distraction_alert_sent = false;
+    }
+
+    fn is_session_distraction(&self, application_name: &str) -> bool {
+        let lowercase = application_name.to_lowercase();
+        self.distraction_apps
+            .iter()
+            .any(|app| lowercase.contains(app.as_str()))
+    }
+
+    fn close_pause(&mut self, now: DateTime<Utc>) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.segments
+                .push(AppUsageSegment::pause(self.session_id, paused_at, now));
+        }
+    }
 }
 
 pub struct AppTrackerActor {
-    receiver: mpsc::Receiver<AppTrackerMessage>,
+    receiver: Mailbox<AppTrackerMessage>,
     repository: Arc<dyn AppTrackingRepository>,
     metrics_repository: Arc<dyn SessionMetricsRepository>,
     distraction_config: DistractionConfig,
+    privacy: PrivacyConfig,
+    tracking: TrackingConfig,
+    suggestion_thresholds: SuggestionThresholds,
     notifier: NotifierHandle,
-    #[cfg(target_os = "linux")]
-    detector: Option<X11WindowDetector>,
+    achievements: Option<AchievementsHandle>,
+    detector: Option<Box<dyn WindowDetector>>,
+    input_sampler: Option<InputActivitySampler>,
+    save_suggestions: bool,
+    polling_interval_seconds: u64,
     state: Option<TrackerState>,
 }
 
 impl AppTrackerActor {
-    #[cfg(target_os = "linux")]
     pub fn new(
         repository: Arc<dyn AppTrackingRepository>,
         metrics_repository: Arc<dyn SessionMetricsRepository>,
         distraction_config: DistractionConfig,
+        privacy: PrivacyConfig,
         notifier: NotifierHandle,
+        achievements: Option<AchievementsHandle>,
+        input_activity: bool,
     ) -> (Self, AppTrackerHandle) {
         let (sender, receiver) = mpsc::channel(32);
 
-        let detector = X11WindowDetector::new();
+        let detector = create_window_detector();
         if detector.is_none() {
-            warn!("X11 window detection not available, app tracking will be disabled");
+            warn!("window detection not available, app tracking will be disabled");
         }
 
         let actor = Self {
-            receiver,
+            receiver: Arc::new(Mutex::new(receiver)),
             repository,
             metrics_repository,
             distraction_config,
+            privacy,
+            tracking: TrackingConfig::default(),
+            suggestion_thresholds: SuggestionThresholds::default(),
             notifier,
+            achievements,
             detector,
+            input_sampler: input_activity.then(InputActivitySampler::new).flatten(),
+            save_suggestions: true,
+            polling_interval_seconds: POLLING_INTERVAL_SECONDS,
             state: None,
         };
 
@@ -142,43 +386,93 @@ impl AppTrackerActor {
         (actor, handle)
     }
 
-    #[cfg(not(target_os = "linux"))]
-    pub fn new(
-        repository: Arc<dyn AppTrackingRepository>,
-        metrics_repository: Arc<dyn SessionMetricsRepository>,
-        distraction_config: DistractionConfig,
-        notifier: NotifierHandle,
-    ) -> (Self, AppTrackerHandle) {
-        let (sender, receiver) = mpsc::channel(32);
+    pub fn with_tracking(mut self, tracking: TrackingConfig) -> Self {
+        self.tracking = tracking;
+        self
+    }
 
-        let actor = Self {
-            receiver,
-            repository,
-            metrics_repository,
-            distraction_config,
-            notifier,
-            state: None,
-        };
+    pub fn with_suggestion_thresholds(mut self, thresholds: SuggestionThresholds) -> Self {
+        self.suggestion_thresholds = thresholds;
+        self
+    }
 
-        let handle = AppTrackerHandle { sender };
+    /// Follows the windows of a `--simulate` script instead of the desktop and
+    /// leaves the user's files alone: no input sampling, no suggestions report
+    #[cfg(any(test, feature = "simulate"))]
+    pub fn simulated(mut self, detector: Box<dyn WindowDetector>) -> Self {
+        self.detector = Some(detector);
+        self.input_sampler = None;
+        self.save_suggestions = false;
+        self
+    }
 
-        (actor, handle)
+    pub fn window_backend(&self) -> Option<&'static str> {
+        self.detector
+            .as_ref()
+            .map(|detector| detector.backend_name())
+    }
+
+    /// Builds a fresh actor on the same channel, for the supervisor to replace a
+    /// crashed one. It knows no session until the timer announces the running
+    /// one again (`TimerHandle::resend_tracking`).
+    pub fn restarter(&self) -> impl Fn() -> Self + Send + 'static {
+        let receiver = Arc::clone(&self.receiver);
+        let repository = Arc::clone(&self.repository);
+        let metrics_repository = Arc::clone(&self.metrics_repository);
+        let distraction_config = self.distraction_config.clone();
+        let privacy = self.privacy.clone();
+        let tracking = self.tracking.clone();
+        let suggestion_thresholds = self.suggestion_thresholds;
+        let notifier = self.notifier.clone();
+        let achievements = self.achievements.clone();
+        let input_activity = self.input_sampler.is_some();
+        let save_suggestions = self.save_suggestions;
+        move || Self {
+            receiver: Arc::clone(&receiver),
+            repository: Arc::clone(&repository),
+            metrics_repository: Arc::clone(&metrics_repository),
+            distraction_config: distraction_config.clone(),
+            privacy: privacy.clone(),
+            tracking: tracking.clone(),
+            suggestion_thresholds,
+            notifier: notifier.clone(),
+            achievements: achievements.clone(),
+            detector: create_window_detector(),
+            input_sampler: input_activity.then(InputActivitySampler::new).flatten(),
+            save_suggestions,
+            polling_interval_seconds: POLLING_INTERVAL_SECONDS,
+            state: None,
+        }
     }
 
     pub async fn run(mut self) {
         info!("app tracker actor started");
 
+        let mailbox = Arc::clone(&self.receiver);
+        let mut receiver = mailbox.lock().await;
+
+        let mut polling_interval_seconds = self.polling_interval_seconds;
         let mut poll_interval =
-            tokio::time::interval(Duration::from_secs(POLLING_INTERVAL_SECONDS));
+            tokio::time::interval(Duration::from_secs(polling_interval_seconds));
+        let mut probe_interval = tokio::time::interval(Duration::from_secs(FOCUS_PROBE_SECONDS));
+        probe_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
         loop {
             tokio::select! {
-                Some(message) = self.receiver.recv() => {
-                    self.handle_message(message);
+                Some(message) = receiver.recv() => {
+                    self.handle_message(message).await;
+                    if self.polling_interval_seconds != polling_interval_seconds {
+                        polling_interval_seconds = self.polling_interval_seconds;
+                        poll_interval = tokio::time::interval(Duration::from_secs(polling_interval_seconds));
+                        info!(seconds = polling_interval_seconds, "window polling interval changed");
+                    }
                 }
                 _ = poll_interval.tick() => {
                     self.poll_active_window();
                 }
+                _ = probe_interval.tick(), if polling_interval_seconds <= POLLING_INTERVAL_SECONDS => {
+                    self.probe_active_window();
+                }
                 else => break,
             }
         }
@@ -186,33 +480,58 @@ impl AppTrackerActor {
         debug!("app tracker actor stopped");
     }
 
-    fn handle_message(&mut self, message: AppTrackerMessage) {
+    async fn handle_message(&mut self, message: AppTrackerMessage) {
         match message {
-            AppTrackerMessage::Started { session_id, mode } => {
+            AppTrackerMessage::Started {
+                session_id,
+                mode,
+                distraction_apps,
+            } => {
                 debug!(session_id, ?mode, "app tracking started for session");
                 self.state = Some(TrackerState {
                     session_id,
                     mode,
                     paused: false,
-                    accumulated: HashMap::new(),
                     current_distraction: None,
                     distraction_consecutive_seconds: 0,
                     distraction_alert_sent: false,
+                    distraction_incidents: Vec::new(),
                     last_app: None,
                     app_consecutive_seconds: 0,
                     short_burst_count: HashMap::new(),
                     context_switch_count: 0,
+                    project_seconds: HashMap::new(),
+                    input_activity: InputActivityTally::default(),
+                    tracked_seconds: 0,
+                    distraction_seconds: 0,
                     current_friction_app: None,
                     friction_consecutive_seconds: 0,
                     friction_reminder_count: 0,
                     friction_response_pending: None,
+                    segments: Vec::new(),
+                    paused_at: None,
+                    pause_count: 0,
+                    distraction_apps,
+                    focus: FocusTimeline::default(),
                 });
             }
             AppTrackerMessage::Ended => {
-                if let Some(state) = self.state.take() {
-                    Self::flush_to_repository(&self.repository, &state);
-                    self.save_metrics(&state);
-                    self.generate_suggestions(&state);
+                if let Some(mut state) = self.state.take() {
+                    state.close_pause(Utc::now());
+                    state.close_distraction_stretch(&self.distraction_config);
+                    Self::flush_to_repository(&self.repository, &state).await;
+                    self.save_metrics(&state).await;
+                    self.send_distraction_summary(&state);
+                    if self.save_suggestions {
+                        self.generate_suggestions(&state).await;
+                    }
+                    if let Some(ref achievements) = self.achievements {
+                        achievements.send_session_tracked(
+                            state.session_id,
+                            state.tracked_seconds,
+                            state.distraction_seconds,
+                        );
+                    }
                     debug!(
                         session_id = state.session_id,
                         "app tracking ended for session"
@@ -222,13 +541,14 @@ impl AppTrackerActor {
             AppTrackerMessage::Paused => {
                 if let Some(mut state) = self.state.take() {
                     state.paused = true;
-                    Self::flush_to_repository(&self.repository, &state);
-                    state.accumulated.clear();
-                    state.current_distraction = None;
-                    state.distraction_consecutive_seconds = 0;
-                    state.distraction_alert_sent = false;
+                    state.paused_at = Some(Utc::now());
+                    state.pause_count += 1;
+                    Self::flush_to_repository(&self.repository, &state).await;
+                    state.segments.clear();
+                    state.close_distraction_stretch(&self.distraction_config);
                     state.last_app = None;
                     state.app_consecutive_seconds = 0;
+                    state.focus = FocusTimeline::default();
                     self.state = Some(state);
                     debug!("app tracking paused");
                 }
@@ -236,13 +556,33 @@ impl AppTrackerActor {
             AppTrackerMessage::Resumed => {
                 if let Some(ref mut state) = self.state {
                     state.paused = false;
+                    state.close_pause(Utc::now());
                     debug!("app tracking resumed");
                 }
             }
+            AppTrackerMessage::CheckIn => {
+                if let Some(ref mut state) = self.state {
+                    state
+                        .segments
+                        .push(AppUsageSegment::check_in(state.session_id, Utc::now()));
+                }
+            }
+            AppTrackerMessage::CheckInAnswered { outcome } => {
+                let pending = self.state.as_mut().and_then(|state| {
+                    state.segments.iter_mut().rev().find(|segment| {
+                        segment.kind == SegmentKind::CheckIn && segment.check_in_outcome.is_none()
+                    })
+                });
+                if let Some(check_in) = pending {
+                    check_in.check_in_outcome = Some(outcome);
+                }
+            }
+            AppTrackerMessage::PollingInterval { seconds } => {
+                self.polling_interval_seconds = seconds.max(1);
+            }
         }
     }
 
-    #[cfg(target_os = "linux")]
     fn poll_active_window(&mut self) {
         let Some(ref mut state) = self.state else {
             return;
@@ -252,35 +592,101 @@ impl AppTrackerActor {
             return;
         }
 
+        if let Some(active) = self
+            .input_sampler
+            .as_mut()
+            .and_then(InputActivitySampler::sample)
+        {
+            state.input_activity.record(active);
+        }
+
+        let Some(ref detector) = self.detector else {
+            return;
+        };
+
+        let Some(window_info) = detector.get_active_window_info(&self.tracking) else {
+            return;
+        };
+
+        let now = Instant::now();
+        state.focus.observe(window_info, now);
+        let spans = state.focus.split(self.polling_interval_seconds, now);
+        let ended_at = Utc::now();
+        for span in spans {
+            self.credit_focus(span, ended_at);
+        }
+    }
+
+    /// Notes which window has the focus between two polls, without crediting any time
+    fn probe_active_window(&mut self) {
+        let Some(ref mut state) = self.state else {
+            return;
+        };
+
+        if state.paused {
+            return;
+        }
+
         let Some(ref detector) = self.detector else {
             return;
         };
 
-        let Some(window_info) = detector.get_active_window_info() else {
+        if let Some(window_info) = detector.get_active_window_info(&self.tracking) {
+            state.focus.observe(window_info, Instant::now());
+        }
+    }
+
+    /// `interval_ended_at` is the wall-clock end of the poll interval the span belongs to
+    fn credit_focus(&mut self, span: FocusSpan, interval_ended_at: DateTime<Utc>) {
+        let Some(ref mut state) = self.state else {
             return;
         };
 
-        let application_name = &window_info.app_name;
-        let window_title = window_info.title_or_empty();
+        let seconds = span.to - span.from;
+        let application_name = &span.window.app_name;
+        let window_title = self.privacy.redact_title(span.window.title_or_empty());
 
         trace!(
             application_name = %application_name,
             window_title = %window_title,
+            seconds,
             "tracking active window"
         );
 
-        let key = WindowKey {
-            app_name: application_name.clone(),
-            title: window_title.to_string(),
+        state.tracked_seconds += seconds as i64;
+
+        if let Some(project) = detect_project(application_name, window_title) {
+            *state.project_seconds.entry(project).or_insert(0) += seconds as i64;
+        }
+
+        let is_distraction = self.distraction_config.is_distraction(application_name)
+            || self.distraction_config.is_title_distraction(window_title)
+            || state.is_session_distraction(application_name);
+        let before_end = |offset: u64| {
+            interval_ended_at
+                - chrono::Duration::seconds((self.polling_interval_seconds - offset) as i64)
         };
-        *state.accumulated.entry(key).or_insert(0) += POLLING_INTERVAL_SECONDS as i64;
+        state.record_segment(
+            AppUsageSegment::new(
+                state.session_id,
+                if is_distraction {
+                    SegmentKind::Distraction
+                } else {
+                    SegmentKind::Focus
+                },
+                application_name.clone(),
+                before_end(span.from),
+                before_end(span.to),
+            )
+            .with_window_title(window_title.to_string()),
+        );
 
-        self.track_context_switch(application_name);
-        self.track_distraction(application_name, window_title);
-        self.track_friction(application_name);
+        self.track_context_switch(application_name, seconds);
+        self.track_distraction(application_name, window_title, seconds);
+        self.track_friction(application_name, seconds);
     }
 
-    fn track_context_switch(&mut self, application_name: &str) {
+    fn track_context_switch(&mut self, application_name: &str, seconds: u64) {
         let Some(ref mut state) = self.state else {
             return;
         };
@@ -292,7 +698,7 @@ impl AppTrackerActor {
             .unwrap_or(false);
 
         if is_same_app {
-            state.app_consecutive_seconds += POLLING_INTERVAL_SECONDS;
+            state.app_consecutive_seconds += seconds;
         } else {
             if let Some(ref previous_app) = state.last_app {
                 let both_whitelisted = self.distraction_config.is_whitelisted(previous_app)
@@ -319,19 +725,22 @@ impl AppTrackerActor {
             }
 
             state.last_app = Some(application_name.to_string());
-            state.app_consecutive_seconds = POLLING_INTERVAL_SECONDS;
+            state.app_consecutive_seconds = seconds;
         }
     }
 
-    fn track_distraction(&mut self, application_name: &str, window_title: &str) {
+    fn track_distraction(&mut self, application_name: &str, window_title: &str, seconds: u64) {
         let Some(ref mut state) = self.state else {
             return;
         };
 
         let is_distraction = self.distraction_config.is_distraction(application_name)
-            || self.distraction_config.is_title_distraction(window_title);
+            || self.distraction_config.is_title_distraction(window_title)
+            || state.is_session_distraction(application_name);
 
         if is_distraction {
+            state.distraction_seconds += seconds as i64;
+
             let same_distraction = state
                 .current_distraction
                 .as_ref()
@@ -339,23 +748,23 @@ impl AppTrackerActor {
                 .unwrap_or(false);
 
             if same_distraction {
-                state.distraction_consecutive_seconds += POLLING_INTERVAL_SECONDS;
+                state.distraction_consecutive_seconds += seconds;
             } else {
+                state.close_distraction_stretch(&self.distraction_config);
                 state.current_distraction = Some(application_name.to_string());
-                state.distraction_consecutive_seconds = POLLING_INTERVAL_SECONDS;
-                state.distraction_alert_sent = false;
+                state.distraction_consecutive_seconds = seconds;
             }
 
             self.maybe_send_distraction_alert();
         } else {
-            state.current_distraction = None;
-            state.distraction_consecutive_seconds = 0;
-            state.distraction_alert_sent = false;
+            state.close_distraction_stretch(&self.distraction_config);
         }
     }
 
     fn maybe_send_distraction_alert(&mut self) {
-        if !self.distraction_config.alert_enabled {
+        if !self.distraction_config.alert_enabled
+            || !self.distraction_config.alert_mode.interrupts()
+        {
             return;
         }
 
@@ -371,16 +780,16 @@ impl AppTrackerActor {
             return;
         }
 
-        if state.distraction_consecutive_seconds < self.distraction_config.alert_after_seconds {
-            return;
-        }
-
         let Some(ref app) = state.current_distraction else {
             return;
         };
 
+        if state.distraction_consecutive_seconds < self.distraction_config.alert_after_for(app) {
+            return;
+        }
+
         let translator = Config::load()
-            .map(|config| Translator::new(config.general.language))
+            .map(|config| Translator::new(config.language()))
             .unwrap_or_default();
 
         let title = format!(
@@ -408,7 +817,33 @@ impl AppTrackerActor {
         );
     }
 
-    fn track_friction(&mut self, application_name: &str) {
+    fn send_distraction_summary(&self, state: &TrackerState) {
+        if !self.distraction_config.alert_enabled
+            || !self.distraction_config.alert_mode.summarizes()
+            || state.distraction_incidents.is_empty()
+        {
+            return;
+        }
+
+        let translator = Config::load()
+            .map(|config| Translator::new(config.language()))
+            .unwrap_or_default();
+
+        let title = format!(
+            "Flux - {}",
+            translator.get("notification.distraction_summary_title")
+        );
+        let body = distraction_summary_body(&state.distraction_incidents, &translator);
+
+        self.notifier.send_alert(title, body);
+        debug!(
+            session_id = state.session_id,
+            incidents = state.distraction_incidents.len(),
+            "distraction summary sent"
+        );
+    }
+
+    fn track_friction(&mut self, application_name: &str, seconds: u64) {
         self.check_friction_response();
 
         let Some(ref mut state) = self.state else {
@@ -431,10 +866,10 @@ impl AppTrackerActor {
             .unwrap_or(false);
 
         if same_app {
-            state.friction_consecutive_seconds += POLLING_INTERVAL_SECONDS;
+            state.friction_consecutive_seconds += seconds;
         } else {
             state.current_friction_app = Some(application_name.to_string());
-            state.friction_consecutive_seconds = POLLING_INTERVAL_SECONDS;
+            state.friction_consecutive_seconds = seconds;
             state.friction_reminder_count = 0;
         }
 
@@ -487,14 +922,14 @@ impl AppTrackerActor {
             return;
         }
 
-        if state.friction_consecutive_seconds < self.distraction_config.friction_delay_seconds {
-            return;
-        }
-
         let Some(ref app) = state.current_friction_app else {
             return;
         };
 
+        if state.friction_consecutive_seconds < self.distraction_config.friction_delay_for(app) {
+            return;
+        }
+
         let is_escalated = state.friction_reminder_count >= 1;
 
         let receiver = if is_escalated {
@@ -516,43 +951,74 @@ impl AppTrackerActor {
         );
     }
 
-    #[cfg(not(target_os = "linux"))]
-    fn poll_active_window(&mut self) {
-        // No-op on non-Linux platforms
-    }
-
-    fn save_metrics(&self, state: &TrackerState) {
+    async fn save_metrics(&self, state: &TrackerState) {
         let metrics = SessionMetrics::new(
             state.session_id,
             state.context_switch_count,
             state.short_burst_count.clone(),
-        );
-
-        if let Err(error) = self.metrics_repository.save(&metrics) {
-            warn!(%error, "failed to save session metrics");
-        } else {
-            debug!(
+        )
+        .with_projects(state.project_seconds.clone())
+        .with_active_input_ratio(state.input_activity.ratio())
+        .with_distraction_time(state.tracked_seconds, state.distraction_seconds)
+        .with_pause_count(state.pause_count);
+
+        let saved = storage::blocking(&self.metrics_repository, move |repository| {
+            repository.save(&metrics).map(|()| metrics)
+        })
+        .await;
+        match saved {
+            Err(error) => warn!(%error, "failed to save session metrics"),
+            Ok(metrics) => debug!(
                 session_id = state.session_id,
                 context_switches = state.context_switch_count,
                 total_short_bursts = metrics.total_short_bursts,
                 focus_score = metrics.focus_score(),
                 "session metrics saved"
-            );
+            ),
         }
     }
 
-    fn generate_suggestions(&self, state: &TrackerState) {
-        if state.short_burst_count.is_empty() {
-            return;
-        }
+    /// Reads the whole session back, as pauses flush and clear the in-memory segments
+    async fn generate_suggestions(&self, state: &TrackerState) {
+        let session_id = state.session_id;
+        let segments = storage::blocking(&self.repository, move |repository| {
+            repository.find_segments_by_session(session_id)
+        })
+        .await
+        .unwrap_or_else(|error| {
+            warn!(%error, "failed to read session segments for suggestions");
+            Vec::new()
+        });
+        let friction_apps: HashSet<String> = self
+            .distraction_config
+            .friction_apps
+            .iter()
+            .cloned()
+            .chain(
+                self.distraction_config
+                    .friction_overrides
+                    .keys()
+                    .map(|app| app.to_lowercase()),
+            )
+            .collect();
+        let context = SuggestionContext {
+            short_burst_count: &state.short_burst_count,
+            segments: &segments,
+            friction_apps: &friction_apps,
+            title_patterns: &self.distraction_config.title_patterns,
+        };
 
         let report = SuggestionReport::from_session_data(
             state.session_id,
-            &state.short_burst_count,
             state.context_switch_count,
+            &context,
+            &SuggestionPipeline::new(self.suggestion_thresholds),
             &self.distraction_config.apps,
             &self.distraction_config.whitelist_apps,
         );
+        let app_state = AppState::load();
+        let report =
+            report.without_dismissed(|application| app_state.is_suggestion_dismissed(application));
 
         if report.suggestions.is_empty() {
             debug!("no suggestions generated for session");
@@ -574,49 +1040,99 @@ impl AppTrackerActor {
         }
     }
 
-    fn flush_to_repository(repository: &Arc<dyn AppTrackingRepository>, state: &TrackerState) {
-        for (key, seconds) in &state.accumulated {
-            if *seconds > 0 {
-                let usage = AppUsage::with_title(
-                    state.session_id,
-                    key.app_name.clone(),
-                    key.title.clone(),
-                    *seconds,
-                );
+    async fn flush_to_repository(
+        repository: &Arc<dyn AppTrackingRepository>,
+        state: &TrackerState,
+    ) {
+        if state.segments.is_empty() {
+            return;
+        }
 
-                if let Err(error) = repository.save_or_update(&usage) {
-                    error!(%error, app_name = key.app_name, title = key.title, "failed to persist app usage");
-                } else {
-                    debug!(
-                        session_id = state.session_id,
-                        app_name = key.app_name,
-                        title = key.title,
-                        seconds,
-                        "flushed app usage to database"
-                    );
-                }
+        let segments = state.segments.clone();
+        match storage::blocking(repository, move |repository| {
+            repository.save_segments(&segments)
+        })
+        .await
+        {
+            Ok(()) => debug!(
+                session_id = state.session_id,
+                count = state.segments.len(),
+                "flushed app usage segments to database"
+            ),
+            Err(error) => error!(
+                %error,
+                session_id = state.session_id,
+                count = state.segments.len(),
+                "failed to persist app usage segments"
+            ),
+        }
+    }
+}
+
+/// One line per application, most distracting first
+fn distraction_summary_body(incidents: &[DistractionIncident], translator: &Translator) -> String {
+    let mut by_application: Vec<(&str, usize, u64)> = Vec::new();
+    for incident in incidents {
+        match by_application
+            .iter_mut()
+            .find(|(name, _, _)| *name == incident.application_name)
+        {
+            Some((_, count, seconds)) => {
+                *count += 1;
+                *seconds += incident.seconds;
             }
+            None => by_application.push((&incident.application_name, 1, incident.seconds)),
         }
     }
+    by_application.sort_by_key(|(_, _, seconds)| std::cmp::Reverse(*seconds));
+
+    let mut lines = vec![translator.format(
+        "notification.distraction_summary_body",
+        &[("count", &incidents.len().to_string())],
+    )];
+    lines.extend(by_application.iter().map(|(name, count, seconds)| {
+        translator.format(
+            "notification.distraction_summary_line",
+            &[
+                ("app", name),
+                ("count", &count.to_string()),
+                ("duration", &format_seconds(*seconds)),
+            ],
+        )
+    }));
+    lines.join("\n")
+}
+
+fn format_seconds(seconds: u64) -> String {
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else {
+        format!("{}min {:02}s", seconds / 60, seconds % 60)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use flux_core::{
-        AppTrackingRepositoryError, NotificationUrgency, SessionMetricsRepositoryError,
+        AppTrackingRepositoryError, AppUsage, DistractionAlertMode, DistractionOverride, Language,
+        NotificationCapabilities, NotificationUrgency, SessionMetricsRepositoryError,
     };
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
     use std::sync::Mutex;
 
     struct MockRepository {
         saved: Mutex<Vec<AppUsage>>,
+        segments: Mutex<Vec<AppUsageSegment>>,
+        batch_count: Mutex<usize>,
     }
 
     impl MockRepository {
         fn new() -> Self {
             Self {
                 saved: Mutex::new(Vec::new()),
+                segments: Mutex::new(Vec::new()),
+                batch_count: Mutex::new(0),
             }
         }
     }
@@ -667,6 +1183,15 @@ mod tests {
             Ok(())
         }
 
+        fn save_or_update_batch(
+            &self,
+            usages: &[AppUsage],
+        ) -> Result<(), AppTrackingRepositoryError> {
+            *self.batch_count.lock().unwrap() += 1;
+            self.saved.lock().unwrap().extend_from_slice(usages);
+            Ok(())
+        }
+
         fn find_by_session(
             &self,
             _session_id: SessionId,
@@ -681,6 +1206,21 @@ mod tests {
             Ok(Vec::new())
         }
 
+        fn save_segments(
+            &self,
+            segments: &[AppUsageSegment],
+        ) -> Result<(), AppTrackingRepositoryError> {
+            self.segments.lock().unwrap().extend_from_slice(segments);
+            Ok(())
+        }
+
+        fn find_segments_by_session(
+            &self,
+            _session_id: SessionId,
+        ) -> Result<Vec<AppUsageSegment>, AppTrackingRepositoryError> {
+            Ok(Vec::new())
+        }
+
         fn delete_by_session(
             &self,
             _session_id: SessionId,
@@ -690,7 +1230,11 @@ mod tests {
     }
 
     fn create_test_notifier() -> NotifierHandle {
-        let (_, handle) = super::super::NotifierActor::new(NotificationUrgency::Normal, false);
+        let (_, handle) = super::super::NotifierActor::new(
+            NotificationUrgency::Normal,
+            false,
+            NotificationCapabilities::default(),
+        );
         handle
     }
 
@@ -700,9 +1244,12 @@ mod tests {
             title_patterns: HashSet::new(),
             alert_enabled: false,
             alert_after_seconds: 30,
+            alert_mode: DistractionAlertMode::Immediate,
             friction_apps: HashSet::new(),
             friction_delay_seconds: 10,
+            friction_overrides: HashMap::new(),
             whitelist_apps: HashSet::new(),
+            overrides: HashMap::new(),
         }
     }
 
@@ -717,14 +1264,17 @@ mod tests {
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         let actor_task = tokio::spawn(async move {
             tokio::time::timeout(Duration::from_millis(100), actor.run()).await
         });
 
-        handle.send_session_started(1, FocusMode::AiAssisted);
+        handle.send_session_started(1, FocusMode::AiAssisted, Vec::new());
         handle.send_session_paused();
         handle.send_session_resumed();
         handle.send_session_ended();
@@ -736,53 +1286,109 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn session_end_flushes_accumulated_data() {
+    async fn session_end_flushes_recorded_segments() {
         let repository = Arc::new(MockRepository::new());
         let repository_clone = repository.clone();
         let (mut actor, _handle) = AppTrackerActor::new(
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         actor.state = Some(TrackerState {
             session_id: 42,
             mode: FocusMode::AiAssisted,
             paused: false,
-            accumulated: HashMap::from([
-                (
-                    WindowKey {
-                        app_name: "cursor".to_string(),
-                        title: String::new(),
-                    },
-                    100,
-                ),
-                (
-                    WindowKey {
-                        app_name: "firefox".to_string(),
-                        title: String::new(),
-                    },
-                    50,
-                ),
-            ]),
             current_distraction: None,
             distraction_consecutive_seconds: 0,
             distraction_alert_sent: false,
+            distraction_incidents: Vec::new(),
             last_app: None,
             app_consecutive_seconds: 0,
             short_burst_count: HashMap::new(),
             context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
             current_friction_app: None,
             friction_consecutive_seconds: 0,
             friction_reminder_count: 0,
             friction_response_pending: None,
+            segments: vec![
+                AppUsageSegment::new(
+                    42,
+                    SegmentKind::Focus,
+                    "cursor".to_string(),
+                    Utc::now() - chrono::Duration::seconds(100),
+                    Utc::now() - chrono::Duration::seconds(50),
+                ),
+                AppUsageSegment::new(
+                    42,
+                    SegmentKind::Distraction,
+                    "discord".to_string(),
+                    Utc::now() - chrono::Duration::seconds(50),
+                    Utc::now(),
+                ),
+            ],
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
         });
 
-        actor.handle_message(AppTrackerMessage::Ended);
+        actor.handle_message(AppTrackerMessage::Ended).await;
 
-        let saved = repository_clone.saved.lock().unwrap();
-        assert_eq!(saved.len(), 2);
+        let segments = repository_clone.segments.lock().unwrap();
+        assert_eq!(segments.len(), 2);
+        assert!(repository_clone.saved.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn pauses_and_check_ins_are_stored_as_segments() {
+        let repository = Arc::new(MockRepository::new());
+        let repository_clone = repository.clone();
+        let metrics_repository = create_test_metrics_repository();
+        let (mut actor, _handle) = AppTrackerActor::new(
+            repository,
+            metrics_repository.clone(),
+            create_test_distraction_config(),
+            PrivacyConfig::default(),
+            create_test_notifier(),
+            None,
+            false,
+        );
+
+        actor
+            .handle_message(AppTrackerMessage::Started {
+                session_id: 7,
+                mode: FocusMode::Review,
+                distraction_apps: Vec::new(),
+            })
+            .await;
+        actor.handle_message(AppTrackerMessage::Paused).await;
+        actor.handle_message(AppTrackerMessage::Resumed).await;
+        actor.handle_message(AppTrackerMessage::CheckIn).await;
+        actor
+            .handle_message(AppTrackerMessage::CheckInAnswered {
+                outcome: CheckInOutcome::NotFocused,
+            })
+            .await;
+        actor.handle_message(AppTrackerMessage::Ended).await;
+
+        let segments = repository_clone.segments.lock().unwrap();
+        let kinds: Vec<SegmentKind> = segments.iter().map(|segment| segment.kind).collect();
+        assert_eq!(kinds, vec![SegmentKind::Pause, SegmentKind::CheckIn]);
+        assert_eq!(
+            segments[1].check_in_outcome,
+            Some(CheckInOutcome::NotFocused)
+        );
+        assert!(segments.iter().all(|segment| segment.session_id == 7));
+        assert_eq!(metrics_repository.saved.lock().unwrap()[0].pause_count, 1);
     }
 
     #[test]
@@ -792,28 +1398,40 @@ mod tests {
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         actor.state = Some(TrackerState {
             session_id: 1,
             mode: FocusMode::AiAssisted,
             paused: false,
-            accumulated: HashMap::new(),
             current_distraction: None,
             distraction_consecutive_seconds: 0,
             distraction_alert_sent: false,
+            distraction_incidents: Vec::new(),
             last_app: None,
             app_consecutive_seconds: 0,
             short_burst_count: HashMap::new(),
             context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
             current_friction_app: None,
             friction_consecutive_seconds: 0,
             friction_reminder_count: 0,
             friction_response_pending: None,
+            segments: Vec::new(),
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
         });
 
-        actor.track_distraction("Discord", "");
+        actor.track_distraction("Discord", "", POLLING_INTERVAL_SECONDS);
 
         let state = actor.state.as_ref().unwrap();
         assert_eq!(state.current_distraction, Some("Discord".to_string()));
@@ -822,7 +1440,7 @@ mod tests {
             POLLING_INTERVAL_SECONDS
         );
 
-        actor.track_distraction("Discord", "");
+        actor.track_distraction("Discord", "", POLLING_INTERVAL_SECONDS);
 
         let state = actor.state.as_ref().unwrap();
         assert_eq!(
@@ -831,6 +1449,32 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn session_distraction_apps_count_on_top_of_the_configuration() {
+        let (mut actor, _handle) = AppTrackerActor::new(
+            Arc::new(MockRepository::new()),
+            create_test_metrics_repository(),
+            create_test_distraction_config(),
+            PrivacyConfig::default(),
+            create_test_notifier(),
+            None,
+            false,
+        );
+        actor
+            .handle_message(AppTrackerMessage::Started {
+                session_id: 3,
+                mode: FocusMode::Review,
+                distraction_apps: vec!["figma".to_string()],
+            })
+            .await;
+
+        actor.track_distraction("Figma", "", POLLING_INTERVAL_SECONDS);
+
+        let state = actor.state.as_ref().unwrap();
+        assert_eq!(state.current_distraction, Some("Figma".to_string()));
+        assert_eq!(state.distraction_seconds, POLLING_INTERVAL_SECONDS as i64);
+    }
+
     #[test]
     fn track_distraction_resets_when_switching_apps() {
         let repository = Arc::new(MockRepository::new());
@@ -838,33 +1482,138 @@ mod tests {
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         actor.state = Some(TrackerState {
             session_id: 1,
             mode: FocusMode::AiAssisted,
             paused: false,
-            accumulated: HashMap::new(),
             current_distraction: Some("Discord".to_string()),
             distraction_consecutive_seconds: 60,
             distraction_alert_sent: true,
+            distraction_incidents: Vec::new(),
             last_app: None,
             app_consecutive_seconds: 0,
             short_burst_count: HashMap::new(),
             context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
             current_friction_app: None,
             friction_consecutive_seconds: 0,
             friction_reminder_count: 0,
             friction_response_pending: None,
+            segments: Vec::new(),
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
         });
 
-        actor.track_distraction("cursor", "");
+        actor.track_distraction("cursor", "", POLLING_INTERVAL_SECONDS);
 
         let state = actor.state.as_ref().unwrap();
         assert_eq!(state.current_distraction, None);
         assert_eq!(state.distraction_consecutive_seconds, 0);
         assert!(!state.distraction_alert_sent);
+        assert_eq!(
+            state.distraction_incidents,
+            vec![DistractionIncident {
+                application_name: "Discord".to_string(),
+                seconds: 60,
+            }]
+        );
+    }
+
+    #[test]
+    fn per_app_threshold_decides_which_stretches_are_incidents() {
+        let repository = Arc::new(MockRepository::new());
+        let mut distraction_config = create_test_distraction_config();
+        distraction_config.overrides.insert(
+            "discord".to_string(),
+            DistractionOverride {
+                alert_after_seconds: Some(10),
+            },
+        );
+        let (mut actor, _handle) = AppTrackerActor::new(
+            repository,
+            create_test_metrics_repository(),
+            distraction_config,
+            PrivacyConfig::default(),
+            create_test_notifier(),
+            None,
+            false,
+        );
+
+        actor.state = Some(TrackerState {
+            session_id: 1,
+            mode: FocusMode::AiAssisted,
+            paused: false,
+            current_distraction: Some("Discord".to_string()),
+            distraction_consecutive_seconds: 15,
+            distraction_alert_sent: false,
+            distraction_incidents: Vec::new(),
+            last_app: None,
+            app_consecutive_seconds: 0,
+            short_burst_count: HashMap::new(),
+            context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
+            current_friction_app: None,
+            friction_consecutive_seconds: 0,
+            friction_reminder_count: 0,
+            friction_response_pending: None,
+            segments: Vec::new(),
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
+        });
+
+        actor.track_distraction("cursor", "", POLLING_INTERVAL_SECONDS);
+
+        actor.track_distraction("Slack", "", POLLING_INTERVAL_SECONDS);
+        actor.track_distraction("cursor", "", POLLING_INTERVAL_SECONDS);
+
+        let state = actor.state.as_ref().unwrap();
+        assert_eq!(
+            state.distraction_incidents,
+            vec![DistractionIncident {
+                application_name: "Discord".to_string(),
+                seconds: 15,
+            }]
+        );
+    }
+
+    #[test]
+    fn distraction_summary_groups_incidents_by_application() {
+        let incident = |application_name: &str, seconds| DistractionIncident {
+            application_name: application_name.to_string(),
+            seconds,
+        };
+        let translator = Translator::new(Language::En);
+
+        let body = distraction_summary_body(
+            &[
+                incident("slack", 40),
+                incident("discord", 90),
+                incident("slack", 80),
+            ],
+            &translator,
+        );
+
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains('3'));
+        assert!(lines[1].contains("slack") && lines[1].contains("2min 00s"));
+        assert!(lines[2].contains("discord") && lines[2].contains("1min 30s"));
     }
 
     #[test]
@@ -874,28 +1623,40 @@ mod tests {
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         actor.state = Some(TrackerState {
             session_id: 1,
             mode: FocusMode::AiAssisted,
             paused: false,
-            accumulated: HashMap::new(),
             current_distraction: Some("Discord".to_string()),
             distraction_consecutive_seconds: 60,
             distraction_alert_sent: true,
+            distraction_incidents: Vec::new(),
             last_app: None,
             app_consecutive_seconds: 0,
             short_burst_count: HashMap::new(),
             context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
             current_friction_app: None,
             friction_consecutive_seconds: 0,
             friction_reminder_count: 0,
             friction_response_pending: None,
+            segments: Vec::new(),
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
         });
 
-        actor.track_distraction("Slack", "");
+        actor.track_distraction("Slack", "", POLLING_INTERVAL_SECONDS);
 
         let state = actor.state.as_ref().unwrap();
         assert_eq!(state.current_distraction, Some("Slack".to_string()));
@@ -913,28 +1674,40 @@ mod tests {
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         actor.state = Some(TrackerState {
             session_id: 1,
             mode: FocusMode::AiAssisted,
             paused: false,
-            accumulated: HashMap::new(),
             current_distraction: None,
             distraction_consecutive_seconds: 0,
             distraction_alert_sent: false,
+            distraction_incidents: Vec::new(),
             last_app: Some("firefox".to_string()),
             app_consecutive_seconds: 60,
             short_burst_count: HashMap::new(),
             context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
             current_friction_app: None,
             friction_consecutive_seconds: 0,
             friction_reminder_count: 0,
             friction_response_pending: None,
+            segments: Vec::new(),
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
         });
 
-        actor.track_context_switch("cursor");
+        actor.track_context_switch("cursor", POLLING_INTERVAL_SECONDS);
 
         let state = actor.state.as_ref().unwrap();
         assert_eq!(state.context_switch_count, 1);
@@ -949,28 +1722,40 @@ mod tests {
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         actor.state = Some(TrackerState {
             session_id: 1,
             mode: FocusMode::AiAssisted,
             paused: false,
-            accumulated: HashMap::new(),
             current_distraction: None,
             distraction_consecutive_seconds: 0,
             distraction_alert_sent: false,
+            distraction_incidents: Vec::new(),
             last_app: Some("discord".to_string()),
             app_consecutive_seconds: 10,
             short_burst_count: HashMap::new(),
             context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
             current_friction_app: None,
             friction_consecutive_seconds: 0,
             friction_reminder_count: 0,
             friction_response_pending: None,
+            segments: Vec::new(),
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
         });
 
-        actor.track_context_switch("cursor");
+        actor.track_context_switch("cursor", POLLING_INTERVAL_SECONDS);
 
         let state = actor.state.as_ref().unwrap();
         assert_eq!(state.context_switch_count, 1);
@@ -984,28 +1769,40 @@ mod tests {
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         actor.state = Some(TrackerState {
             session_id: 1,
             mode: FocusMode::AiAssisted,
             paused: false,
-            accumulated: HashMap::new(),
             current_distraction: None,
             distraction_consecutive_seconds: 0,
             distraction_alert_sent: false,
+            distraction_incidents: Vec::new(),
             last_app: Some("firefox".to_string()),
             app_consecutive_seconds: 120,
             short_burst_count: HashMap::new(),
             context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
             current_friction_app: None,
             friction_consecutive_seconds: 0,
             friction_reminder_count: 0,
             friction_response_pending: None,
+            segments: Vec::new(),
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
         });
 
-        actor.track_context_switch("cursor");
+        actor.track_context_switch("cursor", POLLING_INTERVAL_SECONDS);
 
         let state = actor.state.as_ref().unwrap();
         assert_eq!(state.context_switch_count, 1);
@@ -1019,32 +1816,151 @@ mod tests {
             repository,
             create_test_metrics_repository(),
             create_test_distraction_config(),
+            PrivacyConfig::default(),
             create_test_notifier(),
+            None,
+            false,
         );
 
         actor.state = Some(TrackerState {
             session_id: 1,
             mode: FocusMode::AiAssisted,
             paused: false,
-            accumulated: HashMap::new(),
             current_distraction: None,
             distraction_consecutive_seconds: 0,
             distraction_alert_sent: false,
+            distraction_incidents: Vec::new(),
             last_app: Some("cursor".to_string()),
             app_consecutive_seconds: 30,
             short_burst_count: HashMap::new(),
             context_switch_count: 0,
+            project_seconds: HashMap::new(),
+            input_activity: InputActivityTally::default(),
+            tracked_seconds: 0,
+            distraction_seconds: 0,
             current_friction_app: None,
             friction_consecutive_seconds: 0,
             friction_reminder_count: 0,
             friction_response_pending: None,
+            segments: Vec::new(),
+            paused_at: None,
+            pause_count: 0,
+            distraction_apps: Vec::new(),
+            focus: FocusTimeline::default(),
         });
 
-        actor.track_context_switch("cursor");
+        actor.track_context_switch("cursor", POLLING_INTERVAL_SECONDS);
 
         let state = actor.state.as_ref().unwrap();
         assert_eq!(state.context_switch_count, 0);
         assert_eq!(state.app_consecutive_seconds, 30 + POLLING_INTERVAL_SECONDS);
         assert!(state.short_burst_count.is_empty());
     }
+
+    fn window(app_name: &str) -> WindowInfo {
+        WindowInfo::new(app_name.to_string(), None)
+    }
+
+    fn span(app_name: &str, from: u64, to: u64) -> FocusSpan {
+        FocusSpan {
+            window: window(app_name),
+            from,
+            to,
+        }
+    }
+
+    #[test]
+    fn probed_switches_split_the_poll_interval() {
+        let start = Instant::now();
+        let at = |seconds: u64| start + Duration::from_secs(seconds);
+        let mut focus = FocusTimeline::default();
+        focus.observe(window("cursor"), at(0));
+        assert_eq!(focus.split(5, at(0)), vec![span("cursor", 0, 5)]);
+
+        focus.observe(window("cursor"), at(1));
+        focus.observe(window("discord"), at(2));
+        focus.observe(window("cursor"), at(3));
+        focus.observe(window("cursor"), at(5));
+
+        assert_eq!(
+            focus.split(5, at(5)),
+            vec![
+                span("cursor", 0, 2),
+                span("discord", 2, 3),
+                span("cursor", 3, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn an_unprobed_switch_is_dated_halfway_through_the_interval() {
+        let start = Instant::now();
+        let at = |seconds: u64| start + Duration::from_secs(seconds);
+        let mut focus = FocusTimeline::default();
+        focus.observe(window("cursor"), at(0));
+        focus.split(20, at(0));
+
+        focus.observe(window("slack"), at(20));
+
+        assert_eq!(
+            focus.split(20, at(20)),
+            vec![span("cursor", 0, 10), span("slack", 10, 20)]
+        );
+        focus.observe(window("slack"), at(40));
+        assert_eq!(focus.split(20, at(40)), vec![span("slack", 0, 20)]);
+    }
+
+    struct SwitchingDetector {
+        application_name: Arc<Mutex<&'static str>>,
+    }
+
+    impl WindowDetector for SwitchingDetector {
+        fn get_active_window_info(&self, _tracking: &TrackingConfig) -> Option<WindowInfo> {
+            Some(window(&self.application_name.lock().unwrap()))
+        }
+
+        fn backend_name(&self) -> &'static str {
+            "switching"
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_brief_switch_is_credited_only_the_time_it_lasted() {
+        let application_name = Arc::new(Mutex::new("cursor"));
+        let (actor, _handle) = AppTrackerActor::new(
+            Arc::new(MockRepository::new()),
+            create_test_metrics_repository(),
+            create_test_distraction_config(),
+            PrivacyConfig::default(),
+            create_test_notifier(),
+            None,
+            false,
+        );
+        let mut actor = actor.simulated(Box::new(SwitchingDetector {
+            application_name: Arc::clone(&application_name),
+        }));
+        actor
+            .handle_message(AppTrackerMessage::Started {
+                session_id: 1,
+                mode: FocusMode::Review,
+                distraction_apps: Vec::new(),
+            })
+            .await;
+
+        actor.poll_active_window();
+        for focused in ["cursor", "discord", "cursor"] {
+            tokio::time::advance(Duration::from_secs(1)).await;
+            *application_name.lock().unwrap() = focused;
+            actor.probe_active_window();
+        }
+        tokio::time::advance(Duration::from_secs(2)).await;
+        actor.poll_active_window();
+
+        let state = actor.state.as_ref().unwrap();
+        assert_eq!(state.tracked_seconds, 10);
+        assert_eq!(state.distraction_seconds, 1);
+        assert_eq!(state.context_switch_count, 2);
+        assert_eq!(state.short_burst_count.get("discord"), Some(&1));
+        assert_eq!(state.app_consecutive_seconds, 2);
+    }
 }
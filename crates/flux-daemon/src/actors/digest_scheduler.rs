@@ -1,24 +1,33 @@
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use chrono::{Datelike, Duration as ChronoDuration, Local, NaiveTime, Utc, Weekday};
+use chrono::{
+    DateTime, Datelike, Duration as ChronoDuration, Local, NaiveDate, NaiveTime, Utc, Weekday,
+};
 use tokio::sync::broadcast;
 use tokio::time::{sleep, Duration};
 use tracing::{debug, info, warn};
 
 use flux_core::{
-    AppTrackingRepository, DigestConfig, DistractionConfig, Session, SessionRepository,
+    AppTrackingRepository, Config, DigestConfig, DigestDeliveryGateway, DigestReport, DigestStats,
+    DistractionConfig, Session, SessionRepository, Streak, Translator, WeekStats,
 };
 
+use super::notifier::WeeklyDigestSummary;
 use super::NotifierHandle;
+use crate::storage;
+
+const REPORT_TOP_APPLICATIONS_LIMIT: usize = 5;
 
+#[derive(Clone)]
 pub struct DigestSchedulerActor {
     notifier: NotifierHandle,
     config: DigestConfig,
-    #[allow(dead_code)]
     distraction_config: DistractionConfig,
     session_repository: Arc<dyn SessionRepository>,
-    #[allow(dead_code)]
     app_tracking_repository: Arc<dyn AppTrackingRepository>,
+    delivery_gateways: Vec<Arc<dyn DigestDeliveryGateway>>,
 }
 
 impl DigestSchedulerActor {
@@ -28,6 +37,7 @@ impl DigestSchedulerActor {
         distraction_config: DistractionConfig,
         session_repository: Arc<dyn SessionRepository>,
         app_tracking_repository: Arc<dyn AppTrackingRepository>,
+        delivery_gateways: Vec<Arc<dyn DigestDeliveryGateway>>,
     ) -> Self {
         Self {
             notifier,
@@ -35,6 +45,7 @@ impl DigestSchedulerActor {
             distraction_config,
             session_repository,
             app_tracking_repository,
+            delivery_gateways,
         }
     }
 
@@ -54,7 +65,7 @@ impl DigestSchedulerActor {
 
             tokio::select! {
                 _ = sleep(sleep_duration) => {
-                    self.send_digest();
+                    self.send_digest().await;
                 }
                 _ = shutdown.recv() => {
                     debug!("digest scheduler shutdown");
@@ -94,30 +105,263 @@ impl DigestSchedulerActor {
         }
     }
 
-    fn send_digest(&self) {
+    async fn send_digest(&self) {
         info!("computing weekly digest");
 
         let now = Utc::now();
-        let week_start = now - ChronoDuration::days(7);
+        let current_start = now - ChronoDuration::days(7);
+        let previous_start = now - ChronoDuration::days(14);
+
+        let current_sessions =
+            match storage::blocking(&self.session_repository, move |repository| {
+                repository.find_completed_since(current_start)
+            })
+            .await
+            {
+                Ok(sessions) => sessions,
+                Err(error) => {
+                    warn!(%error, "failed to fetch sessions for digest");
+                    return;
+                }
+            };
+
+        if current_sessions.is_empty() {
+            debug!("no sessions for weekly digest");
+            return;
+        }
+
+        let previous_sessions = storage::blocking(&self.session_repository, move |repository| {
+            repository.find_completed_between(previous_start, current_start)
+        })
+        .await
+        .unwrap_or_else(|error| {
+            warn!(%error, "failed to fetch previous week sessions for digest");
+            Vec::new()
+        });
+
+        let current_week = self.compute_week_stats(&current_sessions).await;
+        let previous_week = if previous_sessions.is_empty() {
+            None
+        } else {
+            Some(self.compute_week_stats(&previous_sessions).await)
+        };
 
-        let sessions = match self.session_repository.find_completed_since(week_start) {
-            Ok(s) => s,
+        let streak = match storage::blocking(&self.session_repository, |repository| {
+            repository.find_completed_since(DateTime::UNIX_EPOCH)
+        })
+        .await
+        {
+            Ok(all_sessions) => Streak::from_sessions(&all_sessions, Local::now().date_naive()),
             Err(error) => {
-                warn!(%error, "failed to fetch sessions for digest");
-                return;
+                warn!(%error, "failed to fetch sessions for streak");
+                Streak::default()
             }
         };
 
-        if sessions.is_empty() {
-            debug!("no sessions for weekly digest");
-            return;
+        let stats = DigestStats::new(current_week, previous_week).with_streak(streak);
+
+        let mut report_path = None;
+        if self.config.save_report || !self.delivery_gateways.is_empty() {
+            let translator = Config::load()
+                .map(|config| Translator::new(config.language()))
+                .unwrap_or_default();
+            let report = build_report(&stats, &translator, Local::now().date_naive());
+
+            if self.config.save_report {
+                report_path = self.save_report(&report);
+            }
+            self.deliver_report(&report).await;
+        }
+
+        self.notifier
+            .send_weekly_digest(build_summary(&stats, report_path));
+
+        if let Some(regression) = stats.regression(self.config.regression_threshold_percent as u32)
+        {
+            info!(
+                focus_drop_percent = regression.focus_drop_percent,
+                distractions_doubled = regression.distractions_doubled,
+                "weekly regression detected"
+            );
+            self.notifier.send_digest_regression(regression);
+        }
+    }
+
+    async fn compute_week_stats(&self, sessions: &[Session]) -> WeekStats {
+        let session_ids: Vec<i64> = sessions.iter().filter_map(|session| session.id).collect();
+        let app_usages = storage::blocking(&self.app_tracking_repository, move |repository| {
+            repository.find_by_sessions(&session_ids)
+        })
+        .await
+        .unwrap_or_else(|error| {
+            warn!(%error, "failed to fetch app usage for digest");
+            Vec::new()
+        });
+
+        WeekStats::compute(sessions, &app_usages, |application| {
+            self.distraction_config.is_distraction(application)
+        })
+    }
+
+    fn save_report(&self, report: &DigestReport) -> Option<PathBuf> {
+        let directory = dirs::data_dir()?.join("flux").join("digests");
+
+        match write_report(report, &directory, Local::now().date_naive()) {
+            Ok(path) => {
+                info!(path = %path.display(), "weekly digest report saved");
+                Some(path)
+            }
+            Err(error) => {
+                warn!(%error, "failed to save weekly digest report");
+                None
+            }
+        }
+    }
+
+    async fn deliver_report(&self, report: &DigestReport) {
+        for gateway in &self.delivery_gateways {
+            match gateway.deliver(report).await {
+                Ok(()) => info!(channel = gateway.channel_name(), "weekly digest delivered"),
+                Err(error) => {
+                    warn!(%error, channel = gateway.channel_name(), "failed to deliver weekly digest")
+                }
+            }
+        }
+    }
+}
+
+fn build_summary(stats: &DigestStats, report_path: Option<PathBuf>) -> WeeklyDigestSummary {
+    WeeklyDigestSummary {
+        total_time: format_duration(stats.current_week.total_seconds),
+        session_count: stats.current_week.session_count,
+        time_delta: stats.time_delta().map(format_signed_duration),
+        top_distraction: stats
+            .current_week
+            .top_distraction_applications(1)
+            .pop()
+            .map(|(application, _)| application),
+        streak_days: stats.streak.current_days,
+        report_path,
+    }
+}
+
+fn build_report(stats: &DigestStats, translator: &Translator, date: NaiveDate) -> DigestReport {
+    let week = &stats.current_week;
+
+    DigestReport {
+        subject: format!(
+            "Flux - {} ({})",
+            translator.get("notification.digest_title"),
+            date.format("%Y-%m-%d")
+        ),
+        summary: translator.format(
+            "notification.digest_body",
+            &[
+                ("time", &format_duration(week.total_seconds)),
+                ("sessions", &week.session_count.to_string()),
+            ],
+        ),
+        markdown: render_report(stats, translator, date),
+    }
+}
+
+fn write_report(
+    report: &DigestReport,
+    directory: &Path,
+    date: NaiveDate,
+) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(directory)?;
+    let path = directory.join(format!("{}.md", date.format("%Y-%m-%d")));
+    fs::write(&path, &report.markdown)?;
+    Ok(path)
+}
+
+fn render_report(stats: &DigestStats, translator: &Translator, date: NaiveDate) -> String {
+    let week = &stats.current_week;
+    let mut report = format!(
+        "# {} — {}\n\n",
+        translator.get("command.digest_header"),
+        date.format("%Y-%m-%d")
+    );
+
+    let mut total_line = format!(
+        "- **{}**: {}",
+        translator.get("command.digest_total_time"),
+        format_duration(week.total_seconds)
+    );
+    if let Some(delta) = stats.time_delta() {
+        total_line.push_str(&format!(
+            " ({} {})",
+            format_signed_duration(delta),
+            translator.get("command.digest_vs_last_week")
+        ));
+    }
+    report.push_str(&total_line);
+    report.push('\n');
+    report.push_str(&format!(
+        "- **{}**: {}\n",
+        translator.get("command.digest_sessions"),
+        week.session_count
+    ));
+    if let Some(rate) = week.completion_rate {
+        report.push_str(&format!(
+            "- **{}**: {}%\n",
+            translator.get("command.digest_completion_rate"),
+            rate
+        ));
+    }
+    if stats.streak.current_days > 0 {
+        report.push_str(&format!(
+            "- **{}**: {}\n",
+            translator.get("command.digest_streak"),
+            translator.format(
+                "command.streak_days",
+                &[
+                    ("current", &stats.streak.current_days.to_string()),
+                    ("longest", &stats.streak.longest_days.to_string()),
+                ],
+            )
+        ));
+    }
+
+    if !week.by_mode.is_empty() {
+        let mut modes: Vec<_> = week.by_mode.iter().collect();
+        modes.sort_by(|first, second| second.1.cmp(first.1).then_with(|| first.0.cmp(second.0)));
+        report.push_str(&format!(
+            "\n## {}\n\n",
+            translator.get("command.digest_by_mode")
+        ));
+        for (mode, seconds) in modes {
+            report.push_str(&format!("- {}: {}\n", mode, format_duration(*seconds)));
         }
+    }
 
-        let total_seconds = compute_total_time(&sessions);
-        let total_time = format_duration(total_seconds);
-        let session_count = sessions.len();
+    render_report_applications(
+        &mut report,
+        &translator.get("command.digest_top_focus"),
+        &week.top_focus_applications(REPORT_TOP_APPLICATIONS_LIMIT),
+    );
+    render_report_applications(
+        &mut report,
+        &translator.get("command.digest_distractions"),
+        &week.top_distraction_applications(REPORT_TOP_APPLICATIONS_LIMIT),
+    );
 
-        self.notifier.send_weekly_digest(total_time, session_count);
+    report
+}
+
+fn render_report_applications(report: &mut String, header: &str, applications: &[(String, i64)]) {
+    if applications.is_empty() {
+        return;
+    }
+
+    report.push_str(&format!("\n## {}\n\n", header));
+    for (application, seconds) in applications {
+        report.push_str(&format!(
+            "- {}: {}\n",
+            application,
+            format_duration(*seconds)
+        ));
     }
 }
 
@@ -134,13 +378,6 @@ fn parse_weekday(day: &str) -> Weekday {
     }
 }
 
-fn compute_total_time(sessions: &[Session]) -> i64 {
-    sessions
-        .iter()
-        .map(|s| s.duration_seconds.unwrap_or(0))
-        .sum()
-}
-
 fn format_duration(seconds: i64) -> String {
     let hours = seconds / 3600;
     let minutes = (seconds % 3600) / 60;
@@ -152,9 +389,16 @@ fn format_duration(seconds: i64) -> String {
     }
 }
 
+fn format_signed_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "+" };
+    format!("{}{}", sign, format_duration(seconds.abs()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use flux_core::Language;
+    use std::collections::HashMap;
 
     #[test]
     fn parse_weekday_handles_all_days() {
@@ -185,4 +429,74 @@ mod tests {
         assert_eq!(format_duration(60), "1min");
         assert_eq!(format_duration(0), "0min");
     }
+
+    #[test]
+    fn format_signed_duration_shows_sign() {
+        assert_eq!(format_signed_duration(3900), "+1h 05min");
+        assert_eq!(format_signed_duration(-1500), "-25min");
+        assert_eq!(format_signed_duration(0), "+0min");
+    }
+
+    fn create_digest_stats() -> DigestStats {
+        let current_week = WeekStats {
+            total_seconds: 7200,
+            session_count: 3,
+            by_mode: HashMap::from([("ai-assisted".to_string(), 7200)]),
+            focus_applications: HashMap::from([("code".to_string(), 5400)]),
+            distraction_applications: HashMap::from([
+                ("discord".to_string(), 600),
+                ("slack".to_string(), 300),
+            ]),
+            total_distraction_seconds: 900,
+            ..Default::default()
+        };
+        let previous_week = WeekStats {
+            total_seconds: 3600,
+            session_count: 2,
+            ..Default::default()
+        };
+
+        DigestStats::new(current_week, Some(previous_week))
+    }
+
+    #[test]
+    fn summary_includes_delta_and_top_distraction() {
+        let summary = build_summary(&create_digest_stats(), None);
+
+        assert_eq!(summary.total_time, "2h 00min");
+        assert_eq!(summary.session_count, 3);
+        assert_eq!(summary.time_delta.as_deref(), Some("+1h 00min"));
+        assert_eq!(summary.top_distraction.as_deref(), Some("discord"));
+    }
+
+    #[test]
+    fn report_lists_totals_modes_and_applications() {
+        let translator = Translator::new(Language::En);
+        let date = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+
+        let report = render_report(&create_digest_stats(), &translator, date);
+
+        assert!(report.starts_with("# 📊 Weekly Summary — 2026-03-15"));
+        assert!(report.contains("- **Total time**: 2h 00min (+1h 00min vs last week)"));
+        assert!(report.contains("- ai-assisted: 2h 00min"));
+        assert!(report.contains("## Top focus apps\n\n- code: 1h 30min"));
+        assert!(report.contains("## Distractions\n\n- discord: 10min\n- slack: 5min"));
+    }
+
+    #[test]
+    fn report_is_written_under_dated_file_name() {
+        let directory = std::env::temp_dir().join(format!("flux-digests-{}", std::process::id()));
+        let date = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+
+        let report = build_report(&create_digest_stats(), &Translator::new(Language::En), date);
+
+        let path = write_report(&report, &directory, date).unwrap();
+
+        assert_eq!(path, directory.join("2026-03-15.md"));
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("Weekly Summary"));
+
+        let _ = fs::remove_dir_all(&directory);
+    }
 }
@@ -0,0 +1,239 @@
+use std::process::{Output, Stdio};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+use flux_core::{FocusMode, HooksConfig, SessionId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    SessionStart,
+    SessionEnd,
+    Pause,
+    Resume,
+}
+
+impl HookEvent {
+    pub fn id(&self) -> &'static str {
+        match self {
+            HookEvent::SessionStart => "session_start",
+            HookEvent::SessionEnd => "session_end",
+            HookEvent::Pause => "pause",
+            HookEvent::Resume => "resume",
+        }
+    }
+
+    fn command<'a>(&self, hooks: &'a HooksConfig) -> Option<&'a str> {
+        let command = match self {
+            HookEvent::SessionStart => &hooks.on_session_start,
+            HookEvent::SessionEnd => &hooks.on_session_end,
+            HookEvent::Pause => &hooks.on_pause,
+            HookEvent::Resume => &hooks.on_resume,
+        };
+
+        command
+            .as_deref()
+            .map(str::trim)
+            .filter(|command| !command.is_empty())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HookContext {
+    pub mode: FocusMode,
+    pub duration: Duration,
+    pub session_id: Option<SessionId>,
+}
+
+impl HookContext {
+    fn environment(&self, event: HookEvent) -> Vec<(&'static str, String)> {
+        vec![
+            ("FLUX_EVENT", event.id().to_string()),
+            ("FLUX_MODE", self.mode.to_string()),
+            ("FLUX_DURATION", self.duration.as_secs().to_string()),
+            (
+                "FLUX_SESSION_ID",
+                self.session_id
+                    .map(|session_id| session_id.to_string())
+                    .unwrap_or_default(),
+            ),
+        ]
+    }
+}
+
+pub struct HookMessage {
+    event: HookEvent,
+    context: HookContext,
+}
+
+#[derive(Clone)]
+pub struct HookRunnerHandle {
+    sender: mpsc::Sender<HookMessage>,
+}
+
+impl HookRunnerHandle {
+    pub fn send_event(&self, event: HookEvent, context: HookContext) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender.send(HookMessage { event, context }).await {
+                error!(%error, "failed to send event to hook runner");
+            }
+        });
+    }
+}
+
+pub struct HookRunnerActor {
+    receiver: mpsc::Receiver<HookMessage>,
+    hooks: HooksConfig,
+}
+
+impl HookRunnerActor {
+    pub fn new(hooks: HooksConfig) -> (Self, HookRunnerHandle) {
+        let (sender, receiver) = mpsc::channel(16);
+
+        let actor = Self { receiver, hooks };
+
+        (actor, HookRunnerHandle { sender })
+    }
+
+    pub async fn run(mut self) {
+        info!("hook runner actor started");
+
+        let timeout = Duration::from_secs(self.hooks.timeout_seconds.max(1));
+
+        while let Some(HookMessage { event, context }) = self.receiver.recv().await {
+            let Some(command) = event.command(&self.hooks) else {
+                continue;
+            };
+
+            debug!(event = event.id(), command, "running hook");
+            match run_hook(command, &context.environment(event), timeout).await {
+                Ok(output) => log_output(event, &output),
+                Err(error) => warn!(event = event.id(), %error, "hook failed"),
+            }
+        }
+
+        debug!("hook runner actor stopped");
+    }
+}
+
+async fn run_hook(
+    command: &str,
+    environment: &[(&'static str, String)],
+    timeout: Duration,
+) -> Result<Output> {
+    let mut process = shell_command(command);
+    process
+        .envs(environment.iter().map(|(name, value)| (*name, value)))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    let child = process.spawn().context("failed to spawn hook")?;
+
+    match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        Ok(output) => output.context("failed to wait for hook"),
+        Err(_) => bail!("hook timed out after {} seconds", timeout.as_secs()),
+    }
+}
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> Command {
+    let mut process = Command::new("sh");
+    process.arg("-c").arg(command);
+    process
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> Command {
+    let mut process = Command::new("cmd");
+    process.arg("/C").arg(command);
+    process
+}
+
+fn log_output(event: HookEvent, output: &Output) {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if output.status.success() {
+        info!(
+            event = event.id(),
+            stdout = stdout.trim(),
+            stderr = stderr.trim(),
+            "hook completed"
+        );
+    } else {
+        warn!(
+            event = event.id(),
+            status = %output.status,
+            stdout = stdout.trim(),
+            stderr = stderr.trim(),
+            "hook exited with an error"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_context() -> HookContext {
+        HookContext {
+            mode: FocusMode::Review,
+            duration: Duration::from_secs(1500),
+            session_id: Some(42),
+        }
+    }
+
+    #[test]
+    fn blank_commands_are_ignored() {
+        let hooks = HooksConfig {
+            on_session_start: Some("echo start".to_string()),
+            on_pause: Some("  ".to_string()),
+            ..HooksConfig::default()
+        };
+
+        assert_eq!(HookEvent::SessionStart.command(&hooks), Some("echo start"));
+        assert_eq!(HookEvent::Pause.command(&hooks), None);
+        assert_eq!(HookEvent::SessionEnd.command(&hooks), None);
+    }
+
+    #[test]
+    fn environment_describes_the_session() {
+        let environment = create_context().environment(HookEvent::SessionEnd);
+
+        assert!(environment.contains(&("FLUX_EVENT", "session_end".to_string())));
+        assert!(environment.contains(&("FLUX_MODE", "review".to_string())));
+        assert!(environment.contains(&("FLUX_DURATION", "1500".to_string())));
+        assert!(environment.contains(&("FLUX_SESSION_ID", "42".to_string())));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn hook_receives_environment_and_output_is_captured() {
+        let environment = create_context().environment(HookEvent::SessionStart);
+
+        let output = run_hook(
+            "echo \"$FLUX_MODE $FLUX_SESSION_ID\"",
+            &environment,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "review 42");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn slow_hook_times_out() {
+        let result = run_hook("sleep 5", &[], Duration::from_millis(100)).await;
+
+        assert!(result.is_err());
+    }
+}
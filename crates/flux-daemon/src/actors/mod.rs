@@ -1,15 +1,29 @@
+mod achievements;
 mod app_tracker;
 mod digest_scheduler;
+mod hook_runner;
 mod notifier;
 mod timer;
-#[cfg(target_os = "linux")]
 mod tray;
 
-pub use app_tracker::{AppTrackerActor, AppTrackerHandle};
+pub use achievements::{AchievementsActor, AchievementsHandle};
+pub use app_tracker::{AppTrackerActor, AppTrackerHandle, POLLING_INTERVAL_SECONDS};
 pub use digest_scheduler::DigestSchedulerActor;
-pub use notifier::{CheckInResponse, NotifierActor, NotifierHandle};
-pub use timer::{TimerActor, TimerHandle};
-#[cfg(target_os = "linux")]
+pub use hook_runner::{HookContext, HookEvent, HookRunnerActor, HookRunnerHandle};
+#[cfg(feature = "simulate")]
+pub use notifier::NotifierMessage;
+pub use notifier::{
+    probe_capabilities, CheckInResponse, FrictionResponse, NotifierActor, NotifierHandle,
+};
+pub use timer::{InterruptOutcome, StartOutcome, TimerActor, TimerHandle};
+#[cfg(target_os = "macos")]
+pub use tray::run_main_thread_event_loop;
 pub use tray::{
     check_for_updates, open_configuration, open_dashboard, spawn_tray, TrayAction, TrayStateHandle,
 };
+
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+/// Receiving end of an actor channel, kept alive when the actor crashes so a restarted one picks it up
+type Mailbox<Message> = Arc<Mutex<mpsc::Receiver<Message>>>;
@@ -1,27 +1,65 @@
+use std::path::PathBuf;
+#[cfg(target_os = "linux")]
+use std::process::Command;
+use std::sync::Arc;
 use std::time::Duration;
 
-use flux_core::{Config, NotificationUrgency, Translator};
+use flux_core::{
+    Achievement, Config, DigestRegression, FocusMode, NotificationCapabilities,
+    NotificationUrgency, Translator,
+};
 #[cfg(target_os = "linux")]
 use notify_rust::Hint;
 use notify_rust::{Notification, Urgency};
-use tokio::sync::{mpsc, oneshot};
+use serde::Deserialize;
+use tokio::sync::{mpsc, oneshot, Mutex};
 use tracing::{debug, error, info, warn};
 
-const CHECK_IN_TIMEOUT: Duration = Duration::from_secs(120);
+use super::Mailbox;
+use crate::log_throttle::LogThrottle;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+const CHECK_IN_TIMEOUT: Duration = Duration::from_secs(120);
+const CHECK_IN_SHOW_WARNING: &str = "notification_show_check_in";
+const CHECK_IN_FOCUSED_SHOW_WARNING: &str = "notification_show_check_in_focused";
+const SESSION_START_SHOW_WARNING: &str = "notification_show_session_start";
+const SESSION_END_SHOW_WARNING: &str = "notification_show_session_end";
+const SESSION_PAUSED_SHOW_WARNING: &str = "notification_show_session_paused";
+const SESSION_RESUMED_SHOW_WARNING: &str = "notification_show_session_resumed";
+const ALERT_SHOW_WARNING: &str = "notification_show_alert";
+const DISTRACTION_ALERT_SHOW_WARNING: &str = "notification_show_distraction_alert";
+const FRICTION_REMINDER_SHOW_WARNING: &str = "notification_show_friction_reminder";
+const FRICTION_ESCALATED_SHOW_WARNING: &str = "notification_show_friction_escalated";
+const WEEKLY_DIGEST_SHOW_WARNING: &str = "notification_show_weekly_digest";
+const DIGEST_REGRESSION_SHOW_WARNING: &str = "notification_show_digest_regression";
+const VEILLE_REMINDER_SHOW_WARNING: &str = "notification_show_veille_reminder";
+const STREAK_MILESTONE_SHOW_WARNING: &str = "notification_show_streak_milestone";
+const ACHIEVEMENT_SHOW_WARNING: &str = "notification_show_achievement";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum CheckInResponse {
     Focused,
     NotFocused,
+    Timeout,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum FrictionResponse {
     Continue,
     BackToWork,
     StopSession,
 }
 
+pub struct WeeklyDigestSummary {
+    pub total_time: String,
+    pub session_count: usize,
+    pub time_delta: Option<String>,
+    pub top_distraction: Option<String>,
+    pub streak_days: u32,
+    pub report_path: Option<PathBuf>,
+}
+
 pub enum NotifierMessage {
     CheckIn {
         percent: u8,
@@ -29,6 +67,7 @@ pub enum NotifierMessage {
     },
     SessionStart {
         duration_minutes: u64,
+        mode: FocusMode,
     },
     SessionEnd {
         total_minutes: u64,
@@ -54,12 +93,23 @@ pub enum NotifierMessage {
         response_sender: oneshot::Sender<FrictionResponse>,
     },
     WeeklyDigest {
-        total_time: String,
-        session_count: usize,
+        summary: WeeklyDigestSummary,
+    },
+    DigestRegression {
+        regression: DigestRegression,
     },
     VeilleReminder {
         minutes: u64,
     },
+    StreakMilestone {
+        days: u32,
+    },
+    AchievementUnlocked {
+        achievement: Achievement,
+    },
+    SoundMuted {
+        muted: bool,
+    },
 }
 
 #[derive(Clone)]
@@ -68,6 +118,14 @@ pub struct NotifierHandle {
 }
 
 impl NotifierHandle {
+    /// Handle whose messages are read by the caller rather than shown on the
+    /// desktop, for `--simulate`
+    #[cfg(feature = "simulate")]
+    pub fn detached() -> (Self, mpsc::Receiver<NotifierMessage>) {
+        let (sender, receiver) = mpsc::channel(32);
+        (Self { sender }, receiver)
+    }
+
     pub fn send_check_in(&self, percent: u8) -> oneshot::Receiver<CheckInResponse> {
         let (response_sender, response_receiver) = oneshot::channel();
         let sender = self.sender.clone();
@@ -85,11 +143,14 @@ impl NotifierHandle {
         response_receiver
     }
 
-    pub fn send_session_start(&self, duration_minutes: u64) {
+    pub fn send_session_start(&self, duration_minutes: u64, mode: FocusMode) {
         let sender = self.sender.clone();
         tokio::spawn(async move {
             if let Err(error) = sender
-                .send(NotifierMessage::SessionStart { duration_minutes })
+                .send(NotifierMessage::SessionStart {
+                    duration_minutes,
+                    mode,
+                })
                 .await
             {
                 error!(%error, "failed to send session start notification message");
@@ -196,17 +257,23 @@ impl NotifierHandle {
         response_receiver
     }
 
-    pub fn send_weekly_digest(&self, total_time: String, session_count: usize) {
+    pub fn send_weekly_digest(&self, summary: WeeklyDigestSummary) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender.send(NotifierMessage::WeeklyDigest { summary }).await {
+                error!(%error, "failed to send weekly digest notification message");
+            }
+        });
+    }
+
+    pub fn send_digest_regression(&self, regression: DigestRegression) {
         let sender = self.sender.clone();
         tokio::spawn(async move {
             if let Err(error) = sender
-                .send(NotifierMessage::WeeklyDigest {
-                    total_time,
-                    session_count,
-                })
+                .send(NotifierMessage::DigestRegression { regression })
                 .await
             {
-                error!(%error, "failed to send weekly digest notification message");
+                error!(%error, "failed to send digest regression notification message");
             }
         });
     }
@@ -222,16 +289,86 @@ impl NotifierHandle {
             }
         });
     }
+
+    pub fn send_streak_milestone(&self, days: u32) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender.send(NotifierMessage::StreakMilestone { days }).await {
+                error!(%error, "failed to send streak milestone notification message");
+            }
+        });
+    }
+
+    pub fn set_sound_muted(&self, muted: bool) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender.send(NotifierMessage::SoundMuted { muted }).await {
+                error!(%error, "failed to send sound setting to notifier");
+            }
+        });
+    }
+
+    pub fn send_achievement_unlocked(&self, achievement: Achievement) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(error) = sender
+                .send(NotifierMessage::AchievementUnlocked { achievement })
+                .await
+            {
+                error!(%error, "failed to send achievement notification message");
+            }
+        });
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn probe_capabilities() -> NotificationCapabilities {
+    let server_name = match notify_rust::get_server_information() {
+        Ok(information) => Some(information.name),
+        Err(error) => {
+            warn!(%error, "notification server unreachable");
+            return NotificationCapabilities::default();
+        }
+    };
+
+    let capabilities = notify_rust::get_capabilities().unwrap_or_default();
+
+    NotificationCapabilities {
+        server_name,
+        actions: capabilities
+            .iter()
+            .any(|capability| capability == "actions"),
+        sound: capabilities.iter().any(|capability| capability == "sound"),
+        urgency: true,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn probe_capabilities() -> NotificationCapabilities {
+    NotificationCapabilities {
+        server_name: None,
+        actions: false,
+        sound: true,
+        urgency: false,
+    }
 }
 
 pub struct NotifierActor {
-    receiver: mpsc::Receiver<NotifierMessage>,
+    receiver: Mailbox<NotifierMessage>,
     urgency: Urgency,
     sound_enabled: bool,
+    /// Set while the battery profile silences notifications
+    sound_muted: bool,
+    capabilities: NotificationCapabilities,
+    warnings: LogThrottle,
 }
 
 impl NotifierActor {
-    pub fn new(urgency: NotificationUrgency, sound_enabled: bool) -> (Self, NotifierHandle) {
+    pub fn new(
+        urgency: NotificationUrgency,
+        sound_enabled: bool,
+        capabilities: NotificationCapabilities,
+    ) -> (Self, NotifierHandle) {
         let (sender, receiver) = mpsc::channel(32);
 
         let urgency = match urgency {
@@ -240,10 +377,17 @@ impl NotifierActor {
             NotificationUrgency::Critical => Urgency::Critical,
         };
 
+        if !capabilities.actions {
+            warn!("notification server does not support actions, check-ins will not wait for an answer");
+        }
+
         let actor = Self {
-            receiver,
+            receiver: Arc::new(Mutex::new(receiver)),
             urgency,
             sound_enabled,
+            sound_muted: false,
+            capabilities,
+            warnings: LogThrottle::default(),
         };
 
         let handle = NotifierHandle { sender };
@@ -251,10 +395,28 @@ impl NotifierActor {
         (actor, handle)
     }
 
+    /// Builds a fresh actor on the same channel, for the supervisor to replace a crashed one
+    pub fn restarter(&self) -> impl Fn() -> Self + Send + 'static {
+        let receiver = Arc::clone(&self.receiver);
+        let urgency = self.urgency;
+        let sound_enabled = self.sound_enabled;
+        let capabilities = self.capabilities.clone();
+        move || Self {
+            receiver: Arc::clone(&receiver),
+            urgency,
+            sound_enabled,
+            sound_muted: false,
+            capabilities: capabilities.clone(),
+            warnings: LogThrottle::default(),
+        }
+    }
+
     pub async fn run(mut self) {
         info!("notifier actor started");
 
-        while let Some(message) = self.receiver.recv().await {
+        let mailbox = Arc::clone(&self.receiver);
+        let mut receiver = mailbox.lock().await;
+        while let Some(message) = receiver.recv().await {
             match message {
                 NotifierMessage::CheckIn {
                     percent,
@@ -262,8 +424,11 @@ impl NotifierActor {
                 } => {
                     self.send_check_in_notification(percent, response_sender);
                 }
-                NotifierMessage::SessionStart { duration_minutes } => {
-                    self.send_session_start_notification(duration_minutes);
+                NotifierMessage::SessionStart {
+                    duration_minutes,
+                    mode,
+                } => {
+                    self.send_session_start_notification(duration_minutes, &mode);
                 }
                 NotifierMessage::SessionEnd { total_minutes } => {
                     self.send_session_end_notification(total_minutes);
@@ -296,15 +461,25 @@ impl NotifierActor {
                 } => {
                     self.send_friction_escalated_notification(&app, response_sender);
                 }
-                NotifierMessage::WeeklyDigest {
-                    total_time,
-                    session_count,
-                } => {
-                    self.send_weekly_digest_notification(&total_time, session_count);
+                NotifierMessage::WeeklyDigest { summary } => {
+                    self.send_weekly_digest_notification(summary);
+                }
+                NotifierMessage::DigestRegression { regression } => {
+                    self.send_digest_regression_notification(&regression);
                 }
                 NotifierMessage::VeilleReminder { minutes } => {
                     self.send_veille_reminder_notification(minutes);
                 }
+                NotifierMessage::StreakMilestone { days } => {
+                    self.send_streak_milestone_notification(days);
+                }
+                NotifierMessage::AchievementUnlocked { achievement } => {
+                    self.send_achievement_notification(achievement);
+                }
+                NotifierMessage::SoundMuted { muted } => {
+                    debug!(muted, "notification sounds updated");
+                    self.sound_muted = muted;
+                }
             }
         }
 
@@ -313,7 +488,7 @@ impl NotifierActor {
 
     fn get_translator(&self) -> Translator {
         Config::load()
-            .map(|config| Translator::new(config.general.language))
+            .map(|config| Translator::new(config.language()))
             .unwrap_or_default()
     }
 
@@ -328,6 +503,28 @@ impl NotifierActor {
             "notification.check_in_body",
             &[("percent", &percent.to_string())],
         );
+
+        if !self.capabilities.actions {
+            let body = format!(
+                "{}\n{}",
+                body,
+                translator.get("notification.check_in_without_actions")
+            );
+            let notification = self.build_notification(&title, &body);
+            let warnings = self.warnings.clone();
+            tokio::task::spawn_blocking(move || match notification.show() {
+                Ok(_) => recovered(&warnings, CHECK_IN_SHOW_WARNING),
+                Err(error) => {
+                    if let Some(suppressed) = warnings.should_log(CHECK_IN_SHOW_WARNING) {
+                        warn!(%error, suppressed, "failed to show check-in notification");
+                    }
+                }
+            });
+            debug!(percent, "check-in sent without actions, assuming focused");
+            let _ = response_sender.send(CheckInResponse::Focused);
+            return;
+        }
+
         let yes_label = translator.get("notification.check_in_yes");
         let no_label = translator.get("notification.check_in_no");
 
@@ -337,9 +534,12 @@ impl NotifierActor {
             .action("no", &no_label)
             .timeout(CHECK_IN_TIMEOUT.as_millis() as i32);
 
+        let warnings = self.warnings.clone();
+
         #[cfg(target_os = "linux")]
         tokio::task::spawn_blocking(move || match notification.show() {
             Ok(handle) => {
+                recovered(&warnings, CHECK_IN_SHOW_WARNING);
                 let mut response = CheckInResponse::Focused;
 
                 handle.wait_for_action(|action| {
@@ -348,6 +548,10 @@ impl NotifierActor {
                             debug!(percent, "check-in response: not focused");
                             CheckInResponse::NotFocused
                         }
+                        "__closed" => {
+                            debug!(percent, "check-in closed without an answer");
+                            CheckInResponse::Timeout
+                        }
                         _ => {
                             debug!(percent, "check-in response: focused (action={})", action);
                             CheckInResponse::Focused
@@ -358,7 +562,9 @@ impl NotifierActor {
                 let _ = response_sender.send(response);
             }
             Err(error) => {
-                warn!(%error, "failed to show check-in notification");
+                if let Some(suppressed) = warnings.should_log(CHECK_IN_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show check-in notification");
+                }
                 let _ = response_sender.send(CheckInResponse::Focused);
             }
         });
@@ -367,13 +573,16 @@ impl NotifierActor {
         tokio::task::spawn_blocking(move || {
             match notification.show() {
                 Ok(_) => {
+                    recovered(&warnings, CHECK_IN_SHOW_WARNING);
                     debug!(
                         percent,
                         "check-in notification shown (no action support on this platform)"
                     );
                 }
                 Err(error) => {
-                    warn!(%error, "failed to show check-in notification");
+                    if let Some(suppressed) = warnings.should_log(CHECK_IN_SHOW_WARNING) {
+                        warn!(%error, suppressed, "failed to show check-in notification");
+                    }
                 }
             }
             let _ = response_sender.send(CheckInResponse::Focused);
@@ -390,15 +599,18 @@ impl NotifierActor {
 
         match self.build_notification(&title, &body).show() {
             Ok(_) => {
+                recovered(&self.warnings, CHECK_IN_FOCUSED_SHOW_WARNING);
                 debug!("check-in focused notification sent");
             }
             Err(error) => {
-                warn!(%error, "failed to show check-in focused notification");
+                if let Some(suppressed) = self.warnings.should_log(CHECK_IN_FOCUSED_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show check-in focused notification");
+                }
             }
         }
     }
 
-    fn send_session_start_notification(&self, duration_minutes: u64) {
+    fn send_session_start_notification(&self, duration_minutes: u64, mode: &FocusMode) {
         let translator = self.get_translator();
         let title = format!(
             "Flux - {}",
@@ -406,15 +618,21 @@ impl NotifierActor {
         );
         let body = translator.format(
             "notification.session_start_body",
-            &[("duration", &duration_minutes.to_string())],
+            &[
+                ("duration", &duration_minutes.to_string()),
+                ("mode", &translator.mode_name(mode)),
+            ],
         );
 
         match self.build_notification(&title, &body).show() {
             Ok(_) => {
+                recovered(&self.warnings, SESSION_START_SHOW_WARNING);
                 debug!(duration_minutes, "session start notification sent");
             }
             Err(error) => {
-                warn!(%error, "failed to show session start notification");
+                if let Some(suppressed) = self.warnings.should_log(SESSION_START_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show session start notification");
+                }
             }
         }
     }
@@ -425,17 +643,24 @@ impl NotifierActor {
             "Flux - {}",
             translator.get("notification.session_end_title")
         );
-        let body = translator.format(
-            "notification.session_end_body",
-            &[("duration", &total_minutes.to_string())],
+        let body = format!(
+            "{}\n{}",
+            translator.format(
+                "notification.session_end_body",
+                &[("duration", &total_minutes.to_string())],
+            ),
+            translator.get("notification.session_end_retro")
         );
 
         match self.build_notification(&title, &body).show() {
             Ok(_) => {
+                recovered(&self.warnings, SESSION_END_SHOW_WARNING);
                 debug!(total_minutes, "session end notification sent");
             }
             Err(error) => {
-                warn!(%error, "failed to show session end notification");
+                if let Some(suppressed) = self.warnings.should_log(SESSION_END_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show session end notification");
+                }
             }
         }
     }
@@ -447,10 +672,13 @@ impl NotifierActor {
 
         match self.build_notification(&title, &body).show() {
             Ok(_) => {
+                recovered(&self.warnings, SESSION_PAUSED_SHOW_WARNING);
                 debug!("session paused notification sent");
             }
             Err(error) => {
-                warn!(%error, "failed to show session paused notification");
+                if let Some(suppressed) = self.warnings.should_log(SESSION_PAUSED_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show session paused notification");
+                }
             }
         }
     }
@@ -462,10 +690,13 @@ impl NotifierActor {
 
         match self.build_notification(&title, &body).show() {
             Ok(_) => {
+                recovered(&self.warnings, SESSION_RESUMED_SHOW_WARNING);
                 debug!("session resumed notification sent");
             }
             Err(error) => {
-                warn!(%error, "failed to show session resumed notification");
+                if let Some(suppressed) = self.warnings.should_log(SESSION_RESUMED_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show session resumed notification");
+                }
             }
         }
     }
@@ -473,10 +704,13 @@ impl NotifierActor {
     fn send_alert_notification(&self, title: &str, body: &str) {
         match self.build_notification(title, body).show() {
             Ok(_) => {
+                recovered(&self.warnings, ALERT_SHOW_WARNING);
                 debug!(title, "alert notification sent");
             }
             Err(error) => {
-                warn!(%error, title, "failed to show alert notification");
+                if let Some(suppressed) = self.warnings.should_log(ALERT_SHOW_WARNING) {
+                    warn!(%error, title, suppressed, "failed to show alert notification");
+                }
             }
         }
     }
@@ -484,22 +718,31 @@ impl NotifierActor {
     fn send_distraction_alert_notification(&self, title: &str, body: &str) {
         match self.build_distraction_notification(title, body).show() {
             Ok(_) => {
+                recovered(&self.warnings, DISTRACTION_ALERT_SHOW_WARNING);
                 debug!(title, "distraction alert notification sent");
             }
             Err(error) => {
-                warn!(%error, title, "failed to show distraction alert notification");
+                if let Some(suppressed) = self.warnings.should_log(DISTRACTION_ALERT_SHOW_WARNING) {
+                    warn!(%error, title, suppressed, "failed to show distraction alert notification");
+                }
             }
         }
     }
 
+    fn plays_sound(&self) -> bool {
+        self.sound_enabled && !self.sound_muted && self.capabilities.sound
+    }
+
     fn build_notification(&self, summary: &str, body: &str) -> Notification {
         let mut notification = Notification::new();
         notification.summary(summary).body(body).appname("Flux");
 
         #[cfg(target_os = "linux")]
-        notification.hint(Hint::Urgency(self.urgency));
+        if self.capabilities.urgency {
+            notification.hint(Hint::Urgency(self.urgency));
+        }
 
-        if self.sound_enabled {
+        if self.plays_sound() {
             notification.sound_name("message-new-instant");
         }
 
@@ -515,9 +758,11 @@ impl NotifierActor {
             .icon("dialog-warning");
 
         #[cfg(target_os = "linux")]
-        notification.hint(Hint::Urgency(Urgency::Critical));
+        if self.capabilities.urgency {
+            notification.hint(Hint::Urgency(Urgency::Critical));
+        }
 
-        if self.sound_enabled {
+        if self.plays_sound() {
             notification.sound_name("dialog-warning");
         }
 
@@ -545,9 +790,12 @@ impl NotifierActor {
             .action("back", &no_label)
             .timeout(60000);
 
+        let warnings = self.warnings.clone();
+
         #[cfg(target_os = "linux")]
         tokio::task::spawn_blocking(move || match notification.show() {
             Ok(handle) => {
+                recovered(&warnings, FRICTION_REMINDER_SHOW_WARNING);
                 let mut response = FrictionResponse::Continue;
                 handle.wait_for_action(|action| {
                     response = match action {
@@ -558,7 +806,9 @@ impl NotifierActor {
                 let _ = response_sender.send(response);
             }
             Err(error) => {
-                warn!(%error, "failed to show friction reminder notification");
+                if let Some(suppressed) = warnings.should_log(FRICTION_REMINDER_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show friction reminder notification");
+                }
                 let _ = response_sender.send(FrictionResponse::Continue);
             }
         });
@@ -569,10 +819,15 @@ impl NotifierActor {
             tokio::task::spawn_blocking(move || {
                 match notification.show() {
                     Ok(_) => {
+                        recovered(&warnings, FRICTION_REMINDER_SHOW_WARNING);
                         debug!("friction reminder notification shown (no action support on this platform)");
                     }
                     Err(error) => {
-                        warn!(%error, "failed to show friction reminder notification");
+                        if let Some(suppressed) =
+                            warnings.should_log(FRICTION_REMINDER_SHOW_WARNING)
+                        {
+                            warn!(%error, suppressed, "failed to show friction reminder notification");
+                        }
                     }
                 }
                 let _ = response_sender.send(FrictionResponse::Continue);
@@ -600,9 +855,12 @@ impl NotifierActor {
             .action("stop", &stop_label)
             .timeout(60000);
 
+        let warnings = self.warnings.clone();
+
         #[cfg(target_os = "linux")]
         tokio::task::spawn_blocking(move || match notification.show() {
             Ok(handle) => {
+                recovered(&warnings, FRICTION_ESCALATED_SHOW_WARNING);
                 let mut response = FrictionResponse::Continue;
                 handle.wait_for_action(|action| {
                     response = match action {
@@ -613,7 +871,9 @@ impl NotifierActor {
                 let _ = response_sender.send(response);
             }
             Err(error) => {
-                warn!(%error, "failed to show friction escalated notification");
+                if let Some(suppressed) = warnings.should_log(FRICTION_ESCALATED_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show friction escalated notification");
+                }
                 let _ = response_sender.send(FrictionResponse::Continue);
             }
         });
@@ -624,10 +884,15 @@ impl NotifierActor {
             tokio::task::spawn_blocking(move || {
                 match notification.show() {
                     Ok(_) => {
+                        recovered(&warnings, FRICTION_ESCALATED_SHOW_WARNING);
                         debug!("friction escalated notification shown (no action support on this platform)");
                     }
                     Err(error) => {
-                        warn!(%error, "failed to show friction escalated notification");
+                        if let Some(suppressed) =
+                            warnings.should_log(FRICTION_ESCALATED_SHOW_WARNING)
+                        {
+                            warn!(%error, suppressed, "failed to show friction escalated notification");
+                        }
                     }
                 }
                 let _ = response_sender.send(FrictionResponse::Continue);
@@ -635,23 +900,97 @@ impl NotifierActor {
         }
     }
 
-    fn send_weekly_digest_notification(&self, total_time: &str, session_count: usize) {
+    fn send_weekly_digest_notification(&self, summary: WeeklyDigestSummary) {
         let translator = self.get_translator();
         let title = format!("Flux - {}", translator.get("notification.digest_title"));
-        let body = translator.format(
+        let mut body = translator.format(
             "notification.digest_body",
             &[
-                ("time", total_time),
-                ("sessions", &session_count.to_string()),
+                ("time", &summary.total_time),
+                ("sessions", &summary.session_count.to_string()),
             ],
         );
+        if let Some(delta) = &summary.time_delta {
+            body.push('\n');
+            body.push_str(&translator.format("notification.digest_delta", &[("delta", delta)]));
+        }
+        if let Some(application) = &summary.top_distraction {
+            body.push('\n');
+            body.push_str(&translator.format(
+                "notification.digest_top_distraction",
+                &[("app", application)],
+            ));
+        }
+        if summary.streak_days > 0 {
+            body.push('\n');
+            body.push_str(&translator.format(
+                "notification.digest_streak",
+                &[("days", &summary.streak_days.to_string())],
+            ));
+        }
 
-        match self.build_notification(&title, &body).show() {
+        let mut notification = self.build_notification(&title, &body);
+        let warnings = self.warnings.clone();
+
+        #[cfg(target_os = "linux")]
+        if let Some(report_path) = summary.report_path {
+            let open_label = translator.get("notification.digest_open_report");
+            notification.action("open", &open_label);
+
+            tokio::task::spawn_blocking(move || match notification.show() {
+                Ok(handle) => {
+                    recovered(&warnings, WEEKLY_DIGEST_SHOW_WARNING);
+                    info!("weekly digest notification sent");
+                    handle.wait_for_action(|action| {
+                        if action == "open" {
+                            if let Err(error) = Command::new("xdg-open").arg(&report_path).spawn() {
+                                warn!(%error, "failed to open weekly digest report");
+                            }
+                        }
+                    });
+                }
+                Err(error) => {
+                    if let Some(suppressed) = warnings.should_log(WEEKLY_DIGEST_SHOW_WARNING) {
+                        warn!(%error, suppressed, "failed to show weekly digest notification");
+                    }
+                }
+            });
+            return;
+        }
+
+        match notification.show() {
             Ok(_) => {
+                recovered(&warnings, WEEKLY_DIGEST_SHOW_WARNING);
                 info!("weekly digest notification sent");
             }
             Err(error) => {
-                warn!(%error, "failed to show weekly digest notification");
+                if let Some(suppressed) = warnings.should_log(WEEKLY_DIGEST_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show weekly digest notification");
+                }
+            }
+        }
+    }
+
+    fn send_digest_regression_notification(&self, regression: &DigestRegression) {
+        let translator = self.get_translator();
+        let title = format!("Flux - {}", translator.get("notification.regression_title"));
+        let body = regression_body(regression, &translator);
+
+        let mut notification = self.build_notification(&title, &body);
+        #[cfg(target_os = "linux")]
+        if self.capabilities.urgency {
+            notification.hint(Hint::Urgency(Urgency::Low));
+        }
+
+        match notification.show() {
+            Ok(_) => {
+                recovered(&self.warnings, DIGEST_REGRESSION_SHOW_WARNING);
+                info!("digest regression notification sent");
+            }
+            Err(error) => {
+                if let Some(suppressed) = self.warnings.should_log(DIGEST_REGRESSION_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show digest regression notification");
+                }
             }
         }
     }
@@ -669,28 +1008,123 @@ impl NotifierActor {
 
         match self.build_notification(&title, &body).show() {
             Ok(_) => {
+                recovered(&self.warnings, VEILLE_REMINDER_SHOW_WARNING);
                 info!(minutes, "veille reminder notification sent");
             }
             Err(error) => {
-                warn!(%error, "failed to show veille reminder notification");
+                if let Some(suppressed) = self.warnings.should_log(VEILLE_REMINDER_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show veille reminder notification");
+                }
+            }
+        }
+    }
+
+    fn send_streak_milestone_notification(&self, days: u32) {
+        let translator = self.get_translator();
+        let title = format!(
+            "Flux - {}",
+            translator.get("notification.streak_milestone_title")
+        );
+        let body = translator.format(
+            "notification.streak_milestone_body",
+            &[("days", &days.to_string())],
+        );
+
+        match self.build_notification(&title, &body).show() {
+            Ok(_) => {
+                recovered(&self.warnings, STREAK_MILESTONE_SHOW_WARNING);
+                info!(days, "streak milestone notification sent");
+            }
+            Err(error) => {
+                if let Some(suppressed) = self.warnings.should_log(STREAK_MILESTONE_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show streak milestone notification");
+                }
+            }
+        }
+    }
+
+    fn send_achievement_notification(&self, achievement: Achievement) {
+        let translator = self.get_translator();
+        let title = format!(
+            "Flux - {}",
+            translator.get("notification.achievement_title")
+        );
+        let body = translator.format(
+            "notification.achievement_body",
+            &[
+                (
+                    "name",
+                    &translator.get(&format!("achievement.{}_name", achievement.id())),
+                ),
+                (
+                    "description",
+                    &translator.get(&format!("achievement.{}_description", achievement.id())),
+                ),
+            ],
+        );
+
+        match self.build_notification(&title, &body).show() {
+            Ok(_) => {
+                recovered(&self.warnings, ACHIEVEMENT_SHOW_WARNING);
+                info!(
+                    achievement = achievement.id(),
+                    "achievement notification sent"
+                );
+            }
+            Err(error) => {
+                if let Some(suppressed) = self.warnings.should_log(ACHIEVEMENT_SHOW_WARNING) {
+                    warn!(%error, suppressed, "failed to show achievement notification");
+                }
             }
         }
     }
 }
 
+/// A notification shown again ends the throttling of its kind's failures
+fn recovered(warnings: &LogThrottle, key: &'static str) {
+    if let Some(suppressed) = warnings.recover(key) {
+        info!(key, suppressed, "notifications shown again");
+    }
+}
+
+fn regression_body(regression: &DigestRegression, translator: &Translator) -> String {
+    let mut lines = Vec::new();
+    if let Some(percent) = regression.focus_drop_percent {
+        lines.push(translator.format(
+            "notification.regression_focus_drop",
+            &[("percent", &percent.to_string())],
+        ));
+    }
+    if regression.distractions_doubled {
+        lines.push(translator.get("notification.regression_distractions"));
+    }
+    lines.push(match &regression.top_application {
+        Some(application) => {
+            translator.format("notification.regression_top_app", &[("app", application)])
+        }
+        None => translator.get("notification.regression_encouragement"),
+    });
+    lines.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use flux_core::Language;
 
     #[tokio::test]
     async fn handle_can_send_simple_messages() {
-        let (actor, handle) = NotifierActor::new(NotificationUrgency::Normal, false);
+        let (actor, handle) = NotifierActor::new(
+            NotificationUrgency::Normal,
+            false,
+            NotificationCapabilities::default(),
+        );
 
         let actor_task = tokio::spawn(async move {
             tokio::time::timeout(std::time::Duration::from_millis(100), actor.run()).await
         });
 
-        handle.send_session_start(45);
+        handle.send_session_start(45, FocusMode::Review);
         handle.send_session_end(45);
         handle.send_session_paused();
         handle.send_session_resumed();
@@ -702,8 +1136,47 @@ mod tests {
         let _ = actor_task.await;
     }
 
+    #[tokio::test]
+    async fn check_in_without_actions_answers_focused_immediately() {
+        let capabilities = NotificationCapabilities {
+            server_name: Some("dunst".to_string()),
+            actions: false,
+            sound: false,
+            urgency: true,
+        };
+        let (actor, handle) = NotifierActor::new(NotificationUrgency::Normal, false, capabilities);
+        let actor_task = tokio::spawn(actor.run());
+
+        let response = tokio::time::timeout(Duration::from_secs(1), handle.send_check_in(50))
+            .await
+            .expect("check-in should not wait for an action")
+            .unwrap();
+
+        assert_eq!(response, CheckInResponse::Focused);
+
+        drop(handle);
+        let _ = actor_task.await;
+    }
+
     #[test]
     fn check_in_response_variants() {
         assert_ne!(CheckInResponse::Focused, CheckInResponse::NotFocused);
     }
+
+    #[test]
+    fn regression_body_names_the_drop_and_the_top_application() {
+        let translator = Translator::new(Language::En);
+        let regression = DigestRegression {
+            focus_drop_percent: Some(40),
+            distractions_doubled: true,
+            top_application: Some("youtube".to_string()),
+        };
+
+        assert_eq!(
+            regression_body(&regression, &translator),
+            "Focus time is down 40% from last week\n\
+             Distraction time doubled since last week\n\
+             Mostly youtube. A short session today can get things going again."
+        );
+    }
 }
@@ -1,21 +1,70 @@
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use tokio::sync::{mpsc, oneshot};
-use tracing::{debug, error, info};
+// The runtime clock, so a paused one (`--simulate`) drives the countdown
+use tokio::time::Instant;
+use tracing::{debug, error, info, warn};
 
-use flux_core::{Config, FocusMode, Session, SessionRepository, Translator};
+use flux_core::{
+    CheckInOutcome, Config, FocusConfig, FocusMode, InterruptionKind, ModeSettings, Session,
+    SessionEndReason, SessionInterruption, SessionRepository, Translator,
+};
 
-#[cfg(target_os = "linux")]
 use super::TrayStateHandle;
-use super::{AppTrackerHandle, CheckInResponse, NotifierHandle};
+use super::{
+    AchievementsHandle, AppTrackerHandle, CheckInResponse, HookContext, HookEvent,
+    HookRunnerHandle, NotifierHandle,
+};
+use crate::dnd::DoNotDisturb;
+use crate::storage;
 
 pub enum TimerMessage {
-    Start { duration: Duration, mode: FocusMode },
-    Stop,
-    Pause,
+    Start {
+        duration: Option<Duration>,
+        mode: FocusMode,
+        tags: Vec<String>,
+        distraction_apps: Vec<String>,
+        request_id: Option<String>,
+        strict: bool,
+        reply: oneshot::Sender<StartOutcome>,
+    },
+    Stop {
+        confirmed: bool,
+        reason: Option<String>,
+        reply: oneshot::Sender<InterruptOutcome>,
+    },
+    End {
+        reason: SessionEndReason,
+    },
+    Pause {
+        confirmed: bool,
+        reason: Option<String>,
+        reply: oneshot::Sender<InterruptOutcome>,
+    },
     Resume,
-    GetStatus { reply: oneshot::Sender<TimerStatus> },
+    GetStatus {
+        reply: oneshot::Sender<TimerStatus>,
+    },
+    /// Announces the running session again to a restarted app tracker
+    ResendTracking,
+}
+
+#[derive(Debug, Clone)]
+pub enum StartOutcome {
+    Started,
+    AlreadyStarted,
+    AlreadyActive(TimerStatus),
+}
+
+/// Answer to a pause or stop request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptOutcome {
+    Done,
+    /// The session is strict and the request was not confirmed
+    Refused {
+        remaining: Duration,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +73,8 @@ pub struct TimerStatus {
     pub remaining: Duration,
     pub mode: Option<FocusMode>,
     pub paused: bool,
+    pub preparing: Option<Duration>,
+    pub strict: bool,
 }
 
 struct TimerState {
@@ -32,22 +83,37 @@ struct TimerState {
     remaining: Duration,
     last_tick: Instant,
     paused: bool,
+    check_ins_enabled: bool,
     check_ins_done: [bool; 3],
     veille_reminder_sent: bool,
+    preparation: Option<Preparation>,
+    strict: bool,
+    distraction_apps: Vec<String>,
+}
+
+/// Countdown between the start request and the session, while the user gets ready
+struct Preparation {
+    remaining: Duration,
+    tags: Vec<String>,
+    reminder_sent: bool,
 }
 
 const CHECK_IN_THRESHOLDS: [u8; 3] = [25, 50, 75];
+const PREPARATION_REMINDER: Duration = Duration::from_secs(10);
 
 pub struct TimerActor {
     receiver: mpsc::Receiver<TimerMessage>,
     state: Option<TimerState>,
     notifier: Option<NotifierHandle>,
     app_tracker: Option<AppTrackerHandle>,
-    #[cfg(target_os = "linux")]
+    achievements: Option<AchievementsHandle>,
+    hooks: Option<HookRunnerHandle>,
     tray_state: Option<TrayStateHandle>,
     session_repository: Option<Arc<dyn SessionRepository>>,
+    do_not_disturb: Option<Arc<dyn DoNotDisturb>>,
     current_session: Option<Session>,
     pending_check_in: Option<oneshot::Receiver<CheckInResponse>>,
+    last_start_request_id: Option<String>,
 }
 
 #[derive(Clone)]
@@ -58,26 +124,72 @@ pub struct TimerHandle {
 impl TimerHandle {
     pub async fn start(
         &self,
-        duration: Duration,
+        duration: Option<Duration>,
         mode: FocusMode,
-    ) -> Result<(), mpsc::error::SendError<TimerMessage>> {
+        tags: Vec<String>,
+        distraction_apps: Vec<String>,
+        request_id: Option<String>,
+        strict: bool,
+    ) -> Option<StartOutcome> {
+        let (reply_sender, reply_receiver) = oneshot::channel();
+        self.sender
+            .send(TimerMessage::Start {
+                duration,
+                mode,
+                tags,
+                distraction_apps,
+                request_id,
+                strict,
+                reply: reply_sender,
+            })
+            .await
+            .ok()?;
+        reply_receiver.await.ok()
+    }
+
+    /// `confirmed` lets the request through a strict session; `reason` is recorded with it
+    pub async fn stop(&self, confirmed: bool, reason: Option<String>) -> Option<InterruptOutcome> {
+        let (reply_sender, reply_receiver) = oneshot::channel();
         self.sender
-            .send(TimerMessage::Start { duration, mode })
+            .send(TimerMessage::Stop {
+                confirmed,
+                reason,
+                reply: reply_sender,
+            })
             .await
+            .ok()?;
+        reply_receiver.await.ok()
     }
 
-    pub async fn stop(&self) -> Result<(), mpsc::error::SendError<TimerMessage>> {
-        self.sender.send(TimerMessage::Stop).await
+    pub async fn end(
+        &self,
+        reason: SessionEndReason,
+    ) -> Result<(), mpsc::error::SendError<TimerMessage>> {
+        self.sender.send(TimerMessage::End { reason }).await
     }
 
-    pub async fn pause(&self) -> Result<(), mpsc::error::SendError<TimerMessage>> {
-        self.sender.send(TimerMessage::Pause).await
+    /// `confirmed` lets the request through a strict session; `reason` is recorded with it
+    pub async fn pause(&self, confirmed: bool, reason: Option<String>) -> Option<InterruptOutcome> {
+        let (reply_sender, reply_receiver) = oneshot::channel();
+        self.sender
+            .send(TimerMessage::Pause {
+                confirmed,
+                reason,
+                reply: reply_sender,
+            })
+            .await
+            .ok()?;
+        reply_receiver.await.ok()
     }
 
     pub async fn resume(&self) -> Result<(), mpsc::error::SendError<TimerMessage>> {
         self.sender.send(TimerMessage::Resume).await
     }
 
+    pub async fn resend_tracking(&self) -> Result<(), mpsc::error::SendError<TimerMessage>> {
+        self.sender.send(TimerMessage::ResendTracking).await
+    }
+
     pub async fn get_status(&self) -> Option<TimerStatus> {
         let (reply_sender, reply_receiver) = oneshot::channel();
         self.sender
@@ -91,12 +203,14 @@ impl TimerHandle {
 }
 
 impl TimerActor {
-    #[cfg(target_os = "linux")]
     pub fn new(
         notifier: Option<NotifierHandle>,
         app_tracker: Option<AppTrackerHandle>,
+        achievements: Option<AchievementsHandle>,
+        hooks: Option<HookRunnerHandle>,
         tray_state: Option<TrayStateHandle>,
         session_repository: Option<Arc<dyn SessionRepository>>,
+        do_not_disturb: Option<Arc<dyn DoNotDisturb>>,
     ) -> (Self, TimerHandle) {
         let (sender, receiver) = mpsc::channel(32);
 
@@ -105,10 +219,14 @@ impl TimerActor {
             state: None,
             notifier,
             app_tracker,
+            achievements,
+            hooks,
             tray_state,
             session_repository,
+            do_not_disturb,
             current_session: None,
             pending_check_in: None,
+            last_start_request_id: None,
         };
 
         let handle = TimerHandle { sender };
@@ -116,27 +234,184 @@ impl TimerActor {
         (actor, handle)
     }
 
-    #[cfg(not(target_os = "linux"))]
-    pub fn new(
-        notifier: Option<NotifierHandle>,
-        app_tracker: Option<AppTrackerHandle>,
-        session_repository: Option<Arc<dyn SessionRepository>>,
-    ) -> (Self, TimerHandle) {
-        let (sender, receiver) = mpsc::channel(32);
+    #[allow(clippy::too_many_arguments)]
+    async fn start_session(
+        &mut self,
+        mode: FocusMode,
+        duration: Duration,
+        check_ins_enabled: bool,
+        tags: Vec<String>,
+        distraction_apps: Vec<String>,
+        preparation: Duration,
+        strict: bool,
+    ) {
+        self.state = Some(TimerState {
+            mode: mode.clone(),
+            total_duration: duration,
+            remaining: duration,
+            last_tick: Instant::now(),
+            paused: false,
+            check_ins_enabled,
+            check_ins_done: [false; 3],
+            veille_reminder_sent: false,
+            preparation: None,
+            strict,
+            distraction_apps,
+        });
+
+        if preparation.is_zero() {
+            self.begin_session(tags).await;
+            return;
+        }
 
-        let actor = Self {
-            receiver,
-            state: None,
-            notifier,
-            app_tracker,
-            session_repository,
-            current_session: None,
-            pending_check_in: None,
+        info!(?mode, ?preparation, "session preparation started");
+        if let Some(ref mut state) = self.state {
+            state.preparation = Some(Preparation {
+                remaining: preparation,
+                tags,
+                reminder_sent: preparation <= PREPARATION_REMINDER,
+            });
+        }
+        self.update_tray_preparing(preparation, mode);
+        self.notify_preparation(preparation);
+    }
+
+    async fn begin_session(&mut self, tags: Vec<String>) {
+        let Some(ref mut state) = self.state else {
+            return;
         };
+        state.last_tick = Instant::now();
+        let mode = state.mode.clone();
+        let duration = state.total_duration;
+        let duration_minutes = duration.as_secs() / 60;
+        let distraction_apps = state.distraction_apps.clone();
+        info!(
+            ?mode,
+            ?duration,
+            check_ins_enabled = state.check_ins_enabled,
+            "session started"
+        );
+
+        self.persist_new_session(mode.clone(), tags, duration_minutes)
+            .await;
+        self.enable_do_not_disturb();
+        self.update_tray_active(duration, mode.clone());
+        self.run_hook(HookEvent::SessionStart);
 
-        let handle = TimerHandle { sender };
+        if let Some(ref notifier) = self.notifier {
+            notifier.send_session_start(duration_minutes, mode.clone());
+        }
 
-        (actor, handle)
+        if let (Some(ref app_tracker), Some(ref session)) =
+            (&self.app_tracker, &self.current_session)
+        {
+            if let Some(session_id) = session.id {
+                app_tracker.send_session_started(session_id, mode, distraction_apps);
+            }
+        }
+    }
+
+    /// A session still in preparation is announced when it starts
+    fn resend_tracking(&self) {
+        let (Some(app_tracker), Some(state), Some(session)) =
+            (&self.app_tracker, &self.state, &self.current_session)
+        else {
+            return;
+        };
+        if state.preparation.is_some() {
+            return;
+        }
+        if let Some(session_id) = session.id {
+            info!(session_id, "session announced again to the app tracker");
+            app_tracker.send_session_restored(
+                session_id,
+                state.mode.clone(),
+                state.distraction_apps.clone(),
+                state.paused,
+            );
+        }
+    }
+
+    /// Counts the preparation down; returns false once the session itself is running
+    async fn tick_preparation(&mut self) -> bool {
+        let Some(ref mut state) = self.state else {
+            return false;
+        };
+        if state.preparation.is_none() {
+            return false;
+        }
+        let elapsed = state.last_tick.elapsed();
+        state.last_tick = Instant::now();
+        self.advance_preparation(elapsed).await;
+        true
+    }
+
+    async fn advance_preparation(&mut self, elapsed: Duration) {
+        let Some(ref mut state) = self.state else {
+            return;
+        };
+        let Some(ref mut preparation) = state.preparation else {
+            return;
+        };
+        preparation.remaining = preparation.remaining.saturating_sub(elapsed);
+        let remaining = preparation.remaining;
+
+        if remaining.is_zero() {
+            let tags = state
+                .preparation
+                .take()
+                .map(|preparation| preparation.tags)
+                .unwrap_or_default();
+            self.begin_session(tags).await;
+            return;
+        }
+
+        let send_reminder = remaining <= PREPARATION_REMINDER && !preparation.reminder_sent;
+        if send_reminder {
+            preparation.reminder_sent = true;
+        }
+        let mode = state.mode.clone();
+        self.update_tray_preparing(remaining, mode);
+        if send_reminder {
+            self.notify_preparation(remaining);
+        }
+    }
+
+    /// Drops a session that has not begun yet; nothing was recorded for it
+    fn cancel_preparation(&mut self) -> bool {
+        let preparing = self
+            .state
+            .as_ref()
+            .is_some_and(|state| state.preparation.is_some());
+        if preparing {
+            info!("session preparation cancelled");
+            self.state = None;
+            self.update_tray_inactive();
+        }
+        preparing
+    }
+
+    /// A strict session that has begun only yields to a confirmed pause or stop
+    fn strict_refusal(&self, confirmed: bool) -> Option<InterruptOutcome> {
+        let state = self.state.as_ref()?;
+        (state.strict && !confirmed && state.preparation.is_none()).then_some(
+            InterruptOutcome::Refused {
+                remaining: state.remaining,
+            },
+        )
+    }
+
+    fn notify_preparation(&self, remaining: Duration) {
+        if let Some(ref notifier) = self.notifier {
+            let translator = Self::get_translator();
+            notifier.send_alert(
+                translator.get("notification.preparation_title"),
+                translator.format(
+                    "notification.preparation_body",
+                    &[("seconds", &remaining.as_secs().to_string())],
+                ),
+            );
+        }
     }
 
     fn total_minutes(&self) -> u64 {
@@ -146,10 +421,23 @@ impl TimerActor {
             .unwrap_or(0)
     }
 
-    fn persist_new_session(&mut self, mode: FocusMode) {
+    async fn persist_new_session(
+        &mut self,
+        mode: FocusMode,
+        tags: Vec<String>,
+        planned_minutes: u64,
+    ) {
         if let Some(ref repository) = self.session_repository {
-            let mut session = Session::start(mode);
-            match repository.save(&mut session) {
+            let session = Session::start(mode)
+                .with_tags(tags)
+                .with_planned_minutes(planned_minutes);
+            let (session, saved) = storage::blocking(repository, move |repository| {
+                let mut session = session;
+                let saved = repository.save(&mut session);
+                (session, saved)
+            })
+            .await;
+            match saved {
                 Ok(_) => {
                     debug!("session persisted");
                     self.current_session = Some(session);
@@ -162,31 +450,102 @@ impl TimerActor {
         }
     }
 
-    fn persist_session_end(&mut self) {
+    /// Focus time elapsed against the planned duration, pauses excluded
+    fn stop_reason(&self) -> SessionEndReason {
+        let Some(ref state) = self.state else {
+            return SessionEndReason::StoppedEarly;
+        };
+        let elapsed = state.total_duration.saturating_sub(state.remaining);
+        SessionEndReason::for_stop(
+            elapsed.as_secs(),
+            state.total_duration.as_secs(),
+            resolve_auto_complete_threshold(),
+        )
+    }
+
+    async fn persist_session_end(&mut self, reason: SessionEndReason) {
         if let (Some(ref repository), Some(ref mut session)) =
             (&self.session_repository, &mut self.current_session)
         {
-            session.end();
-            if let Err(err) = repository.update(session) {
+            session.end_with_reason(chrono::Utc::now(), reason);
+            let ended = session.clone();
+            if let Err(err) =
+                storage::blocking(repository, move |repository| repository.update(&ended)).await
+            {
                 error!(%err, "failed to update session on end");
                 self.notify_persistence_error();
+            } else if let Some(ref achievements) = self.achievements {
+                achievements
+                    .send_session_completed(session.id, session.duration_seconds.unwrap_or(0));
             }
         }
         self.current_session = None;
     }
 
-    fn persist_check_in(&mut self) {
+    async fn persist_interruption(&self, kind: InterruptionKind, reason: Option<&str>) {
+        let (Some(repository), Some(session_id), Some(reason)) = (
+            &self.session_repository,
+            self.current_session.as_ref().and_then(|session| session.id),
+            reason,
+        ) else {
+            return;
+        };
+        let Some(interruption) = SessionInterruption::new(session_id, kind, reason) else {
+            return;
+        };
+        if let Err(err) = storage::blocking(repository, move |repository| {
+            repository.record_interruption(&interruption)
+        })
+        .await
+        {
+            warn!(%err, "failed to record interruption reason");
+        }
+    }
+
+    async fn persist_check_in(&mut self) {
         if let (Some(ref repository), Some(ref mut session)) =
             (&self.session_repository, &mut self.current_session)
         {
             session.increment_check_in();
-            if let Err(err) = repository.update(session) {
+            let updated = session.clone();
+            if let Err(err) =
+                storage::blocking(repository, move |repository| repository.update(&updated)).await
+            {
                 error!(%err, "failed to update session check-in count");
                 self.notify_persistence_error();
             }
         }
     }
 
+    fn run_hook(&self, event: HookEvent) {
+        if let (Some(ref hooks), Some(ref state)) = (&self.hooks, &self.state) {
+            hooks.send_event(
+                event,
+                HookContext {
+                    mode: state.mode.clone(),
+                    duration: state.total_duration,
+                    session_id: self.current_session.as_ref().and_then(|session| session.id),
+                },
+            );
+        }
+    }
+
+    fn enable_do_not_disturb(&self) {
+        if let Some(ref do_not_disturb) = self.do_not_disturb {
+            if let Err(error) = do_not_disturb.enable() {
+                warn!(%error, "failed to enable do-not-disturb");
+            }
+        }
+    }
+
+    fn restore_do_not_disturb(&self) {
+        if let Some(ref do_not_disturb) = self.do_not_disturb {
+            if let Err(error) = do_not_disturb.restore() {
+                warn!(%error, "failed to restore do-not-disturb");
+            }
+        }
+    }
+
     fn notify_persistence_error(&self) {
         if let Some(ref notifier) = self.notifier {
             let translator = Self::get_translator();
@@ -199,60 +558,46 @@ impl TimerActor {
 
     fn get_translator() -> Translator {
         Config::load()
-            .map(|config| Translator::new(config.general.language))
+            .map(|config| Translator::new(config.language()))
             .unwrap_or_default()
     }
 
-    #[cfg(target_os = "linux")]
     fn update_tray_active(&self, remaining: Duration, mode: FocusMode) {
         if let Some(ref tray) = self.tray_state {
             tray.set_active(remaining, mode);
         }
     }
 
-    #[cfg(not(target_os = "linux"))]
-    fn update_tray_active(&self, _remaining: Duration, _mode: FocusMode) {}
+    fn update_tray_preparing(&self, remaining: Duration, mode: FocusMode) {
+        if let Some(ref tray) = self.tray_state {
+            tray.set_preparing(remaining, mode);
+        }
+    }
 
-    #[cfg(target_os = "linux")]
     fn update_tray_paused(&self, remaining: Duration) {
         if let Some(ref tray) = self.tray_state {
             tray.set_paused(remaining);
         }
     }
 
-    #[cfg(not(target_os = "linux"))]
-    fn update_tray_paused(&self, _remaining: Duration) {}
-
-    #[cfg(target_os = "linux")]
     fn update_tray_remaining(&self, remaining: Duration, mode: FocusMode) {
         if let Some(ref tray) = self.tray_state {
             tray.update_remaining(remaining, mode);
         }
     }
 
-    #[cfg(not(target_os = "linux"))]
-    fn update_tray_remaining(&self, _remaining: Duration, _mode: FocusMode) {}
-
-    #[cfg(target_os = "linux")]
     fn update_tray_inactive(&self) {
         if let Some(ref tray) = self.tray_state {
             tray.set_inactive();
         }
     }
 
-    #[cfg(not(target_os = "linux"))]
-    fn update_tray_inactive(&self) {}
-
-    #[cfg(target_os = "linux")]
     fn update_tray_check_in(&self) {
         if let Some(ref tray) = self.tray_state {
             tray.set_check_in_pending();
         }
     }
 
-    #[cfg(not(target_os = "linux"))]
-    fn update_tray_check_in(&self) {}
-
     fn elapsed_percent(&self) -> u8 {
         self.state
             .as_ref()
@@ -266,7 +611,7 @@ impl TimerActor {
 
     fn next_check_in_threshold(&self) -> Option<(usize, u8)> {
         self.state.as_ref().and_then(|state| {
-            if state.mode.disables_interruptions() {
+            if !state.check_ins_enabled {
                 return None;
             }
 
@@ -320,11 +665,16 @@ impl TimerActor {
                 Ok(CheckInResponse::NotFocused) => {
                     info!("check-in response: not focused, pausing session");
                     self.pending_check_in = None;
+                    self.record_check_in_outcome(CheckInOutcome::NotFocused);
                     self.pause_session_internal();
                 }
-                Ok(CheckInResponse::Focused) => {
-                    debug!("check-in response: focused, continuing");
+                Ok(response @ (CheckInResponse::Focused | CheckInResponse::Timeout)) => {
+                    debug!(?response, "check-in answered, continuing");
                     self.pending_check_in = None;
+                    self.record_check_in_outcome(match response {
+                        CheckInResponse::Timeout => CheckInOutcome::Timeout,
+                        _ => CheckInOutcome::Focused,
+                    });
 
                     if let Some(ref state) = self.state {
                         self.update_tray_active(state.remaining, state.mode.clone());
@@ -338,14 +688,25 @@ impl TimerActor {
                 Err(oneshot::error::TryRecvError::Closed) => {
                     debug!("check-in response channel closed, assuming focused");
                     self.pending_check_in = None;
+                    self.record_check_in_outcome(CheckInOutcome::Timeout);
                 }
             }
         }
     }
 
+    fn record_check_in_outcome(&self, outcome: CheckInOutcome) {
+        if let Some(ref app_tracker) = self.app_tracker {
+            app_tracker.send_check_in_answered(outcome);
+        }
+    }
+
     fn pause_session_internal(&mut self) {
         if let Some(ref mut state) = self.state {
-            if !state.paused {
+            if state.strict {
+                debug!("check-in pause skipped, the session is strict");
+                let (remaining, mode) = (state.remaining, state.mode.clone());
+                self.update_tray_active(remaining, mode);
+            } else if !state.paused {
                 state.paused = true;
                 let remaining = state.remaining;
                 info!("session paused from check-in");
@@ -354,7 +715,9 @@ impl TimerActor {
                     app_tracker.send_session_paused();
                 }
 
+                self.restore_do_not_disturb();
                 self.update_tray_paused(remaining);
+                self.run_hook(HookEvent::Pause);
 
                 if let Some(ref notifier) = self.notifier {
                     notifier.send_session_paused();
@@ -370,42 +733,73 @@ impl TimerActor {
             tokio::select! {
                 Some(message) = self.receiver.recv() => {
                     match message {
-                        TimerMessage::Start { duration, mode } => {
-                            info!(?mode, ?duration, "session started");
-                            let duration_minutes = duration.as_secs() / 60;
-                            self.state = Some(TimerState {
-                                mode: mode.clone(),
-                                total_duration: duration,
-                                remaining: duration,
-                                last_tick: Instant::now(),
-                                paused: false,
-                                check_ins_done: [false; 3],
-                                veille_reminder_sent: false,
-                            });
-
-                            self.persist_new_session(mode.clone());
-                            self.update_tray_active(duration, mode);
-
-                            if let Some(ref notifier) = self.notifier {
-                                notifier.send_session_start(duration_minutes);
+                        TimerMessage::Start {
+                            duration,
+                            mode,
+                            tags,
+                            distraction_apps,
+                            request_id,
+                            strict,
+                            reply,
+                        } => {
+                            if request_id.is_some() && request_id == self.last_start_request_id {
+                                debug!(?request_id, "start request already handled");
+                                let _ = reply.send(StartOutcome::AlreadyStarted);
+                                continue;
                             }
-
-                            if let (Some(ref app_tracker), Some(ref session), Some(ref state)) = (&self.app_tracker, &self.current_session, &self.state) {
-                                if let Some(session_id) = session.id {
-                                    app_tracker.send_session_started(session_id, state.mode.clone());
-                                }
+                            if self.state.is_some() {
+                                info!(?mode, "start rejected, a session is already active");
+                                let _ = reply.send(StartOutcome::AlreadyActive(self.current_status()));
+                                continue;
                             }
+                            self.last_start_request_id = request_id;
+                            let _ = reply.send(StartOutcome::Started);
+
+                            let settings = resolve_mode_settings(&mode);
+                            let duration = duration
+                                .unwrap_or(Duration::from_secs(settings.duration_minutes * 60));
+                            self.start_session(
+                                mode,
+                                duration,
+                                settings.check_ins_enabled,
+                                tags,
+                                distraction_apps,
+                                resolve_preparation(),
+                                strict,
+                            )
+                            .await;
                         }
-                        TimerMessage::Stop => {
+                        TimerMessage::Stop {
+                            confirmed,
+                            reason: stop_reason,
+                            reply,
+                        } => {
+                            if let Some(refusal) = self.strict_refusal(confirmed) {
+                                info!("stop refused, the session is strict");
+                                let _ = reply.send(refusal);
+                                continue;
+                            }
+                            let _ = reply.send(InterruptOutcome::Done);
+                            if self.cancel_preparation() {
+                                continue;
+                            }
                             if self.state.is_some() {
                                 let total = self.total_minutes();
-                                info!("session stopped");
+                                let reason = self.stop_reason();
+                                info!(reason = reason.as_str(), "session stopped");
 
                                 if let Some(ref app_tracker) = self.app_tracker {
                                     app_tracker.send_session_ended();
                                 }
 
-                                self.persist_session_end();
+                                self.run_hook(HookEvent::SessionEnd);
+                                self.persist_interruption(
+                                    InterruptionKind::Stop,
+                                    stop_reason.as_deref(),
+                                )
+                                .await;
+                                self.persist_session_end(reason).await;
+                                self.restore_do_not_disturb();
                                 self.update_tray_inactive();
 
                                 if let Some(ref notifier) = self.notifier {
@@ -415,9 +809,36 @@ impl TimerActor {
                                 self.state = None;
                             }
                         }
-                        TimerMessage::Pause => {
+                        TimerMessage::End { reason } => {
+                            if self.cancel_preparation() {
+                                continue;
+                            }
+                            if self.state.is_some() {
+                                info!(reason = reason.as_str(), "session ended");
+
+                                if let Some(ref app_tracker) = self.app_tracker {
+                                    app_tracker.send_session_ended();
+                                }
+
+                                self.run_hook(HookEvent::SessionEnd);
+                                self.persist_session_end(reason).await;
+                                self.restore_do_not_disturb();
+                                self.update_tray_inactive();
+
+                                self.state = None;
+                            }
+                        }
+                        TimerMessage::Pause { confirmed, reason, reply } => {
+                            if let Some(refusal) = self.strict_refusal(confirmed) {
+                                info!("pause refused, the session is strict");
+                                let _ = reply.send(refusal);
+                                continue;
+                            }
+                            let _ = reply.send(InterruptOutcome::Done);
                             if let Some(ref mut state) = self.state {
-                                if !state.paused {
+                                if state.preparation.is_some() {
+                                    debug!("pause ignored during preparation");
+                                } else if !state.paused {
                                     state.paused = true;
                                     let remaining = state.remaining;
                                     info!("session paused");
@@ -426,7 +847,14 @@ impl TimerActor {
                                         app_tracker.send_session_paused();
                                     }
 
+                                    self.restore_do_not_disturb();
                                     self.update_tray_paused(remaining);
+                                    self.run_hook(HookEvent::Pause);
+                                    self.persist_interruption(
+                                        InterruptionKind::Pause,
+                                        reason.as_deref(),
+                                    )
+                                    .await;
 
                                     if let Some(ref notifier) = self.notifier {
                                         notifier.send_session_paused();
@@ -447,7 +875,9 @@ impl TimerActor {
                                         app_tracker.send_session_resumed();
                                     }
 
+                                    self.enable_do_not_disturb();
                                     self.update_tray_active(remaining, mode);
+                                    self.run_hook(HookEvent::Resume);
 
                                     if let Some(ref notifier) = self.notifier {
                                         notifier.send_session_resumed();
@@ -459,9 +889,14 @@ impl TimerActor {
                             let status = self.current_status();
                             let _ = reply.send(status);
                         }
+                        TimerMessage::ResendTracking => self.resend_tracking(),
                     }
                 }
                 _ = tick_interval.tick() => {
+                    if self.tick_preparation().await {
+                        continue;
+                    }
+
                     let tick_result = {
                         if let Some(ref mut state) = self.state {
                             if state.paused {
@@ -493,7 +928,9 @@ impl TimerActor {
                                 app_tracker.send_session_ended();
                             }
 
-                            self.persist_session_end();
+                            self.run_hook(HookEvent::SessionEnd);
+                            self.persist_session_end(SessionEndReason::Completed).await;
+                            self.restore_do_not_disturb();
                             self.update_tray_inactive();
 
                             if let Some(ref notifier) = self.notifier {
@@ -510,8 +947,11 @@ impl TimerActor {
                                 if let Some((index, threshold)) = self.next_check_in_threshold() {
                                     debug!(threshold, "check-in triggered at {}%", threshold);
                                     self.mark_check_in_done(index);
-                                    self.persist_check_in();
+                                    self.persist_check_in().await;
                                     self.update_tray_check_in();
+                                    if let Some(ref app_tracker) = self.app_tracker {
+                                        app_tracker.send_check_in();
+                                    }
                                     if let Some(ref notifier) = self.notifier {
                                         let receiver = notifier.send_check_in(threshold);
                                         self.pending_check_in = Some(receiver);
@@ -537,29 +977,50 @@ impl TimerActor {
                 remaining: state.remaining,
                 mode: Some(state.mode.clone()),
                 paused: state.paused,
+                preparing: state
+                    .preparation
+                    .as_ref()
+                    .map(|preparation| preparation.remaining),
+                strict: state.strict,
             },
             None => TimerStatus {
                 active: false,
                 remaining: Duration::ZERO,
                 mode: None,
                 paused: false,
+                preparing: None,
+                strict: false,
             },
         }
     }
 }
 
+fn resolve_mode_settings(mode: &FocusMode) -> ModeSettings {
+    Config::load()
+        .map(|config| config.focus().settings_for(mode))
+        .unwrap_or_else(|_| FocusConfig::default().settings_for(mode))
+}
+
+fn resolve_auto_complete_threshold() -> u8 {
+    Config::load()
+        .map(|config| config.focus().auto_complete_threshold_percent)
+        .unwrap_or_else(|_| FocusConfig::default().auto_complete_threshold_percent)
+}
+
+fn resolve_preparation() -> Duration {
+    let seconds = Config::load()
+        .map(|config| config.focus().preparation_seconds)
+        .unwrap_or(0);
+    Duration::from_secs(seconds)
+}
+
 #[cfg(test)]
 mod tests {
+    use super::super::app_tracker::AppTrackerMessage;
     use super::*;
 
-    #[cfg(target_os = "linux")]
-    fn create_test_actor() -> (TimerActor, TimerHandle) {
-        TimerActor::new(None, None, None, None)
-    }
-
-    #[cfg(not(target_os = "linux"))]
     fn create_test_actor() -> (TimerActor, TimerHandle) {
-        TimerActor::new(None, None, None)
+        TimerActor::new(None, None, None, None, None, None, None)
     }
 
     #[tokio::test]
@@ -568,7 +1029,14 @@ mod tests {
         tokio::spawn(actor.run());
 
         handle
-            .start(Duration::from_secs(60), FocusMode::AiAssisted)
+            .start(
+                Some(Duration::from_secs(60)),
+                FocusMode::AiAssisted,
+                Vec::new(),
+                Vec::new(),
+                None,
+                false,
+            )
             .await
             .unwrap();
 
@@ -581,19 +1049,153 @@ mod tests {
         assert!(!status.paused);
     }
 
+    #[tokio::test]
+    async fn preparation_delays_the_session_until_the_countdown_ends() {
+        let (mut actor, _handle) = create_test_actor();
+
+        actor
+            .start_session(
+                FocusMode::Review,
+                Duration::from_secs(60),
+                false,
+                Vec::new(),
+                Vec::new(),
+                Duration::from_secs(30),
+                false,
+            )
+            .await;
+        let status = actor.current_status();
+        assert!(status.active);
+        assert_eq!(status.preparing, Some(Duration::from_secs(30)));
+        assert_eq!(status.remaining, Duration::from_secs(60));
+
+        actor.advance_preparation(Duration::from_secs(25)).await;
+        assert_eq!(
+            actor.current_status().preparing,
+            Some(Duration::from_secs(5))
+        );
+
+        actor.advance_preparation(Duration::from_secs(10)).await;
+        let status = actor.current_status();
+        assert!(status.active);
+        assert_eq!(status.preparing, None);
+        assert_eq!(status.remaining, Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn stopping_during_preparation_cancels_without_a_session() {
+        let (mut actor, _handle) = create_test_actor();
+
+        actor
+            .start_session(
+                FocusMode::Review,
+                Duration::from_secs(60),
+                false,
+                Vec::new(),
+                Vec::new(),
+                Duration::from_secs(30),
+                false,
+            )
+            .await;
+
+        assert!(actor.cancel_preparation());
+        assert!(!actor.current_status().active);
+        assert!(!actor.cancel_preparation());
+    }
+
+    #[tokio::test]
+    async fn second_start_is_rejected_while_a_session_is_active() {
+        let (actor, handle) = create_test_actor();
+        tokio::spawn(actor.run());
+
+        let first = handle
+            .start(
+                Some(Duration::from_secs(60)),
+                FocusMode::Review,
+                Vec::new(),
+                Vec::new(),
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        let second = handle
+            .start(
+                Some(Duration::from_secs(600)),
+                FocusMode::Architecture,
+                Vec::new(),
+                Vec::new(),
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(first, StartOutcome::Started));
+        match second {
+            StartOutcome::AlreadyActive(status) => {
+                assert_eq!(status.mode, Some(FocusMode::Review));
+                assert!(status.remaining.as_secs() <= 60);
+            }
+            other => panic!("expected AlreadyActive, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn retried_start_with_same_request_id_is_not_restarted() {
+        let (actor, handle) = create_test_actor();
+        tokio::spawn(actor.run());
+
+        let request_id = Some("cli-42".to_string());
+        let first = handle
+            .start(
+                None,
+                FocusMode::Review,
+                Vec::new(),
+                Vec::new(),
+                request_id.clone(),
+                false,
+            )
+            .await
+            .unwrap();
+        handle.stop(false, None).await.unwrap();
+        let retried = handle
+            .start(
+                None,
+                FocusMode::Review,
+                Vec::new(),
+                Vec::new(),
+                request_id,
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(first, StartOutcome::Started));
+        assert!(matches!(retried, StartOutcome::AlreadyStarted));
+        assert!(!handle.get_status().await.unwrap().active);
+    }
+
     #[tokio::test]
     async fn pause_and_resume() {
         let (actor, handle) = create_test_actor();
         tokio::spawn(actor.run());
 
         handle
-            .start(Duration::from_secs(60), FocusMode::Review)
+            .start(
+                Some(Duration::from_secs(60)),
+                FocusMode::Review,
+                Vec::new(),
+                Vec::new(),
+                None,
+                false,
+            )
             .await
             .unwrap();
 
         tokio::time::sleep(Duration::from_millis(50)).await;
 
-        handle.pause().await.unwrap();
+        handle.pause(false, None).await.unwrap();
         tokio::time::sleep(Duration::from_millis(50)).await;
 
         let status = handle.get_status().await.unwrap();
@@ -612,18 +1214,277 @@ mod tests {
         tokio::spawn(actor.run());
 
         handle
-            .start(Duration::from_secs(60), FocusMode::Architecture)
+            .start(
+                Some(Duration::from_secs(60)),
+                FocusMode::Architecture,
+                Vec::new(),
+                Vec::new(),
+                None,
+                false,
+            )
             .await
             .unwrap();
 
         tokio::time::sleep(Duration::from_millis(50)).await;
-        handle.stop().await.unwrap();
+        handle.stop(false, None).await.unwrap();
         tokio::time::sleep(Duration::from_millis(50)).await;
 
         let status = handle.get_status().await.unwrap();
         assert!(!status.active);
     }
 
+    #[tokio::test]
+    async fn strict_session_refuses_unconfirmed_pause_and_stop() {
+        let (actor, handle) = create_test_actor();
+        tokio::spawn(actor.run());
+
+        handle
+            .start(
+                Some(Duration::from_secs(60)),
+                FocusMode::Review,
+                Vec::new(),
+                Vec::new(),
+                None,
+                true,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            handle.pause(false, None).await.unwrap(),
+            InterruptOutcome::Refused { .. }
+        ));
+        assert!(matches!(
+            handle.stop(false, None).await.unwrap(),
+            InterruptOutcome::Refused { .. }
+        ));
+        let status = handle.get_status().await.unwrap();
+        assert!(status.active);
+        assert!(status.strict);
+        assert!(!status.paused);
+
+        assert_eq!(
+            handle.stop(true, None).await.unwrap(),
+            InterruptOutcome::Done
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!handle.get_status().await.unwrap().active);
+    }
+
+    #[test]
+    fn strict_session_is_not_paused_by_a_check_in() {
+        let (mut actor, _handle) = create_test_actor();
+        actor.state = Some(TimerState {
+            strict: true,
+            ..create_state(FocusMode::Review, true)
+        });
+
+        actor.pause_session_internal();
+
+        assert!(!actor.current_status().paused);
+    }
+
+    #[tokio::test]
+    async fn end_records_reason_on_persisted_session() {
+        let repository: Arc<dyn SessionRepository> =
+            Arc::new(flux_adapters::SqliteSessionRepository::in_memory().unwrap());
+        let (actor, handle) =
+            TimerActor::new(None, None, None, None, None, Some(repository.clone()), None);
+        tokio::spawn(actor.run());
+
+        handle
+            .start(
+                Some(Duration::from_secs(60)),
+                FocusMode::Review,
+                Vec::new(),
+                Vec::new(),
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.end(SessionEndReason::Logout).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let status = handle.get_status().await.unwrap();
+        assert!(!status.active);
+        assert!(repository.find_active().unwrap().is_none());
+        let session = repository.find_by_id(1).unwrap();
+        assert_eq!(session.end_reason, Some(SessionEndReason::Logout));
+    }
+
+    struct RecordingDoNotDisturb {
+        calls: std::sync::Mutex<Vec<&'static str>>,
+    }
+
+    impl RecordingDoNotDisturb {
+        fn new() -> Self {
+            Self {
+                calls: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+
+        fn calls(&self) -> Vec<&'static str> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    impl DoNotDisturb for RecordingDoNotDisturb {
+        fn enable(&self) -> anyhow::Result<()> {
+            self.calls.lock().unwrap().push("enable");
+            Ok(())
+        }
+
+        fn restore(&self) -> anyhow::Result<()> {
+            self.calls.lock().unwrap().push("restore");
+            Ok(())
+        }
+    }
+
+    fn create_test_actor_with_do_not_disturb(
+        do_not_disturb: Arc<RecordingDoNotDisturb>,
+    ) -> (TimerActor, TimerHandle) {
+        TimerActor::new(None, None, None, None, None, None, Some(do_not_disturb))
+    }
+
+    #[tokio::test]
+    async fn do_not_disturb_follows_session_lifecycle() {
+        let do_not_disturb = Arc::new(RecordingDoNotDisturb::new());
+        let (actor, handle) = create_test_actor_with_do_not_disturb(do_not_disturb.clone());
+        tokio::spawn(actor.run());
+
+        handle
+            .start(
+                Some(Duration::from_secs(60)),
+                FocusMode::AiAssisted,
+                Vec::new(),
+                Vec::new(),
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(do_not_disturb.calls(), vec!["enable"]);
+
+        handle.pause(false, None).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(do_not_disturb.calls(), vec!["enable", "restore"]);
+
+        handle.resume().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(do_not_disturb.calls(), vec!["enable", "restore", "enable"]);
+
+        handle.stop(false, None).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(
+            do_not_disturb.calls(),
+            vec!["enable", "restore", "enable", "restore"]
+        );
+    }
+
+    fn create_state(mode: FocusMode, check_ins_enabled: bool) -> TimerState {
+        TimerState {
+            mode,
+            total_duration: Duration::from_secs(100),
+            remaining: Duration::from_secs(40),
+            last_tick: Instant::now(),
+            paused: false,
+            check_ins_enabled,
+            check_ins_done: [false; 3],
+            veille_reminder_sent: false,
+            preparation: None,
+            strict: false,
+            distraction_apps: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_restarted_app_tracker_gets_the_running_session_back() {
+        let (app_tracker, mut tracker_messages) = AppTrackerHandle::detached();
+        let (mut actor, _handle) =
+            TimerActor::new(None, Some(app_tracker), None, None, None, None, None);
+        let mut session = Session::start(FocusMode::Review);
+        session.id = Some(7);
+        actor.current_session = Some(session);
+        actor.state = Some(TimerState {
+            paused: true,
+            distraction_apps: vec!["discord".to_string()],
+            ..create_state(FocusMode::Review, false)
+        });
+
+        actor.resend_tracking();
+
+        match tracker_messages.recv().await {
+            Some(AppTrackerMessage::Started {
+                session_id,
+                mode,
+                distraction_apps,
+            }) => {
+                assert_eq!(session_id, 7);
+                assert_eq!(mode, FocusMode::Review);
+                assert_eq!(distraction_apps, vec!["discord".to_string()]);
+            }
+            _ => panic!("expected the session to be announced again"),
+        }
+        assert!(matches!(
+            tracker_messages.recv().await,
+            Some(AppTrackerMessage::Paused)
+        ));
+
+        actor.state = None;
+        actor.resend_tracking();
+        tokio::task::yield_now().await;
+        assert!(tracker_messages.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn stop_reason_compares_focus_time_with_the_plan() {
+        let (mut actor, _handle) = create_test_actor();
+
+        actor.state = Some(TimerState {
+            remaining: Duration::ZERO,
+            ..create_state(FocusMode::Review, false)
+        });
+        assert_eq!(actor.stop_reason(), SessionEndReason::Completed);
+
+        actor.state = Some(TimerState {
+            remaining: Duration::from_secs(60),
+            ..create_state(FocusMode::Review, false)
+        });
+        assert_eq!(actor.stop_reason(), SessionEndReason::StoppedEarly);
+    }
+
+    #[tokio::test]
+    async fn check_ins_follow_mode_settings() {
+        let (mut actor, _handle) = create_test_actor();
+
+        actor.state = Some(create_state(FocusMode::Review, true));
+        assert_eq!(actor.next_check_in_threshold(), Some((0, 25)));
+
+        actor.state = Some(create_state(FocusMode::Review, false));
+        assert_eq!(actor.next_check_in_threshold(), None);
+    }
+
+    #[tokio::test]
+    async fn start_without_duration_uses_mode_default() {
+        let (actor, handle) = create_test_actor();
+        tokio::spawn(actor.run());
+
+        handle
+            .start(None, FocusMode::Review, Vec::new(), Vec::new(), None, false)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let status = handle.get_status().await.unwrap();
+        let expected = resolve_mode_settings(&FocusMode::Review).duration_minutes * 60;
+        assert!(status.active);
+        assert!(status.remaining.as_secs() <= expected);
+        assert!(status.remaining.as_secs() >= expected - 1);
+    }
+
     #[tokio::test]
     async fn check_in_thresholds_are_correct() {
         assert_eq!(CHECK_IN_THRESHOLDS, [25, 50, 75]);
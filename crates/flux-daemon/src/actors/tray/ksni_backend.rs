@@ -0,0 +1,250 @@
+use ksni::{
+    self,
+    menu::{StandardItem, SubMenu},
+    Icon, MenuItem, TrayService,
+};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tracing::{debug, info, warn};
+
+use super::{
+    menu_entries, SpawnedBackend, TrayAction, TrayBackend, TrayContext, TrayDisplayInfo,
+    TrayMenuEntry, TrayState, ICON_SIZE,
+};
+
+impl TrayState {
+    fn icon_name(&self) -> &'static str {
+        match self {
+            TrayState::Inactive => "appointment-soon",
+            TrayState::Preparing => "alarm-symbolic",
+            TrayState::Active => "user-available",
+            TrayState::Paused => "user-away",
+            TrayState::CheckInPending => "dialog-warning",
+        }
+    }
+}
+
+impl TrayDisplayInfo {
+    fn format_mode(&self) -> String {
+        self.mode
+            .as_ref()
+            .map(|mode| {
+                format!(
+                    "<font color=\"{}\">({})</font>",
+                    self.mode_colors.color_for(mode).to_hex(),
+                    self.translator.mode_name(mode)
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn tooltip_description(&self) -> String {
+        self.describe(self.format_mode())
+    }
+}
+
+struct FluxTray {
+    state: Arc<Mutex<TrayState>>,
+    display_info: Arc<Mutex<TrayDisplayInfo>>,
+    action_sender: Sender<TrayAction>,
+}
+
+impl ksni::Tray for FluxTray {
+    fn id(&self) -> String {
+        "flux".to_string()
+    }
+
+    fn title(&self) -> String {
+        "Flux".to_string()
+    }
+
+    fn icon_pixmap(&self) -> Vec<Icon> {
+        let display_info = self.display_info.lock().unwrap();
+        if display_info.countdown_minutes().is_none() {
+            return vec![];
+        }
+
+        let data = display_info
+            .icon_rgba(ICON_SIZE)
+            .chunks(4)
+            .flat_map(|pixel| [pixel[3], pixel[0], pixel[1], pixel[2]])
+            .collect();
+        vec![Icon {
+            width: ICON_SIZE as i32,
+            height: ICON_SIZE as i32,
+            data,
+        }]
+    }
+
+    fn icon_name(&self) -> String {
+        if self
+            .display_info
+            .lock()
+            .unwrap()
+            .countdown_minutes()
+            .is_some()
+        {
+            return String::new();
+        }
+        let state = self.state.lock().unwrap();
+        state.icon_name().to_string()
+    }
+
+    fn tool_tip(&self) -> ksni::ToolTip {
+        let display_info = self.display_info.lock().unwrap();
+        ksni::ToolTip {
+            title: "Flux".to_string(),
+            description: display_info.tooltip_description(),
+            icon_name: String::new(),
+            icon_pixmap: vec![],
+        }
+    }
+
+    fn menu(&self) -> Vec<MenuItem<Self>> {
+        let state = *self.state.lock().unwrap();
+
+        menu_entries(state).into_iter().map(menu_item).collect()
+    }
+}
+
+fn menu_item(entry: TrayMenuEntry) -> MenuItem<FluxTray> {
+    match entry {
+        TrayMenuEntry::Action(action, label) => MenuItem::Standard(StandardItem {
+            label,
+            activate: Box::new(move |tray: &mut FluxTray| {
+                let _ = tray.action_sender.send(action.clone());
+            }),
+            ..Default::default()
+        }),
+        TrayMenuEntry::Submenu(label, entries) => MenuItem::SubMenu(SubMenu {
+            label,
+            submenu: entries.into_iter().map(menu_item).collect(),
+            ..Default::default()
+        }),
+        TrayMenuEntry::Separator => MenuItem::Separator,
+    }
+}
+
+impl TrayBackend for ksni::Handle<FluxTray> {
+    fn refresh(&self) {
+        self.update(|_| {});
+    }
+
+    fn shutdown(&self) {
+        ksni::Handle::shutdown(self);
+    }
+}
+
+pub(super) fn spawn(context: TrayContext) -> Result<SpawnedBackend, String> {
+    let tray = FluxTray {
+        state: context.state,
+        display_info: context.display_info,
+        action_sender: context.action_sender,
+    };
+
+    let service = TrayService::new(tray);
+    let ksni_handle = service.handle();
+
+    let thread_handle = thread::Builder::new()
+        .name("flux-tray".to_string())
+        .spawn(move || {
+            info!("tray icon initialized");
+            if let Err(error) = service.run() {
+                warn!(%error, "tray service error");
+            }
+            debug!("tray service stopped");
+        })
+        .map_err(|error| format!("failed to spawn tray thread: {}", error))?;
+
+    Ok((Arc::new(ksni_handle), Some(thread_handle)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::{FocusMode, ModesConfig, Translator};
+    use std::time::Duration;
+
+    #[test]
+    fn tray_state_has_correct_icons() {
+        assert_eq!(TrayState::Inactive.icon_name(), "appointment-soon");
+        assert_eq!(TrayState::Preparing.icon_name(), "alarm-symbolic");
+        assert_eq!(TrayState::Active.icon_name(), "user-available");
+        assert_eq!(TrayState::Paused.icon_name(), "user-away");
+        assert_eq!(TrayState::CheckInPending.icon_name(), "dialog-warning");
+    }
+
+    #[test]
+    fn inactive_tooltip_shows_no_session() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Inactive,
+            remaining: None,
+            mode: None,
+            mode_colors: ModesConfig::default(),
+            show_countdown: false,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+        assert_eq!(info.tooltip_description(), "No active session");
+    }
+
+    #[test]
+    fn active_tooltip_shows_remaining_time_and_mode() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Active,
+            remaining: Some(Duration::from_secs(754)),
+            mode: Some(FocusMode::AiAssisted),
+            mode_colors: ModesConfig::default(),
+            show_countdown: false,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+        assert_eq!(
+            info.tooltip_description(),
+            "12:34 remaining <font color=\"#3b82f6\">(AI-assisted)</font>"
+        );
+    }
+
+    #[test]
+    fn paused_tooltip_shows_remaining_time() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Paused,
+            remaining: Some(Duration::from_secs(300)),
+            mode: None,
+            mode_colors: ModesConfig::default(),
+            show_countdown: false,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+        assert_eq!(info.tooltip_description(), "Paused (05:00 remaining)");
+    }
+
+    #[test]
+    fn preparing_tooltip_shows_the_countdown() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Preparing,
+            remaining: Some(Duration::from_secs(30)),
+            mode: Some(FocusMode::Review),
+            mode_colors: ModesConfig::default(),
+            show_countdown: true,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+        assert_eq!(info.tooltip_description(), "Starting in 00:30");
+    }
+
+    #[test]
+    fn check_in_pending_tooltip() {
+        let info = TrayDisplayInfo {
+            state: TrayState::CheckInPending,
+            remaining: None,
+            mode: None,
+            mode_colors: ModesConfig::default(),
+            show_countdown: false,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+        assert_eq!(info.tooltip_description(), "Check-in pending");
+    }
+}
@@ -0,0 +1,859 @@
+#[cfg(target_os = "linux")]
+mod ksni_backend;
+#[cfg(any(windows, target_os = "macos"))]
+mod native_backend;
+
+#[cfg(target_os = "macos")]
+pub use native_backend::run_main_thread_event_loop;
+
+use flux_core::{FocusMode, Language, ModeColor, ModesConfig, Translator};
+use std::process::Command;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+const ICON_SIZE: u32 = 32;
+const INACTIVE_COLOR: ModeColor = ModeColor::new(107, 114, 128);
+const PAUSED_COLOR: ModeColor = ModeColor::new(245, 158, 11);
+const CHECK_IN_COLOR: ModeColor = ModeColor::new(239, 68, 68);
+/// A little under the timer's 1 s tick, so scheduling jitter never drops a displayed second
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_millis(900);
+const START_PRESET_MINUTES: [u64; 3] = [25, 45, 90];
+const DIGIT_COLUMNS: u32 = 3;
+const DIGIT_ROWS: u32 = 5;
+const DIGIT_GLYPHS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b010, 0b010, 0b010],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrayAction {
+    Start { duration: Duration, mode: FocusMode },
+    Pause,
+    Resume,
+    Stop,
+    OpenDashboard,
+    CheckForUpdates,
+    OpenConfiguration,
+    Quit,
+}
+
+impl TrayAction {
+    fn id(&self) -> String {
+        match self {
+            TrayAction::Start { duration, mode } => {
+                format!("start:{}:{}", mode, duration.as_secs() / 60)
+            }
+            TrayAction::Pause => "pause".to_string(),
+            TrayAction::Resume => "resume".to_string(),
+            TrayAction::Stop => "stop".to_string(),
+            TrayAction::OpenDashboard => "dashboard".to_string(),
+            TrayAction::CheckForUpdates => "check-for-updates".to_string(),
+            TrayAction::OpenConfiguration => "configuration".to_string(),
+            TrayAction::Quit => "quit".to_string(),
+        }
+    }
+
+    #[cfg_attr(target_os = "linux", allow(dead_code))]
+    fn from_id(id: &str) -> Option<Self> {
+        if let Some(preset) = id.strip_prefix("start:") {
+            let (mode, minutes) = preset.rsplit_once(':')?;
+            return Some(TrayAction::Start {
+                duration: Duration::from_secs(minutes.parse::<u64>().ok()? * 60),
+                mode: FocusMode::from_stored(mode),
+            });
+        }
+
+        [
+            TrayAction::Pause,
+            TrayAction::Resume,
+            TrayAction::Stop,
+            TrayAction::OpenDashboard,
+            TrayAction::CheckForUpdates,
+            TrayAction::OpenConfiguration,
+            TrayAction::Quit,
+        ]
+        .into_iter()
+        .find(|action| action.id() == id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrayState {
+    #[default]
+    Inactive,
+    Preparing,
+    Active,
+    Paused,
+    CheckInPending,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TrayDisplayInfo {
+    pub state: TrayState,
+    pub remaining: Option<Duration>,
+    pub mode: Option<FocusMode>,
+    pub mode_colors: ModesConfig,
+    pub show_countdown: bool,
+    pub hide_seconds: bool,
+    pub translator: Translator,
+}
+
+impl TrayDisplayInfo {
+    fn format_remaining(&self) -> String {
+        match self.remaining {
+            Some(duration) => {
+                let total_secs = duration.as_secs();
+                let minutes = total_secs / 60;
+                let seconds = total_secs % 60;
+                format!("{:02}:{:02}", minutes, seconds)
+            }
+            None => String::new(),
+        }
+    }
+
+    /// Session time, in whole minutes rounded up when seconds are hidden
+    fn format_session_remaining(&self) -> String {
+        match self.remaining {
+            Some(duration) if self.hide_seconds => {
+                format!("{} min", duration.as_secs().div_ceil(60))
+            }
+            _ => self.format_remaining(),
+        }
+    }
+
+    fn describe(&self, mode: String) -> String {
+        match self.state {
+            TrayState::Inactive => "No active session".to_string(),
+            TrayState::Preparing => format!("Starting in {}", self.format_remaining()),
+            TrayState::Active => {
+                let time = self.format_session_remaining();
+                if mode.is_empty() {
+                    format!("{} remaining", time)
+                } else {
+                    format!("{} remaining {}", time, mode)
+                }
+            }
+            TrayState::Paused => {
+                let time = self.format_session_remaining();
+                format!("Paused ({} remaining)", time)
+            }
+            TrayState::CheckInPending => "Check-in pending".to_string(),
+        }
+    }
+
+    fn icon_color(&self) -> ModeColor {
+        match (self.state, &self.mode) {
+            (TrayState::Active | TrayState::Preparing, Some(mode)) => {
+                self.mode_colors.color_for(mode)
+            }
+            (TrayState::Active | TrayState::Preparing, None) | (TrayState::Inactive, _) => {
+                INACTIVE_COLOR
+            }
+            (TrayState::Paused, _) => PAUSED_COLOR,
+            (TrayState::CheckInPending, _) => CHECK_IN_COLOR,
+        }
+    }
+
+    /// Remaining minutes, rounded up, to draw in the icon when the countdown is enabled
+    fn countdown_minutes(&self) -> Option<u64> {
+        if !self.show_countdown {
+            return None;
+        }
+        match self.state {
+            TrayState::Active | TrayState::Paused => self
+                .remaining
+                .map(|remaining| remaining.as_secs().div_ceil(60).min(999)),
+            TrayState::Inactive | TrayState::Preparing | TrayState::CheckInPending => None,
+        }
+    }
+
+    fn rendering(&self) -> TrayRendering {
+        TrayRendering {
+            state: self.state,
+            description: self.describe(
+                self.mode
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            ),
+            countdown_minutes: self.countdown_minutes(),
+        }
+    }
+
+    /// RGBA pixels of the tray icon: a state-colored circle, with the countdown when shown
+    fn icon_rgba(&self, size: u32) -> Vec<u8> {
+        let mut rgba = circle_rgba(size, self.icon_color());
+        if let Some(minutes) = self.countdown_minutes() {
+            for (pixel, lit) in rgba.chunks_mut(4).zip(countdown_mask(minutes, size)) {
+                if lit {
+                    pixel.copy_from_slice(&[255, 255, 255, 255]);
+                }
+            }
+        }
+        rgba
+    }
+}
+
+/// What the tray host displays: the tooltip, the icon state and the countdown
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TrayRendering {
+    state: TrayState,
+    description: String,
+    countdown_minutes: Option<u64>,
+}
+
+/// Skips timer-driven refreshes that would not change what the tray shows,
+/// and spaces the others at least [`MIN_REFRESH_INTERVAL`] apart
+#[derive(Debug, Default)]
+struct RefreshThrottle {
+    last: Option<(Instant, TrayRendering)>,
+}
+
+impl RefreshThrottle {
+    fn record(&mut self, rendering: TrayRendering, now: Instant) {
+        self.last = Some((now, rendering));
+    }
+
+    fn should_refresh(&mut self, rendering: TrayRendering, now: Instant) -> bool {
+        if let Some((refreshed_at, shown)) = &self.last {
+            if *shown == rendering || now.duration_since(*refreshed_at) < MIN_REFRESH_INTERVAL {
+                return false;
+            }
+        }
+        self.record(rendering, now);
+        true
+    }
+}
+
+fn circle_rgba(size: u32, color: ModeColor) -> Vec<u8> {
+    let center = (size as f32 - 1.0) / 2.0;
+    let radius = size as f32 / 2.0 - 1.0;
+
+    (0..size * size)
+        .flat_map(|index| {
+            let x = (index % size) as f32 - center;
+            let y = (index / size) as f32 - center;
+            let alpha = if x * x + y * y <= radius * radius {
+                255
+            } else {
+                0
+            };
+            [color.red, color.green, color.blue, alpha]
+        })
+        .collect()
+}
+
+/// Pixels lit by `minutes` drawn with a block digit font, centered in a square icon
+fn countdown_mask(minutes: u64, size: u32) -> Vec<bool> {
+    let digits: Vec<usize> = minutes
+        .to_string()
+        .bytes()
+        .map(|digit| (digit - b'0') as usize)
+        .collect();
+    let text_columns = digits.len() as u32 * (DIGIT_COLUMNS + 1) - 1;
+    let scale = ((size - 4) / text_columns)
+        .min((size - 4) / DIGIT_ROWS)
+        .max(1);
+    let left = size.saturating_sub(text_columns * scale) / 2;
+    let top = size.saturating_sub(DIGIT_ROWS * scale) / 2;
+
+    (0..size * size)
+        .map(|index| {
+            let (x, y) = (index % size, index / size);
+            if x < left || y < top {
+                return false;
+            }
+            let (column, row) = ((x - left) / scale, (y - top) / scale);
+            let (glyph, glyph_column) = (
+                (column / (DIGIT_COLUMNS + 1)) as usize,
+                column % (DIGIT_COLUMNS + 1),
+            );
+            row < DIGIT_ROWS
+                && glyph < digits.len()
+                && glyph_column < DIGIT_COLUMNS
+                && DIGIT_GLYPHS[digits[glyph]][row as usize]
+                    & (1 << (DIGIT_COLUMNS - 1 - glyph_column))
+                    != 0
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TrayMenuEntry {
+    Action(TrayAction, String),
+    Submenu(String, Vec<TrayMenuEntry>),
+    Separator,
+}
+
+impl TrayMenuEntry {
+    fn action(action: TrayAction, label: &str) -> Self {
+        TrayMenuEntry::Action(action, label.to_string())
+    }
+}
+
+fn menu_entries(state: TrayState) -> Vec<TrayMenuEntry> {
+    let session_entries = match state {
+        TrayState::Inactive => vec![start_session_menu()],
+        TrayState::Preparing => vec![TrayMenuEntry::action(TrayAction::Stop, "Cancel")],
+        TrayState::Active => vec![
+            TrayMenuEntry::action(TrayAction::Pause, "Pause"),
+            TrayMenuEntry::action(TrayAction::Stop, "Stop"),
+        ],
+        TrayState::Paused => vec![
+            TrayMenuEntry::action(TrayAction::Resume, "Resume"),
+            TrayMenuEntry::action(TrayAction::Stop, "Stop"),
+        ],
+        TrayState::CheckInPending => vec![
+            TrayMenuEntry::action(TrayAction::Resume, "Continue"),
+            TrayMenuEntry::action(TrayAction::Pause, "Pause"),
+            TrayMenuEntry::action(TrayAction::Stop, "Stop"),
+        ],
+    };
+
+    let mut entries = session_entries;
+    entries.extend([
+        TrayMenuEntry::Separator,
+        TrayMenuEntry::action(TrayAction::OpenDashboard, "Dashboard"),
+        TrayMenuEntry::Separator,
+        TrayMenuEntry::action(TrayAction::CheckForUpdates, "Check for updates"),
+        TrayMenuEntry::action(TrayAction::OpenConfiguration, "Open configuration"),
+        TrayMenuEntry::action(TrayAction::Quit, "Quit"),
+    ]);
+    entries
+}
+
+fn start_session_menu() -> TrayMenuEntry {
+    let modes = [
+        FocusMode::AiAssisted,
+        FocusMode::Review,
+        FocusMode::Architecture,
+        FocusMode::Veille,
+    ];
+
+    let mode_menus = modes
+        .into_iter()
+        .map(|mode| {
+            let presets = START_PRESET_MINUTES
+                .iter()
+                .map(|minutes| {
+                    TrayMenuEntry::Action(
+                        TrayAction::Start {
+                            duration: Duration::from_secs(minutes * 60),
+                            mode: mode.clone(),
+                        },
+                        format!("{} min", minutes),
+                    )
+                })
+                .collect();
+            TrayMenuEntry::Submenu(mode.to_string(), presets)
+        })
+        .collect();
+
+    TrayMenuEntry::Submenu("Start session".to_string(), mode_menus)
+}
+
+/// State shared between the tray handles and the platform backend
+#[derive(Clone)]
+struct TrayContext {
+    state: Arc<Mutex<TrayState>>,
+    display_info: Arc<Mutex<TrayDisplayInfo>>,
+    action_sender: Sender<TrayAction>,
+}
+
+/// Platform tray implementation redrawn from the shared [`TrayContext`]
+trait TrayBackend: Send + Sync {
+    fn refresh(&self);
+
+    fn shutdown(&self);
+}
+
+#[derive(Clone)]
+pub struct TrayStateHandle {
+    state: Arc<Mutex<TrayState>>,
+    display_info: Arc<Mutex<TrayDisplayInfo>>,
+    refresh_throttle: Arc<Mutex<RefreshThrottle>>,
+    backend: Arc<dyn TrayBackend>,
+}
+
+impl TrayStateHandle {
+    fn update_display(
+        &self,
+        new_state: TrayState,
+        remaining: Option<Duration>,
+        mode: Option<FocusMode>,
+    ) {
+        {
+            let mut state = self.state.lock().unwrap();
+            *state = new_state;
+        }
+        {
+            let mut info = self.display_info.lock().unwrap();
+            info.state = new_state;
+            info.remaining = remaining;
+            info.mode = mode;
+        }
+        self.refresh_now();
+    }
+
+    /// State changes are shown right away and reset the throttle
+    fn refresh_now(&self) {
+        let rendering = self.display_info.lock().unwrap().rendering();
+        self.refresh_throttle
+            .lock()
+            .unwrap()
+            .record(rendering, Instant::now());
+        self.backend.refresh();
+    }
+
+    pub fn set_active(&self, remaining: Duration, mode: FocusMode) {
+        debug!(?remaining, ?mode, "tray set active");
+        self.update_display(TrayState::Active, Some(remaining), Some(mode));
+    }
+
+    pub fn set_preparing(&self, remaining: Duration, mode: FocusMode) {
+        debug!(?remaining, ?mode, "tray set preparing");
+        self.update_display(TrayState::Preparing, Some(remaining), Some(mode));
+    }
+
+    pub fn set_paused(&self, remaining: Duration) {
+        debug!(?remaining, "tray set paused");
+        self.update_display(TrayState::Paused, Some(remaining), None);
+    }
+
+    pub fn set_inactive(&self) {
+        debug!("tray set inactive");
+        self.update_display(TrayState::Inactive, None, None);
+    }
+
+    pub fn set_check_in_pending(&self) {
+        debug!("tray set check-in pending");
+        self.update_display(TrayState::CheckInPending, None, None);
+    }
+
+    pub fn set_show_countdown(&self, show_countdown: bool) {
+        debug!(show_countdown, "tray countdown updated");
+        self.display_info.lock().unwrap().show_countdown = show_countdown;
+        self.refresh_now();
+    }
+
+    pub fn update_remaining(&self, remaining: Duration, mode: FocusMode) {
+        let rendering = {
+            let mut info = self.display_info.lock().unwrap();
+            info.remaining = Some(remaining);
+            info.mode = Some(mode);
+            info.rendering()
+        };
+        let changed = self
+            .refresh_throttle
+            .lock()
+            .unwrap()
+            .should_refresh(rendering, Instant::now());
+        if changed {
+            self.backend.refresh();
+        }
+    }
+}
+
+pub struct TrayHandle {
+    backend: Arc<dyn TrayBackend>,
+    thread_handle: Option<thread::JoinHandle<()>>,
+    pub state_handle: TrayStateHandle,
+}
+
+impl TrayHandle {
+    pub fn shutdown(&mut self) {
+        self.backend.shutdown();
+        if let Some(handle) = self.thread_handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for TrayHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+pub fn spawn_tray(
+    mode_colors: ModesConfig,
+    show_countdown: bool,
+    hide_seconds: bool,
+    language: Language,
+) -> Result<(TrayHandle, std::sync::mpsc::Receiver<TrayAction>), String> {
+    let state = Arc::new(Mutex::new(TrayState::Inactive));
+    let display_info = Arc::new(Mutex::new(TrayDisplayInfo {
+        mode_colors,
+        show_countdown,
+        hide_seconds,
+        translator: Translator::new(language),
+        ..TrayDisplayInfo::default()
+    }));
+    let (action_sender, action_receiver) = std::sync::mpsc::channel();
+
+    let context = TrayContext {
+        state: Arc::clone(&state),
+        display_info: Arc::clone(&display_info),
+        action_sender,
+    };
+
+    let (backend, thread_handle) = spawn_backend(context)?;
+
+    let state_handle = TrayStateHandle {
+        state,
+        display_info,
+        refresh_throttle: Arc::new(Mutex::new(RefreshThrottle::default())),
+        backend: Arc::clone(&backend),
+    };
+
+    let handle = TrayHandle {
+        backend,
+        thread_handle,
+        state_handle,
+    };
+
+    Ok((handle, action_receiver))
+}
+
+type SpawnedBackend = (Arc<dyn TrayBackend>, Option<thread::JoinHandle<()>>);
+
+#[cfg(target_os = "linux")]
+fn spawn_backend(context: TrayContext) -> Result<SpawnedBackend, String> {
+    ksni_backend::spawn(context)
+}
+
+#[cfg(any(windows, target_os = "macos"))]
+fn spawn_backend(context: TrayContext) -> Result<SpawnedBackend, String> {
+    native_backend::spawn(context)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn spawn_backend(_context: TrayContext) -> Result<SpawnedBackend, String> {
+    Err("tray is not supported on this platform".to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn open_path(path: &std::path::Path) -> std::io::Result<std::process::Child> {
+    Command::new("xdg-open").arg(path).spawn()
+}
+
+#[cfg(target_os = "macos")]
+fn open_path(path: &std::path::Path) -> std::io::Result<std::process::Child> {
+    Command::new("open").arg(path).spawn()
+}
+
+#[cfg(windows)]
+fn open_path(path: &std::path::Path) -> std::io::Result<std::process::Child> {
+    Command::new("cmd")
+        .args(["/C", "start", ""])
+        .arg(path)
+        .spawn()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn open_path(path: &std::path::Path) -> std::io::Result<std::process::Child> {
+    Command::new("xdg-open").arg(path).spawn()
+}
+
+pub fn open_configuration() {
+    if let Some(config_dir) = dirs::config_dir() {
+        let config_path = config_dir.join("flux").join("config.toml");
+        if let Err(error) = open_path(&config_path) {
+            warn!(%error, "failed to open configuration file");
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub fn check_for_updates() {
+    if let Err(error) = Command::new("osascript")
+        .args([
+            "-e",
+            "tell application \"Terminal\" to do script \"flux update\"",
+        ])
+        .spawn()
+    {
+        warn!(%error, "failed to open a terminal for update");
+    }
+}
+
+#[cfg(windows)]
+pub fn check_for_updates() {
+    if let Err(error) = Command::new("cmd")
+        .args(["/C", "start", "", "cmd", "/K", "flux update"])
+        .spawn()
+    {
+        warn!(%error, "failed to open a terminal for update");
+    }
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+pub fn check_for_updates() {
+    let update_script = "flux update; echo ''; echo 'Appuyez sur Entrée pour fermer...'; read";
+    let terminals = ["gnome-terminal", "konsole", "xfce4-terminal", "xterm"];
+
+    for terminal in terminals {
+        let result = match terminal {
+            "gnome-terminal" => Command::new(terminal)
+                .args(["--", "bash", "-c", update_script])
+                .spawn(),
+            "konsole" => Command::new(terminal)
+                .args(["-e", "bash", "-c", update_script])
+                .spawn(),
+            "xfce4-terminal" => Command::new(terminal)
+                .args(["-e", &format!("bash -c '{}'", update_script)])
+                .spawn(),
+            "xterm" => Command::new(terminal)
+                .args(["-e", "bash", "-c", update_script])
+                .spawn(),
+            _ => continue,
+        };
+
+        if result.is_ok() {
+            return;
+        }
+    }
+
+    warn!("no suitable terminal emulator found for update");
+}
+
+pub fn open_dashboard() {
+    let gui_name = format!("flux-gui{}", std::env::consts::EXE_SUFFIX);
+
+    match Command::new(&gui_name).spawn() {
+        Ok(_) => return,
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+            warn!(%error, "failed to spawn flux-gui");
+            return;
+        }
+        Err(_) => {}
+    }
+
+    if let Ok(current_exe) = std::env::current_exe() {
+        let sibling_path = current_exe.with_file_name(&gui_name);
+        if sibling_path.exists() {
+            if let Err(error) = Command::new(sibling_path).spawn() {
+                warn!(%error, "failed to spawn flux-gui");
+            }
+            return;
+        }
+    }
+
+    warn!("flux-gui not found in PATH or alongside daemon binary");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn menu_offers_session_actions_for_the_current_state() {
+        let actions = |state| {
+            menu_entries(state)
+                .into_iter()
+                .filter_map(|entry| match entry {
+                    TrayMenuEntry::Action(action, _) => Some(action),
+                    TrayMenuEntry::Submenu(..) | TrayMenuEntry::Separator => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(actions(TrayState::Inactive)[0], TrayAction::OpenDashboard);
+        assert_eq!(actions(TrayState::Preparing)[0], TrayAction::Stop);
+        assert_eq!(
+            actions(TrayState::Active)[..2],
+            [TrayAction::Pause, TrayAction::Stop]
+        );
+        assert_eq!(
+            actions(TrayState::Paused)[..2],
+            [TrayAction::Resume, TrayAction::Stop]
+        );
+        assert_eq!(
+            actions(TrayState::CheckInPending)[..3],
+            [TrayAction::Resume, TrayAction::Pause, TrayAction::Stop]
+        );
+        assert_eq!(actions(TrayState::Active).last(), Some(&TrayAction::Quit));
+    }
+
+    #[test]
+    fn icon_color_follows_state_and_mode() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Active,
+            remaining: Some(Duration::from_secs(60)),
+            mode: Some(FocusMode::AiAssisted),
+            mode_colors: ModesConfig::default(),
+            show_countdown: false,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+        assert_eq!(info.icon_color(), ModeColor::new(59, 130, 246));
+        assert_eq!(
+            TrayDisplayInfo {
+                state: TrayState::Paused,
+                ..info
+            }
+            .icon_color(),
+            PAUSED_COLOR
+        );
+    }
+
+    #[test]
+    fn circle_icon_is_transparent_in_the_corners() {
+        let rgba = circle_rgba(ICON_SIZE, PAUSED_COLOR);
+        let center = ((ICON_SIZE / 2) * ICON_SIZE + ICON_SIZE / 2) as usize * 4;
+
+        assert_eq!(rgba.len(), (ICON_SIZE * ICON_SIZE * 4) as usize);
+        assert_eq!(rgba[3], 0);
+        assert_eq!(rgba[center + 3], 255);
+    }
+
+    #[test]
+    fn countdown_rounds_remaining_minutes_up_only_when_enabled() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Active,
+            remaining: Some(Duration::from_secs(24 * 60 + 10)),
+            mode: Some(FocusMode::Review),
+            mode_colors: ModesConfig::default(),
+            show_countdown: true,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+
+        assert_eq!(info.countdown_minutes(), Some(25));
+        assert_eq!(
+            TrayDisplayInfo {
+                show_countdown: false,
+                ..info.clone()
+            }
+            .countdown_minutes(),
+            None
+        );
+        assert_eq!(
+            TrayDisplayInfo {
+                state: TrayState::CheckInPending,
+                ..info
+            }
+            .countdown_minutes(),
+            None
+        );
+    }
+
+    #[test]
+    fn countdown_icon_draws_white_digits_inside_the_circle() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Active,
+            remaining: Some(Duration::from_secs(8 * 60)),
+            mode: Some(FocusMode::Review),
+            mode_colors: ModesConfig::default(),
+            show_countdown: true,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+        let mask = countdown_mask(8, ICON_SIZE);
+        let lit = mask.iter().position(|lit| *lit).unwrap();
+        let rgba = info.icon_rgba(ICON_SIZE);
+
+        assert!(!mask[0]);
+        assert_eq!(rgba[lit * 4..lit * 4 + 4], [255, 255, 255, 255]);
+        assert_ne!(countdown_mask(8, ICON_SIZE), countdown_mask(18, ICON_SIZE));
+    }
+
+    #[test]
+    fn menu_action_ids_round_trip() {
+        for entry in menu_entries(TrayState::CheckInPending) {
+            if let TrayMenuEntry::Action(action, _) = entry {
+                assert_eq!(TrayAction::from_id(&action.id()), Some(action));
+            }
+        }
+        let start = TrayAction::Start {
+            duration: Duration::from_secs(45 * 60),
+            mode: FocusMode::Custom("deep:work".to_string()),
+        };
+        assert_eq!(TrayAction::from_id(&start.id()), Some(start));
+        assert_eq!(TrayAction::from_id("unknown"), None);
+        assert_eq!(TrayAction::from_id("start:review:soon"), None);
+    }
+
+    #[test]
+    fn inactive_menu_offers_start_presets_per_mode() {
+        let entries = menu_entries(TrayState::Inactive);
+        let TrayMenuEntry::Submenu(label, modes) = &entries[0] else {
+            panic!("expected the start session submenu first");
+        };
+        let TrayMenuEntry::Submenu(mode, presets) = &modes[1] else {
+            panic!("expected one submenu per mode");
+        };
+
+        assert_eq!(label, "Start session");
+        assert_eq!(modes.len(), 4);
+        assert_eq!(mode, "review");
+        assert_eq!(
+            presets[2],
+            TrayMenuEntry::Action(
+                TrayAction::Start {
+                    duration: Duration::from_secs(90 * 60),
+                    mode: FocusMode::Review,
+                },
+                "90 min".to_string()
+            )
+        );
+        assert!(menu_entries(TrayState::Active)
+            .iter()
+            .all(|entry| !matches!(entry, TrayMenuEntry::Submenu(..))));
+    }
+
+    fn rendering_after(seconds: u64, hide_seconds: bool) -> TrayRendering {
+        TrayDisplayInfo {
+            state: TrayState::Active,
+            remaining: Some(Duration::from_secs(seconds)),
+            mode: Some(FocusMode::Review),
+            hide_seconds,
+            ..TrayDisplayInfo::default()
+        }
+        .rendering()
+    }
+
+    #[test]
+    fn hidden_seconds_round_the_tooltip_up_to_the_minute() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Paused,
+            remaining: Some(Duration::from_secs(754)),
+            hide_seconds: true,
+            ..TrayDisplayInfo::default()
+        };
+
+        assert_eq!(info.describe(String::new()), "Paused (13 min remaining)");
+    }
+
+    #[test]
+    fn throttle_skips_unchanged_renderings_and_fast_ticks() {
+        let mut throttle = RefreshThrottle::default();
+        let start = Instant::now();
+
+        assert!(throttle.should_refresh(rendering_after(754, true), start));
+        assert!(
+            !throttle.should_refresh(rendering_after(753, true), start + Duration::from_secs(1))
+        );
+        assert!(
+            throttle.should_refresh(rendering_after(720, true), start + Duration::from_secs(34))
+        );
+        assert!(!throttle.should_refresh(
+            rendering_after(719, false),
+            start + Duration::from_millis(34_100)
+        ));
+        assert!(
+            throttle.should_refresh(rendering_after(718, false), start + Duration::from_secs(36))
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Tray icon for Windows (notification area) and macOS (status bar) using `tray-icon`
+//!
+//! The icon must live on a thread running the platform event loop: a dedicated
+//! thread on Windows, the main thread on macOS (see [`run_main_thread_event_loop`]).
+
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use tracing::{debug, info, warn};
+use tray_icon::menu::{IsMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+use super::{
+    menu_entries, SpawnedBackend, TrayAction, TrayBackend, TrayContext, TrayDisplayInfo,
+    TrayMenuEntry, TrayState, ICON_SIZE,
+};
+
+const EVENT_LOOP_INTERVAL: Duration = Duration::from_millis(50);
+
+enum TrayCommand {
+    Refresh,
+    Shutdown,
+}
+
+struct NativeTrayBackend {
+    commands: Sender<TrayCommand>,
+}
+
+impl TrayBackend for NativeTrayBackend {
+    fn refresh(&self) {
+        let _ = self.commands.send(TrayCommand::Refresh);
+    }
+
+    fn shutdown(&self) {
+        let _ = self.commands.send(TrayCommand::Shutdown);
+    }
+}
+
+struct NativeTray {
+    context: TrayContext,
+    commands: Receiver<TrayCommand>,
+}
+
+impl TrayDisplayInfo {
+    fn plain_tooltip_description(&self) -> String {
+        let mode = self
+            .mode
+            .as_ref()
+            .map(|mode| format!("({})", self.translator.mode_name(mode)))
+            .unwrap_or_default();
+        format!("Flux - {}", self.describe(mode))
+    }
+}
+
+#[cfg(windows)]
+pub(super) fn spawn(context: TrayContext) -> Result<SpawnedBackend, String> {
+    let (command_sender, command_receiver) = mpsc::channel();
+    let tray = NativeTray {
+        context,
+        commands: command_receiver,
+    };
+
+    let thread_handle = thread::Builder::new()
+        .name("flux-tray".to_string())
+        .spawn(move || run_event_loop(tray))
+        .map_err(|error| format!("failed to spawn tray thread: {}", error))?;
+
+    Ok((
+        Arc::new(NativeTrayBackend {
+            commands: command_sender,
+        }),
+        Some(thread_handle),
+    ))
+}
+
+#[cfg(target_os = "macos")]
+pub(super) fn spawn(context: TrayContext) -> Result<SpawnedBackend, String> {
+    let (command_sender, command_receiver) = mpsc::channel();
+    let tray = NativeTray {
+        context,
+        commands: command_receiver,
+    };
+
+    main_thread_channel()
+        .0
+        .send(tray)
+        .map_err(|error| format!("failed to hand the tray to the main thread: {}", error))?;
+
+    Ok((
+        Arc::new(NativeTrayBackend {
+            commands: command_sender,
+        }),
+        None,
+    ))
+}
+
+#[cfg(target_os = "macos")]
+type MainThreadChannel = (Sender<NativeTray>, std::sync::Mutex<Receiver<NativeTray>>);
+
+#[cfg(target_os = "macos")]
+fn main_thread_channel() -> &'static MainThreadChannel {
+    static CHANNEL: std::sync::OnceLock<MainThreadChannel> = std::sync::OnceLock::new();
+    CHANNEL.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        (sender, std::sync::Mutex::new(receiver))
+    })
+}
+
+/// Run the status bar event loop on the main thread until `daemon` finishes
+#[cfg(target_os = "macos")]
+pub fn run_main_thread_event_loop<T>(daemon: thread::JoinHandle<T>) -> thread::Result<T> {
+    let receiver = main_thread_channel().1.lock().unwrap();
+
+    while !daemon.is_finished() {
+        match receiver.recv_timeout(EVENT_LOOP_INTERVAL) {
+            Ok(tray) => run_event_loop(tray),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    daemon.join()
+}
+
+fn run_event_loop(tray: NativeTray) {
+    let display_info = tray.context.display_info.lock().unwrap().clone();
+    let built = TrayIconBuilder::new()
+        .with_tooltip(display_info.plain_tooltip_description())
+        .with_icon(state_icon(&display_info))
+        .with_menu(Box::new(build_menu(display_info.state)))
+        .build();
+
+    let icon = match built {
+        Ok(icon) => icon,
+        Err(error) => {
+            warn!(%error, "failed to create tray icon");
+            return;
+        }
+    };
+    info!("tray icon initialized");
+
+    loop {
+        pump_platform_events();
+
+        while let Ok(event) = MenuEvent::receiver().try_recv() {
+            if let Some(action) = TrayAction::from_id(event.id.as_ref()) {
+                let _ = tray.context.action_sender.send(action);
+            }
+        }
+
+        let mut refresh = false;
+        loop {
+            match tray.commands.try_recv() {
+                Ok(TrayCommand::Refresh) => refresh = true,
+                Ok(TrayCommand::Shutdown) | Err(TryRecvError::Disconnected) => {
+                    debug!("tray service stopped");
+                    return;
+                }
+                Err(TryRecvError::Empty) => break,
+            }
+        }
+
+        if refresh {
+            update_icon(&icon, &tray.context);
+        }
+    }
+}
+
+fn update_icon(icon: &TrayIcon, context: &TrayContext) {
+    let state = *context.state.lock().unwrap();
+    let display_info = context.display_info.lock().unwrap().clone();
+
+    if let Err(error) = icon.set_tooltip(Some(display_info.plain_tooltip_description())) {
+        debug!(%error, "failed to update tray tooltip");
+    }
+    if let Err(error) = icon.set_icon(Some(state_icon(&display_info))) {
+        debug!(%error, "failed to update tray icon");
+    }
+    icon.set_menu(Some(Box::new(build_menu(state))));
+}
+
+fn build_menu(state: TrayState) -> Menu {
+    let menu = Menu::new();
+
+    for entry in menu_entries(state) {
+        if let Err(error) = menu.append(menu_item(entry).as_ref()) {
+            debug!(%error, "failed to add tray menu item");
+        }
+    }
+
+    menu
+}
+
+fn menu_item(entry: TrayMenuEntry) -> Box<dyn IsMenuItem> {
+    match entry {
+        TrayMenuEntry::Action(action, label) => {
+            Box::new(MenuItem::with_id(action.id(), label, true, None))
+        }
+        TrayMenuEntry::Submenu(label, entries) => {
+            let submenu = Submenu::new(label, true);
+            for entry in entries {
+                if let Err(error) = submenu.append(menu_item(entry).as_ref()) {
+                    debug!(%error, "failed to add tray submenu item");
+                }
+            }
+            Box::new(submenu)
+        }
+        TrayMenuEntry::Separator => Box::new(PredefinedMenuItem::separator()),
+    }
+}
+
+fn state_icon(display_info: &TrayDisplayInfo) -> Icon {
+    let rgba = display_info.icon_rgba(ICON_SIZE);
+    Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE).expect("icon buffer matches its size")
+}
+
+#[cfg(windows)]
+fn pump_platform_events() {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        DispatchMessageW, PeekMessageW, TranslateMessage, MSG, PM_REMOVE,
+    };
+
+    let mut message: MSG = unsafe { std::mem::zeroed() };
+    while unsafe { PeekMessageW(&mut message, std::ptr::null_mut(), 0, 0, PM_REMOVE) } != 0 {
+        unsafe {
+            TranslateMessage(&message);
+            DispatchMessageW(&message);
+        }
+    }
+    thread::sleep(EVENT_LOOP_INTERVAL);
+}
+
+#[cfg(target_os = "macos")]
+fn pump_platform_events() {
+    use core_foundation::runloop::{kCFRunLoopDefaultMode, CFRunLoop};
+
+    CFRunLoop::run_in_mode(unsafe { kCFRunLoopDefaultMode }, EVENT_LOOP_INTERVAL, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::{FocusMode, ModesConfig, Translator};
+
+    #[test]
+    fn plain_tooltip_has_no_markup() {
+        let info = TrayDisplayInfo {
+            state: TrayState::Active,
+            remaining: Some(Duration::from_secs(754)),
+            mode: Some(FocusMode::Review),
+            mode_colors: ModesConfig::default(),
+            show_countdown: false,
+            hide_seconds: false,
+            translator: Translator::default(),
+        };
+        assert_eq!(
+            info.plain_tooltip_description(),
+            "Flux - 12:34 remaining (Code review)"
+        );
+    }
+}
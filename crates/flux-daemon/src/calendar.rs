@@ -0,0 +1,267 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use flux_core::{CalendarConfig, CalendarEvent, CalendarGateway, Config, Translator};
+use tokio::sync::broadcast;
+use tokio::time::{interval, Duration};
+use tracing::{debug, info, warn};
+
+use crate::actors::{InterruptOutcome, NotifierHandle, TimerHandle};
+
+const CHECK_INTERVAL_SECONDS: u64 = 30;
+const REFRESH_INTERVAL_MINUTES: i64 = 15;
+const LOOKAHEAD_HOURS: i64 = 12;
+/// A session already running this long into a meeting is left alone
+const AUTO_PAUSE_GRACE_MINUTES: i64 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MeetingAction {
+    Warn { title: String, minutes: i64 },
+    Pause { title: String },
+    Resume,
+}
+
+/// Which meetings were already announced or paused for, so each one is handled once
+#[derive(Default)]
+struct MeetingWatch {
+    events: Vec<CalendarEvent>,
+    warned: HashSet<(String, DateTime<Utc>)>,
+    paused_for: Option<CalendarEvent>,
+    handled_pauses: HashSet<(String, DateTime<Utc>)>,
+}
+
+impl MeetingWatch {
+    fn actions(
+        &mut self,
+        now: DateTime<Utc>,
+        session_active: bool,
+        session_paused: bool,
+        config: &CalendarConfig,
+    ) -> Vec<MeetingAction> {
+        if !session_active {
+            self.paused_for = None;
+            return Vec::new();
+        }
+
+        let mut actions = Vec::new();
+
+        if let Some(meeting) = &self.paused_for {
+            if now >= meeting.ends_at {
+                if session_paused {
+                    actions.push(MeetingAction::Resume);
+                }
+                self.paused_for = None;
+            }
+        }
+
+        for event in &self.events {
+            let key = (event.title.clone(), event.starts_at);
+
+            if let Some(minutes) = event.minutes_until_start(now) {
+                if minutes <= config.warning_minutes as i64 && self.warned.insert(key) {
+                    actions.push(MeetingAction::Warn {
+                        title: event.title.clone(),
+                        minutes,
+                    });
+                }
+                continue;
+            }
+
+            let just_started = now.signed_duration_since(event.starts_at)
+                <= ChronoDuration::minutes(AUTO_PAUSE_GRACE_MINUTES);
+            if config.auto_pause
+                && !session_paused
+                && self.paused_for.is_none()
+                && event.is_ongoing(now)
+                && just_started
+                && self.handled_pauses.insert(key)
+            {
+                actions.push(MeetingAction::Pause {
+                    title: event.title.clone(),
+                });
+                self.paused_for = Some(event.clone());
+            }
+        }
+
+        actions
+    }
+}
+
+pub async fn run(
+    gateway: Arc<dyn CalendarGateway>,
+    config: CalendarConfig,
+    timer: TimerHandle,
+    notifier: NotifierHandle,
+    mut shutdown: broadcast::Receiver<()>,
+) {
+    info!(
+        source = gateway.source_name(),
+        auto_pause = config.auto_pause,
+        "calendar watcher started"
+    );
+
+    let mut watch = MeetingWatch::default();
+    let mut refreshed_at: Option<DateTime<Utc>> = None;
+    let mut ticker = interval(Duration::from_secs(CHECK_INTERVAL_SECONDS));
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = shutdown.recv() => {
+                debug!("calendar watcher shutdown");
+                break;
+            }
+        }
+
+        let now = Utc::now();
+        let refresh_due = refreshed_at.map_or(true, |last| {
+            now.signed_duration_since(last) >= ChronoDuration::minutes(REFRESH_INTERVAL_MINUTES)
+        });
+        if refresh_due {
+            refreshed_at = Some(now);
+            match gateway
+                .events_between(
+                    now - ChronoDuration::hours(1),
+                    now + ChronoDuration::hours(LOOKAHEAD_HOURS),
+                )
+                .await
+            {
+                Ok(events) => {
+                    debug!(count = events.len(), "calendar refreshed");
+                    watch.events = events;
+                }
+                Err(error) => warn!(%error, "failed to read calendar"),
+            }
+        }
+
+        let Some(status) = timer.get_status().await else {
+            continue;
+        };
+        for action in watch.actions(now, status.active, status.paused, &config) {
+            apply(action, &timer, &notifier).await;
+        }
+    }
+}
+
+async fn apply(action: MeetingAction, timer: &TimerHandle, notifier: &NotifierHandle) {
+    match action {
+        MeetingAction::Warn { title, minutes } => {
+            let translator = get_translator();
+            notifier.send_alert(
+                translator.get("notification.meeting_soon_title"),
+                translator.format(
+                    "notification.meeting_soon_body",
+                    &[("title", &title), ("minutes", &minutes.to_string())],
+                ),
+            );
+        }
+        MeetingAction::Pause { title } => {
+            info!(%title, "meeting started, pausing session");
+            match timer.pause(false, None).await {
+                Some(InterruptOutcome::Done) => {}
+                Some(InterruptOutcome::Refused { .. }) => {
+                    info!("strict session, meeting pause skipped")
+                }
+                None => warn!("failed to pause session for meeting"),
+            }
+        }
+        MeetingAction::Resume => {
+            info!("meeting ended, resuming session");
+            if let Err(error) = timer.resume().await {
+                warn!(%error, "failed to resume session after meeting");
+            }
+        }
+    }
+}
+
+fn get_translator() -> Translator {
+    Config::load()
+        .map(|config| Translator::new(config.language()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 14, 0, 0).unwrap() + ChronoDuration::minutes(minutes)
+    }
+
+    fn config(auto_pause: bool) -> CalendarConfig {
+        CalendarConfig {
+            url: "meetings.ics".to_string(),
+            warning_minutes: 5,
+            auto_pause,
+        }
+    }
+
+    fn watch_with_planning() -> MeetingWatch {
+        MeetingWatch {
+            events: vec![CalendarEvent::new("Planning".to_string(), at(10), at(40))],
+            ..MeetingWatch::default()
+        }
+    }
+
+    #[test]
+    fn meeting_is_announced_once_within_the_warning_window() {
+        let mut watch = watch_with_planning();
+        let config = config(false);
+
+        assert!(watch.actions(at(0), true, false, &config).is_empty());
+        assert_eq!(
+            watch.actions(at(5), true, false, &config),
+            vec![MeetingAction::Warn {
+                title: "Planning".to_string(),
+                minutes: 5,
+            }]
+        );
+        assert!(watch.actions(at(6), true, false, &config).is_empty());
+        assert!(watch.actions(at(10), true, false, &config).is_empty());
+    }
+
+    #[test]
+    fn no_warning_without_a_running_session() {
+        let mut watch = watch_with_planning();
+
+        assert!(watch.actions(at(5), false, false, &config(true)).is_empty());
+    }
+
+    #[test]
+    fn auto_pause_pauses_at_the_start_and_resumes_at_the_end() {
+        let mut watch = watch_with_planning();
+        let config = config(true);
+        watch.actions(at(5), true, false, &config);
+
+        assert_eq!(
+            watch.actions(at(10), true, false, &config),
+            vec![MeetingAction::Pause {
+                title: "Planning".to_string(),
+            }]
+        );
+        assert!(watch.actions(at(20), true, true, &config).is_empty());
+        assert_eq!(
+            watch.actions(at(40), true, true, &config),
+            vec![MeetingAction::Resume]
+        );
+    }
+
+    #[test]
+    fn session_started_during_a_meeting_is_not_paused() {
+        let mut watch = watch_with_planning();
+
+        assert!(watch.actions(at(20), true, false, &config(true)).is_empty());
+    }
+
+    #[test]
+    fn manual_resume_during_the_meeting_is_respected() {
+        let mut watch = watch_with_planning();
+        let config = config(true);
+        watch.actions(at(10), true, false, &config);
+
+        assert!(watch.actions(at(15), true, false, &config).is_empty());
+        assert!(watch.actions(at(40), true, false, &config).is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+
+use anyhow::{bail, Context, Result};
+use tracing::{debug, info};
+
+use super::DoNotDisturb;
+
+const GNOME_SCHEMA: &str = "org.gnome.desktop.notifications";
+const GNOME_KEY: &str = "show-banners";
+const KDE_FILE: &str = "plasmanotifyrc";
+const KDE_GROUP: &str = "DoNotDisturb";
+const KDE_KEY: &str = "Until";
+const KDE_FOREVER: &str = "2100,1,1,0,0,0";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Backend {
+    Gnome { gsettings: PathBuf },
+    Kde { reader: PathBuf, writer: PathBuf },
+    Dunst { dunstctl: PathBuf },
+}
+
+pub struct DesktopDoNotDisturb {
+    backend: Backend,
+    previous_value: Mutex<Option<String>>,
+}
+
+impl DesktopDoNotDisturb {
+    pub fn detect() -> Option<Self> {
+        let current_desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
+        let backend = detect_backend(&current_desktop, |binary| which::which(binary).ok())?;
+        info!(?backend, "do-not-disturb backend detected");
+
+        Some(Self {
+            backend,
+            previous_value: Mutex::new(None),
+        })
+    }
+
+    fn read_current(&self) -> Result<String> {
+        let output = match &self.backend {
+            Backend::Gnome { gsettings } => run(gsettings, &["get", GNOME_SCHEMA, GNOME_KEY])?,
+            Backend::Kde { reader, .. } => run(
+                reader,
+                &["--file", KDE_FILE, "--group", KDE_GROUP, "--key", KDE_KEY],
+            )?,
+            Backend::Dunst { dunstctl } => run(dunstctl, &["is-paused"])?,
+        };
+        Ok(output.trim().to_string())
+    }
+
+    fn write(&self, value: &str) -> Result<()> {
+        match &self.backend {
+            Backend::Gnome { gsettings } => {
+                run(gsettings, &["set", GNOME_SCHEMA, GNOME_KEY, value])?;
+            }
+            Backend::Kde { writer, .. } => {
+                let mut arguments =
+                    vec!["--file", KDE_FILE, "--group", KDE_GROUP, "--key", KDE_KEY];
+                if value.is_empty() {
+                    arguments.push("--delete");
+                } else {
+                    arguments.push(value);
+                }
+                run(writer, &arguments)?;
+            }
+            Backend::Dunst { dunstctl } => {
+                run(dunstctl, &["set-paused", value])?;
+            }
+        }
+        Ok(())
+    }
+
+    fn enabled_value(&self) -> &'static str {
+        match self.backend {
+            Backend::Gnome { .. } => "false",
+            Backend::Kde { .. } => KDE_FOREVER,
+            Backend::Dunst { .. } => "true",
+        }
+    }
+}
+
+impl DoNotDisturb for DesktopDoNotDisturb {
+    fn enable(&self) -> Result<()> {
+        let mut previous_value = self.previous_value.lock().unwrap();
+        if previous_value.is_some() {
+            return Ok(());
+        }
+
+        let current = self.read_current()?;
+        self.write(self.enabled_value())?;
+        debug!(previous = %current, "do-not-disturb enabled");
+        *previous_value = Some(current);
+        Ok(())
+    }
+
+    fn restore(&self) -> Result<()> {
+        let mut previous_value = self.previous_value.lock().unwrap();
+        let Some(value) = previous_value.take() else {
+            return Ok(());
+        };
+
+        self.write(&value)?;
+        debug!(restored = %value, "do-not-disturb restored");
+        Ok(())
+    }
+}
+
+fn detect_backend(
+    current_desktop: &str,
+    find_binary: impl Fn(&str) -> Option<PathBuf>,
+) -> Option<Backend> {
+    let desktop = current_desktop.to_lowercase();
+
+    if desktop.contains("gnome") || desktop.contains("unity") || desktop.contains("budgie") {
+        if let Some(gsettings) = find_binary("gsettings") {
+            return Some(Backend::Gnome { gsettings });
+        }
+    }
+
+    if desktop.contains("kde") {
+        let tools = [
+            ("kreadconfig6", "kwriteconfig6"),
+            ("kreadconfig5", "kwriteconfig5"),
+        ];
+        for (reader, writer) in tools {
+            if let (Some(reader), Some(writer)) = (find_binary(reader), find_binary(writer)) {
+                return Some(Backend::Kde { reader, writer });
+            }
+        }
+    }
+
+    find_binary("dunstctl").map(|dunstctl| Backend::Dunst { dunstctl })
+}
+
+fn run(binary: &Path, arguments: &[&str]) -> Result<String> {
+    let output = Command::new(binary)
+        .args(arguments)
+        .output()
+        .with_context(|| format!("failed to run {}", binary.display()))?;
+
+    if !output.status.success() {
+        bail!(
+            "{} exited with {}: {}",
+            binary.display(),
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_binaries(binary: &str) -> Option<PathBuf> {
+        Some(PathBuf::from(format!("/usr/bin/{}", binary)))
+    }
+
+    #[test]
+    fn detects_gnome_from_current_desktop() {
+        let backend = detect_backend("ubuntu:GNOME", all_binaries);
+
+        assert_eq!(
+            backend,
+            Some(Backend::Gnome {
+                gsettings: PathBuf::from("/usr/bin/gsettings")
+            })
+        );
+    }
+
+    #[test]
+    fn detects_kde_and_prefers_plasma_6_tools() {
+        let backend = detect_backend("KDE", all_binaries);
+
+        assert_eq!(
+            backend,
+            Some(Backend::Kde {
+                reader: PathBuf::from("/usr/bin/kreadconfig6"),
+                writer: PathBuf::from("/usr/bin/kwriteconfig6"),
+            })
+        );
+    }
+
+    #[test]
+    fn falls_back_to_dunst_on_other_desktops() {
+        let backend = detect_backend("i3", all_binaries);
+
+        assert_eq!(
+            backend,
+            Some(Backend::Dunst {
+                dunstctl: PathBuf::from("/usr/bin/dunstctl")
+            })
+        );
+    }
+
+    #[test]
+    fn returns_none_when_no_tool_is_available() {
+        let backend = detect_backend("GNOME", |_| None);
+
+        assert!(backend.is_none());
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(target_os = "linux")]
+mod desktop;
+mod queued;
+
+#[cfg(target_os = "linux")]
+pub use desktop::DesktopDoNotDisturb;
+pub use queued::QueuedDoNotDisturb;
+
+pub trait DoNotDisturb: Send + Sync {
+    fn enable(&self) -> anyhow::Result<()>;
+
+    fn restore(&self) -> anyhow::Result<()>;
+}
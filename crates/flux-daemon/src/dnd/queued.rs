@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use tokio::sync::{mpsc, oneshot};
+use tracing::warn;
+
+use super::DoNotDisturb;
+
+enum Command {
+    Enable,
+    Restore {
+        done: Option<oneshot::Sender<Result<()>>>,
+    },
+}
+
+/// Runs the commands of a backend one after the other on tokio's blocking
+/// pool. The desktop tools can take a while to answer, and the timer only
+/// queues its commands instead of waiting for them.
+pub struct QueuedDoNotDisturb {
+    sender: mpsc::UnboundedSender<Command>,
+}
+
+impl QueuedDoNotDisturb {
+    pub fn spawn(backend: Arc<dyn DoNotDisturb>) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Some(command) = receiver.recv().await {
+                let backend = Arc::clone(&backend);
+                match command {
+                    Command::Enable => {
+                        if let Err(error) = run_blocking(move || backend.enable()).await {
+                            warn!(%error, "failed to enable do-not-disturb");
+                        }
+                    }
+                    Command::Restore { done } => {
+                        let result = run_blocking(move || backend.restore()).await;
+                        match done {
+                            Some(done) => {
+                                let _ = done.send(result);
+                            }
+                            None => {
+                                if let Err(error) = result {
+                                    warn!(%error, "failed to restore do-not-disturb");
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        Self { sender }
+    }
+
+    /// Restores the previous state once the commands already queued ran, for
+    /// the daemon to leave the desktop as it found it
+    pub async fn restore_and_wait(&self) -> Result<()> {
+        let (done, result) = oneshot::channel();
+        self.send(Command::Restore { done: Some(done) })?;
+        result
+            .await
+            .map_err(|_| anyhow!("do-not-disturb worker stopped"))?
+    }
+
+    fn send(&self, command: Command) -> Result<()> {
+        self.sender
+            .send(command)
+            .map_err(|_| anyhow!("do-not-disturb worker stopped"))
+    }
+}
+
+impl DoNotDisturb for QueuedDoNotDisturb {
+    fn enable(&self) -> Result<()> {
+        self.send(Command::Enable)
+    }
+
+    fn restore(&self) -> Result<()> {
+        self.send(Command::Restore { done: None })
+    }
+}
+
+async fn run_blocking(call: impl FnOnce() -> Result<()> + Send + 'static) -> Result<()> {
+    tokio::task::spawn_blocking(call)
+        .await
+        .map_err(|error| anyhow!("do-not-disturb command failed: {error}"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct SlowDoNotDisturb {
+        calls: Mutex<Vec<&'static str>>,
+    }
+
+    impl DoNotDisturb for SlowDoNotDisturb {
+        fn enable(&self) -> Result<()> {
+            std::thread::sleep(Duration::from_millis(50));
+            self.calls.lock().unwrap().push("enable");
+            Ok(())
+        }
+
+        fn restore(&self) -> Result<()> {
+            self.calls.lock().unwrap().push("restore");
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn commands_run_in_order_without_holding_the_caller() {
+        let backend = Arc::new(SlowDoNotDisturb::default());
+        let queued = QueuedDoNotDisturb::spawn(backend.clone());
+
+        queued.enable().unwrap();
+        queued.restore().unwrap();
+        queued.enable().unwrap();
+        assert!(backend.calls.lock().unwrap().is_empty());
+
+        queued.restore_and_wait().await.unwrap();
+        assert_eq!(
+            *backend.calls.lock().unwrap(),
+            vec!["enable", "restore", "enable", "restore"]
+        );
+    }
+}
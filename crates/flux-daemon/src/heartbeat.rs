@@ -0,0 +1,47 @@
+use chrono::Utc;
+use flux_core::{Heartbeat, NotificationCapabilities, HEARTBEAT_INTERVAL_SECONDS};
+use tokio::sync::broadcast;
+use tokio::time::{interval, Duration};
+use tracing::{debug, info, warn};
+
+use crate::log_throttle::LogThrottle;
+
+const HEARTBEAT_WRITE_WARNING: &str = "heartbeat_write";
+
+pub async fn run(capabilities: NotificationCapabilities, mut shutdown: broadcast::Receiver<()>) {
+    let now = Utc::now();
+    let mut heartbeat = Heartbeat {
+        pid: std::process::id(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        started_at: now,
+        updated_at: now,
+        notifications: capabilities,
+    };
+
+    let mut ticker = interval(Duration::from_secs(HEARTBEAT_INTERVAL_SECONDS));
+    let warnings = LogThrottle::default();
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                heartbeat.updated_at = Utc::now();
+                match heartbeat.save() {
+                    Ok(()) => {
+                        if let Some(suppressed) = warnings.recover(HEARTBEAT_WRITE_WARNING) {
+                            info!(suppressed, "heartbeat writes recovered");
+                        }
+                    }
+                    Err(error) => {
+                        if let Some(suppressed) = warnings.should_log(HEARTBEAT_WRITE_WARNING) {
+                            warn!(%error, suppressed, "failed to write heartbeat");
+                        }
+                    }
+                }
+            }
+            _ = shutdown.recv() => {
+                debug!("heartbeat shutdown");
+                break;
+            }
+        }
+    }
+}
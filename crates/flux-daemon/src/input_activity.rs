@@ -0,0 +1,129 @@
+//! Keyboard and mouse activity estimated from `/proc/interrupts` counters
+//!
+//! Only interrupt counts are read: no key or pointer event is ever captured.
+//! Devices behind a USB host controller share its interrupt line with other
+//! peripherals and are not counted.
+
+use std::fs;
+
+use tracing::{debug, warn};
+
+const INTERRUPTS_PATH: &str = "/proc/interrupts";
+const INPUT_DEVICE_MARKERS: [&str; 5] = ["i8042", "keyboard", "mouse", "touchpad", "hid"];
+
+pub struct InputActivitySampler {
+    last_count: u64,
+}
+
+impl InputActivitySampler {
+    pub fn new() -> Option<Self> {
+        let Some(last_count) = read_input_interrupt_count() else {
+            warn!("no keyboard or mouse interrupt line found, input activity disabled");
+            return None;
+        };
+        debug!(last_count, "input activity sampler started");
+        Some(Self { last_count })
+    }
+
+    /// Whether any keyboard or mouse interrupt fired since the previous sample
+    pub fn sample(&mut self) -> Option<bool> {
+        let count = read_input_interrupt_count()?;
+        let active = count != self.last_count;
+        self.last_count = count;
+        Some(active)
+    }
+}
+
+/// Polls with and without input during a session
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InputActivityTally {
+    pub samples: u32,
+    pub active_samples: u32,
+}
+
+impl InputActivityTally {
+    pub fn record(&mut self, active: bool) {
+        self.samples += 1;
+        if active {
+            self.active_samples += 1;
+        }
+    }
+
+    pub fn ratio(&self) -> Option<f64> {
+        (self.samples > 0).then(|| self.active_samples as f64 / self.samples as f64)
+    }
+}
+
+fn read_input_interrupt_count() -> Option<u64> {
+    let content = fs::read_to_string(INTERRUPTS_PATH).ok()?;
+    input_interrupt_count(&content)
+}
+
+fn input_interrupt_count(content: &str) -> Option<u64> {
+    let counts: Vec<u64> = content
+        .lines()
+        .filter_map(|line| {
+            let (irq, rest) = line.trim_start().split_once(':')?;
+            if irq.is_empty() {
+                return None;
+            }
+            let mut total = 0;
+            let mut description = Vec::new();
+            for token in rest.split_whitespace() {
+                match token.parse::<u64>() {
+                    Ok(count) if description.is_empty() => total += count,
+                    _ => description.push(token.to_lowercase()),
+                }
+            }
+            description
+                .iter()
+                .any(|token| {
+                    INPUT_DEVICE_MARKERS
+                        .iter()
+                        .any(|marker| token.contains(marker))
+                })
+                .then_some(total)
+        })
+        .collect();
+
+    (!counts.is_empty()).then(|| counts.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERRUPTS: &str = "            CPU0       CPU1
+   1:          0         42  IR-IO-APIC    1-edge      i8042
+   8:          0          0  IR-IO-APIC    8-edge      rtc0
+  12:         10        143  IR-IO-APIC   12-edge      i8042
+  16:          0       5000  IR-IO-APIC   16-fasteoi   i2c_designware.0, idma64.0
+ NMI:          0          0   Non-maskable interrupts
+";
+
+    #[test]
+    fn sums_keyboard_and_mouse_lines_across_cpus() {
+        assert_eq!(input_interrupt_count(INTERRUPTS), Some(195));
+    }
+
+    #[test]
+    fn no_input_line_means_no_measure() {
+        assert_eq!(
+            input_interrupt_count("  8:  0  0  IR-IO-APIC  8-edge  rtc0\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn tally_ratio_counts_active_polls() {
+        let mut tally = InputActivityTally::default();
+        assert_eq!(tally.ratio(), None);
+
+        tally.record(true);
+        tally.record(false);
+        tally.record(true);
+        tally.record(true);
+
+        assert_eq!(tally.ratio(), Some(0.75));
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use flux_protocol::{JobId, JobState};
+use tokio::task::AbortHandle;
+use tracing::{debug, info};
+
+/// Finished jobs kept so clients can still read their outcome
+const MAX_FINISHED_JOBS: usize = 32;
+
+struct Job {
+    state: JobState,
+    abort: Option<AbortHandle>,
+}
+
+#[derive(Default)]
+struct JobTable {
+    next_id: JobId,
+    jobs: BTreeMap<JobId, Job>,
+}
+
+impl JobTable {
+    fn evict_finished(&mut self) {
+        let finished: Vec<JobId> = self
+            .jobs
+            .iter()
+            .filter(|(_, job)| job.state.is_finished())
+            .map(|(job_id, _)| *job_id)
+            .collect();
+        for job_id in finished
+            .iter()
+            .take(finished.len().saturating_sub(MAX_FINISHED_JOBS))
+        {
+            self.jobs.remove(job_id);
+        }
+    }
+}
+
+/// Long-running operations started on behalf of clients, polled and cancelled by id
+#[derive(Clone, Default)]
+pub struct JobRegistry {
+    table: Arc<Mutex<JobTable>>,
+}
+
+impl JobRegistry {
+    /// Runs `work` in the background; it resolves to a short summary or a failure reason
+    pub fn spawn<Work>(&self, name: &'static str, work: Work) -> JobId
+    where
+        Work: Future<Output = Result<Option<String>, String>> + Send + 'static,
+    {
+        let job_id = {
+            let mut table = self.lock();
+            table.next_id += 1;
+            let job_id = table.next_id;
+            table.jobs.insert(
+                job_id,
+                Job {
+                    state: JobState::Running,
+                    abort: None,
+                },
+            );
+            job_id
+        };
+
+        let registry = self.clone();
+        let task = tokio::spawn(async move {
+            let state = match work.await {
+                Ok(output) => JobState::Completed { output },
+                Err(reason) => JobState::Failed { reason },
+            };
+            debug!(job_id, name, ?state, "job finished");
+            registry.finish(job_id, state);
+        });
+        if let Some(job) = self.lock().jobs.get_mut(&job_id) {
+            if job.state == JobState::Running {
+                job.abort = Some(task.abort_handle());
+            }
+        }
+
+        info!(job_id, name, "job started");
+        job_id
+    }
+
+    pub fn status(&self, job_id: JobId) -> Option<JobState> {
+        self.lock().jobs.get(&job_id).map(|job| job.state.clone())
+    }
+
+    /// Stops a running job; finished jobs keep their outcome
+    pub fn cancel(&self, job_id: JobId) -> Option<JobState> {
+        let mut table = self.lock();
+        let job = table.jobs.get_mut(&job_id)?;
+        if job.state == JobState::Running {
+            if let Some(abort) = job.abort.take() {
+                abort.abort();
+            }
+            job.state = JobState::Cancelled;
+            info!(job_id, "job cancelled");
+        }
+        let state = job.state.clone();
+        table.evict_finished();
+        Some(state)
+    }
+
+    fn finish(&self, job_id: JobId, state: JobState) {
+        let mut table = self.lock();
+        if let Some(job) = table.jobs.get_mut(&job_id) {
+            if job.state == JobState::Running {
+                job.state = state;
+                job.abort = None;
+            }
+        }
+        table.evict_finished();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, JobTable> {
+        self.table
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+
+    async fn wait_until_finished(registry: &JobRegistry, job_id: JobId) -> JobState {
+        for _ in 0..100 {
+            match registry.status(job_id) {
+                Some(state) if state.is_finished() => return state,
+                _ => tokio::time::sleep(Duration::from_millis(5)).await,
+            }
+        }
+        panic!("job {} did not finish", job_id);
+    }
+
+    #[tokio::test]
+    async fn job_reports_running_then_its_outcome() {
+        let registry = JobRegistry::default();
+        let (release_sender, release_receiver) = oneshot::channel::<()>();
+
+        let job_id = registry.spawn("test", async move {
+            release_receiver.await.ok();
+            Ok(Some("done".to_string()))
+        });
+
+        assert_eq!(registry.status(job_id), Some(JobState::Running));
+        release_sender.send(()).unwrap();
+        assert_eq!(
+            wait_until_finished(&registry, job_id).await,
+            JobState::Completed {
+                output: Some("done".to_string())
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_job_keeps_its_reason() {
+        let registry = JobRegistry::default();
+
+        let job_id = registry.spawn("test", async { Err("disk full".to_string()) });
+
+        assert_eq!(
+            wait_until_finished(&registry, job_id).await,
+            JobState::Failed {
+                reason: "disk full".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled_job_stays_cancelled() {
+        let registry = JobRegistry::default();
+
+        let job_id = registry.spawn("test", std::future::pending());
+
+        assert_eq!(registry.cancel(job_id), Some(JobState::Cancelled));
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(registry.status(job_id), Some(JobState::Cancelled));
+        assert_eq!(registry.cancel(999), None);
+    }
+
+    #[tokio::test]
+    async fn only_the_latest_finished_jobs_are_kept() {
+        let registry = JobRegistry::default();
+
+        let job_ids: Vec<JobId> = (0..MAX_FINISHED_JOBS + 3)
+            .map(|_| registry.spawn("test", async { Ok(None) }))
+            .collect();
+        for job_id in &job_ids[3..] {
+            wait_until_finished(&registry, *job_id).await;
+        }
+
+        assert_eq!(registry.status(job_ids[0]), None);
+        assert!(registry.status(*job_ids.last().unwrap()).is_some());
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const DEFAULT_INTERVAL_SECONDS: u64 = 300;
+
+#[derive(Debug, Clone, Copy)]
+struct ThrottleEntry {
+    last_logged: Instant,
+    suppressed: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogThrottle {
+    interval: Duration,
+    entries: Arc<Mutex<HashMap<&'static str, ThrottleEntry>>>,
+}
+
+impl Default for LogThrottle {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_INTERVAL_SECONDS))
+    }
+}
+
+impl LogThrottle {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn should_log(&self, key: &'static str) -> Option<u32> {
+        self.should_log_at(key, Instant::now())
+    }
+
+    pub fn recover(&self, key: &'static str) -> Option<u32> {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        entries.remove(key).map(|entry| entry.suppressed)
+    }
+
+    fn should_log_at(&self, key: &'static str, now: Instant) -> Option<u32> {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        match entries.get_mut(key) {
+            Some(entry) if now.duration_since(entry.last_logged) < self.interval => {
+                entry.suppressed += 1;
+                None
+            }
+            Some(entry) => {
+                let suppressed = entry.suppressed;
+                entry.last_logged = now;
+                entry.suppressed = 0;
+                Some(suppressed)
+            }
+            None => {
+                entries.insert(
+                    key,
+                    ThrottleEntry {
+                        last_logged: now,
+                        suppressed: 0,
+                    },
+                );
+                Some(0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_occurrence_is_logged_and_repeats_are_suppressed() {
+        let throttle = LogThrottle::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert_eq!(throttle.should_log_at("x11", start), Some(0));
+        assert_eq!(
+            throttle.should_log_at("x11", start + Duration::from_secs(5)),
+            None
+        );
+        assert_eq!(
+            throttle.should_log_at("x11", start + Duration::from_secs(10)),
+            None
+        );
+        assert_eq!(
+            throttle.should_log_at("x11", start + Duration::from_secs(61)),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn keys_are_throttled_independently() {
+        let throttle = LogThrottle::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert_eq!(throttle.should_log_at("x11", start), Some(0));
+        assert_eq!(throttle.should_log_at("notification", start), Some(0));
+        assert_eq!(throttle.should_log_at("x11", start), None);
+    }
+
+    #[test]
+    fn recover_reports_suppressed_count_and_resets_key() {
+        let throttle = LogThrottle::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        throttle.should_log_at("heartbeat", start);
+        throttle.should_log_at("heartbeat", start);
+
+        assert_eq!(throttle.recover("heartbeat"), Some(1));
+        assert_eq!(throttle.recover("heartbeat"), None);
+        assert_eq!(throttle.should_log_at("heartbeat", start), Some(0));
+    }
+}
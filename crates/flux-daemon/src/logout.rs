@@ -0,0 +1,174 @@
+use std::process::Stdio;
+
+use flux_core::SessionEndReason;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::actors::TimerHandle;
+
+const LOGIND_DESTINATION: &str = "org.freedesktop.login1";
+const LOGIND_PATH: &str = "/org/freedesktop/login1";
+const MANAGER_INTERFACE: &str = "org.freedesktop.login1.Manager";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LogindSignal {
+    SessionRemoved { session_id: String },
+    UserRemoved { user_id: u32 },
+    PrepareForShutdown { starting: bool },
+}
+
+impl LogindSignal {
+    fn parse(line: &str) -> Option<Self> {
+        let (_, signal) = line.split_once(&format!("{}.", MANAGER_INTERFACE))?;
+        let (name, arguments) = signal.split_once(' ')?;
+        let first_argument = arguments
+            .trim()
+            .trim_start_matches('(')
+            .split(',')
+            .next()?
+            .trim();
+
+        match name {
+            "SessionRemoved" => Some(LogindSignal::SessionRemoved {
+                session_id: first_argument.trim_matches('\'').to_string(),
+            }),
+            "UserRemoved" => Some(LogindSignal::UserRemoved {
+                user_id: first_argument
+                    .trim_start_matches("uint32")
+                    .trim()
+                    .parse()
+                    .ok()?,
+            }),
+            "PrepareForShutdown" => Some(LogindSignal::PrepareForShutdown {
+                starting: first_argument == "true",
+            }),
+            _ => None,
+        }
+    }
+
+    fn ends_desktop_session(&self, session_id: Option<&str>, user_id: u32) -> bool {
+        match self {
+            LogindSignal::SessionRemoved {
+                session_id: removed,
+            } => session_id == Some(removed.as_str()),
+            LogindSignal::UserRemoved { user_id: removed } => *removed == user_id,
+            LogindSignal::PrepareForShutdown { starting } => *starting,
+        }
+    }
+}
+
+pub async fn watch(timer_handle: TimerHandle, mut shutdown: broadcast::Receiver<()>) {
+    let Ok(gdbus) = which::which("gdbus") else {
+        debug!("gdbus not found, logout detection disabled");
+        return;
+    };
+
+    let spawned = Command::new(gdbus)
+        .args([
+            "monitor",
+            "--system",
+            "--dest",
+            LOGIND_DESTINATION,
+            "--object-path",
+            LOGIND_PATH,
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn();
+
+    let mut monitor = match spawned {
+        Ok(monitor) => monitor,
+        Err(error) => {
+            warn!(%error, "failed to monitor logind, logout detection disabled");
+            return;
+        }
+    };
+    let Some(stdout) = monitor.stdout.take() else {
+        return;
+    };
+
+    let session_id = std::env::var("XDG_SESSION_ID").ok();
+    let user_id = flux_protocol::socket::current_user_id();
+    let mut lines = BufReader::new(stdout).lines();
+    info!(?session_id, "watching logind for logout");
+
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let Ok(Some(line)) = line else {
+                    debug!("logind monitor exited");
+                    break;
+                };
+                let Some(signal) = LogindSignal::parse(&line) else {
+                    continue;
+                };
+                if signal.ends_desktop_session(session_id.as_deref(), user_id) {
+                    info!(?signal, "desktop session ending, ending focus session");
+                    if let Err(error) = timer_handle.end(SessionEndReason::Logout).await {
+                        warn!(%error, "failed to end session on logout");
+                    }
+                }
+            }
+            _ = shutdown.recv() => {
+                debug!("logout watcher shutdown");
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_logind_signals() {
+        assert_eq!(
+            LogindSignal::parse(
+                "/org/freedesktop/login1: org.freedesktop.login1.Manager.SessionRemoved ('3', objectpath '/org/freedesktop/login1/session/_33')"
+            ),
+            Some(LogindSignal::SessionRemoved {
+                session_id: "3".to_string()
+            })
+        );
+        assert_eq!(
+            LogindSignal::parse(
+                "/org/freedesktop/login1: org.freedesktop.login1.Manager.UserRemoved (uint32 1000, objectpath '/org/freedesktop/login1/user/_1000')"
+            ),
+            Some(LogindSignal::UserRemoved { user_id: 1000 })
+        );
+        assert_eq!(
+            LogindSignal::parse(
+                "/org/freedesktop/login1: org.freedesktop.login1.Manager.PrepareForShutdown (true,)"
+            ),
+            Some(LogindSignal::PrepareForShutdown { starting: true })
+        );
+        assert_eq!(
+            LogindSignal::parse(
+                "/org/freedesktop/login1: org.freedesktop.login1.Manager.SessionNew ('4', objectpath '/org/freedesktop/login1/session/_34')"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn only_own_session_and_user_end_the_focus_session() {
+        let own_session = LogindSignal::SessionRemoved {
+            session_id: "3".to_string(),
+        };
+        let other_user = LogindSignal::UserRemoved { user_id: 1001 };
+
+        assert!(own_session.ends_desktop_session(Some("3"), 1000));
+        assert!(!own_session.ends_desktop_session(Some("5"), 1000));
+        assert!(!own_session.ends_desktop_session(None, 1000));
+        assert!(!other_user.ends_desktop_session(Some("3"), 1000));
+        assert!(LogindSignal::UserRemoved { user_id: 1000 }.ends_desktop_session(None, 1000));
+        assert!(
+            !LogindSignal::PrepareForShutdown { starting: false }.ends_desktop_session(None, 1000)
+        );
+    }
+}
@@ -1,34 +1,104 @@
 mod actors;
+mod calendar;
+mod dnd;
+mod heartbeat;
+mod input_activity;
+mod jobs;
+mod log_throttle;
+#[cfg(target_os = "linux")]
+mod logout;
+#[cfg(target_os = "linux")]
+mod power;
+mod rollups;
 mod server;
+#[cfg(feature = "simulate")]
+mod simulation;
+mod storage;
+mod supervisor;
+mod sync;
 mod window;
 
-use std::sync::Arc;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
-#[cfg(target_os = "linux")]
-use actors::{check_for_updates, open_configuration, open_dashboard, spawn_tray, TrayAction};
-use actors::{AppTrackerActor, DigestSchedulerActor, NotifierActor, TimerActor};
+use actors::{
+    check_for_updates, open_configuration, open_dashboard, probe_capabilities, spawn_tray,
+    AchievementsActor, AppTrackerActor, DigestSchedulerActor, HookRunnerActor, InterruptOutcome,
+    NotifierActor, NotifierHandle, TimerActor, TimerHandle, TrayAction,
+};
 use anyhow::Result;
+use dnd::{DoNotDisturb, QueuedDoNotDisturb};
 use flux_adapters::{
-    SqliteAppTrackingRepository, SqliteSessionMetricsRepository, SqliteSessionRepository,
+    Database, FolderSyncGateway, IcsCalendarGateway, SmtpDigestGateway,
+    SqliteAchievementRepository, SqliteAppTrackingRepository, SqliteSessionMetricsRepository,
+    SqliteSessionNoteRepository, SqliteSessionRepository, SqliteSyncRepository,
+    WebhookDigestGateway,
+};
+use flux_core::{
+    resolve_smtp_password, AchievementRepository, AppState, AppTrackingRepository, CalendarGateway,
+    Config, DigestDeliveryConfig, DigestDeliveryGateway, Heartbeat, Language, PrivacyConfig,
+    SessionEndReason, SessionMetricsRepository, SessionNoteRepository, SessionRepository,
+    SyncGateway, SyncRepository, Translator, UsageRollupRepository,
 };
-use flux_core::{AppTrackingRepository, Config, SessionMetricsRepository, SessionRepository};
-use server::Server;
+use flux_protocol::{Subsystem, SubsystemHealth, SubsystemStatus};
+use jobs::JobRegistry;
+use server::{DaemonState, Server};
+use supervisor::HealthRegistry;
 use tokio::sync::broadcast;
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 use tracing_subscriber::EnvFilter;
 
+fn main() -> Result<()> {
+    #[cfg(feature = "simulate")]
+    if let Some(script) = simulation::script_argument(std::env::args().skip(1))? {
+        return simulation::run(&script);
+    }
+    start()
+}
+
+#[cfg(not(target_os = "macos"))]
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn start() -> Result<()> {
+    run().await
+}
+
+#[cfg(target_os = "macos")]
+fn start() -> Result<()> {
+    let daemon = std::thread::Builder::new()
+        .name("flux-runtime".to_string())
+        .spawn(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()?
+                .block_on(run())
+        })?;
+
+    actors::run_main_thread_event_loop(daemon).unwrap_or_else(|_| {
+        Err(anyhow::anyhow!(
+            "le daemon s'est arrêté de façon inattendue"
+        ))
+    })
+}
+
+async fn run() -> Result<()> {
     tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::from_default_env().add_directive("flux_daemon=debug".parse()?))
+        .with_ansi(std::io::stderr().is_terminal())
         .init();
 
+    let started_at = Instant::now();
     info!("flux daemon starting");
 
-    let config = Config::load().unwrap_or_else(|error| {
-        warn!(%error, "failed to load config, using defaults");
-        Config::default()
-    });
+    let (config, config_load_error) = match Config::load() {
+        Ok(config) => (config, None),
+        Err(error) => {
+            warn!(%error, "failed to load config, using defaults");
+            (Config::default(), Some(error.to_string()))
+        }
+    };
+    let config_warnings = collect_config_warnings(&config, config_load_error);
 
     let (shutdown_sender, shutdown_receiver) = broadcast::channel::<()>(1);
     let sigint_shutdown_sender = shutdown_sender.clone();
@@ -39,35 +109,126 @@ async fn main() -> Result<()> {
         sigint_shutdown_sender.send(()).ok();
     });
 
+    let notification_capabilities = probe_capabilities();
+    info!(
+        ?notification_capabilities,
+        "notification server capabilities"
+    );
+    let notifications_health = match &notification_capabilities.server_name {
+        Some(server_name) => {
+            SubsystemHealth::new(Subsystem::Notifications, SubsystemStatus::Running)
+                .with_detail(server_name.clone())
+        }
+        None => SubsystemHealth::new(
+            Subsystem::Notifications,
+            SubsystemStatus::Failed { reason: None },
+        ),
+    };
+    let health = HealthRegistry::default();
+    let previous_heartbeat = Heartbeat::load();
+    tokio::spawn(heartbeat::run(
+        notification_capabilities.clone(),
+        shutdown_sender.subscribe(),
+    ));
+
     let (notifier_actor, notifier_handle) = NotifierActor::new(
         config.notifications().urgency.clone(),
         config.notifications().sound_enabled,
+        notification_capabilities,
     );
-    tokio::spawn(notifier_actor.run());
+    let restart_notifier = notifier_actor.restarter();
+    tokio::spawn(supervisor::supervise(
+        Subsystem::Notifications,
+        health.clone(),
+        notifier_actor.run(),
+        move || restart_notifier().run(),
+        shutdown_sender.subscribe(),
+    ));
 
-    #[cfg(target_os = "linux")]
-    let (tray_handle, tray_state, tray_action_receiver) = if config.tray.enabled {
-        match spawn_tray() {
+    let (tray_handle, tray_state, tray_action_receiver, tray_status) = if config.tray.enabled {
+        match spawn_tray(
+            config.modes.clone(),
+            config.tray.show_countdown,
+            config.tray.hide_seconds,
+            config.language(),
+        ) {
             Ok((handle, action_receiver)) => {
                 let state = handle.state_handle.clone();
-                (Some(handle), Some(state), Some(action_receiver))
+                (
+                    Some(handle),
+                    Some(state),
+                    Some(action_receiver),
+                    SubsystemStatus::Running,
+                )
             }
             Err(error) => {
                 warn!(%error, "tray initialization failed, continuing without tray");
-                (None, None, None)
+                (
+                    None,
+                    None,
+                    None,
+                    SubsystemStatus::Failed {
+                        reason: Some(error),
+                    },
+                )
             }
         }
     } else {
-        (None, None, None)
+        (None, None, None, SubsystemStatus::Disabled)
     };
 
-    #[cfg(target_os = "linux")]
     let _tray_handle = tray_handle;
 
-    let session_repository = create_session_repository();
-    let app_tracking_repository = create_app_tracking_repository();
-    let session_metrics_repository = create_session_metrics_repository();
+    let database = open_database(&config.privacy);
+    let session_repository = database.as_ref().map(create_session_repository);
+    if config.storage.required && session_repository.is_none() {
+        error!("session storage is required but the database could not be opened, exiting");
+        if let Err(error) = Heartbeat::remove() {
+            warn!(%error, "failed to remove heartbeat");
+        }
+        anyhow::bail!(
+            "le stockage des sessions est requis ([storage] required = true) mais la base de données n'a pas pu être ouverte"
+        );
+    }
+    if let Some(ref repository) = session_repository {
+        recover_interrupted_session(repository.as_ref(), previous_heartbeat.as_ref());
+    }
+    let app_tracking_repository = database.as_ref().map(create_app_tracking_repository);
+    let session_metrics_repository = database.as_ref().map(create_session_metrics_repository);
+    let achievement_repository = database.as_ref().map(create_achievement_repository);
+    let session_note_repository = database.as_ref().map(create_session_note_repository);
+    let usage_rollup_repository = database.as_ref().map(create_usage_rollup_repository);
+    if let Some(repository) = usage_rollup_repository.clone() {
+        tokio::spawn(rollups::run(repository, shutdown_sender.subscribe()));
+    }
+
+    let persistence_status = initialization_status(session_repository.is_some());
 
+    let achievements_handle = if let (Some(session_repo), Some(achievement_repo)) =
+        (session_repository.clone(), achievement_repository)
+    {
+        let (achievements_actor, handle) =
+            AchievementsActor::new(session_repo, achievement_repo, notifier_handle.clone());
+        tokio::spawn(achievements_actor.run());
+        Some(handle)
+    } else {
+        None
+    };
+
+    let hooks_handle = if config.hooks.is_configured() {
+        let (hook_runner_actor, handle) = HookRunnerActor::new(config.hooks.clone());
+        tokio::spawn(hook_runner_actor.run());
+        Some(handle)
+    } else {
+        None
+    };
+
+    let mut window_detector_health =
+        SubsystemHealth::new(Subsystem::WindowDetector, SubsystemStatus::Disabled);
+
+    // The timer is created after the app tracker, and announces the running
+    // session again when the tracker restarts
+    let tracker_timer: Arc<OnceLock<TimerHandle>> = Arc::default();
     let app_tracker_handle = if let (Some(repository), Some(metrics_repository)) =
         (app_tracking_repository.clone(), session_metrics_repository)
     {
@@ -75,14 +236,40 @@ async fn main() -> Result<()> {
             repository,
             metrics_repository,
             config.distractions().clone(),
+            config.privacy.clone(),
             notifier_handle.clone(),
+            achievements_handle.clone(),
+            config.experimental.input_activity,
         );
-        tokio::spawn(app_tracker_actor.run());
+        let app_tracker_actor = app_tracker_actor
+            .with_tracking(config.tracking.clone())
+            .with_suggestion_thresholds(config.suggestions.thresholds());
+        window_detector_health = window_detector_status(app_tracker_actor.window_backend());
+        let restart_app_tracker = app_tracker_actor.restarter();
+        let restart_timer = Arc::clone(&tracker_timer);
+        tokio::spawn(supervisor::supervise(
+            Subsystem::AppTracking,
+            health.clone(),
+            app_tracker_actor.run(),
+            move || {
+                if let Some(timer) = restart_timer.get().cloned() {
+                    tokio::spawn(async move {
+                        if let Err(error) = timer.resend_tracking().await {
+                            warn!(%error, "cannot announce the session to the restarted app tracker");
+                        }
+                    });
+                }
+                restart_app_tracker().run()
+            },
+            shutdown_sender.subscribe(),
+        ));
         Some(handle)
     } else {
         None
     };
+    let app_tracking_status = initialization_status(app_tracker_handle.is_some());
 
+    let mut digest_status = SubsystemStatus::Disabled;
     if let (Some(session_repo), Some(app_repo)) =
         (session_repository.clone(), app_tracking_repository)
     {
@@ -92,39 +279,133 @@ async fn main() -> Result<()> {
             config.distractions().clone(),
             session_repo,
             app_repo,
+            create_digest_delivery_gateways(&config.digest().delivery),
         );
-        let digest_shutdown = shutdown_sender.subscribe();
-        tokio::spawn(digest_scheduler.run(digest_shutdown));
+        if config.digest().enabled {
+            digest_status = SubsystemStatus::Running;
+            let restart_shutdown_sender = shutdown_sender.clone();
+            let restart_scheduler = digest_scheduler.clone();
+            tokio::spawn(supervisor::supervise(
+                Subsystem::Digest,
+                health.clone(),
+                digest_scheduler.run(shutdown_sender.subscribe()),
+                move || {
+                    restart_scheduler
+                        .clone()
+                        .run(restart_shutdown_sender.subscribe())
+                },
+                shutdown_sender.subscribe(),
+            ));
+        } else {
+            tokio::spawn(digest_scheduler.run(shutdown_sender.subscribe()));
+        }
     }
 
+    let do_not_disturb = if config.notifications().enable_dnd {
+        create_do_not_disturb().map(|backend| Arc::new(QueuedDoNotDisturb::spawn(backend)))
+    } else {
+        None
+    };
+
+    let power_status = if !config.battery.enabled {
+        SubsystemStatus::Disabled
+    } else if cfg!(target_os = "linux") {
+        SubsystemStatus::Running
+    } else {
+        SubsystemStatus::Failed {
+            reason: Some("UPower is only available on Linux".to_string()),
+        }
+    };
     #[cfg(target_os = "linux")]
-    let (timer_actor, timer_handle) = TimerActor::new(
-        Some(notifier_handle),
-        app_tracker_handle,
-        tray_state,
-        session_repository,
-    );
+    if config.battery.enabled {
+        tokio::spawn(power::watch(
+            config.battery.clone(),
+            config.tray.show_countdown,
+            power::PowerTargets {
+                app_tracker: app_tracker_handle.clone(),
+                notifier: notifier_handle.clone(),
+                tray: tray_state.clone(),
+                health: health.clone(),
+            },
+            shutdown_sender.subscribe(),
+        ));
+    }
 
-    #[cfg(not(target_os = "linux"))]
     let (timer_actor, timer_handle) = TimerActor::new(
-        Some(notifier_handle),
+        Some(notifier_handle.clone()),
         app_tracker_handle,
-        session_repository,
+        achievements_handle,
+        hooks_handle,
+        tray_state,
+        session_repository.clone(),
+        do_not_disturb
+            .clone()
+            .map(|queued| queued as Arc<dyn DoNotDisturb>),
     );
+    let _ = tracker_timer.set(timer_handle.clone());
     tokio::spawn(timer_actor.run());
 
+    if let Some(calendar_config) = config.calendar.clone() {
+        let gateway: Arc<dyn CalendarGateway> =
+            Arc::new(IcsCalendarGateway::new(calendar_config.url.clone()));
+        tokio::spawn(calendar::run(
+            gateway,
+            calendar_config,
+            timer_handle.clone(),
+            notifier_handle.clone(),
+            shutdown_sender.subscribe(),
+        ));
+    }
+
+    if let (Some(sync_config), Some(database)) = (config.sync.clone(), database.as_ref()) {
+        match sync_config.device_name() {
+            Some(device) => {
+                let gateway: Arc<dyn SyncGateway> =
+                    Arc::new(FolderSyncGateway::new(sync_config.folder.clone(), &device));
+                tokio::spawn(sync::run(
+                    gateway,
+                    create_sync_repository(database),
+                    usage_rollup_repository.clone(),
+                    sync_config.interval_minutes,
+                    shutdown_sender.subscribe(),
+                ));
+            }
+            None => warn!("session sync disabled: cannot tell the device name, set sync.device"),
+        }
+    }
+
     #[cfg(target_os = "linux")]
+    tokio::spawn(logout::watch(
+        timer_handle.clone(),
+        shutdown_sender.subscribe(),
+    ));
+
     if let Some(action_receiver) = tray_action_receiver {
         let tray_timer_handle = timer_handle.clone();
+        let tray_notifier_handle = notifier_handle.clone();
+        let tray_language = config.language();
         let tray_shutdown_sender = shutdown_sender.clone();
         let runtime_handle = tokio::runtime::Handle::current();
         std::thread::spawn(move || {
             while let Ok(action) = action_receiver.recv() {
                 match action {
+                    TrayAction::Start { duration, mode } => {
+                        let handle = tray_timer_handle.clone();
+                        runtime_handle.spawn(async move {
+                            let _ = handle
+                                .start(Some(duration), mode, Vec::new(), Vec::new(), None, false)
+                                .await;
+                        });
+                    }
                     TrayAction::Pause => {
                         let handle = tray_timer_handle.clone();
+                        let notifier = tray_notifier_handle.clone();
                         runtime_handle.spawn(async move {
-                            let _ = handle.pause().await;
+                            if let Some(InterruptOutcome::Refused { remaining }) =
+                                handle.pause(false, None).await
+                            {
+                                notify_strict_refusal(&notifier, remaining, tray_language);
+                            }
                         });
                     }
                     TrayAction::Resume => {
@@ -135,8 +416,13 @@ async fn main() -> Result<()> {
                     }
                     TrayAction::Stop => {
                         let handle = tray_timer_handle.clone();
+                        let notifier = tray_notifier_handle.clone();
                         runtime_handle.spawn(async move {
-                            let _ = handle.stop().await;
+                            if let Some(InterruptOutcome::Refused { remaining }) =
+                                handle.stop(false, None).await
+                            {
+                                notify_strict_refusal(&notifier, remaining, tray_language);
+                            }
                         });
                     }
                     TrayAction::OpenDashboard => {
@@ -158,75 +444,246 @@ async fn main() -> Result<()> {
         });
     }
 
-    let server = Server::new(timer_handle, shutdown_sender)?;
+    health.register(vec![
+        SubsystemHealth::new(Subsystem::Persistence, persistence_status.clone()),
+        SubsystemHealth::new(Subsystem::AppTracking, app_tracking_status),
+        SubsystemHealth::new(Subsystem::Tray, tray_status),
+        notifications_health,
+        window_detector_health,
+        SubsystemHealth::new(Subsystem::Digest, digest_status),
+        SubsystemHealth::new(Subsystem::Power, power_status),
+    ]);
+
+    let state = DaemonState {
+        subsystems: health,
+        config_warnings,
+        database_path: (persistence_status == SubsystemStatus::Running)
+            .then(database_path)
+            .flatten(),
+        started_at,
+        session_repository,
+        session_note_repository,
+        usage_rollup_repository,
+        jobs: JobRegistry::default(),
+    };
+
+    let server = Server::new(timer_handle, state, shutdown_sender)?;
     server.run(shutdown_receiver).await?;
 
+    if let Some(do_not_disturb) = do_not_disturb {
+        if let Err(error) = do_not_disturb.restore_and_wait().await {
+            warn!(%error, "failed to restore do-not-disturb on shutdown");
+        }
+    }
+
+    if let Err(error) = Heartbeat::remove() {
+        warn!(%error, "failed to remove heartbeat");
+    }
+
     info!("flux daemon stopped");
     std::process::exit(0);
 }
 
-fn create_session_repository() -> Option<Arc<dyn SessionRepository>> {
-    let data_dir = dirs::data_dir()?.join("flux");
+/// The tray cannot ask for the confirmation phrase, so it points to the CLI instead
+fn notify_strict_refusal(notifier: &NotifierHandle, remaining: Duration, language: Language) {
+    let translator = Translator::new(language);
+    notifier.send_alert(
+        translator.get("notification.strict_refused_title"),
+        translator.format(
+            "notification.strict_refused_body",
+            &[("minutes", &remaining.as_secs().div_ceil(60).to_string())],
+        ),
+    );
+}
 
-    if let Err(error) = std::fs::create_dir_all(&data_dir) {
-        warn!(%error, "failed to create data directory, sessions will not be persisted");
-        return None;
+fn window_detector_status(backend: Option<&'static str>) -> SubsystemHealth {
+    match backend {
+        Some(backend) => SubsystemHealth::new(Subsystem::WindowDetector, SubsystemStatus::Running)
+            .with_detail(backend),
+        None if cfg!(any(target_os = "linux", target_os = "macos", windows)) => {
+            SubsystemHealth::new(
+                Subsystem::WindowDetector,
+                SubsystemStatus::Failed { reason: None },
+            )
+        }
+        None => SubsystemHealth::new(Subsystem::WindowDetector, SubsystemStatus::Disabled),
     }
+}
 
-    let database_path = data_dir.join("sessions.db");
+fn collect_config_warnings(config: &Config, load_error: Option<String>) -> Vec<String> {
+    let translator = Translator::new(config.language());
+    let mut warnings = Vec::new();
 
-    match SqliteSessionRepository::new(&database_path) {
-        Ok(repository) => {
-            info!(?database_path, "session persistence enabled");
-            Some(Arc::new(repository))
-        }
-        Err(error) => {
-            warn!(%error, "failed to initialize session repository, sessions will not be persisted");
-            None
-        }
+    if let Some(error) = load_error {
+        warnings.push(translator.format("health.config_invalid", &[("error", &error)]));
     }
-}
 
-fn create_app_tracking_repository() -> Option<Arc<dyn AppTrackingRepository>> {
-    let data_dir = dirs::data_dir()?.join("flux");
+    let requested_profile = AppState::load().active_profile;
+    if config.active_profile_name() != requested_profile {
+        warnings.push(translator.format(
+            "health.config_profile_missing",
+            &[("profile", &requested_profile)],
+        ));
+    }
 
-    if let Err(error) = std::fs::create_dir_all(&data_dir) {
-        warn!(%error, "failed to create data directory, app tracking will not be persisted");
-        return None;
+    for (mode, value) in config.modes.invalid_colors() {
+        warnings.push(translator.format(
+            "health.config_invalid_mode_color",
+            &[("mode", mode), ("value", value)],
+        ));
     }
 
-    let database_path = data_dir.join("sessions.db");
+    for day in config.goals.invalid_rest_days() {
+        warnings.push(translator.format("health.config_invalid_rest_day", &[("day", day)]));
+    }
 
-    match SqliteAppTrackingRepository::new(&database_path) {
-        Ok(repository) => {
-            info!("app tracking enabled");
-            Some(Arc::new(repository))
-        }
-        Err(error) => {
-            warn!(%error, "failed to initialize app tracking repository");
+    warnings
+}
+
+fn initialization_status(initialized: bool) -> SubsystemStatus {
+    if initialized {
+        SubsystemStatus::Running
+    } else {
+        SubsystemStatus::Failed { reason: None }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn create_do_not_disturb() -> Option<Arc<dyn DoNotDisturb>> {
+    match dnd::DesktopDoNotDisturb::detect() {
+        Some(do_not_disturb) => Some(Arc::new(do_not_disturb)),
+        None => {
+            warn!("no supported do-not-disturb backend found (gnome, kde, dunst)");
             None
         }
     }
 }
 
-fn create_session_metrics_repository() -> Option<Arc<dyn SessionMetricsRepository>> {
+#[cfg(not(target_os = "linux"))]
+fn create_do_not_disturb() -> Option<Arc<dyn DoNotDisturb>> {
+    warn!("do-not-disturb integration is not supported on this platform");
+    None
+}
+
+fn create_digest_delivery_gateways(
+    config: &DigestDeliveryConfig,
+) -> Vec<Arc<dyn DigestDeliveryGateway>> {
+    let mut gateways: Vec<Arc<dyn DigestDeliveryGateway>> = Vec::new();
+
+    if let Some(smtp) = &config.smtp {
+        let password = if smtp.username.is_some() {
+            match resolve_smtp_password() {
+                Ok(password) => Some(password),
+                Err(error) => {
+                    warn!(%error, "no SMTP password found, sending without authentication");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        info!(host = %smtp.host, "digest email delivery enabled");
+        gateways.push(Arc::new(SmtpDigestGateway::new(smtp.clone(), password)));
+    }
+
+    if let Some(webhook) = &config.webhook {
+        info!("digest webhook delivery enabled");
+        gateways.push(Arc::new(WebhookDigestGateway::new(webhook.url.clone())));
+    }
+
+    gateways
+}
+
+fn database_path() -> Option<PathBuf> {
+    Some(dirs::data_dir()?.join("flux").join("sessions.db"))
+}
+
+/// One connection shared by every repository of the daemon
+fn open_database(privacy: &PrivacyConfig) -> Option<Database> {
     let data_dir = dirs::data_dir()?.join("flux");
 
     if let Err(error) = std::fs::create_dir_all(&data_dir) {
-        warn!(%error, "failed to create data directory, session metrics will not be persisted");
+        warn!(%error, "failed to create data directory, sessions will not be persisted");
         return None;
     }
 
     let database_path = data_dir.join("sessions.db");
 
-    match SqliteSessionMetricsRepository::new(&database_path) {
-        Ok(repository) => {
-            info!("session metrics enabled");
-            Some(Arc::new(repository))
+    match Database::open(&database_path).and_then(|database| database.with_privacy(privacy)) {
+        Ok(database) => {
+            // The clients only read and write titles the configured way; the
+            // daemon alone rewrites the ones stored the other way
+            if let Err(error) = database.convert_stored_titles() {
+                warn!(%error, "failed to convert the stored window titles");
+            }
+            info!(
+                ?database_path,
+                encrypted_titles = privacy.encrypt_window_titles,
+                "session persistence enabled"
+            );
+            Some(database)
         }
         Err(error) => {
-            warn!(%error, "failed to initialize session metrics repository");
+            warn!(%error, "failed to open the database, sessions will not be persisted");
             None
         }
     }
 }
+
+fn create_session_repository(database: &Database) -> Arc<dyn SessionRepository> {
+    Arc::new(SqliteSessionRepository::with_database(database.clone()))
+}
+
+fn recover_interrupted_session(
+    repository: &dyn SessionRepository,
+    previous_heartbeat: Option<&Heartbeat>,
+) {
+    let mut session = match repository.find_active() {
+        Ok(Some(session)) => session,
+        Ok(None) => return,
+        Err(error) => {
+            warn!(%error, "failed to look up interrupted session");
+            return;
+        }
+    };
+
+    let ended_at = previous_heartbeat
+        .map(|heartbeat| heartbeat.updated_at)
+        .unwrap_or(session.started_at);
+    session.end_with_reason(ended_at, SessionEndReason::Interrupted);
+
+    match repository.update(&session) {
+        Ok(()) => info!(
+            session_id = ?session.id,
+            duration_seconds = ?session.duration_seconds,
+            "closed session interrupted by previous daemon run"
+        ),
+        Err(error) => warn!(%error, "failed to close interrupted session"),
+    }
+}
+
+fn create_app_tracking_repository(database: &Database) -> Arc<dyn AppTrackingRepository> {
+    Arc::new(SqliteAppTrackingRepository::with_database(database.clone()))
+}
+
+fn create_session_metrics_repository(database: &Database) -> Arc<dyn SessionMetricsRepository> {
+    Arc::new(SqliteSessionMetricsRepository::with_database(
+        database.clone(),
+    ))
+}
+
+fn create_achievement_repository(database: &Database) -> Arc<dyn AchievementRepository> {
+    Arc::new(SqliteAchievementRepository::with_database(database.clone()))
+}
+
+fn create_session_note_repository(database: &Database) -> Arc<dyn SessionNoteRepository> {
+    Arc::new(SqliteSessionNoteRepository::with_database(database.clone()))
+}
+
+fn create_usage_rollup_repository(database: &Database) -> Arc<dyn UsageRollupRepository> {
+    Arc::new(SqliteAppTrackingRepository::with_database(database.clone()))
+}
+
+fn create_sync_repository(database: &Database) -> Arc<dyn SyncRepository> {
+    Arc::new(SqliteSyncRepository::with_database(database.clone()))
+}
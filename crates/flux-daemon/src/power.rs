@@ -0,0 +1,265 @@
+use std::path::Path;
+use std::process::Stdio;
+
+use flux_core::BatteryConfig;
+use flux_protocol::{Subsystem, SubsystemHealth, SubsystemStatus};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::actors::{AppTrackerHandle, NotifierHandle, TrayStateHandle, POLLING_INTERVAL_SECONDS};
+use crate::supervisor::HealthRegistry;
+
+const UPOWER_DESTINATION: &str = "org.freedesktop.UPower";
+const UPOWER_PATH: &str = "/org/freedesktop/UPower";
+const ON_BATTERY_PROPERTY: &str = "'OnBattery': <";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PowerSource {
+    Ac,
+    Battery,
+}
+
+impl PowerSource {
+    /// Reads `OnBattery` from a `gdbus call` reply or a `gdbus monitor` PropertiesChanged line
+    fn parse(line: &str) -> Option<Self> {
+        let value = match line.split_once(ON_BATTERY_PROPERTY) {
+            Some((_, value)) => value,
+            None => line.trim().strip_prefix("(<")?,
+        };
+
+        if value.starts_with("true") {
+            Some(PowerSource::Battery)
+        } else if value.starts_with("false") {
+            Some(PowerSource::Ac)
+        } else {
+            None
+        }
+    }
+}
+
+/// Tracking settings the daemon runs with for a power source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PowerSettings {
+    polling_interval_seconds: u64,
+    sound_muted: bool,
+    show_countdown: bool,
+}
+
+impl PowerSettings {
+    fn for_source(source: PowerSource, battery: &BatteryConfig, show_countdown: bool) -> Self {
+        match source {
+            PowerSource::Ac => Self {
+                polling_interval_seconds: POLLING_INTERVAL_SECONDS,
+                sound_muted: false,
+                show_countdown,
+            },
+            PowerSource::Battery => Self {
+                polling_interval_seconds: battery
+                    .polling_interval_seconds
+                    .max(POLLING_INTERVAL_SECONDS),
+                sound_muted: !battery.sound_enabled,
+                show_countdown: show_countdown && battery.tray_countdown,
+            },
+        }
+    }
+
+    fn describe(&self, source: PowerSource) -> String {
+        match source {
+            PowerSource::Ac => "on AC power".to_string(),
+            PowerSource::Battery => format!(
+                "on battery, polling every {}s",
+                self.polling_interval_seconds
+            ),
+        }
+    }
+}
+
+/// Actors whose behavior follows the power source
+pub struct PowerTargets {
+    pub app_tracker: Option<AppTrackerHandle>,
+    pub notifier: NotifierHandle,
+    pub tray: Option<TrayStateHandle>,
+    pub health: HealthRegistry,
+}
+
+impl PowerTargets {
+    fn apply(&self, source: PowerSource, settings: PowerSettings) {
+        info!(?source, ?settings, "power source changed");
+
+        if let Some(app_tracker) = &self.app_tracker {
+            app_tracker.send_polling_interval(settings.polling_interval_seconds);
+        }
+        self.notifier.set_sound_muted(settings.sound_muted);
+        if let Some(tray) = &self.tray {
+            tray.set_show_countdown(settings.show_countdown);
+        }
+        self.health.update(
+            SubsystemHealth::new(Subsystem::Power, SubsystemStatus::Running)
+                .with_detail(settings.describe(source)),
+        );
+    }
+
+    fn fail(&self, reason: &str) {
+        self.health.update(SubsystemHealth::new(
+            Subsystem::Power,
+            SubsystemStatus::Failed {
+                reason: Some(reason.to_string()),
+            },
+        ));
+    }
+}
+
+pub async fn watch(
+    battery: BatteryConfig,
+    show_countdown: bool,
+    targets: PowerTargets,
+    mut shutdown: broadcast::Receiver<()>,
+) {
+    let Ok(gdbus) = which::which("gdbus") else {
+        warn!("gdbus not found, battery profile disabled");
+        targets.fail("gdbus not found");
+        return;
+    };
+
+    let spawned = Command::new(&gdbus)
+        .args([
+            "monitor",
+            "--system",
+            "--dest",
+            UPOWER_DESTINATION,
+            "--object-path",
+            UPOWER_PATH,
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn();
+
+    let mut monitor = match spawned {
+        Ok(monitor) => monitor,
+        Err(error) => {
+            warn!(%error, "failed to monitor UPower, battery profile disabled");
+            targets.fail("UPower monitor failed to start");
+            return;
+        }
+    };
+    let Some(stdout) = monitor.stdout.take() else {
+        return;
+    };
+
+    let mut current = read_power_source(&gdbus).await;
+    match current {
+        Some(source) => targets.apply(
+            source,
+            PowerSettings::for_source(source, &battery, show_countdown),
+        ),
+        None => targets.fail("UPower did not report the power source"),
+    }
+
+    let mut lines = BufReader::new(stdout).lines();
+    info!("watching UPower for power source changes");
+
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let Ok(Some(line)) = line else {
+                    debug!("UPower monitor exited");
+                    break;
+                };
+                let Some(source) = PowerSource::parse(&line) else {
+                    continue;
+                };
+                if current != Some(source) {
+                    current = Some(source);
+                    targets.apply(
+                        source,
+                        PowerSettings::for_source(source, &battery, show_countdown),
+                    );
+                }
+            }
+            _ = shutdown.recv() => {
+                debug!("power watcher shutdown");
+                break;
+            }
+        }
+    }
+}
+
+async fn read_power_source(gdbus: &Path) -> Option<PowerSource> {
+    let output = Command::new(gdbus)
+        .args([
+            "call",
+            "--system",
+            "--dest",
+            UPOWER_DESTINATION,
+            "--object-path",
+            UPOWER_PATH,
+            "--method",
+            "org.freedesktop.DBus.Properties.Get",
+            UPOWER_DESTINATION,
+            "OnBattery",
+        ])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .await
+        .ok()?;
+
+    PowerSource::parse(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_upower_replies_and_signals() {
+        assert_eq!(
+            PowerSource::parse("(<true>,)\n"),
+            Some(PowerSource::Battery)
+        );
+        assert_eq!(PowerSource::parse("(<false>,)"), Some(PowerSource::Ac));
+        assert_eq!(
+            PowerSource::parse(
+                "/org/freedesktop/UPower: org.freedesktop.DBus.Properties.PropertiesChanged ('org.freedesktop.UPower', {'OnBattery': <true>}, @as [])"
+            ),
+            Some(PowerSource::Battery)
+        );
+        assert_eq!(
+            PowerSource::parse(
+                "/org/freedesktop/UPower: org.freedesktop.DBus.Properties.PropertiesChanged ('org.freedesktop.UPower', {'LidIsClosed': <true>}, @as [])"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn battery_profile_slows_polling_and_silences_sounds() {
+        let battery = BatteryConfig {
+            enabled: true,
+            polling_interval_seconds: 20,
+            sound_enabled: false,
+            tray_countdown: false,
+        };
+
+        assert_eq!(
+            PowerSettings::for_source(PowerSource::Battery, &battery, true),
+            PowerSettings {
+                polling_interval_seconds: 20,
+                sound_muted: true,
+                show_countdown: false,
+            }
+        );
+        assert_eq!(
+            PowerSettings::for_source(PowerSource::Ac, &battery, true),
+            PowerSettings {
+                polling_interval_seconds: POLLING_INTERVAL_SECONDS,
+                sound_muted: false,
+                show_countdown: true,
+            }
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDate, NaiveTime};
+use flux_core::UsageRollupRepository;
+use tokio::sync::broadcast;
+use tokio::time::{sleep, Duration};
+use tracing::{debug, info, warn};
+
+use crate::storage;
+
+/// Local time of the nightly refresh, shortly after midnight
+const NIGHTLY_REFRESH_MINUTE: u32 = 5;
+
+pub async fn run(
+    repository: Arc<dyn UsageRollupRepository>,
+    mut shutdown: broadcast::Receiver<()>,
+) {
+    let backfill_start =
+        match storage::blocking(&repository, |repository| repository.last_rollup_day()).await {
+            Ok(last_day) => last_day,
+            Err(error) => {
+                warn!(%error, "failed to read usage rollups, rebuilding them");
+                None
+            }
+        };
+    if backfill_start.is_none() {
+        info!("backfilling usage rollups from existing data");
+    }
+    refresh(&repository, backfill_start).await;
+
+    loop {
+        let delay = next_refresh_delay(Local::now());
+        debug!(
+            minutes = delay.as_secs() / 60,
+            "next usage rollup refresh scheduled"
+        );
+
+        tokio::select! {
+            _ = sleep(delay) => {
+                let yesterday = Local::now().date_naive() - ChronoDuration::days(1);
+                refresh(&repository, Some(yesterday)).await;
+            }
+            _ = shutdown.recv() => {
+                debug!("usage rollups shutdown");
+                break;
+            }
+        }
+    }
+}
+
+async fn refresh(repository: &Arc<dyn UsageRollupRepository>, since: Option<NaiveDate>) {
+    let repository = Arc::clone(repository);
+    match tokio::task::spawn_blocking(move || repository.refresh_rollups(since)).await {
+        Ok(Ok(rows)) => info!(rows, ?since, "usage rollups refreshed"),
+        Ok(Err(error)) => warn!(%error, "failed to refresh usage rollups"),
+        Err(error) => warn!(%error, "usage rollup refresh task failed"),
+    }
+}
+
+fn next_refresh_delay(now: DateTime<Local>) -> Duration {
+    let refresh_time = NaiveTime::from_hms_opt(0, NIGHTLY_REFRESH_MINUTE, 0).unwrap();
+    let tomorrow = now.date_naive() + ChronoDuration::days(1);
+
+    match tomorrow.and_time(refresh_time).and_local_timezone(Local) {
+        chrono::LocalResult::Single(target) => {
+            let delay = target.signed_duration_since(now);
+            Duration::from_secs(delay.num_seconds().max(0) as u64)
+        }
+        _ => Duration::from_secs(24 * 3600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn refresh_runs_shortly_after_the_next_midnight() {
+        let evening = Local.with_ymd_and_hms(2026, 10, 14, 23, 0, 0).unwrap();
+        let delay = next_refresh_delay(evening);
+        assert!(delay >= Duration::from_secs(60 * 60) && delay <= Duration::from_secs(66 * 60));
+
+        let just_after_midnight = Local.with_ymd_and_hms(2026, 10, 15, 0, 10, 0).unwrap();
+        assert!(next_refresh_delay(just_after_midnight) > Duration::from_secs(23 * 3600));
+    }
+}
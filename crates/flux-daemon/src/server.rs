@@ -1,50 +1,106 @@
-use crate::actors::TimerHandle;
-use anyhow::{Context, Result};
-use flux_core::{Config, Translator};
-use flux_protocol::{FocusMode, Request, Response};
+use crate::actors::{InterruptOutcome, StartOutcome, TimerHandle};
+use crate::jobs::JobRegistry;
+use crate::storage;
+use crate::supervisor::HealthRegistry;
+use anyhow::{bail, Context, Result};
+use flux_core::{
+    Config, SessionId, SessionNote, SessionNoteRepository, SessionRepository, Translator,
+    UsageRollupRepository,
+};
+use flux_protocol::{
+    codec, is_supported_protocol, socket, Feature, FocusMode, Request, Response, Subsystem,
+    SubsystemStatus, PROTOCOL_VERSION,
+};
 use interprocess::local_socket::{
     tokio::{prelude::*, Stream},
-    GenericFilePath, ListenerOptions,
+    ListenerOptions,
 };
-use std::path::PathBuf;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tracing::{debug, error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct DaemonState {
+    pub subsystems: HealthRegistry,
+    pub config_warnings: Vec<String>,
+    pub database_path: Option<PathBuf>,
+    pub started_at: Instant,
+    pub session_repository: Option<Arc<dyn SessionRepository>>,
+    pub session_note_repository: Option<Arc<dyn SessionNoteRepository>>,
+    pub usage_rollup_repository: Option<Arc<dyn UsageRollupRepository>>,
+    pub jobs: JobRegistry,
+}
+
+impl DaemonState {
+    fn is_running(&self, subsystem: Subsystem) -> bool {
+        self.subsystems.snapshot().iter().any(|health| {
+            health.subsystem == subsystem
+                && matches!(
+                    health.status,
+                    SubsystemStatus::Running | SubsystemStatus::Degraded { .. }
+                )
+        })
+    }
 
-const DEFAULT_DURATION_MINUTES: u64 = 25;
+    /// Optional features announced in the handshake; requests needing a
+    /// missing one are answered with `Response::Unsupported`
+    fn features(&self) -> Vec<Feature> {
+        let mut features = Vec::new();
+        if self.session_repository.is_some() {
+            features.push(Feature::History);
+        }
+        if self.is_running(Subsystem::AppTracking) {
+            features.push(Feature::AppTracking);
+        }
+        if self.is_running(Subsystem::Tray) {
+            features.push(Feature::Tray);
+        }
+        features
+    }
+}
 
 pub struct Server {
     socket_path: PathBuf,
     timer_handle: TimerHandle,
+    state: Arc<DaemonState>,
     shutdown_sender: tokio::sync::broadcast::Sender<()>,
 }
 
 impl Server {
     pub fn new(
         timer_handle: TimerHandle,
+        state: DaemonState,
         shutdown_sender: tokio::sync::broadcast::Sender<()>,
     ) -> Result<Self> {
-        let socket_path = Self::default_socket_path();
+        let socket_path = socket::socket_path();
         Ok(Self {
             socket_path,
             timer_handle,
+            state: Arc::new(state),
             shutdown_sender,
         })
     }
 
     #[cfg(unix)]
-    fn default_socket_path() -> PathBuf {
-        let uid = unsafe { libc::getuid() };
-        PathBuf::from(format!("/run/user/{}/flux.sock", uid))
-    }
-
-    #[cfg(windows)]
-    fn default_socket_path() -> PathBuf {
-        let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_else(|_| ".".to_string());
-        PathBuf::from(format!(r"{}\flux\flux.sock", local_app_data))
-    }
+    fn prepare_socket(&self) -> Result<()> {
+        use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+        if let Some(directory) = self.socket_path.parent() {
+            if !directory.as_os_str().is_empty() && !directory.exists() {
+                std::fs::DirBuilder::new()
+                    .recursive(true)
+                    .mode(0o700)
+                    .create(directory)
+                    .context("impossible de créer le répertoire du socket")?;
+                std::fs::set_permissions(directory, std::fs::Permissions::from_mode(0o700))
+                    .context("impossible de restreindre les droits du répertoire du socket")?;
+                debug!(path = %directory.display(), "created socket directory");
+            }
+        }
+        socket::check_socket_directory(&self.socket_path)
+            .context("refus d'utiliser le répertoire du socket")?;
 
-    fn cleanup_stale_socket(&self) -> Result<()> {
         if self.socket_path.exists() {
             std::fs::remove_file(&self.socket_path)
                 .context("impossible de supprimer l'ancien socket")?;
@@ -53,17 +109,43 @@ impl Server {
         Ok(())
     }
 
+    #[cfg(windows)]
+    fn prepare_socket(&self) -> Result<()> {
+        if socket::is_listening(&self.socket_path) {
+            bail!(
+                "un autre daemon écoute déjà sur {}",
+                self.socket_path.display()
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    fn restrict_socket_permissions(&self) -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&self.socket_path, std::fs::Permissions::from_mode(0o600))
+            .context("impossible de restreindre les droits du socket")
+    }
+
+    #[cfg(windows)]
+    fn restrict_socket_permissions(&self) -> Result<()> {
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     pub async fn run(&self, mut shutdown: tokio::sync::broadcast::Receiver<()>) -> Result<()> {
-        self.cleanup_stale_socket()?;
-
-        let listener = ListenerOptions::new()
-            .name(
-                self.socket_path
-                    .as_os_str()
-                    .to_fs_name::<GenericFilePath>()?,
+        self.prepare_socket()?;
+
+        let options = ListenerOptions::new().name(socket::socket_name(&self.socket_path)?);
+        #[cfg(windows)]
+        let options = {
+            use interprocess::os::windows::local_socket::ListenerOptionsExt;
+            options.security_descriptor(
+                current_user_only().context("impossible de restreindre l'accès au pipe")?,
             )
-            .create_tokio()?;
+        };
+        let listener = options.create_tokio()?;
+        self.restrict_socket_permissions()?;
 
         info!(path = %self.socket_path.display(), "server listening");
 
@@ -72,10 +154,15 @@ impl Server {
                 accept_result = listener.accept() => {
                     match accept_result {
                         Ok(stream) => {
+                            if !is_same_user(&stream) {
+                                continue;
+                            }
                             let timer_handle = self.timer_handle.clone();
+                            let state = self.state.clone();
+                            let socket_path = self.socket_path.clone();
                             let shutdown_sender = self.shutdown_sender.clone();
                             tokio::spawn(async move {
-                                if let Err(error) = handle_connection(stream, timer_handle, shutdown_sender).await {
+                                if let Err(error) = handle_connection(stream, timer_handle, state, socket_path, shutdown_sender).await {
                                     error!(%error, "connection handler failed");
                                 }
                             });
@@ -96,6 +183,7 @@ impl Server {
         Ok(())
     }
 
+    #[cfg(unix)]
     fn cleanup_socket(&self) {
         if let Err(error) = std::fs::remove_file(&self.socket_path) {
             debug!(%error, "socket file already removed");
@@ -103,6 +191,9 @@ impl Server {
             debug!("socket file cleaned up");
         }
     }
+
+    #[cfg(windows)]
+    fn cleanup_socket(&self) {}
 }
 
 impl Drop for Server {
@@ -111,9 +202,88 @@ impl Drop for Server {
     }
 }
 
+#[cfg(unix)]
+fn is_same_user(stream: &Stream) -> bool {
+    let expected = socket::current_user_id();
+    match stream.peer_creds().map(|credentials| credentials.euid()) {
+        Ok(Some(user_id)) if user_id == expected => true,
+        Ok(user_id) => {
+            warn!(?user_id, expected, "rejected connection from another user");
+            false
+        }
+        Err(error) => {
+            warn!(%error, "rejected connection without peer credentials");
+            false
+        }
+    }
+}
+
+/// The pipe's DACL already keeps other users from opening it
+#[cfg(windows)]
+fn is_same_user(_stream: &Stream) -> bool {
+    true
+}
+
+/// Security descriptor of a pipe only the user running the daemon can open
+#[cfg(windows)]
+fn current_user_only() -> Result<interprocess::os::windows::security_descriptor::SecurityDescriptor>
+{
+    use interprocess::os::windows::security_descriptor::SecurityDescriptor;
+    use widestring::U16CString;
+
+    let descriptor = U16CString::from_str(format!("D:P(A;;GA;;;{})", current_user_sid()?))?;
+    Ok(SecurityDescriptor::deserialize(&descriptor)?)
+}
+
+/// SID of the user running the daemon, as `S-1-5-21-...`
+#[cfg(windows)]
+fn current_user_sid() -> Result<String> {
+    use windows_sys::Win32::Foundation::{CloseHandle, LocalFree, HANDLE};
+    use windows_sys::Win32::Security::Authorization::ConvertSidToStringSidW;
+    use windows_sys::Win32::Security::{GetTokenInformation, TokenUser, TOKEN_QUERY, TOKEN_USER};
+    use windows_sys::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+    // SAFETY: the token is closed once read, TOKEN_USER is read from a buffer
+    // of the size the system asked for, aligned for it, and the SID string
+    // is copied before being freed
+    unsafe {
+        let mut token: HANDLE = std::ptr::null_mut();
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) == 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let mut length = 0u32;
+        GetTokenInformation(token, TokenUser, std::ptr::null_mut(), 0, &mut length);
+        let mut buffer = vec![0u64; (length as usize).div_ceil(8)];
+        let read = GetTokenInformation(
+            token,
+            TokenUser,
+            buffer.as_mut_ptr().cast(),
+            length,
+            &mut length,
+        );
+        let read_error = std::io::Error::last_os_error();
+        CloseHandle(token);
+        if read == 0 {
+            return Err(read_error.into());
+        }
+
+        let user = &*(buffer.as_ptr() as *const TOKEN_USER);
+        let mut sid: *mut u16 = std::ptr::null_mut();
+        if ConvertSidToStringSidW(user.User.Sid, &mut sid) == 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let length = (0..).take_while(|&index| *sid.add(index) != 0).count();
+        let text = String::from_utf16_lossy(std::slice::from_raw_parts(sid, length));
+        LocalFree(sid.cast());
+        Ok(text)
+    }
+}
+
 async fn handle_connection(
     mut stream: Stream,
     timer_handle: TimerHandle,
+    state: Arc<DaemonState>,
+    socket_path: PathBuf,
     shutdown_sender: tokio::sync::broadcast::Sender<()>,
 ) -> Result<()> {
     debug!("new connection accepted");
@@ -125,20 +295,51 @@ async fn handle_connection(
     let mut payload = vec![0u8; length];
     stream.read_exact(&mut payload).await?;
 
-    let request: Request =
-        bincode::deserialize(&payload).context("failed to deserialize request")?;
+    if codec::is_legacy_frame(&payload) {
+        let message = get_translator().get("error.protocol_unreadable");
+        write_frame(&mut stream, &codec::encode_legacy_error(&message)).await?;
+        bail!("rejected request from a client using the legacy protocol");
+    }
+
+    let request: Request = match codec::decode(&payload) {
+        Ok(request) => request,
+        Err(error) => {
+            write_response(
+                &mut stream,
+                &Response::Error {
+                    message: get_translator().get("error.protocol_unreadable"),
+                },
+            )
+            .await?;
+            return Err(error).context("failed to deserialize request");
+        }
+    };
 
     debug!(?request, "received request");
 
-    let response = handle_request(request, &timer_handle, &shutdown_sender).await;
+    let response = handle_request(
+        request,
+        &timer_handle,
+        &state,
+        &socket_path,
+        &shutdown_sender,
+    )
+    .await;
 
     debug!(?response, "sending response");
 
-    let response_bytes = bincode::serialize(&response)?;
-    let response_length = (response_bytes.len() as u32).to_le_bytes();
+    write_response(&mut stream, &response).await
+}
+
+async fn write_response(stream: &mut Stream, response: &Response) -> Result<()> {
+    write_frame(stream, &codec::encode(response)?).await
+}
+
+async fn write_frame(stream: &mut Stream, payload: &[u8]) -> Result<()> {
+    let length = (payload.len() as u32).to_le_bytes();
 
-    stream.write_all(&response_length).await?;
-    stream.write_all(&response_bytes).await?;
+    stream.write_all(&length).await?;
+    stream.write_all(payload).await?;
     stream.flush().await?;
 
     Ok(())
@@ -146,13 +347,15 @@ async fn handle_connection(
 
 fn get_translator() -> Translator {
     Config::load()
-        .map(|config| Translator::new(config.general.language))
+        .map(|config| Translator::new(config.language()))
         .unwrap_or_default()
 }
 
 async fn handle_request(
     request: Request,
     timer_handle: &TimerHandle,
+    state: &DaemonState,
+    socket_path: &Path,
     shutdown_sender: &tokio::sync::broadcast::Sender<()>,
 ) -> Response {
     let translator = get_translator();
@@ -160,6 +363,44 @@ async fn handle_request(
     match request {
         Request::Ping => Response::Pong,
 
+        Request::GetHealth => Response::Health {
+            subsystems: state.subsystems.snapshot(),
+            config_warnings: state.config_warnings.clone(),
+        },
+
+        Request::Handshake {
+            protocol_version,
+            client_version,
+        } => {
+            let accepted = is_supported_protocol(protocol_version);
+            if !accepted {
+                warn!(
+                    protocol_version,
+                    %client_version,
+                    "rejected client with unsupported protocol version"
+                );
+            }
+            Response::Handshake {
+                protocol_version: PROTOCOL_VERSION,
+                daemon_version: env!("CARGO_PKG_VERSION").to_string(),
+                accepted,
+                features: state.features(),
+            }
+        }
+
+        Request::GetDaemonInfo => Response::DaemonInfo {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            pid: std::process::id(),
+            uptime_seconds: state.started_at.elapsed().as_secs(),
+            socket_path: socket_path.display().to_string(),
+            database_path: state
+                .database_path
+                .as_ref()
+                .map(|path| path.display().to_string()),
+            tray_active: state.is_running(Subsystem::Tray),
+            tracker_active: state.is_running(Subsystem::AppTracking),
+        },
+
         Request::Shutdown => {
             info!("shutdown request received via IPC");
             shutdown_sender.send(()).ok();
@@ -173,6 +414,8 @@ async fn handle_request(
                     remaining_seconds: status.remaining.as_secs(),
                     mode: status.mode,
                     paused: status.paused,
+                    preparing_seconds: status.preparing.map(|preparing| preparing.as_secs()),
+                    strict: status.strict,
                 }
             } else {
                 Response::Error {
@@ -181,40 +424,104 @@ async fn handle_request(
             }
         }
 
-        Request::StartSession { duration, mode } => {
-            let duration_minutes = duration.unwrap_or(DEFAULT_DURATION_MINUTES);
+        Request::StartSession {
+            duration,
+            mode,
+            tags,
+            request_id,
+            strict,
+            distraction_apps,
+        } => {
+            let duration = duration.map(|minutes| Duration::from_secs(minutes * 60));
             let focus_mode = mode.unwrap_or(FocusMode::AiAssisted);
 
-            if timer_handle
-                .start(Duration::from_secs(duration_minutes * 60), focus_mode)
+            match timer_handle
+                .start(
+                    duration,
+                    focus_mode,
+                    tags,
+                    distraction_apps,
+                    request_id,
+                    strict,
+                )
                 .await
-                .is_ok()
             {
-                Response::Ok
-            } else {
-                Response::Error {
+                Some(StartOutcome::Started | StartOutcome::AlreadyStarted) => Response::Ok,
+                Some(StartOutcome::AlreadyActive(status)) => Response::AlreadyActive {
+                    mode: status.mode.unwrap_or(FocusMode::AiAssisted),
+                    remaining_seconds: status.remaining.as_secs(),
+                },
+                None => Response::Error {
                     message: translator.get("error.unable_to_start_session"),
+                },
+            }
+        }
+
+        Request::AddNote { session_id, text } => {
+            match (&state.session_repository, &state.session_note_repository) {
+                (Some(sessions), Some(notes)) => {
+                    match add_note(sessions, notes, session_id, &text, &translator).await {
+                        Ok(session_id) => Response::NoteAdded { session_id },
+                        Err(message) => Response::Error { message },
+                    }
                 }
+                _ => Response::Unsupported {
+                    feature: Feature::History,
+                },
             }
         }
 
-        Request::StopSession => {
-            if timer_handle.stop().await.is_ok() {
-                Response::Ok
-            } else {
-                Response::Error {
+        Request::RefreshRollups => match state.usage_rollup_repository.clone() {
+            Some(repository) => Response::Accepted {
+                job_id: state.jobs.spawn(
+                    "refresh_rollups",
+                    refresh_rollups(repository, translator.clone()),
+                ),
+            },
+            None => Response::Unsupported {
+                feature: Feature::History,
+            },
+        },
+
+        Request::GetJobStatus { job_id } => match state.jobs.status(job_id) {
+            Some(job_state) => Response::JobStatus {
+                job_id,
+                state: job_state,
+            },
+            None => job_not_found(job_id, &translator),
+        },
+
+        Request::CancelJob { job_id } => match state.jobs.cancel(job_id) {
+            Some(job_state) => Response::JobStatus {
+                job_id,
+                state: job_state,
+            },
+            None => job_not_found(job_id, &translator),
+        },
+
+        Request::StopSession {
+            confirmation,
+            reason,
+        } => {
+            let confirmed = is_strict_confirmation(confirmation.as_deref(), &translator);
+            match timer_handle.stop(confirmed, reason).await {
+                Some(outcome) => interrupt_response(outcome, &translator),
+                None => Response::Error {
                     message: translator.get("error.unable_to_stop_session"),
-                }
+                },
             }
         }
 
-        Request::PauseSession => {
-            if timer_handle.pause().await.is_ok() {
-                Response::Ok
-            } else {
-                Response::Error {
+        Request::PauseSession {
+            confirmation,
+            reason,
+        } => {
+            let confirmed = is_strict_confirmation(confirmation.as_deref(), &translator);
+            match timer_handle.pause(confirmed, reason).await {
+                Some(outcome) => interrupt_response(outcome, &translator),
+                None => Response::Error {
                     message: translator.get("error.unable_to_pause_session"),
-                }
+                },
             }
         }
 
@@ -229,3 +536,319 @@ async fn handle_request(
         }
     }
 }
+
+/// The phrase is compared loosely so a stray capital or trailing space does not count as a refusal
+fn is_strict_confirmation(confirmation: Option<&str>, translator: &Translator) -> bool {
+    let phrase = translator.get("command.strict_confirmation_phrase");
+    confirmation.is_some_and(|confirmation| confirmation.trim().eq_ignore_ascii_case(&phrase))
+}
+
+fn interrupt_response(outcome: InterruptOutcome, translator: &Translator) -> Response {
+    match outcome {
+        InterruptOutcome::Done => Response::Ok,
+        InterruptOutcome::Refused { remaining } => Response::StrictSession {
+            remaining_seconds: remaining.as_secs(),
+            confirmation_phrase: translator.get("command.strict_confirmation_phrase"),
+        },
+    }
+}
+
+/// Saves the note on the given session, or on the most recent one
+async fn add_note(
+    sessions: &Arc<dyn SessionRepository>,
+    notes: &Arc<dyn SessionNoteRepository>,
+    session_id: Option<SessionId>,
+    text: &str,
+    translator: &Translator,
+) -> Result<SessionId, String> {
+    let session_id = storage::blocking(sessions, move |sessions| match session_id {
+        Some(id) => sessions.find_by_id(id).ok().and_then(|session| session.id),
+        None => sessions
+            .find_latest()
+            .ok()
+            .flatten()
+            .and_then(|session| session.id),
+    })
+    .await
+    .ok_or_else(|| translator.get("error.note_session_not_found"))?;
+
+    let note =
+        SessionNote::new(session_id, text).ok_or_else(|| translator.get("error.note_empty"))?;
+
+    storage::blocking(notes, move |notes| notes.save(&note))
+        .await
+        .map_err(|error| {
+            error!(%error, "failed to save session note");
+            translator.format("error.note_save_failed", &[("error", &error.to_string())])
+        })?;
+
+    info!(session_id, "session note saved");
+    Ok(session_id)
+}
+
+async fn refresh_rollups(
+    repository: Arc<dyn UsageRollupRepository>,
+    translator: Translator,
+) -> Result<Option<String>, String> {
+    let rows = tokio::task::spawn_blocking(move || repository.refresh_rollups(None))
+        .await
+        .map_err(|error| error.to_string())?
+        .map_err(|error| error.to_string())?;
+
+    Ok(Some(translator.format(
+        "command.rollups_refreshed",
+        &[("rows", &rows.to_string())],
+    )))
+}
+
+fn job_not_found(job_id: u64, translator: &Translator) -> Response {
+    Response::Error {
+        message: translator.format("error.job_not_found", &[("id", &job_id.to_string())]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::TimerActor;
+    use flux_core::Language;
+    use flux_protocol::JobState;
+
+    fn create_timer() -> TimerHandle {
+        let (actor, handle) = TimerActor::new(None, None, None, None, None, None, None);
+        tokio::spawn(actor.run());
+        handle
+    }
+
+    fn create_state() -> DaemonState {
+        DaemonState {
+            subsystems: HealthRegistry::default(),
+            config_warnings: Vec::new(),
+            database_path: None,
+            started_at: Instant::now(),
+            session_repository: None,
+            session_note_repository: None,
+            usage_rollup_repository: None,
+            jobs: JobRegistry::default(),
+        }
+    }
+
+    fn start_request(mode: FocusMode, request_id: &str) -> Request {
+        Request::StartSession {
+            duration: Some(25),
+            mode: Some(mode),
+            tags: Vec::new(),
+            request_id: Some(request_id.to_string()),
+            strict: false,
+            distraction_apps: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn strict_confirmation_ignores_case_and_surrounding_spaces() {
+        let translator = Translator::new(Language::En);
+        let phrase = translator.get("command.strict_confirmation_phrase");
+
+        assert!(is_strict_confirmation(
+            Some(&format!("  {} ", phrase.to_uppercase())),
+            &translator
+        ));
+        assert!(!is_strict_confirmation(Some("stop"), &translator));
+        assert!(!is_strict_confirmation(None, &translator));
+    }
+
+    #[tokio::test]
+    async fn concurrent_starts_from_two_clients_keep_the_first_session() {
+        let timer_handle = create_timer();
+        let state = create_state();
+        let (shutdown_sender, _) = tokio::sync::broadcast::channel(1);
+        let socket_path = PathBuf::from("/tmp/flux-test.sock");
+
+        let (gui_response, cli_response) = tokio::join!(
+            handle_request(
+                start_request(FocusMode::Review, "gui-1"),
+                &timer_handle,
+                &state,
+                &socket_path,
+                &shutdown_sender,
+            ),
+            handle_request(
+                start_request(FocusMode::Architecture, "cli-1"),
+                &timer_handle,
+                &state,
+                &socket_path,
+                &shutdown_sender,
+            ),
+        );
+
+        let responses = [gui_response, cli_response];
+        let started = responses
+            .iter()
+            .filter(|response| **response == Response::Ok)
+            .count();
+        let rejected: Vec<_> = responses
+            .iter()
+            .filter_map(|response| match response {
+                Response::AlreadyActive { mode, .. } => Some(mode.clone()),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(started, 1);
+        assert_eq!(rejected.len(), 1);
+        let status = timer_handle.get_status().await.unwrap();
+        assert_eq!(status.mode, Some(rejected[0].clone()));
+    }
+
+    #[tokio::test]
+    async fn retried_start_request_is_acknowledged_once() {
+        let timer_handle = create_timer();
+        let state = create_state();
+        let (shutdown_sender, _) = tokio::sync::broadcast::channel(1);
+        let socket_path = PathBuf::from("/tmp/flux-test.sock");
+
+        for _ in 0..2 {
+            let response = handle_request(
+                start_request(FocusMode::Review, "cli-retry"),
+                &timer_handle,
+                &state,
+                &socket_path,
+                &shutdown_sender,
+            )
+            .await;
+            assert_eq!(response, Response::Ok);
+        }
+
+        let other_client = handle_request(
+            start_request(FocusMode::Review, "gui-2"),
+            &timer_handle,
+            &state,
+            &socket_path,
+            &shutdown_sender,
+        )
+        .await;
+        assert!(matches!(other_client, Response::AlreadyActive { .. }));
+    }
+
+    #[tokio::test]
+    async fn note_without_session_goes_to_the_latest_session() {
+        let timer_handle = create_timer();
+        let sessions: Arc<dyn SessionRepository> =
+            Arc::new(flux_adapters::SqliteSessionRepository::in_memory().unwrap());
+        let notes = Arc::new(flux_adapters::SqliteSessionNoteRepository::in_memory().unwrap());
+        let mut session = flux_core::Session::start(FocusMode::Review);
+        session.end();
+        let session_id = sessions.save(&mut session).unwrap();
+        let state = DaemonState {
+            session_repository: Some(sessions),
+            session_note_repository: Some(notes.clone()),
+            ..create_state()
+        };
+        let (shutdown_sender, _) = tokio::sync::broadcast::channel(1);
+        let socket_path = PathBuf::from("/tmp/flux-test.sock");
+
+        let response = handle_request(
+            Request::AddNote {
+                session_id: None,
+                text: "fixed the flaky test".to_string(),
+            },
+            &timer_handle,
+            &state,
+            &socket_path,
+            &shutdown_sender,
+        )
+        .await;
+
+        assert_eq!(response, Response::NoteAdded { session_id });
+        let saved = notes.find_by_sessions(&[session_id]).unwrap();
+        assert_eq!(saved[0].text, "fixed the flaky test");
+    }
+
+    #[tokio::test]
+    async fn handshake_announces_what_the_daemon_lacks_and_requests_get_a_typed_refusal() {
+        let timer_handle = create_timer();
+        let state = create_state();
+        let (shutdown_sender, _) = tokio::sync::broadcast::channel(1);
+        let socket_path = PathBuf::from("/tmp/flux-test.sock");
+        let send = |request| {
+            handle_request(
+                request,
+                &timer_handle,
+                &state,
+                &socket_path,
+                &shutdown_sender,
+            )
+        };
+
+        let handshake = send(Request::Handshake {
+            protocol_version: PROTOCOL_VERSION,
+            client_version: "0.2.13".to_string(),
+        })
+        .await;
+        let note = send(Request::AddNote {
+            session_id: None,
+            text: "fixed the flaky test".to_string(),
+        })
+        .await;
+
+        assert!(matches!(handshake, Response::Handshake { features, .. } if features.is_empty()));
+        assert_eq!(
+            note,
+            Response::Unsupported {
+                feature: Feature::History
+            }
+        );
+        assert_eq!(send(Request::RefreshRollups).await, note);
+    }
+
+    #[tokio::test]
+    async fn rollup_refresh_runs_as_a_job_polled_by_id() {
+        let timer_handle = create_timer();
+        let repository = Arc::new(flux_adapters::SqliteAppTrackingRepository::in_memory().unwrap());
+        let state = DaemonState {
+            usage_rollup_repository: Some(repository),
+            ..create_state()
+        };
+        let (shutdown_sender, _) = tokio::sync::broadcast::channel(1);
+        let socket_path = PathBuf::from("/tmp/flux-test.sock");
+        let send = |request| {
+            handle_request(
+                request,
+                &timer_handle,
+                &state,
+                &socket_path,
+                &shutdown_sender,
+            )
+        };
+
+        let Response::Accepted { job_id } = send(Request::RefreshRollups).await else {
+            panic!("refresh was not accepted");
+        };
+        let mut status = send(Request::GetJobStatus { job_id }).await;
+        for _ in 0..100 {
+            if !matches!(
+                status,
+                Response::JobStatus {
+                    state: JobState::Running,
+                    ..
+                }
+            ) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            status = send(Request::GetJobStatus { job_id }).await;
+        }
+
+        assert!(matches!(
+            status,
+            Response::JobStatus {
+                state: JobState::Completed { output: Some(_) },
+                ..
+            }
+        ));
+        assert!(matches!(
+            send(Request::CancelJob { job_id: job_id + 1 }).await,
+            Response::Error { .. }
+        ));
+    }
+}
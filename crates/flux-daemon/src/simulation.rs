@@ -0,0 +1,635 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use flux_adapters::Database;
+use flux_core::{Config, FocusMode, SessionEndReason, TrackingConfig, Translator};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tracing_subscriber::EnvFilter;
+
+use crate::actors::{
+    AppTrackerActor, CheckInResponse, FrictionResponse, NotifierHandle, NotifierMessage,
+    TimerActor, TimerHandle,
+};
+use crate::storage;
+use crate::window::{WindowDetector, WindowInfo};
+
+/// Simulated time after which a session that never ends is stopped
+const SIMULATION_LIMIT: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A session replayed by `flux-daemon --simulate <script.toml>`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Script {
+    #[serde(default)]
+    session: ScriptedSession,
+    #[serde(default)]
+    answers: ScriptedAnswers,
+    #[serde(default, rename = "event")]
+    events: Vec<ScriptedEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScriptedSession {
+    #[serde(default = "default_mode")]
+    mode: String,
+    /// The mode's configured duration when absent
+    duration_minutes: Option<u64>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl Default for ScriptedSession {
+    fn default() -> Self {
+        Self {
+            mode: default_mode(),
+            duration_minutes: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+fn default_mode() -> String {
+    FocusMode::AiAssisted.as_str().to_string()
+}
+
+/// What the simulated user clicks on the notifications that ask something
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScriptedAnswers {
+    #[serde(default = "default_check_in")]
+    check_in: CheckInResponse,
+    #[serde(default = "default_friction")]
+    friction: FrictionResponse,
+}
+
+impl Default for ScriptedAnswers {
+    fn default() -> Self {
+        Self {
+            check_in: default_check_in(),
+            friction: default_friction(),
+        }
+    }
+}
+
+fn default_check_in() -> CheckInResponse {
+    CheckInResponse::Focused
+}
+
+fn default_friction() -> FrictionResponse {
+    FrictionResponse::Continue
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "RawEvent")]
+struct ScriptedEvent {
+    /// Offset from the start request, preparation included
+    at: Duration,
+    action: ScriptedAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ScriptedAction {
+    Focus(WindowInfo),
+    Command(ScriptedCommand),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ScriptedCommand {
+    Pause,
+    Resume,
+    Stop,
+}
+
+impl ScriptedCommand {
+    fn as_str(self) -> &'static str {
+        match self {
+            ScriptedCommand::Pause => "pause",
+            ScriptedCommand::Resume => "resume",
+            ScriptedCommand::Stop => "stop",
+        }
+    }
+}
+
+/// `[[event]]` as written: either a window (`app`, optional `title`) or a `command`
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawEvent {
+    at: String,
+    app: Option<String>,
+    title: Option<String>,
+    command: Option<ScriptedCommand>,
+}
+
+impl TryFrom<RawEvent> for ScriptedEvent {
+    type Error = String;
+
+    fn try_from(event: RawEvent) -> Result<Self, Self::Error> {
+        let at = parse_offset(&event.at).ok_or_else(|| {
+            format!(
+                "invalid offset \"{}\" (expected e.g. 90s, 2m or 1m30s)",
+                event.at
+            )
+        })?;
+
+        let action = match (event.app, event.title, event.command) {
+            (Some(app), title, None) => ScriptedAction::Focus(WindowInfo::new(app, title)),
+            (None, None, Some(command)) => ScriptedAction::Command(command),
+            _ => {
+                return Err(format!(
+                    "event at {} needs either `app` (with an optional `title`) or `command`",
+                    event.at
+                ))
+            }
+        };
+
+        Ok(Self { at, action })
+    }
+}
+
+/// Reads offsets such as `90s`, `2m` or `1h5m30s`
+fn parse_offset(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    let mut seconds = 0;
+    let mut digits = String::new();
+    for character in value.chars() {
+        if character.is_ascii_digit() {
+            digits.push(character);
+            continue;
+        }
+        let unit = match character {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds += digits.parse::<u64>().ok()? * unit;
+        digits.clear();
+    }
+
+    digits.is_empty().then(|| Duration::from_secs(seconds))
+}
+
+fn format_offset(offset: Duration) -> String {
+    let seconds = offset.as_secs();
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Reads `--simulate <script.toml>` from the daemon's arguments
+pub fn script_argument(mut arguments: impl Iterator<Item = String>) -> Result<Option<PathBuf>> {
+    while let Some(argument) = arguments.next() {
+        if argument == "--simulate" {
+            return arguments
+                .next()
+                .map(|path| Some(PathBuf::from(path)))
+                .ok_or_else(|| anyhow!("--simulate expects the path of a script"));
+        }
+    }
+    Ok(None)
+}
+
+/// The window the script last focused
+struct ScriptedWindowDetector {
+    window: Arc<Mutex<Option<WindowInfo>>>,
+}
+
+impl WindowDetector for ScriptedWindowDetector {
+    fn get_active_window_info(&self, tracking: &TrackingConfig) -> Option<WindowInfo> {
+        let window = self.window.lock().unwrap().clone()?;
+        if tracking.tracks_title(&window.app_name) {
+            Some(window)
+        } else {
+            Some(WindowInfo::new(window.app_name, None))
+        }
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "script"
+    }
+}
+
+/// Replays the script against the timer and the app tracker on a paused
+/// clock, which jumps ahead whenever every actor is idle: a 50 minute session
+/// takes a fraction of a second. Notifications are printed instead of shown
+/// and nothing is written to the user's database.
+pub fn run(path: &Path) -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+
+    let content =
+        std::fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))?;
+    let mut script: Script =
+        toml::from_str(&content).with_context(|| format!("invalid script {}", path.display()))?;
+    script.events.sort_by_key(|event| event.at);
+    let config = Config::load().context("cannot load the configuration")?;
+
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()?
+        .block_on(simulate(script, config))
+}
+
+async fn simulate(script: Script, config: Config) -> Result<()> {
+    let translator = Translator::new(config.language());
+    let database = Database::in_memory()?;
+    let session_repository = crate::create_session_repository(&database);
+    let metrics_repository = crate::create_session_metrics_repository(&database);
+    let window = Arc::new(Mutex::new(None));
+
+    let (notifier_handle, notifications) = NotifierHandle::detached();
+    let (app_tracker_actor, app_tracker_handle) = AppTrackerActor::new(
+        crate::create_app_tracking_repository(&database),
+        Arc::clone(&metrics_repository),
+        config.distractions().clone(),
+        config.privacy.clone(),
+        notifier_handle.clone(),
+        None,
+        false,
+    );
+    let app_tracker_actor = app_tracker_actor
+        .with_tracking(config.tracking.clone())
+        .with_suggestion_thresholds(config.suggestions.thresholds())
+        .simulated(Box::new(ScriptedWindowDetector {
+            window: Arc::clone(&window),
+        }));
+    tokio::spawn(app_tracker_actor.run());
+
+    let (timer_actor, timer_handle) = TimerActor::new(
+        Some(notifier_handle),
+        Some(app_tracker_handle),
+        None,
+        None,
+        None,
+        Some(Arc::clone(&session_repository)),
+        None,
+    );
+    tokio::spawn(timer_actor.run());
+
+    let started_at = Instant::now();
+    tokio::spawn(answer_notifications(
+        notifications,
+        script.answers,
+        translator.clone(),
+        started_at,
+    ));
+
+    timer_handle
+        .start(
+            script
+                .session
+                .duration_minutes
+                .map(|minutes| Duration::from_secs(minutes * 60)),
+            FocusMode::from_stored(&script.session.mode),
+            script.session.tags,
+            Vec::new(),
+            None,
+            false,
+        )
+        .await
+        .ok_or_else(|| anyhow!("the timer did not answer the start request"))?;
+
+    for event in script.events {
+        tokio::time::sleep_until(started_at + event.at).await;
+        let offset = format_offset(started_at.elapsed());
+        match event.action {
+            ScriptedAction::Focus(focused) => {
+                match &focused.title {
+                    Some(title) => println!("[{offset}] ▸ {} ({title})", focused.app_name),
+                    None => println!("[{offset}] ▸ {}", focused.app_name),
+                }
+                *window.lock().unwrap() = Some(focused);
+            }
+            ScriptedAction::Command(command) => {
+                println!("[{offset}] ▸ flux {}", command.as_str());
+                run_command(&timer_handle, command).await;
+            }
+        }
+    }
+
+    let still_running = wait_for_session_end(&timer_handle, started_at).await;
+    if still_running {
+        println!(
+            "[{}] {}",
+            format_offset(started_at.elapsed()),
+            translator.get("simulation.stopped")
+        );
+        timer_handle.stop(true, None).await;
+    }
+    // Lets the app tracker flush the session before reading it back
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let Some(session) =
+        storage::blocking(&session_repository, |repository| repository.find_latest()).await?
+    else {
+        return Ok(());
+    };
+    println!();
+    println!(
+        "{}",
+        translator.format(
+            "simulation.ended",
+            &[
+                ("reason", &end_reason_label(session.end_reason, &translator)),
+                ("elapsed", &format_offset(started_at.elapsed())),
+            ],
+        )
+    );
+
+    let Some(session_id) = session.id else {
+        return Ok(());
+    };
+    let metrics = storage::blocking(&metrics_repository, move |repository| {
+        repository.find_by_session(session_id)
+    })
+    .await?;
+    if let Some(metrics) = metrics {
+        println!(
+            "{}",
+            translator.format(
+                "simulation.metrics",
+                &[
+                    (
+                        "tracked",
+                        &format_offset(Duration::from_secs(metrics.tracked_seconds as u64)),
+                    ),
+                    (
+                        "distracted",
+                        &format_offset(Duration::from_secs(metrics.distraction_seconds as u64)),
+                    ),
+                    ("switches", &metrics.context_switch_count.to_string()),
+                    ("pauses", &metrics.pause_count.to_string()),
+                ],
+            )
+        );
+    }
+
+    Ok(())
+}
+
+async fn run_command(timer: &TimerHandle, command: ScriptedCommand) {
+    match command {
+        ScriptedCommand::Pause => {
+            timer.pause(true, None).await;
+        }
+        ScriptedCommand::Resume => {
+            let _ = timer.resume().await;
+        }
+        ScriptedCommand::Stop => {
+            timer.stop(true, None).await;
+        }
+    }
+}
+
+/// Returns true when the session is still running but nothing left in the
+/// script can end it: paused with no event left, or past the limit
+async fn wait_for_session_end(timer: &TimerHandle, started_at: Instant) -> bool {
+    loop {
+        let Some(status) = timer.get_status().await else {
+            return false;
+        };
+        if !status.active {
+            return false;
+        }
+        if status.paused || started_at.elapsed() >= SIMULATION_LIMIT {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+fn end_reason_label(reason: Option<SessionEndReason>, translator: &Translator) -> String {
+    match reason {
+        Some(SessionEndReason::Completed) => translator.get("simulation.reason_completed"),
+        Some(SessionEndReason::StoppedEarly) => translator.get("simulation.reason_stopped_early"),
+        Some(reason) => reason.as_str().to_string(),
+        None => translator.get("simulation.reason_running"),
+    }
+}
+
+/// Stands in for the notifier actor: prints each notification at its
+/// simulated time and answers the ones that ask something as scripted
+async fn answer_notifications(
+    mut notifications: mpsc::Receiver<NotifierMessage>,
+    answers: ScriptedAnswers,
+    translator: Translator,
+    started_at: Instant,
+) {
+    while let Some(message) = notifications.recv().await {
+        let offset = format_offset(started_at.elapsed());
+        let print = |title: &str, body: &str| {
+            println!("[{offset}] {}", title.trim_start_matches("Flux - "));
+            for line in body.lines() {
+                println!("        {line}");
+            }
+        };
+        let print_answer = |answer: String| println!("        → {answer}");
+
+        match message {
+            NotifierMessage::CheckIn {
+                percent,
+                response_sender,
+            } => {
+                print(
+                    &translator.get("notification.check_in_title"),
+                    &translator.format(
+                        "notification.check_in_body",
+                        &[("percent", &percent.to_string())],
+                    ),
+                );
+                print_answer(translator.get(match answers.check_in {
+                    CheckInResponse::Focused => "notification.check_in_yes",
+                    CheckInResponse::NotFocused => "notification.check_in_no",
+                    CheckInResponse::Timeout => "simulation.no_answer",
+                }));
+                let _ = response_sender.send(answers.check_in);
+            }
+            NotifierMessage::SessionStart {
+                duration_minutes,
+                mode,
+            } => print(
+                &translator.get("notification.session_start_title"),
+                &translator.format(
+                    "notification.session_start_body",
+                    &[
+                        ("duration", &duration_minutes.to_string()),
+                        ("mode", &translator.mode_name(&mode)),
+                    ],
+                ),
+            ),
+            NotifierMessage::SessionEnd { total_minutes } => print(
+                &translator.get("notification.session_end_title"),
+                &translator.format(
+                    "notification.session_end_body",
+                    &[("duration", &total_minutes.to_string())],
+                ),
+            ),
+            NotifierMessage::SessionPaused => print(
+                &translator.get("notification.paused_title"),
+                &translator.get("notification.paused_body"),
+            ),
+            NotifierMessage::SessionResumed => print(
+                &translator.get("notification.resumed_title"),
+                &translator.get("notification.resumed_body"),
+            ),
+            NotifierMessage::CheckInFocused => print(
+                &translator.get("notification.check_in_focused_title"),
+                &translator.get("notification.check_in_focused_body"),
+            ),
+            NotifierMessage::Alert { title, body }
+            | NotifierMessage::DistractionAlert { title, body } => print(&title, &body),
+            NotifierMessage::FrictionReminder {
+                app,
+                seconds,
+                response_sender,
+            } => {
+                print(
+                    &translator.get("notification.friction_title"),
+                    &translator.format(
+                        "notification.friction_body",
+                        &[("app", &app), ("seconds", &seconds.to_string())],
+                    ),
+                );
+                print_answer(friction_answer_label(answers.friction, &translator));
+                let _ = response_sender.send(answers.friction);
+            }
+            NotifierMessage::FrictionEscalated {
+                app,
+                response_sender,
+            } => {
+                print(
+                    &translator.get("notification.friction_escalated_title"),
+                    &translator.format("notification.friction_escalated_body", &[("app", &app)]),
+                );
+                print_answer(friction_answer_label(answers.friction, &translator));
+                let _ = response_sender.send(answers.friction);
+            }
+            NotifierMessage::VeilleReminder { minutes } => print(
+                &translator.get("notification.veille_reminder_title"),
+                &translator.format(
+                    "notification.veille_reminder_body",
+                    &[("minutes", &minutes.to_string())],
+                ),
+            ),
+            // Sent by the digest scheduler, the achievements actor and the
+            // battery watcher, none of which run in a simulation
+            NotifierMessage::WeeklyDigest { .. }
+            | NotifierMessage::DigestRegression { .. }
+            | NotifierMessage::StreakMilestone { .. }
+            | NotifierMessage::AchievementUnlocked { .. }
+            | NotifierMessage::SoundMuted { .. } => {}
+        }
+    }
+}
+
+fn friction_answer_label(answer: FrictionResponse, translator: &Translator) -> String {
+    translator.get(match answer {
+        FrictionResponse::Continue => "notification.friction_yes",
+        FrictionResponse::BackToWork => "notification.friction_no",
+        FrictionResponse::StopSession => "notification.friction_yes_stop",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_events_are_read_in_order() {
+        let mut script: Script = toml::from_str(
+            r#"
+            [session]
+            mode = "review"
+            duration_minutes = 10
+
+            [answers]
+            check_in = "not-focused"
+            friction = "back-to-work"
+
+            [[event]]
+            at = "4m"
+            command = "pause"
+
+            [[event]]
+            at = "1m30s"
+            app = "firefox"
+            title = "YouTube"
+            "#,
+        )
+        .unwrap();
+        script.events.sort_by_key(|event| event.at);
+
+        assert_eq!(script.session.duration_minutes, Some(10));
+        assert_eq!(script.answers.check_in, CheckInResponse::NotFocused);
+        assert_eq!(script.answers.friction, FrictionResponse::BackToWork);
+        assert_eq!(
+            script.events,
+            vec![
+                ScriptedEvent {
+                    at: Duration::from_secs(90),
+                    action: ScriptedAction::Focus(WindowInfo::new(
+                        "firefox".to_string(),
+                        Some("YouTube".to_string()),
+                    )),
+                },
+                ScriptedEvent {
+                    at: Duration::from_secs(240),
+                    action: ScriptedAction::Command(ScriptedCommand::Pause),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn event_with_both_a_window_and_a_command_is_rejected() {
+        let result = toml::from_str::<Script>(
+            r#"
+            [[event]]
+            at = "1m"
+            app = "slack"
+            command = "stop"
+            "#,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn offsets_combine_hours_minutes_and_seconds() {
+        assert_eq!(parse_offset("45s"), Some(Duration::from_secs(45)));
+        assert_eq!(parse_offset("1h5m30s"), Some(Duration::from_secs(3930)));
+        assert_eq!(parse_offset("0s"), Some(Duration::ZERO));
+        assert_eq!(parse_offset("90"), None);
+        assert_eq!(parse_offset("2 min"), None);
+        assert_eq!(parse_offset(""), None);
+    }
+
+    #[test]
+    fn script_argument_needs_a_path() {
+        let arguments = |values: &[&str]| {
+            values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .into_iter()
+        };
+
+        assert_eq!(
+            script_argument(arguments(&["--simulate", "focus.toml"])).unwrap(),
+            Some(PathBuf::from("focus.toml"))
+        );
+        assert_eq!(script_argument(arguments(&[])).unwrap(), None);
+        assert!(script_argument(arguments(&["--simulate"])).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+/// Async facade over the synchronous repository ports. The call runs on
+/// tokio's blocking pool, so a slow query (or a wait on the shared SQLite
+/// connection) never holds a runtime worker the timer tick needs.
+pub async fn blocking<R, T, F>(repository: &Arc<R>, call: F) -> T
+where
+    R: ?Sized + Send + Sync + 'static,
+    T: Send + 'static,
+    F: FnOnce(&R) -> T + Send + 'static,
+{
+    let repository = Arc::clone(repository);
+    match tokio::task::spawn_blocking(move || call(&repository)).await {
+        Ok(value) => value,
+        Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+        // Blocking tasks are only cancelled when the runtime shuts down, which
+        // drops the caller first
+        Err(error) => unreachable!("repository call cancelled: {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_adapters::SqliteSessionRepository;
+    use flux_core::{FocusMode, Session, SessionRepository};
+
+    #[tokio::test]
+    async fn calls_run_off_the_runtime_thread() {
+        let repository: Arc<dyn SessionRepository> =
+            Arc::new(SqliteSessionRepository::in_memory().unwrap());
+        let runtime_thread = std::thread::current().id();
+
+        let (thread, saved) = blocking(&repository, |repository| {
+            let mut session = Session::start(FocusMode::Review);
+            (std::thread::current().id(), repository.save(&mut session))
+        })
+        .await;
+
+        assert_ne!(thread, runtime_thread);
+        assert_eq!(saved.unwrap(), 1);
+    }
+}
@@ -0,0 +1,223 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use flux_protocol::{Subsystem, SubsystemHealth, SubsystemStatus};
+use tokio::sync::broadcast;
+use tokio::task::JoinError;
+use tokio::time::{sleep, Duration};
+use tracing::{debug, error, info};
+
+const INITIAL_RESTART_DELAY: Duration = Duration::from_secs(1);
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(60);
+/// An actor that ran this long before stopping restarts without backoff
+const STABLE_RUN: Duration = Duration::from_secs(300);
+
+/// Subsystem statuses shared with the server, updated when an actor is restarted
+#[derive(Clone, Default)]
+pub struct HealthRegistry {
+    subsystems: Arc<Mutex<Vec<SubsystemHealth>>>,
+}
+
+impl HealthRegistry {
+    /// Adds the initialization status of subsystems that have not been restarted yet
+    pub fn register(&self, subsystems: Vec<SubsystemHealth>) {
+        let mut registered = self.lock();
+        for health in subsystems {
+            if !registered
+                .iter()
+                .any(|existing| existing.subsystem == health.subsystem)
+            {
+                registered.push(health);
+            }
+        }
+    }
+
+    /// Replaces the status of a subsystem that reports changes while running
+    pub fn update(&self, health: SubsystemHealth) {
+        let mut registered = self.lock();
+        match registered
+            .iter_mut()
+            .find(|existing| existing.subsystem == health.subsystem)
+        {
+            Some(existing) => *existing = health,
+            None => registered.push(health),
+        }
+    }
+
+    pub fn snapshot(&self) -> Vec<SubsystemHealth> {
+        self.lock().clone()
+    }
+
+    /// Marks the subsystem as degraded and returns how many times it was restarted
+    pub fn record_restart(&self, subsystem: Subsystem, reason: Option<String>) -> u32 {
+        let mut registered = self.lock();
+        let index = match registered
+            .iter()
+            .position(|health| health.subsystem == subsystem)
+        {
+            Some(index) => index,
+            None => {
+                registered.push(SubsystemHealth::new(subsystem, SubsystemStatus::Running));
+                registered.len() - 1
+            }
+        };
+
+        let health = &mut registered[index];
+        let restarts = match health.status {
+            SubsystemStatus::Degraded { restarts, .. } => restarts + 1,
+            _ => 1,
+        };
+        health.status = SubsystemStatus::Degraded { restarts, reason };
+        restarts
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<SubsystemHealth>> {
+        self.subsystems
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Runs an actor and restarts it with backoff whenever it panics or stops before shutdown
+pub async fn supervise<Actor, Restart>(
+    subsystem: Subsystem,
+    health: HealthRegistry,
+    first_run: Actor,
+    mut restart: Restart,
+    mut shutdown: broadcast::Receiver<()>,
+) where
+    Actor: Future<Output = ()> + Send + 'static,
+    Restart: FnMut() -> Actor,
+{
+    let mut next_run = Some(first_run);
+    let mut consecutive_failures = 0;
+
+    loop {
+        let run = next_run.take().unwrap_or_else(&mut restart);
+        let started_at = Instant::now();
+        let mut task = tokio::spawn(run);
+
+        let reason = tokio::select! {
+            biased;
+            _ = shutdown.recv() => {
+                task.abort();
+                debug!(subsystem = subsystem.id(), "supervisor shutdown");
+                return;
+            }
+            outcome = &mut task => failure_reason(outcome),
+        };
+
+        if started_at.elapsed() >= STABLE_RUN {
+            consecutive_failures = 0;
+        }
+        consecutive_failures += 1;
+
+        let restarts = health.record_restart(subsystem, reason.clone());
+        let delay = restart_delay(consecutive_failures);
+        error!(
+            subsystem = subsystem.id(),
+            reason = reason.as_deref().unwrap_or("exited"),
+            restarts,
+            delay_seconds = delay.as_secs(),
+            "actor stopped unexpectedly, restarting"
+        );
+
+        tokio::select! {
+            biased;
+            _ = shutdown.recv() => return,
+            _ = sleep(delay) => {}
+        }
+        info!(subsystem = subsystem.id(), restarts, "actor restarted");
+    }
+}
+
+fn failure_reason(outcome: Result<(), JoinError>) -> Option<String> {
+    let error = outcome.err()?;
+    if !error.is_panic() {
+        return Some(error.to_string());
+    }
+
+    let payload = error.into_panic();
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
+
+/// Doubles after each consecutive failure, up to a minute
+fn restart_delay(consecutive_failures: u32) -> Duration {
+    let exponent = consecutive_failures.saturating_sub(1).min(6);
+    (INITIAL_RESTART_DELAY * 2u32.pow(exponent)).min(MAX_RESTART_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn restart_delay_doubles_up_to_a_minute() {
+        assert_eq!(restart_delay(1), Duration::from_secs(1));
+        assert_eq!(restart_delay(2), Duration::from_secs(2));
+        assert_eq!(restart_delay(4), Duration::from_secs(8));
+        assert_eq!(restart_delay(7), Duration::from_secs(60));
+        assert_eq!(restart_delay(40), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn registration_keeps_restarts_recorded_earlier() {
+        let health = HealthRegistry::default();
+        health.record_restart(Subsystem::Notifications, None);
+
+        health.register(vec![
+            SubsystemHealth::new(Subsystem::Notifications, SubsystemStatus::Running),
+            SubsystemHealth::new(Subsystem::Tray, SubsystemStatus::Disabled),
+        ]);
+
+        let subsystems = health.snapshot();
+        assert_eq!(subsystems.len(), 2);
+        assert!(subsystems[0].is_degraded());
+        assert_eq!(subsystems[1].status, SubsystemStatus::Disabled);
+    }
+
+    #[tokio::test]
+    async fn panicking_actor_is_restarted_and_reported_degraded() {
+        let health = HealthRegistry::default();
+        health.register(vec![SubsystemHealth::new(
+            Subsystem::AppTracking,
+            SubsystemStatus::Running,
+        )]);
+        let runs = Arc::new(AtomicU32::new(0));
+        let (shutdown_sender, shutdown_receiver) = broadcast::channel(1);
+
+        let actor = |runs: Arc<AtomicU32>| async move {
+            if runs.fetch_add(1, Ordering::SeqCst) == 0 {
+                panic!("poll failed");
+            }
+            std::future::pending::<()>().await;
+        };
+        let restart_runs = Arc::clone(&runs);
+        let supervisor = tokio::spawn(supervise(
+            Subsystem::AppTracking,
+            health.clone(),
+            actor(Arc::clone(&runs)),
+            move || actor(Arc::clone(&restart_runs)),
+            shutdown_receiver,
+        ));
+
+        sleep(INITIAL_RESTART_DELAY + Duration::from_millis(500)).await;
+
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            health.snapshot()[0].status,
+            SubsystemStatus::Degraded {
+                restarts: 1,
+                reason: Some("poll failed".to_string()),
+            }
+        );
+
+        shutdown_sender.send(()).unwrap();
+        supervisor.await.unwrap();
+    }
+}
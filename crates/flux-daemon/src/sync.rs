@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use flux_core::{SyncGateway, SyncMerge, SyncRepository, UsageRollupRepository};
+use tokio::sync::broadcast;
+use tokio::time::{interval, Duration};
+use tracing::{debug, info, warn};
+
+use crate::storage;
+
+/// Merges the session history with the other devices every `interval_minutes`,
+/// starting right away so that a device catches up when the daemon starts
+pub async fn run(
+    gateway: Arc<dyn SyncGateway>,
+    repository: Arc<dyn SyncRepository>,
+    rollups: Option<Arc<dyn UsageRollupRepository>>,
+    interval_minutes: u64,
+    mut shutdown: broadcast::Receiver<()>,
+) {
+    info!(
+        folder = gateway.target_name(),
+        interval_minutes, "session sync started"
+    );
+
+    let mut ticker = interval(Duration::from_secs(interval_minutes.max(1) * 60));
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = shutdown.recv() => {
+                debug!("session sync shutdown");
+                break;
+            }
+        }
+
+        match synchronize(gateway.as_ref(), &repository).await {
+            Ok(merge) => {
+                debug!(
+                    received = merge.incoming.len(),
+                    sent = merge.outgoing,
+                    "session history synced"
+                );
+                // Received sessions may predate the last rollup refresh
+                if let Some(rollups) = rollups.as_ref().filter(|_| !merge.incoming.is_empty()) {
+                    if let Err(error) =
+                        storage::blocking(rollups, |rollups| rollups.refresh_rollups(None)).await
+                    {
+                        warn!(%error, "failed to refresh usage rollups after sync");
+                    }
+                }
+            }
+            Err(error) => warn!(%error, "failed to sync session history"),
+        }
+    }
+}
+
+async fn synchronize(
+    gateway: &dyn SyncGateway,
+    repository: &Arc<dyn SyncRepository>,
+) -> anyhow::Result<SyncMerge> {
+    let local = storage::blocking(repository, |repository| repository.export()).await?;
+    let merge = SyncMerge::new(&local, gateway.pull().await?);
+
+    let incoming = merge.incoming.clone();
+    storage::blocking(repository, move |repository| repository.import(&incoming)).await?;
+    if merge.outgoing > 0 {
+        let records = storage::blocking(repository, |repository| repository.export()).await?;
+        gateway.push(&records).await?;
+    }
+
+    Ok(merge)
+}
@@ -0,0 +1,163 @@
+use std::ffi::c_void;
+
+use core_foundation::base::{CFType, CFTypeRef, TCFType};
+use core_foundation::dictionary::{CFDictionary, CFDictionaryRef};
+use core_foundation::number::CFNumber;
+use core_foundation::string::{CFString, CFStringRef};
+use core_graphics::window::{
+    copy_window_info, kCGNullWindowID, kCGWindowLayer, kCGWindowListExcludeDesktopElements,
+    kCGWindowListOptionOnScreenOnly, kCGWindowName, kCGWindowOwnerName, kCGWindowOwnerPID,
+};
+use flux_core::TrackingConfig;
+use tracing::{debug, warn};
+
+use super::{WindowDetector, WindowInfo};
+
+const NORMAL_WINDOW_LAYER: i64 = 0;
+const FOCUSED_WINDOW_ATTRIBUTE: &str = "AXFocusedWindow";
+const TITLE_ATTRIBUTE: &str = "AXTitle";
+const ACCESSIBILITY_SUCCESS: i32 = 0;
+
+type AccessibilityElementRef = *const c_void;
+
+#[link(name = "ApplicationServices", kind = "framework")]
+extern "C" {
+    fn AXIsProcessTrusted() -> bool;
+    fn AXUIElementCreateApplication(pid: i32) -> AccessibilityElementRef;
+    fn AXUIElementCopyAttributeValue(
+        element: AccessibilityElementRef,
+        attribute: CFStringRef,
+        value: *mut CFTypeRef,
+    ) -> i32;
+}
+
+pub struct MacWindowDetector {
+    accessibility_trusted: bool,
+}
+
+impl MacWindowDetector {
+    pub fn new() -> Option<Self> {
+        copy_window_info(kCGWindowListOptionOnScreenOnly, kCGNullWindowID).or_else(|| {
+            warn!("failed to list windows, no window server available");
+            None
+        })?;
+
+        let accessibility_trusted = unsafe { AXIsProcessTrusted() };
+        if !accessibility_trusted {
+            warn!("accessibility permission not granted, window titles may be missing");
+        }
+
+        Some(Self {
+            accessibility_trusted,
+        })
+    }
+
+    fn frontmost_window(&self) -> Option<(String, i32, Option<String>)> {
+        let windows = copy_window_info(
+            kCGWindowListOptionOnScreenOnly | kCGWindowListExcludeDesktopElements,
+            kCGNullWindowID,
+        )?;
+
+        windows.iter().find_map(|window| {
+            let window: CFDictionary<CFString, CFType> =
+                unsafe { CFDictionary::wrap_under_get_rule(*window as CFDictionaryRef) };
+
+            let layer = number_value(&window, unsafe { kCGWindowLayer })?;
+            if layer != NORMAL_WINDOW_LAYER {
+                return None;
+            }
+
+            let owner_name = string_value(&window, unsafe { kCGWindowOwnerName })?;
+            let owner_pid = number_value(&window, unsafe { kCGWindowOwnerPID })?;
+            let title =
+                string_value(&window, unsafe { kCGWindowName }).filter(|title| !title.is_empty());
+
+            Some((owner_name, owner_pid as i32, title))
+        })
+    }
+
+    fn focused_window_title(&self, pid: i32) -> Option<String> {
+        if !self.accessibility_trusted {
+            return None;
+        }
+
+        unsafe {
+            let application = AXUIElementCreateApplication(pid);
+            if application.is_null() {
+                return None;
+            }
+            let application = CFType::wrap_under_create_rule(application as CFTypeRef);
+
+            let window = copy_attribute(
+                application.as_CFTypeRef() as AccessibilityElementRef,
+                FOCUSED_WINDOW_ATTRIBUTE,
+            )?;
+            let title = copy_attribute(
+                window.as_CFTypeRef() as AccessibilityElementRef,
+                TITLE_ATTRIBUTE,
+            )?;
+
+            title
+                .downcast::<CFString>()
+                .map(|title| title.to_string())
+                .filter(|title| !title.is_empty())
+        }
+    }
+}
+
+impl WindowDetector for MacWindowDetector {
+    fn get_active_window_info(&self, tracking: &TrackingConfig) -> Option<WindowInfo> {
+        let (application_name, pid, title) = self.frontmost_window()?;
+        let window_title = if tracking.tracks_title(&application_name) {
+            title.or_else(|| self.focused_window_title(pid))
+        } else {
+            None
+        };
+
+        debug!(application_name = %application_name, window_title = ?window_title, "detected active window");
+        Some(WindowInfo::new(application_name, window_title))
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "macos"
+    }
+}
+
+fn string_value(window: &CFDictionary<CFString, CFType>, key: CFStringRef) -> Option<String> {
+    window
+        .find(key)?
+        .downcast::<CFString>()
+        .map(|value| value.to_string())
+}
+
+fn number_value(window: &CFDictionary<CFString, CFType>, key: CFStringRef) -> Option<i64> {
+    window.find(key)?.downcast::<CFNumber>()?.to_i64()
+}
+
+unsafe fn copy_attribute(element: AccessibilityElementRef, attribute: &str) -> Option<CFType> {
+    let attribute = CFString::new(attribute);
+    let mut value: CFTypeRef = std::ptr::null();
+
+    let status =
+        AXUIElementCopyAttributeValue(element, attribute.as_concrete_TypeRef(), &mut value);
+    if status != ACCESSIBILITY_SUCCESS || value.is_null() {
+        return None;
+    }
+
+    Some(CFType::wrap_under_create_rule(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detector_can_be_created_or_fails_gracefully() {
+        let detector = MacWindowDetector::new();
+
+        match detector {
+            Some(_) => println!("macOS detector created successfully"),
+            None => println!("window server not available (expected in CI)"),
+        }
+    }
+}
@@ -1,9 +1,23 @@
 #[cfg(target_os = "linux")]
 mod x11_detector;
 
+#[cfg(target_os = "macos")]
+mod mac_detector;
+
+#[cfg(windows)]
+mod windows_detector;
+
 #[cfg(target_os = "linux")]
 pub use x11_detector::X11WindowDetector;
 
+#[cfg(target_os = "macos")]
+pub use mac_detector::MacWindowDetector;
+
+#[cfg(windows)]
+pub use windows_detector::WindowsWindowDetector;
+
+use flux_core::TrackingConfig;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WindowInfo {
     pub app_name: String,
@@ -21,5 +35,28 @@ impl WindowInfo {
 }
 
 pub trait WindowDetector: Send + Sync {
-    fn get_active_window_info(&self) -> Option<WindowInfo>;
+    /// Reads the window title only for applications listed in `tracking`
+    fn get_active_window_info(&self, tracking: &TrackingConfig) -> Option<WindowInfo>;
+
+    fn backend_name(&self) -> &'static str;
+}
+
+#[cfg(target_os = "linux")]
+pub fn create_window_detector() -> Option<Box<dyn WindowDetector>> {
+    X11WindowDetector::new().map(|detector| Box::new(detector) as Box<dyn WindowDetector>)
+}
+
+#[cfg(target_os = "macos")]
+pub fn create_window_detector() -> Option<Box<dyn WindowDetector>> {
+    MacWindowDetector::new().map(|detector| Box::new(detector) as Box<dyn WindowDetector>)
+}
+
+#[cfg(windows)]
+pub fn create_window_detector() -> Option<Box<dyn WindowDetector>> {
+    WindowsWindowDetector::new().map(|detector| Box::new(detector) as Box<dyn WindowDetector>)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+pub fn create_window_detector() -> Option<Box<dyn WindowDetector>> {
+    None
 }
@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use flux_core::TrackingConfig;
+use tracing::{debug, warn};
+use windows_sys::Win32::Foundation::{CloseHandle, HWND};
+use windows_sys::Win32::System::Threading::{
+    OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+};
+use windows_sys::Win32::UI::WindowsAndMessaging::{
+    GetForegroundWindow, GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId,
+};
+
+use super::{WindowDetector, WindowInfo};
+
+const MAX_PATH_LENGTH: usize = 32_768;
+
+pub struct WindowsWindowDetector;
+
+impl WindowsWindowDetector {
+    pub fn new() -> Option<Self> {
+        Some(Self)
+    }
+
+    fn get_window_title(&self, window: HWND) -> Option<String> {
+        let length = unsafe { GetWindowTextLengthW(window) };
+        if length <= 0 {
+            return None;
+        }
+
+        let mut buffer = vec![0u16; length as usize + 1];
+        let copied = unsafe { GetWindowTextW(window, buffer.as_mut_ptr(), buffer.len() as i32) };
+        if copied <= 0 {
+            return None;
+        }
+
+        Some(String::from_utf16_lossy(&buffer[..copied as usize]))
+    }
+
+    fn get_process_path(&self, window: HWND) -> Option<String> {
+        let mut process_id = 0u32;
+        unsafe { GetWindowThreadProcessId(window, &mut process_id) };
+        if process_id == 0 {
+            return None;
+        }
+
+        let process = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, process_id) };
+        if process.is_null() {
+            warn!(process_id, "failed to open foreground process");
+            return None;
+        }
+
+        let mut buffer = vec![0u16; MAX_PATH_LENGTH];
+        let mut size = buffer.len() as u32;
+        let succeeded = unsafe {
+            QueryFullProcessImageNameW(process, PROCESS_NAME_WIN32, buffer.as_mut_ptr(), &mut size)
+        };
+        unsafe { CloseHandle(process) };
+
+        if succeeded == 0 {
+            return None;
+        }
+
+        Some(String::from_utf16_lossy(&buffer[..size as usize]))
+    }
+}
+
+impl WindowDetector for WindowsWindowDetector {
+    fn get_active_window_info(&self, tracking: &TrackingConfig) -> Option<WindowInfo> {
+        let window = unsafe { GetForegroundWindow() };
+        if window.is_null() {
+            return None;
+        }
+
+        let application_name = application_name(&self.get_process_path(window)?)?;
+        let window_title = if tracking.tracks_title(&application_name) {
+            self.get_window_title(window)
+        } else {
+            None
+        };
+
+        debug!(application_name = %application_name, window_title = ?window_title, "detected active window");
+        Some(WindowInfo::new(application_name, window_title))
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "windows"
+    }
+}
+
+fn application_name(executable_path: &str) -> Option<String> {
+    Path::new(executable_path)
+        .file_stem()
+        .map(|name| name.to_string_lossy().into_owned())
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn application_name_is_the_executable_stem() {
+        assert_eq!(
+            application_name(r"C:\Program Files\Mozilla Firefox\firefox.exe"),
+            Some("firefox".to_string())
+        );
+        assert_eq!(application_name(""), None);
+    }
+}
@@ -3,10 +3,16 @@ use std::path::Path;
 
 use tracing::{debug, trace, warn};
 use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
 use x11rb::protocol::xproto::{AtomEnum, ConnectionExt, Window};
 use x11rb::rust_connection::RustConnection;
 
+use flux_core::TrackingConfig;
+
 use super::{WindowDetector, WindowInfo};
+use crate::log_throttle::LogThrottle;
+
+const ACTIVE_WINDOW_WARNING: &str = "x11_active_window";
 
 pub struct X11WindowDetector {
     connection: RustConnection,
@@ -17,6 +23,7 @@ pub struct X11WindowDetector {
     net_wm_name_atom: u32,
     wm_name_atom: u32,
     utf8_string_atom: u32,
+    warnings: LogThrottle,
 }
 
 impl X11WindowDetector {
@@ -73,11 +80,12 @@ impl X11WindowDetector {
             net_wm_name_atom,
             wm_name_atom,
             utf8_string_atom,
+            warnings: LogThrottle::default(),
         })
     }
 
     fn get_active_window(&self) -> Option<Window> {
-        let reply = self
+        let reply = match self
             .connection
             .get_property(
                 false,
@@ -87,9 +95,17 @@ impl X11WindowDetector {
                 0,
                 1,
             )
-            .ok()?
-            .reply()
-            .ok()?;
+            .map_err(ReplyError::from)
+            .and_then(|cookie| cookie.reply())
+        {
+            Ok(reply) => reply,
+            Err(error) => {
+                if let Some(suppressed) = self.warnings.should_log(ACTIVE_WINDOW_WARNING) {
+                    warn!(%error, suppressed, "failed to query active X11 window");
+                }
+                return None;
+            }
+        };
 
         if reply.value.len() >= 4 {
             let window_id = u32::from_ne_bytes([
@@ -255,10 +271,14 @@ impl X11WindowDetector {
 }
 
 impl WindowDetector for X11WindowDetector {
-    fn get_active_window_info(&self) -> Option<WindowInfo> {
+    fn get_active_window_info(&self, tracking: &TrackingConfig) -> Option<WindowInfo> {
         let window = self.get_active_window()?;
         let window_class = self.get_window_class(window)?;
-        let window_title = self.get_window_title(window);
+        let window_title = if tracking.tracks_title(&window_class) {
+            self.get_window_title(window)
+        } else {
+            None
+        };
 
         let lowercase_class = window_class.to_lowercase();
 
@@ -274,6 +294,10 @@ impl WindowDetector for X11WindowDetector {
         debug!(window_class = %window_class, window_title = ?window_title, "detected active window");
         Some(WindowInfo::new(window_class, window_title))
     }
+
+    fn backend_name(&self) -> &'static str {
+        "x11"
+    }
 }
 
 #[cfg(test)]
@@ -1,29 +1,63 @@
+use std::time::{Duration, Instant, SystemTime};
+
 use eframe::egui::{self, Rounding, ScrollArea};
-use flux_core::{AppState, Config};
+use flux_core::{AppState, AppUsageSegment, Config, DailyTarget, SessionId, Streak};
 
-use crate::data::{Period, Stats, StatsData};
+use crate::data::{self, format_duration, PendingConfigChange, Period, Stats, StatsData};
+use crate::export;
 use crate::theme::Theme;
 use crate::views;
+use crate::views::about::AboutDialog;
+use crate::views::annotations::{self, AnnotationDraft};
+use crate::views::config_diff::ConfigDiffAction;
+use crate::views::distractions::RulePreview;
+use crate::views::health_banner::HealthMonitor;
 use crate::views::overview::AppAction;
 use crate::views::session_control::{SessionController, StartSessionForm};
+use crate::views::suggestion_banner::SuggestionAction;
+
+const REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Session shown in the history detail view, with its unsaved annotations
+struct OpenedSession {
+    id: SessionId,
+    segments: Vec<AppUsageSegment>,
+    draft: AnnotationDraft,
+    status: Option<String>,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum View {
     Overview,
     History,
+    Distractions,
+    Heatmap,
+    Achievements,
 }
 
 pub struct FluxApp {
     data: StatsData,
     selected_period: Period,
+    compare_previous_period: bool,
     current_stats: Stats,
+    streak: Streak,
+    daily_target: Option<DailyTarget>,
     current_view: View,
+    selected_tag: Option<String>,
+    rule_preview: RulePreview,
     theme: Theme,
     theme_applied: bool,
     show_clear_modal: bool,
+    pending_config_change: Option<PendingConfigChange>,
+    export_message: Option<String>,
     runtime: Option<tokio::runtime::Runtime>,
     session_controller: SessionController,
     session_form: StartSessionForm,
+    health_monitor: HealthMonitor,
+    about_dialog: AboutDialog,
+    database_modified_at: Option<SystemTime>,
+    last_refresh_check: Instant,
+    opened_session: Option<OpenedSession>,
 }
 
 impl Drop for FluxApp {
@@ -37,6 +71,8 @@ impl Drop for FluxApp {
 impl FluxApp {
     pub fn new(data: StatsData) -> Self {
         let current_stats = data.stats_for_period(Period::Today);
+        let streak = data.streak();
+        let daily_target = data.daily_target();
 
         let runtime = tokio::runtime::Builder::new_multi_thread()
             .worker_threads(1)
@@ -45,23 +81,114 @@ impl FluxApp {
             .expect("failed to create tokio runtime");
 
         let session_controller = SessionController::new(runtime.handle());
+        let health_monitor = HealthMonitor::new(runtime.handle());
+        let about_dialog = AboutDialog::new(runtime.handle());
 
         Self {
             data,
             selected_period: Period::Today,
+            compare_previous_period: false,
             current_stats,
+            streak,
+            daily_target,
             current_view: View::Overview,
-            theme: Theme::dark(),
+            selected_tag: None,
+            rule_preview: RulePreview::default(),
+            theme: Theme::dark().with_mode_colors(Config::load().unwrap_or_default().modes),
             theme_applied: false,
             show_clear_modal: false,
+            pending_config_change: None,
+            export_message: None,
             runtime: Some(runtime),
             session_controller,
             session_form: StartSessionForm::default(),
+            health_monitor,
+            about_dialog,
+            database_modified_at: data::database_modified_at(),
+            last_refresh_check: Instant::now(),
+            opened_session: None,
+        }
+    }
+
+    fn refresh(&mut self) {
+        if self.data.reload().is_ok() {
+            self.update_stats();
+        }
+    }
+
+    fn refresh_if_database_changed(&mut self, ctx: &egui::Context) {
+        ctx.request_repaint_after(REFRESH_CHECK_INTERVAL);
+        if self.last_refresh_check.elapsed() < REFRESH_CHECK_INTERVAL {
+            return;
+        }
+        self.last_refresh_check = Instant::now();
+
+        let modified_at = data::database_modified_at();
+        if modified_at != self.database_modified_at {
+            self.database_modified_at = modified_at;
+            self.refresh();
         }
     }
 
     fn update_stats(&mut self) {
         self.current_stats = self.data.stats_for_period(self.selected_period);
+        self.streak = self.data.streak();
+        self.daily_target = self.data.daily_target();
+    }
+
+    fn render_streak(&self, ui: &mut egui::Ui) {
+        if self.streak.current_days == 0 {
+            return;
+        }
+
+        let translator = &self.data.translator;
+        let color = if self.streak.active_today {
+            self.theme.colors.warning
+        } else {
+            self.theme.colors.text_muted
+        };
+
+        ui.label(
+            egui::RichText::new(translator.format(
+                "gui.header_streak",
+                &[("days", &self.streak.current_days.to_string())],
+            ))
+            .size(self.theme.typography.body)
+            .color(color),
+        )
+        .on_hover_text(translator.format(
+            "gui.header_streak_tooltip",
+            &[("longest", &self.streak.longest_days.to_string())],
+        ));
+    }
+
+    fn render_daily_target(&self, ui: &mut egui::Ui) {
+        let Some(target) = self.daily_target.filter(|target| !target.rest_day) else {
+            return;
+        };
+
+        let translator = &self.data.translator;
+        let color = if target.remaining_seconds() == 0 {
+            self.theme.colors.success
+        } else {
+            self.theme.colors.text_muted
+        };
+
+        ui.label(
+            egui::RichText::new(translator.format(
+                "gui.header_daily_target",
+                &[
+                    ("focused", &format_duration(target.focused_seconds)),
+                    ("target", &format_duration(target.target_seconds)),
+                ],
+            ))
+            .size(self.theme.typography.body)
+            .color(color),
+        )
+        .on_hover_text(translator.format(
+            "gui.header_daily_target_tooltip",
+            &[("percent", &target.progress_percent().to_string())],
+        ));
     }
 }
 
@@ -73,10 +200,13 @@ impl eframe::App for FluxApp {
         }
 
         self.session_controller.poll(ctx);
+        self.health_monitor.poll(ctx);
+        self.about_dialog.poll(ctx);
 
-        if self.session_controller.session_just_ended() && self.data.reload().is_ok() {
-            self.update_stats();
+        if self.session_controller.session_just_ended() {
+            self.refresh();
         }
+        self.refresh_if_database_changed(ctx);
 
         let panel_frame = egui::Frame::none()
             .fill(self.theme.colors.background)
@@ -93,16 +223,30 @@ impl eframe::App for FluxApp {
                             .color(self.theme.colors.text_primary)
                             .strong(),
                     );
+                    self.render_streak(ui);
+                    self.render_daily_target(ui);
 
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        if self.render_profile_selector(ui) && self.data.reload().is_ok() {
-                            self.update_stats();
+                        if self.render_about_button(ui) {
+                            self.about_dialog.open();
+                        }
+                        let refresh_clicked = self.render_refresh_button(ui);
+                        let profile_changed = self.render_profile_selector(ui);
+                        if refresh_clicked || profile_changed {
+                            self.refresh();
                         }
                     });
                 });
 
                 ui.add_space(self.theme.spacing.md);
 
+                views::health_banner::render_health_banner(
+                    ui,
+                    self.health_monitor.health(),
+                    &self.data.translator,
+                    &self.theme,
+                );
+
                 self.render_view_tabs(ui);
 
                 ui.add_space(self.theme.spacing.md);
@@ -126,10 +270,33 @@ impl eframe::App for FluxApp {
                     .show(ui, |ui| match self.current_view {
                         View::Overview => self.render_overview(ui),
                         View::History => self.render_history(ui),
+                        View::Distractions => views::distractions::render_distractions(
+                            ui,
+                            &self.data.distraction_config,
+                            &mut self.rule_preview,
+                            &self.data.translator,
+                            &self.theme,
+                        ),
+                        View::Heatmap => views::heatmap::render_heatmap(
+                            ui,
+                            &self.data.heatmap_days(),
+                            self.data.week_start(),
+                            &self.data.translator,
+                            &self.theme,
+                        ),
+                        View::Achievements => views::achievements::render_achievements(
+                            ui,
+                            &self.data.achievements,
+                            &self.data.translator,
+                            &self.theme,
+                        ),
                     });
             });
 
         self.render_clear_modal(ctx);
+        self.render_config_diff_modal(ctx);
+        self.about_dialog
+            .render(ctx, &self.data.translator, &self.theme);
     }
 }
 
@@ -144,6 +311,15 @@ impl FluxApp {
                     &self.data.translator.get("gui.tab_overview"),
                 ),
                 (View::History, &self.data.translator.get("gui.tab_history")),
+                (
+                    View::Distractions,
+                    &self.data.translator.get("gui.tab_distractions"),
+                ),
+                (View::Heatmap, &self.data.translator.get("gui.tab_heatmap")),
+                (
+                    View::Achievements,
+                    &self.data.translator.get("gui.tab_achievements"),
+                ),
             ];
 
             for (view, label) in tabs {
@@ -184,12 +360,32 @@ impl FluxApp {
             ui,
             &mut self.session_controller,
             &mut self.session_form,
+            &self.data.recent_custom_modes(),
             &self.data.translator,
             &self.theme,
         );
 
         ui.add_space(self.theme.spacing.lg);
 
+        if let Some(action) = views::suggestion_banner::render_suggestion_banner(
+            ui,
+            &self.data.suggestions,
+            &self.data.translator,
+            &self.theme,
+        ) {
+            let result = match action {
+                SuggestionAction::Accept(position) => self
+                    .data
+                    .accept_suggestion(position)
+                    .map(|change| self.review_config_change(change)),
+                SuggestionAction::Dismiss(position) => self.data.dismiss_suggestion(position),
+            };
+            if let Err(error) = result {
+                tracing::warn!("suggestion action failed: {}", error);
+            }
+            self.current_stats = self.data.stats_for_period(self.selected_period);
+        }
+
         if self.data.has_sessions() {
             if let Some(action) = views::overview::render_stats_cards(
                 ui,
@@ -202,8 +398,9 @@ impl FluxApp {
                     AppAction::ToggleDistraction(app) => self.data.toggle_distraction(&app),
                     AppAction::ToggleWhitelist(app) => self.data.toggle_whitelist(&app),
                 };
-                if let Err(error) = result {
-                    tracing::warn!("toggle action failed: {}", error);
+                match result {
+                    Ok(change) => self.review_config_change(Some(change)),
+                    Err(error) => tracing::warn!("toggle action failed: {}", error),
                 }
                 self.current_stats = self.data.stats_for_period(self.selected_period);
             }
@@ -212,16 +409,36 @@ impl FluxApp {
             if !daily_data.is_empty() {
                 ui.add_space(self.theme.spacing.lg);
 
+                let previous_data = self
+                    .data
+                    .previous_daily_focus_for_period(self.selected_period);
+
                 self.theme.card_frame().show(ui, |ui| {
-                    ui.label(
-                        egui::RichText::new(self.data.translator.get("gui.chart_title"))
-                            .size(self.theme.typography.title)
-                            .color(self.theme.colors.text_primary)
-                            .strong(),
-                    );
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(self.data.translator.get("gui.chart_title"))
+                                .size(self.theme.typography.title)
+                                .color(self.theme.colors.text_primary)
+                                .strong(),
+                        );
+                        if previous_data.is_some() {
+                            ui.with_layout(
+                                egui::Layout::right_to_left(egui::Align::Center),
+                                |ui| {
+                                    ui.checkbox(
+                                        &mut self.compare_previous_period,
+                                        self.data.translator.get("gui.chart_compare_previous"),
+                                    );
+                                },
+                            );
+                        }
+                    });
                     ui.add_space(self.theme.spacing.md);
 
-                    views::chart::render_focus_chart(ui, &daily_data, &self.theme);
+                    let previous = previous_data
+                        .as_deref()
+                        .filter(|_| self.compare_previous_period);
+                    views::chart::render_focus_chart(ui, &daily_data, previous, &self.theme);
                 });
             }
         } else {
@@ -230,7 +447,54 @@ impl FluxApp {
     }
 
     fn render_history(&mut self, ui: &mut egui::Ui) {
-        let sessions = self.data.sessions_for_period(self.selected_period);
+        if let Some(opened) = self.opened_session.as_mut() {
+            let Some(session) = self.data.sessions.iter().find(|s| s.id == Some(opened.id)) else {
+                self.opened_session = None;
+                return;
+            };
+
+            let back_clicked = views::timeline::render_session_timeline(
+                ui,
+                session,
+                &opened.segments,
+                &self.data.translator,
+                &self.theme,
+            );
+            ui.add_space(self.theme.spacing.md);
+
+            let saved = AnnotationDraft::new(session, self.data.note_for(opened.id));
+            let save_clicked = annotations::render_annotation_editor(
+                ui,
+                &mut opened.draft,
+                &saved,
+                opened.status.as_deref(),
+                &self.data.translator,
+                &self.theme,
+            );
+
+            if back_clicked {
+                self.opened_session = None;
+            } else if save_clicked {
+                self.save_annotations();
+            }
+            return;
+        }
+
+        let period_sessions = self.data.sessions_for_period(self.selected_period);
+        views::history::render_tag_filter(
+            ui,
+            &period_sessions,
+            &mut self.selected_tag,
+            &self.data.translator,
+        );
+
+        let sessions: Vec<_> = match self.selected_tag {
+            Some(ref tag) => period_sessions
+                .into_iter()
+                .filter(|session| session.has_tag(tag))
+                .collect(),
+            None => period_sessions,
+        };
         let session_count = sessions.len();
 
         ui.horizontal(|ui| {
@@ -247,18 +511,138 @@ impl FluxApp {
                 if session_count > 0 && ui.add(clear_button).clicked() {
                     self.show_clear_modal = true;
                 }
+
+                let export_button = egui::Button::new(
+                    egui::RichText::new(self.data.translator.get("gui.export_sessions"))
+                        .size(self.theme.typography.label)
+                        .color(self.theme.colors.accent),
+                )
+                .fill(egui::Color32::TRANSPARENT)
+                .stroke(egui::Stroke::new(1.0, self.theme.colors.accent))
+                .rounding(Rounding::same(self.theme.rounding.sm));
+
+                if session_count > 0 && ui.add(export_button).clicked() {
+                    self.export_message = Some(
+                        match export::write_sessions_csv(
+                            &sessions,
+                            &self.data.session_metrics,
+                            &self.data.session_notes,
+                            &self.data.score_weights,
+                        ) {
+                            Ok(path) => self.data.translator.format(
+                                "gui.export_success",
+                                &[("path", &path.display().to_string())],
+                            ),
+                            Err(error) => self
+                                .data
+                                .translator
+                                .format("gui.export_failed", &[("error", &error.to_string())]),
+                        },
+                    );
+                }
+
+                if let Some(ref message) = self.export_message {
+                    ui.label(
+                        egui::RichText::new(message)
+                            .size(self.theme.typography.label)
+                            .color(self.theme.colors.text_muted),
+                    );
+                }
             });
         });
 
         ui.add_space(self.theme.spacing.md);
 
-        let action =
-            views::history::render_session_list(ui, &sessions, &self.data.translator, &self.theme);
+        let action = views::history::render_session_list(
+            ui,
+            &sessions,
+            &self.data.session_notes,
+            &self.data.translator,
+            &self.theme,
+        );
+
+        match action {
+            views::history::HistoryAction::OpenSession(id) => {
+                let segments = self.data.session_segments(id).unwrap_or_default();
+                let draft = self
+                    .data
+                    .sessions
+                    .iter()
+                    .find(|session| session.id == Some(id))
+                    .map(|session| AnnotationDraft::new(session, self.data.note_for(id)))
+                    .unwrap_or_default();
+                self.opened_session = Some(OpenedSession {
+                    id,
+                    segments,
+                    draft,
+                    status: None,
+                });
+            }
+            views::history::HistoryAction::DeleteSession(id) => {
+                if self.data.delete_session(id).is_ok() {
+                    self.update_stats();
+                }
+            }
+            views::history::HistoryAction::None => {}
+        }
+    }
+
+    fn save_annotations(&mut self) {
+        let Some(opened) = self.opened_session.as_mut() else {
+            return;
+        };
+
+        let result = self
+            .data
+            .save_annotations(opened.id, &opened.draft.note, opened.draft.tags());
+        opened.status = Some(match result {
+            Ok(()) => {
+                if let Some(session) = self.data.sessions.iter().find(|s| s.id == Some(opened.id)) {
+                    opened.draft = AnnotationDraft::new(session, self.data.note_for(opened.id));
+                }
+                self.data.translator.get("gui.annotations_saved")
+            }
+            Err(error) => self.data.translator.format(
+                "gui.annotations_save_failed",
+                &[("error", &error.to_string())],
+            ),
+        });
+        self.update_stats();
+    }
 
-        if let views::history::HistoryAction::DeleteSession(id) = action {
-            if self.data.delete_session(id).is_ok() {
-                self.update_stats();
+    fn review_config_change(&mut self, change: Option<PendingConfigChange>) {
+        match change {
+            Some(change) if change.has_changes() => self.pending_config_change = Some(change),
+            Some(change) => self.apply_config_change(change),
+            None => {}
+        }
+    }
+
+    fn apply_config_change(&mut self, change: PendingConfigChange) {
+        if let Err(error) = self.data.apply_config_change(change) {
+            tracing::warn!("config change failed: {}", error);
+        }
+        self.current_stats = self.data.stats_for_period(self.selected_period);
+    }
+
+    fn render_config_diff_modal(&mut self, ctx: &egui::Context) {
+        let Some(change) = &self.pending_config_change else {
+            return;
+        };
+
+        match views::config_diff::render_config_diff(
+            ctx,
+            change,
+            &self.data.translator,
+            &self.theme,
+        ) {
+            Some(ConfigDiffAction::Save) => {
+                if let Some(change) = self.pending_config_change.take() {
+                    self.apply_config_change(change);
+                }
             }
+            Some(ConfigDiffAction::Cancel) => self.pending_config_change = None,
+            None => {}
         }
     }
 
@@ -321,6 +705,36 @@ impl FluxApp {
             });
     }
 
+    fn render_refresh_button(&self, ui: &mut egui::Ui) -> bool {
+        let button = egui::Button::new(
+            egui::RichText::new("⟳")
+                .size(self.theme.typography.label)
+                .color(self.theme.colors.accent),
+        )
+        .fill(egui::Color32::TRANSPARENT)
+        .stroke(egui::Stroke::new(1.0, self.theme.colors.accent))
+        .rounding(Rounding::same(self.theme.rounding.sm));
+
+        ui.add(button)
+            .on_hover_text(self.data.translator.get("gui.refresh"))
+            .clicked()
+    }
+
+    fn render_about_button(&self, ui: &mut egui::Ui) -> bool {
+        let button = egui::Button::new(
+            egui::RichText::new("ℹ")
+                .size(self.theme.typography.label)
+                .color(self.theme.colors.accent),
+        )
+        .fill(egui::Color32::TRANSPARENT)
+        .stroke(egui::Stroke::new(1.0, self.theme.colors.accent))
+        .rounding(Rounding::same(self.theme.rounding.sm));
+
+        ui.add(button)
+            .on_hover_text(self.data.translator.get("gui.about_title"))
+            .clicked()
+    }
+
     fn render_profile_selector(&self, ui: &mut egui::Ui) -> bool {
         let config = Config::load().unwrap_or_default();
         let mut state = AppState::load();
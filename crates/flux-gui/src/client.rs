@@ -1,8 +1,5 @@
-use flux_protocol::{Request, Response};
-use interprocess::local_socket::{
-    tokio::{prelude::*, Stream},
-    GenericFilePath,
-};
+use flux_protocol::{decode, encode, socket, Request, Response};
+use interprocess::local_socket::tokio::{prelude::*, Stream};
 use std::path::PathBuf;
 use std::time::Duration;
 use thiserror::Error;
@@ -32,35 +29,23 @@ pub struct DaemonClient {
 
 impl DaemonClient {
     pub fn new() -> Self {
-        let socket_path = Self::default_socket_path();
+        let socket_path = socket::socket_path();
         Self {
             socket_path,
             timeout: DEFAULT_TIMEOUT,
         }
     }
 
-    #[cfg(unix)]
-    fn default_socket_path() -> PathBuf {
-        let uid = unsafe { libc::getuid() };
-        PathBuf::from(format!("/run/user/{}/flux.sock", uid))
-    }
-
-    #[cfg(windows)]
-    fn default_socket_path() -> PathBuf {
-        let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_else(|_| ".".to_string());
-        PathBuf::from(format!(r"{}\flux\flux.sock", local_app_data))
-    }
-
     pub async fn send(&self, request: Request) -> Result<Response, ClientError> {
         let stream = self.connect().await?;
         self.send_request(stream, request).await
     }
 
     async fn connect(&self) -> Result<Stream, ClientError> {
+        socket::check_socket_directory(&self.socket_path)
+            .map_err(|error| ClientError::Connection(error.to_string()))?;
         let connect_future = Stream::connect(
-            self.socket_path
-                .as_os_str()
-                .to_fs_name::<GenericFilePath>()
+            socket::socket_name(&self.socket_path)
                 .map_err(|error| ClientError::Connection(error.to_string()))?,
         );
 
@@ -76,8 +61,8 @@ impl DaemonClient {
         mut stream: Stream,
         request: Request,
     ) -> Result<Response, ClientError> {
-        let request_bytes = bincode::serialize(&request)
-            .map_err(|error| ClientError::Serialization(error.to_string()))?;
+        let request_bytes =
+            encode(&request).map_err(|error| ClientError::Serialization(error.to_string()))?;
 
         let request_length = (request_bytes.len() as u32).to_le_bytes();
 
@@ -108,8 +93,7 @@ impl DaemonClient {
             .map_err(|_| ClientError::Timeout)?
             .map_err(|error| ClientError::InputOutput(error.to_string()))?;
 
-        bincode::deserialize(&response_bytes)
-            .map_err(|error| ClientError::Serialization(error.to_string()))
+        decode(&response_bytes).map_err(|error| ClientError::Serialization(error.to_string()))
     }
 }
 
@@ -1,16 +1,25 @@
 use std::collections::HashMap;
+use std::fs;
 use std::path::PathBuf;
+use std::time::SystemTime;
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
 use flux_adapters::{
-    SqliteAppTrackingRepository, SqliteSessionMetricsRepository, SqliteSessionRepository,
+    Database, SqliteAchievementRepository, SqliteAppTrackingRepository,
+    SqliteSessionMetricsRepository, SqliteSessionNoteRepository, SqliteSessionRepository,
 };
 use flux_core::{
-    AppTrackingRepository, AppUsage, Config, DistractionConfig, Session, SessionId, SessionMetrics,
-    SessionMetricsRepository, SessionRepository, Translator,
+    AchievementRepository, AppState, AppTrackingRepository, AppUsage, AppUsageSegment,
+    CompletionBreakdown, Config, ConfigWriter, DailyAppUsage, DailyTarget, DiffLine,
+    DistractionConfig, FocusMode, GoalsConfig, PrivacyConfig, ScoreBreakdown, ScoreContribution,
+    ScoreWeights, Session, SessionId, SessionMetrics, SessionMetricsRepository, SessionNote,
+    SessionNoteRepository, SessionRepository, Streak, SuggestionReport, Translator,
+    UnlockedAchievement, UsageRollupRepository, WeekStart,
 };
 
+const RECENT_CUSTOM_MODES: usize = 5;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Period {
     Today,
@@ -39,17 +48,21 @@ pub struct Stats {
     pub total_seconds: i64,
     pub session_count: usize,
     pub by_mode: HashMap<String, i64>,
+    pub by_project: HashMap<String, i64>,
     pub focus_applications: HashMap<String, i64>,
     pub distraction_applications: HashMap<String, i64>,
     pub total_distraction_seconds: i64,
     pub total_check_ins: i32,
     pub average_focus_score: Option<u8>,
+    pub average_score_breakdown: Vec<ScoreContribution>,
     pub total_context_switches: u32,
     pub total_short_bursts: u32,
     pub sessions_with_metrics: usize,
+    pub average_active_input_percent: Option<u8>,
     pub short_bursts_by_app: HashMap<String, u32>,
     pub focus_title_breakdown: HashMap<String, HashMap<String, i64>>,
     pub distraction_title_breakdown: HashMap<String, HashMap<String, i64>>,
+    pub completion: CompletionBreakdown,
 }
 
 #[derive(Debug, Clone)]
@@ -59,14 +72,37 @@ pub struct DailyFocus {
     pub session_count: usize,
 }
 
+/// A configuration edit waiting for the user to confirm the TOML diff before
+/// it is written to disk
+pub struct PendingConfigChange {
+    pub diff: Vec<DiffLine>,
+    writer: ConfigWriter,
+    distraction_config: DistractionConfig,
+    remaining_suggestions: Option<SuggestionReport>,
+}
+
+impl PendingConfigChange {
+    pub fn has_changes(&self) -> bool {
+        self.writer.has_changes()
+    }
+}
+
 #[derive(Debug)]
 pub struct StatsData {
     pub translator: Translator,
     pub sessions: Vec<Session>,
     pub app_usages: Vec<AppUsage>,
     pub session_metrics: Vec<SessionMetrics>,
+    pub session_notes: Vec<SessionNote>,
+    pub daily_rollups: Vec<DailyAppUsage>,
+    pub achievements: Vec<UnlockedAchievement>,
     pub distraction_config: DistractionConfig,
-    database_path: Option<PathBuf>,
+    pub suggestions: SuggestionReport,
+    pub score_weights: ScoreWeights,
+    goals: GoalsConfig,
+    week_start: WeekStart,
+    profile_name: String,
+    database: Option<Database>,
 }
 
 impl StatsData {
@@ -88,11 +124,12 @@ impl StatsData {
             &filtered_usages,
             &filtered_metrics,
             &self.distraction_config,
+            &self.score_weights,
         )
     }
 
     pub fn sessions_for_period(&self, period: Period) -> Vec<&Session> {
-        let since = period_start(period);
+        let since = period_start(period, self.week_start);
         self.sessions
             .iter()
             .filter(|session| session.started_at >= since)
@@ -100,18 +137,10 @@ impl StatsData {
     }
 
     pub fn daily_focus_for_period(&self, period: Period) -> Vec<DailyFocus> {
-        let sessions = self.sessions_for_period(period);
-
-        let mut by_day: HashMap<NaiveDate, (i64, usize)> = HashMap::new();
+        let by_day = focus_by_day(self.sessions_for_period(period));
 
-        for session in sessions {
-            let local_date: DateTime<Local> = session.started_at.into();
-            let date = local_date.date_naive();
-            let seconds = session.duration_seconds.unwrap_or(0);
-
-            let entry = by_day.entry(date).or_insert((0, 0));
-            entry.0 += seconds;
-            entry.1 += 1;
+        if period == Period::Week && !by_day.is_empty() {
+            return week_axis(self.week_start.week_of(Local::now().date_naive()), &by_day);
         }
 
         let mut daily: Vec<DailyFocus> = by_day
@@ -127,18 +156,61 @@ impl StatsData {
         daily
     }
 
+    /// Focus per day of the period before the selected one, on the same day
+    /// axis, so the chart can draw both. Only weeks have a fixed axis.
+    pub fn previous_daily_focus_for_period(&self, period: Period) -> Option<Vec<DailyFocus>> {
+        if period != Period::Week {
+            return None;
+        }
+        let current_week = self.week_start.week_of(Local::now().date_naive());
+        Some(week_before(&self.sessions, current_week))
+    }
+
+    /// Focus per day over the past year, taken from the usage rollups where they exist
+    pub fn heatmap_days(&self) -> Vec<DailyFocus> {
+        with_rollup_minutes(
+            self.daily_focus_for_period(Period::All),
+            &self.daily_rollups,
+        )
+    }
+
+    pub fn streak(&self) -> Streak {
+        Streak::from_sessions(&self.sessions, Local::now().date_naive())
+    }
+
+    pub fn daily_target(&self) -> Option<DailyTarget> {
+        self.goals
+            .daily_target(self.week_start, &self.sessions, Local::now().date_naive())
+    }
+
+    pub fn recent_custom_modes(&self) -> Vec<String> {
+        recent_custom_modes(&self.sessions, RECENT_CUSTOM_MODES)
+    }
+
+    pub fn week_start(&self) -> WeekStart {
+        self.week_start
+    }
+
     pub fn has_sessions(&self) -> bool {
         !self.sessions.is_empty()
     }
 
-    pub fn delete_session(&mut self, id: SessionId) -> Result<()> {
-        let database_path = self
-            .database_path
-            .as_ref()
-            .context("database path not configured")?;
+    pub fn note_for(&self, id: SessionId) -> Option<&SessionNote> {
+        self.session_notes.iter().find(|note| note.session_id == id)
+    }
 
-        let repository = SqliteSessionRepository::new(database_path)
-            .map_err(|error| anyhow::anyhow!("database access error: {}", error))?;
+    pub fn session_segments(&self, id: SessionId) -> Result<Vec<AppUsageSegment>> {
+        let database = self.database.clone().context("database not opened")?;
+        let repository = SqliteAppTrackingRepository::with_database(database);
+
+        repository
+            .find_segments_by_session(id)
+            .map_err(|error| anyhow::anyhow!("read error: {}", error))
+    }
+
+    pub fn delete_session(&mut self, id: SessionId) -> Result<()> {
+        let database = self.database.clone().context("database not opened")?;
+        let repository = SqliteSessionRepository::with_database(database);
 
         repository
             .delete_session(id)
@@ -148,14 +220,46 @@ impl StatsData {
         Ok(())
     }
 
-    pub fn clear_sessions(&mut self) -> Result<u32> {
-        let database_path = self
-            .database_path
-            .as_ref()
-            .context("database path not configured")?;
+    /// Stores the note and tags edited in the session detail view; a blank
+    /// note removes the existing one
+    pub fn save_annotations(&mut self, id: SessionId, note: &str, tags: Vec<String>) -> Result<()> {
+        let database = self.database.clone().context("database not opened")?;
+        let session = self
+            .sessions
+            .iter()
+            .find(|session| session.id == Some(id))
+            .cloned()
+            .context("session not found")?
+            .with_tags(tags);
+
+        SqliteSessionRepository::with_database(database.clone())
+            .update(&session)
+            .map_err(|error| anyhow::anyhow!("update error: {}", error))?;
+
+        let notes = SqliteSessionNoteRepository::with_database(database);
+        let note = SessionNote::new(id, note);
+        match &note {
+            Some(note) => notes.save(note),
+            None => notes.delete(id),
+        }
+        .map_err(|error| anyhow::anyhow!("note error: {}", error))?;
+
+        self.session_notes
+            .retain(|existing| existing.session_id != id);
+        self.session_notes.extend(note);
+        if let Some(existing) = self
+            .sessions
+            .iter_mut()
+            .find(|existing| existing.id == Some(id))
+        {
+            *existing = session;
+        }
+        Ok(())
+    }
 
-        let repository = SqliteSessionRepository::new(database_path)
-            .map_err(|error| anyhow::anyhow!("database access error: {}", error))?;
+    pub fn clear_sessions(&mut self) -> Result<u32> {
+        let database = self.database.clone().context("database not opened")?;
+        let repository = SqliteSessionRepository::with_database(database);
 
         let count = repository
             .clear_completed_sessions()
@@ -166,88 +270,184 @@ impl StatsData {
     }
 
     pub fn reload(&mut self) -> Result<()> {
-        let (sessions, database_path) = load_all_sessions()?;
+        let config = Config::load().unwrap_or_default();
+        let (sessions, database) = load_all_sessions(&config.privacy)?;
         let session_ids: Vec<i64> = sessions.iter().filter_map(|s| s.id).collect();
-        let app_usages = load_app_usages(&session_ids, database_path.as_ref());
-        let session_metrics = load_session_metrics(&session_ids, database_path.as_ref());
+        let app_usages = load_app_usages(&session_ids, database.as_ref());
+        let session_metrics = load_session_metrics(&session_ids, database.as_ref());
+        let session_notes = load_session_notes(&session_ids, database.as_ref());
+        let daily_rollups = load_daily_rollups(database.as_ref());
+        let achievements = load_achievements(database.as_ref());
 
         self.sessions = sessions;
         self.app_usages = app_usages;
         self.session_metrics = session_metrics;
-        self.database_path = database_path;
+        self.session_notes = session_notes;
+        self.daily_rollups = daily_rollups;
+        self.achievements = achievements;
+        self.database = database;
 
-        let config = Config::load().unwrap_or_default();
         self.distraction_config = config.distractions().clone();
+        self.suggestions = SuggestionReport::load().unwrap_or_default();
+        self.score_weights = config.metrics.score_weights();
+        self.goals = config.goals;
+        self.week_start = config.general.week_starts_on;
 
         Ok(())
     }
 
-    pub fn toggle_distraction(&mut self, app_name: &str) -> Result<bool> {
-        let is_distraction = self.distraction_config.is_distraction(app_name);
+    pub fn toggle_distraction(&self, app_name: &str) -> Result<PendingConfigChange> {
+        let mut distraction_config = self.distraction_config.clone();
+        if distraction_config.is_distraction(app_name) {
+            distraction_config.remove_app(app_name);
+        } else {
+            distraction_config.add_app(app_name);
+        }
 
-        if is_distraction {
-            self.distraction_config.remove_app(app_name);
+        self.prepare_config_change(distraction_config, None)
+    }
+
+    pub fn toggle_whitelist(&self, app_name: &str) -> Result<PendingConfigChange> {
+        let mut distraction_config = self.distraction_config.clone();
+        if distraction_config.is_whitelisted(app_name) {
+            distraction_config.remove_from_whitelist(app_name);
         } else {
-            self.distraction_config.add_app(app_name);
+            distraction_config.add_to_whitelist(app_name);
         }
 
-        self.distraction_config
-            .save()
-            .context("impossible de sauvegarder la configuration")?;
+        self.prepare_config_change(distraction_config, None)
+    }
+
+    pub fn accept_suggestion(&self, position: usize) -> Result<Option<PendingConfigChange>> {
+        let mut suggestions = self.suggestions.clone();
+        let Some(suggestion) = suggestions.take(position) else {
+            return Ok(None);
+        };
 
-        Ok(!is_distraction)
+        let mut distraction_config = self.distraction_config.clone();
+        distraction_config.accept_suggestion(&suggestion);
+        self.prepare_config_change(distraction_config, Some(suggestions))
+            .map(Some)
     }
 
-    pub fn toggle_whitelist(&mut self, app_name: &str) -> Result<bool> {
-        let is_whitelisted = self.distraction_config.is_whitelisted(app_name);
+    /// Writes a change previewed by `toggle_distraction`, `toggle_whitelist` or
+    /// `accept_suggestion` once the user confirmed it
+    pub fn apply_config_change(&mut self, change: PendingConfigChange) -> Result<()> {
+        if change.writer.has_changes() {
+            change
+                .writer
+                .save()
+                .context("impossible de sauvegarder la configuration")?;
+        }
+        self.distraction_config = change.distraction_config;
 
-        if is_whitelisted {
-            self.distraction_config.remove_from_whitelist(app_name);
-        } else {
-            self.distraction_config.add_to_whitelist(app_name);
+        if let Some(suggestions) = change.remaining_suggestions {
+            self.suggestions = suggestions;
+            self.suggestions
+                .store()
+                .context("impossible de sauvegarder les suggestions")?;
         }
 
-        self.distraction_config
-            .save()
-            .context("impossible de sauvegarder la configuration")?;
+        Ok(())
+    }
 
-        Ok(!is_whitelisted)
+    fn prepare_config_change(
+        &self,
+        distraction_config: DistractionConfig,
+        remaining_suggestions: Option<SuggestionReport>,
+    ) -> Result<PendingConfigChange> {
+        let writer = distraction_config
+            .prepare_save(&self.profile_name)
+            .context("impossible de lire la configuration")?;
+
+        Ok(PendingConfigChange {
+            diff: writer.diff(),
+            writer,
+            distraction_config,
+            remaining_suggestions,
+        })
+    }
+
+    pub fn dismiss_suggestion(&mut self, position: usize) -> Result<()> {
+        let Some(suggestion) = self.suggestions.take(position) else {
+            return Ok(());
+        };
+
+        let mut state = AppState::load();
+        state.dismiss_suggestion(&suggestion.application_name);
+        state
+            .save()
+            .context("impossible de sauvegarder la suggestion écartée")?;
+        self.suggestions
+            .store()
+            .context("impossible de sauvegarder les suggestions")
     }
 }
 
 pub fn load_initial_data() -> Result<StatsData> {
     let config = Config::load().unwrap_or_default();
-    let translator = Translator::new(config.general.language);
+    let translator = Translator::new(config.language());
     let distraction_config = config.distractions().clone();
-    let (sessions, database_path) = load_all_sessions()?;
+    let profile_name = config.active_profile_name();
+    let (sessions, database) = load_all_sessions(&config.privacy)?;
 
     let session_ids: Vec<i64> = sessions.iter().filter_map(|s| s.id).collect();
-    let app_usages = load_app_usages(&session_ids, database_path.as_ref());
-    let session_metrics = load_session_metrics(&session_ids, database_path.as_ref());
+    let app_usages = load_app_usages(&session_ids, database.as_ref());
+    let session_metrics = load_session_metrics(&session_ids, database.as_ref());
+    let session_notes = load_session_notes(&session_ids, database.as_ref());
+    let daily_rollups = load_daily_rollups(database.as_ref());
+    let achievements = load_achievements(database.as_ref());
 
     Ok(StatsData {
         translator,
         sessions,
         app_usages,
         session_metrics,
+        session_notes,
+        daily_rollups,
+        achievements,
         distraction_config,
-        database_path,
+        suggestions: SuggestionReport::load().unwrap_or_default(),
+        score_weights: config.metrics.score_weights(),
+        goals: config.goals,
+        week_start: config.general.week_starts_on,
+        profile_name,
+        database,
     })
 }
 
-fn load_all_sessions() -> Result<(Vec<Session>, Option<PathBuf>)> {
-    let data_dir = dirs::data_dir()
-        .context("cannot find data directory")?
-        .join("flux");
+pub fn database_file() -> Option<PathBuf> {
+    dirs::data_dir().map(|directory| directory.join("flux").join("sessions.db"))
+}
 
-    let database_path = data_dir.join("sessions.db");
+/// Last write to the sessions database, including its write-ahead log
+pub fn database_modified_at() -> Option<SystemTime> {
+    let database_path = database_file()?;
+    let mut write_ahead_log = database_path.clone().into_os_string();
+    write_ahead_log.push("-wal");
+    latest_modification(&[database_path, PathBuf::from(write_ahead_log)])
+}
+
+fn latest_modification(paths: &[PathBuf]) -> Option<SystemTime> {
+    paths
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok()?.modified().ok())
+        .max()
+}
+
+/// Opens the database once for the whole dashboard; every later read or
+/// deletion goes through the same handle
+fn load_all_sessions(privacy: &PrivacyConfig) -> Result<(Vec<Session>, Option<Database>)> {
+    let database_path = database_file().context("cannot find data directory")?;
 
     if !database_path.exists() {
         return Ok((Vec::new(), None));
     }
 
-    let repository = SqliteSessionRepository::new(&database_path)
+    let database = Database::open(&database_path)
+        .and_then(|database| database.with_privacy(privacy))
         .map_err(|error| anyhow::anyhow!("database access error: {}", error))?;
+    let repository = SqliteSessionRepository::with_database(database.clone());
 
     let since = Utc::now() - Duration::days(365);
 
@@ -255,58 +455,164 @@ fn load_all_sessions() -> Result<(Vec<Session>, Option<PathBuf>)> {
         .find_completed_since(since)
         .map_err(|error| anyhow::anyhow!("read error: {}", error))?;
 
-    Ok((sessions, Some(database_path)))
+    Ok((sessions, Some(database)))
 }
 
-fn load_app_usages(session_ids: &[i64], database_path: Option<&PathBuf>) -> Vec<AppUsage> {
-    let Some(path) = database_path else {
+fn load_app_usages(session_ids: &[i64], database: Option<&Database>) -> Vec<AppUsage> {
+    let Some(database) = database else {
         return Vec::new();
     };
 
-    let repository = match SqliteAppTrackingRepository::new(path) {
-        Ok(repo) => repo,
-        Err(_) => return Vec::new(),
-    };
+    let repository = SqliteAppTrackingRepository::with_database(database.clone());
 
     repository.find_by_sessions(session_ids).unwrap_or_default()
 }
 
-fn load_session_metrics(
-    session_ids: &[i64],
-    database_path: Option<&PathBuf>,
-) -> Vec<SessionMetrics> {
-    let Some(path) = database_path else {
+fn load_session_metrics(session_ids: &[i64], database: Option<&Database>) -> Vec<SessionMetrics> {
+    let Some(database) = database else {
         return Vec::new();
     };
 
-    let repository = match SqliteSessionMetricsRepository::new(path) {
-        Ok(repo) => repo,
-        Err(_) => return Vec::new(),
+    let repository = SqliteSessionMetricsRepository::with_database(database.clone());
+
+    repository.find_by_sessions(session_ids).unwrap_or_default()
+}
+
+fn load_session_notes(session_ids: &[i64], database: Option<&Database>) -> Vec<SessionNote> {
+    let Some(database) = database else {
+        return Vec::new();
     };
 
+    let repository = SqliteSessionNoteRepository::with_database(database.clone());
+
     repository.find_by_sessions(session_ids).unwrap_or_default()
 }
 
-fn period_start(period: Period) -> DateTime<Utc> {
+fn load_daily_rollups(database: Option<&Database>) -> Vec<DailyAppUsage> {
+    let Some(database) = database else {
+        return Vec::new();
+    };
+
+    let repository = SqliteAppTrackingRepository::with_database(database.clone());
+
+    let since = Local::now().date_naive() - Duration::days(365);
+    repository.find_rollups_since(since).unwrap_or_default()
+}
+
+fn load_achievements(database: Option<&Database>) -> Vec<UnlockedAchievement> {
+    let Some(database) = database else {
+        return Vec::new();
+    };
+
+    let repository = SqliteAchievementRepository::with_database(database.clone());
+
+    repository.find_all().unwrap_or_default()
+}
+
+fn period_start(period: Period, week_start: WeekStart) -> DateTime<Utc> {
+    let today = Local::now().date_naive();
     match period {
-        Period::Today => Local::now()
-            .date_naive()
-            .and_hms_opt(0, 0, 0)
-            .unwrap()
-            .and_local_timezone(Local)
-            .unwrap()
-            .with_timezone(&Utc),
-        Period::Week => Utc::now() - Duration::days(7),
+        Period::Today => local_midnight(today),
+        Period::Week => local_midnight(week_start.week_of(today)),
         Period::Month => Utc::now() - Duration::days(30),
         Period::All => Utc::now() - Duration::days(365 * 10),
     }
 }
 
+fn recent_custom_modes(sessions: &[Session], limit: usize) -> Vec<String> {
+    let mut sessions: Vec<&Session> = sessions.iter().collect();
+    sessions.sort_by_key(|session| std::cmp::Reverse(session.started_at));
+
+    let mut modes: Vec<String> = Vec::new();
+    for session in sessions {
+        if let FocusMode::Custom(name) = &session.mode {
+            if !modes.contains(name) {
+                modes.push(name.clone());
+            }
+        }
+        if modes.len() == limit {
+            break;
+        }
+    }
+    modes
+}
+
+fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|start| start.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now)
+}
+
+fn week_axis(first_day: NaiveDate, by_day: &HashMap<NaiveDate, (i64, usize)>) -> Vec<DailyFocus> {
+    (0..7)
+        .map(|offset| {
+            let date = first_day + Duration::days(offset);
+            let (seconds, session_count) = by_day.get(&date).copied().unwrap_or((0, 0));
+            DailyFocus {
+                date,
+                minutes: seconds / 60,
+                session_count,
+            }
+        })
+        .collect()
+}
+
+fn focus_by_day<'a>(
+    sessions: impl IntoIterator<Item = &'a Session>,
+) -> HashMap<NaiveDate, (i64, usize)> {
+    let mut by_day: HashMap<NaiveDate, (i64, usize)> = HashMap::new();
+
+    for session in sessions {
+        let local_date: DateTime<Local> = session.started_at.into();
+        let date = local_date.date_naive();
+        let seconds = session.duration_seconds.unwrap_or(0);
+
+        let entry = by_day.entry(date).or_insert((0, 0));
+        entry.0 += seconds;
+        entry.1 += 1;
+    }
+    by_day
+}
+
+/// Focus per day over the seven days before `first_day`
+fn week_before(sessions: &[Session], first_day: NaiveDate) -> Vec<DailyFocus> {
+    let since = local_midnight(first_day - Duration::days(7));
+    let until = local_midnight(first_day);
+    let by_day = focus_by_day(
+        sessions
+            .iter()
+            .filter(|session| session.started_at >= since && session.started_at < until),
+    );
+    week_axis(first_day - Duration::days(7), &by_day)
+}
+
+/// Days with rollups show the tracked focus time instead of the session length
+fn with_rollup_minutes(days: Vec<DailyFocus>, rollups: &[DailyAppUsage]) -> Vec<DailyFocus> {
+    let mut focus_seconds: HashMap<NaiveDate, i64> = HashMap::new();
+    for rollup in rollups.iter().filter(|rollup| !rollup.is_distraction) {
+        *focus_seconds.entry(rollup.date).or_insert(0) += rollup.seconds;
+    }
+
+    days.into_iter()
+        .map(|day| match focus_seconds.get(&day.date) {
+            Some(seconds) => DailyFocus {
+                minutes: seconds / 60,
+                ..day
+            },
+            None => day,
+        })
+        .collect()
+}
+
 fn compute_stats(
     sessions: &[&Session],
     app_usages: &[&AppUsage],
     session_metrics: &[&SessionMetrics],
     distraction_config: &DistractionConfig,
+    score_weights: &ScoreWeights,
 ) -> Stats {
     let mut total_seconds = 0i64;
     let mut by_mode: HashMap<String, i64> = HashMap::new();
@@ -366,11 +672,19 @@ fn compute_stats(
         }
     }
 
+    let score_breakdowns: Vec<ScoreBreakdown> = session_metrics
+        .iter()
+        .map(|metrics| metrics.score_breakdown_with(score_weights))
+        .collect();
+
     let (average_focus_score, total_context_switches, total_short_bursts, short_bursts_by_app) =
         if session_metrics.is_empty() {
             (None, 0, 0, HashMap::new())
         } else {
-            let sum_scores: u32 = session_metrics.iter().map(|m| m.focus_score() as u32).sum();
+            let sum_scores: u32 = score_breakdowns
+                .iter()
+                .map(|breakdown| breakdown.score as u32)
+                .sum();
             let average = (sum_scores / session_metrics.len() as u32) as u8;
             let switches: u32 = session_metrics.iter().map(|m| m.context_switch_count).sum();
             let bursts: u32 = session_metrics.iter().map(|m| m.total_short_bursts).sum();
@@ -385,21 +699,42 @@ fn compute_stats(
             (Some(average), switches, bursts, aggregated_bursts)
         };
 
+    let average_score_breakdown = ScoreBreakdown::average_contributions(&score_breakdowns);
+
+    let input_ratios: Vec<f64> = session_metrics
+        .iter()
+        .filter_map(|metrics| metrics.active_input_ratio)
+        .collect();
+    let average_active_input_percent = (!input_ratios.is_empty()).then(|| {
+        (input_ratios.iter().sum::<f64>() / input_ratios.len() as f64 * 100.0).round() as u8
+    });
+
+    let mut by_project: HashMap<String, i64> = HashMap::new();
+    for metrics in session_metrics {
+        for (project, seconds) in &metrics.project_seconds {
+            *by_project.entry(project.clone()).or_insert(0) += seconds;
+        }
+    }
+
     Stats {
         total_seconds,
         session_count: sessions.len(),
         by_mode,
+        by_project,
         focus_applications,
         distraction_applications,
         total_distraction_seconds,
         total_check_ins,
         average_focus_score,
+        average_score_breakdown,
         total_context_switches,
         total_short_bursts,
         sessions_with_metrics: session_metrics.len(),
+        average_active_input_percent,
         short_bursts_by_app,
         focus_title_breakdown,
         distraction_title_breakdown,
+        completion: CompletionBreakdown::from_sessions(sessions.iter().copied()),
     }
 }
 
@@ -424,6 +759,23 @@ mod tests {
         assert_eq!(format_duration(7200), "2h 00min");
     }
 
+    #[test]
+    fn latest_modification_ignores_missing_files() {
+        let directory = std::env::temp_dir().join(format!("flux-gui-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let database = directory.join("sessions.db");
+        fs::write(&database, "sessions").unwrap();
+
+        let modified_at = latest_modification(&[database.clone(), directory.join("missing")]);
+
+        assert_eq!(
+            modified_at,
+            fs::metadata(&database).unwrap().modified().ok()
+        );
+        assert_eq!(latest_modification(&[directory.join("missing")]), None);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
     #[test]
     fn format_duration_shows_only_minutes_when_under_hour() {
         assert_eq!(format_duration(1500), "25min");
@@ -431,6 +783,104 @@ mod tests {
         assert_eq!(format_duration(0), "0min");
     }
 
+    #[test]
+    fn week_axis_lists_seven_days_from_the_configured_start() {
+        let sunday = NaiveDate::from_ymd_opt(2026, 10, 11).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2026, 10, 13).unwrap();
+        let by_day = HashMap::from([(tuesday, (1800, 2))]);
+
+        let days = week_axis(sunday, &by_day);
+
+        assert_eq!(days.len(), 7);
+        assert_eq!(days[0].date, sunday);
+        assert_eq!(days[0].minutes, 0);
+        assert_eq!(days[2].date, tuesday);
+        assert_eq!(days[2].minutes, 30);
+        assert_eq!(days[2].session_count, 2);
+        assert_eq!(days[6].date, NaiveDate::from_ymd_opt(2026, 10, 17).unwrap());
+    }
+
+    #[test]
+    fn the_previous_week_keeps_only_its_own_sessions() {
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        let session = |date: NaiveDate, hour: u32, minutes: i64| {
+            let mut session = Session::start(FocusMode::Review);
+            session.started_at = date
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+                .and_local_timezone(Local)
+                .unwrap()
+                .with_timezone(&Utc);
+            session.duration_seconds = Some(minutes * 60);
+            session
+        };
+        let sessions = vec![
+            session(monday - Duration::days(7), 9, 45),
+            session(monday - Duration::days(1), 23, 30),
+            session(monday, 9, 60),
+            session(monday - Duration::days(8), 9, 90),
+        ];
+
+        let days = week_before(&sessions, monday);
+
+        assert_eq!(days.len(), 7);
+        assert_eq!(days[0].date, monday - Duration::days(7));
+        assert_eq!(days[0].minutes, 45);
+        assert_eq!(days[6].minutes, 30);
+        assert_eq!(days.iter().map(|day| day.session_count).sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn rollups_replace_session_length_on_the_days_they_cover() {
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2026, 10, 13).unwrap();
+        let day = |date, minutes| DailyFocus {
+            date,
+            minutes,
+            session_count: 1,
+        };
+        let rollup = |application_name: &str, seconds, is_distraction| DailyAppUsage {
+            date: tuesday,
+            application_name: application_name.to_string(),
+            seconds,
+            is_distraction,
+        };
+
+        let days = with_rollup_minutes(
+            vec![day(monday, 25), day(tuesday, 50)],
+            &[
+                rollup("cursor", 1800, false),
+                rollup("zed", 600, false),
+                rollup("discord", 900, true),
+            ],
+        );
+
+        assert_eq!(days[0].minutes, 25);
+        assert_eq!(days[1].minutes, 40);
+        assert_eq!(days[1].session_count, 1);
+    }
+
+    #[test]
+    fn recent_custom_modes_are_unique_and_most_recent_first() {
+        let session = |mode: FocusMode, days_ago: i64| {
+            let mut session = Session::start(mode);
+            session.started_at -= Duration::days(days_ago);
+            session
+        };
+        let sessions = vec![
+            session(FocusMode::Custom("writing".to_string()), 3),
+            session(FocusMode::Custom("deep-work".to_string()), 1),
+            session(FocusMode::Review, 0),
+            session(FocusMode::Custom("writing".to_string()), 2),
+            session(FocusMode::Custom("oncall".to_string()), 5),
+        ];
+
+        assert_eq!(
+            recent_custom_modes(&sessions, 2),
+            vec!["deep-work".to_string(), "writing".to_string()]
+        );
+    }
+
     #[test]
     fn stats_default_is_empty() {
         let stats = Stats::default();
@@ -444,6 +894,7 @@ mod tests {
         assert_eq!(stats.total_context_switches, 0);
         assert_eq!(stats.total_short_bursts, 0);
         assert_eq!(stats.sessions_with_metrics, 0);
+        assert!(stats.average_active_input_percent.is_none());
         assert!(stats.short_bursts_by_app.is_empty());
         assert!(stats.focus_title_breakdown.is_empty());
         assert!(stats.distraction_title_breakdown.is_empty());
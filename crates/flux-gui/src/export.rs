@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use flux_core::{ScoreWeights, Session, SessionId, SessionMetrics, SessionNote};
+
+const CSV_HEADER: &str = "id,date,mode,duration_seconds,check_ins,focus_score,tags,note";
+
+pub fn sessions_to_csv(
+    sessions: &[&Session],
+    session_metrics: &[SessionMetrics],
+    session_notes: &[SessionNote],
+    score_weights: &ScoreWeights,
+) -> String {
+    let scores: HashMap<SessionId, u8> = session_metrics
+        .iter()
+        .map(|metrics| {
+            (
+                metrics.session_id,
+                metrics.score_breakdown_with(score_weights).score,
+            )
+        })
+        .collect();
+    let notes: HashMap<SessionId, &str> = session_notes
+        .iter()
+        .map(|note| (note.session_id, note.text.as_str()))
+        .collect();
+
+    let mut sorted_sessions: Vec<_> = sessions.to_vec();
+    sorted_sessions.sort_by_key(|session| std::cmp::Reverse(session.started_at));
+
+    let mut lines = vec![CSV_HEADER.to_string()];
+
+    for session in sorted_sessions {
+        let local_date: DateTime<Local> = session.started_at.into();
+        let focus_score = session
+            .id
+            .and_then(|id| scores.get(&id))
+            .map(|score| score.to_string())
+            .unwrap_or_default();
+        let note = session
+            .id
+            .and_then(|id| notes.get(&id))
+            .map(|text| text.to_string())
+            .unwrap_or_default();
+
+        let fields = [
+            session.id.map(|id| id.to_string()).unwrap_or_default(),
+            local_date.format("%Y-%m-%d %H:%M").to_string(),
+            session.mode.to_string(),
+            session.duration_seconds.unwrap_or(0).to_string(),
+            session.check_in_count.to_string(),
+            focus_score,
+            session.tags.join(";"),
+            note,
+        ];
+
+        lines.push(
+            fields
+                .iter()
+                .map(|field| escape_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+pub fn write_sessions_csv(
+    sessions: &[&Session],
+    session_metrics: &[SessionMetrics],
+    session_notes: &[SessionNote],
+    score_weights: &ScoreWeights,
+) -> Result<PathBuf> {
+    let directory = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .context("cannot determine export directory")?;
+
+    let path = export_path(&directory, Utc::now());
+    std::fs::write(
+        &path,
+        sessions_to_csv(sessions, session_metrics, session_notes, score_weights),
+    )
+    .with_context(|| format!("cannot write {}", path.display()))?;
+
+    Ok(path)
+}
+
+fn export_path(directory: &Path, now: DateTime<Utc>) -> PathBuf {
+    let local: DateTime<Local> = now.into();
+    directory.join(format!(
+        "flux-sessions-{}.csv",
+        local.format("%Y%m%d-%H%M%S")
+    ))
+}
+
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use flux_core::FocusMode;
+
+    fn create_test_session(id: SessionId, mode: FocusMode, hours_ago: i64) -> Session {
+        let mut session = Session::start(mode);
+        session.id = Some(id);
+        session.started_at = Utc::now() - Duration::hours(hours_ago);
+        session.duration_seconds = Some(1500);
+        session.check_in_count = 2;
+        session
+    }
+
+    #[test]
+    fn csv_contains_header_and_one_row_per_session() {
+        let first = create_test_session(1, FocusMode::AiAssisted, 2);
+        let second = create_test_session(2, FocusMode::Review, 1);
+
+        let csv = sessions_to_csv(&[&first, &second], &[], &[], &ScoreWeights::default());
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert!(lines[1].starts_with("2,"));
+        assert!(lines[1].contains(",review,1500,2,,"));
+        assert!(lines[2].starts_with("1,"));
+    }
+
+    #[test]
+    fn csv_includes_focus_score_when_metrics_exist() {
+        let session = create_test_session(7, FocusMode::Architecture, 1);
+        let metrics = SessionMetrics::new(7, 5, HashMap::new());
+
+        let csv = sessions_to_csv(&[&session], &[metrics], &[], &ScoreWeights::default());
+
+        assert!(csv.lines().nth(1).unwrap().ends_with(",90,,"));
+    }
+
+    #[test]
+    fn csv_includes_session_tags() {
+        let session = create_test_session(3, FocusMode::Review, 1)
+            .with_tags(vec!["clientX".to_string(), "backend".to_string()]);
+
+        let csv = sessions_to_csv(&[&session], &[], &[], &ScoreWeights::default());
+
+        assert!(csv.lines().nth(1).unwrap().ends_with(",backend;clientx,"));
+    }
+
+    #[test]
+    fn csv_includes_session_note() {
+        let session = create_test_session(5, FocusMode::Review, 1);
+        let note = SessionNote::new(5, "merged the parser, fixed CI").unwrap();
+
+        let csv = sessions_to_csv(&[&session], &[], &[note], &ScoreWeights::default());
+
+        assert!(csv
+            .lines()
+            .nth(1)
+            .unwrap()
+            .ends_with(",\"merged the parser, fixed CI\""));
+    }
+
+    #[test]
+    fn escape_field_quotes_special_characters() {
+        assert_eq!(escape_field("plain"), "plain");
+        assert_eq!(escape_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -5,6 +5,7 @@ use tracing::info;
 mod app;
 pub mod client;
 mod data;
+mod export;
 mod theme;
 mod views;
 
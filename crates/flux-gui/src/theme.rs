@@ -1,4 +1,5 @@
 use eframe::egui::{self, Color32, Rounding, Stroke};
+use flux_core::{FocusMode, ModeColor, ModesConfig};
 
 pub struct Theme {
     pub colors: Colors,
@@ -23,6 +24,11 @@ impl Theme {
         }
     }
 
+    pub fn with_mode_colors(mut self, modes: ModesConfig) -> Self {
+        self.colors.set_mode_colors(modes);
+        self
+    }
+
     #[allow(clippy::field_reassign_with_default)]
     pub fn apply(&self, ctx: &egui::Context) {
         let mut visuals = egui::Visuals::dark();
@@ -106,11 +112,12 @@ pub struct Colors {
     pub mode_review: Color32,
     pub mode_architecture: Color32,
     pub mode_custom: Color32,
+    modes: ModesConfig,
 }
 
 impl Colors {
     pub fn dark() -> Self {
-        Self {
+        let mut colors = Self {
             background: Color32::from_rgb(15, 15, 15),
             surface: Color32::from_rgb(26, 26, 26),
             surface_elevated: Color32::from_rgb(32, 32, 32),
@@ -127,23 +134,36 @@ impl Colors {
             warning: Color32::from_rgb(245, 158, 11),
             error: Color32::from_rgb(239, 68, 68),
 
-            mode_ai_assisted: Color32::from_rgb(59, 130, 246),
-            mode_review: Color32::from_rgb(168, 85, 247),
-            mode_architecture: Color32::from_rgb(6, 182, 212),
-            mode_custom: Color32::from_rgb(16, 185, 129),
-        }
+            mode_ai_assisted: Color32::PLACEHOLDER,
+            mode_review: Color32::PLACEHOLDER,
+            mode_architecture: Color32::PLACEHOLDER,
+            mode_custom: Color32::PLACEHOLDER,
+            modes: ModesConfig::default(),
+        };
+        colors.set_mode_colors(ModesConfig::default());
+        colors
+    }
+
+    fn set_mode_colors(&mut self, modes: ModesConfig) {
+        self.mode_ai_assisted = to_color(modes.color_for(&FocusMode::AiAssisted));
+        self.mode_review = to_color(modes.color_for(&FocusMode::Review));
+        self.mode_architecture = to_color(modes.color_for(&FocusMode::Architecture));
+        self.mode_custom = to_color(modes.color_for(&FocusMode::Custom(String::new())));
+        self.modes = modes;
     }
 
     pub fn mode_color(&self, mode: &str) -> Color32 {
-        match mode.to_lowercase().as_str() {
-            "prompting" | "ai-assisted" => self.mode_ai_assisted,
-            "review" => self.mode_review,
-            "architecture" => self.mode_architecture,
-            _ => self.mode_custom,
-        }
+        to_color(
+            self.modes
+                .color_for(&FocusMode::from_stored(&mode.to_lowercase())),
+        )
     }
 }
 
+fn to_color(color: ModeColor) -> Color32 {
+    Color32::from_rgb(color.red, color.green, color.blue)
+}
+
 pub struct Spacing {
     pub xs: f32,
     pub sm: f32,
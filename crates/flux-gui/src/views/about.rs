@@ -0,0 +1,392 @@
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+use eframe::egui::{self, Rounding};
+use flux_core::{AppState, Config, Translator};
+use flux_protocol::{
+    Feature, Request, Response, SubsystemHealth, SubsystemStatus, PROTOCOL_VERSION,
+};
+
+use crate::client::DaemonClient;
+use crate::data;
+use crate::theme::Theme;
+
+const GUI_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Environment read on this machine when the dialog opens
+#[derive(Debug, Clone)]
+struct LocalDetails {
+    config_path: PathBuf,
+    database_path: Option<PathBuf>,
+    database_size: Option<u64>,
+    active_profile: String,
+}
+
+impl LocalDetails {
+    fn collect() -> Self {
+        let database_path = data::database_file();
+        let database_size = database_path
+            .as_ref()
+            .and_then(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len());
+
+        Self {
+            config_path: Config::config_path(),
+            database_path,
+            database_size,
+            active_profile: AppState::load().active_profile,
+        }
+    }
+}
+
+/// What the daemon reported through the handshake and health requests
+#[derive(Debug, Clone, Default)]
+struct DaemonDetails {
+    version: Option<String>,
+    protocol_version: Option<u32>,
+    features: Option<Vec<Feature>>,
+    subsystems: Vec<SubsystemHealth>,
+}
+
+impl DaemonDetails {
+    async fn fetch() -> Self {
+        let client = DaemonClient::new();
+        let handshake = Request::Handshake {
+            protocol_version: PROTOCOL_VERSION,
+            client_version: GUI_VERSION.to_string(),
+        };
+
+        let Ok(Response::Handshake {
+            protocol_version,
+            daemon_version,
+            features,
+            ..
+        }) = client.send(handshake).await
+        else {
+            return Self::default();
+        };
+
+        let subsystems = match client.send(Request::GetHealth).await {
+            Ok(Response::Health { subsystems, .. }) => subsystems,
+            _ => Vec::new(),
+        };
+
+        Self {
+            version: Some(daemon_version),
+            protocol_version: Some(protocol_version),
+            features: Some(features),
+            subsystems,
+        }
+    }
+}
+
+pub struct AboutDialog {
+    open: bool,
+    local: Option<LocalDetails>,
+    daemon: Option<DaemonDetails>,
+    sender: mpsc::Sender<DaemonDetails>,
+    receiver: mpsc::Receiver<DaemonDetails>,
+    runtime: tokio::runtime::Handle,
+}
+
+impl AboutDialog {
+    pub fn new(runtime: &tokio::runtime::Handle) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            local: None,
+            daemon: None,
+            sender,
+            receiver,
+            runtime: runtime.clone(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.local = Some(LocalDetails::collect());
+        self.daemon = None;
+
+        let sender = self.sender.clone();
+        self.runtime.spawn(async move {
+            let _ = sender.send(DaemonDetails::fetch().await);
+        });
+    }
+
+    pub fn poll(&mut self, context: &egui::Context) {
+        while let Ok(details) = self.receiver.try_recv() {
+            self.daemon = Some(details);
+            context.request_repaint();
+        }
+    }
+
+    pub fn render(&mut self, context: &egui::Context, translator: &Translator, theme: &Theme) {
+        let Some(local) = self.local.as_ref().filter(|_| self.open) else {
+            return;
+        };
+        let rows = environment_rows(local, self.daemon.as_ref(), translator);
+        let subsystems = self
+            .daemon
+            .as_ref()
+            .map(|daemon| subsystem_rows(&daemon.subsystems, translator))
+            .unwrap_or_default();
+
+        let mut open = self.open;
+        egui::Window::new(translator.get("gui.about_title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(context, |ui| {
+                ui.add_space(theme.spacing.sm);
+                render_rows(ui, "about_environment", &rows, theme);
+
+                if !subsystems.is_empty() {
+                    ui.add_space(theme.spacing.md);
+                    ui.label(
+                        egui::RichText::new(translator.get("gui.about_subsystems"))
+                            .size(theme.typography.body)
+                            .color(theme.colors.text_primary)
+                            .strong(),
+                    );
+                    ui.add_space(theme.spacing.sm);
+                    render_rows(ui, "about_subsystems", &subsystems, theme);
+                }
+
+                ui.add_space(theme.spacing.md);
+
+                let copy_button = egui::Button::new(
+                    egui::RichText::new(translator.get("gui.about_copy"))
+                        .size(theme.typography.label)
+                        .color(theme.colors.accent),
+                )
+                .fill(egui::Color32::TRANSPARENT)
+                .stroke(egui::Stroke::new(1.0, theme.colors.accent))
+                .rounding(Rounding::same(theme.rounding.sm));
+
+                if ui.add(copy_button).clicked() {
+                    ui.ctx().copy_text(report_text(&rows, &subsystems));
+                }
+            });
+        self.open = open;
+    }
+}
+
+fn render_rows(ui: &mut egui::Ui, id: &str, rows: &[(String, String)], theme: &Theme) {
+    egui::Grid::new(id)
+        .num_columns(2)
+        .spacing([theme.spacing.md, theme.spacing.xs])
+        .show(ui, |ui| {
+            for (label, value) in rows {
+                ui.label(
+                    egui::RichText::new(label)
+                        .size(theme.typography.label)
+                        .color(theme.colors.text_secondary),
+                );
+                ui.label(
+                    egui::RichText::new(value)
+                        .size(theme.typography.label)
+                        .color(theme.colors.text_primary),
+                );
+                ui.end_row();
+            }
+        });
+}
+
+fn environment_rows(
+    local: &LocalDetails,
+    daemon: Option<&DaemonDetails>,
+    translator: &Translator,
+) -> Vec<(String, String)> {
+    let daemon_value = |value: Option<String>| match daemon {
+        None => translator.get("gui.about_loading"),
+        Some(_) => value.unwrap_or_else(|| translator.get("gui.daemon_error")),
+    };
+    let daemon_protocol = daemon.and_then(|daemon| daemon.protocol_version);
+
+    vec![
+        (
+            translator.get("gui.about_gui_version"),
+            GUI_VERSION.to_string(),
+        ),
+        (
+            translator.get("gui.about_daemon_version"),
+            daemon_value(daemon.and_then(|daemon| daemon.version.clone())),
+        ),
+        (
+            translator.get("gui.about_protocol_version"),
+            daemon_value(daemon_protocol.map(|daemon_protocol| {
+                translator.format(
+                    "gui.about_protocol_value",
+                    &[
+                        ("gui", &PROTOCOL_VERSION.to_string()),
+                        ("daemon", &daemon_protocol.to_string()),
+                    ],
+                )
+            })),
+        ),
+        (
+            translator.get("gui.about_features"),
+            daemon_value(
+                daemon
+                    .and_then(|daemon| daemon.features.as_deref())
+                    .map(|features| feature_names(features, translator)),
+            ),
+        ),
+        (
+            translator.get("gui.about_config_path"),
+            local.config_path.display().to_string(),
+        ),
+        (
+            translator.get("gui.about_database_path"),
+            local
+                .database_path
+                .as_ref()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| translator.get("gui.about_unknown")),
+        ),
+        (
+            translator.get("gui.about_database_size"),
+            local
+                .database_size
+                .map(format_size)
+                .unwrap_or_else(|| translator.get("gui.about_unknown")),
+        ),
+        (
+            translator.get("gui.about_active_profile"),
+            local.active_profile.clone(),
+        ),
+    ]
+}
+
+fn feature_names(features: &[Feature], translator: &Translator) -> String {
+    if features.is_empty() {
+        return translator.get("feature.none");
+    }
+    features
+        .iter()
+        .map(|feature| translator.get(&format!("feature.{}", feature.id())))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn subsystem_rows(
+    subsystems: &[SubsystemHealth],
+    translator: &Translator,
+) -> Vec<(String, String)> {
+    subsystems
+        .iter()
+        .map(|health| {
+            let status = match &health.status {
+                SubsystemStatus::Running => translator.get("health.status_running"),
+                SubsystemStatus::Disabled => translator.get("health.status_disabled"),
+                SubsystemStatus::Failed { reason } => match reason {
+                    Some(reason) => {
+                        format!("{} ({})", translator.get("health.status_failed"), reason)
+                    }
+                    None => translator.get("health.status_failed"),
+                },
+                SubsystemStatus::Degraded { restarts, .. } => translator.format(
+                    "health.status_degraded",
+                    &[("restarts", &restarts.to_string())],
+                ),
+            };
+            (
+                translator.get(&format!("health.{}_name", health.subsystem.id())),
+                status,
+            )
+        })
+        .collect()
+}
+
+/// Plain text version of the dialog, ready to paste into a bug report
+fn report_text(rows: &[(String, String)], subsystems: &[(String, String)]) -> String {
+    rows.iter()
+        .chain(subsystems)
+        .map(|(label, value)| format!("{}: {}\n", label, value))
+        .collect()
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::Language;
+    use flux_protocol::Subsystem;
+
+    fn local_details() -> LocalDetails {
+        LocalDetails {
+            config_path: PathBuf::from("/home/dev/.config/flux/config.toml"),
+            database_path: Some(PathBuf::from("/home/dev/.local/share/flux/sessions.db")),
+            database_size: Some(3 * 1024 * 1024),
+            active_profile: "deep_work".to_string(),
+        }
+    }
+
+    #[test]
+    fn report_lists_versions_paths_and_subsystems() {
+        let translator = Translator::new(Language::En);
+        let daemon = DaemonDetails {
+            version: Some("0.3.0".to_string()),
+            protocol_version: Some(PROTOCOL_VERSION),
+            features: Some(vec![Feature::History, Feature::AppTracking]),
+            subsystems: vec![SubsystemHealth::new(
+                Subsystem::Tray,
+                SubsystemStatus::Degraded {
+                    restarts: 2,
+                    reason: None,
+                },
+            )],
+        };
+
+        let rows = environment_rows(&local_details(), Some(&daemon), &translator);
+        let report = report_text(&rows, &subsystem_rows(&daemon.subsystems, &translator));
+
+        assert!(report.contains(GUI_VERSION));
+        assert!(report.contains("0.3.0"));
+        assert!(report.contains("/home/dev/.local/share/flux/sessions.db"));
+        assert!(report.contains("3.0 MB"));
+        assert!(report.contains("deep_work"));
+        assert!(report.contains("Session history, Application tracking"));
+        assert!(report.contains("restarted 2 time(s)"));
+    }
+
+    #[test]
+    fn unreachable_daemon_is_reported_instead_of_its_versions() {
+        let translator = Translator::new(Language::En);
+
+        let rows = environment_rows(
+            &local_details(),
+            Some(&DaemonDetails::default()),
+            &translator,
+        );
+
+        assert_eq!(rows[1].1, translator.get("gui.daemon_error"));
+        assert_eq!(rows[2].1, translator.get("gui.daemon_error"));
+        assert_eq!(rows[3].1, translator.get("gui.daemon_error"));
+    }
+
+    #[test]
+    fn sizes_use_binary_units() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * 1024 * 1024 * 1024), "5.0 GB");
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Local};
+use eframe::egui::{self, Ui};
+use flux_core::{Achievement, Translator, UnlockedAchievement};
+
+use crate::theme::Theme;
+
+pub fn render_achievements(
+    ui: &mut Ui,
+    unlocked: &[UnlockedAchievement],
+    translator: &Translator,
+    theme: &Theme,
+) {
+    theme.card_frame().show(ui, |ui| {
+        ui.set_min_width(ui.available_width());
+
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(translator.get("gui.achievements_title"))
+                    .size(theme.typography.title)
+                    .color(theme.colors.text_primary)
+                    .strong(),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.label(
+                    egui::RichText::new(translator.format(
+                        "gui.achievements_progress",
+                        &[
+                            ("unlocked", &unlocked.len().to_string()),
+                            ("total", &Achievement::all().len().to_string()),
+                        ],
+                    ))
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_secondary),
+                );
+            });
+        });
+
+        ui.add_space(theme.spacing.md);
+
+        for achievement in Achievement::all() {
+            let unlocked_entry = unlocked
+                .iter()
+                .find(|entry| entry.achievement == *achievement);
+            render_badge(ui, *achievement, unlocked_entry, translator, theme);
+            ui.add_space(theme.spacing.sm);
+        }
+    });
+}
+
+fn render_badge(
+    ui: &mut Ui,
+    achievement: Achievement,
+    unlocked: Option<&UnlockedAchievement>,
+    translator: &Translator,
+    theme: &Theme,
+) {
+    let (icon, name_color) = match unlocked {
+        Some(_) => ("🏆", theme.colors.text_primary),
+        None => ("🔒", theme.colors.text_muted),
+    };
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(icon).size(theme.typography.title));
+
+        ui.vertical(|ui| {
+            ui.label(
+                egui::RichText::new(
+                    translator.get(&format!("achievement.{}_name", achievement.id())),
+                )
+                .size(theme.typography.body)
+                .color(name_color)
+                .strong(),
+            );
+            ui.label(
+                egui::RichText::new(
+                    translator.get(&format!("achievement.{}_description", achievement.id())),
+                )
+                .size(theme.typography.label)
+                .color(theme.colors.text_secondary),
+            );
+        });
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let status = match unlocked {
+                Some(entry) => {
+                    let local: DateTime<Local> = entry.unlocked_at.into();
+                    translator.format(
+                        "gui.achievements_unlocked_on",
+                        &[("date", &local.format("%Y-%m-%d").to_string())],
+                    )
+                }
+                None => translator.get("gui.achievements_locked"),
+            };
+            ui.label(
+                egui::RichText::new(status)
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_muted),
+            );
+        });
+    });
+}
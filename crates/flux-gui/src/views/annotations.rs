@@ -0,0 +1,140 @@
+use eframe::egui::{self, Rounding, Ui};
+use flux_core::{Session, SessionNote, Translator};
+
+use crate::theme::Theme;
+
+/// Note and tags of the opened session as typed in the detail view, kept
+/// until the user saves them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnnotationDraft {
+    pub note: String,
+    pub tags: String,
+}
+
+impl AnnotationDraft {
+    pub fn new(session: &Session, note: Option<&SessionNote>) -> Self {
+        Self {
+            note: note.map(|note| note.text.clone()).unwrap_or_default(),
+            tags: session.tags.join(", "),
+        }
+    }
+
+    /// Tags are typed comma-separated; the session normalizes them on save
+    pub fn tags(&self) -> Vec<String> {
+        self.tags.split(',').map(str::to_string).collect()
+    }
+}
+
+/// Returns true when the user asks to save the draft
+pub fn render_annotation_editor(
+    ui: &mut Ui,
+    draft: &mut AnnotationDraft,
+    saved: &AnnotationDraft,
+    status: Option<&str>,
+    translator: &Translator,
+    theme: &Theme,
+) -> bool {
+    let mut save_clicked = false;
+
+    theme.card_frame().show(ui, |ui| {
+        ui.set_min_width(ui.available_width());
+
+        ui.label(
+            egui::RichText::new(translator.get("gui.annotations_title"))
+                .size(theme.typography.body)
+                .color(theme.colors.text_primary)
+                .strong(),
+        );
+        ui.add_space(theme.spacing.sm);
+
+        render_field(
+            ui,
+            &translator.get("gui.annotations_note"),
+            &mut draft.note,
+            &translator.get("gui.annotations_note_hint"),
+            theme,
+        );
+        ui.add_space(theme.spacing.xs);
+        render_field(
+            ui,
+            &translator.get("gui.annotations_tags"),
+            &mut draft.tags,
+            &translator.get("gui.annotations_tags_hint"),
+            theme,
+        );
+        ui.add_space(theme.spacing.sm);
+
+        ui.horizontal(|ui| {
+            let save_button = egui::Button::new(
+                egui::RichText::new(translator.get("gui.annotations_save"))
+                    .size(theme.typography.label)
+                    .color(theme.colors.accent),
+            )
+            .fill(egui::Color32::TRANSPARENT)
+            .stroke(egui::Stroke::new(1.0, theme.colors.accent))
+            .rounding(Rounding::same(theme.rounding.sm));
+
+            save_clicked = ui.add_enabled(draft != saved, save_button).clicked();
+
+            if let Some(status) = status {
+                ui.label(
+                    egui::RichText::new(status)
+                        .size(theme.typography.label)
+                        .color(theme.colors.text_muted),
+                );
+            }
+        });
+    });
+
+    save_clicked
+}
+
+fn render_field(ui: &mut Ui, label: &str, value: &mut String, hint: &str, theme: &Theme) {
+    ui.horizontal(|ui| {
+        ui.add_sized(
+            [80.0, ui.spacing().interact_size.y],
+            egui::Label::new(
+                egui::RichText::new(label)
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_secondary),
+            ),
+        );
+        ui.add(
+            egui::TextEdit::singleline(value)
+                .hint_text(hint)
+                .desired_width(ui.available_width())
+                .font(egui::TextStyle::Body),
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::FocusMode;
+
+    #[test]
+    fn draft_starts_from_the_stored_note_and_tags() {
+        let session = Session::start(FocusMode::Review)
+            .with_tags(vec!["spike".to_string(), "clientx".to_string()]);
+        let note = SessionNote::new(1, "shipped the parser").unwrap();
+
+        let draft = AnnotationDraft::new(&session, Some(&note));
+
+        assert_eq!(draft.note, "shipped the parser");
+        assert_eq!(draft.tags, "clientx, spike");
+        assert_eq!(AnnotationDraft::new(&session, None).note, "");
+    }
+
+    #[test]
+    fn typed_tags_round_trip_through_the_session() {
+        let draft = AnnotationDraft {
+            note: String::new(),
+            tags: " Spike,clientx, ,spike".to_string(),
+        };
+
+        let session = Session::start(FocusMode::Review).with_tags(draft.tags());
+
+        assert_eq!(session.tags, vec!["clientx", "spike"]);
+    }
+}
@@ -1,16 +1,27 @@
 use chrono::Datelike;
 use eframe::egui::{self, Ui};
-use egui_plot::{Bar, BarChart, Plot, PlotBounds};
+use egui_plot::{Bar, BarChart, Line, LineStyle, Plot, PlotBounds, PlotPoints};
 
 use crate::data::DailyFocus;
 use crate::theme::Theme;
 
-pub fn render_focus_chart(ui: &mut Ui, daily_data: &[DailyFocus], theme: &Theme) {
+/// `previous` is drawn as a dashed line behind the bars, day by day
+pub fn render_focus_chart(
+    ui: &mut Ui,
+    daily_data: &[DailyFocus],
+    previous: Option<&[DailyFocus]>,
+    theme: &Theme,
+) {
     if daily_data.is_empty() {
         return;
     }
 
-    let max_minutes = daily_data.iter().map(|day| day.minutes).max().unwrap_or(60);
+    let max_minutes = daily_data
+        .iter()
+        .chain(previous.unwrap_or_default())
+        .map(|day| day.minutes)
+        .max()
+        .unwrap_or(60);
     let y_max = ((max_minutes as f64 * 1.6) / 30.0).ceil() * 30.0;
 
     let bars: Vec<Bar> = daily_data
@@ -32,6 +43,18 @@ pub fn render_focus_chart(ui: &mut Ui, daily_data: &[DailyFocus], theme: &Theme)
 
     let chart = BarChart::new(bars);
 
+    let previous_line = previous.map(|days| {
+        let points: PlotPoints = days
+            .iter()
+            .enumerate()
+            .map(|(index, day)| [index as f64, day.minutes as f64])
+            .collect();
+        Line::new(points)
+            .color(theme.colors.text_muted)
+            .style(LineStyle::dashed_loose())
+            .width(1.5)
+    });
+
     let x_labels: Vec<String> = daily_data
         .iter()
         .map(|day| {
@@ -69,6 +92,9 @@ pub fn render_focus_chart(ui: &mut Ui, daily_data: &[DailyFocus], theme: &Theme)
             }
         })
         .show(ui, |plot_ui| {
+            if let Some(line) = previous_line {
+                plot_ui.line(line);
+            }
             plot_ui.bar_chart(chart);
             plot_ui.set_plot_bounds(PlotBounds::from_min_max([-0.5, 0.0], [x_max - 0.5, y_max]));
         });
@@ -0,0 +1,97 @@
+use eframe::egui::{self, Rounding, ScrollArea};
+use flux_core::{DiffLine, Translator};
+
+use crate::data::PendingConfigChange;
+use crate::theme::Theme;
+
+const DIFF_MAX_HEIGHT: f32 = 320.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigDiffAction {
+    Save,
+    Cancel,
+}
+
+/// Shows the TOML lines a settings change would rewrite, so hand-edited
+/// configuration files are never modified without a confirmation
+pub fn render_config_diff(
+    context: &egui::Context,
+    change: &PendingConfigChange,
+    translator: &Translator,
+    theme: &Theme,
+) -> Option<ConfigDiffAction> {
+    let mut action = None;
+
+    egui::Window::new(translator.get("gui.config_diff_title"))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(context, |ui| {
+            ui.add_space(theme.spacing.sm);
+            ui.label(
+                egui::RichText::new(translator.get("gui.config_diff_message"))
+                    .size(theme.typography.body)
+                    .color(theme.colors.text_secondary),
+            );
+            ui.add_space(theme.spacing.md);
+
+            egui::Frame::none()
+                .fill(theme.colors.surface)
+                .rounding(Rounding::same(theme.rounding.sm))
+                .inner_margin(egui::Margin::same(theme.spacing.sm))
+                .show(ui, |ui| {
+                    ScrollArea::vertical()
+                        .max_height(DIFF_MAX_HEIGHT)
+                        .show(ui, |ui| {
+                            for line in &change.diff {
+                                render_line(ui, line, theme);
+                            }
+                        });
+                });
+
+            ui.add_space(theme.spacing.lg);
+
+            ui.horizontal(|ui| {
+                let cancel_button = egui::Button::new(
+                    egui::RichText::new(translator.get("gui.clear_cancel"))
+                        .size(theme.typography.body),
+                )
+                .rounding(Rounding::same(theme.rounding.sm));
+
+                if ui.add(cancel_button).clicked() {
+                    action = Some(ConfigDiffAction::Cancel);
+                }
+
+                ui.add_space(theme.spacing.md);
+
+                let save_button = egui::Button::new(
+                    egui::RichText::new(translator.get("gui.config_diff_save"))
+                        .size(theme.typography.body)
+                        .color(egui::Color32::WHITE),
+                )
+                .fill(theme.colors.accent)
+                .rounding(Rounding::same(theme.rounding.sm));
+
+                if ui.add(save_button).clicked() {
+                    action = Some(ConfigDiffAction::Save);
+                }
+            });
+        });
+
+    action
+}
+
+fn render_line(ui: &mut egui::Ui, line: &DiffLine, theme: &Theme) {
+    let (prefix, color) = match line {
+        DiffLine::Added(_) => ('+', theme.colors.success),
+        DiffLine::Removed(_) => ('-', theme.colors.error),
+        DiffLine::Unchanged(_) => (' ', theme.colors.text_muted),
+    };
+
+    ui.label(
+        egui::RichText::new(format!("{} {}", prefix, line.text()))
+            .size(theme.typography.label)
+            .color(color)
+            .monospace(),
+    );
+}
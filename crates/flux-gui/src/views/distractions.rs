@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use eframe::egui::{self, Ui};
+use flux_core::{DistractionConfig, DistractionRule, DistractionVerdict, Translator};
+
+use crate::theme::Theme;
+use crate::views::empty_state;
+
+#[derive(Default)]
+pub struct RulePreview {
+    pub application: String,
+    pub window_title: String,
+}
+
+pub fn render_distractions(
+    ui: &mut Ui,
+    config: &DistractionConfig,
+    preview: &mut RulePreview,
+    translator: &Translator,
+    theme: &Theme,
+) {
+    theme.card_frame().show(ui, |ui| {
+        ui.set_min_width(ui.available_width());
+        render_rule_preview(ui, config, preview, translator, theme);
+    });
+
+    ui.add_space(theme.spacing.lg);
+
+    theme.card_frame().show(ui, |ui| {
+        ui.set_min_width(ui.available_width());
+        render_configured_rules(ui, config, translator, theme);
+    });
+}
+
+fn render_rule_preview(
+    ui: &mut Ui,
+    config: &DistractionConfig,
+    preview: &mut RulePreview,
+    translator: &Translator,
+    theme: &Theme,
+) {
+    ui.label(
+        egui::RichText::new(translator.get("gui.rule_preview_title"))
+            .size(theme.typography.title)
+            .color(theme.colors.text_primary)
+            .strong(),
+    );
+    ui.add_space(theme.spacing.md);
+
+    egui::Grid::new("rule_preview_inputs")
+        .num_columns(2)
+        .spacing([theme.spacing.md, theme.spacing.sm])
+        .show(ui, |ui| {
+            ui.label(translator.get("gui.rule_preview_application"));
+            ui.text_edit_singleline(&mut preview.application);
+            ui.end_row();
+
+            ui.label(translator.get("gui.rule_preview_window_title"));
+            ui.text_edit_singleline(&mut preview.window_title);
+            ui.end_row();
+        });
+
+    ui.add_space(theme.spacing.md);
+
+    if preview.application.trim().is_empty() && preview.window_title.trim().is_empty() {
+        ui.label(
+            egui::RichText::new(translator.get("gui.rule_preview_hint"))
+                .size(theme.typography.label)
+                .color(theme.colors.text_muted),
+        );
+        return;
+    }
+
+    let result = config.explain(preview.application.trim(), preview.window_title.trim());
+
+    let (verdict_key, verdict_color) = match result.verdict {
+        DistractionVerdict::Distraction => ("gui.verdict_distraction", theme.colors.error),
+        DistractionVerdict::Friction => ("gui.verdict_friction", theme.colors.warning),
+        DistractionVerdict::Whitelisted => ("gui.verdict_whitelisted", theme.colors.success),
+        DistractionVerdict::Neutral => ("gui.verdict_neutral", theme.colors.text_secondary),
+    };
+
+    ui.label(
+        egui::RichText::new(translator.get(verdict_key))
+            .size(theme.typography.body)
+            .color(verdict_color)
+            .strong(),
+    );
+
+    for rule in &result.rules {
+        ui.label(
+            egui::RichText::new(format!("• {}", describe_rule(rule, translator)))
+                .size(theme.typography.label)
+                .color(theme.colors.text_secondary),
+        );
+    }
+}
+
+fn render_configured_rules(
+    ui: &mut Ui,
+    config: &DistractionConfig,
+    translator: &Translator,
+    theme: &Theme,
+) {
+    ui.label(
+        egui::RichText::new(translator.get("gui.rules_configured"))
+            .size(theme.typography.title)
+            .color(theme.colors.text_primary)
+            .strong(),
+    );
+    ui.add_space(theme.spacing.md);
+
+    for (label_key, patterns) in [
+        ("gui.rules_applications", &config.apps),
+        ("gui.rules_title_patterns", &config.title_patterns),
+        ("gui.rules_friction", &config.friction_apps),
+        ("gui.rules_whitelist", &config.whitelist_apps),
+    ] {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(
+                egui::RichText::new(format!("{}:", translator.get(label_key)))
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_secondary),
+            );
+            ui.label(
+                egui::RichText::new(format_patterns(patterns, translator))
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_primary),
+            );
+        });
+    }
+
+    if config.apps.is_empty() && config.title_patterns.is_empty() {
+        empty_state::render_empty_state(
+            ui,
+            "🛡",
+            &translator.get("gui.rules_empty"),
+            &translator.get("gui.empty_rules_hint"),
+            "flux distractions add discord\n\n[distractions]\napps = [\"discord\", \"slack\"]",
+            theme,
+        );
+    }
+}
+
+fn describe_rule(rule: &DistractionRule, translator: &Translator) -> String {
+    let (key, pattern) = match rule {
+        DistractionRule::Application(pattern) => ("gui.rule_application", pattern),
+        DistractionRule::TitlePattern(pattern) => ("gui.rule_title_pattern", pattern),
+        DistractionRule::Friction(pattern) => ("gui.rule_friction", pattern),
+        DistractionRule::Whitelist(pattern) => ("gui.rule_whitelist", pattern),
+    };
+    translator.format(key, &[("pattern", pattern)])
+}
+
+fn format_patterns(patterns: &HashSet<String>, translator: &Translator) -> String {
+    if patterns.is_empty() {
+        return translator.get("gui.rules_none");
+    }
+
+    let mut sorted: Vec<_> = patterns.iter().map(String::as_str).collect();
+    sorted.sort();
+    sorted.join(", ")
+}
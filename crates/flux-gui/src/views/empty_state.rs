@@ -0,0 +1,51 @@
+use eframe::egui::{self, Rounding, Ui};
+
+use crate::theme::Theme;
+
+/// Placeholder for a tab without data, with the command or config snippet that fills it
+pub fn render_empty_state(
+    ui: &mut Ui,
+    icon: &str,
+    title: &str,
+    hint: &str,
+    snippet: &str,
+    theme: &Theme,
+) {
+    ui.vertical_centered(|ui| {
+        ui.add_space(theme.spacing.xxl);
+
+        ui.label(egui::RichText::new(icon).size(48.0));
+
+        ui.add_space(theme.spacing.md);
+
+        ui.label(
+            egui::RichText::new(title)
+                .size(theme.typography.title)
+                .color(theme.colors.text_primary),
+        );
+
+        ui.add_space(theme.spacing.sm);
+
+        ui.label(
+            egui::RichText::new(hint)
+                .size(theme.typography.body)
+                .color(theme.colors.text_secondary),
+        );
+
+        ui.add_space(theme.spacing.md);
+
+        egui::Frame::none()
+            .fill(theme.colors.surface)
+            .stroke(egui::Stroke::new(1.0, theme.colors.border))
+            .rounding(Rounding::same(theme.rounding.sm))
+            .inner_margin(egui::Margin::symmetric(theme.spacing.md, theme.spacing.sm))
+            .show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new(snippet)
+                        .size(theme.typography.body)
+                        .color(theme.colors.accent)
+                        .monospace(),
+                );
+            });
+    });
+}
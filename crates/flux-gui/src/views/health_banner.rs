@@ -0,0 +1,197 @@
+use eframe::egui::{self, Rounding, Stroke, Ui};
+use flux_core::Translator;
+use flux_protocol::{Request, Response, SubsystemHealth, SubsystemStatus};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::client::DaemonClient;
+use crate::theme::Theme;
+
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Default)]
+pub struct DaemonHealth {
+    pub failures: Vec<SubsystemHealth>,
+    pub config_warnings: Vec<String>,
+}
+
+impl DaemonHealth {
+    fn from_response(subsystems: Vec<SubsystemHealth>, config_warnings: Vec<String>) -> Self {
+        Self {
+            failures: subsystems
+                .into_iter()
+                .filter(SubsystemHealth::is_failed)
+                .collect(),
+            config_warnings,
+        }
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.failures.is_empty() && self.config_warnings.is_empty()
+    }
+}
+
+pub struct HealthMonitor {
+    receiver: mpsc::Receiver<DaemonHealth>,
+    health: DaemonHealth,
+}
+
+impl HealthMonitor {
+    pub fn new(runtime: &tokio::runtime::Handle) -> Self {
+        let (sender, receiver) = mpsc::channel::<DaemonHealth>();
+
+        runtime.spawn(Self::background_task(sender));
+
+        Self {
+            receiver,
+            health: DaemonHealth::default(),
+        }
+    }
+
+    async fn background_task(sender: mpsc::Sender<DaemonHealth>) {
+        let client = DaemonClient::new();
+
+        loop {
+            let health = match client.send(Request::GetHealth).await {
+                Ok(Response::Health {
+                    subsystems,
+                    config_warnings,
+                }) => DaemonHealth::from_response(subsystems, config_warnings),
+                _ => DaemonHealth::default(),
+            };
+
+            if sender.send(health).is_err() {
+                break;
+            }
+
+            tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+        }
+    }
+
+    pub fn poll(&mut self, context: &egui::Context) {
+        while let Ok(health) = self.receiver.try_recv() {
+            self.health = health;
+            context.request_repaint();
+        }
+    }
+
+    pub fn health(&self) -> &DaemonHealth {
+        &self.health
+    }
+}
+
+pub fn render_health_banner(
+    ui: &mut Ui,
+    health: &DaemonHealth,
+    translator: &Translator,
+    theme: &Theme,
+) {
+    if health.is_healthy() {
+        return;
+    }
+
+    egui::Frame::none()
+        .fill(theme.colors.surface)
+        .stroke(Stroke::new(1.0, theme.colors.warning))
+        .rounding(Rounding::same(theme.rounding.md))
+        .inner_margin(egui::Margin::same(theme.spacing.md))
+        .show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+
+            ui.label(
+                egui::RichText::new(translator.get("gui.health_banner_title"))
+                    .size(theme.typography.body)
+                    .color(theme.colors.warning)
+                    .strong(),
+            );
+
+            for failure in &health.failures {
+                ui.add_space(theme.spacing.sm);
+                render_failure(ui, failure, translator, theme);
+            }
+
+            if !health.config_warnings.is_empty() {
+                ui.add_space(theme.spacing.sm);
+                ui.label(
+                    egui::RichText::new(translator.get("gui.health_config_warnings"))
+                        .size(theme.typography.body)
+                        .color(theme.colors.text_primary),
+                );
+                for warning in &health.config_warnings {
+                    ui.label(
+                        egui::RichText::new(warning)
+                            .size(theme.typography.label)
+                            .color(theme.colors.text_secondary),
+                    );
+                }
+            }
+        });
+
+    ui.add_space(theme.spacing.md);
+}
+
+fn render_failure(ui: &mut Ui, failure: &SubsystemHealth, translator: &Translator, theme: &Theme) {
+    let subsystem = failure.subsystem.id();
+
+    ui.label(
+        egui::RichText::new(translator.get(&format!("health.{}_name", subsystem)))
+            .size(theme.typography.body)
+            .color(theme.colors.text_primary),
+    );
+
+    if let SubsystemStatus::Failed {
+        reason: Some(reason),
+    } = &failure.status
+    {
+        ui.label(
+            egui::RichText::new(reason)
+                .size(theme.typography.label)
+                .color(theme.colors.text_muted),
+        );
+    }
+
+    ui.label(
+        egui::RichText::new(translator.get(&format!("health.{}_hint", subsystem)))
+            .size(theme.typography.label)
+            .color(theme.colors.text_secondary),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_protocol::Subsystem;
+
+    #[test]
+    fn only_failed_subsystems_are_kept() {
+        let subsystems = vec![
+            SubsystemHealth::new(Subsystem::Persistence, SubsystemStatus::Running),
+            SubsystemHealth::new(
+                Subsystem::AppTracking,
+                SubsystemStatus::Failed { reason: None },
+            ),
+            SubsystemHealth::new(Subsystem::Tray, SubsystemStatus::Disabled),
+        ];
+
+        let health = DaemonHealth::from_response(subsystems, Vec::new());
+
+        assert_eq!(health.failures.len(), 1);
+        assert_eq!(health.failures[0].subsystem, Subsystem::AppTracking);
+        assert!(!health.is_healthy());
+    }
+
+    #[test]
+    fn config_warnings_make_daemon_unhealthy() {
+        let subsystems = vec![SubsystemHealth::new(
+            Subsystem::Persistence,
+            SubsystemStatus::Running,
+        )];
+
+        assert!(DaemonHealth::from_response(subsystems.clone(), Vec::new()).is_healthy());
+        assert!(!DaemonHealth::from_response(
+            subsystems,
+            vec!["profil actif introuvable".to_string()]
+        )
+        .is_healthy());
+    }
+}
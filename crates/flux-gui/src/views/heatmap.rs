@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Local, NaiveDate};
+use eframe::egui::{self, Rounding, Sense, Ui};
+use flux_core::{Translator, WeekStart};
+
+use crate::data::{format_duration, DailyFocus};
+use crate::theme::Theme;
+use crate::views::empty_state;
+
+const HEATMAP_DAYS: i64 = 365;
+const CELL_SIZE: f32 = 11.0;
+const CELL_GAP: f32 = 3.0;
+const INTENSITY_LEVELS: u8 = 4;
+
+pub fn render_heatmap(
+    ui: &mut Ui,
+    daily_data: &[DailyFocus],
+    week_start: WeekStart,
+    translator: &Translator,
+    theme: &Theme,
+) {
+    let today = Local::now().date_naive();
+    let by_day: HashMap<NaiveDate, &DailyFocus> =
+        daily_data.iter().map(|day| (day.date, day)).collect();
+    let max_minutes = daily_data.iter().map(|day| day.minutes).max().unwrap_or(0);
+    let active_days = daily_data.iter().filter(|day| day.minutes > 0).count();
+
+    if active_days == 0 {
+        empty_state::render_empty_state(
+            ui,
+            "🗓",
+            &translator.get("gui.heatmap_empty"),
+            &translator.get("gui.empty_heatmap_hint"),
+            "flux start",
+            theme,
+        );
+        return;
+    }
+
+    theme.card_frame().show(ui, |ui| {
+        ui.set_min_width(ui.available_width());
+
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(translator.get("gui.heatmap_title"))
+                    .size(theme.typography.title)
+                    .color(theme.colors.text_primary)
+                    .strong(),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.label(
+                    egui::RichText::new(translator.format(
+                        "gui.heatmap_active_days",
+                        &[("days", &active_days.to_string())],
+                    ))
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_secondary),
+                );
+            });
+        });
+
+        ui.add_space(theme.spacing.md);
+
+        egui::ScrollArea::horizontal().show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing = egui::vec2(CELL_GAP, CELL_GAP);
+                for week in heatmap_weeks(today, week_start) {
+                    ui.vertical(|ui| {
+                        for date in week {
+                            let day = by_day.get(&date).copied();
+                            render_cell(ui, date, day, max_minutes, translator, theme);
+                        }
+                    });
+                }
+            });
+        });
+
+        ui.add_space(theme.spacing.sm);
+        render_legend(ui, translator, theme);
+    });
+}
+
+fn render_cell(
+    ui: &mut Ui,
+    date: NaiveDate,
+    day: Option<&DailyFocus>,
+    max_minutes: i64,
+    translator: &Translator,
+    theme: &Theme,
+) {
+    let minutes = day.map(|day| day.minutes).unwrap_or(0);
+    let session_count = day.map(|day| day.session_count).unwrap_or(0);
+
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(CELL_SIZE, CELL_SIZE), Sense::hover());
+    ui.painter().rect_filled(
+        rect,
+        Rounding::same(2.0),
+        level_color(intensity_level(minutes, max_minutes), theme),
+    );
+
+    response.on_hover_text(translator.format(
+        "gui.heatmap_day",
+        &[
+            ("date", &date.format("%d/%m/%Y").to_string()),
+            ("duration", &format_duration(minutes * 60)),
+            ("sessions", &session_count.to_string()),
+        ],
+    ));
+}
+
+fn render_legend(ui: &mut Ui, translator: &Translator, theme: &Theme) {
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = CELL_GAP;
+        ui.label(
+            egui::RichText::new(translator.get("gui.heatmap_less"))
+                .size(theme.typography.label)
+                .color(theme.colors.text_muted),
+        );
+        for level in 0..=INTENSITY_LEVELS {
+            let (rect, _) =
+                ui.allocate_exact_size(egui::vec2(CELL_SIZE, CELL_SIZE), Sense::hover());
+            ui.painter()
+                .rect_filled(rect, Rounding::same(2.0), level_color(level, theme));
+        }
+        ui.label(
+            egui::RichText::new(translator.get("gui.heatmap_more"))
+                .size(theme.typography.label)
+                .color(theme.colors.text_muted),
+        );
+    });
+}
+
+fn level_color(level: u8, theme: &Theme) -> egui::Color32 {
+    if level == 0 {
+        return theme.colors.surface_elevated;
+    }
+    theme
+        .colors
+        .accent
+        .gamma_multiply(level as f32 / INTENSITY_LEVELS as f32)
+}
+
+/// Days of the past year grouped by week, each week starting on the configured day
+fn heatmap_weeks(today: NaiveDate, week_start: WeekStart) -> Vec<Vec<NaiveDate>> {
+    let first_day = week_start.week_of(today - Duration::days(HEATMAP_DAYS - 1));
+    let mut weeks = Vec::new();
+    let mut week_first_day = first_day;
+
+    while week_first_day <= today {
+        weeks.push(
+            (0..7)
+                .map(|offset| week_first_day + Duration::days(offset))
+                .take_while(|date| *date <= today)
+                .collect(),
+        );
+        week_first_day += Duration::days(7);
+    }
+
+    weeks
+}
+
+/// Shade from 0 (no focus) to 4 (the busiest day of the year)
+fn intensity_level(minutes: i64, max_minutes: i64) -> u8 {
+    if minutes <= 0 || max_minutes <= 0 {
+        return 0;
+    }
+    let level = (minutes * INTENSITY_LEVELS as i64 + max_minutes - 1) / max_minutes;
+    level.clamp(1, INTENSITY_LEVELS as i64) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Weekday};
+
+    #[test]
+    fn weeks_cover_the_past_year_up_to_today() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+
+        let weeks = heatmap_weeks(today, WeekStart::Monday);
+
+        assert_eq!(weeks.len(), 53);
+        assert_eq!(weeks[0][0].weekday(), Weekday::Mon);
+        assert!(weeks[0][0] <= today - Duration::days(HEATMAP_DAYS - 1));
+        assert_eq!(weeks.last().unwrap().last(), Some(&today));
+        assert!(weeks[..weeks.len() - 1].iter().all(|week| week.len() == 7));
+    }
+
+    #[test]
+    fn weeks_follow_the_configured_first_day() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+
+        let weeks = heatmap_weeks(today, WeekStart::Sunday);
+
+        assert!(weeks.iter().all(|week| week[0].weekday() == Weekday::Sun));
+    }
+
+    #[test]
+    fn intensity_scales_with_the_busiest_day() {
+        assert_eq!(intensity_level(0, 120), 0);
+        assert_eq!(intensity_level(1, 120), 1);
+        assert_eq!(intensity_level(60, 120), 2);
+        assert_eq!(intensity_level(119, 120), 4);
+        assert_eq!(intensity_level(120, 120), 4);
+        assert_eq!(intensity_level(30, 0), 0);
+    }
+}
@@ -1,18 +1,60 @@
 use chrono::{DateTime, Local, Utc};
 use eframe::egui::{self, Rounding, ScrollArea, Ui};
-use flux_core::{Session, SessionId, Translator};
+use flux_core::{Session, SessionId, SessionNote, Translator};
 
 use crate::data::format_duration;
 use crate::theme::Theme;
+use crate::views::empty_state;
 
 pub enum HistoryAction {
     None,
+    OpenSession(SessionId),
     DeleteSession(SessionId),
 }
 
+pub fn render_tag_filter(
+    ui: &mut Ui,
+    sessions: &[&Session],
+    selected_tag: &mut Option<String>,
+    translator: &Translator,
+) {
+    let tags = available_tags(sessions);
+    if tags.is_empty() {
+        *selected_tag = None;
+        return;
+    }
+
+    let all_tags_label = translator.get("gui.history_all_tags");
+
+    ui.horizontal(|ui| {
+        ui.label(translator.get("gui.history_tag_filter"));
+
+        egui::ComboBox::from_id_salt("history_tag_filter")
+            .selected_text(selected_tag.as_deref().unwrap_or(&all_tags_label))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(selected_tag, None, &all_tags_label);
+                for tag in tags {
+                    let label = tag.clone();
+                    ui.selectable_value(selected_tag, Some(tag), label);
+                }
+            });
+    });
+}
+
+pub fn available_tags(sessions: &[&Session]) -> Vec<String> {
+    let mut tags: Vec<String> = sessions
+        .iter()
+        .flat_map(|session| session.tags.iter().cloned())
+        .collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
 pub fn render_session_list(
     ui: &mut Ui,
     sessions: &[&Session],
+    notes: &[SessionNote],
     translator: &Translator,
     theme: &Theme,
 ) -> HistoryAction {
@@ -23,7 +65,7 @@ pub fn render_session_list(
 
     let mut action = HistoryAction::None;
     let mut sorted_sessions: Vec<_> = sessions.iter().collect();
-    sorted_sessions.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    sorted_sessions.sort_by_key(|session| std::cmp::Reverse(session.started_at));
 
     ScrollArea::vertical()
         .auto_shrink([false, false])
@@ -31,8 +73,13 @@ pub fn render_session_list(
             ui.set_min_width(ui.available_width());
 
             for session in sorted_sessions {
-                if let Some(id) = render_session_row(ui, session, theme) {
-                    action = HistoryAction::DeleteSession(id);
+                let note = notes
+                    .iter()
+                    .find(|note| Some(note.session_id) == session.id)
+                    .map(|note| note.text.as_str());
+                let row_action = render_session_row(ui, session, note, translator, theme);
+                if !matches!(row_action, HistoryAction::None) {
+                    action = row_action;
                 }
                 ui.add_space(theme.spacing.sm);
             }
@@ -41,8 +88,15 @@ pub fn render_session_list(
     action
 }
 
-fn render_session_row(ui: &mut Ui, session: &Session, theme: &Theme) -> Option<SessionId> {
+fn render_session_row(
+    ui: &mut Ui,
+    session: &Session,
+    note: Option<&str>,
+    translator: &Translator,
+    theme: &Theme,
+) -> HistoryAction {
     let mut delete_clicked = false;
+    let mut details_clicked = false;
 
     let frame = egui::Frame::none()
         .fill(theme.colors.surface)
@@ -67,7 +121,7 @@ fn render_session_row(ui: &mut Ui, session: &Session, theme: &Theme) -> Option<S
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
                     ui.label(
-                        egui::RichText::new(session.mode.to_string())
+                        egui::RichText::new(translator.mode_name(&session.mode))
                             .size(theme.typography.body)
                             .color(theme.colors.text_primary)
                             .strong(),
@@ -90,6 +144,23 @@ fn render_session_row(ui: &mut Ui, session: &Session, theme: &Theme) -> Option<S
                             delete_clicked = true;
                         }
 
+                        let details_button = egui::Button::new(
+                            egui::RichText::new("🔍")
+                                .size(theme.typography.body)
+                                .color(theme.colors.text_muted),
+                        )
+                        .fill(egui::Color32::TRANSPARENT)
+                        .stroke(egui::Stroke::NONE);
+
+                        if ui
+                            .add(details_button)
+                            .on_hover_cursor(egui::CursorIcon::PointingHand)
+                            .on_hover_text(translator.get("gui.session_details"))
+                            .clicked()
+                        {
+                            details_clicked = true;
+                        }
+
                         ui.add_space(theme.spacing.sm);
 
                         ui.label(
@@ -118,15 +189,26 @@ fn render_session_row(ui: &mut Ui, session: &Session, theme: &Theme) -> Option<S
                         "✓",
                         &format!("{} check-ins", session.check_in_count),
                     );
+
+                    if !session.tags.is_empty() {
+                        ui.add_space(theme.spacing.md);
+
+                        render_session_stat(ui, theme, "🏷", &session.tags.join(", "));
+                    }
                 });
+
+                if let Some(note) = note {
+                    ui.add_space(theme.spacing.xs);
+                    render_session_stat(ui, theme, "📝", note);
+                }
             });
         });
     });
 
-    if delete_clicked {
-        session.id
-    } else {
-        None
+    match session.id {
+        Some(id) if delete_clicked => HistoryAction::DeleteSession(id),
+        Some(id) if details_clicked => HistoryAction::OpenSession(id),
+        _ => HistoryAction::None,
     }
 }
 
@@ -147,22 +229,17 @@ fn render_session_stat(ui: &mut Ui, theme: &Theme, icon: &str, value: &str) {
 }
 
 fn render_empty_state(ui: &mut Ui, translator: &Translator, theme: &Theme) {
-    ui.vertical_centered(|ui| {
-        ui.add_space(theme.spacing.xxl);
-
-        ui.label(egui::RichText::new("📋").size(48.0));
-
-        ui.add_space(theme.spacing.md);
-
-        ui.label(
-            egui::RichText::new(translator.get("gui.history_empty"))
-                .size(theme.typography.title)
-                .color(theme.colors.text_primary),
-        );
-    });
+    empty_state::render_empty_state(
+        ui,
+        "📋",
+        &translator.get("gui.history_empty"),
+        &translator.get("gui.empty_history_hint"),
+        "flux start --tag deep-work",
+        theme,
+    );
 }
 
-fn format_datetime(datetime: DateTime<Utc>) -> String {
+pub fn format_datetime(datetime: DateTime<Utc>) -> String {
     let local: DateTime<Local> = datetime.into();
     local.format("%d/%m/%Y %H:%M").to_string()
 }
@@ -1,4 +1,14 @@
+pub mod about;
+pub mod achievements;
+pub mod annotations;
 pub mod chart;
+pub mod config_diff;
+pub mod distractions;
+pub mod empty_state;
+pub mod health_banner;
+pub mod heatmap;
 pub mod history;
 pub mod overview;
 pub mod session_control;
+pub mod suggestion_banner;
+pub mod timeline;
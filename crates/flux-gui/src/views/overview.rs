@@ -2,10 +2,11 @@ use std::collections::HashMap;
 use std::f32::consts::PI;
 
 use eframe::egui::{self, Rounding, Ui};
-use flux_core::{DistractionConfig, Translator};
+use flux_core::{DistractionConfig, FocusMode, ScoreContribution, Translator};
 
 use crate::data::{format_duration, Period, Stats};
 use crate::theme::Theme;
+use crate::views::empty_state;
 
 pub enum AppAction {
     ToggleDistraction(String),
@@ -118,7 +119,38 @@ pub fn render_stats_cards(
             );
             ui.add_space(theme.spacing.md);
 
-            render_mode_breakdown(ui, stats, theme);
+            render_duration_bars(
+                ui,
+                &stats.by_mode,
+                stats.total_seconds,
+                theme,
+                |mode| translator.mode_name(&FocusMode::from_stored(mode)),
+                |mode| theme.colors.mode_color(mode),
+            );
+        });
+    }
+
+    if !stats.by_project.is_empty() {
+        ui.add_space(theme.spacing.lg);
+
+        theme.card_frame().show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(translator.get("command.stats_by_project"))
+                    .size(theme.typography.title)
+                    .color(theme.colors.text_primary)
+                    .strong(),
+            );
+            ui.add_space(theme.spacing.md);
+
+            let total = stats.by_project.values().sum();
+            render_duration_bars(
+                ui,
+                &stats.by_project,
+                total,
+                theme,
+                |project| project.to_string(),
+                |_| theme.colors.accent,
+            );
         });
     }
 
@@ -210,7 +242,13 @@ pub fn render_stats_cards(
 
             ui.horizontal(|ui| {
                 if let Some(score) = stats.average_focus_score {
-                    render_focus_score_gauge(ui, score, translator, theme);
+                    render_focus_score_gauge(
+                        ui,
+                        score,
+                        &stats.average_score_breakdown,
+                        translator,
+                        theme,
+                    );
                 }
 
                 ui.add_space(theme.spacing.lg);
@@ -231,11 +269,76 @@ pub fn render_stats_cards(
                         theme.colors.error,
                         theme,
                     );
+                    if let Some(percent) = stats.average_active_input_percent {
+                        ui.add_space(theme.spacing.md);
+                        render_metric_row(
+                            ui,
+                            &translator.get("command.stats_active_input"),
+                            percent as u32,
+                            theme.colors.accent,
+                            theme,
+                        );
+                    }
                 });
             });
         });
     }
 
+    let completion = &stats.completion;
+    if completion.total() > 0 {
+        ui.add_space(theme.spacing.lg);
+
+        theme.card_frame().show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(translator.get("gui.completion_title"))
+                    .size(theme.typography.title)
+                    .color(theme.colors.text_primary)
+                    .strong(),
+            );
+            ui.add_space(theme.spacing.md);
+
+            for (label, count, color) in [
+                (
+                    "gui.completion_completed",
+                    completion.completed,
+                    theme.colors.success,
+                ),
+                (
+                    "gui.completion_stopped_early",
+                    completion.stopped_early,
+                    theme.colors.warning,
+                ),
+                (
+                    "gui.completion_discarded",
+                    completion.discarded,
+                    theme.colors.error,
+                ),
+            ] {
+                render_share_row(
+                    ui,
+                    &translator.get(label),
+                    completion.percent_of(count),
+                    count,
+                    color,
+                    theme,
+                );
+                ui.add_space(theme.spacing.sm);
+            }
+
+            if let Some(percent) = completion.average_stop_percent {
+                ui.add_space(theme.spacing.sm);
+                ui.label(
+                    egui::RichText::new(translator.format(
+                        "gui.completion_average_stop",
+                        &[("percent", &percent.to_string())],
+                    ))
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_secondary),
+                );
+            }
+        });
+    }
+
     if action.is_none() && !stats.short_bursts_by_app.is_empty() {
         ui.add_space(theme.spacing.lg);
 
@@ -305,22 +408,29 @@ fn render_stat_card(
     });
 }
 
-fn render_mode_breakdown(ui: &mut Ui, stats: &Stats, theme: &Theme) {
-    let mut modes: Vec<_> = stats.by_mode.iter().collect();
-    modes.sort_by(|a, b| b.1.cmp(a.1));
+fn render_duration_bars(
+    ui: &mut Ui,
+    entries: &HashMap<String, i64>,
+    total_seconds: i64,
+    theme: &Theme,
+    label_for: impl Fn(&str) -> String,
+    color_for: impl Fn(&str) -> egui::Color32,
+) {
+    let mut sorted: Vec<_> = entries.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
 
-    let total = stats.total_seconds.max(1) as f32;
+    let total = total_seconds.max(1) as f32;
 
-    for (mode, seconds) in modes {
+    for (name, seconds) in sorted {
         let percentage = (*seconds as f32 / total * 100.0) as u32;
         let progress = *seconds as f32 / total;
-        let mode_color = theme.colors.mode_color(mode);
+        let bar_color = color_for(name);
 
         ui.horizontal(|ui| {
             ui.set_min_width(ui.available_width());
 
             ui.label(
-                egui::RichText::new(mode)
+                egui::RichText::new(label_for(name))
                     .size(theme.typography.body)
                     .color(theme.colors.text_primary)
                     .strong(),
@@ -356,7 +466,7 @@ fn render_mode_breakdown(ui: &mut Ui, stats: &Stats, theme: &Theme) {
 
         let filled_width = rect.width() * progress;
         let filled_rect = egui::Rect::from_min_size(rect.min, egui::vec2(filled_width, bar_height));
-        ui.painter().rect_filled(filled_rect, rounding, mode_color);
+        ui.painter().rect_filled(filled_rect, rounding, bar_color);
 
         ui.add_space(theme.spacing.md);
     }
@@ -683,51 +793,28 @@ fn render_context_switch_details(
 }
 
 pub fn render_empty_state(ui: &mut Ui, translator: &Translator, theme: &Theme) {
-    ui.vertical_centered(|ui| {
-        ui.add_space(theme.spacing.xxl);
-
-        ui.label(egui::RichText::new("📊").size(48.0));
-
-        ui.add_space(theme.spacing.md);
-
-        ui.label(
-            egui::RichText::new(translator.get("command.stats_no_sessions"))
-                .size(theme.typography.title)
-                .color(theme.colors.text_primary),
-        );
-
-        ui.add_space(theme.spacing.sm);
-
-        ui.label(
-            egui::RichText::new("Start your first focus session:")
-                .size(theme.typography.body)
-                .color(theme.colors.text_secondary),
-        );
-
-        ui.add_space(theme.spacing.md);
-
-        egui::Frame::none()
-            .fill(theme.colors.surface)
-            .stroke(egui::Stroke::new(1.0, theme.colors.border))
-            .rounding(Rounding::same(theme.rounding.sm))
-            .inner_margin(egui::Margin::symmetric(theme.spacing.md, theme.spacing.sm))
-            .show(ui, |ui| {
-                ui.label(
-                    egui::RichText::new("flux start")
-                        .size(theme.typography.body)
-                        .color(theme.colors.accent)
-                        .monospace(),
-                );
-            });
-    });
+    empty_state::render_empty_state(
+        ui,
+        "📊",
+        &translator.get("command.stats_no_sessions"),
+        &translator.get("gui.empty_overview_hint"),
+        "flux start",
+        theme,
+    );
 }
 
-fn render_focus_score_gauge(ui: &mut Ui, score: u8, _translator: &Translator, theme: &Theme) {
+fn render_focus_score_gauge(
+    ui: &mut Ui,
+    score: u8,
+    breakdown: &[ScoreContribution],
+    translator: &Translator,
+    theme: &Theme,
+) {
     let gauge_size = 100.0;
     let stroke_width = 8.0;
     let radius = (gauge_size - stroke_width) / 2.0;
 
-    let (rect, _response) = ui.allocate_exact_size(
+    let (rect, response) = ui.allocate_exact_size(
         egui::vec2(gauge_size, gauge_size * 0.6),
         egui::Sense::hover(),
     );
@@ -770,6 +857,50 @@ fn render_focus_score_gauge(ui: &mut Ui, score: u8, _translator: &Translator, th
         egui::FontId::proportional(theme.typography.heading * 1.2),
         score_color,
     );
+
+    response.on_hover_ui(|ui| render_score_breakdown(ui, breakdown, translator, theme));
+}
+
+fn render_score_breakdown(
+    ui: &mut Ui,
+    breakdown: &[ScoreContribution],
+    translator: &Translator,
+    theme: &Theme,
+) {
+    ui.label(
+        egui::RichText::new(translator.get("gui.score_breakdown_title"))
+            .size(theme.typography.body)
+            .color(theme.colors.text_primary)
+            .strong(),
+    );
+
+    if breakdown.is_empty() {
+        ui.label(
+            egui::RichText::new(translator.get("gui.score_breakdown_none"))
+                .size(theme.typography.label)
+                .color(theme.colors.text_secondary),
+        );
+        return;
+    }
+
+    for contribution in breakdown {
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(translator.format(
+                    &format!("command.score_factor_{}", contribution.factor.as_str()),
+                    &[("count", &contribution.count.to_string())],
+                ))
+                .size(theme.typography.label)
+                .color(theme.colors.text_secondary),
+            );
+            ui.label(
+                egui::RichText::new(format!("{}", contribution.points))
+                    .size(theme.typography.label)
+                    .color(theme.colors.error)
+                    .strong(),
+            );
+        });
+    }
 }
 
 fn draw_arc(
@@ -808,6 +939,34 @@ fn focus_score_color(score: u8, theme: &Theme) -> egui::Color32 {
     }
 }
 
+fn render_share_row(
+    ui: &mut Ui,
+    label: &str,
+    percent: u32,
+    count: usize,
+    color: egui::Color32,
+    theme: &Theme,
+) {
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(label)
+                .size(theme.typography.body)
+                .color(theme.colors.text_secondary),
+        );
+        ui.label(
+            egui::RichText::new(format!("{}%", percent))
+                .size(theme.typography.body)
+                .color(color)
+                .strong(),
+        );
+        ui.label(
+            egui::RichText::new(format!("({})", count))
+                .size(theme.typography.label)
+                .color(theme.colors.text_muted),
+        );
+    });
+}
+
 fn render_metric_row(ui: &mut Ui, label: &str, value: u32, color: egui::Color32, theme: &Theme) {
     ui.horizontal(|ui| {
         ui.label(
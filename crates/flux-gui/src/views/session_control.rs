@@ -1,6 +1,6 @@
 use eframe::egui::{self, Rounding, Ui};
 use flux_core::{FocusMode, Translator};
-use flux_protocol::{Request, Response};
+use flux_protocol::{new_request_id, Request, Response};
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
@@ -17,6 +17,7 @@ pub enum SessionStatus {
     Active {
         remaining_seconds: u64,
         mode: Option<FocusMode>,
+        strict: bool,
     },
     Paused {
         remaining_seconds: u64,
@@ -81,9 +82,19 @@ impl SessionController {
             SessionCommand::Start { duration, mode } => Request::StartSession {
                 duration: Some(duration),
                 mode: Some(mode),
+                tags: Vec::new(),
+                request_id: Some(new_request_id()),
+                strict: false,
+                distraction_apps: Vec::new(),
+            },
+            SessionCommand::Stop => Request::StopSession {
+                confirmation: None,
+                reason: None,
+            },
+            SessionCommand::Pause => Request::PauseSession {
+                confirmation: None,
+                reason: None,
             },
-            SessionCommand::Stop => Request::StopSession,
-            SessionCommand::Pause => Request::PauseSession,
             SessionCommand::Resume => Request::ResumeSession,
             SessionCommand::RefreshStatus => Request::GetStatus,
         };
@@ -94,6 +105,8 @@ impl SessionController {
                 remaining_seconds,
                 mode,
                 paused,
+                strict,
+                ..
             }) => {
                 if !active {
                     SessionStatus::NoSession
@@ -106,12 +119,29 @@ impl SessionController {
                     SessionStatus::Active {
                         remaining_seconds,
                         mode,
+                        strict,
                     }
                 }
             }
+            Ok(Response::AlreadyActive {
+                mode,
+                remaining_seconds,
+            }) => SessionStatus::Active {
+                remaining_seconds,
+                mode: Some(mode),
+                strict: false,
+            },
             Ok(Response::Ok) => SessionStatus::Unknown,
             Ok(Response::Error { .. }) => SessionStatus::Unknown,
             Ok(Response::Pong) => SessionStatus::Unknown,
+            Ok(Response::Health { .. }) => SessionStatus::Unknown,
+            Ok(Response::DaemonInfo { .. }) => SessionStatus::Unknown,
+            Ok(Response::Handshake { .. }) => SessionStatus::Unknown,
+            Ok(Response::NoteAdded { .. }) => SessionStatus::Unknown,
+            Ok(Response::Accepted { .. }) => SessionStatus::Unknown,
+            Ok(Response::JobStatus { .. }) => SessionStatus::Unknown,
+            Ok(Response::StrictSession { .. }) => SessionStatus::Unknown,
+            Ok(Response::Unsupported { .. }) => SessionStatus::Unknown,
             Err(_) => SessionStatus::DaemonUnavailable,
         }
     }
@@ -229,6 +259,7 @@ pub struct StartSessionForm {
     pub selected_mode: FocusMode,
     pub selected_duration: DurationPreset,
     pub custom_minutes: u64,
+    pub custom_mode: String,
 }
 
 impl Default for StartSessionForm {
@@ -237,6 +268,7 @@ impl Default for StartSessionForm {
             selected_mode: FocusMode::AiAssisted,
             selected_duration: DurationPreset::Pomodoro,
             custom_minutes: 25,
+            custom_mode: String::new(),
         }
     }
 }
@@ -248,12 +280,26 @@ impl StartSessionForm {
             preset => preset.minutes(),
         }
     }
+
+    pub fn is_custom_mode(&self) -> bool {
+        matches!(self.selected_mode, FocusMode::Custom(_))
+    }
+
+    /// Mode to start with, `None` while the custom mode name is empty
+    pub fn mode(&self) -> Option<FocusMode> {
+        if !self.is_custom_mode() {
+            return Some(self.selected_mode.clone());
+        }
+        let name = self.custom_mode.trim();
+        (!name.is_empty()).then(|| FocusMode::from_stored(name))
+    }
 }
 
 pub fn render_session_control(
     ui: &mut Ui,
     controller: &mut SessionController,
     form: &mut StartSessionForm,
+    recent_custom_modes: &[String],
     translator: &Translator,
     theme: &Theme,
 ) {
@@ -264,35 +310,32 @@ pub fn render_session_control(
             render_daemon_status(ui, &status, translator, theme);
         }
         SessionStatus::NoSession => {
-            render_start_form(ui, controller, form, translator, theme);
+            render_start_form(ui, controller, form, recent_custom_modes, translator, theme);
         }
         SessionStatus::Active {
             remaining_seconds,
             mode,
+            strict,
         } => {
-            render_active_session(
-                ui,
-                controller,
-                *remaining_seconds,
-                mode.as_ref(),
-                false,
-                translator,
-                theme,
-            );
+            let session = ActiveSession {
+                remaining_seconds: *remaining_seconds,
+                mode: mode.as_ref(),
+                paused: false,
+                strict: *strict,
+            };
+            render_active_session(ui, controller, session, translator, theme);
         }
         SessionStatus::Paused {
             remaining_seconds,
             mode,
         } => {
-            render_active_session(
-                ui,
-                controller,
-                *remaining_seconds,
-                mode.as_ref(),
-                true,
-                translator,
-                theme,
-            );
+            let session = ActiveSession {
+                remaining_seconds: *remaining_seconds,
+                mode: mode.as_ref(),
+                paused: true,
+                strict: false,
+            };
+            render_active_session(ui, controller, session, translator, theme);
         }
     });
 }
@@ -333,6 +376,7 @@ fn render_start_form(
     ui: &mut Ui,
     controller: &mut SessionController,
     form: &mut StartSessionForm,
+    recent_custom_modes: &[String],
     translator: &Translator,
     theme: &Theme,
 ) {
@@ -356,6 +400,7 @@ fn render_start_form(
     ui.horizontal_wrapped(|ui| {
         ui.spacing_mut().item_spacing.x = theme.spacing.sm;
 
+        let custom_label = translator.get("gui.mode_custom");
         let modes = [
             (
                 FocusMode::AiAssisted,
@@ -368,10 +413,18 @@ fn render_start_form(
                 "Architecture",
                 theme.colors.mode_architecture,
             ),
+            (
+                FocusMode::Custom(String::new()),
+                custom_label.as_str(),
+                theme.colors.accent,
+            ),
         ];
 
         for (mode, label, color) in modes {
-            let is_selected = form.selected_mode == mode;
+            let is_selected = match mode {
+                FocusMode::Custom(_) => form.is_custom_mode(),
+                ref builtin => form.selected_mode == *builtin,
+            };
 
             let (bg_color, text_color, stroke) = if is_selected {
                 (
@@ -402,6 +455,10 @@ fn render_start_form(
         }
     });
 
+    if form.is_custom_mode() {
+        render_custom_mode_input(ui, form, recent_custom_modes, translator, theme);
+    }
+
     ui.add_space(theme.spacing.md);
 
     ui.label(
@@ -490,22 +547,86 @@ fn render_start_form(
     .rounding(Rounding::same(theme.rounding.md))
     .min_size(egui::vec2(ui.available_width(), 36.0));
 
-    let button_enabled = !controller.is_pending();
+    let mode = form.mode();
+    let button_enabled = !controller.is_pending() && mode.is_some();
 
     if ui.add_enabled(button_enabled, start_button).clicked() {
-        controller.start_session(form.duration_minutes(), form.selected_mode.clone());
+        if let Some(mode) = mode {
+            controller.start_session(form.duration_minutes(), mode);
+        }
     }
 }
 
-fn render_active_session(
+fn render_custom_mode_input(
     ui: &mut Ui,
-    controller: &mut SessionController,
+    form: &mut StartSessionForm,
+    recent_custom_modes: &[String],
+    translator: &Translator,
+    theme: &Theme,
+) {
+    ui.add_space(theme.spacing.sm);
+
+    ui.add(
+        egui::TextEdit::singleline(&mut form.custom_mode)
+            .hint_text(translator.get("gui.custom_mode_hint"))
+            .desired_width(220.0)
+            .font(egui::TextStyle::Body),
+    );
+
+    if recent_custom_modes.is_empty() {
+        return;
+    }
+
+    ui.add_space(theme.spacing.xs);
+
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing.x = theme.spacing.xs;
+
+        ui.label(
+            egui::RichText::new(translator.get("gui.custom_mode_recent"))
+                .size(theme.typography.label)
+                .color(theme.colors.text_secondary),
+        );
+
+        for recent in recent_custom_modes {
+            let chip = egui::Button::new(
+                egui::RichText::new(recent)
+                    .size(theme.typography.label)
+                    .color(theme.colors.mode_color(recent)),
+            )
+            .fill(theme.colors.surface_elevated)
+            .stroke(egui::Stroke::new(1.0, theme.colors.border))
+            .rounding(Rounding::same(theme.rounding.sm));
+
+            if ui.add(chip).clicked() {
+                form.custom_mode = recent.clone();
+            }
+        }
+    });
+}
+
+struct ActiveSession<'a> {
     remaining_seconds: u64,
-    mode: Option<&FocusMode>,
+    mode: Option<&'a FocusMode>,
     paused: bool,
+    strict: bool,
+}
+
+fn render_active_session(
+    ui: &mut Ui,
+    controller: &mut SessionController,
+    session: ActiveSession,
     translator: &Translator,
     theme: &Theme,
 ) {
+    let ActiveSession {
+        remaining_seconds,
+        mode,
+        paused,
+        strict,
+    } = session;
+    let interrupt_enabled = !controller.is_pending() && !strict;
+
     ui.horizontal(|ui| {
         ui.label(
             egui::RichText::new(translator.get("gui.session_active"))
@@ -527,7 +648,7 @@ fn render_active_session(
 
                 mode_frame.show(ui, |ui| {
                     ui.label(
-                        egui::RichText::new(&mode_str)
+                        egui::RichText::new(translator.mode_name(mode))
                             .size(theme.typography.label)
                             .color(mode_color),
                     );
@@ -605,7 +726,8 @@ fn render_active_session(
             .min_size(egui::vec2(button_width, 36.0));
 
             if ui
-                .add_enabled(!controller.is_pending(), pause_button)
+                .add_enabled(interrupt_enabled, pause_button)
+                .on_disabled_hover_text(translator.get("gui.strict_locked"))
                 .clicked()
             {
                 controller.pause_session();
@@ -622,10 +744,44 @@ fn render_active_session(
         .min_size(egui::vec2(button_width, 36.0));
 
         if ui
-            .add_enabled(!controller.is_pending(), stop_button)
+            .add_enabled(interrupt_enabled, stop_button)
+            .on_disabled_hover_text(translator.get("gui.strict_locked"))
             .clicked()
         {
             controller.stop_session();
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_mode_needs_a_name() {
+        let mut form = StartSessionForm {
+            selected_mode: FocusMode::Custom(String::new()),
+            custom_mode: "  ".to_string(),
+            ..StartSessionForm::default()
+        };
+        assert_eq!(form.mode(), None);
+
+        form.custom_mode = " deep-work ".to_string();
+        assert_eq!(
+            form.mode(),
+            Some(FocusMode::Custom("deep-work".to_string()))
+        );
+
+        form.custom_mode = "review".to_string();
+        assert_eq!(form.mode(), Some(FocusMode::Review));
+    }
+
+    #[test]
+    fn builtin_mode_ignores_the_custom_name() {
+        let form = StartSessionForm {
+            custom_mode: "deep-work".to_string(),
+            ..StartSessionForm::default()
+        };
+        assert_eq!(form.mode(), Some(FocusMode::AiAssisted));
+    }
+}
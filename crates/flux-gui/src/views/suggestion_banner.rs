@@ -0,0 +1,94 @@
+use eframe::egui::{self, Rounding, Stroke, Ui};
+use flux_core::{SuggestionReport, Translator};
+
+use crate::theme::Theme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestionAction {
+    Accept(usize),
+    Dismiss(usize),
+}
+
+/// Positions are counted from 1, like `flux suggestions list`
+pub fn render_suggestion_banner(
+    ui: &mut Ui,
+    report: &SuggestionReport,
+    translator: &Translator,
+    theme: &Theme,
+) -> Option<SuggestionAction> {
+    if report.suggestions.is_empty() {
+        return None;
+    }
+
+    let mut action = None;
+
+    egui::Frame::none()
+        .fill(theme.colors.surface)
+        .stroke(Stroke::new(1.0, theme.colors.accent))
+        .rounding(Rounding::same(theme.rounding.md))
+        .inner_margin(egui::Margin::same(theme.spacing.md))
+        .show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+
+            ui.label(
+                egui::RichText::new(translator.get("gui.suggestions_banner_title"))
+                    .size(theme.typography.body)
+                    .color(theme.colors.accent)
+                    .strong(),
+            );
+
+            for (index, suggestion) in report.suggestions.iter().enumerate() {
+                let position = index + 1;
+                let reason = translator.suggestion_reason(suggestion);
+
+                ui.add_space(theme.spacing.sm);
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(&suggestion.application_name)
+                            .size(theme.typography.body)
+                            .color(theme.colors.text_primary),
+                    );
+                    ui.label(
+                        egui::RichText::new(reason)
+                            .size(theme.typography.label)
+                            .color(theme.colors.text_secondary),
+                    );
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if action_button(
+                            ui,
+                            &translator.get("gui.suggestions_dismiss"),
+                            theme.colors.text_muted,
+                            theme,
+                        ) {
+                            action = Some(SuggestionAction::Dismiss(position));
+                        }
+                        if action_button(
+                            ui,
+                            &translator.get("gui.suggestions_accept"),
+                            theme.colors.error,
+                            theme,
+                        ) {
+                            action = Some(SuggestionAction::Accept(position));
+                        }
+                    });
+                });
+            }
+        });
+
+    ui.add_space(theme.spacing.md);
+    action
+}
+
+fn action_button(ui: &mut Ui, label: &str, color: egui::Color32, theme: &Theme) -> bool {
+    let button = egui::Button::new(
+        egui::RichText::new(label)
+            .size(theme.typography.label)
+            .color(color),
+    )
+    .fill(egui::Color32::TRANSPARENT)
+    .stroke(Stroke::new(1.0, color))
+    .rounding(Rounding::same(theme.rounding.sm));
+
+    ui.add(button).clicked()
+}
@@ -0,0 +1,451 @@
+use chrono::{DateTime, Local, Utc};
+use eframe::egui::{self, Rounding, Sense, Ui};
+use flux_core::{AppUsageSegment, CheckInOutcome, SegmentKind, Session, Translator};
+
+use crate::data::format_duration;
+use crate::theme::Theme;
+use crate::views::empty_state;
+use crate::views::history::format_datetime;
+
+const BAR_HEIGHT: f32 = 28.0;
+const CHECK_IN_MARKER_WIDTH: f32 = 3.0;
+const CHECK_IN_DOT_RADIUS: f32 = 4.0;
+
+/// Returns true when the user asks to go back to the session list
+pub fn render_session_timeline(
+    ui: &mut Ui,
+    session: &Session,
+    segments: &[AppUsageSegment],
+    translator: &Translator,
+    theme: &Theme,
+) -> bool {
+    let back_clicked = ui
+        .add(
+            egui::Button::new(
+                egui::RichText::new(format!("← {}", translator.get("gui.timeline_back")))
+                    .size(theme.typography.label)
+                    .color(theme.colors.accent),
+            )
+            .fill(egui::Color32::TRANSPARENT)
+            .stroke(egui::Stroke::new(1.0, theme.colors.accent))
+            .rounding(Rounding::same(theme.rounding.sm)),
+        )
+        .clicked();
+
+    ui.add_space(theme.spacing.md);
+
+    theme.card_frame().show(ui, |ui| {
+        ui.set_min_width(ui.available_width());
+
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(translator.mode_name(&session.mode))
+                    .size(theme.typography.title)
+                    .color(theme.colors.mode_color(&session.mode.to_string()))
+                    .strong(),
+            );
+            ui.label(
+                egui::RichText::new(format!(
+                    "{} · {}",
+                    format_datetime(session.started_at),
+                    format_duration(session.duration_seconds.unwrap_or(0))
+                ))
+                .size(theme.typography.label)
+                .color(theme.colors.text_secondary),
+            );
+        });
+
+        ui.add_space(theme.spacing.md);
+
+        if segments.is_empty() {
+            empty_state::render_empty_state(
+                ui,
+                "🕒",
+                &translator.get("gui.timeline_empty"),
+                &translator.get("gui.empty_timeline_hint"),
+                "flux daemon status",
+                theme,
+            );
+            return;
+        }
+
+        let session_end = session
+            .ended_at
+            .or_else(|| segments.iter().map(|segment| segment.ended_at).max())
+            .unwrap_or(session.started_at);
+
+        render_bar(
+            ui,
+            session.started_at,
+            session_end,
+            segments,
+            translator,
+            theme,
+        );
+
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(format_time(session.started_at))
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_muted),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.label(
+                    egui::RichText::new(format_time(session_end))
+                        .size(theme.typography.label)
+                        .color(theme.colors.text_muted),
+                );
+            });
+        });
+
+        ui.add_space(theme.spacing.sm);
+        render_legend(ui, translator, theme);
+        ui.add_space(theme.spacing.md);
+
+        let distractions = distraction_totals(segments);
+        if !distractions.is_empty() {
+            render_distraction_summary(ui, &distractions, translator, theme);
+            ui.add_space(theme.spacing.md);
+        }
+
+        for segment in segments {
+            render_segment_row(ui, segment, translator, theme);
+        }
+    });
+
+    back_clicked
+}
+
+fn render_bar(
+    ui: &mut Ui,
+    session_start: DateTime<Utc>,
+    session_end: DateTime<Utc>,
+    segments: &[AppUsageSegment],
+    translator: &Translator,
+    theme: &Theme,
+) {
+    let marker_space = CHECK_IN_DOT_RADIUS * 2.0 + 2.0;
+    let (area, _) = ui.allocate_exact_size(
+        egui::vec2(ui.available_width(), marker_space + BAR_HEIGHT),
+        Sense::hover(),
+    );
+    let bar =
+        egui::Rect::from_min_max(egui::pos2(area.left(), area.top() + marker_space), area.max);
+    ui.painter().rect_filled(
+        bar,
+        Rounding::same(theme.rounding.sm),
+        theme.colors.surface_elevated,
+    );
+
+    for (index, segment) in segments.iter().enumerate() {
+        let Some((start, end)) = span_fractions(session_start, session_end, segment) else {
+            continue;
+        };
+
+        let left = bar.left() + bar.width() * start;
+        let right = if segment.kind == SegmentKind::CheckIn {
+            left + CHECK_IN_MARKER_WIDTH
+        } else {
+            (bar.left() + bar.width() * end).max(left + 1.0)
+        };
+        let rect = egui::Rect::from_min_max(
+            egui::pos2(left, bar.top()),
+            egui::pos2(right.min(bar.right()), bar.bottom()),
+        );
+
+        let color = segment_color(segment, theme);
+        ui.painter().rect_filled(rect, Rounding::ZERO, color);
+        let hover_rect = if segment.kind == SegmentKind::CheckIn {
+            let center = egui::pos2(rect.center().x, area.top() + CHECK_IN_DOT_RADIUS);
+            ui.painter()
+                .circle_filled(center, CHECK_IN_DOT_RADIUS, color);
+            rect.union(egui::Rect::from_center_size(
+                center,
+                egui::Vec2::splat(CHECK_IN_DOT_RADIUS * 2.0),
+            ))
+        } else {
+            rect
+        };
+        ui.interact(
+            hover_rect,
+            ui.id().with(("timeline_segment", index)),
+            Sense::hover(),
+        )
+        .on_hover_text(segment_description(segment, translator));
+    }
+}
+
+fn render_legend(ui: &mut Ui, translator: &Translator, theme: &Theme) {
+    let kinds = [
+        SegmentKind::Focus,
+        SegmentKind::Distraction,
+        SegmentKind::Pause,
+    ]
+    .map(|kind| (kind_color(kind, theme), kind_label(kind, translator)));
+    let check_ins = [
+        CheckInOutcome::Focused,
+        CheckInOutcome::NotFocused,
+        CheckInOutcome::Timeout,
+    ]
+    .map(|outcome| {
+        (
+            check_in_color(Some(outcome), theme),
+            check_in_label(Some(outcome), translator),
+        )
+    });
+
+    ui.horizontal_wrapped(|ui| {
+        for (color, label) in kinds.into_iter().chain(check_ins) {
+            let (rect, _) = ui.allocate_exact_size(egui::vec2(10.0, 10.0), Sense::hover());
+            ui.painter().rect_filled(rect, Rounding::same(2.0), color);
+            ui.label(
+                egui::RichText::new(label)
+                    .size(theme.typography.label)
+                    .color(theme.colors.text_secondary),
+            );
+            ui.add_space(theme.spacing.sm);
+        }
+    });
+}
+
+fn render_distraction_summary(
+    ui: &mut Ui,
+    distractions: &[(String, usize, i64)],
+    translator: &Translator,
+    theme: &Theme,
+) {
+    ui.label(
+        egui::RichText::new(translator.get("gui.timeline_distractions_title"))
+            .size(theme.typography.body)
+            .color(theme.colors.text_primary)
+            .strong(),
+    );
+    for (application_name, count, seconds) in distractions {
+        ui.label(
+            egui::RichText::new(translator.format(
+                "gui.timeline_distraction_entry",
+                &[
+                    ("app", application_name),
+                    ("count", &count.to_string()),
+                    ("duration", &format_duration(*seconds)),
+                ],
+            ))
+            .size(theme.typography.label)
+            .color(theme.colors.error),
+        );
+    }
+}
+
+fn render_segment_row(
+    ui: &mut Ui,
+    segment: &AppUsageSegment,
+    translator: &Translator,
+    theme: &Theme,
+) {
+    ui.horizontal(|ui| {
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(4.0, 14.0), Sense::hover());
+        ui.painter()
+            .rect_filled(rect, Rounding::ZERO, segment_color(segment, theme));
+        ui.label(
+            egui::RichText::new(segment_description(segment, translator))
+                .size(theme.typography.label)
+                .color(theme.colors.text_secondary),
+        );
+    });
+}
+
+fn segment_description(segment: &AppUsageSegment, translator: &Translator) -> String {
+    let subject = if segment.kind == SegmentKind::CheckIn {
+        check_in_label(segment.check_in_outcome, translator)
+    } else if segment.application_name.is_empty() {
+        kind_label(segment.kind, translator)
+    } else if segment.window_title.is_empty() {
+        segment.application_name.clone()
+    } else {
+        format!("{} — {}", segment.application_name, segment.window_title)
+    };
+
+    if segment.kind == SegmentKind::CheckIn {
+        return format!("{} {}", format_time(segment.started_at), subject);
+    }
+
+    format!(
+        "{} – {} {} ({})",
+        format_time(segment.started_at),
+        format_time(segment.ended_at),
+        subject,
+        format_duration(segment.duration_seconds())
+    )
+}
+
+fn kind_label(kind: SegmentKind, translator: &Translator) -> String {
+    match kind {
+        SegmentKind::Focus => translator.get("gui.timeline_focus"),
+        SegmentKind::Distraction => translator.get("gui.timeline_distraction"),
+        SegmentKind::Pause => translator.get("gui.timeline_pause"),
+        SegmentKind::CheckIn => translator.get("gui.timeline_check_in"),
+    }
+}
+
+fn kind_color(kind: SegmentKind, theme: &Theme) -> egui::Color32 {
+    match kind {
+        SegmentKind::Focus => theme.colors.accent,
+        SegmentKind::Distraction => theme.colors.error,
+        SegmentKind::Pause => theme.colors.text_muted,
+        SegmentKind::CheckIn => theme.colors.warning,
+    }
+}
+
+/// Check-ins recorded before outcomes were stored keep the neutral label
+fn check_in_label(outcome: Option<CheckInOutcome>, translator: &Translator) -> String {
+    match outcome {
+        Some(CheckInOutcome::Focused) => translator.get("gui.timeline_check_in_focused"),
+        Some(CheckInOutcome::NotFocused) => translator.get("gui.timeline_check_in_not_focused"),
+        Some(CheckInOutcome::Timeout) => translator.get("gui.timeline_check_in_timeout"),
+        None => translator.get("gui.timeline_check_in"),
+    }
+}
+
+fn check_in_color(outcome: Option<CheckInOutcome>, theme: &Theme) -> egui::Color32 {
+    match outcome {
+        Some(CheckInOutcome::Focused) => theme.colors.success,
+        Some(CheckInOutcome::NotFocused) => theme.colors.error,
+        Some(CheckInOutcome::Timeout) => theme.colors.warning,
+        None => theme.colors.text_muted,
+    }
+}
+
+fn segment_color(segment: &AppUsageSegment, theme: &Theme) -> egui::Color32 {
+    match segment.kind {
+        SegmentKind::CheckIn => check_in_color(segment.check_in_outcome, theme),
+        kind => kind_color(kind, theme),
+    }
+}
+
+fn format_time(datetime: DateTime<Utc>) -> String {
+    let local: DateTime<Local> = datetime.into();
+    local.format("%H:%M:%S").to_string()
+}
+
+/// Distraction stretches per application, most time-consuming first
+fn distraction_totals(segments: &[AppUsageSegment]) -> Vec<(String, usize, i64)> {
+    let mut totals: Vec<(String, usize, i64)> = Vec::new();
+    for segment in segments
+        .iter()
+        .filter(|segment| segment.kind == SegmentKind::Distraction)
+    {
+        match totals
+            .iter_mut()
+            .find(|(name, _, _)| *name == segment.application_name)
+        {
+            Some((_, count, seconds)) => {
+                *count += 1;
+                *seconds += segment.duration_seconds();
+            }
+            None => totals.push((
+                segment.application_name.clone(),
+                1,
+                segment.duration_seconds(),
+            )),
+        }
+    }
+    totals.sort_by_key(|(_, _, seconds)| std::cmp::Reverse(*seconds));
+    totals
+}
+
+/// Position of a segment along the session, as fractions of its length
+fn span_fractions(
+    session_start: DateTime<Utc>,
+    session_end: DateTime<Utc>,
+    segment: &AppUsageSegment,
+) -> Option<(f32, f32)> {
+    let length = session_end
+        .signed_duration_since(session_start)
+        .num_milliseconds();
+    if length <= 0 {
+        return None;
+    }
+
+    let fraction = |at: DateTime<Utc>| {
+        (at.signed_duration_since(session_start).num_milliseconds() as f32 / length as f32)
+            .clamp(0.0, 1.0)
+    };
+
+    Some((fraction(segment.started_at), fraction(segment.ended_at)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn segments_are_placed_relative_to_the_session() {
+        let start = Utc.with_ymd_and_hms(2026, 10, 14, 9, 0, 0).unwrap();
+        let end = start + Duration::minutes(40);
+        let segment = AppUsageSegment::new(
+            1,
+            SegmentKind::Distraction,
+            "discord".to_string(),
+            start + Duration::minutes(10),
+            start + Duration::minutes(20),
+        );
+
+        assert_eq!(span_fractions(start, end, &segment), Some((0.25, 0.5)));
+    }
+
+    #[test]
+    fn distractions_are_summed_per_application() {
+        let start = Utc.with_ymd_and_hms(2026, 10, 14, 9, 0, 0).unwrap();
+        let segment = |kind, application_name: &str, from: i64, to: i64| {
+            AppUsageSegment::new(
+                1,
+                kind,
+                application_name.to_string(),
+                start + Duration::minutes(from),
+                start + Duration::minutes(to),
+            )
+        };
+
+        let totals = distraction_totals(&[
+            segment(SegmentKind::Focus, "cursor", 0, 10),
+            segment(SegmentKind::Distraction, "slack", 10, 11),
+            segment(SegmentKind::Distraction, "discord", 11, 14),
+            segment(SegmentKind::Focus, "cursor", 14, 20),
+            segment(SegmentKind::Distraction, "slack", 20, 21),
+        ]);
+
+        assert_eq!(
+            totals,
+            vec![
+                ("discord".to_string(), 1, 180),
+                ("slack".to_string(), 2, 120),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_ins_are_described_by_their_outcome() {
+        let translator = Translator::new(flux_core::Language::En);
+        let at = Utc.with_ymd_and_hms(2026, 10, 14, 9, 0, 0).unwrap();
+        let answered = AppUsageSegment::check_in(1, at)
+            .with_check_in_outcome(Some(CheckInOutcome::NotFocused));
+
+        assert!(segment_description(&answered, &translator).ends_with("Check-in: not focused"));
+        assert!(
+            segment_description(&AppUsageSegment::check_in(1, at), &translator)
+                .ends_with("Check-in")
+        );
+    }
+
+    #[test]
+    fn segments_outside_the_session_are_clamped() {
+        let start = Utc.with_ymd_and_hms(2026, 10, 14, 9, 0, 0).unwrap();
+        let end = start + Duration::minutes(10);
+        let segment =
+            AppUsageSegment::pause(1, start - Duration::minutes(1), end + Duration::minutes(1));
+
+        assert_eq!(span_fractions(start, end, &segment), Some((0.0, 1.0)));
+        assert_eq!(span_fractions(start, start, &segment), None);
+    }
+}
@@ -0,0 +1,56 @@
+//! Wire format of the IPC messages
+//!
+//! Every frame carries a JSON envelope holding the protocol version and the
+//! message. Enums are tagged by name and optional fields default when absent,
+//! so peers can add variants and fields without breaking each other.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::PROTOCOL_VERSION;
+
+/// Variant index of `Response::Error` in the bincode encoding of protocol version 1
+const LEGACY_ERROR_VARIANT: u32 = 2;
+
+#[derive(Error, Debug)]
+pub enum ProtocolError {
+    #[error("message IPC invalide: {0}")]
+    InvalidMessage(String),
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope<T> {
+    version: u32,
+    message: T,
+}
+
+/// Encode a message in the current protocol envelope
+pub fn encode<T: Serialize>(message: &T) -> Result<Vec<u8>, ProtocolError> {
+    serde_json::to_vec(&Envelope {
+        version: PROTOCOL_VERSION,
+        message,
+    })
+    .map_err(|error| ProtocolError::InvalidMessage(error.to_string()))
+}
+
+/// Decode a message from a protocol envelope, whatever its version
+pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ProtocolError> {
+    serde_json::from_slice::<Envelope<T>>(bytes)
+        .map(|envelope| envelope.message)
+        .map_err(|error| ProtocolError::InvalidMessage(error.to_string()))
+}
+
+/// Whether a frame was sent by a peer speaking the bincode protocol (version 1)
+pub fn is_legacy_frame(bytes: &[u8]) -> bool {
+    bytes.first() != Some(&b'{')
+}
+
+/// Encode an error response readable by bincode peers (version 1)
+pub fn encode_legacy_error(message: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(12 + message.len());
+    bytes.extend_from_slice(&LEGACY_ERROR_VARIANT.to_le_bytes());
+    bytes.extend_from_slice(&(message.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(message.as_bytes());
+    bytes
+}
@@ -1,38 +1,129 @@
 //! Flux protocol definitions for CLI-daemon communication
 //!
 //! This crate defines the IPC protocol between the flux CLI and daemon.
-//! All types are serializable with bincode for efficient binary communication.
+//! Messages are exchanged as length-prefixed JSON envelopes (see [`codec`]).
 
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+pub mod codec;
+pub mod socket;
+
+pub use codec::{decode, encode, ProtocolError};
 pub use flux_core::FocusMode;
 
+/// Version of the IPC protocol spoken by this build
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// Oldest client protocol version the daemon still accepts
+pub const MINIMUM_PROTOCOL_VERSION: u32 = 2;
+
+/// Whether a peer speaking `version` can talk to this build
+pub fn is_supported_protocol(version: u32) -> bool {
+    (MINIMUM_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&version)
+}
+
+/// Unique token identifying a start request across retries
+pub fn new_request_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanoseconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    format!(
+        "{}-{}-{}",
+        std::process::id(),
+        nanoseconds,
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Identifier of a long-running operation started by the daemon
+pub type JobId = u64;
+
 /// Requests sent from CLI to daemon
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum Request {
     /// Start a new focus session
     StartSession {
         /// Duration in minutes (None = use default from config)
+        #[serde(default)]
         duration: Option<u64>,
         /// Focus mode (None = use default)
+        #[serde(default)]
         mode: Option<FocusMode>,
+        /// Free-form tags attached to the session
+        #[serde(default)]
+        tags: Vec<String>,
+        /// Idempotency token: a retried request with the same token starts no new session
+        #[serde(default)]
+        request_id: Option<String>,
+        /// Refuse pause and stop requests until the session ends
+        #[serde(default)]
+        strict: bool,
+        /// Apps counted as distractions for this session only, on top of the configured ones
+        #[serde(default)]
+        distraction_apps: Vec<String>,
     },
     /// Stop the current focus session
-    StopSession,
+    StopSession {
+        /// Phrase typed by the user to stop a strict session anyway
+        #[serde(default)]
+        confirmation: Option<String>,
+        /// Why the session is stopped, recorded with the session
+        #[serde(default)]
+        reason: Option<String>,
+    },
     /// Pause the current focus session
-    PauseSession,
+    PauseSession {
+        /// Phrase typed by the user to pause a strict session anyway
+        #[serde(default)]
+        confirmation: Option<String>,
+        /// Why the session is paused, recorded with the session
+        #[serde(default)]
+        reason: Option<String>,
+    },
     /// Resume a paused session
     ResumeSession,
     /// Get current session status
     GetStatus,
     /// Ping the daemon to check if it's alive
     Ping,
+    /// Get the initialization status of the daemon subsystems
+    GetHealth,
+    /// Get runtime information about the daemon process
+    GetDaemonInfo,
     /// Shutdown the daemon gracefully
     Shutdown,
+    /// Attach a one-line retrospective to a session
+    AddNote {
+        /// Target session (None = the most recent session)
+        #[serde(default)]
+        session_id: Option<i64>,
+        /// Note text
+        text: String,
+    },
+    /// Rebuild the daily usage rollups in the background; answered with `Accepted`
+    RefreshRollups,
+    /// Get the state of a long-running operation
+    GetJobStatus { job_id: JobId },
+    /// Cancel a long-running operation; answered with its resulting state
+    CancelJob { job_id: JobId },
+    /// Exchange protocol versions
+    Handshake {
+        /// Protocol version spoken by the client
+        protocol_version: u32,
+        /// Client version
+        client_version: String,
+    },
 }
 
 /// Responses sent from daemon to CLI
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum Response {
     /// Session status information
     SessionStatus {
@@ -44,13 +135,206 @@ pub enum Response {
         mode: Option<FocusMode>,
         /// Whether the session is paused
         paused: bool,
+        /// Seconds left in the preparation countdown (None once the session has begun)
+        #[serde(default)]
+        preparing_seconds: Option<u64>,
+        /// Whether pause and stop need the confirmation phrase
+        #[serde(default)]
+        strict: bool,
     },
     /// Generic success acknowledgment
     Ok,
+    /// A start request was rejected because a session is already running
+    AlreadyActive {
+        /// Focus mode of the running session
+        mode: FocusMode,
+        /// Remaining time of the running session in seconds
+        remaining_seconds: u64,
+    },
+    /// A pause or stop was refused because the session is strict
+    StrictSession {
+        /// Remaining time of the session in seconds
+        remaining_seconds: u64,
+        /// Phrase to send back as confirmation to interrupt the session anyway
+        confirmation_phrase: String,
+    },
     /// Error response with message
     Error { message: String },
     /// Pong response to ping
     Pong,
+    /// Status of each daemon subsystem
+    Health {
+        subsystems: Vec<SubsystemHealth>,
+        /// Problems found while loading the configuration
+        #[serde(default)]
+        config_warnings: Vec<String>,
+    },
+    /// Runtime information about the daemon process
+    DaemonInfo {
+        /// Daemon version
+        version: String,
+        /// Daemon process identifier
+        pid: u32,
+        /// Seconds since the daemon started
+        uptime_seconds: u64,
+        /// Path of the IPC socket
+        socket_path: String,
+        /// Path of the session database (None if persistence is unavailable)
+        #[serde(default)]
+        database_path: Option<String>,
+        /// Whether the tray icon is running
+        tray_active: bool,
+        /// Whether the application tracker is running
+        tracker_active: bool,
+    },
+    /// A note was attached to a session
+    NoteAdded {
+        /// Session the note was attached to
+        session_id: i64,
+    },
+    /// A long-running operation was started; poll it with `GetJobStatus`
+    Accepted { job_id: JobId },
+    /// State of a long-running operation
+    JobStatus { job_id: JobId, state: JobState },
+    /// Answer to a handshake
+    Handshake {
+        /// Protocol version spoken by the daemon
+        protocol_version: u32,
+        /// Daemon version
+        daemon_version: String,
+        /// Whether the daemon accepts the client protocol version
+        accepted: bool,
+        /// Optional features this daemon provides
+        #[serde(default)]
+        features: Vec<Feature>,
+    },
+    /// The request needs a feature this daemon does not provide
+    Unsupported { feature: Feature },
+}
+
+/// Optional capability of a daemon, announced in the handshake so clients can
+/// hide what the daemon cannot do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Feature {
+    /// Session history in the database: notes and usage rollups
+    History,
+    /// Active window tracking and distraction alerts
+    AppTracking,
+    /// Tray icon
+    Tray,
+    /// Feature announced by a newer daemon
+    #[serde(other)]
+    Unknown,
+}
+
+impl Feature {
+    /// Stable identifier used for translation keys
+    pub fn id(&self) -> &'static str {
+        match self {
+            Feature::History => "history",
+            Feature::AppTracking => "app_tracking",
+            Feature::Tray => "tray",
+            Feature::Unknown => "unknown",
+        }
+    }
+}
+
+/// Progress of a long-running operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum JobState {
+    Running,
+    Completed {
+        /// Short summary of the result
+        #[serde(default)]
+        output: Option<String>,
+    },
+    Failed {
+        reason: String,
+    },
+    Cancelled,
+}
+
+impl JobState {
+    pub fn is_finished(&self) -> bool {
+        *self != JobState::Running
+    }
+}
+
+/// Daemon subsystem reported by the health request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Subsystem {
+    Persistence,
+    AppTracking,
+    Tray,
+    Notifications,
+    WindowDetector,
+    Digest,
+    Power,
+}
+
+impl Subsystem {
+    /// Stable identifier used for translation keys
+    pub fn id(&self) -> &'static str {
+        match self {
+            Subsystem::Persistence => "persistence",
+            Subsystem::AppTracking => "app_tracking",
+            Subsystem::Tray => "tray",
+            Subsystem::Notifications => "notifications",
+            Subsystem::WindowDetector => "window_detector",
+            Subsystem::Digest => "digest",
+            Subsystem::Power => "power",
+        }
+    }
+}
+
+/// Initialization status of a daemon subsystem
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubsystemStatus {
+    /// The subsystem is running
+    Running,
+    /// The subsystem is disabled by configuration or unsupported on this platform
+    Disabled,
+    /// The subsystem failed to initialize
+    Failed { reason: Option<String> },
+    /// The subsystem stopped unexpectedly and was restarted by the supervisor
+    Degraded {
+        restarts: u32,
+        reason: Option<String>,
+    },
+}
+
+/// Status of a single daemon subsystem
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubsystemHealth {
+    pub subsystem: Subsystem,
+    pub status: SubsystemStatus,
+    /// Backend in use, such as the notification server or window system
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+impl SubsystemHealth {
+    pub fn new(subsystem: Subsystem, status: SubsystemStatus) -> Self {
+        Self {
+            subsystem,
+            status,
+            detail: None,
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self.status, SubsystemStatus::Failed { .. })
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        matches!(self.status, SubsystemStatus::Degraded { .. })
+    }
 }
 
 #[cfg(test)]
@@ -67,8 +351,8 @@ mod tests {
         ];
 
         for mode in modes {
-            let bytes = bincode::serialize(&mode).unwrap();
-            let decoded: FocusMode = bincode::deserialize(&bytes).unwrap();
+            let bytes = encode(&mode).unwrap();
+            let decoded: FocusMode = decode(&bytes).unwrap();
             assert_eq!(mode, decoded);
         }
     }
@@ -78,10 +362,14 @@ mod tests {
         let request = Request::StartSession {
             duration: Some(25),
             mode: Some(FocusMode::AiAssisted),
+            tags: vec!["backend".to_string(), "client-x".to_string()],
+            request_id: Some(new_request_id()),
+            strict: true,
+            distraction_apps: vec!["slack".to_string()],
         };
 
-        let bytes = bincode::serialize(&request).unwrap();
-        let decoded: Request = bincode::deserialize(&bytes).unwrap();
+        let bytes = encode(&request).unwrap();
+        let decoded: Request = decode(&bytes).unwrap();
 
         assert_eq!(request, decoded);
     }
@@ -91,10 +379,14 @@ mod tests {
         let request = Request::StartSession {
             duration: None,
             mode: None,
+            tags: Vec::new(),
+            request_id: None,
+            strict: false,
+            distraction_apps: Vec::new(),
         };
 
-        let bytes = bincode::serialize(&request).unwrap();
-        let decoded: Request = bincode::deserialize(&bytes).unwrap();
+        let bytes = encode(&request).unwrap();
+        let decoded: Request = decode(&bytes).unwrap();
 
         assert_eq!(request, decoded);
     }
@@ -102,17 +394,40 @@ mod tests {
     #[test]
     fn request_variants_serialization() {
         let requests = vec![
-            Request::StopSession,
-            Request::PauseSession,
+            Request::StopSession {
+                confirmation: None,
+                reason: None,
+            },
+            Request::PauseSession {
+                confirmation: Some("I give up this session".to_string()),
+                reason: Some("standup meeting".to_string()),
+            },
             Request::ResumeSession,
             Request::GetStatus,
             Request::Ping,
+            Request::GetHealth,
+            Request::GetDaemonInfo,
             Request::Shutdown,
+            Request::Handshake {
+                protocol_version: PROTOCOL_VERSION,
+                client_version: "0.2.13".to_string(),
+            },
+            Request::AddNote {
+                session_id: None,
+                text: "refactored the parser".to_string(),
+            },
+            Request::AddNote {
+                session_id: Some(12),
+                text: "reviewed two merge requests".to_string(),
+            },
+            Request::RefreshRollups,
+            Request::GetJobStatus { job_id: 3 },
+            Request::CancelJob { job_id: 3 },
         ];
 
         for request in requests {
-            let bytes = bincode::serialize(&request).unwrap();
-            let decoded: Request = bincode::deserialize(&bytes).unwrap();
+            let bytes = encode(&request).unwrap();
+            let decoded: Request = decode(&bytes).unwrap();
             assert_eq!(request, decoded);
         }
     }
@@ -124,10 +439,12 @@ mod tests {
             remaining_seconds: 1500,
             mode: Some(FocusMode::Architecture),
             paused: false,
+            preparing_seconds: Some(20),
+            strict: true,
         };
 
-        let bytes = bincode::serialize(&response).unwrap();
-        let decoded: Response = bincode::deserialize(&bytes).unwrap();
+        let bytes = encode(&response).unwrap();
+        let decoded: Response = decode(&bytes).unwrap();
 
         assert_eq!(response, decoded);
     }
@@ -136,16 +453,200 @@ mod tests {
     fn response_variants_serialization() {
         let responses = vec![
             Response::Ok,
+            Response::AlreadyActive {
+                mode: FocusMode::Review,
+                remaining_seconds: 1200,
+            },
+            Response::StrictSession {
+                remaining_seconds: 900,
+                confirmation_phrase: "I give up this session".to_string(),
+            },
             Response::Error {
                 message: "Session déjà active".to_string(),
             },
             Response::Pong,
+            Response::NoteAdded { session_id: 12 },
+            Response::Accepted { job_id: 3 },
+            Response::JobStatus {
+                job_id: 3,
+                state: JobState::Running,
+            },
+            Response::JobStatus {
+                job_id: 3,
+                state: JobState::Completed {
+                    output: Some("412 lignes agrégées".to_string()),
+                },
+            },
+            Response::JobStatus {
+                job_id: 4,
+                state: JobState::Failed {
+                    reason: "base de données verrouillée".to_string(),
+                },
+            },
+            Response::JobStatus {
+                job_id: 5,
+                state: JobState::Cancelled,
+            },
+            Response::Health {
+                subsystems: vec![
+                    SubsystemHealth::new(Subsystem::Persistence, SubsystemStatus::Running),
+                    SubsystemHealth::new(Subsystem::Tray, SubsystemStatus::Disabled),
+                    SubsystemHealth::new(Subsystem::WindowDetector, SubsystemStatus::Running)
+                        .with_detail("x11"),
+                    SubsystemHealth::new(
+                        Subsystem::Digest,
+                        SubsystemStatus::Degraded {
+                            restarts: 2,
+                            reason: Some("le planificateur a paniqué".to_string()),
+                        },
+                    ),
+                    SubsystemHealth::new(
+                        Subsystem::AppTracking,
+                        SubsystemStatus::Failed {
+                            reason: Some("base de données verrouillée".to_string()),
+                        },
+                    ),
+                ],
+                config_warnings: vec!["profil actif introuvable: travail".to_string()],
+            },
+            Response::DaemonInfo {
+                version: "0.2.13".to_string(),
+                pid: 4242,
+                uptime_seconds: 3600,
+                socket_path: "/run/user/1000/flux.sock".to_string(),
+                database_path: Some("/home/damien/.local/share/flux/sessions.db".to_string()),
+                tray_active: true,
+                tracker_active: false,
+            },
+            Response::Handshake {
+                protocol_version: PROTOCOL_VERSION,
+                daemon_version: "0.2.13".to_string(),
+                accepted: true,
+                features: vec![Feature::History, Feature::Tray],
+            },
+            Response::Unsupported {
+                feature: Feature::History,
+            },
         ];
 
         for response in responses {
-            let bytes = bincode::serialize(&response).unwrap();
-            let decoded: Response = bincode::deserialize(&bytes).unwrap();
+            let bytes = encode(&response).unwrap();
+            let decoded: Response = decode(&bytes).unwrap();
             assert_eq!(response, decoded);
         }
     }
+
+    #[test]
+    fn request_ids_are_unique() {
+        assert_ne!(new_request_id(), new_request_id());
+    }
+
+    #[test]
+    fn messages_are_wrapped_in_a_versioned_envelope() {
+        let bytes = encode(&Request::Ping).unwrap();
+
+        let envelope: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(envelope["version"], PROTOCOL_VERSION);
+        assert_eq!(envelope["message"]["type"], "ping");
+    }
+
+    #[test]
+    fn older_message_shapes_use_defaults() {
+        let request: Request =
+            decode(br#"{"version": 2, "message": {"type": "start_session", "duration": 25}}"#)
+                .unwrap();
+        assert_eq!(
+            request,
+            Request::StartSession {
+                duration: Some(25),
+                mode: None,
+                tags: Vec::new(),
+                request_id: None,
+                strict: false,
+                distraction_apps: Vec::new(),
+            }
+        );
+
+        let request: Request =
+            decode(br#"{"version": 2, "message": {"type": "stop_session"}}"#).unwrap();
+        assert_eq!(
+            request,
+            Request::StopSession {
+                confirmation: None,
+                reason: None
+            }
+        );
+
+        let response: Response =
+            decode(br#"{"version": 2, "message": {"type": "health", "subsystems": []}}"#).unwrap();
+        assert_eq!(
+            response,
+            Response::Health {
+                subsystems: Vec::new(),
+                config_warnings: Vec::new(),
+            }
+        );
+
+        let response: Response = decode(
+            br#"{"version": 2, "message": {"type": "handshake", "protocol_version": 2, "daemon_version": "0.2.12", "accepted": true}}"#,
+        )
+        .unwrap();
+        assert!(matches!(response, Response::Handshake { features, .. } if features.is_empty()));
+    }
+
+    #[test]
+    fn features_from_a_newer_daemon_are_kept_as_unknown() {
+        let response: Response = decode(
+            br#"{"version": 3, "message": {"type": "handshake", "protocol_version": 3, "daemon_version": "0.3.0", "accepted": true, "features": ["Tray", "HttpApi"]}}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            response,
+            Response::Handshake { features, .. } if features == vec![Feature::Tray, Feature::Unknown]
+        ));
+    }
+
+    #[test]
+    fn newer_message_shapes_ignore_unknown_fields() {
+        let response: Response = decode(
+            br#"{"version": 3, "message": {"type": "error", "message": "oups", "code": 7}, "trace": "abc"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response,
+            Response::Error {
+                message: "oups".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_variant_is_rejected() {
+        let result: Result<Request, _> =
+            decode(br#"{"version": 3, "message": {"type": "teleport"}}"#);
+
+        assert!(matches!(result, Err(ProtocolError::InvalidMessage(_))));
+    }
+
+    #[test]
+    fn legacy_frames_get_a_bincode_error() {
+        assert!(codec::is_legacy_frame(&[7, 0, 0, 0]));
+        assert!(!codec::is_legacy_frame(&encode(&Request::Ping).unwrap()));
+
+        let bytes = codec::encode_legacy_error("mettez à jour");
+
+        assert_eq!(&bytes[..4], &[2, 0, 0, 0]);
+        assert_eq!(&bytes[4..12], &("mettez à jour".len() as u64).to_le_bytes());
+        assert_eq!(&bytes[12..], "mettez à jour".as_bytes());
+    }
+
+    #[test]
+    fn supported_protocol_versions() {
+        assert!(is_supported_protocol(PROTOCOL_VERSION));
+        assert!(is_supported_protocol(MINIMUM_PROTOCOL_VERSION));
+        assert!(!is_supported_protocol(PROTOCOL_VERSION + 1));
+        assert!(!is_supported_protocol(0));
+    }
 }
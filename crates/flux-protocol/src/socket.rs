@@ -0,0 +1,222 @@
+//! Location of the daemon socket, shared by the daemon and its clients
+//!
+//! The daemon listens on a Unix domain socket on Unix and on a named pipe on
+//! Windows. Both are addressed by a path, `\\.\pipe\...` for named pipes.
+
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use interprocess::local_socket::{GenericFilePath, Name, ToFsName};
+
+/// Environment variable overriding the socket path
+pub const SOCKET_PATH_VARIABLE: &str = "FLUX_SOCKET";
+
+#[cfg(unix)]
+const SOCKET_FILE: &str = "flux.sock";
+
+#[cfg(windows)]
+const PIPE_DIRECTORY: &str = r"\\.\pipe\";
+
+/// Path of the daemon socket for the current user
+pub fn socket_path() -> PathBuf {
+    configured_path(std::env::var_os(SOCKET_PATH_VARIABLE)).unwrap_or_else(default_path)
+}
+
+/// Local socket name to listen on or connect to for a socket path
+pub fn socket_name(path: &Path) -> io::Result<Name<'_>> {
+    path.as_os_str().to_fs_name::<GenericFilePath>()
+}
+
+/// Whether a daemon is listening at the socket path
+#[cfg(unix)]
+pub fn is_listening(path: &Path) -> bool {
+    path.exists()
+}
+
+/// Whether a daemon is listening at the socket path
+#[cfg(windows)]
+pub fn is_listening(path: &Path) -> bool {
+    let Some(pipe_name) = path.file_name() else {
+        return false;
+    };
+
+    std::fs::read_dir(PIPE_DIRECTORY)
+        .map(|entries| {
+            entries
+                .flatten()
+                .any(|entry| entry.file_name() == pipe_name)
+        })
+        .unwrap_or(false)
+}
+
+/// Checks the directory of a socket in the shared temporary directory before
+/// it is used: anyone can create `/tmp/flux-<uid>` first and choose what
+/// listens there, so it must be a real directory owned by the current user
+/// and closed to others. A missing directory has no socket to trust yet.
+#[cfg(unix)]
+pub fn check_socket_directory(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(directory) if directory == fallback_directory() => {
+            match check_private_directory(directory, current_user_id()) {
+                Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+                result => result,
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Named pipes live outside the file system
+#[cfg(windows)]
+pub fn check_socket_directory(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// Identifier of the current user
+#[cfg(unix)]
+pub fn current_user_id() -> u32 {
+    unsafe { libc::getuid() }
+}
+
+fn configured_path(value: Option<OsString>) -> Option<PathBuf> {
+    value.filter(|path| !path.is_empty()).map(PathBuf::from)
+}
+
+#[cfg(unix)]
+fn default_path() -> PathBuf {
+    runtime_directory(&candidate_directories(), fallback_directory()).join(SOCKET_FILE)
+}
+
+#[cfg(unix)]
+fn runtime_directory(candidates: &[PathBuf], fallback: PathBuf) -> PathBuf {
+    candidates
+        .iter()
+        .find(|directory| directory.is_dir())
+        .cloned()
+        .unwrap_or(fallback)
+}
+
+#[cfg(unix)]
+fn candidate_directories() -> Vec<PathBuf> {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .filter(|directory| !directory.is_empty())
+        .map(PathBuf::from)
+        .into_iter()
+        .chain(std::iter::once(PathBuf::from(format!(
+            "/run/user/{}",
+            current_user_id()
+        ))))
+        .collect()
+}
+
+#[cfg(unix)]
+fn fallback_directory() -> PathBuf {
+    std::env::temp_dir().join(format!("flux-{}", current_user_id()))
+}
+
+#[cfg(unix)]
+fn check_private_directory(directory: &Path, user_id: u32) -> io::Result<()> {
+    use std::os::unix::fs::MetadataExt;
+
+    let metadata = std::fs::symlink_metadata(directory)?;
+    let problem = if metadata.file_type().is_symlink() {
+        Some("est un lien symbolique")
+    } else if !metadata.is_dir() {
+        Some("n'est pas un répertoire")
+    } else if metadata.uid() != user_id {
+        Some("appartient à un autre utilisateur")
+    } else if metadata.mode() & 0o777 != 0o700 {
+        Some("est accessible aux autres utilisateurs")
+    } else {
+        None
+    };
+
+    match problem {
+        Some(problem) => Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} {}", directory.display(), problem),
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+fn default_path() -> PathBuf {
+    let user_name = std::env::var("USERNAME").unwrap_or_else(|_| "default".to_string());
+    PathBuf::from(format!("{}flux-{}", PIPE_DIRECTORY, user_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configured_path_ignores_empty_value() {
+        assert_eq!(
+            configured_path(Some(OsString::from("/tmp/custom.sock"))),
+            Some(PathBuf::from("/tmp/custom.sock"))
+        );
+        assert_eq!(configured_path(Some(OsString::new())), None);
+        assert_eq!(configured_path(None), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn first_existing_directory_is_used() {
+        let directory = runtime_directory(
+            &[
+                PathBuf::from("/nonexistent-flux-runtime"),
+                std::env::temp_dir(),
+            ],
+            PathBuf::from("/fallback"),
+        );
+
+        assert_eq!(directory, std::env::temp_dir());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn fallback_is_used_without_runtime_directory() {
+        let directory = runtime_directory(
+            &[PathBuf::from("/nonexistent-flux-runtime")],
+            PathBuf::from("/fallback"),
+        );
+
+        assert_eq!(directory, PathBuf::from("/fallback"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn only_a_private_directory_of_the_user_is_trusted() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory =
+            std::env::temp_dir().join(format!("flux-socket-check-{}", std::process::id()));
+        let link = directory.with_extension("link");
+        let _ = std::fs::remove_dir_all(&directory);
+        let _ = std::fs::remove_file(&link);
+        std::fs::create_dir(&directory).unwrap();
+        std::os::unix::fs::symlink(&directory, &link).unwrap();
+        let user_id = current_user_id();
+
+        std::fs::set_permissions(&directory, std::fs::Permissions::from_mode(0o700)).unwrap();
+        assert!(check_private_directory(&directory, user_id).is_ok());
+        assert!(check_private_directory(&directory, user_id + 1).is_err());
+        assert!(check_private_directory(&link, user_id).is_err());
+
+        std::fs::set_permissions(&directory, std::fs::Permissions::from_mode(0o777)).unwrap();
+        let error = check_private_directory(&directory, user_id).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+
+        std::fs::remove_file(&link).unwrap();
+        std::fs::remove_dir(&directory).unwrap();
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn default_path_is_a_named_pipe() {
+        assert!(default_path().starts_with(PIPE_DIRECTORY));
+        assert!(socket_name(&default_path()).is_ok());
+    }
+}